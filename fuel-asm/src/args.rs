@@ -27,6 +27,9 @@ crate::enum_try_from! {
 
         /// Get the Chain ID this VM is operating within
         GetChainId = 0x04,
+
+        /// Get the number of nested contract calls that can still be made.
+        GetRemainingCallDepth = 0x05,
     },
     Immediate18
 }
@@ -230,10 +233,14 @@ crate::enum_try_from! {
         /// Set `$rA` to `Memory address of tx.witnesses[$rB].data`
         WitnessData = 0x401,
 
-        /// Set `$rA` to `tx.policyTypes`
+        /// Set `$rA` to `tx.policyTypes`.
+        ///
+        /// The policy at the bit `n` of `tx.policyTypes` is read with the argument
+        /// `0x501 + n`. Reading a policy whose bit is unset panics with
+        /// `PolicyIsNotSet`.
         PolicyTypes = 0x500,
 
-        /// Set `$rA` to `tx.policies[0x00].gasPrice`
+        /// Set `$rA` to `tx.policies[count_ones(0b1 & tx.policyTypes) - 1].tip`
         PolicyTip = 0x501,
 
         /// Set `$rA` to `tx.policies[count_ones(0b11 & tx.policyTypes) - 1].witnessLimit`
@@ -244,6 +251,9 @@ crate::enum_try_from! {
 
         /// Set `$rA` to `tx.policies[count_ones(0b1111 & tx.policyTypes) - 1].maxFee`
         PolicyMaxFee = 0x504,
+
+        /// Set `$rA` to `tx.policies[count_ones(0b11111 & tx.policyTypes) - 1].gasPriceFloor`
+        PolicyGasPriceFloor = 0x505,
     },
     Immediate12
 }
@@ -262,6 +272,7 @@ fn encode_gm_args() {
         GMArgs::GetCaller,
         GMArgs::GetVerifyingPredicate,
         GMArgs::GetChainId,
+        GMArgs::GetRemainingCallDepth,
     ];
 
     args.into_iter().for_each(|a| {
@@ -340,6 +351,7 @@ fn encode_gtf_args() {
         GTFArgs::PolicyWitnessLimit,
         GTFArgs::PolicyMaturity,
         GTFArgs::PolicyMaxFee,
+        GTFArgs::PolicyGasPriceFloor,
     ];
 
     args.into_iter().for_each(|a| {
@@ -0,0 +1,463 @@
+//! A text assembler for Fuel bytecode, the inverse of
+//! [`disassemble`](crate::disassemble).
+
+use alloc::{
+    collections::BTreeMap,
+    string::{
+        String,
+        ToString,
+    },
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    disassemble::register_name,
+    Instruction,
+    Opcode,
+    Operand,
+    RegId,
+};
+
+/// The reason an [`AssembleError`] was raised.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AssembleErrorKind {
+    /// No instruction has the mnemonic.
+    UnknownMnemonic(String),
+    /// The instruction has the wrong number of operands.
+    OperandCount {
+        /// The number of operands of the instruction.
+        expected: usize,
+        /// The number of operands given.
+        found: usize,
+    },
+    /// The operand isn't a register.
+    InvalidRegister(String),
+    /// The operand isn't an immediate, or a label of a jump.
+    InvalidImmediate(String),
+    /// The immediate doesn't fit the width of the operand.
+    ImmediateOutOfRange {
+        /// The immediate.
+        value: u64,
+        /// The width of the operand, in bits.
+        bits: u32,
+    },
+    /// The label isn't an identifier.
+    InvalidLabel(String),
+    /// The label is defined more than once.
+    DuplicateLabel(String),
+    /// The label isn't defined.
+    UnknownLabel(String),
+    /// The label is in the other direction of the jump, or too far for its immediate.
+    UnreachableLabel(String),
+}
+
+impl fmt::Display for AssembleErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownMnemonic(mnemonic) => write!(f, "unknown mnemonic `{mnemonic}`"),
+            Self::OperandCount { expected, found } => {
+                write!(f, "expected {expected} operands, found {found}")
+            }
+            Self::InvalidRegister(operand) => write!(f, "invalid register `{operand}`"),
+            Self::InvalidImmediate(operand) => write!(f, "invalid immediate `{operand}`"),
+            Self::ImmediateOutOfRange { value, bits } => {
+                write!(f, "immediate `{value}` out of range for {bits} bits")
+            }
+            Self::InvalidLabel(label) => write!(f, "invalid label `{label}`"),
+            Self::DuplicateLabel(label) => write!(f, "duplicate label `{label}`"),
+            Self::UnknownLabel(label) => write!(f, "unknown label `{label}`"),
+            Self::UnreachableLabel(label) => write!(f, "unreachable label `{label}`"),
+        }
+    }
+}
+
+/// Failed to assemble a program, at the 1-based `line` and `column`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssembleError {
+    /// The line of the error.
+    pub line: usize,
+    /// The column of the error, in bytes.
+    pub column: usize,
+    /// The reason of the error.
+    pub kind: AssembleErrorKind,
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AssembleError {}
+
+/// A word of a line, with its 1-based position.
+#[derive(Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, kind: AssembleErrorKind) -> AssembleError {
+        AssembleError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+}
+
+/// Splits `line` into its tokens, dropping the comment.
+fn tokenize(line: &str, line_number: usize) -> Vec<Token<'_>> {
+    let code = line.split("//").next().unwrap_or_default();
+    let code = code.split(';').next().unwrap_or_default();
+
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in code.char_indices().chain([(code.len(), ' ')]) {
+        let separator = c.is_whitespace() || c == ',';
+        match start {
+            Some(begin) if separator => {
+                tokens.push(Token {
+                    text: code.get(begin..i).expect("The indices are char boundaries"),
+                    line: line_number,
+                    column: begin + 1,
+                });
+                start = None;
+            }
+            None if !separator => start = Some(i),
+            _ => {}
+        }
+    }
+    tokens
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_number(text: &str) -> Option<u64> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None if text.bytes().all(|b| b.is_ascii_digit()) => text.parse().ok(),
+        None => None,
+    }
+}
+
+fn parse_register(token: &Token) -> Result<RegId, AssembleError> {
+    let invalid =
+        || token.error(AssembleErrorKind::InvalidRegister(token.text.to_string()));
+    let name = token.text.strip_prefix('$').ok_or_else(invalid)?;
+
+    let named = (0..RegId::WRITABLE.to_u8())
+        .map(RegId::new)
+        .find(|reg| register_name(*reg) == Some(name));
+    if let Some(reg) = named {
+        return Ok(reg)
+    }
+
+    let number = name.strip_prefix('r').unwrap_or(name);
+    parse_number(number)
+        .and_then(|number| u8::try_from(number).ok())
+        .and_then(RegId::new_checked)
+        .ok_or_else(invalid)
+}
+
+/// How a jump uses its immediate.
+enum Jump {
+    Forwards,
+    Backwards,
+    Absolute,
+}
+
+fn jump(opcode: Opcode) -> Option<Jump> {
+    match opcode {
+        Opcode::JMPF | Opcode::JNZF | Opcode::JNEF => Some(Jump::Forwards),
+        Opcode::JMPB | Opcode::JNZB | Opcode::JNEB => Some(Jump::Backwards),
+        Opcode::JI | Opcode::JNZI | Opcode::JNEI => Some(Jump::Absolute),
+        _ => None,
+    }
+}
+
+/// Parses the immediate of the instruction at `index`, of `bits` width.
+fn parse_immediate(
+    token: &Token,
+    bits: u32,
+    opcode: Opcode,
+    index: usize,
+    labels: &BTreeMap<&str, usize>,
+) -> Result<u32, AssembleError> {
+    let fits = |value: u64| u32::try_from(value).ok().filter(|v| *v < 1 << bits);
+
+    if let Some(value) = parse_number(token.text) {
+        return fits(value).ok_or_else(|| {
+            token.error(AssembleErrorKind::ImmediateOutOfRange { value, bits })
+        })
+    }
+
+    let label = token.text;
+    let Some(jump) = jump(opcode).filter(|_| is_identifier(label)) else {
+        return Err(token.error(AssembleErrorKind::InvalidImmediate(label.to_string())))
+    };
+    let target = *labels
+        .get(label)
+        .ok_or_else(|| token.error(AssembleErrorKind::UnknownLabel(label.to_string())))?;
+    // The relative jumps skip the jump instruction itself
+    let value = match jump {
+        Jump::Forwards => target.checked_sub(index + 1),
+        Jump::Backwards => index.checked_sub(target + 1),
+        Jump::Absolute => Some(target),
+    };
+    value.and_then(|value| fits(value as u64)).ok_or_else(|| {
+        token.error(AssembleErrorKind::UnreachableLabel(label.to_string()))
+    })
+}
+
+/// An instruction to assemble, its mnemonic and operands.
+struct Statement<'a> {
+    mnemonic: Token<'a>,
+    operands: Vec<Token<'a>>,
+}
+
+impl Statement<'_> {
+    fn assemble(
+        &self,
+        index: usize,
+        labels: &BTreeMap<&str, usize>,
+    ) -> Result<Instruction, AssembleError> {
+        let mnemonic = self.mnemonic.text.to_ascii_lowercase();
+        let opcode = Opcode::from_mnemonic(&mnemonic).ok_or_else(|| {
+            self.mnemonic
+                .error(AssembleErrorKind::UnknownMnemonic(mnemonic.clone()))
+        })?;
+
+        // The layout of the operands, the registers first and then the immediate
+        let layout = Instruction::try_from([opcode as u8, 0, 0, 0])
+            .expect("The opcode is valid")
+            .operands()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if layout.len() != self.operands.len() {
+            return Err(self.mnemonic.error(AssembleErrorKind::OperandCount {
+                expected: layout.len(),
+                found: self.operands.len(),
+            }))
+        }
+
+        let mut word = (opcode as u32) << 24;
+        for ((operand, token), i) in layout.iter().zip(&self.operands).zip(0u32..) {
+            match operand {
+                Operand::Register(_) => {
+                    let reg = parse_register(token)?;
+                    word |= u32::from(reg.to_u8()) << (18 - 6 * i);
+                }
+                Operand::Immediate(_) => {
+                    let bits = 24 - 6 * i;
+                    word |= parse_immediate(token, bits, opcode, index, labels)?;
+                }
+            }
+        }
+
+        Ok(Instruction::try_from(word).expect("The opcode is valid"))
+    }
+}
+
+/// Assembles the program `src`.
+///
+/// A program has one instruction per line, a mnemonic followed by its operands,
+/// separated by whitespace or commas:
+///
+/// - Registers are named, like `$zero` or `$hp`, or numbered, like `$r16`, `$16` or
+///   `$0x10`.
+/// - Immediates are decimal or hexadecimal, like `42` or `0x2a`, and must fit the width
+///   of the operand.
+/// - A label, like `loop:`, names the instruction that follows it. The immediate of a
+///   jump can be a label: the relative jumps get the offset to it, and the absolute jumps
+///   its index.
+/// - Comments start with `//` or `;` and run to the end of the line.
+pub fn assemble(src: &str) -> Result<Vec<Instruction>, AssembleError> {
+    let mut labels = BTreeMap::new();
+    let mut statements = Vec::new();
+    for (i, line) in src.lines().enumerate() {
+        let tokens = tokenize(line, i + 1);
+        let mut tokens = tokens.as_slice();
+        while let Some((token, rest)) = tokens.split_first() {
+            let Some(label) = token.text.strip_suffix(':') else {
+                break
+            };
+            if !is_identifier(label) {
+                return Err(token.error(AssembleErrorKind::InvalidLabel(label.to_string())))
+            }
+            if labels.insert(label, statements.len()).is_some() {
+                return Err(
+                    token.error(AssembleErrorKind::DuplicateLabel(label.to_string()))
+                )
+            }
+            tokens = rest;
+        }
+        if let Some((mnemonic, operands)) = tokens.split_first() {
+            statements.push(Statement {
+                mnemonic: *mnemonic,
+                operands: operands.to_vec(),
+            });
+        }
+    }
+
+    statements
+        .iter()
+        .enumerate()
+        .map(|(index, statement)| statement.assemble(index, &labels))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        disassemble,
+        op,
+    };
+    use alloc::vec;
+
+    const PROGRAM: &str = "
+        // Counts down from 3, logging each step
+        movi $0x10 3
+        loop:
+        log $r16, $zero, $zero, $zero
+        subi $16 $0x10 1   ; decrement
+        jnzb $r16 $zero loop
+        jnzf $r16 $zero end
+        jmpf $zero end
+        noop
+        end: wdcm $r16 $r17 $r18 0x2a
+        ji 0
+        ret $one
+    ";
+
+    #[test]
+    fn assemble__parses_registers_immediates_and_labels() {
+        let program = assemble(PROGRAM).expect("A valid program");
+
+        assert_eq!(
+            program,
+            vec![
+                op::movi(0x10, 3),
+                op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+                op::subi(0x10, 0x10, 1),
+                op::jnzb(0x10, RegId::ZERO, 1),
+                op::jnzf(0x10, RegId::ZERO, 2),
+                op::jmpf(RegId::ZERO, 1),
+                op::noop(),
+                op::wdcm(0x10, 0x11, 0x12, 0x2a),
+                op::ji(0),
+                op::ret(RegId::ONE),
+            ]
+        );
+    }
+
+    #[test]
+    fn assemble__round_trips_through_disassembly() {
+        // Given
+        let bytes: Vec<u8> = assemble(PROGRAM).unwrap().into_iter().collect();
+
+        // When
+        let text = disassemble(&bytes)
+            .map(|line| line.expect("A valid instruction").instruction.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let reassembled: Vec<u8> = assemble(&text).unwrap().into_iter().collect();
+
+        // Then
+        assert_eq!(reassembled, bytes);
+    }
+
+    #[test]
+    fn assemble__reports_the_position_of_errors() {
+        let cases = [
+            (
+                "noop\n  nope",
+                2,
+                3,
+                AssembleErrorKind::UnknownMnemonic("nope".into()),
+            ),
+            (
+                "ret",
+                1,
+                1,
+                AssembleErrorKind::OperandCount {
+                    expected: 1,
+                    found: 0,
+                },
+            ),
+            (
+                "ret $r64",
+                1,
+                5,
+                AssembleErrorKind::InvalidRegister("$r64".into()),
+            ),
+            (
+                "ret 1",
+                1,
+                5,
+                AssembleErrorKind::InvalidRegister("1".into()),
+            ),
+            (
+                "addi $r16 $zero 4096",
+                1,
+                17,
+                AssembleErrorKind::ImmediateOutOfRange {
+                    value: 4096,
+                    bits: 12,
+                },
+            ),
+            (
+                "addi $r16 $zero end",
+                1,
+                17,
+                AssembleErrorKind::InvalidImmediate("end".into()),
+            ),
+            (
+                "jmpf $zero end",
+                1,
+                12,
+                AssembleErrorKind::UnknownLabel("end".into()),
+            ),
+            (
+                "a: noop\na: noop",
+                2,
+                1,
+                AssembleErrorKind::DuplicateLabel("a".into()),
+            ),
+            (
+                "1a: noop",
+                1,
+                1,
+                AssembleErrorKind::InvalidLabel("1a".into()),
+            ),
+            (
+                "start: jmpf $zero start",
+                1,
+                19,
+                AssembleErrorKind::UnreachableLabel("start".into()),
+            ),
+        ];
+
+        for (src, line, column, kind) in cases {
+            assert_eq!(
+                assemble(src),
+                Err(AssembleError { line, column, kind }),
+                "{src}"
+            );
+        }
+    }
+}
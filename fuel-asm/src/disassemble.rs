@@ -0,0 +1,231 @@
+//! A text disassembler for Fuel bytecode.
+
+use core::fmt;
+
+use crate::{
+    from_bytes_iter,
+    raw_instruction_at,
+    DecodeError,
+    Instruction,
+    RawInstruction,
+    RegId,
+};
+
+/// A decoded operand of an instruction.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Operand {
+    /// A register, displayed by its name, e.g. `$hp` or `$r16`.
+    Register(RegId),
+    /// An immediate value, displayed in decimal and hexadecimal, e.g. `42 (0x2a)`.
+    Immediate(u32),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Register(reg) => match register_name(reg) {
+                Some(name) => write!(f, "${name}"),
+                None => write!(f, "$r{}", reg.to_u8()),
+            },
+            Self::Immediate(imm) => write!(f, "{imm} ({imm:#x})"),
+        }
+    }
+}
+
+/// The name of a reserved register.
+pub(crate) fn register_name(reg: RegId) -> Option<&'static str> {
+    let name = match reg {
+        RegId::ZERO => "zero",
+        RegId::ONE => "one",
+        RegId::OF => "of",
+        RegId::PC => "pc",
+        RegId::SSP => "ssp",
+        RegId::SP => "sp",
+        RegId::FP => "fp",
+        RegId::HP => "hp",
+        RegId::ERR => "err",
+        RegId::GGAS => "ggas",
+        RegId::CGAS => "cgas",
+        RegId::BAL => "bal",
+        RegId::IS => "is",
+        RegId::RET => "ret",
+        RegId::RETL => "retl",
+        RegId::FLAG => "flag",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Displays the instruction in the syntax of [`assemble`](crate::assemble), e.g.
+/// `addi $r16, $zero, 42`.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        for (i, operand) in self.operands().into_iter().flatten().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            match operand {
+                Operand::Register(_) => write!(f, "{separator}{operand}")?,
+                Operand::Immediate(imm) => write!(f, "{separator}{imm}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A word of bytecode, disassembled by [`disassemble`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DisassembledLine {
+    /// The offset of the word in the bytecode, in bytes.
+    pub offset: usize,
+    /// The raw word.
+    pub raw: RawInstruction,
+    /// The decoded instruction.
+    pub instruction: Instruction,
+}
+
+impl DisassembledLine {
+    /// The assembly mnemonic of the instruction, e.g. `addi`.
+    pub fn mnemonic(&self) -> &'static str {
+        self.instruction.mnemonic()
+    }
+
+    /// The decoded operands of the instruction, in order.
+    pub fn operands(&self) -> impl Iterator<Item = Operand> {
+        self.instruction.operands().into_iter().flatten()
+    }
+}
+
+impl fmt::Display for DisassembledLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        for (i, operand) in self.operands().enumerate() {
+            let separator = if i == 0 { " " } else { ", " };
+            write!(f, "{separator}{operand}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Disassembles `bytes`, one line per word of 4 bytes, decoding them with
+/// [`from_bytes_iter`](crate::from_bytes_iter).
+///
+/// Yields an `Err` for a word with an unknown opcode, and for trailing bytes that
+/// don't form a full word, then carries on with the next word.
+pub fn disassemble(
+    bytes: &[u8],
+) -> impl Iterator<Item = Result<DisassembledLine, DecodeError>> + '_ {
+    from_bytes_iter(bytes)
+        .zip((0..).step_by(Instruction::SIZE))
+        .map(|(instruction, offset)| {
+            Ok(DisassembledLine {
+                instruction: instruction?,
+                offset,
+                raw: raw_instruction_at(bytes, offset)?,
+            })
+        })
+}
+
+/// Renders `bytes` as a program listing, one word per line, with aligned columns
+/// of the offset, the raw word and the instruction.
+///
+/// Unknown opcodes are rendered as `.word 0x…`, and trailing bytes that don't
+/// form a full word as `.bytes 0x…`.
+#[cfg(feature = "alloc")]
+pub fn fmt_program(bytes: &[u8]) -> alloc::string::String {
+    use alloc::string::String;
+    use core::fmt::Write;
+
+    const WRITE: &str = "Writing to a string can't fail";
+
+    let mut program = String::new();
+    for line in disassemble(bytes) {
+        let (offset, raw, text) = match line {
+            Ok(line) => {
+                let mut text = alloc::format!("{:<4}", line.mnemonic());
+                for (i, operand) in line.operands().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(text, "{separator}{operand}").expect(WRITE);
+                }
+                (line.offset, alloc::format!("{:08x}", line.raw), text)
+            }
+            Err(DecodeError::InvalidOpcode { offset, raw }) => {
+                let raw = alloc::format!("{raw:08x}");
+                let text = alloc::format!(".word 0x{raw}");
+                (offset, raw, text)
+            }
+            Err(DecodeError::TrailingBytes { offset, .. }) => {
+                let mut raw = String::new();
+                for byte in bytes.get(offset..).unwrap_or_default() {
+                    write!(raw, "{byte:02x}").expect(WRITE);
+                }
+                let text = alloc::format!(".bytes 0x{raw}");
+                (offset, raw, text)
+            }
+        };
+        let line = alloc::format!("{offset:#06x}  {raw:<8}  {text}");
+        writeln!(program, "{}", line.trim_end()).expect(WRITE);
+    }
+    program
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::op;
+    use alloc::vec::Vec;
+
+    /// A program with every operand layout, an unknown opcode and trailing bytes.
+    fn program() -> Vec<u8> {
+        let mut bytes: Vec<u8> = [
+            op::mcp(0x10, RegId::HP, 0x11),
+            op::bldd(0x10, 0x11, 0x12, 0x13),
+            op::wdcm(0x10, 0x11, 0x12, 0x2a),
+            op::addi(0x10, RegId::ZERO, 42),
+            op::movi(0x3f, 0x3ffff),
+            op::ji(1024),
+            op::ret(RegId::ONE),
+            op::noop(),
+        ]
+        .into_iter()
+        .collect();
+        bytes.extend([0x00, 0x00, 0x00, 0x00, 0xba, 0xad]);
+        bytes
+    }
+
+    #[test]
+    fn disassemble__decodes_the_words() {
+        let lines = disassemble(&program()).collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 10);
+        let addi = lines[3].as_ref().expect("A valid instruction");
+        assert_eq!(addi.offset, 12);
+        assert_eq!(addi.raw, u32::from(op::addi(0x10, RegId::ZERO, 42)));
+        assert_eq!(addi.mnemonic(), "addi");
+        assert_eq!(
+            addi.operands().collect::<Vec<_>>(),
+            [
+                Operand::Register(RegId::new(0x10)),
+                Operand::Register(RegId::ZERO),
+                Operand::Immediate(42),
+            ]
+        );
+        assert_eq!(
+            lines[8],
+            Err(DecodeError::InvalidOpcode {
+                offset: 32,
+                raw: 0x00000000,
+            })
+        );
+        assert_eq!(
+            lines[9],
+            Err(DecodeError::TrailingBytes { offset: 36, len: 2 })
+        );
+    }
+
+    #[test]
+    fn fmt_program__snapshot() {
+        insta::assert_snapshot!(fmt_program(&program()));
+    }
+}
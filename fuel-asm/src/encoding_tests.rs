@@ -1,3 +1,4 @@
+#![allow(non_snake_case)]
 #![allow(clippy::iter_cloned_collect)] // https://github.com/rust-lang/rust-clippy/issues/9119
 
 use crate::*;
@@ -82,3 +83,248 @@ fn panic_reason_description() {
         }
     }
 }
+
+#[test]
+fn blob_instructions__round_trip() {
+    let instructions = [
+        op::bsiz(0x10, 0x11),
+        op::bldd(0x10, 0x11, 0x12, 0x13),
+        op::bldd(RegId::HP, RegId::ZERO, RegId::ONE, 0x3f),
+    ];
+
+    for instruction in instructions {
+        let raw = RawInstruction::from_be_bytes(instruction.to_bytes());
+        assert_eq!(Instruction::try_from(raw), Ok(instruction));
+    }
+    assert_eq!(op::bsiz(0x10, 0x11).to_bytes(), [0xba, 0x41, 0x10, 0x00]);
+    assert_eq!(Opcode::try_from(0xba), Ok(Opcode::BSIZ));
+    assert_eq!(Opcode::try_from(0xbb), Ok(Opcode::BLDD));
+    assert_eq!(
+        format!("{:?}", op::bldd(0x10, 0x11, 0x12, 0x13)),
+        "BLDD { dst_ptr: 0x10, blob_id_ptr: 0x11, offset: 0x12, len: 0x13 }"
+    );
+}
+
+#[test]
+fn elliptic_curve_instructions__round_trip() {
+    let instructions = [
+        op::ecop(0x10, 0x11, 0x12, 0x13),
+        op::epar(0x10, 0x11, 0x12, 0x13),
+        op::ecop(RegId::HP, RegId::ZERO, RegId::ONE, 0x3f),
+    ];
+
+    for instruction in instructions {
+        let raw = RawInstruction::from_be_bytes(instruction.to_bytes());
+        assert_eq!(Instruction::try_from(raw), Ok(instruction));
+    }
+    assert_eq!(Opcode::try_from(0xbe), Ok(Opcode::ECOP));
+    assert_eq!(Opcode::try_from(0xbf), Ok(Opcode::EPAR));
+    assert_eq!(
+        format!("{:?}", op::epar(0x10, 0x11, 0x12, 0x13)),
+        "EPAR { success: 0x10, curve_id: 0x11, pairs: 0x12, points_addr: 0x13 }"
+    );
+}
+
+#[test]
+fn from_bytes_iter__locates_invalid_words_and_trailing_bytes() {
+    let bytes: Vec<u8> = [op::noop(), op::ret(RegId::ONE)]
+        .into_iter()
+        .flat_map(Instruction::to_bytes)
+        .chain([0x00, 0x00, 0x00, 0x01])
+        .chain([0x47, 0x00])
+        .collect();
+
+    let decoded = from_bytes_iter(&bytes).collect::<Vec<_>>();
+
+    assert_eq!(
+        decoded,
+        [
+            Ok(op::noop()),
+            Ok(op::ret(RegId::ONE)),
+            Err(DecodeError::InvalidOpcode {
+                offset: 8,
+                raw: 0x00000001
+            }),
+            Err(DecodeError::TrailingBytes { offset: 12, len: 2 }),
+        ]
+    );
+}
+
+#[quickcheck_macros::quickcheck]
+fn from_bytes_iter__never_panics(bytes: Vec<u8>) -> bool {
+    let decoded = from_bytes_iter(&bytes).collect::<Vec<_>>();
+    decoded.len() == bytes.len().div_ceil(Instruction::SIZE)
+        && decoded
+            .iter()
+            .zip(from_bytes(bytes.iter().copied()))
+            .all(|(lhs, rhs)| lhs.map_err(InvalidOpcode::from) == rhs)
+}
+
+#[test]
+fn try_constructors__accept_the_max_immediate_and_reject_above() {
+    assert_eq!(
+        op::try_wdcm(0x10, 0x11, 0x12, 63),
+        Ok(op::wdcm(0x10, 0x11, 0x12, 63))
+    );
+    assert_eq!(
+        op::try_wdcm(0x10, 0x11, 0x12, 64),
+        Err(ImmediateOutOfRange { bits: 6, value: 64 })
+    );
+
+    assert_eq!(
+        op::try_addi(0x10, 0x11, 4095),
+        Ok(op::addi(0x10, 0x11, 4095))
+    );
+    assert_eq!(
+        op::try_addi(0x10, 0x11, 4096),
+        Err(ImmediateOutOfRange {
+            bits: 12,
+            value: 4096
+        })
+    );
+
+    assert_eq!(op::try_movi(0x10, 262143), Ok(op::movi(0x10, 262143)));
+    assert_eq!(
+        op::try_movi(0x10, 262144),
+        Err(ImmediateOutOfRange {
+            bits: 18,
+            value: 262144
+        })
+    );
+
+    assert_eq!(op::try_ji(16777215), Ok(op::ji(16777215)));
+    assert_eq!(
+        op::try_ji(16777216),
+        Err(ImmediateOutOfRange {
+            bits: 24,
+            value: 16777216
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Value `4096` out of range for 12-bit immediate")]
+fn constructors__panic_on_an_out_of_range_immediate() {
+    op::addi(0x10, 0x11, 4096);
+}
+
+#[test]
+#[should_panic(expected = "CheckRegId was given invalid RegId")]
+fn try_constructors__panic_on_an_out_of_range_register() {
+    let _ = op::try_addi(0x40, 0x11, 42);
+}
+
+#[test]
+fn immediate_out_of_range__is_an_error() {
+    let error: Box<dyn std::error::Error> =
+        Box::new(op::try_addi(0x10, 0x11, 4096).unwrap_err());
+    assert_eq!(
+        error.to_string(),
+        "Value `4096` out of range for 12-bit immediate"
+    );
+}
+
+/// An instruction of every opcode.
+fn every_instruction() -> Vec<Instruction> {
+    (0..=u8::MAX)
+        .filter_map(|byte| Opcode::try_from(byte).ok())
+        .map(|opcode| {
+            opcode.test_construct(
+                RegId::new(0x10),
+                RegId::HP,
+                RegId::new(0x3f),
+                RegId::ONE,
+                0x2a,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn serde__human_readable_uses_the_assembly_syntax() {
+    assert_eq!(
+        serde_json::to_string(&op::addi(0x10, RegId::ZERO, 42)).unwrap(),
+        r#""addi $r16, $zero, 42""#
+    );
+    assert_eq!(serde_json::to_string(&Opcode::MOVE).unwrap(), r#""move""#);
+
+    for instruction in every_instruction() {
+        let json = serde_json::to_string(&instruction).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Instruction>(&json).unwrap(),
+            instruction
+        );
+
+        let json = serde_json::to_string(&instruction.opcode()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Opcode>(&json).unwrap(),
+            instruction.opcode()
+        );
+    }
+}
+
+#[test]
+fn serde__binary_uses_the_bytecode_encoding() {
+    for instruction in every_instruction() {
+        let bytes = postcard::to_stdvec(&instruction).unwrap();
+        assert_eq!(bytes, instruction.to_bytes());
+        assert_eq!(
+            postcard::from_bytes::<Instruction>(&bytes).unwrap(),
+            instruction
+        );
+
+        let bytes = postcard::to_stdvec(&instruction.opcode()).unwrap();
+        assert_eq!(bytes, [instruction.opcode() as u8]);
+        assert_eq!(
+            postcard::from_bytes::<Opcode>(&bytes).unwrap(),
+            instruction.opcode()
+        );
+    }
+}
+
+#[test]
+fn serde__rejects_unknown_mnemonics_and_reserved_opcodes() {
+    let error = serde_json::from_str::<Instruction>(r#""nope $zero""#).unwrap_err();
+    assert_eq!(error.to_string(), "1:1: unknown mnemonic `nope`");
+    let error = serde_json::from_str::<Instruction>(r#""noop\nnoop""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "expected a single instruction, found `noop\nnoop`"
+    );
+    let error = serde_json::from_str::<Opcode>(r#""nope""#).unwrap_err();
+    assert_eq!(error.to_string(), "unknown mnemonic `nope`");
+
+    let error = postcard::from_bytes::<Instruction>(&[0x00, 0, 0, 0]).unwrap_err();
+    assert!(matches!(error, postcard::Error::SerdeDeCustom));
+    let error = postcard::from_bytes::<Opcode>(&[0x00]).unwrap_err();
+    assert!(matches!(error, postcard::Error::SerdeDeCustom));
+    let error = bincode::deserialize::<Instruction>(&[0x00, 0, 0, 0]).unwrap_err();
+    assert_eq!(error.to_string(), "invalid opcode `0x00`");
+}
+
+#[test]
+fn serde__human_readable_accepts_the_derived_form() {
+    assert_eq!(
+        serde_json::from_str::<Instruction>(r#"{"ADDI":[64,0,42]}"#).unwrap(),
+        op::addi(0x10, RegId::ZERO, 42)
+    );
+    assert_eq!(
+        serde_json::from_str::<Opcode>(r#""MOVE""#).unwrap(),
+        Opcode::MOVE
+    );
+
+    for instruction in every_instruction() {
+        let opcode = instruction.opcode();
+        let [_, a, b, c] = instruction.to_bytes();
+        let json = serde_json::json!({ format!("{opcode:?}"): [a, b, c] });
+        assert_eq!(
+            serde_json::from_value::<Instruction>(json).unwrap(),
+            instruction
+        );
+        let json = serde_json::json!(format!("{opcode:?}"));
+        assert_eq!(serde_json::from_value::<Opcode>(json).unwrap(), opcode);
+    }
+
+    let error = serde_json::from_str::<Instruction>(r#"{"NOPE":[0,0,0]}"#).unwrap_err();
+    assert_eq!(error.to_string(), "unknown instruction `NOPE`");
+}
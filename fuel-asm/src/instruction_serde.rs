@@ -0,0 +1,150 @@
+//! Serde support for [`Instruction`] and [`Opcode`].
+//!
+//! The human-readable formats use the syntax of [`assemble`], e.g. `"addi $r16, $zero,
+//! 42"` and `"addi"`. The binary formats use the bytecode encoding, the 4 bytes of the
+//! instruction and the byte of the opcode.
+//!
+//! The human-readable formats also accept the form derived before, the name of the
+//! variant of an opcode, e.g. `"ADDI"`, and the variant of an instruction with its 3
+//! bytes of arguments, e.g. `{"ADDI": [64, 0, 42]}`. The binary formats don't, it
+//! encoded the index of the variant instead of the opcode.
+
+use alloc::string::String;
+use core::fmt;
+
+use serde::de::{
+    Error,
+    MapAccess,
+    Visitor,
+};
+
+use crate::{
+    assemble,
+    Instruction,
+    Opcode,
+};
+
+impl serde::Serialize for Instruction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.to_bytes().serialize(serializer)
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Instruction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            match deserializer.deserialize_any(HumanReadableVisitor)? {
+                HumanReadable::Assembly(s) => {
+                    match assemble(&s).map_err(D::Error::custom)?.as_slice() {
+                        [instruction] => Ok(*instruction),
+                        _ => Err(D::Error::custom(alloc::format!(
+                            "expected a single instruction, found `{s}`"
+                        ))),
+                    }
+                }
+                HumanReadable::Legacy(name, args) => {
+                    let opcode = legacy_opcode(&name).ok_or_else(|| {
+                        D::Error::custom(alloc::format!("unknown instruction `{name}`"))
+                    })?;
+                    let [a, b, c] = args;
+                    Instruction::try_from([opcode as u8, a, b, c])
+                        .map_err(|_| D::Error::custom("invalid instruction"))
+                }
+            }
+        } else {
+            let bytes = <[u8; 4]>::deserialize(deserializer)?;
+            Instruction::try_from(bytes).map_err(|_| {
+                D::Error::custom(alloc::format!("invalid opcode `{:#04x}`", bytes[0]))
+            })
+        }
+    }
+}
+
+impl serde::Serialize for Opcode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(self.mnemonic())
+        } else {
+            serializer.serialize_u8(*self as u8)
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Opcode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Opcode::from_mnemonic(&s)
+                .or_else(|| legacy_opcode(&s))
+                .ok_or_else(|| D::Error::custom(alloc::format!("unknown mnemonic `{s}`")))
+        } else {
+            let byte = u8::deserialize(deserializer)?;
+            Opcode::try_from(byte).map_err(|_| {
+                D::Error::custom(alloc::format!("invalid opcode `{byte:#04x}`"))
+            })
+        }
+    }
+}
+
+/// The opcode with the `name` of its variant, the derived human-readable form, e.g.
+/// `"ADDI"`.
+fn legacy_opcode(name: &str) -> Option<Opcode> {
+    if name.bytes().any(|b| b.is_ascii_lowercase()) {
+        return None
+    }
+    Opcode::from_mnemonic(&name.to_ascii_lowercase())
+}
+
+/// The human-readable forms of an [`Instruction`], assembled after the deserialization.
+enum HumanReadable {
+    /// The assembly syntax.
+    Assembly(String),
+    /// The derived form, the name of the variant and the 3 bytes of arguments.
+    Legacy(String, [u8; 3]),
+}
+
+struct HumanReadableVisitor;
+
+impl<'de> Visitor<'de> for HumanReadableVisitor {
+    type Value = HumanReadable;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an instruction in the assembly syntax")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(HumanReadable::Assembly(v.into()))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let (name, args) = map
+            .next_entry()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        if map.next_key::<String>()?.is_some() {
+            return Err(A::Error::invalid_length(2, &self))
+        }
+        Ok(HumanReadable::Legacy(name, args))
+    }
+}
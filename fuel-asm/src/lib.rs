@@ -13,6 +13,11 @@
 extern crate alloc;
 
 mod args;
+#[cfg(feature = "alloc")]
+mod assemble;
+mod disassemble;
+#[cfg(feature = "serde")]
+mod instruction_serde;
 mod panic_instruction;
 // This is `pub` to make documentation for the private `impl_instructions!` macro more
 // accessible.
@@ -39,8 +44,24 @@ pub type RegisterId = usize;
 /// Register value type
 pub type Word = u64;
 
+#[cfg(feature = "alloc")]
+pub use assemble::{
+    assemble,
+    AssembleError,
+    AssembleErrorKind,
+};
+#[cfg(feature = "alloc")]
+pub use disassemble::fmt_program;
+pub use disassemble::{
+    disassemble,
+    DisassembledLine,
+    Operand,
+};
 pub use panic_instruction::PanicInstruction;
-pub use panic_reason::PanicReason;
+pub use panic_reason::{
+    InvalidPanicReason,
+    PanicReason,
+};
 
 /// Represents a 6-bit register ID, guaranteed to be masked by construction.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -74,6 +95,28 @@ pub type RawInstruction = u32;
 #[derive(Debug, Eq, PartialEq)]
 pub struct InvalidOpcode;
 
+/// An immediate value doesn't fit the width of its field.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ImmediateOutOfRange {
+    /// The width of the field, in bits.
+    pub bits: u32,
+    /// The supplied value.
+    pub value: u32,
+}
+
+impl core::fmt::Display for ImmediateOutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Value `{}` out of range for {}-bit immediate",
+            self.value, self.bits
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ImmediateOutOfRange {}
+
 bitflags::bitflags! {
     /// Possible values for the FLAG instruction.
     /// See https://github.com/FuelLabs/fuel-specs/blob/master/src/fuel-vm/index.md#flags
@@ -334,6 +377,16 @@ impl_instructions! {
 
     "Call external function"
     0xb0 ECAL ecal [a: RegId b: RegId c: RegId d: RegId]
+
+    "Get the size of a blob."
+    0xba BSIZ bsiz [dst: RegId blob_id_ptr: RegId]
+    "Copy a range of the bytes of a blob to memory."
+    0xbb BLDD bldd [dst_ptr: RegId blob_id_ptr: RegId offset: RegId len: RegId]
+
+    "Add or multiply points of an elliptic curve."
+    0xbe ECOP ecop [dst_addr: RegId curve_id: RegId operation: RegId points_addr: RegId]
+    "Check a pairing equation over an elliptic curve."
+    0xbf EPAR epar [success: RegId curve_id: RegId pairs: RegId points_addr: RegId]
 }
 
 impl Instruction {
@@ -690,7 +743,7 @@ impl Opcode {
             | K256 | S256 | NOOP | FLAG | ADDI | ANDI | DIVI | EXPI | MODI | MULI
             | MLDV | ORI | SLLI | SRLI | SUBI | XORI | JNEI | LB | LW | SB | SW
             | MCPI | MCLI | GM | MOVI | JNZI | JI | JMP | JNE | JMPF | JMPB | JNZF
-            | JNZB | JNEF | JNEB | CFEI | CFSI | CFE | CFS | GTF => true,
+            | JNZB | JNEF | JNEB | CFEI | CFSI | CFE | CFS | GTF | ECOP | EPAR => true,
             _ => false,
         }
     }
@@ -923,27 +976,104 @@ where
     us.into_iter().map(Instruction::try_from)
 }
 
+/// Failed to decode an instruction from a byte slice.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DecodeError {
+    /// The word has a reserved or unknown opcode.
+    InvalidOpcode {
+        /// The offset of the word in the slice, in bytes.
+        offset: usize,
+        /// The word.
+        raw: RawInstruction,
+    },
+    /// The slice ends with a fragment shorter than an instruction.
+    TrailingBytes {
+        /// The offset of the fragment in the slice, in bytes.
+        offset: usize,
+        /// The length of the fragment, in bytes.
+        len: usize,
+    },
+}
+
+impl From<DecodeError> for InvalidOpcode {
+    fn from(_: DecodeError) -> Self {
+        InvalidOpcode
+    }
+}
+
+/// Reads the raw instruction at `offset` of `bytes`.
+///
+/// Returns [`DecodeError::TrailingBytes`] if fewer than [`Instruction::SIZE`] bytes are
+/// left at `offset`.
+pub fn raw_instruction_at(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<RawInstruction, DecodeError> {
+    let word = offset
+        .checked_add(Instruction::SIZE)
+        .and_then(|end| bytes.get(offset..end))
+        .and_then(|word| <[u8; 4]>::try_from(word).ok())
+        .ok_or(DecodeError::TrailingBytes {
+            offset,
+            len: bytes.len().saturating_sub(offset),
+        })?;
+    Ok(RawInstruction::from_be_bytes(word))
+}
+
+/// Given a byte slice, produces an iterator yielding its `Instruction`s.
+///
+/// Unlike [`from_bytes`], the produced iterator never silently drops a trailing fragment
+/// shorter than an instruction, and locates the words it fails to decode.
+pub fn from_bytes_iter(
+    bytes: &[u8],
+) -> impl Iterator<Item = Result<Instruction, DecodeError>> + '_ {
+    (0..bytes.len()).step_by(Instruction::SIZE).map(|offset| {
+        let raw = raw_instruction_at(bytes, offset)?;
+        Instruction::try_from(raw).map_err(|_| DecodeError::InvalidOpcode { offset, raw })
+    })
+}
+
 // Short-hand, `panic!`ing constructors for the short-hand instruction construtors (e.g
 // op::add).
 
 fn check_imm06(u: u8) -> Imm06 {
-    Imm06::new_checked(u)
-        .unwrap_or_else(|| panic!("Value `{u}` out of range for 6-bit immediate"))
+    try_imm06(u).unwrap_or_else(|e| panic!("{e}"))
 }
 
 fn check_imm12(u: u16) -> Imm12 {
-    Imm12::new_checked(u)
-        .unwrap_or_else(|| panic!("Value `{u}` out of range for 12-bit immediate"))
+    try_imm12(u).unwrap_or_else(|e| panic!("{e}"))
 }
 
 fn check_imm18(u: u32) -> Imm18 {
-    Imm18::new_checked(u)
-        .unwrap_or_else(|| panic!("Value `{u}` out of range for 18-bit immediate"))
+    try_imm18(u).unwrap_or_else(|e| panic!("{e}"))
 }
 
 fn check_imm24(u: u32) -> Imm24 {
-    Imm24::new_checked(u)
-        .unwrap_or_else(|| panic!("Value `{u}` out of range for 24-bit immediate"))
+    try_imm24(u).unwrap_or_else(|e| panic!("{e}"))
+}
+
+// Fallible constructors for the immediates, used by the `op::try_*` constructors.
+
+fn try_imm06(u: u8) -> Result<Imm06, ImmediateOutOfRange> {
+    Imm06::new_checked(u).ok_or(ImmediateOutOfRange {
+        bits: 6,
+        value: u.into(),
+    })
+}
+
+fn try_imm12(u: u16) -> Result<Imm12, ImmediateOutOfRange> {
+    Imm12::new_checked(u).ok_or(ImmediateOutOfRange {
+        bits: 12,
+        value: u.into(),
+    })
+}
+
+fn try_imm18(u: u32) -> Result<Imm18, ImmediateOutOfRange> {
+    Imm18::new_checked(u).ok_or(ImmediateOutOfRange { bits: 18, value: u })
+}
+
+fn try_imm24(u: u32) -> Result<Imm24, ImmediateOutOfRange> {
+    Imm24::new_checked(u).ok_or(ImmediateOutOfRange { bits: 24, value: u })
 }
 
 // --------------------------------------------------------
@@ -982,7 +1112,7 @@ fn check_predicate_allowed() {
             let should_allow = match repr {
                 BAL | BHEI | BHSH | BURN | CALL | CB | CCP | CROO | CSIZ | LDC | LOG
                 | LOGD | MINT | RETD | RVRT | SMO | SCWQ | SRW | SRWQ | SWW | SWWQ
-                | TIME | TR | TRO | ECAL => false,
+                | TIME | TR | TRO | ECAL | BSIZ | BLDD => false,
                 _ => true,
             };
             assert_eq!(should_allow, repr.is_predicate_allowed());
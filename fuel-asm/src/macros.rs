@@ -37,7 +37,6 @@
 //! ```rust,ignore
 //! /// Solely the opcode portion of an instruction represented as a single byte.
 //! #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-//! #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 //! #[repr(u8)]
 //! pub enum Opcode {
 //!     /// Adds two registers.
@@ -71,7 +70,6 @@
 //! /// The register and immediate data associated with the instruction is represented within
 //! /// an inner unit type wrapper around the 3 remaining bytes.
 //! #[derive(Clone, Copy, Eq, Hash, PartialEq)]
-//! #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 //! pub enum Instruction {
 //!     /// Adds two registers.
 //!     ADD(op::ADD),
@@ -295,6 +293,24 @@ macro_rules! op_constructor {
             $Op::new($ra.check(), $rb.check(), $rc.check(), check_imm06($imm)).into()
         }
 
+        paste::paste! {
+            #[doc = $doc]
+            ///
+            /// Fails if the immediate doesn't fit its field.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a register id is above 63, like [`CheckRegId::check`].
+            pub fn [<try_ $op>]<A: CheckRegId, B: CheckRegId, C: CheckRegId>(
+                $ra: A,
+                $rb: B,
+                $rc: C,
+                $imm: u8,
+            ) -> Result<Instruction, ImmediateOutOfRange> {
+                Ok($Op::new($ra.check(), $rb.check(), $rc.check(), try_imm06($imm)?).into())
+            }
+        }
+
         #[cfg(feature = "typescript")]
         const _: () = {
             use super::*;
@@ -320,6 +336,23 @@ macro_rules! op_constructor {
             $Op::new($ra.check(), $rb.check(), check_imm12($imm)).into()
         }
 
+        paste::paste! {
+            #[doc = $doc]
+            ///
+            /// Fails if the immediate doesn't fit its field.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a register id is above 63, like [`CheckRegId::check`].
+            pub fn [<try_ $op>]<A: CheckRegId, B: CheckRegId>(
+                $ra: A,
+                $rb: B,
+                $imm: u16,
+            ) -> Result<Instruction, ImmediateOutOfRange> {
+                Ok($Op::new($ra.check(), $rb.check(), try_imm12($imm)?).into())
+            }
+        }
+
         #[cfg(feature = "typescript")]
         const _: () = {
             use super::*;
@@ -337,6 +370,22 @@ macro_rules! op_constructor {
             $Op::new($ra.check(), check_imm18($imm)).into()
         }
 
+        paste::paste! {
+            #[doc = $doc]
+            ///
+            /// Fails if the immediate doesn't fit its field.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a register id is above 63, like [`CheckRegId::check`].
+            pub fn [<try_ $op>]<A: CheckRegId>(
+                $ra: A,
+                $imm: u32,
+            ) -> Result<Instruction, ImmediateOutOfRange> {
+                Ok($Op::new($ra.check(), try_imm18($imm)?).into())
+            }
+        }
+
         #[cfg(feature = "typescript")]
         const _: () = {
             use super::*;
@@ -354,6 +403,15 @@ macro_rules! op_constructor {
             $Op::new(check_imm24($imm)).into()
         }
 
+        paste::paste! {
+            #[doc = $doc]
+            ///
+            /// Fails if the immediate doesn't fit its field.
+            pub fn [<try_ $op>]($imm: u32) -> Result<Instruction, ImmediateOutOfRange> {
+                Ok($Op::new(try_imm24($imm)?).into())
+            }
+        }
+
         #[cfg(feature = "typescript")]
         const _: () = {
             use super::*;
@@ -764,6 +822,93 @@ macro_rules! op_reg_ids {
     };
 }
 
+// Generate a private fn for use within the `Instruction::operands` implementation.
+macro_rules! op_operands {
+    (RegId) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let ra = self.unpack();
+            [Some(Operand::Register(ra)), None, None, None]
+        }
+    };
+    (RegId RegId) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let (ra, rb) = self.unpack();
+            [
+                Some(Operand::Register(ra)),
+                Some(Operand::Register(rb)),
+                None,
+                None,
+            ]
+        }
+    };
+    (RegId RegId RegId) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let (ra, rb, rc) = self.unpack();
+            [
+                Some(Operand::Register(ra)),
+                Some(Operand::Register(rb)),
+                Some(Operand::Register(rc)),
+                None,
+            ]
+        }
+    };
+    (RegId RegId RegId RegId) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let (ra, rb, rc, rd) = self.unpack();
+            [
+                Some(Operand::Register(ra)),
+                Some(Operand::Register(rb)),
+                Some(Operand::Register(rc)),
+                Some(Operand::Register(rd)),
+            ]
+        }
+    };
+    (RegId RegId RegId Imm06) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let (ra, rb, rc, imm) = self.unpack();
+            [
+                Some(Operand::Register(ra)),
+                Some(Operand::Register(rb)),
+                Some(Operand::Register(rc)),
+                Some(Operand::Immediate(imm.into())),
+            ]
+        }
+    };
+    (RegId RegId Imm12) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let (ra, rb, imm) = self.unpack();
+            [
+                Some(Operand::Register(ra)),
+                Some(Operand::Register(rb)),
+                Some(Operand::Immediate(imm.into())),
+                None,
+            ]
+        }
+    };
+    (RegId Imm18) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let (ra, imm) = self.unpack();
+            [
+                Some(Operand::Register(ra)),
+                Some(Operand::Immediate(imm.into())),
+                None,
+                None,
+            ]
+        }
+    };
+    (Imm24) => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            let imm = self.unpack();
+            [Some(Operand::Immediate(imm.into())), None, None, None]
+        }
+    };
+    () => {
+        pub(super) fn operands(&self) -> [Option<Operand>; 4] {
+            [None; 4]
+        }
+    };
+}
+
 // Generate test constructors that can be used to generate instructions from non-matching
 // input.
 #[cfg(test)]
@@ -1006,7 +1151,6 @@ macro_rules! impl_instructions {
     (decl_opcode_enum $($doc:literal $ix:literal $Op:ident $op:ident [$($fname:ident: $field:ident)*])*) => {
         /// Solely the opcode portion of an instruction represented as a single byte.
         #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         #[repr(u8)]
         pub enum Opcode {
             $(
@@ -1026,7 +1170,6 @@ macro_rules! impl_instructions {
         /// The register and immediate data associated with the instruction is represented within
         /// an inner unit type wrapper around the 3 remaining bytes.
         #[derive(Clone, Copy, Eq, Hash, PartialEq)]
-        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum Instruction {
             $(
                 #[doc = $doc]
@@ -1059,6 +1202,7 @@ macro_rules! impl_instructions {
         impl $Op {
             op_unpack!($($field)*);
             op_reg_ids!($($field)*);
+            op_operands!($($field)*);
         }
 
         op_constructor!($doc $Op $op [$($fname: $field)*]);
@@ -1116,6 +1260,27 @@ macro_rules! impl_instructions {
             }
         }
 
+        impl Opcode {
+            /// The assembly mnemonic of this opcode, e.g. `addi`.
+            pub fn mnemonic(self) -> &'static str {
+                match self {
+                    $(
+                        Self::$Op => stringify!($op).trim_end_matches('_'),
+                    )*
+                }
+            }
+
+            /// The opcode with the assembly `mnemonic`, if any.
+            pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+                $(
+                    if mnemonic == stringify!($op).trim_end_matches('_') {
+                        return Some(Self::$Op)
+                    }
+                )*
+                None
+            }
+        }
+
         impl Opcode {
             /// Construct the instruction from all possible raw fields, ignoring inapplicable ones.
             #[cfg(test)]
@@ -1149,6 +1314,20 @@ macro_rules! impl_instructions {
                     )*
                 }
             }
+
+            /// Unpacks all operands, registers and immediates, into a slice of options.
+            pub fn operands(&self) -> [Option<Operand>; 4] {
+                match self {
+                    $(
+                        Self::$Op(op) => op.operands(),
+                    )*
+                }
+            }
+
+            /// The assembly mnemonic of this instruction, e.g. `addi`.
+            pub fn mnemonic(&self) -> &'static str {
+                self.opcode().mnemonic()
+            }
         }
 
         impl From<Instruction> for [u8; 4] {
@@ -9,13 +9,13 @@ use crate::{
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
-#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 /// Describe a panic reason with the instruction that generated it
 pub struct PanicInstruction {
     reason: PanicReason,
     instruction: RawInstruction,
+    /// The byte of the reason, kept to encode the reasons unknown to this version
+    /// as they were decoded.
+    raw_reason: u8,
 }
 
 impl PanicInstruction {
@@ -24,6 +24,17 @@ impl PanicInstruction {
         Self {
             reason,
             instruction,
+            raw_reason: reason as u8,
+        }
+    }
+
+    /// Represents an error described by the byte of a reason, possibly unknown to this
+    /// version, and an instruction.
+    pub fn from_raw_reason(raw_reason: u8, instruction: RawInstruction) -> Self {
+        Self {
+            reason: PanicReason::from(raw_reason),
+            instruction,
+            raw_reason,
         }
     }
 
@@ -32,6 +43,12 @@ impl PanicInstruction {
         &self.reason
     }
 
+    /// The byte of the underlying panic reason, the original one if the reason is
+    /// unknown.
+    pub const fn raw_reason(&self) -> u8 {
+        self.raw_reason
+    }
+
     /// Underlying instruction
     pub const fn instruction(&self) -> &RawInstruction {
         &self.instruction
@@ -58,15 +75,36 @@ impl fmt::Debug for InstructionDbg {
     }
 }
 
+/// Helper struct to debug-format a `PanicReason` in `PanicInstruction::fmt`, with the
+/// byte of the unknown reasons.
+struct ReasonDbg(PanicReason, u8);
+impl fmt::Debug for ReasonDbg {
+    /// Formats like this: `UnknownPanicReason(0xf7)` if unknown
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_unknown() {
+            write!(f, "{:?}({:#04x})", self.0, self.1)
+        } else {
+            write!(f, "{:?}", self.0)
+        }
+    }
+}
+
 impl fmt::Debug for PanicInstruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("PanicInstruction")
-            .field("reason", &self.reason)
+            .field("reason", &ReasonDbg(self.reason, self.raw_reason))
             .field("instruction", &InstructionDbg(self.instruction))
             .finish()
     }
 }
 
+/// Displays the reason, with its byte if unknown.
+impl fmt::Display for PanicInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", ReasonDbg(self.reason, self.raw_reason))
+    }
+}
+
 #[cfg(feature = "typescript")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 impl PanicInstruction {
@@ -95,7 +133,7 @@ const INSTR_OFFSET: Word = REASON_OFFSET - (Instruction::SIZE * 8) as Word;
 
 impl From<PanicInstruction> for Word {
     fn from(r: PanicInstruction) -> Word {
-        let reason = Word::from(r.reason as u8);
+        let reason = Word::from(r.raw_reason);
         let instruction = Word::from(r.instruction);
         (reason << REASON_OFFSET) | (instruction << INSTR_OFFSET)
     }
@@ -108,10 +146,128 @@ impl From<Word> for PanicInstruction {
         let reason_u8 = (val >> REASON_OFFSET) as u8;
         // Cast to truncate in order to remove the `reason` bits.
         let instruction = (val >> INSTR_OFFSET) as u32;
-        let reason = PanicReason::from(reason_u8);
-        Self {
-            reason,
-            instruction,
+        Self::from_raw_reason(reason_u8, instruction)
+    }
+}
+
+// The reason is encoded as its byte, in the layout of the derived implementations, so
+// that the unknown reasons are decoded and encoded back without loss.
+
+impl fuel_types::canonical::Serialize for PanicInstruction {
+    fn size_static(&self) -> usize {
+        Word::MIN.size_static() + self.instruction.size_static()
+    }
+
+    fn size_dynamic(&self) -> usize {
+        0
+    }
+
+    fn encode_static<O: fuel_types::canonical::Output + ?Sized>(
+        &self,
+        buffer: &mut O,
+    ) -> Result<(), fuel_types::canonical::Error> {
+        Word::from(self.raw_reason).encode_static(buffer)?;
+        self.instruction.encode_static(buffer)
+    }
+}
+
+impl fuel_types::canonical::Deserialize for PanicInstruction {
+    fn decode_static<I: fuel_types::canonical::Input + ?Sized>(
+        buffer: &mut I,
+    ) -> Result<Self, fuel_types::canonical::Error> {
+        let raw_reason = u8::try_from(Word::decode_static(buffer)?)
+            .map_err(|_| fuel_types::canonical::Error::UnknownDiscriminant)?;
+        let instruction = RawInstruction::decode_static(buffer)?;
+        Ok(Self::from_raw_reason(raw_reason, instruction))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+
+    use serde::{
+        Deserialize,
+        Serialize,
+    };
+
+    /// The reason in the human-readable formats, its name if known and its byte
+    /// otherwise.
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum ReasonRepr {
+        Known(PanicReason),
+        Unknown(u8),
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "PanicInstruction")]
+    struct Repr<Reason> {
+        reason: Reason,
+        instruction: RawInstruction,
+    }
+
+    impl Serialize for PanicInstruction {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let instruction = self.instruction;
+            if serializer.is_human_readable() {
+                let reason = if self.reason.is_unknown() {
+                    ReasonRepr::Unknown(self.raw_reason)
+                } else {
+                    ReasonRepr::Known(self.reason)
+                };
+                Repr {
+                    reason,
+                    instruction,
+                }
+                .serialize(serializer)
+            } else {
+                // The index of the variant of the derived implementation is its byte
+                let reason = u32::from(self.raw_reason);
+                Repr {
+                    reason,
+                    instruction,
+                }
+                .serialize(serializer)
+            }
         }
     }
+
+    impl<'de> Deserialize<'de> for PanicInstruction {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error;
+            if deserializer.is_human_readable() {
+                let Repr {
+                    reason,
+                    instruction,
+                } = Repr::<ReasonRepr>::deserialize(deserializer)?;
+                Ok(match reason {
+                    ReasonRepr::Known(reason) => Self::error(reason, instruction),
+                    ReasonRepr::Unknown(raw_reason) => {
+                        Self::from_raw_reason(raw_reason, instruction)
+                    }
+                })
+            } else {
+                let Repr {
+                    reason,
+                    instruction,
+                } = Repr::<u32>::deserialize(deserializer)?;
+                let raw_reason = u8::try_from(reason).map_err(D::Error::custom)?;
+                Ok(Self::from_raw_reason(raw_reason, instruction))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for PanicInstruction {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_raw_reason(u.arbitrary()?, u.arbitrary()?))
+    }
 }
@@ -129,6 +129,47 @@ enum_from! {
         /// Attempt to use sequential memory instructions with too large slot count,
         /// typically because it cannot fit into usize
         TooManySlots = 0x2d,
+        /// The call would exceed the maximum depth of the nested contract calls
+        CallDepthExceeded = 0x2e,
+        /// The execution exceeded the maximum number of instructions
+        InstructionLimitExceeded = 0x2f,
+        /// The requested blob is not found.
+        BlobNotFound = 0x30,
+        /// The bytes of an elliptic curve point don't encode a point of the curve.
+        InvalidEllipticCurvePoint = 0x31,
+        /// The elliptic curve isn't supported.
+        UnsupportedCurveId = 0x32,
+        /// The elliptic curve operation isn't supported.
+        UnsupportedOperationType = 0x33,
+    }
+}
+
+/// The byte isn't the one of any [`PanicReason`] known to this version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidPanicReason(pub u8);
+
+impl fmt::Display for InvalidPanicReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unknown panic reason `{:#04x}`", self.0)
+    }
+}
+
+impl PanicReason {
+    /// Converts the byte into its reason, failing if it isn't known to this version.
+    ///
+    /// Unlike the `From<u8>` conversion, which maps the unknown bytes to
+    /// [`PanicReason::UnknownPanicReason`], the unknown byte is kept in the error. The
+    /// byte of [`PanicReason::UnknownPanicReason`] itself isn't a known reason either.
+    pub fn try_from_u8(byte: u8) -> Result<Self, InvalidPanicReason> {
+        match Self::from(byte) {
+            Self::UnknownPanicReason => Err(InvalidPanicReason(byte)),
+            reason => Ok(reason),
+        }
+    }
+
+    /// Whether the reason is unknown, like the ones added by a newer version.
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, Self::UnknownPanicReason)
     }
 }
 
@@ -166,4 +207,24 @@ mod tests {
             assert_eq!(PanicReason::UnknownPanicReason as u8, i2);
         }
     }
+
+    #[test]
+    fn test_u8_panic_reason_try_from() {
+        let last_known_panic_reason: u8 = PanicReason::iter().next_back().unwrap() as u8;
+
+        assert_eq!(PanicReason::try_from_u8(0), Err(InvalidPanicReason(0)));
+        for i in 1..=last_known_panic_reason {
+            let reason = PanicReason::try_from_u8(i).expect("A known reason");
+            assert_eq!(reason, PanicReason::from(i));
+            assert!(!reason.is_unknown());
+        }
+        for i in last_known_panic_reason + 1..=255 {
+            assert_eq!(PanicReason::try_from_u8(i), Err(InvalidPanicReason(i)));
+            assert!(PanicReason::from(i).is_unknown());
+        }
+        assert_eq!(
+            InvalidPanicReason(0xf7).to_string(),
+            "Unknown panic reason `0xf7`"
+        );
+    }
 }
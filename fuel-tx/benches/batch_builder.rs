@@ -0,0 +1,90 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+use fuel_crypto::SecretKey;
+use fuel_tx::{
+    BatchBuilder,
+    ConsensusParameters,
+    Finalizable,
+    Output,
+    Script,
+    TransactionBuilder,
+};
+use fuel_types::AssetId;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::sync::Arc;
+
+fn template(builder: &mut TransactionBuilder<Script>) {
+    builder
+        .set_script(vec![0x10; 64], vec![])
+        .script_gas_limit(1_000_000)
+        .max_fee_limit(1_000);
+}
+
+fn configure(builder: &mut TransactionBuilder<Script>, rng: &mut StdRng) {
+    builder
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1_000,
+            AssetId::default(),
+            rng.gen(),
+        )
+        .add_output(Output::coin(rng.gen(), 1, AssetId::default()));
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let params = Arc::new(ConsensusParameters::standard());
+    let mut group = c.benchmark_group("batch_builder");
+
+    for count in [100, 1_000] {
+        group.bench_with_input(BenchmarkId::new("naive", count), &count, |b, &count| {
+            let rng = &mut StdRng::seed_from_u64(8586);
+            b.iter(|| {
+                let txs: Vec<Script> = (0..count)
+                    .map(|_| {
+                        let mut builder = TransactionBuilder::script(vec![], vec![]);
+                        builder.with_params(params.as_ref().clone());
+                        template(&mut builder);
+                        configure(&mut builder, rng);
+                        builder.finalize()
+                    })
+                    .collect();
+                black_box(txs)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", count), &count, |b, &count| {
+            let rng = &mut StdRng::seed_from_u64(8586);
+            let batch = BatchBuilder::new(params.clone(), template);
+            b.iter(|| black_box(batch.build(count, |builder| configure(builder, rng))))
+        });
+
+        #[cfg(feature = "rayon")]
+        group.bench_with_input(
+            BenchmarkId::new("par_batch", count),
+            &count,
+            |b, &count| {
+                let batch = BatchBuilder::new(params.clone(), template);
+                b.iter(|| {
+                    black_box(batch.par_build(count, |builder, index| {
+                        configure(builder, &mut StdRng::seed_from_u64(index as u64))
+                    }))
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
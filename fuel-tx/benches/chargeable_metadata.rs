@@ -0,0 +1,77 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+use fuel_tx::{
+    Cacheable,
+    Chargeable,
+    ConsensusParameters,
+    Finalizable,
+    Input,
+    Script,
+    TransactionBuilder,
+};
+use fuel_types::ChainId;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn script_with_predicates(rng: &mut StdRng, count: usize) -> Script {
+    let mut builder = TransactionBuilder::script(vec![0x10; 64], vec![]);
+    builder.script_gas_limit(1_000_000);
+    for _ in 0..count {
+        let predicate = vec![0x10; 256];
+        let owner = Input::predicate_owner(&predicate);
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            rng.gen(),
+            rng.gen(),
+            Default::default(),
+            1_000,
+            predicate,
+            vec![0x20; 64],
+        ));
+    }
+    builder.finalize()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let params = ConsensusParameters::standard();
+    let gas_costs = params.gas_costs();
+    let fee = params.fee_params();
+    let mut group = c.benchmark_group("max_gas");
+
+    for count in [1, 10, 100] {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let script = script_with_predicates(rng, count);
+
+        group.bench_with_input(
+            BenchmarkId::new("uncached", count),
+            &script,
+            |b, script| b.iter(|| black_box(script.max_gas(gas_costs, fee))),
+        );
+
+        let mut script = script;
+        script
+            .precompute(&ChainId::default())
+            .expect("Should be able to calculate cache");
+        script.precompute_gas(gas_costs, fee);
+
+        group.bench_with_input(
+            BenchmarkId::new("precomputed", count),
+            &script,
+            |b, script| b.iter(|| black_box(script.max_gas(gas_costs, fee))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -10,14 +10,19 @@ use crate::{
             Tip,
             Witnesses,
         },
+        gas_to_fee,
         Chargeable,
         Create,
         Executable,
         Script,
     },
+    Blob,
+    BlobBody,
     ConsensusParameters,
+    Contract,
     ContractParameters,
     FeeParameters,
+    FeeParametersError,
     GasCosts,
     Input,
     Mint,
@@ -28,6 +33,11 @@ use crate::{
     Transaction,
     TxParameters,
     TxPointer,
+    Upgrade,
+    UpgradePurpose,
+    Upload,
+    UploadSubsection,
+    ValidityError,
     Witness,
 };
 
@@ -38,25 +48,50 @@ use crate::{
 
 use crate::{
     field::{
+        GasPriceFloor,
         MaxFeeLimit,
+        Script as ScriptField,
+        ScriptData,
         WitnessLimit,
     },
     policies::Policies,
 };
 use alloc::{
-    collections::BTreeMap,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    vec,
     vec::Vec,
 };
-use fuel_crypto::SecretKey;
+use fuel_asm::Instruction;
+use fuel_crypto::{
+    PublicKey,
+    SecretKey,
+};
 use fuel_types::{
+    canonical::Serialize,
+    Address,
     AssetId,
     BlockHeight,
+    Bytes32,
     ChainId,
     Nonce,
     Salt,
     Word,
 };
 
+mod batch;
+pub mod repair;
+
+pub use batch::BatchBuilder;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(test)]
+mod differential_tests;
+
 pub trait BuildableAloc
 where
     Self: Default + Clone + Executable + Chargeable + field::Policies + Into<Transaction>,
@@ -101,6 +136,104 @@ impl<T> BuildableSet for T where T: BuildableAloc + BuildableStd {}
 
 impl<T> Buildable for T where T: BuildableSet {}
 
+/// The error returned by the fallible methods of the [`TransactionBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum BuilderError {
+    /// A signing key doesn't own the signed inputs that rely on its witness.
+    #[display(
+        fmt = "The key {} doesn't own the inputs signed with its witness, owned by {:?}",
+        public_key,
+        expected_owners
+    )]
+    KeyOwnerMismatch {
+        /// The public counterpart of the signing key.
+        public_key: PublicKey,
+        /// The owners of the signed inputs that rely on the witness of the key.
+        expected_owners: Vec<Address>,
+    },
+    /// A signed input refers to a witness that is neither produced by a signing key nor
+    /// already attached.
+    #[display(
+        fmt = "The input at index {} owned by {} has no signing key nor attached signature",
+        index,
+        owner
+    )]
+    MissingSignature {
+        /// The index of the input.
+        index: usize,
+        /// The owner of the input.
+        owner: Address,
+    },
+    /// The witness slot is already assigned to a signing key, so it can't be reserved.
+    #[display(fmt = "The witness slot {} is already used by a signing key", index)]
+    WitnessSlotInUse {
+        /// The index of the witness.
+        index: u8,
+    },
+    /// The coin output transfers nothing.
+    #[display(fmt = "The coin output at index {} has a zero amount", index)]
+    ZeroAmountCoinOutput {
+        /// The index the output would have.
+        index: usize,
+    },
+    /// The coin output transfers the same asset to the same address as a previous one.
+    #[display(
+        fmt = "The coin output at index {} has the same recipient and asset as the output at index {}",
+        index,
+        existing_index
+    )]
+    DuplicateCoinRecipient {
+        /// The index of the previous coin output.
+        existing_index: usize,
+        /// The index the output would have.
+        index: usize,
+    },
+    /// Two storage slots of the created contract share the key.
+    #[display(fmt = "The storage slots share the key {}", key)]
+    StorageSlotsDuplicated {
+        /// The duplicated key.
+        key: Bytes32,
+    },
+    /// The transaction already has a change output for the asset.
+    #[display(fmt = "Output {} is already the change of asset {}", index, asset_id)]
+    ChangeOutputExists {
+        /// The index of the existing change output.
+        index: usize,
+        /// The asset of the change output.
+        asset_id: AssetId,
+    },
+}
+
+/// The growth of a transaction's size and maximum gas if an element is added to it.
+///
+/// Produced by [`TransactionBuilder::preview_add_input`] and
+/// [`TransactionBuilder::preview_add_output`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImpactPreview {
+    /// The increase of the canonical serialized size of the transaction, in bytes.
+    pub size_delta: usize,
+    /// The increase of the maximum gas of the transaction.
+    pub max_gas_delta: Word,
+    max_gas: Word,
+    gas_price_factor: Word,
+}
+
+impl ImpactPreview {
+    /// The increase of the maximum fee of the transaction for the given `gas_price`.
+    ///
+    /// Returns an error if the fee parameters of the builder are invalid.
+    pub fn max_fee_delta(&self, gas_price: Word) -> Result<Word, FeeParametersError> {
+        let before = gas_to_fee(self.max_gas, gas_price, self.gas_price_factor)?;
+        let after = gas_to_fee(
+            self.max_gas.saturating_add(self.max_gas_delta),
+            gas_price,
+            self.gas_price_factor,
+        )?;
+        Ok(after.saturating_sub(before).try_into().unwrap_or(Word::MAX))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionBuilder<Tx> {
     tx: Tx,
@@ -111,11 +244,23 @@ pub struct TransactionBuilder<Tx> {
     // zeroize the keys
     // Maps signing keys -> witness indexes
     sign_keys: BTreeMap<SecretKey, u8>,
+
+    // Witness indexes managed outside of the builder, never assigned to signing keys
+    reserved_witnesses: BTreeSet<u8>,
+
+    strict: bool,
+
+    // Allows coin outputs with the same recipient and asset in the strict mode
+    allow_duplicate_recipients: bool,
 }
 
 impl TransactionBuilder<Script> {
     pub fn script(script: Vec<u8>, script_data: Vec<u8>) -> Self {
-        let tx = Script {
+        Self::with_tx(Self::new_script(script, script_data))
+    }
+
+    fn new_script(script: Vec<u8>, script_data: Vec<u8>) -> Script {
+        Script {
             script_gas_limit: Default::default(),
             script,
             script_data,
@@ -125,8 +270,37 @@ impl TransactionBuilder<Script> {
             witnesses: Default::default(),
             receipts_root: Default::default(),
             metadata: None,
-        };
-        Self::with_tx(tx)
+        }
+    }
+
+    /// Resets the builder to the state of [`Self::script`] with an empty script and
+    /// script data, but keeps the consensus parameters.
+    ///
+    /// Allows reusing a builder for many transactions without cloning the parameters.
+    pub fn reset_keeping_params(&mut self) -> &mut Self {
+        let Self {
+            tx,
+            params: _,
+            sign_keys,
+            reserved_witnesses,
+            strict,
+            allow_duplicate_recipients,
+        } = self;
+
+        *tx = Self::new_script(Vec::new(), Vec::new());
+        sign_keys.clear();
+        reserved_witnesses.clear();
+        *strict = false;
+        *allow_duplicate_recipients = false;
+
+        self
+    }
+
+    /// Replace the script and the script data of the transaction.
+    pub fn set_script(&mut self, script: Vec<u8>, script_data: Vec<u8>) -> &mut Self {
+        *self.tx.script_mut() = script;
+        *self.tx.script_data_mut() = script_data;
+        self
     }
 }
 
@@ -150,10 +324,130 @@ impl TransactionBuilder<Create> {
             metadata: None,
         };
 
-        *tx.bytecode_length_mut() = (bytecode.as_ref().len() / 4) as Word;
         *tx.bytecode_witness_index_mut() = 0;
 
-        tx.witnesses_mut().push(bytecode);
+        tx.witnesses_mut().push(Witness::default());
+
+        let mut builder = Self::with_tx(tx);
+        builder.set_bytecode(bytecode);
+        builder
+    }
+
+    /// Replace the bytecode of the contract.
+    ///
+    /// The bytecode is zero-padded to a whole number of instructions, see
+    /// [`Contract::padded_bytecode_len`].
+    pub fn set_bytecode(&mut self, mut bytecode: Witness) -> &mut Self {
+        let len = Contract::padded_bytecode_len(bytecode.as_ref().len());
+        bytecode.as_vec_mut().resize(len, 0);
+
+        *self.tx.bytecode_length_mut() = (len / Instruction::SIZE) as Word;
+
+        let index = *self.tx.bytecode_witness_index() as usize;
+        let witnesses = self.tx.witnesses_mut();
+        if witnesses.len() <= index {
+            witnesses.resize(index + 1, Witness::default());
+        }
+        witnesses[index] = bytecode;
+
+        self
+    }
+
+    /// Adds the `ContractCreated` output of the deployed contract, with the
+    /// [`Create::contract_id`] and the [`Create::state_root`] expected by the validity
+    /// check of the `Create` transaction.
+    ///
+    /// The output is outdated by the later changes to the bytecode, the salt or the
+    /// storage slots.
+    ///
+    /// # Panics
+    ///
+    /// If the bytecode witness is missing, which is only possible for a transaction
+    /// passed to [`Self::from_tx`].
+    pub fn add_contract_created_output(&mut self) -> &mut Self {
+        let contract_id = self
+            .tx
+            .contract_id()
+            .expect("The bytecode witness should exist");
+        let state_root = self.tx.state_root();
+
+        self.add_output(Output::contract_created(contract_id, state_root))
+    }
+}
+
+impl TransactionBuilder<Upgrade> {
+    pub fn upgrade(upgrade_purpose: UpgradePurpose) -> Self {
+        let tx = Upgrade {
+            upgrade_purpose,
+            policies: Policies::new().with_max_fee(0),
+            inputs: Default::default(),
+            outputs: Default::default(),
+            witnesses: Default::default(),
+            metadata: None,
+        };
+
+        Self::with_tx(tx)
+    }
+
+    /// Creates the builder of the upgrade to the `consensus_parameters`, serialized to
+    /// the first witness.
+    pub fn upgrade_consensus_parameters(
+        consensus_parameters: &ConsensusParameters,
+    ) -> Self {
+        let mut builder = Self::upgrade(UpgradePurpose::ConsensusParameters {
+            witness_index: 0,
+            checksum: consensus_parameters.hash(),
+        });
+        builder
+            .tx
+            .witnesses_mut()
+            .push(consensus_parameters.to_bytes().into());
+        builder
+    }
+}
+
+impl TransactionBuilder<Upload> {
+    /// Creates the builder of the upload of the `subsection`, stored in the first
+    /// witness.
+    pub fn upload(subsection: UploadSubsection) -> Self {
+        let UploadSubsection {
+            root,
+            subsection,
+            subsection_index,
+            subsections_number,
+            proof_set,
+        } = subsection;
+
+        let tx = Upload {
+            root,
+            witness_index: 0,
+            subsection_index,
+            subsections_number,
+            proof_set,
+            policies: Policies::new().with_max_fee(0),
+            inputs: Default::default(),
+            outputs: Default::default(),
+            witnesses: vec![subsection.into()],
+            metadata: None,
+        };
+
+        Self::with_tx(tx)
+    }
+}
+
+impl TransactionBuilder<Blob> {
+    /// Creates the builder of the blob with the `body`. The payload of the blob must be
+    /// added as the witness at `body.witness_index`.
+    pub fn blob(body: BlobBody) -> Self {
+        let tx = Blob {
+            id: body.id,
+            witness_index: body.witness_index,
+            policies: Policies::new().with_max_fee(0),
+            inputs: Default::default(),
+            outputs: Default::default(),
+            witnesses: Default::default(),
+            metadata: None,
+        };
 
         Self::with_tx(tx)
     }
@@ -191,9 +485,22 @@ impl<Tx> TransactionBuilder<Tx> {
             tx,
             params: ConsensusParameters::standard(),
             sign_keys,
+            reserved_witnesses: BTreeSet::new(),
+            strict: false,
+            allow_duplicate_recipients: false,
         }
     }
 
+    /// Creates a builder continuing the construction of an existing transaction, with
+    /// the standard consensus parameters.
+    ///
+    /// The witnesses of the transaction are kept as they are. Its signatures are
+    /// invalidated by any change, see [`TransactionBuilder::add_signing_key`] to sign
+    /// the inputs again.
+    pub fn from_tx(tx: Tx) -> Self {
+        Self::with_tx(tx)
+    }
+
     pub fn get_params(&self) -> &ConsensusParameters {
         &self.params
     }
@@ -228,7 +535,7 @@ impl<Tx> TransactionBuilder<Tx> {
     }
 
     pub fn with_tx_params(&mut self, tx_params: TxParameters) -> &mut Self {
-        self.params.tx_params = tx_params;
+        self.params.set_tx_params(tx_params);
         self
     }
 
@@ -236,12 +543,12 @@ impl<Tx> TransactionBuilder<Tx> {
         &mut self,
         predicate_params: PredicateParameters,
     ) -> &mut Self {
-        self.params.predicate_params = predicate_params;
+        self.params.set_predicate_params(predicate_params);
         self
     }
 
     pub fn with_script_params(&mut self, script_params: ScriptParameters) -> &mut Self {
-        self.params.script_params = script_params;
+        self.params.set_script_params(script_params);
         self
     }
 
@@ -249,22 +556,27 @@ impl<Tx> TransactionBuilder<Tx> {
         &mut self,
         contract_params: ContractParameters,
     ) -> &mut Self {
-        self.params.contract_params = contract_params;
+        self.params.set_contract_params(contract_params);
         self
     }
 
     pub fn with_fee_params(&mut self, fee_params: FeeParameters) -> &mut Self {
-        self.params.fee_params = fee_params;
+        self.params.set_fee_params(fee_params);
         self
     }
 
     pub fn with_base_asset_id(&mut self, base_asset_id: AssetId) -> &mut Self {
-        self.params.base_asset_id = base_asset_id;
+        self.params.set_base_asset_id(base_asset_id);
+        self
+    }
+
+    pub fn with_privileged_address(&mut self, privileged_address: Address) -> &mut Self {
+        self.params.set_privileged_address(privileged_address);
         self
     }
 
     pub fn with_gas_costs(&mut self, gas_costs: GasCosts) -> &mut Self {
-        self.params.gas_costs = gas_costs;
+        self.params.set_gas_costs(gas_costs);
         self
     }
 }
@@ -274,6 +586,41 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self.sign_keys.keys()
     }
 
+    /// Signs the inputs owned by the key, reusing their witness if the transaction
+    /// already has signed inputs of the key, e.g. one rebuilt with
+    /// [`TransactionBuilder::from_tx`].
+    pub fn add_signing_key(&mut self, secret_key: SecretKey) -> &mut Self {
+        let owner = Input::owner(&secret_key.public_key());
+        let witness_index = self
+            .tx
+            .inputs()
+            .iter()
+            .filter(|input| input.is_signed())
+            .find(|input| {
+                input.input_owner().or_else(|| input.recipient()) == Some(&owner)
+            })
+            .and_then(Input::witness_index);
+
+        match witness_index {
+            Some(witness_index) => {
+                self.sign_keys.insert(secret_key, witness_index);
+            }
+            None => {
+                self.upsert_secret(secret_key);
+            }
+        }
+
+        self
+    }
+
+    /// Enables the additional checks of the strict mode, performed by
+    /// [`Finalizable::try_finalize`] and [`Self::try_add_output`]. In the strict mode,
+    /// [`Finalizable::finalize`] panics if the checks of `try_finalize` fail.
+    pub fn strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
     pub fn tip(&mut self, tip: Word) -> &mut Self {
         self.tx.set_tip(tip);
         self
@@ -289,7 +636,7 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
     }
 
     pub fn with_chain_id(&mut self, chain_id: ChainId) -> &mut Self {
-        self.params.chain_id = chain_id;
+        self.params.set_chain_id(chain_id);
         self
     }
 
@@ -311,6 +658,14 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    /// Commits the transaction to the minimum gas price it accepts to be included at,
+    /// see [`field::GasPriceFloor`].
+    pub fn min_gas_price(&mut self, gas_price_floor: Word) -> &mut Self {
+        self.tx.set_gas_price_floor(gas_price_floor);
+
+        self
+    }
+
     pub fn add_unsigned_coin_input(
         &mut self,
         secret: SecretKey,
@@ -376,6 +731,79 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    /// Adds the coin input owned by the `predicate`, with no predicate gas used yet.
+    ///
+    /// Fails if the predicate is empty or not word aligned, or if the predicate or its
+    /// data exceed the predicate parameters of the builder.
+    pub fn add_predicate_coin_input(
+        &mut self,
+        utxo_id: crate::UtxoId,
+        amount: Word,
+        asset_id: AssetId,
+        tx_pointer: TxPointer,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+    ) -> Result<&mut Self, ValidityError> {
+        let owner = Input::predicate_owner(&predicate);
+        let input = Input::checked_coin_predicate(
+            utxo_id,
+            owner,
+            amount,
+            asset_id,
+            tx_pointer,
+            0,
+            predicate,
+            predicate_data,
+            self.params.predicate_params(),
+        )?;
+
+        Ok(self.add_input(input))
+    }
+
+    /// Adds the message input owned by the `predicate`, with no predicate gas used
+    /// yet. The input is a message coin if the `data` is empty.
+    ///
+    /// Fails if the predicate is empty or not word aligned, or if the predicate or its
+    /// data exceed the predicate parameters of the builder.
+    pub fn add_predicate_message_input(
+        &mut self,
+        sender: Address,
+        nonce: Nonce,
+        amount: Word,
+        data: Vec<u8>,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+    ) -> Result<&mut Self, ValidityError> {
+        let recipient = Input::predicate_owner(&predicate);
+        let predicate_params = self.params.predicate_params();
+        let input = if data.is_empty() {
+            Input::checked_message_coin_predicate(
+                sender,
+                recipient,
+                amount,
+                nonce,
+                0,
+                predicate,
+                predicate_data,
+                predicate_params,
+            )?
+        } else {
+            Input::checked_message_data_predicate(
+                sender,
+                recipient,
+                amount,
+                nonce,
+                0,
+                data,
+                predicate,
+                predicate_data,
+                predicate_params,
+            )?
+        };
+
+        Ok(self.add_input(input))
+    }
+
     pub fn inputs(&self) -> &[Input] {
         self.tx.inputs()
     }
@@ -400,23 +828,225 @@ impl<Tx: Buildable> TransactionBuilder<Tx> {
         self
     }
 
+    /// Reserves the witness slot at `index` for a witness managed outside of the
+    /// builder, e.g. the one expected by a predicate at a hardcoded index.
+    ///
+    /// Empty witnesses are created up to and including `index`, and the slot is never
+    /// assigned to a signing key. Use [`Self::set_witness`] to fill it.
+    pub fn reserve_witness_slot(&mut self, index: u8) -> Result<(), BuilderError> {
+        if self.sign_keys.values().any(|i| *i == index) {
+            return Err(BuilderError::WitnessSlotInUse { index })
+        }
+
+        let witnesses = self.tx.witnesses_mut();
+        if witnesses.len() <= index as usize {
+            witnesses.resize(index as usize + 1, Witness::default());
+        }
+        self.reserved_witnesses.insert(index);
+
+        Ok(())
+    }
+
+    /// Replaces the witness at `index`, creating empty witnesses up to it if needed.
+    pub fn set_witness(&mut self, index: u8, witness: Witness) -> &mut Self {
+        let witnesses = self.tx.witnesses_mut();
+        if witnesses.len() <= index as usize {
+            witnesses.resize(index as usize + 1, Witness::default());
+        }
+        witnesses[index as usize] = witness;
+
+        self
+    }
+
+    /// Returns how much the size and the maximum gas of the transaction would grow if
+    /// the `input` was added, without modifying the builder.
+    pub fn preview_add_input(&self, input: &Input) -> ImpactPreview {
+        let gas_costs = self.params.gas_costs();
+        let size_delta = input.size();
+
+        let input_gas = |metered_bytes: usize| match input {
+            Input::CoinSigned(_)
+            | Input::MessageCoinSigned(_)
+            | Input::MessageDataSigned(_) => {
+                let witness_index = input.witness_index();
+                let witness_is_charged = self
+                    .tx
+                    .inputs()
+                    .iter()
+                    .any(|i| i.is_signed() && i.witness_index() == witness_index);
+
+                if witness_is_charged {
+                    0
+                } else {
+                    gas_costs.ecr1
+                }
+            }
+            Input::CoinPredicate(_)
+            | Input::MessageCoinPredicate(_)
+            | Input::MessageDataPredicate(_) => {
+                let (predicate, _, predicate_gas_used) =
+                    input.predicate().expect("The input is a predicate");
+                gas_costs
+                    .contract_root
+                    .resolve(predicate.len() as Word)
+                    .saturating_add(*predicate_gas_used)
+                    .saturating_add(
+                        gas_costs.vm_initialization.resolve(metered_bytes as Word),
+                    )
+            }
+            Input::Contract(_) => 0,
+        };
+
+        self.preview_impact(size_delta, input_gas)
+    }
+
+    /// Returns how much the size and the maximum gas of the transaction would grow if
+    /// the `output` was added, without modifying the builder.
+    pub fn preview_add_output(&self, output: &Output) -> ImpactPreview {
+        self.preview_impact(output.size(), |_| 0)
+    }
+
+    /// Computes the impact of growing the transaction by `size_delta` bytes, plus the
+    /// gas charged for a new element by `element_gas`, that receives the new metered
+    /// size of the transaction.
+    ///
+    /// Only the bytes gas, the VM initialization (also paid per predicate), and the
+    /// `tx_id` hashing depend on the size of the transaction, so the other terms of the
+    /// max gas formula are not affected.
+    fn preview_impact<F>(&self, size_delta: usize, element_gas: F) -> ImpactPreview
+    where
+        F: FnOnce(usize) -> Word,
+    {
+        let gas_costs = self.params.gas_costs();
+        let fee_params = self.params.fee_params();
+        let predicates = self
+            .tx
+            .inputs()
+            .iter()
+            .filter(|input| input.is_predicate())
+            .count() as Word;
+
+        let size_dependent_gas = |metered_bytes: usize| {
+            let metered_bytes = metered_bytes as Word;
+            let vm_initialization_gas =
+                gas_costs.vm_initialization.resolve(metered_bytes);
+            metered_bytes
+                .saturating_mul(fee_params.gas_per_byte)
+                .saturating_add(vm_initialization_gas)
+                .saturating_add(vm_initialization_gas.saturating_mul(predicates))
+                .saturating_add(gas_costs.s256.resolve(metered_bytes))
+        };
+
+        let metered_bytes = self.tx.metered_bytes_size();
+        let new_metered_bytes = metered_bytes.saturating_add(size_delta);
+
+        let max_gas_delta = size_dependent_gas(new_metered_bytes)
+            .saturating_sub(size_dependent_gas(metered_bytes))
+            .saturating_add(element_gas(new_metered_bytes));
+
+        ImpactPreview {
+            size_delta,
+            max_gas_delta,
+            max_gas: self.tx.max_gas(gas_costs, fee_params),
+            gas_price_factor: fee_params.gas_price_factor,
+        }
+    }
+
     /// Adds a secret to the builder, and adds a corresponding witness if it's a new entry
+    ///
+    /// Reserved witness slots are skipped when choosing the index of the new witness.
     fn upsert_secret(&mut self, secret_key: SecretKey) -> u8 {
-        let witness_len = u8::try_from(self.witnesses().len())
+        if let Some(witness_index) = self.sign_keys.get(&secret_key) {
+            return *witness_index
+        }
+
+        // if this private key hasn't been used before,
+        // add a new witness entry and return its index
+        let witnesses = self.tx.witnesses_mut();
+        let mut witness_index = u8::try_from(witnesses.len())
             .expect("The number of witnesses can't exceed `u8::MAX`");
+        witnesses.push(Witness::default());
+        while self.reserved_witnesses.contains(&witness_index) {
+            witness_index = witness_index
+                .checked_add(1)
+                .expect("The number of witnesses can't exceed `u8::MAX`");
+            witnesses.push(Witness::default());
+        }
 
-        let witness_index = self.sign_keys.entry(secret_key).or_insert_with(|| {
-            // if this private key hasn't been used before,
-            // add a new witness entry and return its index
-            self.tx.witnesses_mut().push(Witness::default());
-            witness_len
-        });
-        *witness_index
+        self.sign_keys.insert(secret_key, witness_index);
+        witness_index
+    }
+
+    /// Checks that the witness of every signing key is only used by inputs owned by the
+    /// key, and that every other signed input has an attached signature.
+    fn check_sign_keys(&self) -> Result<(), BuilderError> {
+        let signed_inputs = || {
+            self.tx
+                .inputs()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, input)| {
+                    if !input.is_signed() {
+                        return None
+                    }
+                    let owner = input.input_owner().or_else(|| input.recipient())?;
+                    Some((index, *owner, input.witness_index()?))
+                })
+        };
+
+        for (secret, witness_index) in self.sign_keys.iter() {
+            let public_key = secret.public_key();
+            let owner = Input::owner(&public_key);
+
+            let mut expected_owners = signed_inputs()
+                .filter(|(_, _, index)| index == witness_index)
+                .map(|(_, owner, _)| owner)
+                .collect::<Vec<_>>();
+            expected_owners.sort();
+            expected_owners.dedup();
+
+            if expected_owners != [owner] {
+                return Err(BuilderError::KeyOwnerMismatch {
+                    public_key,
+                    expected_owners,
+                })
+            }
+        }
+
+        for (index, owner, witness_index) in signed_inputs() {
+            let is_signed_by_key = self.sign_keys.values().any(|i| *i == witness_index);
+            let has_signature = self
+                .tx
+                .witnesses()
+                .get(witness_index as usize)
+                .is_some_and(|witness| !witness.as_ref().is_empty());
+
+            if !is_signed_by_key && !has_signature {
+                return Err(BuilderError::MissingSignature { index, owner })
+            }
+        }
+
+        Ok(())
+    }
+
+    fn try_finalize_inner(&self) -> Result<Tx, BuilderError> {
+        if self.strict {
+            self.check_sign_keys()?;
+        }
+
+        Ok(self.finalize_inner())
     }
 
     fn finalize_inner(&self) -> Tx {
         let mut tx = self.tx.clone();
 
+        // The mutations made since `from_tx` drop the cache, but the cache of an
+        // untouched transaction may be computed for another chain id
+        if tx.is_computed() {
+            tx.precompute(&self.get_chain_id())
+                .expect("Should be able to calculate cache");
+        }
+
         self.sign_keys
             .iter()
             .for_each(|(k, _)| tx.sign_inputs(k, &self.get_chain_id()));
@@ -442,12 +1072,91 @@ impl<Tx: field::Outputs> TransactionBuilder<Tx> {
         self.tx.outputs_mut().push(output);
         self
     }
+
+    /// Allows coin outputs with the same recipient and asset in the strict mode, see
+    /// [`Self::try_add_output`].
+    pub fn allow_duplicate_recipients(&mut self, allow: bool) -> &mut Self {
+        self.allow_duplicate_recipients = allow;
+        self
+    }
+
+    /// Appends the output like [`Self::add_output`], but in the strict mode rejects
+    /// coin outputs with a zero amount, or with the same recipient and asset as a
+    /// previous coin output unless [`Self::allow_duplicate_recipients`] is set.
+    pub fn try_add_output(&mut self, output: Output) -> Result<&mut Self, BuilderError> {
+        if self.strict {
+            self.check_coin_output(&output)?;
+        }
+
+        Ok(self.add_output(output))
+    }
+
+    fn check_coin_output(&self, output: &Output) -> Result<(), BuilderError> {
+        let outputs = self.tx.outputs();
+        let index = outputs.len();
+
+        let Output::Coin {
+            to,
+            amount,
+            asset_id,
+        } = output
+        else {
+            return Ok(())
+        };
+
+        if *amount == 0 {
+            return Err(BuilderError::ZeroAmountCoinOutput { index })
+        }
+
+        if self.allow_duplicate_recipients {
+            return Ok(())
+        }
+
+        let existing_index = outputs.iter().position(|existing| {
+            matches!(
+                existing,
+                Output::Coin {
+                    to: existing_to,
+                    asset_id: existing_asset_id,
+                    ..
+                } if existing_to == to && existing_asset_id == asset_id
+            )
+        });
+
+        match existing_index {
+            Some(existing_index) => Err(BuilderError::DuplicateCoinRecipient {
+                existing_index,
+                index,
+            }),
+            None => Ok(()),
+        }
+    }
 }
 
 pub trait Finalizable<Tx> {
+    /// Signs and finalizes the transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the builder is in strict mode and [`Finalizable::try_finalize`] fails.
     fn finalize(&self) -> Tx;
 
     fn finalize_without_signature(&self) -> Tx;
+
+    /// Signs and finalizes the transaction like [`Finalizable::finalize`], but fails if
+    /// the builder is in strict mode and one of its checks doesn't pass.
+    fn try_finalize(&self) -> Result<Tx, BuilderError> {
+        Ok(self.finalize())
+    }
+}
+
+/// Unwraps the result of [`Finalizable::try_finalize`] for [`Finalizable::finalize`] in
+/// the strict mode.
+fn expect_strict<Tx>(result: Result<Tx, BuilderError>) -> Tx {
+    match result {
+        Ok(tx) => tx,
+        Err(err) => panic!("The strict checks of the builder failed: {err}"),
+    }
 }
 
 impl Finalizable<Mint> for TransactionBuilder<Mint> {
@@ -465,22 +1174,91 @@ impl Finalizable<Mint> for TransactionBuilder<Mint> {
 
 impl Finalizable<Create> for TransactionBuilder<Create> {
     fn finalize(&self) -> Create {
+        if self.strict {
+            return expect_strict(self.try_finalize())
+        }
         self.finalize_inner()
     }
 
     fn finalize_without_signature(&self) -> Create {
         self.finalize_without_signature_inner()
     }
+
+    fn try_finalize(&self) -> Result<Create, BuilderError> {
+        if let Some(key) = self.tx.duplicated_storage_slot_key() {
+            return Err(BuilderError::StorageSlotsDuplicated { key: *key })
+        }
+
+        self.try_finalize_inner()
+    }
+}
+
+impl Finalizable<Upgrade> for TransactionBuilder<Upgrade> {
+    fn finalize(&self) -> Upgrade {
+        if self.strict {
+            return expect_strict(self.try_finalize())
+        }
+        self.finalize_inner()
+    }
+
+    fn finalize_without_signature(&self) -> Upgrade {
+        self.finalize_without_signature_inner()
+    }
+
+    fn try_finalize(&self) -> Result<Upgrade, BuilderError> {
+        self.try_finalize_inner()
+    }
+}
+
+impl Finalizable<Upload> for TransactionBuilder<Upload> {
+    fn finalize(&self) -> Upload {
+        if self.strict {
+            return expect_strict(self.try_finalize())
+        }
+        self.finalize_inner()
+    }
+
+    fn finalize_without_signature(&self) -> Upload {
+        self.finalize_without_signature_inner()
+    }
+
+    fn try_finalize(&self) -> Result<Upload, BuilderError> {
+        self.try_finalize_inner()
+    }
+}
+
+impl Finalizable<Blob> for TransactionBuilder<Blob> {
+    fn finalize(&self) -> Blob {
+        if self.strict {
+            return expect_strict(self.try_finalize())
+        }
+        self.finalize_inner()
+    }
+
+    fn finalize_without_signature(&self) -> Blob {
+        self.finalize_without_signature_inner()
+    }
+
+    fn try_finalize(&self) -> Result<Blob, BuilderError> {
+        self.try_finalize_inner()
+    }
 }
 
 impl Finalizable<Script> for TransactionBuilder<Script> {
     fn finalize(&self) -> Script {
+        if self.strict {
+            return expect_strict(self.try_finalize())
+        }
         self.finalize_inner()
     }
 
     fn finalize_without_signature(&self) -> Script {
         self.finalize_without_signature_inner()
     }
+
+    fn try_finalize(&self) -> Result<Script, BuilderError> {
+        self.try_finalize_inner()
+    }
 }
 
 impl<Tx> TransactionBuilder<Tx>
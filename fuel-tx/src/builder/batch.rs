@@ -0,0 +1,227 @@
+use super::{
+    Finalizable,
+    TransactionBuilder,
+};
+use crate::{
+    ConsensusParameters,
+    Script,
+};
+use alloc::{
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+/// Builds many similar [`Script`] transactions sharing the same consensus parameters.
+///
+/// Every transaction is built by a scratch builder, reset with
+/// [`TransactionBuilder::reset_keeping_params`] between the transactions, configured by
+/// the `template` and then by the closure of the batch. The parameters are only cloned
+/// once per scratch builder instead of once per transaction.
+#[derive(Debug, Clone)]
+pub struct BatchBuilder<T> {
+    params: Arc<ConsensusParameters>,
+    template: T,
+}
+
+impl<T> BatchBuilder<T>
+where
+    T: Fn(&mut TransactionBuilder<Script>),
+{
+    /// Creates the batch builder, applying the `template` to every transaction before
+    /// its own configuration.
+    pub fn new(params: Arc<ConsensusParameters>, template: T) -> Self {
+        Self { params, template }
+    }
+
+    /// The consensus parameters shared by the transactions.
+    pub fn params(&self) -> &ConsensusParameters {
+        &self.params
+    }
+
+    fn scratch_builder(&self) -> TransactionBuilder<Script> {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.with_params(self.params.as_ref().clone());
+        builder
+    }
+
+    fn build_one<F>(&self, builder: &mut TransactionBuilder<Script>, f: F) -> Script
+    where
+        F: FnOnce(&mut TransactionBuilder<Script>),
+    {
+        builder.reset_keeping_params();
+        (self.template)(builder);
+        f(builder);
+        builder.finalize()
+    }
+
+    /// Builds `count` transactions, `f` configures each of them after the template.
+    pub fn build<F>(&self, count: usize, mut f: F) -> Vec<Script>
+    where
+        F: FnMut(&mut TransactionBuilder<Script>),
+    {
+        let mut builder = self.scratch_builder();
+        (0..count)
+            .map(|_| self.build_one(&mut builder, &mut f))
+            .collect()
+    }
+
+    /// Builds a transaction per item, `f` configures each of them from its item after
+    /// the template.
+    pub fn build_iter<I, F>(&self, items: I, mut f: F) -> Vec<Script>
+    where
+        I: IntoIterator,
+        F: FnMut(&mut TransactionBuilder<Script>, I::Item),
+    {
+        let mut builder = self.scratch_builder();
+        items
+            .into_iter()
+            .map(|item| self.build_one(&mut builder, |builder| f(builder, item)))
+            .collect()
+    }
+
+    /// Builds `count` transactions in parallel, `f` configures each of them from its
+    /// index after the template.
+    ///
+    /// Returns the same transactions, in the same order, as the sequential
+    /// [`Self::build_iter`] over `0..count`.
+    #[cfg(feature = "rayon")]
+    pub fn par_build<F>(&self, count: usize, f: F) -> Vec<Script>
+    where
+        T: Sync,
+        F: Fn(&mut TransactionBuilder<Script>, usize) + Sync,
+    {
+        use rayon::prelude::*;
+
+        (0..count)
+            .into_par_iter()
+            .map_init(
+                || self.scratch_builder(),
+                |builder, index| self.build_one(builder, |builder| f(builder, index)),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        Output,
+        Word,
+    };
+    use fuel_crypto::SecretKey;
+    use fuel_types::AssetId;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn params() -> Arc<ConsensusParameters> {
+        let mut params = ConsensusParameters::standard();
+        params.set_chain_id(42.into());
+        Arc::new(params)
+    }
+
+    fn template(builder: &mut TransactionBuilder<Script>) {
+        builder
+            .set_script(vec![0x10; 8], vec![0x11; 4])
+            .script_gas_limit(1_000)
+            .max_fee_limit(100);
+    }
+
+    /// Configures the transaction at `index` of the batch.
+    fn configure(builder: &mut TransactionBuilder<Script>, index: usize) {
+        let rng = &mut StdRng::seed_from_u64(index as u64);
+        builder
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                1_000,
+                AssetId::default(),
+                rng.gen(),
+            )
+            .add_output(Output::coin(
+                rng.gen(),
+                index as Word + 1,
+                AssetId::default(),
+            ));
+    }
+
+    fn naive(count: usize) -> Vec<Script> {
+        (0..count)
+            .map(|index| {
+                let mut builder = TransactionBuilder::script(vec![], vec![]);
+                builder.with_params(params().as_ref().clone());
+                template(&mut builder);
+                configure(&mut builder, index);
+                builder.finalize()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn build__matches_naive_construction() {
+        // Given
+        let batch = BatchBuilder::new(params(), template);
+        let mut index = 0;
+
+        // When
+        let txs = batch.build(16, |builder| {
+            configure(builder, index);
+            index += 1;
+        });
+
+        // Then
+        assert_eq!(txs, naive(16));
+    }
+
+    #[test]
+    fn build_iter__matches_naive_construction() {
+        // Given
+        let batch = BatchBuilder::new(params(), template);
+
+        // When
+        let txs = batch.build_iter(0..16, configure);
+
+        // Then
+        assert_eq!(txs, naive(16));
+    }
+
+    #[test]
+    fn build__resets_the_builder_between_transactions() {
+        // Given
+        let batch = BatchBuilder::new(params(), template);
+        let mut index = 0;
+
+        // When
+        let txs = batch.build(2, |builder| {
+            if index == 0 {
+                configure(builder, index);
+            }
+            index += 1;
+        });
+
+        // Then
+        assert_eq!(txs[1].inputs.len(), 0);
+        assert_eq!(txs[1].outputs.len(), 0);
+        assert_eq!(txs[1].witnesses.len(), 0);
+        assert_eq!(txs[1].script, vec![0x10; 8]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_build__matches_naive_construction() {
+        // Given
+        let batch = BatchBuilder::new(params(), template);
+
+        // When
+        let txs = batch.par_build(64, configure);
+
+        // Then
+        assert_eq!(txs, naive(64));
+    }
+}
@@ -0,0 +1,460 @@
+//! Differential tests between the transactions produced by the [`TransactionBuilder`]
+//! and the ones constructed manually from the structs.
+//!
+//! The manual path is the written down version of the rules applied by the builder.
+//! If the builder starts to behave differently, either the builder is wrong or the
+//! rules below must be updated together with the documentation of the builder.
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::Finalizable;
+use fuel_crypto::{
+    Message,
+    Signature,
+};
+use fuel_types::{
+    canonical::Serialize,
+    BlockHeight,
+    Bytes32,
+    Nonce,
+};
+use quickcheck::{
+    Arbitrary,
+    Gen,
+};
+use quickcheck_macros::quickcheck;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// The shape of a random logical transaction, the values are derived from the `seed`.
+#[derive(Debug, Clone)]
+struct Shape {
+    seed: u64,
+    keys: usize,
+    inputs: Vec<InputShape>,
+    outputs: usize,
+    tip: Option<Word>,
+    witness_limit: Option<Word>,
+    maturity: Option<u32>,
+    max_fee: Option<Word>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum InputShape {
+    Coin { key: usize },
+    Message { key: usize, with_data: bool },
+    CoinPredicate,
+}
+
+impl Arbitrary for Shape {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let keys = usize::arbitrary(g) % 3 + 1;
+        let inputs = (0..usize::arbitrary(g) % 6)
+            .map(|_| match u8::arbitrary(g) % 3 {
+                0 => InputShape::Coin {
+                    key: usize::arbitrary(g) % keys,
+                },
+                1 => InputShape::Message {
+                    key: usize::arbitrary(g) % keys,
+                    with_data: bool::arbitrary(g),
+                },
+                _ => InputShape::CoinPredicate,
+            })
+            .collect();
+
+        Self {
+            seed: u64::arbitrary(g),
+            keys,
+            inputs,
+            outputs: usize::arbitrary(g) % 4,
+            tip: Option::arbitrary(g),
+            witness_limit: Option::arbitrary(g),
+            maturity: Option::arbitrary(g),
+            max_fee: Option::arbitrary(g),
+        }
+    }
+}
+
+/// The concrete values of a logical transaction, independent of how it is built.
+struct Logical {
+    keys: Vec<SecretKey>,
+    inputs: Vec<LogicalInput>,
+    outputs: Vec<Output>,
+    shape: Shape,
+    rng: StdRng,
+}
+
+enum LogicalInput {
+    Coin {
+        key: usize,
+        utxo_id: crate::UtxoId,
+        amount: Word,
+        asset_id: AssetId,
+        tx_pointer: TxPointer,
+    },
+    Message {
+        key: usize,
+        sender: Address,
+        nonce: Nonce,
+        amount: Word,
+        data: Vec<u8>,
+    },
+    Predicate(Input),
+}
+
+impl Logical {
+    fn new(shape: Shape) -> Self {
+        let rng = &mut StdRng::seed_from_u64(shape.seed);
+
+        let keys = (0..shape.keys).map(|_| SecretKey::random(rng)).collect();
+        let inputs = shape
+            .inputs
+            .iter()
+            .map(|input| match *input {
+                InputShape::Coin { key } => LogicalInput::Coin {
+                    key,
+                    utxo_id: rng.gen(),
+                    amount: rng.gen(),
+                    asset_id: rng.gen(),
+                    tx_pointer: rng.gen(),
+                },
+                InputShape::Message { key, with_data } => LogicalInput::Message {
+                    key,
+                    sender: rng.gen(),
+                    nonce: rng.gen(),
+                    amount: rng.gen(),
+                    data: if with_data {
+                        vec![rng.gen(); 17]
+                    } else {
+                        vec![]
+                    },
+                },
+                InputShape::CoinPredicate => {
+                    let predicate = vec![rng.gen(); 32];
+                    LogicalInput::Predicate(Input::coin_predicate(
+                        rng.gen(),
+                        Input::predicate_owner(&predicate),
+                        rng.gen(),
+                        rng.gen(),
+                        rng.gen(),
+                        rng.gen_range(0..1_000_000),
+                        predicate,
+                        vec![rng.gen(); 9],
+                    ))
+                }
+            })
+            .collect();
+        let outputs = (0..shape.outputs)
+            .map(|_| Output::coin(rng.gen(), rng.gen(), rng.gen()))
+            .collect();
+        let rng = StdRng::seed_from_u64(rng.gen());
+
+        Self {
+            keys,
+            inputs,
+            outputs,
+            shape,
+            rng,
+        }
+    }
+
+    fn bytes(&mut self, max_len: usize) -> Vec<u8> {
+        let len = self.rng.gen_range(0..max_len);
+        (0..len).map(|_| self.rng.gen::<u8>()).collect()
+    }
+
+    fn with_builder<Tx: Buildable>(&self, builder: &mut TransactionBuilder<Tx>) -> Tx
+    where
+        TransactionBuilder<Tx>: Finalizable<Tx>,
+    {
+        if let Some(tip) = self.shape.tip {
+            builder.tip(tip);
+        }
+        if let Some(witness_limit) = self.shape.witness_limit {
+            builder.witness_limit(witness_limit);
+        }
+        if let Some(maturity) = self.shape.maturity {
+            builder.maturity(maturity.into());
+        }
+        if let Some(max_fee) = self.shape.max_fee {
+            builder.max_fee_limit(max_fee);
+        }
+
+        for input in &self.inputs {
+            match input {
+                LogicalInput::Coin {
+                    key,
+                    utxo_id,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                } => {
+                    builder.add_unsigned_coin_input(
+                        self.keys[*key],
+                        *utxo_id,
+                        *amount,
+                        *asset_id,
+                        *tx_pointer,
+                    );
+                }
+                LogicalInput::Message {
+                    key,
+                    sender,
+                    nonce,
+                    amount,
+                    data,
+                } => {
+                    builder.add_unsigned_message_input(
+                        self.keys[*key],
+                        *sender,
+                        *nonce,
+                        *amount,
+                        data.clone(),
+                    );
+                }
+                LogicalInput::Predicate(input) => {
+                    builder.add_input(input.clone());
+                }
+            }
+        }
+
+        for output in &self.outputs {
+            builder.add_output(*output);
+        }
+
+        builder.finalize()
+    }
+
+    /// The builder starts with the `MaxFee` policy set to zero, the other policies are
+    /// only set when requested.
+    fn manual_policies(&self) -> Policies {
+        let mut policies = Policies::new().with_max_fee(0);
+        if let Some(tip) = self.shape.tip {
+            policies = policies.with_tip(tip);
+        }
+        if let Some(witness_limit) = self.shape.witness_limit {
+            policies = policies.with_witness_limit(witness_limit);
+        }
+        if let Some(maturity) = self.shape.maturity {
+            policies = policies.with_maturity(maturity.into());
+        }
+        if let Some(max_fee) = self.shape.max_fee {
+            policies = policies.with_max_fee(max_fee);
+        }
+        policies
+    }
+
+    /// Every signing key gets a new witness, appended after the existing ones, when it
+    /// is used for the first time. Messages without data are message coins.
+    fn manual_inputs(
+        &self,
+        witnesses: &mut Vec<Witness>,
+    ) -> (Vec<Input>, Vec<(usize, u8)>) {
+        let mut key_witnesses: Vec<(usize, u8)> = vec![];
+        let mut witness_index_of = |key: usize, witnesses: &mut Vec<Witness>| {
+            if let Some((_, index)) = key_witnesses.iter().find(|(k, _)| *k == key) {
+                return *index
+            }
+            let index = u8::try_from(witnesses.len()).expect("Few witnesses");
+            witnesses.push(Witness::default());
+            key_witnesses.push((key, index));
+            index
+        };
+
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| match input {
+                LogicalInput::Coin {
+                    key,
+                    utxo_id,
+                    amount,
+                    asset_id,
+                    tx_pointer,
+                } => {
+                    let owner = Input::owner(&self.keys[*key].public_key());
+                    Input::coin_signed(
+                        *utxo_id,
+                        owner,
+                        *amount,
+                        *asset_id,
+                        *tx_pointer,
+                        witness_index_of(*key, witnesses),
+                    )
+                }
+                LogicalInput::Message {
+                    key,
+                    sender,
+                    nonce,
+                    amount,
+                    data,
+                } => {
+                    let recipient = Input::owner(&self.keys[*key].public_key());
+                    let witness_index = witness_index_of(*key, witnesses);
+                    if data.is_empty() {
+                        Input::message_coin_signed(
+                            *sender,
+                            recipient,
+                            *amount,
+                            *nonce,
+                            witness_index,
+                        )
+                    } else {
+                        Input::message_data_signed(
+                            *sender,
+                            recipient,
+                            *amount,
+                            *nonce,
+                            witness_index,
+                            data.clone(),
+                        )
+                    }
+                }
+                LogicalInput::Predicate(input) => input.clone(),
+            })
+            .collect();
+
+        (inputs, key_witnesses)
+    }
+
+    /// The witness of every signing key is the signature of the transaction id.
+    fn manual_sign<Tx: Buildable>(&self, tx: &mut Tx, key_witnesses: &[(usize, u8)]) {
+        let chain_id = ConsensusParameters::standard().chain_id();
+        let id = tx.id(&chain_id);
+        let message = Message::from_bytes_ref(&id);
+
+        for (key, index) in key_witnesses {
+            let signature = Signature::sign(&self.keys[*key], message);
+            tx.witnesses_mut()[*index as usize] = signature.as_ref().to_vec().into();
+        }
+
+        tx.precompute(&chain_id)
+            .expect("Should be able to calculate cache");
+    }
+}
+
+fn is_same<Tx: Buildable + Serialize>(built: &Tx, manual: &Tx) -> bool {
+    let chain_id = ConsensusParameters::standard().chain_id();
+    built.to_bytes() == manual.to_bytes() && built.id(&chain_id) == manual.id(&chain_id)
+}
+
+#[quickcheck]
+fn script__builder_matches_manual_construction(shape: Shape) -> bool {
+    let mut logical = Logical::new(shape);
+    let script: Vec<u8> = logical.bytes(64);
+    let script_data: Vec<u8> = logical.bytes(64);
+    let script_gas_limit: Word = logical.rng.gen();
+
+    // Given
+    let mut builder = TransactionBuilder::script(script.clone(), script_data.clone());
+    builder.script_gas_limit(script_gas_limit);
+
+    // When
+    let built = logical.with_builder(&mut builder);
+    let mut witnesses = vec![];
+    let (inputs, key_witnesses) = logical.manual_inputs(&mut witnesses);
+    let mut manual = Script {
+        script_gas_limit,
+        script,
+        script_data,
+        policies: logical.manual_policies(),
+        inputs,
+        outputs: logical.outputs.clone(),
+        witnesses,
+        receipts_root: Bytes32::zeroed(),
+        metadata: None,
+    };
+    logical.manual_sign(&mut manual, &key_witnesses);
+
+    // Then
+    is_same(&built, &manual)
+}
+
+#[quickcheck]
+fn create__builder_matches_manual_construction(shape: Shape) -> bool {
+    let mut logical = Logical::new(shape);
+    let bytecode: Vec<u8> = logical.bytes(64);
+    let salt: Salt = logical.rng.gen();
+    let storage_slots: Vec<StorageSlot> = (0..logical.rng.gen_range(0..8usize))
+        .map(|_| logical.rng.gen())
+        .collect();
+
+    // Given
+    let mut builder =
+        TransactionBuilder::create(bytecode.clone().into(), salt, storage_slots.clone());
+
+    // When
+    let built = logical.with_builder(&mut builder);
+    // The bytecode is zero-padded to whole instructions and is the first witness.
+    let mut padded_bytecode = bytecode;
+    padded_bytecode.resize(Contract::padded_bytecode_len(padded_bytecode.len()), 0);
+    let bytecode_length = (padded_bytecode.len() / Instruction::SIZE) as Word;
+    let mut witnesses = vec![padded_bytecode.into()];
+    let (inputs, key_witnesses) = logical.manual_inputs(&mut witnesses);
+    // The storage slots are sorted.
+    let mut sorted_storage_slots = storage_slots;
+    sorted_storage_slots.sort();
+    let mut manual = Create {
+        bytecode_length,
+        bytecode_witness_index: 0,
+        salt,
+        storage_slots: sorted_storage_slots,
+        policies: logical.manual_policies(),
+        inputs,
+        outputs: logical.outputs.clone(),
+        witnesses,
+        metadata: None,
+    };
+    logical.manual_sign(&mut manual, &key_witnesses);
+
+    // Then
+    is_same(&built, &manual)
+}
+
+#[test]
+fn manual_construction__detects_divergence() {
+    let shape = Shape {
+        seed: 42,
+        keys: 2,
+        inputs: vec![
+            InputShape::Coin { key: 1 },
+            InputShape::Message {
+                key: 0,
+                with_data: true,
+            },
+        ],
+        outputs: 1,
+        tip: Some(1),
+        witness_limit: None,
+        maturity: Some(7),
+        max_fee: None,
+    };
+    let logical = Logical::new(shape);
+
+    // Given
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    let built = logical.with_builder(&mut builder);
+    let mut witnesses = vec![];
+    let (inputs, key_witnesses) = logical.manual_inputs(&mut witnesses);
+    let mut manual = Script {
+        script_gas_limit: 0,
+        script: vec![],
+        script_data: vec![],
+        // When
+        policies: Policies::new()
+            .with_tip(1)
+            .with_maturity(BlockHeight::new(7)),
+        inputs,
+        outputs: logical.outputs.clone(),
+        witnesses,
+        receipts_root: Bytes32::zeroed(),
+        metadata: None,
+    };
+    logical.manual_sign(&mut manual, &key_witnesses);
+
+    // Then
+    assert!(!is_same(&built, &manual));
+}
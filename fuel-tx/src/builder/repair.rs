@@ -0,0 +1,403 @@
+//! Mechanical fixes of the transactions failing the validity checks.
+//!
+//! [`suggest`] maps a [`ValidityError`] to a [`Repair`] when the fix can't change what
+//! the transaction does, only whether it is accepted. [`suggest_for_burned_assets`]
+//! adds the change outputs of the assets the transaction would burn. [`Repair::apply`]
+//! fixes a builder rebuilt from the failed transaction with
+//! [`TransactionBuilder::from_tx`]. The repaired transaction has to be signed again.
+//!
+//! The errors depending on the intent of the sender, e.g. a change output for an asset
+//! that no input spends, have no repair.
+
+use super::{
+    BuilderError,
+    TransactionBuilder,
+};
+use crate::{
+    field::{
+        Inputs,
+        Outputs,
+    },
+    policies::PolicyType,
+    BudgetKind,
+    Chargeable,
+    ConsensusParameters,
+    Output,
+    Script,
+    Transaction,
+    ValidityError,
+};
+use alloc::vec::Vec;
+use fuel_types::{
+    Address,
+    AssetId,
+    Word,
+};
+
+/// The fix of a [`ValidityError`] or of a burned asset, see [`suggest`] and
+/// [`suggest_for_burned_assets`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Repair {
+    /// Raises the `WitnessLimit` policy to the size of the witnesses.
+    RaiseWitnessLimit {
+        /// The new witness limit.
+        witness_limit: Word,
+    },
+    /// Sets the missing `MaxFee` policy to the maximum fee of the transaction at its
+    /// gas price floor.
+    SetMaxFee {
+        /// The new maximum fee.
+        max_fee: Word,
+    },
+    /// Adds the change output of an asset spent by the inputs, which is burned
+    /// otherwise.
+    AddChangeOutput {
+        /// The owner of the first input spending the asset.
+        to: Address,
+        /// The burned asset.
+        asset_id: AssetId,
+    },
+}
+
+impl Repair {
+    /// Applies the fix to the builder of the failed transaction.
+    ///
+    /// Fails if the builder already has the change output added by the repair.
+    pub fn apply(
+        &self,
+        builder: &mut TransactionBuilder<Script>,
+    ) -> Result<(), BuilderError> {
+        match *self {
+            Self::RaiseWitnessLimit { witness_limit } => {
+                builder.witness_limit(witness_limit);
+            }
+            Self::SetMaxFee { max_fee } => {
+                builder.max_fee_limit(max_fee);
+            }
+            Self::AddChangeOutput { to, asset_id } => {
+                if let Some(index) = change_output_index(builder.outputs(), &asset_id) {
+                    return Err(BuilderError::ChangeOutputExists { index, asset_id })
+                }
+                builder.add_output(Output::change(to, 0, asset_id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Suggests the fix of the `err` returned by the validity checks of the `tx`.
+///
+/// Returns `None` if the error has no mechanical fix. The missing `MaxFee` policy is
+/// only fixed if the transaction has a `GasPriceFloor` policy, because otherwise the
+/// fee depends on the gas price of the block.
+pub fn suggest(
+    tx: &Transaction,
+    err: &ValidityError,
+    params: &ConsensusParameters,
+) -> Option<Repair> {
+    match err {
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::WitnessLimit,
+            used,
+            ..
+        } => Some(Repair::RaiseWitnessLimit {
+            witness_limit: *used,
+        }),
+        ValidityError::TransactionMaxFeeNotSet => {
+            let max_fee = match tx {
+                Transaction::Script(tx) => max_fee_at_floor(tx, params),
+                Transaction::Create(tx) => max_fee_at_floor(tx, params),
+                Transaction::Upgrade(tx) => max_fee_at_floor(tx, params),
+                Transaction::Upload(tx) => max_fee_at_floor(tx, params),
+                Transaction::Blob(tx) => max_fee_at_floor(tx, params),
+                Transaction::Mint(_) => None,
+            }?;
+            Some(Repair::SetMaxFee { max_fee })
+        }
+        _ => None,
+    }
+}
+
+/// Suggests the change outputs of the assets spent by the inputs of the `tx` without
+/// a change output, in the order of the inputs.
+///
+/// The change goes to the owner of the first input spending the asset.
+pub fn suggest_for_burned_assets(
+    tx: &Transaction,
+    params: &ConsensusParameters,
+) -> Vec<Repair> {
+    let (inputs, outputs) = match tx {
+        Transaction::Script(tx) => (tx.inputs(), tx.outputs()),
+        Transaction::Create(tx) => (tx.inputs(), tx.outputs()),
+        Transaction::Upgrade(tx) => (tx.inputs(), tx.outputs()),
+        Transaction::Upload(tx) => (tx.inputs(), tx.outputs()),
+        Transaction::Blob(tx) => (tx.inputs(), tx.outputs()),
+        Transaction::Mint(_) => return Vec::new(),
+    };
+    let base_asset_id = params.base_asset_id();
+
+    let mut repairs = Vec::new();
+    for input in inputs {
+        let Some(asset_id) = input.asset_id(base_asset_id) else {
+            continue
+        };
+        let Some(to) = input.input_owner().or(input.recipient()) else {
+            continue
+        };
+        let repair = Repair::AddChangeOutput {
+            to: *to,
+            asset_id: *asset_id,
+        };
+        let is_suggested = repairs.iter().any(|repair| {
+            matches!(repair, Repair::AddChangeOutput { asset_id: suggested, .. } if suggested == asset_id)
+        });
+        if !is_suggested && change_output_index(outputs, asset_id).is_none() {
+            repairs.push(repair);
+        }
+    }
+
+    repairs
+}
+
+fn change_output_index(outputs: &[Output], asset_id: &AssetId) -> Option<usize> {
+    outputs.iter().position(|output| {
+        matches!(output, Output::Change { asset_id: change, .. } if change == asset_id)
+    })
+}
+
+/// The maximum fee at the gas price floor of the `tx` once the `MaxFee` policy is set,
+/// which grows the transaction.
+fn max_fee_at_floor<Tx>(tx: &Tx, params: &ConsensusParameters) -> Option<Word>
+where
+    Tx: Chargeable + Clone,
+{
+    let floor = tx.policies().get(PolicyType::GasPriceFloor)?;
+
+    let mut tx = tx.clone();
+    tx.policies_mut().set(PolicyType::MaxFee, Some(0));
+    tx.max_fee(params.gas_costs(), params.fee_params(), floor)
+        .ok()?
+        .try_into()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        field::{
+            MaxFeeLimit,
+            Policies as _,
+            Witnesses,
+        },
+        policies::PolicyType,
+        Finalizable,
+        FormatValidityChecks,
+        Input,
+        Signable,
+    };
+    use alloc::vec;
+    use fuel_crypto::SecretKey;
+    use fuel_types::AssetId;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn signed_builder(rng: &mut StdRng, key: SecretKey) -> TransactionBuilder<Script> {
+        let mut builder = TransactionBuilder::script(vec![0x10; 8], vec![]);
+        builder
+            .script_gas_limit(1_000)
+            .min_gas_price(3)
+            .add_unsigned_coin_input(
+                key,
+                rng.gen(),
+                1_000_000,
+                AssetId::default(),
+                rng.gen(),
+            );
+        builder
+    }
+
+    /// Repairs the failed `tx` and signs it again with the `key`.
+    fn repair(tx: Transaction, err: &ValidityError, key: SecretKey) -> Script {
+        let params = ConsensusParameters::standard();
+        let repair = suggest(&tx, err, &params).expect("The error has a repair");
+        let tx = tx.as_script().cloned().expect("A script");
+
+        let mut builder = TransactionBuilder::from_tx(tx);
+        repair.apply(&mut builder).expect("The repair applies");
+        builder.add_signing_key(key);
+        builder.finalize()
+    }
+
+    #[test]
+    fn suggest__raises_the_witness_limit_to_the_size_of_the_witnesses() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let tx = signed_builder(rng, key).witness_limit(10).finalize();
+        let err = tx
+            .check(Default::default(), &ConsensusParameters::standard())
+            .expect_err("The witnesses exceed the limit");
+        let tx = Transaction::from(tx);
+
+        // When
+        let repaired = repair(tx, &err, key);
+
+        // Then
+        assert_eq!(repaired.witnesses.len(), 1);
+        assert_eq!(
+            repaired.policies().get(PolicyType::WitnessLimit),
+            Some(repaired.witnesses_size() as Word)
+        );
+        repaired
+            .check(Default::default(), &ConsensusParameters::standard())
+            .expect("The repaired transaction is valid");
+    }
+
+    #[test]
+    fn suggest__sets_the_max_fee_at_the_gas_price_floor() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let params = ConsensusParameters::standard();
+        let mut tx = signed_builder(rng, key).finalize();
+        tx.policies_mut().set(PolicyType::MaxFee, None);
+        tx.sign_inputs(&key, &params.chain_id());
+        let err = tx
+            .check(Default::default(), &params)
+            .expect_err("The max fee is not set");
+        let tx = Transaction::from(tx);
+
+        // When
+        let repaired = repair(tx, &err, key);
+
+        // Then
+        let max_fee_at_floor = repaired
+            .max_fee(params.gas_costs(), params.fee_params(), 3)
+            .expect("The fee parameters are valid");
+        assert_eq!(repaired.max_fee_limit() as u128, max_fee_at_floor);
+        repaired
+            .check(Default::default(), &params)
+            .expect("The repaired transaction is valid");
+    }
+
+    #[test]
+    fn suggest__has_no_max_fee_without_gas_price_floor() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let params = ConsensusParameters::standard();
+        let mut tx = signed_builder(rng, key).finalize();
+        tx.policies_mut().set(PolicyType::MaxFee, None);
+        tx.policies_mut().set(PolicyType::GasPriceFloor, None);
+        tx.sign_inputs(&key, &params.chain_id());
+        let err = tx
+            .check(Default::default(), &params)
+            .expect_err("The max fee is not set");
+
+        // When
+        let repair = suggest(&Transaction::from(tx), &err, &params);
+
+        // Then
+        assert_eq!(err, ValidityError::TransactionMaxFeeNotSet);
+        assert_eq!(repair, None);
+    }
+
+    #[test]
+    fn suggest_for_burned_assets__adds_the_change_outputs_of_the_spent_assets() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let params = ConsensusParameters::standard();
+        let owner = Input::owner(&key.public_key());
+        let other_asset: AssetId = rng.gen();
+        let mut builder = signed_builder(rng, key);
+        builder
+            .add_unsigned_coin_input(key, rng.gen(), 10, other_asset, rng.gen())
+            .add_unsigned_coin_input(key, rng.gen(), 20, other_asset, rng.gen())
+            .add_output(Output::change(rng.gen(), 0, AssetId::default()));
+        let tx = Transaction::from(builder.finalize());
+
+        // When
+        let repairs = suggest_for_burned_assets(&tx, &params);
+
+        // Then
+        assert_eq!(
+            repairs,
+            vec![Repair::AddChangeOutput {
+                to: owner,
+                asset_id: other_asset,
+            }]
+        );
+        let mut builder =
+            TransactionBuilder::from_tx(tx.as_script().cloned().expect("A script"));
+        for repair in &repairs {
+            repair.apply(&mut builder).expect("The repair applies");
+        }
+        builder.add_signing_key(key);
+        let repaired = Transaction::from(builder.finalize());
+        assert_eq!(suggest_for_burned_assets(&repaired, &params), vec![]);
+        repaired
+            .check(Default::default(), &params)
+            .expect("The repaired transaction is valid");
+    }
+
+    #[test]
+    fn apply__fails_if_the_change_output_exists() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let mut builder = signed_builder(rng, key);
+        builder.add_output(Output::change(rng.gen(), 0, AssetId::default()));
+        let repair = Repair::AddChangeOutput {
+            to: rng.gen(),
+            asset_id: AssetId::default(),
+        };
+
+        // When
+        let result = repair.apply(&mut builder);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(BuilderError::ChangeOutputExists {
+                index: 0,
+                asset_id: AssetId::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn suggest__returns_none_for_errors_without_repair() {
+        // Given
+        let params = ConsensusParameters::standard();
+        let tx = Transaction::default();
+        let errors = [
+            ValidityError::TransactionOutputChangeAssetIdNotFound {
+                index: 0,
+                asset_id: AssetId::default(),
+            },
+            ValidityError::BudgetExceeded {
+                budget: BudgetKind::TransactionSize,
+                used: 2,
+                max: 1,
+                index: None,
+            },
+        ];
+
+        for err in errors {
+            // When
+            let repair = suggest(&tx, &err, &params);
+
+            // Then
+            assert_eq!(repair, None, "{err:?}");
+        }
+    }
+}
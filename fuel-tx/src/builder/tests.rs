@@ -0,0 +1,815 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::{
+    field::Inputs,
+    BudgetKind,
+    Finalizable,
+    FormatValidityChecks,
+    UniqueIdentifier,
+};
+use fuel_types::{
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
+    Address,
+    ContractId,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn preview_params() -> ConsensusParameters {
+    let mut params = ConsensusParameters::standard();
+    params.set_fee_params(
+        FeeParameters::DEFAULT
+            .with_gas_price_factor(92)
+            .expect("The gas price factor is not zero"),
+    );
+    params.set_gas_costs(GasCosts::default());
+    params
+}
+
+fn base_script_builder(rng: &mut StdRng) -> TransactionBuilder<Script> {
+    let predicate = vec![0x24; 64];
+    let mut builder = TransactionBuilder::script(vec![0x10; 32], vec![0x11; 16]);
+    builder
+        .with_params(preview_params())
+        .script_gas_limit(1_000)
+        .witness_limit(2_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+        )
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&predicate),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            1_234,
+            predicate,
+            vec![0x42; 24],
+        ))
+        .add_output(Output::change(rng.gen(), 0, AssetId::default()));
+    builder
+}
+
+fn candidate_inputs(rng: &mut StdRng) -> Vec<Input> {
+    let predicate = vec![0x37; 128];
+    vec![
+        // Reuses the witness of the first signed input.
+        Input::coin_signed(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0),
+        // Requires the recovery of a new witness.
+        Input::coin_signed(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen(), 1),
+        Input::coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&predicate),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            500,
+            predicate.clone(),
+            vec![0x01; 3],
+        ),
+        Input::message_coin_signed(rng.gen(), rng.gen(), rng.gen(), rng.gen(), 2),
+        Input::message_data_signed(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            0,
+            vec![0xaa; 77],
+        ),
+        Input::message_coin_predicate(
+            rng.gen(),
+            Input::predicate_owner(&predicate),
+            rng.gen(),
+            rng.gen(),
+            100,
+            predicate.clone(),
+            vec![],
+        ),
+        Input::message_data_predicate(
+            rng.gen(),
+            Input::predicate_owner(&predicate),
+            rng.gen(),
+            rng.gen(),
+            10_000,
+            vec![0xbb; 1_000],
+            predicate,
+            vec![0x02; 9],
+        ),
+        Input::contract(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen()),
+    ]
+}
+
+fn candidate_outputs(rng: &mut StdRng) -> Vec<Output> {
+    vec![
+        Output::coin(rng.gen(), rng.gen(), rng.gen()),
+        Output::contract(0, rng.gen(), rng.gen()),
+        Output::change(rng.gen(), rng.gen(), rng.gen()),
+        Output::variable(rng.gen(), rng.gen(), rng.gen()),
+        Output::contract_created(ContractId::zeroed(), rng.gen()),
+    ]
+}
+
+fn assert_preview_matches<Tx>(
+    before: &TransactionBuilder<Tx>,
+    after: &TransactionBuilder<Tx>,
+    preview: ImpactPreview,
+) where
+    Tx: Buildable + Serialize,
+    TransactionBuilder<Tx>: Finalizable<Tx>,
+{
+    let params = before.get_params();
+    let gas_costs = params.gas_costs();
+    let fee_params = params.fee_params();
+
+    let tx_before = before.finalize_without_signature();
+    let tx_after = after.finalize_without_signature();
+
+    assert_eq!(
+        preview.size_delta,
+        tx_after.size() - tx_before.size(),
+        "size delta"
+    );
+    assert_eq!(
+        preview.max_gas_delta,
+        tx_after.max_gas(gas_costs, fee_params)
+            - tx_before.max_gas(gas_costs, fee_params),
+        "max gas delta"
+    );
+    for gas_price in [0, 1, 7, 1_000, 123_456_789] {
+        let max_fee_before = tx_before
+            .max_fee(gas_costs, fee_params, gas_price)
+            .expect("The fee parameters are valid");
+        let max_fee_after = tx_after
+            .max_fee(gas_costs, fee_params, gas_price)
+            .expect("The fee parameters are valid");
+        assert_eq!(
+            preview
+                .max_fee_delta(gas_price)
+                .expect("The fee parameters are valid") as u128,
+            max_fee_after - max_fee_before,
+            "max fee delta for gas price {gas_price}"
+        );
+    }
+}
+
+#[test]
+fn preview_add_input__matches_script_with_added_input() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let builder = base_script_builder(rng);
+
+    for input in candidate_inputs(rng) {
+        // When
+        let preview = builder.preview_add_input(&input);
+
+        // Then
+        let mut after = builder.clone();
+        after.add_input(input.clone());
+        assert_preview_matches(&builder, &after, preview);
+    }
+}
+
+#[test]
+fn preview_add_output__matches_script_with_added_output() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let builder = base_script_builder(rng);
+
+    for output in candidate_outputs(rng) {
+        // When
+        let preview = builder.preview_add_output(&output);
+
+        // Then
+        let mut after = builder.clone();
+        after.add_output(output);
+        assert_preview_matches(&builder, &after, preview);
+    }
+}
+
+#[test]
+fn preview_add_input__matches_create_with_added_input() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut builder =
+        TransactionBuilder::create(vec![0x50; 256].into(), rng.gen(), vec![rng.gen()]);
+    builder
+        .with_params(preview_params())
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            rng.gen(),
+            AssetId::default(),
+            rng.gen(),
+        );
+
+    for input in candidate_inputs(rng) {
+        // When
+        let preview = builder.preview_add_input(&input);
+
+        // Then
+        let mut after = builder.clone();
+        after.add_input(input.clone());
+        assert_preview_matches(&builder, &after, preview);
+    }
+
+    for output in candidate_outputs(rng) {
+        // When
+        let preview = builder.preview_add_output(&output);
+
+        // Then
+        let mut after = builder.clone();
+        after.add_output(output);
+        assert_preview_matches(&builder, &after, preview);
+    }
+}
+
+#[test]
+fn preview_add_input__does_not_modify_builder() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let builder = base_script_builder(rng);
+    let tx_before = builder.finalize_without_signature();
+
+    // When
+    let _ = builder.preview_add_input(&Input::coin_signed(
+        rng.gen(),
+        Address::zeroed(),
+        1,
+        AssetId::default(),
+        rng.gen(),
+        0,
+    ));
+    let _ = builder.preview_add_output(&Output::coin(rng.gen(), 1, AssetId::default()));
+
+    // Then
+    assert_eq!(tx_before, builder.finalize_without_signature());
+}
+
+#[test]
+fn create__pads_bytecode_to_whole_instructions() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    for len in 0..=9 {
+        let bytecode = vec![0xfa; len];
+        let padded_len = Contract::padded_bytecode_len(len);
+
+        // When
+        let tx = TransactionBuilder::create(bytecode.clone().into(), rng.gen(), vec![])
+            .add_random_fee_input()
+            .finalize();
+
+        // Then
+        assert_eq!(padded_len, len.div_ceil(4) * 4, "bytecode of {len} bytes");
+        let witness = tx.witnesses()[*tx.bytecode_witness_index() as usize].as_ref();
+        assert_eq!(witness.len(), padded_len);
+        assert_eq!(&witness[..len], bytecode.as_slice());
+        assert!(witness[len..].iter().all(|byte| *byte == 0));
+        assert_eq!(*tx.bytecode_length() * 4, padded_len as Word);
+        tx.check_without_signatures(Default::default(), &ConsensusParameters::standard())
+            .expect("The bytecode length matches the witness");
+    }
+}
+
+#[test]
+fn set_bytecode__replaces_and_pads_bytecode() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut builder = TransactionBuilder::create(vec![0xfa; 8].into(), rng.gen(), vec![]);
+
+    for len in 0..=9 {
+        // When
+        builder.set_bytecode(vec![0xfb; len].into());
+
+        // Then
+        let tx = builder.finalize_without_signature();
+        let padded_len = Contract::padded_bytecode_len(len);
+        assert_eq!(tx.witnesses().len(), 1, "bytecode of {len} bytes");
+        assert_eq!(tx.witnesses()[0].as_ref().len(), padded_len);
+        assert_eq!(*tx.bytecode_length() * 4, padded_len as Word);
+    }
+}
+
+fn signing_builder(rng: &mut StdRng, keys: &[SecretKey]) -> TransactionBuilder<Script> {
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.strict(true);
+    for key in keys {
+        builder.add_unsigned_coin_input(
+            *key,
+            rng.gen(),
+            rng.gen(),
+            AssetId::default(),
+            rng.gen(),
+        );
+    }
+    builder
+}
+
+#[test]
+fn try_finalize__strict__rejects_swapped_keys() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key_a = SecretKey::random(rng);
+    let key_b = SecretKey::random(rng);
+    let owner_a = Input::owner(&key_a.public_key());
+    let owner_b = Input::owner(&key_b.public_key());
+
+    // Given
+    let mut builder = signing_builder(rng, &[key_a, key_b]);
+    let witness_a = builder.inputs()[0].witness_index().unwrap();
+    builder.add_input(Input::coin_signed(
+        rng.gen(),
+        owner_b,
+        rng.gen(),
+        AssetId::default(),
+        rng.gen(),
+        witness_a,
+    ));
+
+    // When
+    let result = builder.try_finalize();
+
+    // Then
+    let mut expected_owners = vec![owner_a, owner_b];
+    expected_owners.sort();
+    assert_eq!(
+        result,
+        Err(BuilderError::KeyOwnerMismatch {
+            public_key: key_a.public_key(),
+            expected_owners,
+        })
+    );
+}
+
+#[test]
+fn try_finalize__non_strict__keeps_swapped_keys() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key_a = SecretKey::random(rng);
+    let key_b = SecretKey::random(rng);
+
+    // Given
+    let mut builder = signing_builder(rng, &[key_a]);
+    builder.strict(false).add_input(Input::coin_signed(
+        rng.gen(),
+        Input::owner(&key_b.public_key()),
+        rng.gen(),
+        AssetId::default(),
+        rng.gen(),
+        0,
+    ));
+
+    // When
+    let result = builder.try_finalize();
+
+    // Then
+    assert_eq!(result, Ok(builder.finalize()));
+}
+
+#[test]
+#[should_panic(expected = "The strict checks of the builder failed")]
+fn finalize__strict__panics_on_swapped_keys() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key_a = SecretKey::random(rng);
+    let key_b = SecretKey::random(rng);
+
+    // Given
+    let mut builder = signing_builder(rng, &[key_a]);
+    builder.add_input(Input::coin_signed(
+        rng.gen(),
+        Input::owner(&key_b.public_key()),
+        rng.gen(),
+        AssetId::default(),
+        rng.gen(),
+        0,
+    ));
+
+    // When
+    let _ = builder.finalize();
+}
+
+#[test]
+fn finalize__non_strict__keeps_swapped_keys() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key_a = SecretKey::random(rng);
+    let key_b = SecretKey::random(rng);
+
+    // Given
+    let mut builder = signing_builder(rng, &[key_a]);
+    builder.strict(false).add_input(Input::coin_signed(
+        rng.gen(),
+        Input::owner(&key_b.public_key()),
+        rng.gen(),
+        AssetId::default(),
+        rng.gen(),
+        0,
+    ));
+
+    // When
+    let tx = builder.finalize();
+
+    // Then
+    assert_eq!(tx.inputs().len(), 2);
+    assert_eq!(tx.witnesses().len(), 1);
+}
+
+#[test]
+fn try_finalize__strict__accepts_externally_signed_inputs() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key = SecretKey::random(rng);
+    let external_key = SecretKey::random(rng);
+
+    // Given
+    let mut builder = signing_builder(rng, &[key]);
+    builder.add_input(Input::coin_signed(
+        rng.gen(),
+        Input::owner(&external_key.public_key()),
+        rng.gen(),
+        AssetId::default(),
+        rng.gen(),
+        1,
+    ));
+    let id = builder
+        .finalize_without_signature()
+        .id(&builder.get_chain_id());
+    let signature = fuel_crypto::Signature::sign(
+        &external_key,
+        &fuel_crypto::Message::from_bytes(*id),
+    );
+    builder.add_witness(signature.as_ref().into());
+
+    // When
+    let tx = builder.try_finalize().expect("The inputs are signed");
+
+    // Then
+    tx.check(Default::default(), builder.get_params())
+        .expect("The signatures are valid");
+}
+
+#[test]
+fn try_finalize__strict__rejects_inputs_without_signature() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key = SecretKey::random(rng);
+    let owner = rng.gen();
+
+    // Given
+    let mut builder = signing_builder(rng, &[key]);
+    builder
+        .add_input(Input::coin_signed(
+            rng.gen(),
+            owner,
+            rng.gen(),
+            AssetId::default(),
+            rng.gen(),
+            1,
+        ))
+        .add_witness(Witness::default());
+
+    // When
+    let result = builder.try_finalize();
+
+    // Then
+    assert_eq!(
+        result,
+        Err(BuilderError::MissingSignature { index: 1, owner })
+    );
+}
+
+#[test]
+fn reserve_witness_slot__signing_key_skips_reserved_slot() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key = SecretKey::random(rng);
+    let predicate_data = Witness::from(vec![0x42; 24]);
+
+    // Given
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder
+        .reserve_witness_slot(0)
+        .expect("The slot is not used");
+    builder.add_unsigned_coin_input(
+        key,
+        rng.gen(),
+        rng.gen(),
+        AssetId::default(),
+        rng.gen(),
+    );
+    builder.set_witness(0, predicate_data.clone());
+
+    // When
+    let tx = builder.finalize();
+
+    // Then
+    assert_eq!(tx.inputs()[0].witness_index(), Some(1));
+    assert_eq!(tx.witnesses().len(), 2);
+    assert_eq!(tx.witnesses()[0], predicate_data);
+    tx.check(Default::default(), builder.get_params())
+        .expect("The signature is placed in the slot of the key");
+}
+
+#[test]
+fn reserve_witness_slot__rejects_slot_of_signing_key() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key = SecretKey::random(rng);
+
+    // Given
+    let mut builder = signing_builder(rng, &[key]);
+
+    // When
+    let result = builder.reserve_witness_slot(0);
+
+    // Then
+    assert_eq!(result, Err(BuilderError::WitnessSlotInUse { index: 0 }));
+}
+
+fn strict_output_builder() -> TransactionBuilder<Script> {
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.strict(true);
+    builder
+}
+
+#[test]
+fn try_add_output__strict__rejects_zero_amount_coin() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let mut builder = strict_output_builder();
+    builder.add_output(Output::coin(rng.gen(), 10, rng.gen()));
+
+    // When
+    let result = builder
+        .try_add_output(Output::coin(rng.gen(), 0, rng.gen()))
+        .map(|_| ());
+
+    // Then
+    assert_eq!(result, Err(BuilderError::ZeroAmountCoinOutput { index: 1 }));
+    assert_eq!(builder.outputs().len(), 1);
+}
+
+#[test]
+fn try_add_output__strict__rejects_duplicate_recipient() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let to: Address = rng.gen();
+    let asset_id: AssetId = rng.gen();
+
+    // Given
+    let mut builder = strict_output_builder();
+    builder
+        .add_output(Output::coin(to, 10, asset_id))
+        .add_output(Output::coin(to, 10, rng.gen()));
+
+    // When
+    let result = builder
+        .try_add_output(Output::coin(to, 20, asset_id))
+        .map(|_| ());
+
+    // Then
+    assert_eq!(
+        result,
+        Err(BuilderError::DuplicateCoinRecipient {
+            existing_index: 0,
+            index: 2,
+        })
+    );
+}
+
+#[test]
+fn try_add_output__strict__accepts_duplicate_recipient_when_allowed() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let to: Address = rng.gen();
+    let asset_id: AssetId = rng.gen();
+
+    // Given
+    let mut builder = strict_output_builder();
+    builder
+        .allow_duplicate_recipients(true)
+        .add_output(Output::coin(to, 10, asset_id));
+
+    // When
+    let result = builder
+        .try_add_output(Output::coin(to, 20, asset_id))
+        .map(|_| ());
+
+    // Then
+    assert_eq!(result, Ok(()));
+    assert_eq!(builder.outputs().len(), 2);
+}
+
+#[test]
+fn try_add_output__strict__exempts_change_and_variable_outputs() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let to: Address = rng.gen();
+    let asset_id: AssetId = rng.gen();
+
+    // Given
+    let mut builder = strict_output_builder();
+    builder.add_output(Output::coin(to, 10, asset_id));
+
+    // When
+    let result = builder
+        .try_add_output(Output::change(to, 0, asset_id))
+        .and_then(|builder| builder.try_add_output(Output::variable(to, 0, asset_id)))
+        .and_then(|builder| builder.try_add_output(Output::change(to, 0, asset_id)))
+        .map(|_| ());
+
+    // Then
+    assert_eq!(result, Ok(()));
+    assert_eq!(builder.outputs().len(), 4);
+}
+
+#[test]
+fn try_add_output__non_strict__accepts_zero_amount_and_duplicates() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let to: Address = rng.gen();
+    let asset_id: AssetId = rng.gen();
+
+    // Given
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.add_output(Output::coin(to, 10, asset_id));
+
+    // When
+    let result = builder
+        .try_add_output(Output::coin(to, 0, asset_id))
+        .map(|_| ());
+
+    // Then
+    assert_eq!(result, Ok(()));
+    assert_eq!(builder.outputs().len(), 2);
+}
+
+#[test]
+fn min_gas_price__is_committed_in_the_id_and_encoding() {
+    let chain_id = ChainId::default();
+
+    // Given
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(100);
+    let without_floor = builder.finalize();
+
+    // When
+    let with_floor = builder.min_gas_price(7).finalize();
+
+    // Then
+    assert_eq!(with_floor.gas_price_floor(), 7);
+    assert_ne!(with_floor.id(&chain_id), without_floor.id(&chain_id));
+    let decoded = <Script as Deserialize>::from_bytes(&with_floor.to_bytes())
+        .expect("Valid canonical encoding");
+    assert_eq!(decoded, with_floor);
+}
+
+#[test]
+fn add_signing_key__reuses_the_witness_of_a_rebuilt_transaction() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let key = SecretKey::random(rng);
+
+    // Given
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(100).add_unsigned_coin_input(
+        key,
+        rng.gen(),
+        1_000,
+        AssetId::default(),
+        rng.gen(),
+    );
+    let tx = builder.finalize();
+    let mut builder = TransactionBuilder::from_tx(tx);
+    builder.tip(1);
+
+    // When
+    let tx = builder.add_signing_key(key).finalize();
+
+    // Then
+    assert_eq!(tx.witnesses().len(), 1);
+    tx.check(Default::default(), builder.get_params())
+        .expect("The input is signed again");
+}
+
+#[test]
+fn with_privileged_address__roundtrips() {
+    // Given
+    let privileged_address = Address::new([0xbb; 32]);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+
+    // When
+    builder.with_privileged_address(privileged_address);
+
+    // Then
+    assert_eq!(
+        builder.get_params().privileged_address(),
+        &privileged_address
+    );
+}
+
+#[test]
+fn add_contract_created_output__passes_create_validity() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let storage_slots = (0..10).map(|_| rng.gen()).collect();
+    let mut builder =
+        TransactionBuilder::create(vec![0xfa; 8].into(), rng.gen(), storage_slots);
+    builder.add_random_fee_input();
+
+    // When
+    let tx = builder.add_contract_created_output().finalize();
+
+    // Then
+    tx.check(Default::default(), builder.get_params())
+        .expect("The output matches the contract");
+}
+
+#[test]
+fn add_predicate_coin_input__adds_the_input_owned_by_the_predicate() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let predicate = vec![0x24; 64];
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+
+    // When
+    builder
+        .add_predicate_coin_input(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            predicate.clone(),
+            vec![0x25; 16],
+        )
+        .expect("The predicate is valid");
+
+    // Then
+    let input = &builder.inputs()[0];
+    assert!(input.is_coin_predicate());
+    assert_eq!(
+        input.input_owner(),
+        Some(&Input::predicate_owner(&predicate))
+    );
+}
+
+#[test]
+fn add_predicate_message_input__picks_the_message_kind_from_the_data() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+
+    // When
+    builder
+        .add_predicate_message_input(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            vec![],
+            vec![0x24; 8],
+            vec![],
+        )
+        .expect("The predicate is valid")
+        .add_predicate_message_input(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            vec![0x26; 4],
+            vec![0x24; 8],
+            vec![],
+        )
+        .expect("The predicate is valid");
+
+    // Then
+    assert!(builder.inputs()[0].is_message_coin_predicate());
+    assert!(builder.inputs()[1].is_message_data_predicate());
+}
+
+#[test]
+fn add_predicate_coin_input__rejects_predicate_above_the_builder_limit() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.with_predicate_params(
+        PredicateParameters::DEFAULT.with_max_predicate_length(64),
+    );
+
+    // When
+    let result = builder
+        .add_predicate_coin_input(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            vec![0x24; 72],
+            vec![],
+        )
+        .map(|_| ());
+
+    // Then
+    assert_eq!(
+        result,
+        Err(ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateLength,
+            used: 72,
+            max: 64,
+            index: None,
+        })
+    );
+    assert!(builder.inputs().is_empty());
+}
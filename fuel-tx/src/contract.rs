@@ -5,6 +5,7 @@ use crate::{
 };
 
 use derivative::Derivative;
+use fuel_asm::Instruction;
 use fuel_crypto::Hasher;
 use fuel_merkle::{
     binary::root_calculator::MerkleRootCalculator as BinaryMerkleTree,
@@ -35,7 +36,7 @@ const PADDING_BYTE: u8 = 0u8;
 const MULTIPLE: usize = 8;
 
 /// See https://stackoverflow.com/a/9194117
-fn next_multiple<const N: usize>(x: usize) -> usize {
+const fn next_multiple<const N: usize>(x: usize) -> usize {
     x + (N - x % N) % N
 }
 
@@ -66,6 +67,12 @@ impl Contract {
         self.0.is_empty()
     }
 
+    /// Number of bytes of a bytecode of `len` bytes once zero-padded to a whole number
+    /// of instructions, as expected by the `bytecode_length` of a `Create`.
+    pub const fn padded_bytecode_len(len: usize) -> usize {
+        next_multiple::<{ Instruction::SIZE }>(len)
+    }
+
     /// Calculate the code root of the contract, using [`Self::root_from_code`].
     pub fn root(&self) -> Bytes32 {
         Self::root_from_code(self)
@@ -97,7 +104,11 @@ impl Contract {
         tree.root().into()
     }
 
-    /// Calculate the root of the initial storage slots for this contract
+    /// Calculate the root of the initial storage slots for this contract.
+    ///
+    /// The slots are sorted by key and the last value of a duplicated key wins, so the
+    /// order of the slots doesn't affect the root. It is the state root expected by the
+    /// `ContractCreated` output of a `Create` transaction.
     pub fn initial_state_root<'a, I>(storage_slots: I) -> Bytes32
     where
         I: Iterator<Item = &'a StorageSlot>,
@@ -247,6 +258,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn initial_state_root_matches_known_root() {
+        // Given
+        let slots: Vec<StorageSlot> = [
+            ([0xff; 32], [0x04; 32]),
+            ([0x01; 32], [0x02; 32]),
+            ([0x03; 32], [0x00; 32]),
+        ]
+        .into_iter()
+        .map(|(key, value)| (Bytes32::new(key), Bytes32::new(value)).into())
+        .collect();
+
+        // When
+        let root = Contract::initial_state_root(slots.iter());
+        let reversed_root = Contract::initial_state_root(slots.iter().rev());
+
+        // Then
+        let expected_root: Bytes32 = Bytes32::new([
+            0xb9, 0xc7, 0xd3, 0x7c, 0x9c, 0x54, 0x5a, 0x0e, 0x1c, 0x41, 0xa1, 0x5f, 0xbf,
+            0x9a, 0x5a, 0x61, 0x09, 0x91, 0x49, 0x5c, 0xde, 0x48, 0x4b, 0x0e, 0x39, 0xd3,
+            0x5a, 0x36, 0xe0, 0x54, 0xe5, 0x2f,
+        ]);
+        assert_eq!(root, expected_root);
+        assert_eq!(reversed_root, expected_root);
+    }
+
+    #[test]
+    fn initial_state_root_of_no_slots_is_default_root() {
+        // When
+        let root = Contract::initial_state_root(iter::empty());
+
+        // Then
+        assert_eq!(root, Contract::default_state_root());
+        assert_eq!(root, Bytes32::zeroed());
+    }
+
     #[test]
     fn default_state_root_snapshot() {
         let default_root = Contract::default_state_root();
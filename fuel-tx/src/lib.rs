@@ -6,7 +6,8 @@
 #![allow(clippy::wrong_self_convention)]
 #![deny(clippy::cast_possible_truncation)]
 #![deny(clippy::string_slice)]
-#![deny(unused_crate_dependencies)]
+// The dev-dependencies of the benches are unused by the unit tests
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
 #![deny(unsafe_code)]
 
 // TODO: Add docs
@@ -25,6 +26,7 @@ pub use fuel_asm::{
 pub use fuel_types::{
     Address,
     AssetId,
+    BlobId,
     Bytes32,
     Bytes4,
     Bytes64,
@@ -34,7 +36,10 @@ pub use fuel_types::{
     Salt,
     Word,
 };
-pub use tx_pointer::TxPointer;
+pub use tx_pointer::{
+    TxPointer,
+    TxPointerParseError,
+};
 
 #[cfg(feature = "builder")]
 mod builder;
@@ -51,16 +56,25 @@ mod transaction;
 #[cfg(test)]
 mod tests;
 
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod test_helper;
+
 #[cfg(feature = "builder")]
 pub use builder::{
+    repair,
+    BatchBuilder,
     Buildable,
+    BuilderError,
     Finalizable,
+    ImpactPreview,
     TransactionBuilder,
 };
 
 #[cfg(feature = "alloc")]
 pub use receipt::{
+    receipts_root,
     Receipt,
+    ReceiptsExt,
     ScriptExecutionResult,
 };
 
@@ -72,33 +86,63 @@ pub use transaction::{
     input::InputRepr,
     output,
     output::Output,
+    output::OutputNotFound,
     output::OutputRepr,
     policies,
+    Blob,
+    BlobBody,
+    BlobIdExt,
+    BudgetKind,
     Cacheable,
     Chargeable,
     ConsensusParameters,
+    ConsensusParametersBuilder,
+    ConsensusParametersError,
+    ConsensusParametersV1,
     ContractParameters,
     Create,
+    DecodeError,
     DependentCost,
+    EncodingVersion,
     Executable,
+    FeeBreakdown,
     FeeParameters,
+    FeeParametersError,
     FormatValidityChecks,
+    GasCostDelta,
+    GasCostOverride,
+    GasCostOverrideError,
     GasCosts,
+    GasCostsOverrides,
     GasCostsValues,
+    GasMetadata,
     GasUnit,
+    InputSignatureError,
+    InstructionCostError,
     Mint,
     PredicateParameters,
     Script,
     ScriptParameters,
+    SignatureError,
+    SplitError,
     StorageSlot,
     Transaction,
     TransactionFee,
     TransactionRepr,
+    TransactionType,
     TxId,
     TxParameters,
+    Upgrade,
+    UpgradePurpose,
+    Upload,
+    UploadSubsection,
     UtxoId,
+    UtxoIdParseError,
     ValidityError,
     Witness,
+    WrongTransactionType,
+    FRAMED_TX_HEADER_SIZE,
+    FRAMED_TX_MAGIC,
 };
 
 pub use transaction::{
@@ -106,6 +150,9 @@ pub use transaction::{
     UniqueIdentifier,
 };
 
+#[cfg(feature = "serde")]
+pub use transaction::JsonConfigError;
+
 #[cfg(feature = "alloc")]
 #[allow(deprecated)]
 pub use transaction::consensus_parameters::default_parameters;
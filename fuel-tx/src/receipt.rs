@@ -3,6 +3,7 @@ use alloc::vec::Vec;
 use derivative::Derivative;
 use fuel_asm::PanicInstruction;
 use fuel_crypto::Hasher;
+use fuel_merkle::binary::root_calculator::MerkleRootCalculator;
 use fuel_types::{
     canonical::{
         Deserialize,
@@ -18,21 +19,43 @@ use fuel_types::{
     Word,
 };
 
+mod query;
 mod receipt_repr;
 mod script_result;
+#[cfg(all(test, feature = "serde"))]
+mod snapshot_tests;
 
 use crate::input::message::compute_message_id;
+pub use query::ReceiptsExt;
 pub use script_result::ScriptExecutionResult;
 
+/// In the human-readable serde formats, the variants are named in the screaming
+/// snake case, like `RETURN_DATA`, and the ids and the byte payloads are `0x`-prefixed
+/// hex strings. The previous variant names, the ids without the prefix and the
+/// payloads as sequences of bytes are still accepted, and a missing payload is `None`.
 #[derive(Clone, Derivative)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Deserialize, Serialize)]
 #[derivative(Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum Receipt {
+    #[cfg_attr(feature = "serde", serde(alias = "Call"))]
     Call {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         to: ContractId,
         amount: Word,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         asset_id: AssetId,
         gas: Word,
         param1: Word,
@@ -41,44 +64,83 @@ pub enum Receipt {
         is: Word,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "Return"))]
     Return {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
         val: Word,
         pc: Word,
         is: Word,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "ReturnData"))]
     ReturnData {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
         ptr: Word,
         len: Word,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         digest: Bytes32,
         pc: Word,
         is: Word,
         #[derivative(Debug(format_with = "fmt_option_truncated_hex::<16>"))]
         #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[canonical(skip)]
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, with = "crate::transaction::hex_bytes::option")
+        )]
         data: Option<Vec<u8>>,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "Panic"))]
     Panic {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
         reason: PanicInstruction,
         pc: Word,
         is: Word,
         #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[canonical(skip)]
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                serialize_with = "crate::transaction::hex_bytes::id::option::serialize"
+            )
+        )]
         contract_id: Option<ContractId>,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "Revert"))]
     Revert {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
         ra: Word,
         pc: Word,
         is: Word,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "Log"))]
     Log {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
         ra: Word,
         rb: Word,
@@ -88,65 +150,150 @@ pub enum Receipt {
         is: Word,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "LogData"))]
     LogData {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
         ra: Word,
         rb: Word,
         ptr: Word,
         len: Word,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         digest: Bytes32,
         pc: Word,
         is: Word,
         #[derivative(Debug(format_with = "fmt_option_truncated_hex::<16>"))]
         #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[canonical(skip)]
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, with = "crate::transaction::hex_bytes::option")
+        )]
         data: Option<Vec<u8>>,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "Transfer"))]
     Transfer {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         to: ContractId,
         amount: Word,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         asset_id: AssetId,
         pc: Word,
         is: Word,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "TransferOut"))]
     TransferOut {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         id: ContractId,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         to: Address,
         amount: Word,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         asset_id: AssetId,
         pc: Word,
         is: Word,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "ScriptResult"))]
     ScriptResult {
         result: ScriptExecutionResult,
         gas_used: Word,
     },
 
+    #[cfg_attr(feature = "serde", serde(alias = "MessageOut"))]
     MessageOut {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         sender: Address,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         recipient: Address,
         amount: Word,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         nonce: Nonce,
         len: Word,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         digest: Bytes32,
         #[derivative(Debug(format_with = "fmt_option_truncated_hex::<16>"))]
         #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[canonical(skip)]
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, with = "crate::transaction::hex_bytes::option")
+        )]
         data: Option<Vec<u8>>,
     },
+    /// Emitted by `MINT` before the balance of the contract is increased. Like the
+    /// other receipts, it is kept if the execution reverts or panics afterwards,
+    /// while the balance change is reverted.
+    #[cfg_attr(feature = "serde", serde(alias = "Mint"))]
     Mint {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         sub_id: Bytes32,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         contract_id: ContractId,
         val: Word,
         pc: Word,
         is: Word,
     },
+    /// Emitted by `BURN` before the balance of the contract is decreased. Like the
+    /// other receipts, it is kept if the execution reverts or panics afterwards,
+    /// while the balance change is reverted.
+    #[cfg_attr(feature = "serde", serde(alias = "Burn"))]
     Burn {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         sub_id: Bytes32,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::transaction::hex_bytes::id::serialize")
+        )]
         contract_id: ContractId,
         val: Word,
         pc: Word,
@@ -154,6 +301,23 @@ pub enum Receipt {
     },
 }
 
+/// Computes the root of the binary Merkle tree with the canonical bytes of the
+/// `receipts` as leaves, the `receipts_root` of the script that produced them.
+///
+/// The root of no receipts is
+/// [`Script::EMPTY_RECEIPTS_ROOT`](crate::Script::EMPTY_RECEIPTS_ROOT).
+pub fn receipts_root(receipts: &[Receipt]) -> Bytes32 {
+    if receipts.is_empty() {
+        return crate::Script::EMPTY_RECEIPTS_ROOT
+    }
+
+    let mut tree = MerkleRootCalculator::new();
+    for receipt in receipts {
+        tree.push(receipt.to_bytes().as_slice());
+    }
+    tree.root().into()
+}
+
 impl Receipt {
     pub const fn call(
         id: ContractId,
@@ -657,6 +821,9 @@ impl Receipt {
         }
     }
 
+    /// The id of the message sent by a `MessageOut` receipt, hashed the same way
+    /// as the id of a message input. `None` for the other receipts, or if the
+    /// data of the message isn't available.
     pub fn message_id(&self) -> Option<MessageId> {
         match self {
             Self::MessageOut {
@@ -715,7 +882,17 @@ fn trim_contract_id(id: Option<&ContractId>) -> Option<&ContractId> {
 #[cfg(test)]
 mod tests {
     use crate::Receipt;
-    use fuel_types::ContractId;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use fuel_types::{
+        Address,
+        ContractId,
+        MessageId,
+        Nonce,
+        Word,
+    };
 
     // TODO: Rewrite the test cases when `Receipt` will have its struct for
     //  each variant. It will allow to use `Default` trait.
@@ -813,4 +990,39 @@ mod tests {
     fn receipt_to(#[case] receipt: Receipt, #[case] expected_to: Option<ContractId>) {
         assert_eq!(receipt.to(), expected_to.as_ref());
     }
+
+    // The same message as the known ids of the message inputs.
+    const SENDER: Address = Address::new([1u8; 32]);
+    const RECIPIENT: Address = Address::new([2u8; 32]);
+    const NONCE: Nonce = Nonce::new([3u8; 32]);
+    const AMOUNT: Word = 4;
+
+    fn message_out(data: Option<Vec<u8>>) -> Receipt {
+        Receipt::message_out_with_len(
+            SENDER,
+            RECIPIENT,
+            AMOUNT,
+            NONCE,
+            data.as_ref().map_or(0, |data| data.len() as Word),
+            Default::default(),
+            data,
+        )
+    }
+
+    #[rstest::rstest]
+    #[case(
+        message_out(Some(vec![5, 6, 7])),
+        Some("c2ea5ddb04288a066432b790c6efeb2e3793a40145c460fc859ada2ba5cf80f5")
+    )]
+    #[case(
+        message_out(Some(vec![])),
+        Some("67739d8c13a69646d3ce1a59cba24ba008d6ca10f9af265bff8079edad30f449")
+    )]
+    #[case(message_out(None), None)]
+    #[case(Receipt::ret(ContractId::from([1; 32]), 0, 0, 0), None)]
+    fn receipt_message_id(#[case] receipt: Receipt, #[case] expected_id: Option<&str>) {
+        let expected_id =
+            expected_id.map(|id| id.parse::<MessageId>().expect("The id is a valid hex"));
+        assert_eq!(receipt.message_id(), expected_id);
+    }
 }
@@ -0,0 +1,196 @@
+use crate::{
+    Receipt,
+    ScriptExecutionResult,
+};
+use fuel_asm::PanicReason;
+use fuel_types::{
+    ContractId,
+    Word,
+};
+
+/// Queries over the receipts of a transaction.
+pub trait ReceiptsExt {
+    /// The result of the script and the gas it used, from its `ScriptResult`
+    /// receipt.
+    fn script_result(&self) -> Option<(ScriptExecutionResult, Word)>;
+
+    /// The data of the last `ReturnData` receipt of the script, whose id is zero, the
+    /// data returned by the script if it returned with `RETD`. The `ReturnData`
+    /// receipts of the called contracts are ignored. `None` if the data isn't
+    /// available.
+    fn return_data(&self) -> Option<&[u8]>;
+
+    /// The `Log` and `LogData` receipts.
+    fn logs(&self) -> impl Iterator<Item = &Receipt>;
+
+    /// The receipts emitted by the contract `id`.
+    fn for_contract(&self, id: ContractId) -> impl Iterator<Item = &Receipt>;
+
+    /// The reason of the `Panic` receipt.
+    fn panic_reason(&self) -> Option<PanicReason>;
+}
+
+impl ReceiptsExt for [Receipt] {
+    fn script_result(&self) -> Option<(ScriptExecutionResult, Word)> {
+        self.iter().rev().find_map(|receipt| match receipt {
+            Receipt::ScriptResult { result, gas_used } => Some((*result, *gas_used)),
+            _ => None,
+        })
+    }
+
+    fn return_data(&self) -> Option<&[u8]> {
+        self.iter()
+            .rev()
+            .find(|receipt| {
+                matches!(receipt, Receipt::ReturnData { id, .. } if *id == ContractId::zeroed())
+            })
+            .and_then(Receipt::data)
+    }
+
+    fn logs(&self) -> impl Iterator<Item = &Receipt> {
+        self.iter().filter(|receipt| {
+            matches!(receipt, Receipt::Log { .. } | Receipt::LogData { .. })
+        })
+    }
+
+    fn for_contract(&self, id: ContractId) -> impl Iterator<Item = &Receipt> {
+        self.iter().filter(move |receipt| receipt.id() == Some(&id))
+    }
+
+    fn panic_reason(&self) -> Option<PanicReason> {
+        self.iter().find_map(|receipt| match receipt {
+            Receipt::Panic { reason, .. } => Some(*reason.reason()),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use alloc::{
+        vec,
+        vec::Vec,
+    };
+    use fuel_asm::{
+        op,
+        PanicInstruction,
+    };
+    use fuel_types::{
+        Address,
+        Bytes32,
+    };
+
+    const SCRIPT: ContractId = ContractId::zeroed();
+    const CONTRACT: ContractId = ContractId::new([1; 32]);
+    const OTHER: ContractId = ContractId::new([2; 32]);
+
+    fn panic_reason() -> PanicInstruction {
+        PanicInstruction::error(PanicReason::NotEnoughBalance, op::noop().into())
+    }
+
+    /// One receipt of every variant, in a plausible execution order.
+    fn all_receipts() -> Vec<Receipt> {
+        vec![
+            Receipt::call(SCRIPT, CONTRACT, 0, Default::default(), 10, 0, 0, 0, 0),
+            Receipt::log(CONTRACT, 1, 0, 0, 0, 0, 0),
+            Receipt::log_data(CONTRACT, 0, 0, 0, 0, 0, vec![1, 2]),
+            Receipt::log(OTHER, 2, 0, 0, 0, 0, 0),
+            Receipt::mint(Bytes32::zeroed(), CONTRACT, 5, 0, 0),
+            Receipt::burn(Bytes32::zeroed(), CONTRACT, 5, 0, 0),
+            Receipt::transfer(CONTRACT, OTHER, 1, Default::default(), 0, 0),
+            Receipt::transfer_out(
+                CONTRACT,
+                Address::zeroed(),
+                1,
+                Default::default(),
+                0,
+                0,
+            ),
+            Receipt::message_out(
+                &Bytes32::zeroed(),
+                0,
+                Address::zeroed(),
+                Address::zeroed(),
+                0,
+                vec![],
+            ),
+            Receipt::return_data(CONTRACT, 0, 0, 0, vec![3]),
+            Receipt::ret(CONTRACT, 0, 0, 0),
+            Receipt::revert(CONTRACT, 0, 0, 0),
+            Receipt::panic(CONTRACT, panic_reason(), 0, 0),
+            Receipt::return_data(SCRIPT, 0, 0, 0, vec![4, 5]),
+            Receipt::script_result(ScriptExecutionResult::Panic, 42),
+        ]
+    }
+
+    #[test]
+    fn script_result__returns_the_result_and_the_gas_used() {
+        assert_eq!(
+            all_receipts().script_result(),
+            Some((ScriptExecutionResult::Panic, 42))
+        );
+    }
+
+    #[test]
+    fn return_data__returns_the_data_of_the_last_return_data() {
+        assert_eq!(all_receipts().return_data(), Some(&[4, 5][..]));
+    }
+
+    #[test]
+    fn return_data__ignores_the_return_data_of_contracts() {
+        // Given
+        let mut receipts = all_receipts();
+        receipts.retain(
+            |receipt| !matches!(receipt, Receipt::ReturnData { id, .. } if *id == SCRIPT),
+        );
+
+        // When
+        let data = receipts.return_data();
+
+        // Then
+        assert_eq!(data, None);
+    }
+
+    #[test]
+    fn logs__returns_the_log_and_log_data_receipts() {
+        let receipts = all_receipts();
+        let logs: Vec<_> = receipts.logs().collect();
+
+        assert_eq!(logs, vec![&receipts[1], &receipts[2], &receipts[3]]);
+    }
+
+    #[test]
+    fn for_contract__returns_the_receipts_emitted_by_the_contract() {
+        let receipts = all_receipts();
+        let emitted: Vec<_> = receipts.for_contract(OTHER).collect();
+
+        assert_eq!(emitted, vec![&receipts[3]]);
+        assert_eq!(receipts.for_contract(CONTRACT).count(), 10);
+    }
+
+    #[test]
+    fn panic_reason__returns_the_reason_of_the_panic() {
+        assert_eq!(
+            all_receipts().panic_reason(),
+            Some(PanicReason::NotEnoughBalance)
+        );
+    }
+
+    #[test]
+    fn queries__return_none_without_the_receipts() {
+        let receipts = [
+            Receipt::call(SCRIPT, CONTRACT, 0, Default::default(), 10, 0, 0, 0, 0),
+            Receipt::ret(CONTRACT, 0, 0, 0),
+            Receipt::return_data_with_len(SCRIPT, 0, 2, Bytes32::zeroed(), 0, 0, None),
+        ];
+
+        assert_eq!(receipts.script_result(), None);
+        assert_eq!(receipts.return_data(), None);
+        assert_eq!(receipts.logs().count(), 0);
+        assert_eq!(receipts.for_contract(OTHER).count(), 0);
+        assert_eq!(receipts.panic_reason(), None);
+    }
+}
@@ -0,0 +1,121 @@
+//! snapshot tests to ensure the JSON format of receipts doesn't change
+
+use super::*;
+use alloc::{
+    format,
+    vec,
+};
+use fuel_asm::{
+    op,
+    PanicReason,
+};
+
+fn receipts() -> Vec<(&'static str, Receipt)> {
+    let id = ContractId::new([1u8; 32]);
+    let to = ContractId::new([2u8; 32]);
+    let asset_id = AssetId::new([3u8; 32]);
+    let address = Address::new([4u8; 32]);
+    let data = vec![0xde, 0xad, 0xbe, 0xef];
+
+    vec![
+        ("call", Receipt::call(id, to, 5, asset_id, 6, 7, 8, 9, 10)),
+        ("return", Receipt::ret(id, 5, 9, 10)),
+        (
+            "return_data",
+            Receipt::return_data(id, 5, 9, 10, data.clone()),
+        ),
+        (
+            "panic",
+            Receipt::panic(
+                id,
+                PanicInstruction::error(PanicReason::NotEnoughBalance, op::noop().into()),
+                9,
+                10,
+            )
+            .with_panic_contract_id(Some(to)),
+        ),
+        ("revert", Receipt::revert(id, 5, 9, 10)),
+        ("log", Receipt::log(id, 5, 6, 7, 8, 9, 10)),
+        (
+            "log_data",
+            Receipt::log_data(id, 5, 6, 7, 9, 10, data.clone()),
+        ),
+        ("transfer", Receipt::transfer(id, to, 5, asset_id, 9, 10)),
+        (
+            "transfer_out",
+            Receipt::transfer_out(id, address, 5, asset_id, 9, 10),
+        ),
+        (
+            "script_result",
+            Receipt::script_result(ScriptExecutionResult::Success, 5),
+        ),
+        (
+            "message_out",
+            Receipt::message_out(&Bytes32::new([5u8; 32]), 6, address, address, 7, data),
+        ),
+        ("mint", Receipt::mint(Bytes32::new([5u8; 32]), id, 6, 9, 10)),
+        ("burn", Receipt::burn(Bytes32::new([5u8; 32]), id, 6, 9, 10)),
+    ]
+}
+
+#[test]
+fn receipt_json_snapshot() {
+    for (name, receipt) in receipts() {
+        let json = serde_json::to_string_pretty(&receipt).expect("Failed to serialize");
+        insta::assert_snapshot!(format!("receipt_json_{name}"), json);
+
+        let restored: Receipt =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+        assert_eq!(restored, receipt);
+        assert_eq!(restored.data(), receipt.data());
+    }
+}
+
+#[test]
+fn receipt_json_accepts_the_previous_format() {
+    let json = r#"{"LogData":{"id":"0101010101010101010101010101010101010101010101010101010101010101","ra":5,"rb":6,"ptr":7,"len":2,"digest":"0000000000000000000000000000000000000000000000000000000000000000","pc":9,"is":10,"data":[222,173]}}"#;
+
+    let receipt: Receipt = serde_json::from_str(json).expect("Failed to deserialize");
+
+    assert_eq!(
+        receipt,
+        Receipt::log_data_with_len(
+            ContractId::new([1u8; 32]),
+            5,
+            6,
+            7,
+            2,
+            Bytes32::zeroed(),
+            9,
+            10,
+            Some(vec![0xde, 0xad]),
+        )
+    );
+    assert_eq!(receipt.data(), Some(&[0xde, 0xad][..]));
+}
+
+#[test]
+fn receipt_json_defaults_a_missing_payload_to_none() {
+    let json = r#"{"RETURN_DATA":{"id":"0x0101010101010101010101010101010101010101010101010101010101010101","ptr":5,"len":0,"digest":"0x0000000000000000000000000000000000000000000000000000000000000000","pc":9,"is":10}}"#;
+
+    let receipt: Receipt = serde_json::from_str(json).expect("Failed to deserialize");
+
+    assert_eq!(receipt.data(), None);
+}
+
+#[test]
+fn receipt_binary_format_is_unchanged() {
+    let receipt =
+        Receipt::return_data(ContractId::new([1u8; 32]), 5, 9, 10, vec![0xde, 0xad]);
+
+    let bytes = bincode::serialize(&receipt).expect("Failed to serialize");
+
+    // The variant index, then the fields in order, with the data as a sequence
+    assert_eq!(bytes[..4], 2u32.to_le_bytes());
+    assert_eq!(
+        bytes[bytes.len() - 11..],
+        [1, 2, 0, 0, 0, 0, 0, 0, 0, 0xde, 0xad]
+    );
+    let restored: Receipt = bincode::deserialize(&bytes).expect("Failed to deserialize");
+    assert_eq!(restored.data(), receipt.data());
+}
@@ -0,0 +1,3 @@
+//! Utilities to build transactions in tests.
+
+pub mod chain;
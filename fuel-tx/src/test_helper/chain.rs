@@ -0,0 +1,122 @@
+//! Helpers to build chains of transactions, where a transaction spends the outputs of
+//! the previous one.
+
+use crate::{
+    field::Outputs,
+    Input,
+    Output,
+    Transaction,
+    TxPointer,
+    UniqueIdentifier,
+    UtxoId,
+};
+use fuel_crypto::SecretKey;
+use fuel_types::{
+    Address,
+    ChainId,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The error returned when an output of a transaction can't be spent by the next
+/// transaction of a chain.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum ChainError {
+    /// The transaction doesn't have an output at this index.
+    #[display(fmt = "The transaction doesn't have an output at index {}", index)]
+    OutputIndexOutOfBounds { index: u16 },
+    /// The output can't be spent as a coin.
+    #[display(
+        fmt = "The output at index {} is not a coin, change, or variable",
+        index
+    )]
+    OutputNotSpendable { index: u16 },
+    /// The output doesn't hold any coins.
+    #[display(fmt = "The output at index {} has a zero amount", index)]
+    OutputZeroAmount { index: u16 },
+    /// The output is owned by another address than the one of the key.
+    #[display(
+        fmt = "The output at index {} is owned by {}, but the key belongs to {}",
+        index,
+        owner,
+        expected
+    )]
+    OutputOwnerMismatch {
+        /// The index of the output.
+        index: u16,
+        /// The owner of the output.
+        owner: Address,
+        /// The address of the key.
+        expected: Address,
+    },
+}
+
+/// Creates an `Input::coin_signed` that spends the output at `output_index` of the
+/// `prev` transaction, on behalf of `key`.
+///
+/// The output must be a coin, change, or variable output with a non-zero amount,
+/// owned by the address of `key`. The returned input uses the witness index `0`; the
+/// caller is responsible for providing and signing the witness.
+pub fn spend_output(
+    prev: &Transaction,
+    output_index: u16,
+    chain_id: &ChainId,
+    key: &SecretKey,
+    tx_pointer: TxPointer,
+) -> Result<Input, ChainError> {
+    let index = output_index;
+    let outputs: &[Output] = match prev {
+        Transaction::Script(script) => script.outputs(),
+        Transaction::Create(create) => create.outputs(),
+        Transaction::Mint(_) => &[],
+        Transaction::Upgrade(upgrade) => upgrade.outputs(),
+        Transaction::Upload(upload) => upload.outputs(),
+        Transaction::Blob(blob) => blob.outputs(),
+    };
+
+    let output = outputs
+        .get(index as usize)
+        .ok_or(ChainError::OutputIndexOutOfBounds { index })?;
+    let (owner, amount, asset_id) = match *output {
+        Output::Coin {
+            to,
+            amount,
+            asset_id,
+        }
+        | Output::Change {
+            to,
+            amount,
+            asset_id,
+        }
+        | Output::Variable {
+            to,
+            amount,
+            asset_id,
+        } => (to, amount, asset_id),
+        _ => return Err(ChainError::OutputNotSpendable { index }),
+    };
+
+    if amount == 0 {
+        return Err(ChainError::OutputZeroAmount { index })
+    }
+
+    let expected = Input::owner(&key.public_key());
+    if owner != expected {
+        return Err(ChainError::OutputOwnerMismatch {
+            index,
+            owner,
+            expected,
+        })
+    }
+
+    // `UtxoId` can only address the first `u8::MAX` outputs.
+    let output_index =
+        u8::try_from(index).map_err(|_| ChainError::OutputIndexOutOfBounds { index })?;
+    let utxo_id = UtxoId::new(prev.id(chain_id), output_index);
+
+    Ok(Input::coin_signed(
+        utxo_id, owner, amount, asset_id, tx_pointer, 0,
+    ))
+}
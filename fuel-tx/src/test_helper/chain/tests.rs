@@ -0,0 +1,103 @@
+#![allow(non_snake_case)]
+
+use super::*;
+use crate::{
+    field::Inputs,
+    ConsensusParameters,
+    Finalizable,
+    FormatValidityChecks,
+    Signable,
+    TransactionBuilder,
+    Witness,
+};
+use fuel_types::AssetId;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn funding_transaction(rng: &mut StdRng, owner: Address) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1_000,
+            AssetId::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(owner, 600, AssetId::default()))
+        .add_output(Output::coin(owner, 0, AssetId::default()))
+        .add_output(Output::contract_created(rng.gen(), rng.gen()))
+        .add_output(Output::coin(rng.gen(), 300, AssetId::default()))
+        .add_output(Output::change(owner, 0, AssetId::default()))
+        .finalize_as_transaction()
+}
+
+#[test]
+fn spend_output__links_two_transactions() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = ConsensusParameters::standard();
+    let chain_id = params.chain_id();
+    let key = SecretKey::random(rng);
+    let owner = Input::owner(&key.public_key());
+
+    // Given
+    let first = funding_transaction(rng, owner);
+    let tx_pointer = TxPointer::new(1.into(), 0);
+
+    // When
+    let input = spend_output(&first, 0, &chain_id, &key, tx_pointer)
+        .expect("The output is spendable");
+
+    // Then
+    let mut second = TransactionBuilder::script(vec![], vec![])
+        .with_params(params.clone())
+        .add_input(input)
+        .add_witness(Witness::default())
+        .add_output(Output::change(owner, 0, AssetId::default()))
+        .finalize();
+    second.sign_inputs(&key, &chain_id);
+
+    let spent = &second.inputs()[0];
+    assert_eq!(spent.utxo_id(), Some(&UtxoId::new(first.id(&chain_id), 0)));
+    assert_eq!(spent.amount(), Some(600));
+    assert_eq!(spent.input_owner(), Some(&owner));
+    assert_eq!(spent.tx_pointer(), Some(&tx_pointer));
+    second
+        .check(1.into(), &params)
+        .expect("The chained transaction is valid");
+}
+
+#[test]
+fn spend_output__rejects_unspendable_outputs() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let key = SecretKey::random(rng);
+    let owner = Input::owner(&key.public_key());
+    let first = funding_transaction(rng, owner);
+
+    let cases = [
+        (1, ChainError::OutputZeroAmount { index: 1 }),
+        (2, ChainError::OutputNotSpendable { index: 2 }),
+        (4, ChainError::OutputZeroAmount { index: 4 }),
+        (5, ChainError::OutputIndexOutOfBounds { index: 5 }),
+        (256, ChainError::OutputIndexOutOfBounds { index: 256 }),
+    ];
+    for (index, expected) in cases {
+        // When
+        let result = spend_output(&first, index, &chain_id, &key, Default::default());
+
+        // Then
+        assert_eq!(result, Err(expected), "output {index}");
+    }
+
+    // When
+    let result = spend_output(&first, 3, &chain_id, &key, Default::default());
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(ChainError::OutputOwnerMismatch { index: 3, expected, .. }) if expected == owner
+    ));
+}
@@ -1,8 +1,9 @@
 use crate::{
     field::{
         Inputs,
-        Script,
+        Script as ScriptField,
         ScriptData,
+        Witnesses,
     },
     policies::Policies,
     *,
@@ -15,6 +16,7 @@ use fuel_asm::{
 use fuel_tx_test_helpers::{
     generate_bytes,
     generate_nonempty_padded_bytes,
+    TransactionFactory,
 };
 use fuel_types::{
     bytes,
@@ -22,6 +24,7 @@ use fuel_types::{
         Deserialize,
         Serialize,
     },
+    BlobId,
     Immediate24,
 };
 use rand::{
@@ -52,6 +55,16 @@ where
 
         assert_eq!(&d_s, data);
 
+        let d_s = postcard::to_stdvec(&data).expect("Failed to serialize data");
+        let d_s: T = postcard::from_bytes(&d_s).expect("Failed to deserialize data");
+
+        assert_eq!(&d_s, data);
+
+        let d_s = serde_json::to_string(&data).expect("Failed to serialize data");
+        let d_s: T = serde_json::from_str(&d_s).expect("Failed to deserialize data");
+
+        assert_eq!(&d_s, data);
+
         let mut d_bytes = Vec::new();
         data.clone()
             .encode_static(&mut d_bytes)
@@ -257,6 +270,41 @@ fn receipt() {
     assert_encoding_correct(&receipts);
 }
 
+#[test]
+fn receipt_with_an_unknown_panic_reason_roundtrips_losslessly() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let instruction = u32::from(op::noop());
+    let word = (0xf7 << 56) | (Word::from(instruction) << 24);
+    let receipt = Receipt::panic(
+        rng.gen(),
+        PanicInstruction::from(word),
+        rng.gen(),
+        rng.gen(),
+    );
+
+    let reason = receipt.reason().expect("A panic receipt");
+    assert!(reason.reason().is_unknown());
+    assert_eq!(reason.raw_reason(), 0xf7);
+    assert_eq!(reason.to_string(), "UnknownPanicReason(0xf7)");
+
+    let bytes = receipt.to_bytes();
+    let decoded = Receipt::from_bytes(&bytes).expect("Failed to decode");
+    assert_eq!(decoded, receipt);
+    assert_eq!(decoded.to_bytes(), bytes);
+    assert_eq!(Word::from(decoded.reason().expect("A panic receipt")), word);
+
+    let json = serde_json::to_string(&receipt).expect("Failed to serialize");
+    let postcard = postcard::to_stdvec(&receipt).expect("Failed to serialize");
+    assert_eq!(
+        serde_json::from_str::<Receipt>(&json).ok(),
+        Some(receipt.clone())
+    );
+    assert_eq!(
+        postcard::from_bytes::<Receipt>(&postcard).ok(),
+        Some(receipt)
+    );
+}
+
 #[test]
 fn transaction() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -397,6 +445,195 @@ fn transaction() {
     )]);
 }
 
+#[test]
+fn transaction_size_matches_encoded_length_of_every_variant() {
+    // The factory produces the transactions of the `fuel_tx` crate
+    use fuel_tx::{
+        BlobBody,
+        BlobIdExt,
+        Create,
+        Mint,
+        Script,
+        Transaction,
+        TransactionBuilder,
+        UpgradePurpose,
+        UploadSubsection,
+    };
+
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let mut txs: Vec<Transaction> = vec![];
+    txs.extend(
+        TransactionFactory::<_, Script>::from_seed(1)
+            .take(50)
+            .map(|(tx, _)| tx.into()),
+    );
+    txs.extend(
+        TransactionFactory::<_, Create>::from_seed(2)
+            .take(50)
+            .map(|(tx, _)| tx.into()),
+    );
+    txs.extend(
+        TransactionFactory::<_, Mint>::from_seed(3)
+            .take(50)
+            .map(Into::into),
+    );
+    for _ in 0..50 {
+        let bytecode = generate_bytes(rng);
+        let subsection = UploadSubsection::split_bytecode(&bytecode, 16)
+            .expect("The subsection size is not zero")
+            .swap_remove(0);
+        txs.push(
+            TransactionBuilder::upgrade(UpgradePurpose::StateTransition {
+                root: rng.gen(),
+            })
+            .add_random_fee_input()
+            .finalize_as_transaction(),
+        );
+        txs.push(
+            TransactionBuilder::upload(subsection)
+                .add_random_fee_input()
+                .finalize_as_transaction(),
+        );
+        txs.push(
+            TransactionBuilder::blob(BlobBody {
+                id: <BlobId as BlobIdExt>::compute(&bytecode),
+                witness_index: 0,
+            })
+            .add_witness(bytecode.into())
+            .add_random_fee_input()
+            .finalize_as_transaction(),
+        );
+    }
+
+    for tx in txs {
+        assert_eq!(tx.size(), tx.to_bytes().len());
+    }
+}
+
+#[test]
+fn transaction_with_every_input_and_output_roundtrips_through_serde() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let predicate = vec![0x24u8; 8];
+    let data = vec![0x25u8; 4];
+
+    let inputs = vec![
+        Input::coin_signed(rng.gen(), rng.gen(), 1, rng.gen(), rng.gen(), 0),
+        Input::coin_predicate(
+            rng.gen(),
+            rng.gen(),
+            2,
+            rng.gen(),
+            rng.gen(),
+            3,
+            predicate.clone(),
+            data.clone(),
+        ),
+        Input::contract(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen()),
+        Input::message_coin_signed(rng.gen(), rng.gen(), 4, rng.gen(), 0),
+        Input::message_coin_predicate(
+            rng.gen(),
+            rng.gen(),
+            5,
+            rng.gen(),
+            6,
+            predicate.clone(),
+            data.clone(),
+        ),
+        Input::message_data_signed(rng.gen(), rng.gen(), 7, rng.gen(), 0, data.clone()),
+        Input::message_data_predicate(
+            rng.gen(),
+            rng.gen(),
+            8,
+            rng.gen(),
+            9,
+            data.clone(),
+            predicate.clone(),
+            data.clone(),
+        ),
+    ];
+    let outputs = vec![
+        Output::coin(rng.gen(), 1, rng.gen()),
+        Output::contract(2, rng.gen(), rng.gen()),
+        Output::change(rng.gen(), 0, rng.gen()),
+        Output::variable(rng.gen(), 0, rng.gen()),
+        Output::contract_created(rng.gen(), rng.gen()),
+    ];
+    let tx: Transaction = Transaction::script(
+        1_000,
+        vec![0x26; 8],
+        vec![0x27; 4],
+        Policies::new().with_max_fee(1_000),
+        inputs,
+        outputs,
+        vec![vec![0x28; 64].into()],
+    )
+    .into();
+
+    let json = serde_json::to_string(&tx).expect("Failed to serialize");
+    let bytes = postcard::to_stdvec(&tx).expect("Failed to serialize");
+
+    assert_eq!(
+        serde_json::from_str::<Transaction>(&json).ok(),
+        Some(tx.clone())
+    );
+    assert_eq!(postcard::from_bytes::<Transaction>(&bytes).ok(), Some(tx));
+    for payload in ["0x2424242424242424", "0x25252525", "0x2626262626262626"] {
+        assert!(
+            json.contains(&format!("\"{payload}\"")),
+            "{payload} not in {json}"
+        );
+    }
+}
+
+#[test]
+fn witness_is_a_hex_string_in_human_readable_formats() {
+    let witness: Witness = vec![0xab; 32].into();
+
+    let json = serde_json::to_value(&witness).expect("Failed to serialize");
+    let bytes = postcard::to_stdvec(&witness).expect("Failed to serialize");
+
+    let hex = json.as_str().expect("The witness is a string");
+    assert_eq!(hex.len(), 66);
+    assert_eq!(hex, format!("0x{}", "ab".repeat(32)));
+    // The length prefix and the bytes
+    assert_eq!(bytes.len(), 33);
+}
+
+#[test]
+fn byte_arrays_are_accepted_in_human_readable_formats() {
+    let witness = serde_json::json!({ "data": [1, 2, 3] });
+    let script = serde_json::json!({
+        "script_gas_limit": 0,
+        "script": [4, 5],
+        "script_data": "0x06",
+        "policies": Policies::new(),
+        "inputs": [],
+        "outputs": [],
+        "witnesses": [[7], "0x08", { "data": "0x09" }],
+        "receipts_root": Bytes32::zeroed(),
+    });
+
+    let witness: Witness = serde_json::from_value(witness).expect("Legacy witness");
+    let script: Script = serde_json::from_value(script).expect("Legacy script");
+
+    assert_eq!(witness, vec![1, 2, 3].into());
+    assert_eq!(script.script(), &vec![4, 5]);
+    assert_eq!(script.script_data(), &vec![6]);
+    assert_eq!(
+        script.witnesses(),
+        &vec![vec![7].into(), vec![8].into(), vec![9].into()]
+    );
+}
+
+#[test]
+fn malformed_hex_is_rejected_in_human_readable_formats() {
+    for hex in ["0x1", "0xzz", "0x0g"] {
+        serde_json::from_value::<Witness>(serde_json::json!(hex))
+            .expect_err("The hex is malformed");
+    }
+}
+
 #[test]
 fn create_input_data_offset() {
     let rng = &mut StdRng::seed_from_u64(8586);
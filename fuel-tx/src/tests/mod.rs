@@ -7,3 +7,5 @@ mod bytes;
 mod display;
 #[cfg(not(feature = "serde"))]
 use bincode as _;
+#[cfg(not(feature = "serde"))]
+use postcard as _;
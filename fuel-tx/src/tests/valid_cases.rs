@@ -38,6 +38,7 @@ pub fn test_params() -> ConsensusParameters {
         CHAIN_ID,
         Default::default(),
         Default::default(),
+        Default::default(),
     )
 }
 
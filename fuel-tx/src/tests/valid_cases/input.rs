@@ -3,11 +3,16 @@
 use super::PREDICATE_PARAMS;
 
 use fuel_crypto::{
+    Message,
     PublicKey,
     SecretKey,
+    Signature,
 };
 use fuel_tx::{
-    field::Witnesses,
+    field::{
+        Inputs,
+        Witnesses,
+    },
     ConsensusParameters,
     *,
 };
@@ -151,7 +156,153 @@ fn coin_signed() {
         .check(block_height, &ConsensusParameters::standard())
         .expect_err("Expected failure");
 
-    assert_eq!(ValidityError::InputWitnessIndexBounds { index: 0 }, err);
+    assert_eq!(
+        ValidityError::InputWitnessIndexBounds {
+            index: 0,
+            witness_index: 0
+        },
+        err
+    );
+}
+
+#[test]
+fn externally_signed_signing_hash_passes_check() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let owner = Input::owner(&secret.public_key());
+    let chain_id = ChainId::default();
+
+    let mut tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_signed(
+            rng.gen(),
+            owner,
+            rng.gen(),
+            rng.gen(),
+            Default::default(),
+            0,
+        ))
+        .add_witness(Witness::default())
+        .finalize();
+
+    // The signature is computed outside, as by an offline device
+    let signing_hash = tx.signing_hash(&chain_id);
+    let signature = Signature::sign(&secret, &Message::from_bytes(*signing_hash));
+    tx.witnesses_mut()[0] = signature.as_ref().into();
+
+    assert_eq!(signing_hash, tx.id(&chain_id));
+    assert_eq!(
+        Input::verify_coin_signature(&tx.inputs()[0], 0, &signing_hash, tx.witnesses()),
+        Ok(())
+    );
+    assert_eq!(
+        Input::verify_coin_signature(&tx.inputs()[0], 0, &rng.gen(), tx.witnesses()),
+        Err(ValidityError::InputInvalidSignature { index: 0 })
+    );
+    tx.check(Default::default(), &ConsensusParameters::standard())
+        .expect("The external signature is valid");
+}
+
+fn builder_signed_script(rng: &mut StdRng) -> Script {
+    TransactionBuilder::script(vec![], vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            Default::default(),
+        )
+        .add_unsigned_message_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            generate_nonempty_padded_bytes(rng),
+        )
+        .add_unsigned_message_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            vec![],
+        )
+        .finalize()
+}
+
+#[test]
+fn builder_signed_transaction_verifies_input_signatures() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let tx = builder_signed_script(rng);
+    let tx_id = tx.id(&chain_id);
+
+    for input in tx.inputs() {
+        assert_eq!(input.verify_signature(&tx_id, tx.witnesses()), Ok(()));
+    }
+    assert_eq!(tx.verify_input_signatures(&chain_id), Ok(()));
+}
+
+#[test]
+fn flipped_signature_byte_fails_input_signature_verification() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let chain_id = ChainId::default();
+    let mut tx = builder_signed_script(rng);
+    let owner = *tx.inputs()[1]
+        .recipient()
+        .expect("The message has a recipient");
+    let witness_index = tx.inputs()[1].witness_index().expect("The input is signed");
+
+    tx.witnesses_mut()[witness_index as usize].as_vec_mut()[0] ^= 1;
+
+    let err = tx
+        .verify_input_signatures(&chain_id)
+        .expect_err("The signature is altered");
+
+    match err {
+        (1, InputSignatureError::MalformedSignature { witness_index: w }) => {
+            assert_eq!(w, witness_index)
+        }
+        (
+            1,
+            InputSignatureError::OwnerMismatch {
+                owner: o,
+                recovered,
+            },
+        ) => {
+            assert_eq!(o, owner);
+            assert_ne!(recovered, owner);
+        }
+        err => panic!("Unexpected error {err:?}"),
+    }
+}
+
+#[test]
+fn input_signature_verification_distinguishes_errors() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let other = SecretKey::random(rng);
+    let owner = Input::owner(&secret.public_key());
+    let tx_id: Bytes32 = rng.gen();
+    let input = Input::coin_signed(rng.gen(), owner, rng.gen(), rng.gen(), rng.gen(), 0);
+
+    assert_eq!(
+        input.verify_signature(&tx_id, &[]),
+        Err(InputSignatureError::MissingWitness { witness_index: 0 })
+    );
+    assert_eq!(
+        input.verify_signature(&tx_id, &[vec![1u8; 63].into()]),
+        Err(InputSignatureError::MalformedSignature { witness_index: 0 })
+    );
+    assert_eq!(
+        input.verify_signature(&tx_id, &[Witness::sign(&other, &tx_id)]),
+        Err(InputSignatureError::OwnerMismatch {
+            owner,
+            recovered: Input::owner(&other.public_key()),
+        })
+    );
+    assert_eq!(
+        input.verify_signature(&tx_id, &[Witness::sign(&secret, &tx_id)]),
+        Ok(())
+    );
 }
 
 #[test]
@@ -208,7 +359,7 @@ fn coin_predicate() {
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         predicate,
         generate_bytes(rng),
     )
@@ -224,7 +375,7 @@ fn coin_predicate() {
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         predicate,
         generate_bytes(rng),
     )
@@ -244,7 +395,7 @@ fn coin_predicate() {
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         predicate,
         generate_bytes(rng),
     )
@@ -330,7 +481,7 @@ fn message_metadata() {
         recipient,
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         generate_bytes(rng),
         predicate,
         generate_bytes(rng),
@@ -359,7 +510,13 @@ fn message_metadata() {
         .check(block_height, &ConsensusParameters::standard())
         .expect_err("Expected failure");
 
-    assert_eq!(ValidityError::InputWitnessIndexBounds { index: 0 }, err,);
+    assert_eq!(
+        ValidityError::InputWitnessIndexBounds {
+            index: 0,
+            witness_index: 0
+        },
+        err,
+    );
 
     let mut predicate = generate_nonempty_padded_bytes(rng);
     let recipient = Input::predicate_owner(&predicate);
@@ -370,7 +527,7 @@ fn message_metadata() {
         recipient,
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         generate_bytes(rng),
         predicate,
         generate_bytes(rng),
@@ -400,14 +557,22 @@ fn message_metadata() {
     )
     .expect_err("expected max data length error");
 
-    assert_eq!(ValidityError::InputMessageDataLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::MessageDataLength,
+            used: PREDICATE_PARAMS.max_message_data_length + 1,
+            max: PREDICATE_PARAMS.max_message_data_length,
+            index: Some(1),
+        },
+        err
+    );
 
     let err = Input::message_data_predicate(
         rng.gen(),
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         data,
         generate_nonempty_padded_bytes(rng),
         generate_bytes(rng),
@@ -415,7 +580,15 @@ fn message_metadata() {
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max data length error");
 
-    assert_eq!(ValidityError::InputMessageDataLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::MessageDataLength,
+            used: PREDICATE_PARAMS.max_message_data_length + 1,
+            max: PREDICATE_PARAMS.max_message_data_length,
+            index: Some(1),
+        },
+        err
+    );
 
     let predicate = vec![0xff; PREDICATE_PARAMS.max_predicate_length as usize + 1];
 
@@ -424,7 +597,7 @@ fn message_metadata() {
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         generate_bytes(rng),
         predicate,
         generate_bytes(rng),
@@ -432,7 +605,15 @@ fn message_metadata() {
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate length error");
 
-    assert_eq!(ValidityError::InputPredicateLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateLength,
+            used: PREDICATE_PARAMS.max_predicate_length + 1,
+            max: PREDICATE_PARAMS.max_predicate_length,
+            index: Some(1),
+        },
+        err
+    );
 
     let predicate_data =
         vec![0xff; PREDICATE_PARAMS.max_predicate_data_length as usize + 1];
@@ -442,7 +623,7 @@ fn message_metadata() {
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         generate_bytes(rng),
         generate_bytes(rng),
         predicate_data,
@@ -450,7 +631,35 @@ fn message_metadata() {
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate data length error");
 
-    assert_eq!(ValidityError::InputPredicateDataLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateDataLength,
+            used: PREDICATE_PARAMS.max_predicate_data_length + 1,
+            max: PREDICATE_PARAMS.max_predicate_data_length,
+            index: Some(1),
+        },
+        err
+    );
+}
+
+#[test]
+fn message_data_empty() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let txhash: Bytes32 = rng.gen();
+
+    let err =
+        Input::message_data_signed(rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0, vec![])
+            .check(
+                1,
+                &txhash,
+                &[],
+                &[vec![].into()],
+                &Default::default(),
+                &mut None,
+            )
+            .expect_err("expected empty data error");
+
+    assert_eq!(ValidityError::InputMessageDataEmpty { index: 1 }, err);
 }
 
 #[test]
@@ -466,7 +675,7 @@ fn message_message_coin() {
         recipient,
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         predicate,
         generate_bytes(rng),
     )
@@ -483,7 +692,13 @@ fn message_message_coin() {
         .check(block_height, &ConsensusParameters::standard())
         .expect_err("Expected failure");
 
-    assert_eq!(ValidityError::InputWitnessIndexBounds { index: 0 }, err,);
+    assert_eq!(
+        ValidityError::InputWitnessIndexBounds {
+            index: 0,
+            witness_index: 0
+        },
+        err,
+    );
 
     let mut predicate = generate_nonempty_padded_bytes(rng);
     let recipient = Input::predicate_owner(&predicate);
@@ -494,7 +709,7 @@ fn message_message_coin() {
         recipient,
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         predicate,
         generate_bytes(rng),
     )
@@ -510,14 +725,22 @@ fn message_message_coin() {
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         predicate,
         generate_bytes(rng),
     )
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate length error");
 
-    assert_eq!(ValidityError::InputPredicateLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateLength,
+            used: PREDICATE_PARAMS.max_predicate_length + 1,
+            max: PREDICATE_PARAMS.max_predicate_length,
+            index: Some(1),
+        },
+        err
+    );
 
     let predicate_data =
         vec![0xff; PREDICATE_PARAMS.max_predicate_data_length as usize + 1];
@@ -527,14 +750,82 @@ fn message_message_coin() {
         rng.gen(),
         rng.gen(),
         rng.gen(),
-        rng.gen(),
+        rng.gen_range(0..=PREDICATE_PARAMS.max_gas_per_predicate),
         generate_bytes(rng),
         predicate_data,
     )
     .check(1, &txhash, &[], &[], &Default::default(), &mut None)
     .expect_err("expected max predicate data length error");
 
-    assert_eq!(ValidityError::InputPredicateDataLength { index: 1 }, err,);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateDataLength,
+            used: PREDICATE_PARAMS.max_predicate_data_length + 1,
+            max: PREDICATE_PARAMS.max_predicate_data_length,
+            index: Some(1),
+        },
+        err
+    );
+}
+
+#[test]
+fn predicate_gas_used_exceeds_max_gas_per_predicate() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let txhash: Bytes32 = rng.gen();
+    let predicate_gas_used = PREDICATE_PARAMS.max_gas_per_predicate + 1;
+
+    let predicate = generate_nonempty_padded_bytes(rng);
+    let owner = Input::predicate_owner(&predicate);
+
+    let err = Input::coin_predicate(
+        rng.gen(),
+        owner,
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        predicate_gas_used,
+        predicate,
+        generate_bytes(rng),
+    )
+    .check(1, &txhash, &[], &[], &Default::default(), &mut None)
+    .expect_err("expected max gas per predicate error");
+
+    assert_eq!(ValidityError::InputPredicateGasExceeded { index: 1 }, err);
+
+    let predicate = generate_nonempty_padded_bytes(rng);
+    let recipient = Input::predicate_owner(&predicate);
+
+    let err = Input::message_data_predicate(
+        rng.gen(),
+        recipient,
+        rng.gen(),
+        rng.gen(),
+        predicate_gas_used,
+        generate_nonempty_padded_bytes(rng),
+        predicate,
+        generate_bytes(rng),
+    )
+    .check(1, &txhash, &[], &[], &Default::default(), &mut None)
+    .expect_err("expected max gas per predicate error");
+
+    assert_eq!(ValidityError::InputPredicateGasExceeded { index: 1 }, err);
+
+    let predicate = generate_nonempty_padded_bytes(rng);
+    let recipient = Input::predicate_owner(&predicate);
+
+    let err = Input::message_coin_predicate(
+        rng.gen(),
+        recipient,
+        rng.gen(),
+        rng.gen(),
+        predicate_gas_used,
+        predicate,
+        generate_bytes(rng),
+    )
+    .check(1, &txhash, &[], &[], &Default::default(), &mut None)
+    .expect_err("expected max gas per predicate error");
+
+    assert_eq!(ValidityError::InputPredicateGasExceeded { index: 1 }, err);
 }
 
 #[test]
@@ -553,7 +844,10 @@ fn transaction_with_duplicate_coin_inputs_is_invalid() {
         .check_without_signatures(Default::default(), &ConsensusParameters::standard())
         .expect_err("Expected checkable failure");
 
-    assert_eq!(err, ValidityError::DuplicateInputUtxoId { utxo_id });
+    assert_eq!(
+        err,
+        ValidityError::DuplicateInputUtxoId { index: 1, utxo_id }
+    );
 }
 
 #[test]
@@ -590,7 +884,13 @@ fn transaction_with_duplicate_message_inputs_is_invalid() {
         )
         .expect_err("Expected checkable failure");
 
-    assert_eq!(err, ValidityError::DuplicateMessageInputId { message_id });
+    assert_eq!(
+        err,
+        ValidityError::DuplicateMessageInputId {
+            index: 2,
+            message_id
+        }
+    );
 }
 
 #[test]
@@ -622,7 +922,13 @@ fn transaction_with_duplicate_contract_inputs_is_invalid() {
         .check_without_signatures(Default::default(), &ConsensusParameters::standard())
         .expect_err("Expected checkable failure");
 
-    assert_eq!(err, ValidityError::DuplicateInputContractId { contract_id });
+    assert_eq!(
+        err,
+        ValidityError::DuplicateInputContractId {
+            index: 2,
+            contract_id
+        }
+    );
 }
 
 #[test]
@@ -649,3 +955,315 @@ fn transaction_with_duplicate_contract_utxo_id_is_valid() {
         .check_without_signatures(Default::default(), &ConsensusParameters::standard())
         .expect("Duplicated UTXO id is valid for contract input");
 }
+
+#[test]
+fn transaction_with_non_adjacent_duplicate_coin_inputs_reports_second_index() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let utxo_id = rng.gen();
+
+    let a = Input::coin_signed(utxo_id, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0);
+    let b = Input::coin_signed(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0);
+    let c = Input::coin_signed(utxo_id, rng.gen(), rng.gen(), rng.gen(), rng.gen(), 0);
+
+    let err = TransactionBuilder::script(vec![], vec![])
+        .add_input(a)
+        .add_input(b)
+        .add_input(c)
+        .add_witness(rng.gen())
+        .finalize()
+        .check_without_signatures(Default::default(), &ConsensusParameters::standard())
+        .expect_err("Expected checkable failure");
+
+    assert_eq!(
+        err,
+        ValidityError::DuplicateInputUtxoId { index: 2, utxo_id }
+    );
+}
+
+#[test]
+fn transaction_with_max_inputs_without_duplicates_is_valid() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = ConsensusParameters::standard();
+    let per_kind = params.tx_params().max_inputs as usize / 3;
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    for _ in 0..per_kind {
+        builder.add_input(Input::coin_signed(
+            rng.gen(),
+            rng.gen(),
+            1,
+            AssetId::BASE,
+            rng.gen(),
+            0,
+        ));
+        builder.add_input(Input::message_data_signed(
+            rng.gen(),
+            rng.gen(),
+            1,
+            rng.gen(),
+            0,
+            generate_nonempty_padded_bytes(rng),
+        ));
+    }
+    for i in 0..per_kind {
+        builder.add_input(Input::contract(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+        ));
+        builder.add_output(Output::contract(
+            (2 * per_kind + i) as u8,
+            rng.gen(),
+            rng.gen(),
+        ));
+    }
+
+    let tx = builder.add_witness(rng.gen()).finalize();
+    assert_eq!(tx.inputs().len(), params.tx_params().max_inputs as usize);
+
+    tx.check_without_signatures(Default::default(), &params)
+        .expect("Distinct inputs are valid");
+}
+
+fn checked_coin_predicate(
+    rng: &mut StdRng,
+    predicate: Vec<u8>,
+    predicate_data: Vec<u8>,
+    predicate_params: &PredicateParameters,
+) -> Result<Input, ValidityError> {
+    Input::checked_coin_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        0,
+        predicate,
+        predicate_data,
+        predicate_params,
+    )
+}
+
+#[test]
+fn checked_coin_predicate_accepts_predicate_at_the_length_limit() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT.with_max_predicate_length(64);
+
+    let input = checked_coin_predicate(rng, vec![0x24; 64], vec![], &params)
+        .expect("The predicate is within the limit");
+
+    assert_eq!(input.predicate_len(), Some(64));
+}
+
+#[test]
+fn checked_coin_predicate_rejects_predicate_above_the_length_limit() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT.with_max_predicate_length(64);
+
+    let err = checked_coin_predicate(rng, vec![0x24; 65], vec![], &params)
+        .expect_err("The predicate exceeds the limit");
+
+    assert_eq!(
+        err,
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateLength,
+            used: 65,
+            max: 64,
+            index: None,
+        }
+    );
+}
+
+#[test]
+fn checked_coin_predicate_accepts_predicate_data_at_the_length_limit() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT.with_max_predicate_data_length(10);
+
+    let input = checked_coin_predicate(rng, vec![0x24; 8], vec![0x25; 10], &params)
+        .expect("The predicate data is within the limit");
+
+    assert_eq!(input.predicate_data_len(), Some(10));
+}
+
+#[test]
+fn checked_coin_predicate_rejects_predicate_data_above_the_length_limit() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT.with_max_predicate_data_length(10);
+
+    let err = checked_coin_predicate(rng, vec![0x24; 8], vec![0x25; 11], &params)
+        .expect_err("The predicate data exceeds the limit");
+
+    assert_eq!(
+        err,
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateDataLength,
+            used: 11,
+            max: 10,
+            index: None,
+        }
+    );
+}
+
+#[test]
+fn checked_predicate_constructors_reject_misaligned_predicate() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT;
+    let predicate = vec![0x24; 12];
+    let expected = ValidityError::PredicateNotWordAligned { length: 12 };
+
+    let coin = checked_coin_predicate(rng, predicate.clone(), vec![], &params);
+    let message_coin = Input::checked_message_coin_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        rng.gen(),
+        rng.gen(),
+        0,
+        predicate.clone(),
+        vec![],
+        &params,
+    );
+    let message_data = Input::checked_message_data_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        rng.gen(),
+        rng.gen(),
+        0,
+        generate_nonempty_padded_bytes(rng),
+        predicate.clone(),
+        vec![],
+        &params,
+    );
+
+    assert_eq!(coin, Err(expected.clone()));
+    assert_eq!(message_coin, Err(expected.clone()));
+    assert_eq!(message_data, Err(expected));
+}
+
+#[test]
+fn checked_predicate_constructors_reject_empty_predicate() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT;
+
+    let coin = checked_coin_predicate(rng, vec![], vec![], &params);
+    let message_coin = Input::checked_message_coin_predicate(
+        rng.gen(),
+        Input::predicate_owner([]),
+        rng.gen(),
+        rng.gen(),
+        0,
+        vec![],
+        vec![],
+        &params,
+    );
+    let message_data = Input::checked_message_data_predicate(
+        rng.gen(),
+        Input::predicate_owner([]),
+        rng.gen(),
+        rng.gen(),
+        0,
+        generate_nonempty_padded_bytes(rng),
+        vec![],
+        vec![],
+        &params,
+    );
+
+    assert_eq!(coin, Err(ValidityError::PredicateEmpty));
+    assert_eq!(message_coin, Err(ValidityError::PredicateEmpty));
+    assert_eq!(message_data, Err(ValidityError::PredicateEmpty));
+}
+
+#[test]
+fn checked_predicate_constructors_reject_owner_other_than_predicate_root() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT;
+    let predicate = vec![0x24; 8];
+    let owner: Address = rng.gen();
+
+    let coin = Input::checked_coin_predicate(
+        rng.gen(),
+        owner,
+        rng.gen(),
+        rng.gen(),
+        rng.gen(),
+        0,
+        predicate.clone(),
+        vec![],
+        &params,
+    );
+    let message_coin = Input::checked_message_coin_predicate(
+        rng.gen(),
+        owner,
+        rng.gen(),
+        rng.gen(),
+        0,
+        predicate.clone(),
+        vec![],
+        &params,
+    );
+    let message_data = Input::checked_message_data_predicate(
+        rng.gen(),
+        owner,
+        rng.gen(),
+        rng.gen(),
+        0,
+        generate_nonempty_padded_bytes(rng),
+        predicate,
+        vec![],
+        &params,
+    );
+
+    assert_eq!(coin, Err(ValidityError::PredicateOwnerMismatch));
+    assert_eq!(message_coin, Err(ValidityError::PredicateOwnerMismatch));
+    assert_eq!(message_data, Err(ValidityError::PredicateOwnerMismatch));
+}
+
+#[test]
+fn checked_message_data_predicate_rejects_empty_data() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let predicate = vec![0x24; 8];
+
+    let result = Input::checked_message_data_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        rng.gen(),
+        rng.gen(),
+        0,
+        vec![],
+        predicate,
+        vec![],
+        &PredicateParameters::DEFAULT,
+    );
+
+    assert_eq!(result, Err(ValidityError::MessageDataEmpty));
+}
+
+#[test]
+fn checked_message_data_predicate_rejects_data_above_the_length_limit() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let params = PredicateParameters::DEFAULT.with_max_message_data_length(10);
+    let predicate = vec![0x24; 8];
+
+    let result = Input::checked_message_data_predicate(
+        rng.gen(),
+        Input::predicate_owner(&predicate),
+        rng.gen(),
+        rng.gen(),
+        0,
+        vec![0x25; 11],
+        predicate,
+        vec![],
+        &params,
+    );
+
+    assert_eq!(
+        result,
+        Err(ValidityError::BudgetExceeded {
+            budget: BudgetKind::MessageDataLength,
+            used: 11,
+            max: 10,
+            index: None,
+        })
+    );
+}
@@ -9,6 +9,10 @@ use super::{
     TX_PARAMS,
 };
 use crate::{
+    field::{
+        Inputs,
+        Witnesses,
+    },
     policies::{
         Policies,
         PolicyType,
@@ -18,13 +22,17 @@ use crate::{
 };
 use core::cmp;
 use fuel_crypto::{
+    Message,
     SecretKey,
     Signature,
 };
 use fuel_tx_test_helpers::generate_bytes;
-use fuel_types::canonical::{
-    Deserialize,
-    Serialize,
+use fuel_types::{
+    bytes::WORD_SIZE,
+    canonical::{
+        Deserialize,
+        Serialize,
+    },
 };
 use rand::{
     rngs::StdRng,
@@ -190,7 +198,94 @@ fn script_set_witness_limit_less_than_witness_data_size_fails() {
         .expect_err("Expected erroneous transaction");
 
     // Then
-    assert_eq!(ValidityError::TransactionWitnessLimitExceeded, err);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::WitnessLimit,
+            used: witness_size as u64,
+            max: limit as u64,
+            index: None,
+        },
+        err
+    );
+}
+
+fn script_with_unaligned_witness(rng: &mut StdRng) -> TransactionBuilder<Script> {
+    let mut builder =
+        TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng));
+    builder
+        .add_random_fee_input()
+        .add_witness(vec![0xfa; 5].into());
+    builder
+}
+
+#[test]
+fn witnesses_size__includes_length_prefixes_and_padding() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let tx = script_with_unaligned_witness(rng).finalize();
+
+    // When
+    let size = tx.witnesses_size();
+
+    // Then
+    let raw_len: usize = tx.witnesses().iter().map(|w| w.as_ref().len()).sum();
+    assert_eq!(raw_len, Signature::LEN + 5);
+    assert_eq!(size, tx.witnesses().size_dynamic());
+    assert_eq!(size, 2 * WORD_SIZE + Signature::LEN + 8);
+}
+
+#[test]
+fn script__check__witness_limit_equal_to_witnesses_size_succeeds() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut builder = script_with_unaligned_witness(rng);
+    let witnesses_size = builder.finalize().witnesses_size();
+
+    // Given
+    let limit = witnesses_size as Word;
+
+    // When
+    let result = builder
+        .witness_limit(limit)
+        .finalize()
+        .check(1000.into(), &test_params());
+
+    // Then
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn script__check__witness_limit_one_byte_below_witnesses_size_fails() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut builder = script_with_unaligned_witness(rng);
+    let witnesses_size = builder.finalize().witnesses_size();
+
+    // Given
+    let limit = witnesses_size as Word - 1;
+
+    // When
+    let err = builder
+        .witness_limit(limit)
+        .finalize()
+        .check(1000.into(), &test_params())
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(
+        err,
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::WitnessLimit,
+            used: witnesses_size as Word,
+            max: limit,
+            index: None,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        format!(
+            "The witness limit budget is exceeded: used {witnesses_size}, max {limit}"
+        )
+    );
 }
 
 #[test]
@@ -231,7 +326,15 @@ fn create_set_witness_limit_less_than_witness_data_size_fails() {
         .expect_err("Expected erroneous transaction");
 
     // Then
-    assert_eq!(ValidityError::TransactionWitnessLimitExceeded, err);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::WitnessLimit,
+            used: limit as u64,
+            max: limit as u64 - 1,
+            index: None,
+        },
+        err
+    );
 }
 
 #[test]
@@ -462,6 +565,66 @@ fn max_iow() {
     assert_eq!(ValidityError::TransactionWitnessesMax, err);
 }
 
+fn script_with_signers(rng: &mut StdRng, signers: &[SecretKey]) -> Script {
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    for secret in signers {
+        builder.add_unsigned_coin_input(
+            *secret,
+            rng.gen(),
+            rng.gen(),
+            AssetId::default(),
+            rng.gen(),
+        );
+    }
+    builder.finalize()
+}
+
+#[test]
+fn script__check__signature_checks_at_max_succeeds() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut params = test_params();
+    params.set_tx_params(TX_PARAMS.with_max_signature_checks(2));
+
+    let a = SecretKey::random(rng);
+    let b = SecretKey::random(rng);
+
+    for signers in [vec![a, b], vec![a, a, b, b, a], vec![a, a, a]] {
+        // Given
+        let tx = script_with_signers(rng, &signers);
+
+        // When
+        let result = tx.check(1000.into(), &params);
+
+        // Then
+        assert_eq!(result, Ok(()));
+    }
+}
+
+#[test]
+fn script__check__signature_checks_above_max_fails() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let mut params = test_params();
+    params.set_tx_params(TX_PARAMS.with_max_signature_checks(2));
+
+    let a = SecretKey::random(rng);
+    let b = SecretKey::random(rng);
+    let c = SecretKey::random(rng);
+
+    for signers in [vec![a, b, c], vec![a, a, b, b, c, c]] {
+        // Given
+        let tx = script_with_signers(rng, &signers);
+
+        // When
+        let result = tx.check(1000.into(), &params);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ValidityError::TooManySignatureChecks { count: 3, max: 2 })
+        );
+    }
+}
+
 #[test]
 fn output_change_asset_id() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -496,7 +659,10 @@ fn output_change_asset_id() {
         .expect_err("Expected erroneous transaction");
 
     assert_eq!(
-        ValidityError::TransactionOutputChangeAssetIdDuplicated(a),
+        ValidityError::TransactionOutputChangeAssetIdDuplicated {
+            index: 1,
+            asset_id: a
+        },
         err
     );
 
@@ -512,7 +678,7 @@ fn output_change_asset_id() {
 
     assert!(matches!(
         err,
-        ValidityError::TransactionOutputChangeAssetIdNotFound(asset_id) if asset_id == c
+        ValidityError::TransactionOutputChangeAssetIdNotFound { index: 1, asset_id } if asset_id == c
     ));
 
     let err = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
@@ -527,10 +693,189 @@ fn output_change_asset_id() {
 
     assert!(matches!(
         err,
-        ValidityError::TransactionOutputCoinAssetIdNotFound(asset_id) if asset_id == c
+        ValidityError::TransactionOutputCoinAssetIdNotFound { index: 1, asset_id } if asset_id == c
     ));
 }
 
+#[test]
+fn script__check__reports_index_of_change_output_with_unknown_asset() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let a: AssetId = rng.gen();
+    let b: AssetId = rng.gen();
+    let c: AssetId = rng.gen();
+
+    // Given
+    let tx = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), a, rng.gen())
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), b, rng.gen())
+        .add_output(Output::change(rng.gen(), 0, a))
+        .add_output(Output::change(rng.gen(), 0, b))
+        .add_output(Output::change(rng.gen(), 0, c))
+        .finalize();
+
+    // When
+    let err = tx
+        .check(1000.into(), &test_params())
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(
+        err,
+        ValidityError::TransactionOutputChangeAssetIdNotFound {
+            index: 2,
+            asset_id: c
+        }
+    );
+}
+
+#[test]
+fn script__check__reports_index_of_coin_output_with_unknown_asset() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let a: AssetId = rng.gen();
+    let c: AssetId = rng.gen();
+
+    // Given
+    let tx = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), a, rng.gen())
+        .add_output(Output::change(rng.gen(), 0, a))
+        .add_output(Output::coin(rng.gen(), 0, a))
+        .add_output(Output::coin(rng.gen(), 0, a))
+        .add_output(Output::coin(rng.gen(), 0, c))
+        .finalize();
+
+    // When
+    let err = tx
+        .check(1000.into(), &test_params())
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(
+        err,
+        ValidityError::TransactionOutputCoinAssetIdNotFound {
+            index: 3,
+            asset_id: c
+        }
+    );
+}
+
+#[test]
+fn script__check__reports_index_of_duplicated_change_output() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+    let secret = SecretKey::random(rng);
+    let a: AssetId = rng.gen();
+    let b: AssetId = rng.gen();
+
+    // Given
+    let tx = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), a, rng.gen())
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), b, rng.gen())
+        .add_output(Output::change(rng.gen(), 0, a))
+        .add_output(Output::change(rng.gen(), 0, b))
+        .add_output(Output::change(rng.gen(), 0, a))
+        .finalize();
+
+    // When
+    let err = tx
+        .check(1000.into(), &test_params())
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(
+        err,
+        ValidityError::TransactionOutputChangeAssetIdDuplicated {
+            index: 2,
+            asset_id: a
+        }
+    );
+}
+
+#[test]
+fn script__check__reports_index_of_input_with_invalid_signature() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let mut builder =
+        TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng));
+    for _ in 0..4 {
+        builder.add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            rng.gen(),
+            AssetId::BASE,
+            rng.gen(),
+        );
+    }
+    let mut tx = builder.finalize();
+    let witness_index = tx.inputs()[2].witness_index().unwrap() as usize;
+    let foreign_signature =
+        Signature::sign(&SecretKey::random(rng), &Message::new([1u8; 32]));
+    tx.witnesses_mut()[witness_index] = foreign_signature.as_ref().to_vec().into();
+
+    // When
+    let err = tx
+        .check(1000.into(), &test_params())
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(err, ValidityError::InputInvalidSignature { index: 2 });
+}
+
+#[test]
+fn script__check__reports_index_and_witness_index_of_input_with_missing_witness() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    // Given
+    let secret = SecretKey::random(rng);
+    let tx = TransactionBuilder::script(generate_bytes(rng), generate_bytes(rng))
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), AssetId::BASE, rng.gen())
+        .add_unsigned_coin_input(secret, rng.gen(), rng.gen(), AssetId::BASE, rng.gen())
+        .add_input(Input::coin_signed(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            AssetId::BASE,
+            rng.gen(),
+            5,
+        ))
+        .finalize();
+
+    // When
+    let err = tx
+        .check(1000.into(), &test_params())
+        .expect_err("Expected erroneous transaction");
+
+    // Then
+    assert_eq!(
+        err,
+        ValidityError::InputWitnessIndexBounds {
+            index: 2,
+            witness_index: 5
+        }
+    );
+}
+
+#[test]
+fn validity_error__display_includes_index() {
+    assert_eq!(
+        ValidityError::InputInvalidSignature { index: 7 }.to_string(),
+        "Input 7 has an invalid signature"
+    );
+    assert_eq!(
+        ValidityError::InputWitnessIndexBounds {
+            index: 7,
+            witness_index: 3
+        }
+        .to_string(),
+        "Input 7 refers to the missing witness 3"
+    );
+    assert_eq!(
+        ValidityError::TransactionCreateOutputVariable { index: 4 }.to_string(),
+        "Output 4 of the create transaction is variable"
+    );
+}
+
 #[test]
 fn script__check__happy_path() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -601,7 +946,15 @@ fn script__check__errors_if_script_too_long() {
     .check(block_height, &test_params())
     .expect_err("Expected erroneous transaction");
 
-    assert_eq!(ValidityError::TransactionScriptLength, err);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::ScriptLength,
+            used: SCRIPT_PARAMS.max_script_length + 1,
+            max: SCRIPT_PARAMS.max_script_length,
+            index: None,
+        },
+        err
+    );
 }
 
 #[test]
@@ -625,7 +978,15 @@ fn script__check__errors_if_script_data_too_long() {
     .check(block_height, &test_params())
     .expect_err("Expected erroneous transaction");
 
-    assert_eq!(ValidityError::TransactionScriptDataLength, err);
+    assert_eq!(
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::ScriptDataLength,
+            used: SCRIPT_PARAMS.max_script_data_length + 1,
+            max: SCRIPT_PARAMS.max_script_data_length,
+            index: None,
+        },
+        err
+    );
 }
 
 #[test]
@@ -756,7 +1117,10 @@ fn create__check__cannot_have_multiple_change_outputs() {
 
     assert_eq!(
         err,
-        ValidityError::TransactionOutputChangeAssetIdDuplicated(AssetId::BASE)
+        ValidityError::TransactionOutputChangeAssetIdDuplicated {
+            index: 1,
+            asset_id: AssetId::BASE
+        }
     );
 }
 
@@ -907,6 +1271,49 @@ fn create__check_without_signatures__errors_if_wrong_witness_index() {
     assert_eq!(err, ValidityError::TransactionCreateBytecodeWitnessIndex);
 }
 
+#[test]
+fn create__check_without_signatures__requires_exact_bytecode_length() {
+    let rng = &mut StdRng::seed_from_u64(8586);
+
+    let block_height = 1000.into();
+
+    for len in 0..=9 {
+        // Given
+        let mut tx = Transaction::create(
+            0,
+            Policies::default().with_max_fee(0),
+            rng.gen(),
+            vec![],
+            vec![Input::coin_signed(
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                rng.gen(),
+                1,
+            )],
+            vec![],
+            vec![vec![0xfa; len].into(), Default::default()],
+        );
+        tx.precompute(&CHAIN_ID)
+            .expect("Failed to precompute the metadata");
+
+        // When
+        let result = tx.check_without_signatures(block_height, &test_params());
+
+        // Then
+        if len % 4 == 0 {
+            assert_eq!(result, Ok(()), "bytecode of {len} bytes");
+        } else {
+            assert_eq!(
+                result,
+                Err(ValidityError::TransactionCreateBytecodeLen),
+                "bytecode of {len} bytes"
+            );
+        }
+    }
+}
+
 #[test]
 fn create__check__something() {
     let rng = &mut StdRng::seed_from_u64(8586);
@@ -1006,7 +1413,7 @@ fn script__check__transaction_at_maximum_size_is_valid() {
     let block_height = 100.into();
     let mut params = test_params();
     let max_size = 1024usize;
-    params.tx_params.max_size = max_size as u64;
+    params.set_tx_params(params.tx_params().with_max_size(max_size as u64));
 
     let base_size = {
         let tx = TransactionBuilder::script(vec![], vec![])
@@ -1038,7 +1445,7 @@ fn script__check__transaction_exceeding_maximum_size_is_invalid() {
     let block_height = 100.into();
     let mut params = test_params();
     let max_size = 1024usize;
-    params.tx_params.max_size = max_size as u64;
+    params.set_tx_params(params.tx_params().with_max_size(max_size as u64));
 
     let base_size = {
         let tx = TransactionBuilder::script(vec![], vec![])
@@ -1064,7 +1471,15 @@ fn script__check__transaction_exceeding_maximum_size_is_invalid() {
         .check(block_height, &params)
         .expect_err("Expected valid transaction");
 
-    assert_eq!(err, ValidityError::TransactionSizeLimitExceeded);
+    assert_eq!(
+        err,
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::TransactionSize,
+            used: tx.size() as u64,
+            max: max_size as u64,
+            index: None,
+        }
+    );
 }
 
 #[test]
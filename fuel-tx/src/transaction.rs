@@ -22,7 +22,9 @@ use fuel_types::{
     },
     Address,
     AssetId,
+    BlobId,
     Bytes32,
+    ChainId,
     Nonce,
     Salt,
     Word,
@@ -44,6 +46,9 @@ use alloc::vec::{
 use itertools::Itertools;
 
 mod fee;
+mod framed;
+#[cfg(feature = "serde")]
+pub(crate) mod hex_bytes;
 mod metadata;
 mod repr;
 mod types;
@@ -54,27 +59,54 @@ mod id;
 pub mod consensus_parameters;
 pub mod policies;
 
+#[cfg(feature = "serde")]
+pub use consensus_parameters::JsonConfigError;
 pub use consensus_parameters::{
     ConsensusParameters,
+    ConsensusParametersBuilder,
+    ConsensusParametersError,
+    ConsensusParametersV1,
     ContractParameters,
     DependentCost,
     FeeParameters,
+    FeeParametersError,
+    GasCostDelta,
+    GasCostOverride,
+    GasCostOverrideError,
     GasCosts,
+    GasCostsOverrides,
     GasCostsValues,
     GasUnit,
+    InstructionCostError,
     PredicateParameters,
     ScriptParameters,
     TxParameters,
 };
+#[cfg(feature = "builder")]
+pub(crate) use fee::gas_to_fee;
 pub use fee::{
     Chargeable,
+    FeeBreakdown,
+    GasMetadata,
     TransactionFee,
 };
+pub use framed::{
+    DecodeError,
+    EncodingVersion,
+    FRAMED_TX_HEADER_SIZE,
+    FRAMED_TX_MAGIC,
+};
 pub use metadata::Cacheable;
-pub use repr::TransactionRepr;
+pub use repr::{
+    TransactionRepr,
+    TransactionType,
+    WrongTransactionType,
+};
 pub use types::*;
 pub use validity::{
+    BudgetKind,
     FormatValidityChecks,
+    InputSignatureError,
     ValidityError,
 };
 
@@ -94,6 +126,9 @@ pub enum Transaction {
     Script(Script),
     Create(Create),
     Mint(Mint),
+    Upgrade(Upgrade),
+    Upload(Upload),
+    Blob(Blob),
 }
 
 impl Default for Transaction {
@@ -190,6 +225,68 @@ impl Transaction {
         }
     }
 
+    pub fn upgrade(
+        upgrade_purpose: UpgradePurpose,
+        policies: Policies,
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+        witnesses: Vec<Witness>,
+    ) -> Upgrade {
+        Upgrade {
+            upgrade_purpose,
+            policies,
+            inputs,
+            outputs,
+            witnesses,
+            metadata: None,
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn upload(
+        root: Bytes32,
+        witness_index: u8,
+        subsection_index: u16,
+        subsections_number: u16,
+        proof_set: Vec<Bytes32>,
+        policies: Policies,
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+        witnesses: Vec<Witness>,
+    ) -> Upload {
+        Upload {
+            root,
+            witness_index,
+            subsection_index,
+            subsections_number,
+            proof_set,
+            policies,
+            inputs,
+            outputs,
+            witnesses,
+            metadata: None,
+        }
+    }
+
+    pub fn blob(
+        id: BlobId,
+        witness_index: u8,
+        policies: Policies,
+        inputs: Vec<Input>,
+        outputs: Vec<Output>,
+        witnesses: Vec<Witness>,
+    ) -> Blob {
+        Blob {
+            id,
+            witness_index,
+            policies,
+            inputs,
+            outputs,
+            witnesses,
+            metadata: None,
+        }
+    }
+
     /// Convert the type into a JSON string
     ///
     /// This is implemented as infallible because serde_json will fail only if the type
@@ -217,6 +314,11 @@ impl Transaction {
         serde_json::from_str(json.as_ref()).ok()
     }
 
+    /// The type of the transaction, e.g. for logs and metrics.
+    pub fn tx_type(&self) -> TransactionType {
+        self.into()
+    }
+
     pub const fn is_script(&self) -> bool {
         matches!(self, Self::Script { .. })
     }
@@ -229,6 +331,18 @@ impl Transaction {
         matches!(self, Self::Mint { .. })
     }
 
+    pub const fn is_upgrade(&self) -> bool {
+        matches!(self, Self::Upgrade { .. })
+    }
+
+    pub const fn is_upload(&self) -> bool {
+        matches!(self, Self::Upload { .. })
+    }
+
+    pub const fn is_blob(&self) -> bool {
+        matches!(self, Self::Blob { .. })
+    }
+
     pub const fn as_script(&self) -> Option<&Script> {
         match self {
             Self::Script(script) => Some(script),
@@ -270,6 +384,101 @@ impl Transaction {
             _ => None,
         }
     }
+
+    pub const fn as_upgrade(&self) -> Option<&Upgrade> {
+        match self {
+            Self::Upgrade(upgrade) => Some(upgrade),
+            _ => None,
+        }
+    }
+
+    pub fn as_upgrade_mut(&mut self) -> Option<&mut Upgrade> {
+        match self {
+            Self::Upgrade(upgrade) => Some(upgrade),
+            _ => None,
+        }
+    }
+
+    pub const fn as_upload(&self) -> Option<&Upload> {
+        match self {
+            Self::Upload(upload) => Some(upload),
+            _ => None,
+        }
+    }
+
+    pub fn as_upload_mut(&mut self) -> Option<&mut Upload> {
+        match self {
+            Self::Upload(upload) => Some(upload),
+            _ => None,
+        }
+    }
+
+    pub const fn as_blob(&self) -> Option<&Blob> {
+        match self {
+            Self::Blob(blob) => Some(blob),
+            _ => None,
+        }
+    }
+
+    pub fn as_blob_mut(&mut self) -> Option<&mut Blob> {
+        match self {
+            Self::Blob(blob) => Some(blob),
+            _ => None,
+        }
+    }
+
+    /// Clears the fields that are zeroed in the transaction seen by predicates, the
+    /// "predicate context", the same way the VM does before verifying or estimating
+    /// predicates. For a script, an upgrade, an upload and a blob, these are:
+    ///
+    /// - the `receipts_root` of a script;
+    /// - the `tx_pointer` and `predicate_gas_used` of coin inputs;
+    /// - the `predicate_gas_used` of message inputs;
+    /// - the `utxo_id`, `balance_root`, `state_root` and `tx_pointer` of contract inputs;
+    /// - the `balance_root` and `state_root` of contract outputs;
+    /// - the `amount` of change outputs;
+    /// - the `to`, `amount` and `asset_id` of variable outputs.
+    ///
+    /// The predicates and the predicate data of all inputs, including the ones of other
+    /// predicates, and the witnesses are kept. A create is seen by its predicates as is,
+    /// and a mint has no predicates and is kept as is.
+    ///
+    /// See the "Note" sections of the specification:
+    /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/tx-format/input.md>.
+    pub fn prepare_predicate_context(&mut self) {
+        match self {
+            Self::Script(script) => script.prepare_init_predicate(),
+            Self::Upgrade(upgrade) => upgrade.prepare_init_predicate(),
+            Self::Upload(upload) => upload.prepare_init_predicate(),
+            Self::Blob(blob) => blob.prepare_init_predicate(),
+            Self::Create(_) | Self::Mint(_) => {}
+        }
+    }
+
+    /// The canonical bytes of the predicate context, see
+    /// [`Self::prepare_predicate_context`], as written to the VM memory for predicates:
+    /// the transaction id, the transaction size as a big-endian word and the serialized
+    /// transaction.
+    ///
+    /// In the VM memory, the size and the transaction follow the balances, which are
+    /// zeroed for predicates, so the serialized transaction starts at
+    /// [`ConsensusParameters::tx_offset`].
+    pub fn predicate_context_bytes(&self, chain_id: &ChainId) -> Vec<u8> {
+        let mut tx = self.clone();
+        tx.prepare_predicate_context();
+
+        let id = tx.id(chain_id);
+        let tx_bytes = tx.to_bytes();
+        let tx_size = tx_bytes.len() as Word;
+
+        let mut bytes = Vec::with_capacity(
+            Bytes32::LEN + fuel_types::bytes::WORD_SIZE + tx_bytes.len(),
+        );
+        bytes.extend_from_slice(id.as_ref());
+        bytes.extend_from_slice(&tx_size.to_be_bytes());
+        bytes.extend_from_slice(&tx_bytes);
+        bytes
+    }
 }
 
 pub trait Executable: field::Inputs + field::Outputs + field::Witnesses {
@@ -434,12 +643,117 @@ impl From<Mint> for Transaction {
     }
 }
 
+impl From<Upgrade> for Transaction {
+    fn from(upgrade: Upgrade) -> Self {
+        Transaction::Upgrade(upgrade)
+    }
+}
+
+impl From<Upload> for Transaction {
+    fn from(upload: Upload) -> Self {
+        Transaction::Upload(upload)
+    }
+}
+
+impl From<Blob> for Transaction {
+    fn from(blob: Blob) -> Self {
+        Transaction::Blob(blob)
+    }
+}
+
+impl TryFrom<Transaction> for Script {
+    type Error = WrongTransactionType;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx {
+            Transaction::Script(script) => Ok(script),
+            tx => Err(WrongTransactionType {
+                expected: TransactionType::Script,
+                found: tx.tx_type(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Transaction> for Create {
+    type Error = WrongTransactionType;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx {
+            Transaction::Create(create) => Ok(create),
+            tx => Err(WrongTransactionType {
+                expected: TransactionType::Create,
+                found: tx.tx_type(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Transaction> for Mint {
+    type Error = WrongTransactionType;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx {
+            Transaction::Mint(mint) => Ok(mint),
+            tx => Err(WrongTransactionType {
+                expected: TransactionType::Mint,
+                found: tx.tx_type(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Transaction> for Upgrade {
+    type Error = WrongTransactionType;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx {
+            Transaction::Upgrade(upgrade) => Ok(upgrade),
+            tx => Err(WrongTransactionType {
+                expected: TransactionType::Upgrade,
+                found: tx.tx_type(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Transaction> for Upload {
+    type Error = WrongTransactionType;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx {
+            Transaction::Upload(upload) => Ok(upload),
+            tx => Err(WrongTransactionType {
+                expected: TransactionType::Upload,
+                found: tx.tx_type(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Transaction> for Blob {
+    type Error = WrongTransactionType;
+
+    fn try_from(tx: Transaction) -> Result<Self, Self::Error> {
+        match tx {
+            Transaction::Blob(blob) => Ok(blob),
+            tx => Err(WrongTransactionType {
+                expected: TransactionType::Blob,
+                found: tx.tx_type(),
+            }),
+        }
+    }
+}
+
 impl Serialize for Transaction {
     fn size_static(&self) -> usize {
         match self {
             Transaction::Script(script) => script.size_static(),
             Transaction::Create(create) => create.size_static(),
             Transaction::Mint(mint) => mint.size_static(),
+            Transaction::Upgrade(upgrade) => upgrade.size_static(),
+            Transaction::Upload(upload) => upload.size_static(),
+            Transaction::Blob(blob) => blob.size_static(),
         }
     }
 
@@ -448,6 +762,9 @@ impl Serialize for Transaction {
             Transaction::Script(script) => script.size_dynamic(),
             Transaction::Create(create) => create.size_dynamic(),
             Transaction::Mint(mint) => mint.size_dynamic(),
+            Transaction::Upgrade(upgrade) => upgrade.size_dynamic(),
+            Transaction::Upload(upload) => upload.size_dynamic(),
+            Transaction::Blob(blob) => blob.size_dynamic(),
         }
     }
 
@@ -459,6 +776,9 @@ impl Serialize for Transaction {
             Transaction::Script(script) => script.encode_static(buffer),
             Transaction::Create(create) => create.encode_static(buffer),
             Transaction::Mint(mint) => mint.encode_static(buffer),
+            Transaction::Upgrade(upgrade) => upgrade.encode_static(buffer),
+            Transaction::Upload(upload) => upload.encode_static(buffer),
+            Transaction::Blob(blob) => blob.encode_static(buffer),
         }
     }
 
@@ -470,6 +790,9 @@ impl Serialize for Transaction {
             Transaction::Script(script) => script.encode_dynamic(buffer),
             Transaction::Create(create) => create.encode_dynamic(buffer),
             Transaction::Mint(mint) => mint.encode_dynamic(buffer),
+            Transaction::Upgrade(upgrade) => upgrade.encode_dynamic(buffer),
+            Transaction::Upload(upload) => upload.encode_dynamic(buffer),
+            Transaction::Blob(blob) => blob.encode_dynamic(buffer),
         }
     }
 }
@@ -494,6 +817,15 @@ impl Deserialize for Transaction {
             TransactionRepr::Mint => {
                 Ok(<Mint as Deserialize>::decode_static(buffer)?.into())
             }
+            TransactionRepr::Upgrade => {
+                Ok(<Upgrade as Deserialize>::decode_static(buffer)?.into())
+            }
+            TransactionRepr::Upload => {
+                Ok(<Upload as Deserialize>::decode_static(buffer)?.into())
+            }
+            TransactionRepr::Blob => {
+                Ok(<Blob as Deserialize>::decode_static(buffer)?.into())
+            }
         }
     }
 
@@ -505,6 +837,9 @@ impl Deserialize for Transaction {
             Transaction::Script(script) => script.decode_dynamic(buffer),
             Transaction::Create(create) => create.decode_dynamic(buffer),
             Transaction::Mint(mint) => mint.decode_dynamic(buffer),
+            Transaction::Upgrade(upgrade) => upgrade.decode_dynamic(buffer),
+            Transaction::Upload(upload) => upload.decode_dynamic(buffer),
+            Transaction::Blob(blob) => blob.decode_dynamic(buffer),
         }
     }
 }
@@ -515,13 +850,16 @@ pub mod field {
     use crate::{
         input,
         output,
+        output::OutputNotFound,
         policies,
+        GasMetadata,
         Input,
         Output,
         StorageSlot,
         Witness,
     };
     use fuel_types::{
+        canonical::Serialize,
         AssetId,
         BlockHeight,
         Bytes32,
@@ -618,6 +956,24 @@ pub mod field {
         }
     }
 
+    pub trait GasPriceFloor {
+        fn gas_price_floor(&self) -> Word;
+        fn set_gas_price_floor(&mut self, value: Word);
+    }
+
+    impl<T: Policies + ?Sized> GasPriceFloor for T {
+        #[inline(always)]
+        fn gas_price_floor(&self) -> Word {
+            self.policies().get(PolicyType::GasPriceFloor).unwrap_or(0)
+        }
+
+        #[inline(always)]
+        fn set_gas_price_floor(&mut self, value: Word) {
+            self.policies_mut()
+                .set(PolicyType::GasPriceFloor, Some(value))
+        }
+    }
+
     pub trait TxPointer {
         fn tx_pointer(&self) -> &crate::TxPointer;
         fn tx_pointer_mut(&mut self) -> &mut crate::TxPointer;
@@ -684,6 +1040,16 @@ pub mod field {
         fn script_data_offset(&self) -> usize;
     }
 
+    pub trait UpgradePurpose {
+        fn upgrade_purpose(&self) -> &crate::UpgradePurpose;
+        fn upgrade_purpose_mut(&mut self) -> &mut crate::UpgradePurpose;
+        fn upgrade_purpose_offset(&self) -> usize {
+            Self::upgrade_purpose_offset_static()
+        }
+
+        fn upgrade_purpose_offset_static() -> usize;
+    }
+
     pub trait Policies {
         fn policies(&self) -> &policies::Policies;
         fn policies_mut(&mut self) -> &mut policies::Policies;
@@ -720,6 +1086,65 @@ pub mod field {
         fn salt_offset_static() -> usize;
     }
 
+    pub trait BytecodeRoot {
+        fn bytecode_root(&self) -> &Bytes32;
+        fn bytecode_root_mut(&mut self) -> &mut Bytes32;
+        fn bytecode_root_offset(&self) -> usize {
+            Self::bytecode_root_offset_static()
+        }
+
+        fn bytecode_root_offset_static() -> usize;
+    }
+
+    pub trait SubsectionIndex {
+        fn subsection_index(&self) -> &u16;
+        fn subsection_index_mut(&mut self) -> &mut u16;
+        fn subsection_index_offset(&self) -> usize {
+            Self::subsection_index_offset_static()
+        }
+
+        fn subsection_index_offset_static() -> usize;
+    }
+
+    pub trait SubsectionsNumber {
+        fn subsections_number(&self) -> &u16;
+        fn subsections_number_mut(&mut self) -> &mut u16;
+        fn subsections_number_offset(&self) -> usize {
+            Self::subsections_number_offset_static()
+        }
+
+        fn subsections_number_offset_static() -> usize;
+    }
+
+    pub trait ProofSet {
+        fn proof_set(&self) -> &Vec<Bytes32>;
+        fn proof_set_mut(&mut self) -> &mut Vec<Bytes32>;
+        fn proof_set_offset(&self) -> usize {
+            Self::proof_set_offset_static()
+        }
+
+        fn proof_set_offset_static() -> usize;
+
+        /// Returns the offset to the proof at `idx` index, if any.
+        fn proof_set_offset_at(&self, idx: usize) -> Option<usize> {
+            if idx < self.proof_set().len() {
+                Some(self.proof_set_offset() + idx * Bytes32::LEN)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub trait BlobId {
+        fn blob_id(&self) -> &fuel_types::BlobId;
+        fn blob_id_mut(&mut self) -> &mut fuel_types::BlobId;
+        fn blob_id_offset(&self) -> usize {
+            Self::blob_id_offset_static()
+        }
+
+        fn blob_id_offset_static() -> usize;
+    }
+
     pub trait StorageSlots {
         fn storage_slots(&self) -> &Vec<StorageSlot>;
         fn storage_slots_mut(&mut self) -> StorageSlotRef;
@@ -781,6 +1206,42 @@ pub mod field {
 
         /// Returns the offset to the `Output` at `idx` index, if any.
         fn outputs_offset_at(&self, idx: usize) -> Option<usize>;
+
+        /// Returns the index of the `Output::Change` receiving the change of the
+        /// `asset_id`, if any.
+        fn find_change_output(&self, asset_id: &AssetId) -> Option<usize> {
+            self.outputs().iter().position(|output| {
+                matches!(output, Output::Change { asset_id: a, .. } if a == asset_id)
+            })
+        }
+
+        /// Returns the `Output::Variable` outputs along with their indexes.
+        fn variable_outputs(&self) -> impl Iterator<Item = (usize, &Output)> {
+            self.outputs()
+                .iter()
+                .enumerate()
+                .filter(|(_, output)| output.is_variable())
+        }
+
+        /// Sets the `amount` of the `Output::Change` receiving the change of the
+        /// `asset_id`.
+        fn update_change_amount(
+            &mut self,
+            asset_id: &AssetId,
+            amount: Word,
+        ) -> Result<(), OutputNotFound> {
+            let index = self.find_change_output(asset_id).ok_or(OutputNotFound {
+                asset_id: *asset_id,
+            })?;
+
+            if let Some(Output::Change { amount: a, .. }) =
+                self.outputs_mut().get_mut(index)
+            {
+                *a = amount;
+            }
+
+            Ok(())
+        }
     }
 
     pub trait Witnesses {
@@ -790,6 +1251,31 @@ pub mod field {
 
         /// Returns the offset to the `Witness` at `idx` index, if any.
         fn witnesses_offset_at(&self, idx: usize) -> Option<usize>;
+
+        /// Returns the size of the serialized witnesses, including the length prefixes
+        /// and the padding. It is the size limited by the `WitnessLimit` policy.
+        fn witnesses_size(&self) -> usize {
+            self.witnesses().size_dynamic()
+        }
+    }
+
+    pub(crate) mod private {
+        use super::GasMetadata;
+
+        /// Sealed setter of the memoized gas, so only
+        /// [`Chargeable::precompute_gas`](crate::Chargeable::precompute_gas) can store
+        /// it.
+        pub trait SetGasMetadata {
+            /// Stores the memoized gas in the metadata. Does nothing if the transaction
+            /// isn't precomputed.
+            fn set_gas_metadata(&mut self, gas: GasMetadata);
+        }
+    }
+
+    pub trait ChargeableMetadata: private::SetGasMetadata {
+        /// The gas memoized by
+        /// [`Chargeable::precompute_gas`](crate::Chargeable::precompute_gas), if any.
+        fn gas_metadata(&self) -> Option<&GasMetadata>;
     }
 }
 
@@ -957,9 +1443,218 @@ pub mod typescript {
     ts_methods!(Mint, crate::Transaction::Mint);
 }
 
+#[cfg(all(test, feature = "std"))]
+mod snapshot_tests;
+
 #[cfg(test)]
 mod tests {
+    #![allow(non_snake_case)]
+
     use super::*;
+    use crate::field::{
+        ChargeableMetadata,
+        Outputs,
+        ReceiptsRoot,
+        WitnessLimit,
+    };
+
+    fn malleable_inputs() -> Vec<Input> {
+        vec![
+            Input::coin_predicate(
+                UtxoId::new([1u8; 32].into(), 2),
+                [2u8; 32].into(),
+                11,
+                [3u8; 32].into(),
+                TxPointer::new(46.into(), 5),
+                100_000,
+                vec![4u8; 8],
+                vec![5u8; 6],
+            ),
+            Input::contract(
+                UtxoId::new([6u8; 32].into(), 7),
+                [8u8; 32].into(),
+                [9u8; 32].into(),
+                TxPointer::new(47.into(), 6),
+                [10u8; 32].into(),
+            ),
+            Input::message_data_predicate(
+                [11u8; 32].into(),
+                [12u8; 32].into(),
+                13,
+                [14u8; 32].into(),
+                200_000,
+                vec![15u8; 4],
+                vec![16u8; 8],
+                vec![17u8; 3],
+            ),
+        ]
+    }
+
+    fn malleable_outputs() -> Vec<Output> {
+        vec![
+            Output::coin([18u8; 32].into(), 19, [3u8; 32].into()),
+            Output::contract(1, [20u8; 32].into(), [21u8; 32].into()),
+            Output::change([22u8; 32].into(), 23, [3u8; 32].into()),
+            Output::variable([24u8; 32].into(), 25, [26u8; 32].into()),
+        ]
+    }
+
+    /// A script with non-zero values in all the fields cleared for predicates.
+    pub(super) fn malleable_script() -> Script {
+        let mut script = Transaction::script(
+            1_000,
+            vec![27u8; 8],
+            vec![28u8; 4],
+            Policies::new().with_max_fee(1_000),
+            malleable_inputs(),
+            malleable_outputs(),
+            vec![vec![29u8; 64].into()],
+        );
+        *script.receipts_root_mut() = [30u8; 32].into();
+        script
+    }
+
+    /// A create with non-zero values in the fields cleared for the predicates of a
+    /// script.
+    pub(super) fn malleable_create() -> Create {
+        Transaction::create(
+            0,
+            Policies::new().with_max_fee(1_000),
+            [31u8; 32].into(),
+            vec![],
+            malleable_inputs(),
+            malleable_outputs(),
+            vec![vec![32u8; 8].into()],
+        )
+    }
+
+    fn assert_predicate_context_of_inputs_and_outputs(
+        inputs: &[Input],
+        outputs: &[Output],
+    ) {
+        assert_eq!(inputs.len(), 3);
+        assert!(matches!(
+            &inputs[0],
+            Input::CoinPredicate(coin)
+                if coin.tx_pointer == TxPointer::default()
+                    && coin.predicate_gas_used == 0
+                    && coin.amount == 11
+                    && coin.predicate == vec![4u8; 8]
+                    && coin.predicate_data == vec![5u8; 6]
+        ));
+        assert_eq!(
+            inputs[1],
+            Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                [10u8; 32].into(),
+            )
+        );
+        assert!(matches!(
+            &inputs[2],
+            Input::MessageDataPredicate(message)
+                if message.predicate_gas_used == 0
+                    && message.data == vec![15u8; 4]
+                    && message.predicate == vec![16u8; 8]
+                    && message.predicate_data == vec![17u8; 3]
+        ));
+
+        assert_eq!(
+            outputs,
+            [
+                Output::coin([18u8; 32].into(), 19, [3u8; 32].into()),
+                Output::contract(1, Default::default(), Default::default()),
+                Output::change([22u8; 32].into(), 0, [3u8; 32].into()),
+                Output::variable(Default::default(), 0, Default::default()),
+            ]
+        );
+    }
+
+    #[test]
+    fn prepare_predicate_context__clears_malleable_fields_of_script() {
+        // Given
+        let mut tx: Transaction = malleable_script().into();
+
+        // When
+        tx.prepare_predicate_context();
+
+        // Then
+        let script = tx.as_script().expect("Script");
+        assert_eq!(*script.receipts_root(), Bytes32::zeroed());
+        assert_eq!(script.witnesses, vec![vec![29u8; 64].into()]);
+        assert_predicate_context_of_inputs_and_outputs(&script.inputs, &script.outputs);
+    }
+
+    #[test]
+    fn prepare_predicate_context__keeps_create() {
+        // Given
+        let create: Transaction = malleable_create().into();
+        let mut tx = create.clone();
+
+        // When
+        tx.prepare_predicate_context();
+
+        // Then
+        assert_eq!(tx, create);
+    }
+
+    #[test]
+    fn prepare_predicate_context__keeps_mint() {
+        // Given
+        let mint: Transaction = Transaction::mint(
+            TxPointer::new(1.into(), 2),
+            input::contract::Contract {
+                utxo_id: UtxoId::new([1u8; 32].into(), 2),
+                balance_root: [2u8; 32].into(),
+                state_root: [3u8; 32].into(),
+                tx_pointer: TxPointer::new(4.into(), 5),
+                contract_id: [6u8; 32].into(),
+            },
+            output::contract::Contract {
+                input_index: 0,
+                balance_root: [7u8; 32].into(),
+                state_root: [8u8; 32].into(),
+            },
+            9,
+            [10u8; 32].into(),
+            11,
+        )
+        .into();
+        let mut tx = mint.clone();
+
+        // When
+        tx.prepare_predicate_context();
+
+        // Then
+        assert_eq!(tx, mint);
+    }
+
+    #[test]
+    fn predicate_context_bytes__is_id_size_and_serialized_context() {
+        let chain_id = ChainId::new(9);
+
+        for tx in [
+            Transaction::from(malleable_script()),
+            malleable_create().into(),
+        ] {
+            // Given
+            let mut context = tx.clone();
+            context.prepare_predicate_context();
+            let context_bytes = context.to_bytes();
+
+            // When
+            let bytes = tx.predicate_context_bytes(&chain_id);
+
+            // Then
+            let (id, rest) = bytes.split_at(Bytes32::LEN);
+            let (size, rest) = rest.split_at(8);
+            assert_eq!(id, tx.id(&chain_id).as_ref());
+            assert_eq!(size, (context_bytes.len() as Word).to_be_bytes());
+            assert_eq!(rest, context_bytes);
+        }
+    }
 
     #[test]
     fn metered_data_includes_witnesses() {
@@ -1013,4 +1708,283 @@ mod tests {
                 + create_with_witnesses.witnesses.size_dynamic()
         );
     }
+
+    fn gas_params() -> (GasCosts, FeeParameters) {
+        let gas_costs = GasCosts::default();
+        let fee = FeeParameters::DEFAULT.with_gas_per_byte(3);
+        (gas_costs, fee)
+    }
+
+    #[test]
+    fn precompute_gas__cached_gas_matches_uncached_gas_of_script_with_predicates() {
+        let (gas_costs, fee) = gas_params();
+        let uncached = malleable_script();
+
+        // Given
+        let mut cached = uncached.clone();
+        cached
+            .precompute(&ChainId::default())
+            .expect("Should be able to calculate cache");
+
+        // When
+        cached.precompute_gas(&gas_costs, &fee);
+
+        // Then
+        let gas = cached.gas_metadata().expect("The gas is memoized");
+        assert!(uncached.gas_metadata().is_none());
+        assert_eq!(gas.min_gas(), uncached.min_gas(&gas_costs, &fee));
+        assert_eq!(gas.max_gas(), uncached.max_gas(&gas_costs, &fee));
+        assert_eq!(
+            cached.min_gas(&gas_costs, &fee),
+            uncached.min_gas(&gas_costs, &fee)
+        );
+        assert_eq!(
+            cached.max_gas(&gas_costs, &fee),
+            uncached.max_gas(&gas_costs, &fee)
+        );
+    }
+
+    #[test]
+    fn precompute_gas__is_ignored_for_other_parameters() {
+        let (gas_costs, fee) = gas_params();
+        let mut script = malleable_script();
+        script
+            .precompute(&ChainId::default())
+            .expect("Should be able to calculate cache");
+
+        // Given
+        script.precompute_gas(&gas_costs, &fee);
+        let other_fee = fee.with_gas_per_byte(4);
+
+        // When
+        let max_gas = script.max_gas(&gas_costs, &other_fee);
+
+        // Then
+        let gas = script.gas_metadata().expect("The gas is memoized");
+        assert!(!gas.is_computed_for(&gas_costs, &other_fee));
+        assert_ne!(max_gas, gas.max_gas());
+        assert_eq!(max_gas, malleable_script().max_gas(&gas_costs, &other_fee));
+    }
+
+    #[test]
+    fn precompute_gas__is_dropped_by_mutation() {
+        let (gas_costs, fee) = gas_params();
+        let mut script = malleable_script();
+        script
+            .precompute(&ChainId::default())
+            .expect("Should be able to calculate cache");
+        script.precompute_gas(&gas_costs, &fee);
+
+        // When
+        script.set_witness_limit(script.witness_limit() + 100);
+
+        // Then
+        assert!(script.gas_metadata().is_none());
+        let mut expected = malleable_script();
+        expected.set_witness_limit(script.witness_limit());
+        assert_eq!(
+            script.max_gas(&gas_costs, &fee),
+            expected.max_gas(&gas_costs, &fee)
+        );
+    }
+
+    #[test]
+    fn precompute_gas__does_nothing_without_precompute() {
+        let (gas_costs, fee) = gas_params();
+        let mut script = malleable_script();
+
+        // When
+        script.precompute_gas(&gas_costs, &fee);
+
+        // Then
+        assert!(script.gas_metadata().is_none());
+    }
+
+    #[test]
+    fn try_from__returns_the_variant_of_the_same_type() {
+        // Given
+        let script = Script::default();
+        let create = Create::default();
+        let mint = Mint::default();
+
+        // When
+        let converted_script = Script::try_from(Transaction::from(script.clone()));
+        let converted_create = Create::try_from(Transaction::from(create.clone()));
+        let converted_mint = Mint::try_from(Transaction::from(mint.clone()));
+
+        // Then
+        assert_eq!(converted_script, Ok(script));
+        assert_eq!(converted_create, Ok(create));
+        assert_eq!(converted_mint, Ok(mint));
+    }
+
+    #[test]
+    fn try_from__reports_the_expected_and_found_types_on_mismatch() {
+        // Given
+        let script = Transaction::from(Script::default());
+        let create = Transaction::from(Create::default());
+        let mint = Transaction::from(Mint::default());
+
+        // When
+        let script_as_create = Create::try_from(script.clone());
+        let script_as_mint = Mint::try_from(script);
+        let create_as_script = Script::try_from(create.clone());
+        let create_as_mint = Mint::try_from(create);
+        let mint_as_script = Script::try_from(mint.clone());
+        let mint_as_create = Create::try_from(mint);
+
+        // Then
+        let wrong = |expected, found| WrongTransactionType { expected, found };
+        assert_eq!(
+            script_as_create.unwrap_err(),
+            wrong(TransactionType::Create, TransactionType::Script)
+        );
+        assert_eq!(
+            script_as_mint.unwrap_err(),
+            wrong(TransactionType::Mint, TransactionType::Script)
+        );
+        assert_eq!(
+            create_as_script.unwrap_err(),
+            wrong(TransactionType::Script, TransactionType::Create)
+        );
+        assert_eq!(
+            create_as_mint.unwrap_err(),
+            wrong(TransactionType::Mint, TransactionType::Create)
+        );
+        assert_eq!(
+            mint_as_script.unwrap_err(),
+            wrong(TransactionType::Script, TransactionType::Mint)
+        );
+        assert_eq!(
+            mint_as_create.unwrap_err(),
+            wrong(TransactionType::Create, TransactionType::Mint)
+        );
+    }
+
+    #[test]
+    fn wrong_transaction_type__display_names_both_types() {
+        // Given
+        let error = Script::try_from(Transaction::from(Mint::default()))
+            .expect_err("A mint is not a script");
+
+        // When
+        let message = alloc::format!("{error}");
+
+        // Then
+        assert_eq!(
+            message,
+            "Expected the `Script` transaction, but found the `Mint` transaction"
+        );
+    }
+
+    #[test]
+    fn tx_type__matches_the_variant() {
+        assert_eq!(
+            Transaction::from(Script::default()).tx_type(),
+            TransactionType::Script
+        );
+        assert_eq!(
+            Transaction::from(Create::default()).tx_type(),
+            TransactionType::Create
+        );
+        assert_eq!(
+            Transaction::from(Mint::default()).tx_type(),
+            TransactionType::Mint
+        );
+        assert_eq!(
+            Transaction::from(Upgrade::default()).tx_type(),
+            TransactionType::Upgrade
+        );
+        assert_eq!(
+            Transaction::from(Upload::default()).tx_type(),
+            TransactionType::Upload
+        );
+        assert_eq!(
+            Transaction::from(Blob::default()).tx_type(),
+            TransactionType::Blob
+        );
+    }
+
+    fn script_with_outputs(outputs: Vec<Output>) -> Script {
+        Transaction::script(0, vec![], vec![], Policies::new(), vec![], outputs, vec![])
+    }
+
+    #[test]
+    fn find_change_output__returns_the_index_for_each_asset() {
+        // Given
+        let base = AssetId::BASE;
+        let other: AssetId = [1u8; 32].into();
+        let script = script_with_outputs(vec![
+            Output::coin(Address::zeroed(), 1, base),
+            Output::change(Address::zeroed(), 0, other),
+            Output::variable(Address::zeroed(), 0, base),
+            Output::change(Address::zeroed(), 0, base),
+        ]);
+
+        // When
+        let base_change = script.find_change_output(&base);
+        let other_change = script.find_change_output(&other);
+        let missing_change = script.find_change_output(&[2u8; 32].into());
+
+        // Then
+        assert_eq!(base_change, Some(3));
+        assert_eq!(other_change, Some(1));
+        assert_eq!(missing_change, None);
+    }
+
+    #[test]
+    fn variable_outputs__yields_only_variable_outputs_with_their_indexes() {
+        // Given
+        let first = Output::variable(Address::zeroed(), 0, AssetId::BASE);
+        let second = Output::variable([1u8; 32].into(), 5, [2u8; 32].into());
+        let script = script_with_outputs(vec![
+            Output::change(Address::zeroed(), 0, AssetId::BASE),
+            first,
+            Output::coin(Address::zeroed(), 1, AssetId::BASE),
+            second,
+        ]);
+
+        // When
+        let variables = script.variable_outputs().collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(variables, vec![(1, &first), (3, &second)]);
+    }
+
+    #[test]
+    fn update_change_amount__sets_only_the_change_of_the_asset() {
+        // Given
+        let base = AssetId::BASE;
+        let other: AssetId = [1u8; 32].into();
+        let mut script = script_with_outputs(vec![
+            Output::change(Address::zeroed(), 0, base),
+            Output::change(Address::zeroed(), 0, other),
+        ]);
+
+        // When
+        let result = script.update_change_amount(&other, 42);
+
+        // Then
+        assert_eq!(result, Ok(()));
+        assert_eq!(script.outputs()[0].amount(), Some(0));
+        assert_eq!(script.outputs()[1].amount(), Some(42));
+    }
+
+    #[test]
+    fn update_change_amount__fails_without_change_output() {
+        // Given
+        let asset_id: AssetId = [1u8; 32].into();
+        let outputs = vec![
+            Output::coin(Address::zeroed(), 1, asset_id),
+            Output::variable(Address::zeroed(), 0, asset_id),
+        ];
+        let mut script = script_with_outputs(outputs.clone());
+
+        // When
+        let result = script.update_change_amount(&asset_id, 42);
+
+        // Then
+        assert_eq!(result, Err(OutputNotFound { asset_id }));
+        assert_eq!(script.outputs(), &outputs);
+    }
 }
@@ -1,35 +1,48 @@
+use fuel_crypto::Hasher;
 use fuel_types::{
     bytes::WORD_SIZE,
+    canonical::{
+        self,
+        Deserialize,
+        Serialize,
+    },
+    Address,
     AssetId,
     Bytes32,
     ChainId,
 };
 
 pub mod gas;
+#[cfg(feature = "serde")]
+mod json;
 
 pub use gas::{
     DependentCost,
+    GasCostDelta,
+    GasCostOverride,
+    GasCostOverrideError,
     GasCosts,
+    GasCostsOverrides,
     GasCostsValues,
     GasUnit,
+    InstructionCostError,
 };
+#[cfg(feature = "serde")]
+pub use json::JsonConfigError;
 
 const MAX_GAS: u64 = 100_000_000;
 const MAX_SIZE: u64 = 17 * 1024 * 1024;
 
-/// A collection of parameters for convenience
+/// A versioned collection of parameters for convenience.
+///
+/// Serialized with an explicit tag of the version, so new versions can be introduced
+/// without breaking the existing configs.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
-pub struct ConsensusParameters {
-    pub tx_params: TxParameters,
-    pub predicate_params: PredicateParameters,
-    pub script_params: ScriptParameters,
-    pub contract_params: ContractParameters,
-    pub fee_params: FeeParameters,
-    pub chain_id: ChainId,
-    pub gas_costs: GasCosts,
-    pub base_asset_id: AssetId,
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
+pub enum ConsensusParameters {
+    /// The first version of the consensus parameters.
+    V1(ConsensusParametersV1),
 }
 
 impl Default for ConsensusParameters {
@@ -39,35 +52,39 @@ impl Default for ConsensusParameters {
 }
 
 impl ConsensusParameters {
-    /// Constructor for the `ConsensusParameters` with Standard values.
+    /// Constructor for the `ConsensusParameters` with Standard values, using the latest
+    /// version.
     pub fn standard() -> Self {
-        Self {
-            tx_params: TxParameters::DEFAULT,
-            predicate_params: PredicateParameters::DEFAULT,
-            script_params: ScriptParameters::DEFAULT,
-            contract_params: ContractParameters::DEFAULT,
-            fee_params: FeeParameters::DEFAULT,
-            chain_id: ChainId::default(),
-            gas_costs: GasCosts::default(),
-            base_asset_id: Default::default(),
-        }
+        ConsensusParametersV1::standard().into()
     }
 
-    /// Constructor for the `ConsensusParameters` with Standard values around `ChainId`.
+    /// Constructor for the `ConsensusParameters` with Standard values around `ChainId`,
+    /// using the latest version.
     pub fn standard_with_id(chain_id: ChainId) -> Self {
-        Self {
-            tx_params: TxParameters::DEFAULT,
-            predicate_params: PredicateParameters::DEFAULT,
-            script_params: ScriptParameters::DEFAULT,
-            contract_params: ContractParameters::DEFAULT,
-            fee_params: FeeParameters::DEFAULT,
-            chain_id,
-            gas_costs: GasCosts::default(),
-            base_asset_id: Default::default(),
-        }
+        ConsensusParametersV1::standard_with_id(chain_id).into()
+    }
+
+    /// The digest of the canonical serialization of the parameters, identifying them
+    /// in the parameters upgrades.
+    ///
+    /// Unlike the serde formats, the canonical serialization doesn't depend on the
+    /// platform or on the order of the fields in a config, so equal parameters always
+    /// have the same hash.
+    pub fn hash(&self) -> Bytes32 {
+        Hasher::hash(self.to_bytes())
+    }
+
+    /// Decodes the parameters from their canonical serialization, see [`Self::hash`].
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Result<Self, canonical::Error> {
+        Self::from_bytes(bytes)
     }
 
-    /// Constructor for the `ConsensusParameters`
+    /// Builder of the `ConsensusParameters`, starting from [`Self::standard`].
+    pub fn builder() -> ConsensusParametersBuilder {
+        ConsensusParametersBuilder::new()
+    }
+
+    /// Constructor for the `ConsensusParameters`, using the latest version.
     pub const fn new(
         tx_params: TxParameters,
         predicate_params: PredicateParameters,
@@ -77,8 +94,9 @@ impl ConsensusParameters {
         chain_id: ChainId,
         gas_costs: GasCosts,
         base_asset_id: AssetId,
+        privileged_address: Address,
     ) -> Self {
-        Self {
+        Self::V1(ConsensusParametersV1 {
             tx_params,
             predicate_params,
             script_params,
@@ -87,53 +105,504 @@ impl ConsensusParameters {
             chain_id,
             gas_costs,
             base_asset_id,
-        }
+            privileged_address,
+        })
     }
 
     /// Get the transaction parameters
     pub fn tx_params(&self) -> &TxParameters {
-        &self.tx_params
+        match self {
+            Self::V1(params) => &params.tx_params,
+        }
     }
 
     /// Get the predicate parameters
     pub fn predicate_params(&self) -> &PredicateParameters {
-        &self.predicate_params
+        match self {
+            Self::V1(params) => &params.predicate_params,
+        }
     }
 
     /// Get the script parameters
     pub fn script_params(&self) -> &ScriptParameters {
-        &self.script_params
+        match self {
+            Self::V1(params) => &params.script_params,
+        }
     }
 
     /// Get the contract parameters
     pub fn contract_params(&self) -> &ContractParameters {
-        &self.contract_params
+        match self {
+            Self::V1(params) => &params.contract_params,
+        }
     }
 
     /// Get the fee parameters
     pub fn fee_params(&self) -> &FeeParameters {
-        &self.fee_params
+        match self {
+            Self::V1(params) => &params.fee_params,
+        }
     }
 
     pub fn base_asset_id(&self) -> &AssetId {
-        &self.base_asset_id
+        match self {
+            Self::V1(params) => &params.base_asset_id,
+        }
+    }
+
+    /// Get the address authorized to perform the privileged operations
+    pub fn privileged_address(&self) -> &Address {
+        match self {
+            Self::V1(params) => &params.privileged_address,
+        }
     }
 
     /// Get the chain ID
     pub fn chain_id(&self) -> ChainId {
-        self.chain_id
+        match self {
+            Self::V1(params) => params.chain_id,
+        }
     }
 
     /// Get the gas costs
     pub fn gas_costs(&self) -> &GasCosts {
-        &self.gas_costs
+        match self {
+            Self::V1(params) => &params.gas_costs,
+        }
+    }
+
+    /// Set the transaction parameters
+    pub fn set_tx_params(&mut self, tx_params: TxParameters) {
+        match self {
+            Self::V1(params) => params.tx_params = tx_params,
+        }
+    }
+
+    /// Set the predicate parameters
+    pub fn set_predicate_params(&mut self, predicate_params: PredicateParameters) {
+        match self {
+            Self::V1(params) => params.predicate_params = predicate_params,
+        }
+    }
+
+    /// Set the script parameters
+    pub fn set_script_params(&mut self, script_params: ScriptParameters) {
+        match self {
+            Self::V1(params) => params.script_params = script_params,
+        }
+    }
+
+    /// Set the contract parameters
+    pub fn set_contract_params(&mut self, contract_params: ContractParameters) {
+        match self {
+            Self::V1(params) => params.contract_params = contract_params,
+        }
+    }
+
+    /// Set the fee parameters
+    pub fn set_fee_params(&mut self, fee_params: FeeParameters) {
+        match self {
+            Self::V1(params) => params.fee_params = fee_params,
+        }
+    }
+
+    /// Set the base asset id
+    pub fn set_base_asset_id(&mut self, base_asset_id: AssetId) {
+        match self {
+            Self::V1(params) => params.base_asset_id = base_asset_id,
+        }
+    }
+
+    /// Set the address authorized to perform the privileged operations
+    pub fn set_privileged_address(&mut self, privileged_address: Address) {
+        match self {
+            Self::V1(params) => params.privileged_address = privileged_address,
+        }
+    }
+
+    /// Set the chain ID
+    pub fn set_chain_id(&mut self, chain_id: ChainId) {
+        match self {
+            Self::V1(params) => params.chain_id = chain_id,
+        }
+    }
+
+    /// Set the gas costs
+    pub fn set_gas_costs(&mut self, gas_costs: GasCosts) {
+        match self {
+            Self::V1(params) => params.gas_costs = gas_costs,
+        }
+    }
+}
+
+impl From<ConsensusParametersV1> for ConsensusParameters {
+    fn from(params: ConsensusParametersV1) -> Self {
+        Self::V1(params)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ConsensusParameters {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use alloc::string::String;
+        use core::fmt;
+        use serde::{
+            de::{
+                value::MapAccessDeserializer,
+                DeserializeSeed,
+                EnumAccess,
+                Error,
+                IntoDeserializer,
+                MapAccess,
+                VariantAccess,
+            },
+            Deserialize,
+        };
+
+        const VERSIONS: &[&str] = &["V1"];
+
+        enum Version {
+            V1,
+        }
+
+        struct VersionVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for VersionVisitor {
+            type Value = Version;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a version of the consensus parameters")
+            }
+
+            fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+                match value {
+                    0 => Ok(Version::V1),
+                    _ => Err(E::custom(format_args!(
+                        "unsupported version {value} of the consensus parameters, \
+                         expected one of {VERSIONS:?}"
+                    ))),
+                }
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                match value {
+                    "V1" => Ok(Version::V1),
+                    _ => Err(E::custom(format_args!(
+                        "unsupported version `{value}` of the consensus parameters, \
+                         expected one of {VERSIONS:?}"
+                    ))),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for Version {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                deserializer.deserialize_identifier(VersionVisitor)
+            }
+        }
+
+        /// The fields of the unversioned parameters, which predate the versioning
+        /// and are read as [`ConsensusParametersV1`].
+        const LEGACY_FIELDS: &[&str] = &[
+            "tx_params",
+            "predicate_params",
+            "script_params",
+            "contract_params",
+            "fee_params",
+            "chain_id",
+            "gas_costs",
+            "base_asset_id",
+            "privileged_address",
+        ];
+
+        /// Replays the key already read from the map before the remaining entries.
+        struct LegacyMap<A> {
+            first_key: Option<String>,
+            map: A,
+        }
+
+        impl<'de, A> MapAccess<'de> for LegacyMap<A>
+        where
+            A: MapAccess<'de>,
+        {
+            type Error = A::Error;
+
+            fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, A::Error>
+            where
+                K: DeserializeSeed<'de>,
+            {
+                match self.first_key.take() {
+                    Some(key) => seed.deserialize(key.into_deserializer()).map(Some),
+                    None => self.map.next_key_seed(seed),
+                }
+            }
+
+            fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, A::Error>
+            where
+                V: DeserializeSeed<'de>,
+            {
+                self.map.next_value_seed(seed)
+            }
+        }
+
+        struct ParametersVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ParametersVisitor {
+            type Value = ConsensusParameters;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("versioned consensus parameters")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (version, variant) = data.variant()?;
+                match version {
+                    Version::V1 => variant.newtype_variant().map(ConsensusParameters::V1),
+                }
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let Some(key) = map.next_key::<String>()? else {
+                    return Ok(ConsensusParametersV1::default().into())
+                };
+
+                if key == "V1" {
+                    let params = map.next_value()?;
+                    if let Some(key) = map.next_key::<String>()? {
+                        return Err(A::Error::custom(format_args!(
+                            "unexpected key `{key}` after the version of the consensus \
+                             parameters"
+                        )))
+                    }
+                    Ok(ConsensusParameters::V1(params))
+                } else if LEGACY_FIELDS.contains(&key.as_str()) {
+                    let map = LegacyMap {
+                        first_key: Some(key),
+                        map,
+                    };
+                    ConsensusParametersV1::deserialize(MapAccessDeserializer::new(map))
+                        .map(ConsensusParameters::V1)
+                } else {
+                    Err(A::Error::custom(format_args!(
+                        "unsupported version `{key}` of the consensus parameters, \
+                         expected one of {VERSIONS:?} or the legacy unversioned fields"
+                    )))
+                }
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            // The configs predating the versioning hold the fields of the first
+            // version without the tag.
+            deserializer.deserialize_map(ParametersVisitor)
+        } else {
+            deserializer.deserialize_enum(
+                "ConsensusParameters",
+                VERSIONS,
+                ParametersVisitor,
+            )
+        }
+    }
+}
+
+/// The first version of the consensus parameters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
+pub struct ConsensusParametersV1 {
+    pub tx_params: TxParameters,
+    pub predicate_params: PredicateParameters,
+    pub script_params: ScriptParameters,
+    pub contract_params: ContractParameters,
+    pub fee_params: FeeParameters,
+    pub chain_id: ChainId,
+    pub gas_costs: GasCosts,
+    pub base_asset_id: AssetId,
+    /// The address authorized to perform the privileged operations, e.g. the network
+    /// upgrades. Zero in the configs predating the field.
+    pub privileged_address: Address,
+}
+
+impl Default for ConsensusParametersV1 {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl ConsensusParametersV1 {
+    /// Constructor for the `ConsensusParametersV1` with Standard values.
+    pub fn standard() -> Self {
+        Self::standard_with_id(ChainId::default())
+    }
+
+    /// Constructor for the `ConsensusParametersV1` with Standard values around
+    /// `ChainId`.
+    pub fn standard_with_id(chain_id: ChainId) -> Self {
+        Self {
+            tx_params: TxParameters::DEFAULT,
+            predicate_params: PredicateParameters::DEFAULT,
+            script_params: ScriptParameters::DEFAULT,
+            contract_params: ContractParameters::DEFAULT,
+            fee_params: FeeParameters::DEFAULT,
+            chain_id,
+            gas_costs: GasCosts::default(),
+            base_asset_id: Default::default(),
+            privileged_address: Default::default(),
+        }
+    }
+}
+
+/// The error returned by [`ConsensusParametersBuilder::build`] when the parameters are
+/// inconsistent with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum ConsensusParametersError {
+    /// A predicate may use more gas than the whole transaction.
+    #[display(
+        fmt = "The max gas per predicate {} exceeds the max gas per transaction {}",
+        max_gas_per_predicate,
+        max_gas_per_tx
+    )]
+    PredicateGasExceedsTxGas {
+        /// The maximum gas per predicate.
+        max_gas_per_predicate: u64,
+        /// The maximum gas per transaction.
+        max_gas_per_tx: u64,
+    },
+    /// A contract may be bigger than the transaction deploying it.
+    #[display(
+        fmt = "The max contract size {} exceeds the max transaction size {}",
+        contract_max_size,
+        max_size
+    )]
+    ContractSizeExceedsTxSize {
+        /// The maximum size of a contract, in bytes.
+        contract_max_size: u64,
+        /// The maximum size of a transaction, in bytes.
+        max_size: u64,
+    },
+}
+
+/// Builder of the [`ConsensusParameters`], overriding only some of the standard values.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ConsensusParametersBuilder {
+    params: ConsensusParameters,
+}
+
+impl Default for ConsensusParametersBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConsensusParametersBuilder {
+    /// Creates the builder with the [`ConsensusParameters::standard`] values.
+    pub fn new() -> Self {
+        Self {
+            params: ConsensusParameters::standard(),
+        }
+    }
+
+    /// Replace the chain ID with the given argument
+    pub fn with_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.params.set_chain_id(chain_id);
+        self
+    }
+
+    /// Replace the base asset id with the given argument
+    pub fn with_base_asset_id(mut self, base_asset_id: AssetId) -> Self {
+        self.params.set_base_asset_id(base_asset_id);
+        self
+    }
+
+    /// Replace the privileged address with the given argument
+    pub fn with_privileged_address(mut self, privileged_address: Address) -> Self {
+        self.params.set_privileged_address(privileged_address);
+        self
+    }
+
+    /// Replace the transaction parameters with the given argument
+    pub fn with_tx_params(mut self, tx_params: TxParameters) -> Self {
+        self.params.set_tx_params(tx_params);
+        self
+    }
+
+    /// Replace the predicate parameters with the given argument
+    pub fn with_predicate_params(
+        mut self,
+        predicate_params: PredicateParameters,
+    ) -> Self {
+        self.params.set_predicate_params(predicate_params);
+        self
+    }
+
+    /// Replace the script parameters with the given argument
+    pub fn with_script_params(mut self, script_params: ScriptParameters) -> Self {
+        self.params.set_script_params(script_params);
+        self
+    }
+
+    /// Replace the contract parameters with the given argument
+    pub fn with_contract_params(mut self, contract_params: ContractParameters) -> Self {
+        self.params.set_contract_params(contract_params);
+        self
+    }
+
+    /// Replace the fee parameters with the given argument
+    pub fn with_fee_params(mut self, fee_params: FeeParameters) -> Self {
+        self.params.set_fee_params(fee_params);
+        self
+    }
+
+    /// Replace the gas costs with the given argument
+    pub fn with_gas_costs(mut self, gas_costs: GasCosts) -> Self {
+        self.params.set_gas_costs(gas_costs);
+        self
+    }
+
+    /// Builds the `ConsensusParameters`, checking that the parameters are consistent
+    /// with each other.
+    pub fn build(self) -> Result<ConsensusParameters, ConsensusParametersError> {
+        let tx_params = self.params.tx_params();
+        let predicate_params = self.params.predicate_params();
+        let contract_params = self.params.contract_params();
+
+        if predicate_params.max_gas_per_predicate > tx_params.max_gas_per_tx {
+            return Err(ConsensusParametersError::PredicateGasExceedsTxGas {
+                max_gas_per_predicate: predicate_params.max_gas_per_predicate,
+                max_gas_per_tx: tx_params.max_gas_per_tx,
+            })
+        }
+
+        if contract_params.contract_max_size > tx_params.max_size {
+            return Err(ConsensusParametersError::ContractSizeExceedsTxSize {
+                contract_max_size: contract_params.contract_max_size,
+                max_size: tx_params.max_size,
+            })
+        }
+
+        Ok(self.params)
     }
 }
 
 /// Consensus configurable parameters used for verifying transactions
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedFeeParameters"))]
+#[derive(fuel_types::canonical::Serialize)]
 pub struct FeeParameters {
     /// Factor to convert between gas and transaction assets value.
     pub gas_price_factor: u64,
@@ -141,6 +610,16 @@ pub struct FeeParameters {
     pub gas_per_byte: u64,
 }
 
+/// The error returned when constructing invalid [`FeeParameters`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum FeeParametersError {
+    /// The gas price factor is zero, so gas can't be converted into fees.
+    #[display(fmt = "The gas price factor can't be zero")]
+    ZeroGasPriceFactor,
+}
+
 impl FeeParameters {
     /// Default consensus parameters with settings suggested in fuel-specs
     pub const DEFAULT: Self = Self {
@@ -148,10 +627,34 @@ impl FeeParameters {
         gas_per_byte: 4,
     };
 
-    /// Replace the gas price factor with the given argument
-    pub const fn with_gas_price_factor(mut self, gas_price_factor: u64) -> Self {
+    /// Constructor for the `FeeParameters`, rejecting a zero `gas_price_factor`.
+    pub const fn new(
+        gas_price_factor: u64,
+        gas_per_byte: u64,
+    ) -> Result<Self, FeeParametersError> {
+        Self::DEFAULT
+            .with_gas_per_byte(gas_per_byte)
+            .with_gas_price_factor(gas_price_factor)
+    }
+
+    /// Replace the gas price factor with the given argument, rejecting zero.
+    pub const fn with_gas_price_factor(
+        mut self,
+        gas_price_factor: u64,
+    ) -> Result<Self, FeeParametersError> {
         self.gas_price_factor = gas_price_factor;
-        self
+        match self.validate() {
+            Ok(()) => Ok(self),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Checks the parameters, which may be invalid when built from the public fields.
+    pub const fn validate(&self) -> Result<(), FeeParametersError> {
+        if self.gas_price_factor == 0 {
+            return Err(FeeParametersError::ZeroGasPriceFactor)
+        }
+        Ok(())
     }
 
     pub const fn with_gas_per_byte(mut self, gas_per_byte: u64) -> Self {
@@ -160,6 +663,50 @@ impl FeeParameters {
     }
 }
 
+/// The serialized form of [`FeeParameters`], validated before the conversion.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct UncheckedFeeParameters {
+    gas_price_factor: u64,
+    gas_per_byte: u64,
+}
+
+#[cfg(feature = "serde")]
+impl Default for UncheckedFeeParameters {
+    fn default() -> Self {
+        let FeeParameters {
+            gas_price_factor,
+            gas_per_byte,
+        } = FeeParameters::DEFAULT;
+        Self {
+            gas_price_factor,
+            gas_per_byte,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedFeeParameters> for FeeParameters {
+    type Error = FeeParametersError;
+
+    fn try_from(value: UncheckedFeeParameters) -> Result<Self, Self::Error> {
+        Self::new(value.gas_price_factor, value.gas_per_byte)
+    }
+}
+
+impl Deserialize for FeeParameters {
+    fn decode_static<I: canonical::Input + ?Sized>(
+        buffer: &mut I,
+    ) -> Result<Self, canonical::Error> {
+        let gas_price_factor = u64::decode(buffer)?;
+        let gas_per_byte = u64::decode(buffer)?;
+
+        Self::new(gas_price_factor, gas_per_byte)
+            .map_err(|_| canonical::Error::Unknown("The gas price factor can't be zero"))
+    }
+}
+
 impl Default for FeeParameters {
     fn default() -> Self {
         Self::DEFAULT
@@ -171,6 +718,7 @@ impl Default for FeeParameters {
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub struct PredicateParameters {
     /// Maximum length of predicate, in instructions.
     pub max_predicate_length: u64,
@@ -214,6 +762,15 @@ impl PredicateParameters {
         self.max_message_data_length = max_message_data_length;
         self
     }
+
+    /// Replace the max gas per predicate with the given argument
+    pub const fn with_max_gas_per_predicate(
+        mut self,
+        max_gas_per_predicate: u64,
+    ) -> Self {
+        self.max_gas_per_predicate = max_gas_per_predicate;
+        self
+    }
 }
 
 impl Default for PredicateParameters {
@@ -224,7 +781,8 @@ impl Default for PredicateParameters {
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(default))]
+#[cfg_attr(feature = "serde", serde(from = "UncheckedTxParameters"))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub struct TxParameters {
     /// Maximum number of inputs.
     pub max_inputs: u8,
@@ -236,17 +794,32 @@ pub struct TxParameters {
     pub max_gas_per_tx: u64,
     /// Maximum size in bytes
     pub max_size: u64,
+    /// Maximum number of signatures recovered to verify the signed inputs. Inputs
+    /// sharing a witness only require a single recovery. Defaults to `max_inputs`,
+    /// the previous implicit limit.
+    pub max_signature_checks: u16,
+    /// Maximum number of subsections of the bytecode uploaded by `Upload` transactions.
+    pub max_bytecode_subsections: u16,
+    /// Maximum length of the Merkle proof set of an `Upload` transaction.
+    pub max_proof_set_length: u16,
+    /// Maximum size of the payload of a `Blob` transaction, in bytes.
+    pub max_blob_size: u64,
 }
 
 impl TxParameters {
     /// Default consensus parameters with settings suggested in fuel-specs
     pub const DEFAULT: Self = Self {
-        max_inputs: 255,
+        max_inputs: Self::DEFAULT_MAX_INPUTS,
         max_outputs: 255,
         max_witnesses: 255,
         max_gas_per_tx: MAX_GAS,
         max_size: MAX_SIZE,
+        max_signature_checks: Self::DEFAULT_MAX_INPUTS as u16,
+        max_bytecode_subsections: 256,
+        max_proof_set_length: 16,
+        max_blob_size: 16 * 1024 * 1024,
     };
+    const DEFAULT_MAX_INPUTS: u8 = 255;
 
     /// Transaction memory offset in VM runtime
     pub const fn tx_offset(&self) -> usize {
@@ -285,6 +858,33 @@ impl TxParameters {
         self.max_size = max_size;
         self
     }
+
+    /// Replace the max number of signature checks with the given argument
+    pub const fn with_max_signature_checks(mut self, max_signature_checks: u16) -> Self {
+        self.max_signature_checks = max_signature_checks;
+        self
+    }
+
+    /// Replace the max number of bytecode subsections with the given argument
+    pub const fn with_max_bytecode_subsections(
+        mut self,
+        max_bytecode_subsections: u16,
+    ) -> Self {
+        self.max_bytecode_subsections = max_bytecode_subsections;
+        self
+    }
+
+    /// Replace the max length of the proof set with the given argument
+    pub const fn with_max_proof_set_length(mut self, max_proof_set_length: u16) -> Self {
+        self.max_proof_set_length = max_proof_set_length;
+        self
+    }
+
+    /// Replace the max size of the blob payload with the given argument
+    pub const fn with_max_blob_size(mut self, max_blob_size: u64) -> Self {
+        self.max_blob_size = max_blob_size;
+        self
+    }
 }
 
 impl Default for TxParameters {
@@ -293,14 +893,97 @@ impl Default for TxParameters {
     }
 }
 
+/// The serialized form of [`TxParameters`], where the missing
+/// `max_signature_checks` is derived from `max_inputs`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(default)]
+struct UncheckedTxParameters {
+    max_inputs: u8,
+    max_outputs: u8,
+    max_witnesses: u32,
+    max_gas_per_tx: u64,
+    max_size: u64,
+    #[serde(deserialize_with = "deserialize_some")]
+    max_signature_checks: Option<u16>,
+    max_bytecode_subsections: u16,
+    max_proof_set_length: u16,
+    max_blob_size: u64,
+}
+
+/// Deserializes a present value as `Some`, so the formats that aren't self-describing
+/// don't expect the tag of an `Option`.
+#[cfg(feature = "serde")]
+fn deserialize_some<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    serde::Deserialize::deserialize(deserializer).map(Some)
+}
+
+#[cfg(feature = "serde")]
+impl Default for UncheckedTxParameters {
+    fn default() -> Self {
+        let TxParameters {
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_size,
+            max_signature_checks: _,
+            max_bytecode_subsections,
+            max_proof_set_length,
+            max_blob_size,
+        } = TxParameters::DEFAULT;
+        Self {
+            max_inputs,
+            max_outputs,
+            max_witnesses,
+            max_gas_per_tx,
+            max_size,
+            max_signature_checks: None,
+            max_bytecode_subsections,
+            max_proof_set_length,
+            max_blob_size,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<UncheckedTxParameters> for TxParameters {
+    fn from(value: UncheckedTxParameters) -> Self {
+        Self {
+            max_inputs: value.max_inputs,
+            max_outputs: value.max_outputs,
+            max_witnesses: value.max_witnesses,
+            max_gas_per_tx: value.max_gas_per_tx,
+            max_size: value.max_size,
+            max_signature_checks: value
+                .max_signature_checks
+                .unwrap_or(value.max_inputs as u16),
+            max_bytecode_subsections: value.max_bytecode_subsections,
+            max_proof_set_length: value.max_proof_set_length,
+            max_blob_size: value.max_blob_size,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub struct ScriptParameters {
     /// Maximum length of script, in instructions.
     pub max_script_length: u64,
     /// Maximum length of script data, in bytes.
     pub max_script_data_length: u64,
+    /// Maximum number of receipts the script can emit, not counting the panic and
+    /// the script result receipts ending the execution.
+    pub max_receipts_count: u64,
+    /// Maximum total size of the receipts the script can emit, including their
+    /// data, in bytes.
+    pub max_receipts_size: u64,
 }
 
 impl ScriptParameters {
@@ -308,6 +991,8 @@ impl ScriptParameters {
     pub const DEFAULT: Self = Self {
         max_script_length: 1024 * 1024,
         max_script_data_length: 1024 * 1024,
+        max_receipts_count: u16::MAX as u64 - 2,
+        max_receipts_size: 64 * 1024 * 1024,
     };
 
     /// Replace the max script length with the given argument
@@ -324,6 +1009,18 @@ impl ScriptParameters {
         self.max_script_data_length = max_script_data_length;
         self
     }
+
+    /// Replace the max receipts count with the given argument
+    pub const fn with_max_receipts_count(mut self, max_receipts_count: u64) -> Self {
+        self.max_receipts_count = max_receipts_count;
+        self
+    }
+
+    /// Replace the max receipts size with the given argument
+    pub const fn with_max_receipts_size(mut self, max_receipts_size: u64) -> Self {
+        self.max_receipts_size = max_receipts_size;
+        self
+    }
 }
 
 impl Default for ScriptParameters {
@@ -335,12 +1032,16 @@ impl Default for ScriptParameters {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub struct ContractParameters {
     /// Maximum contract size, in bytes.
     pub contract_max_size: u64,
 
     /// Maximum number of initial storage slots.
     pub max_storage_slots: u64,
+
+    /// Maximum depth of the nested contract calls.
+    pub max_call_depth: u64,
 }
 
 impl ContractParameters {
@@ -348,6 +1049,7 @@ impl ContractParameters {
     pub const DEFAULT: Self = Self {
         contract_max_size: 16 * 1024 * 1024,
         max_storage_slots: 255,
+        max_call_depth: 1024,
     };
 
     /// Replace the max contract size with the given argument
@@ -361,6 +1063,12 @@ impl ContractParameters {
         self.max_storage_slots = max_storage_slots;
         self
     }
+
+    /// Replace the max call depth with the given argument
+    pub const fn with_max_call_depth(mut self, max_call_depth: u64) -> Self {
+        self.max_call_depth = max_call_depth;
+        self
+    }
 }
 
 impl Default for ContractParameters {
@@ -369,6 +1077,305 @@ impl Default for ContractParameters {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    fn custom_params() -> ConsensusParameters {
+        let mut params = ConsensusParameters::standard_with_id(ChainId::new(7));
+        params.set_tx_params(TxParameters::DEFAULT.with_max_inputs(3));
+        params.set_fee_params(FeeParameters::DEFAULT.with_gas_per_byte(11));
+        params.set_base_asset_id(AssetId::new([0xaa; 32]));
+        params.set_privileged_address(Address::new([0xbb; 32]));
+        params
+    }
+
+    #[test]
+    fn consensus_parameters__json_roundtrip() {
+        let params = custom_params();
+
+        let json = serde_json::to_string(&params).expect("Serializable");
+        let decoded: ConsensusParameters =
+            serde_json::from_str(&json).expect("Deserializable");
+
+        assert!(json.starts_with("{\"V1\":"), "{json}");
+        assert_eq!(decoded, params);
+    }
+
+    #[test]
+    fn consensus_parameters__bincode_roundtrip() {
+        let params = custom_params();
+
+        let bytes = bincode::serialize(&params).expect("Serializable");
+        let decoded: ConsensusParameters =
+            bincode::deserialize(&bytes).expect("Deserializable");
+
+        assert_eq!(decoded, params);
+    }
+
+    #[test]
+    fn consensus_parameters__json_without_privileged_address_uses_zero_address() {
+        let mut json = serde_json::to_value(custom_params()).expect("Serializable");
+        json["V1"]
+            .as_object_mut()
+            .expect("The parameters are an object")
+            .remove("privileged_address");
+
+        let decoded: ConsensusParameters =
+            serde_json::from_value(json).expect("Deserializable");
+
+        assert_eq!(decoded.privileged_address(), &Address::zeroed());
+        assert_eq!(decoded.base_asset_id(), custom_params().base_asset_id());
+    }
+
+    #[test]
+    fn tx_parameters__json_without_upload_limits_uses_defaults() {
+        let json = r#"{ "max_inputs": 3, "max_signature_checks": 7 }"#;
+
+        let decoded: TxParameters = serde_json::from_str(json).expect("Deserializable");
+
+        assert_eq!(
+            decoded,
+            TxParameters::DEFAULT
+                .with_max_inputs(3)
+                .with_max_signature_checks(7)
+        );
+        assert_eq!(decoded.max_bytecode_subsections, 256);
+        assert_eq!(decoded.max_proof_set_length, 16);
+    }
+
+    #[test]
+    fn tx_parameters__json_without_max_signature_checks_uses_max_inputs() {
+        let json = r#"{ "max_inputs": 3 }"#;
+
+        let decoded: TxParameters = serde_json::from_str(json).expect("Deserializable");
+
+        assert_eq!(decoded.max_signature_checks, 3);
+    }
+
+    #[test]
+    fn consensus_parameters__canonical_roundtrip() {
+        let params = custom_params();
+
+        let decoded = ConsensusParameters::from_canonical_bytes(&params.to_bytes())
+            .expect("Deserializable");
+
+        assert_eq!(decoded, params);
+    }
+
+    #[test]
+    fn consensus_parameters__from_canonical_bytes__rejects_zero_gas_price_factor() {
+        let mut params = ConsensusParametersV1::standard();
+        params.fee_params.gas_price_factor = 0;
+        let bytes = ConsensusParameters::V1(params).to_bytes();
+
+        let result = ConsensusParameters::from_canonical_bytes(&bytes);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn consensus_parameters__hash__is_independent_of_the_construction() {
+        let built = ConsensusParameters::builder()
+            .with_base_asset_id(AssetId::new([0xaa; 32]))
+            .with_fee_params(FeeParameters::DEFAULT.with_gas_per_byte(11))
+            .with_tx_params(TxParameters::DEFAULT.with_max_inputs(3))
+            .with_privileged_address(Address::new([0xbb; 32]))
+            .with_chain_id(ChainId::new(7))
+            .build()
+            .expect("The parameters are consistent");
+        let json = r#"{ "V1": {
+            "privileged_address": "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+            "chain_id": 7,
+            "base_asset_id": "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "fee_params": { "gas_per_byte": 11 },
+            "tx_params": { "max_inputs": 3, "max_signature_checks": 255 }
+        } }"#;
+        let parsed = ConsensusParameters::from_json_str(json).expect("Valid config");
+
+        assert_eq!(built.hash(), custom_params().hash());
+        assert_eq!(parsed.hash(), custom_params().hash());
+    }
+
+    #[test]
+    fn consensus_parameters__hash__changes_with_every_field() {
+        let params = custom_params();
+        let ConsensusParameters::V1(v1) = params.clone();
+        let mutations: [fn(&mut ConsensusParametersV1); 12] = [
+            |p| p.tx_params.max_outputs -= 1,
+            |p| p.predicate_params.max_gas_per_predicate += 1,
+            |p| p.script_params.max_script_length += 1,
+            |p| p.script_params.max_receipts_count += 1,
+            |p| p.script_params.max_receipts_size += 1,
+            |p| p.contract_params.max_storage_slots += 1,
+            |p| p.contract_params.max_call_depth += 1,
+            |p| p.fee_params.gas_price_factor += 1,
+            |p| p.chain_id = ChainId::new(8),
+            |p| {
+                p.gas_costs =
+                    GasCosts::new(GasCostsValues::clone(&p.gas_costs).with_add(1_000))
+            },
+            |p| p.base_asset_id = AssetId::new([0xab; 32]),
+            |p| p.privileged_address = Address::new([0xbc; 32]),
+        ];
+
+        for (index, mutate) in mutations.iter().enumerate() {
+            let mut mutated = v1.clone();
+            mutate(&mut mutated);
+
+            assert_ne!(
+                ConsensusParameters::V1(mutated).hash(),
+                params.hash(),
+                "mutation {index}"
+            );
+        }
+    }
+
+    #[test]
+    fn consensus_parameters__unknown_version_is_rejected() {
+        let result = serde_json::from_str::<ConsensusParameters>(r#"{"V2": {}}"#);
+
+        let error = result.expect_err("The version is unknown").to_string();
+        assert!(error.contains("unsupported version"), "{error}");
+    }
+
+    #[test]
+    fn consensus_parameters__legacy_unversioned_json_is_read_as_v1() {
+        // Given
+        let legacy = r#"{
+            "chain_id": 7,
+            "tx_params": { "max_inputs": 3 },
+            "fee_params": { "gas_per_byte": 11 }
+        }"#;
+        let versioned = r#"{ "V1": {
+            "chain_id": 7,
+            "tx_params": { "max_inputs": 3 },
+            "fee_params": { "gas_per_byte": 11 }
+        } }"#;
+
+        // When
+        let from_legacy = serde_json::from_str::<ConsensusParameters>(legacy);
+        let from_versioned = serde_json::from_str::<ConsensusParameters>(versioned);
+
+        // Then
+        let from_legacy = from_legacy.expect("The legacy form is supported");
+        assert_eq!(from_legacy.chain_id(), ChainId::new(7));
+        assert_eq!(from_legacy.tx_params().max_inputs, 3);
+        assert_eq!(
+            from_legacy,
+            from_versioned.expect("The versioned form is valid")
+        );
+    }
+
+    #[test]
+    fn consensus_parameters_builder__overrides_standard_values() {
+        let tx_params = TxParameters::DEFAULT.with_max_gas_per_tx(MAX_GAS * 2);
+
+        let params = ConsensusParameters::builder()
+            .with_chain_id(ChainId::new(7))
+            .with_tx_params(tx_params)
+            .with_privileged_address(Address::new([0xbb; 32]))
+            .build()
+            .expect("The parameters are consistent");
+
+        let mut expected = ConsensusParameters::standard_with_id(ChainId::new(7));
+        expected.set_tx_params(tx_params);
+        expected.set_privileged_address(Address::new([0xbb; 32]));
+        assert_eq!(params, expected);
+    }
+
+    #[test]
+    fn consensus_parameters_builder__rejects_predicate_gas_above_tx_gas() {
+        let result = ConsensusParameters::builder()
+            .with_tx_params(TxParameters::DEFAULT.with_max_gas_per_tx(1_000))
+            .with_predicate_params(
+                PredicateParameters::DEFAULT.with_max_gas_per_predicate(1_001),
+            )
+            .build();
+
+        assert_eq!(
+            result,
+            Err(ConsensusParametersError::PredicateGasExceedsTxGas {
+                max_gas_per_predicate: 1_001,
+                max_gas_per_tx: 1_000,
+            })
+        );
+    }
+
+    #[test]
+    fn consensus_parameters_builder__rejects_contract_size_above_tx_size() {
+        let result = ConsensusParameters::builder()
+            .with_tx_params(TxParameters::DEFAULT.with_max_size(1_024))
+            .with_contract_params(
+                ContractParameters::DEFAULT.with_contract_max_size(2_048),
+            )
+            .build();
+
+        assert_eq!(
+            result,
+            Err(ConsensusParametersError::ContractSizeExceedsTxSize {
+                contract_max_size: 2_048,
+                max_size: 1_024,
+            })
+        );
+    }
+
+    #[test]
+    fn fee_parameters__new__rejects_zero_gas_price_factor() {
+        assert_eq!(
+            FeeParameters::new(0, 4),
+            Err(FeeParametersError::ZeroGasPriceFactor)
+        );
+        assert_eq!(
+            FeeParameters::new(92, 4),
+            FeeParameters::DEFAULT
+                .with_gas_price_factor(92)
+                .map(|params| params.with_gas_per_byte(4))
+        );
+    }
+
+    #[test]
+    fn fee_parameters__with_gas_price_factor__rejects_zero() {
+        assert_eq!(
+            FeeParameters::DEFAULT.with_gas_price_factor(0),
+            Err(FeeParametersError::ZeroGasPriceFactor)
+        );
+    }
+
+    #[test]
+    fn fee_parameters__validate__rejects_zero_set_through_the_field() {
+        let params = FeeParameters {
+            gas_price_factor: 0,
+            ..FeeParameters::DEFAULT
+        };
+
+        assert_eq!(
+            params.validate(),
+            Err(FeeParametersError::ZeroGasPriceFactor)
+        );
+    }
+
+    #[test]
+    fn fee_parameters__deserialization_rejects_zero_gas_price_factor() {
+        let result = serde_json::from_str::<FeeParameters>(
+            r#"{"gas_price_factor": 0, "gas_per_byte": 4}"#,
+        );
+
+        let error = result.expect_err("The factor is zero").to_string();
+        assert!(error.contains("can't be zero"), "{error}");
+    }
+
+    #[test]
+    fn fee_parameters__deserialization_defaults_missing_fields() {
+        let params: FeeParameters =
+            serde_json::from_str(r#"{"gas_per_byte": 7}"#).expect("Deserializable");
+
+        assert_eq!(params, FeeParameters::DEFAULT.with_gas_per_byte(7));
+    }
+}
+
 #[cfg(feature = "typescript")]
 mod typescript {
     use wasm_bindgen::prelude::*;
@@ -409,8 +1416,14 @@ pub mod default_parameters {
     pub const MAX_SCRIPT_DATA_LENGTH: u64 =
         ScriptParameters::DEFAULT.max_script_data_length;
 
+    pub const MAX_RECEIPTS_COUNT: u64 = ScriptParameters::DEFAULT.max_receipts_count;
+
+    pub const MAX_RECEIPTS_SIZE: u64 = ScriptParameters::DEFAULT.max_receipts_size;
+
     pub const MAX_STORAGE_SLOTS: u64 = ContractParameters::DEFAULT.max_storage_slots;
 
+    pub const MAX_CALL_DEPTH: u64 = ContractParameters::DEFAULT.max_call_depth;
+
     pub const MAX_PREDICATE_LENGTH: u64 =
         PredicateParameters::DEFAULT.max_predicate_length;
     pub const MAX_PREDICATE_DATA_LENGTH: u64 =
@@ -1,11 +1,31 @@
 //! Tools for gas instrumentalization
 
-use core::ops::Deref;
+use core::{
+    fmt,
+    ops::Deref,
+};
 
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
+#[cfg(feature = "serde")]
+use alloc::{
+    collections::BTreeMap,
+    format,
+};
+use alloc::{
+    string::String,
+    vec::Vec,
+};
 
-use fuel_types::Word;
+use fuel_asm::{
+    Instruction,
+    Opcode,
+    RawInstruction,
+};
+use fuel_types::{
+    canonical,
+    Word,
+};
 
 /// Default gas costs are generated from the
 /// `fuel-core` repo using the `collect` bin
@@ -13,6 +33,8 @@ use fuel_types::Word;
 /// The git sha is included in the file to
 /// show what version of `fuel-core` was used
 /// to generate the costs.
+/// The same costs are checked in as `default_gas_costs.json`,
+/// which must be regenerated alongside.
 #[allow(dead_code)]
 mod default_gas_costs;
 
@@ -175,6 +197,40 @@ impl serde::Serialize for GasCosts {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl canonical::Serialize for GasCosts {
+    fn size_static(&self) -> usize {
+        self.0.size_static()
+    }
+
+    fn size_dynamic(&self) -> usize {
+        self.0.size_dynamic()
+    }
+
+    fn encode_static<O: canonical::Output + ?Sized>(
+        &self,
+        buffer: &mut O,
+    ) -> Result<(), canonical::Error> {
+        self.0.encode_static(buffer)
+    }
+
+    fn encode_dynamic<O: canonical::Output + ?Sized>(
+        &self,
+        buffer: &mut O,
+    ) -> Result<(), canonical::Error> {
+        self.0.encode_dynamic(buffer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl canonical::Deserialize for GasCosts {
+    fn decode_static<I: canonical::Input + ?Sized>(
+        buffer: &mut I,
+    ) -> Result<Self, canonical::Error> {
+        GasCostsValues::decode(buffer).map(Self::new)
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl GasCosts {
     /// Create new cost values wrapped in an [`Arc`].
@@ -199,10 +255,16 @@ impl Default for GasCostsValues {
 }
 
 /// Gas costs for every op.
+///
+/// When deserializing, any absent entry falls back to its value in
+/// [`GasCostsValues::default`], so configs written before a new cost was
+/// introduced keep loading. Unknown entries are ignored; use
+/// [`GasCostsValues::deserialize_with_unknown_fields`] to reject or report them.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(default = "GasCostsValues::unit"))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub struct GasCostsValues {
     pub add: Word,
     pub addi: Word,
@@ -298,10 +360,17 @@ pub struct GasCostsValues {
     pub xori: Word,
 
     // Dependent
+    pub bldd: DependentCost,
+    pub bsiz: DependentCost,
     pub call: DependentCost,
     pub ccp: DependentCost,
     pub croo: DependentCost,
     pub csiz: DependentCost,
+    /// Elliptic curve point addition or multiplication, per group operation: one for
+    /// an addition, one per bit of the scalar for a multiplication.
+    pub ecop: DependentCost,
+    /// Elliptic curve pairing check, per pair.
+    pub epar: DependentCost,
     pub k256: DependentCost,
     pub ldc: DependentCost,
     pub logd: DependentCost,
@@ -326,8 +395,13 @@ pub struct GasCostsValues {
 }
 
 /// Dependent cost is a cost that depends on the number of units.
+///
+/// When deserializing from a human-readable format, the legacy
+/// `{ "base": .., "dep_per_unit": .. }` form is also accepted, and is interpreted as a
+/// [`DependentCost::LightOperation`] processing `dep_per_unit` units per gas.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub enum DependentCost {
     /// When an operation is dependent on the magnitude of its inputs, and the
     /// time per unit of input is less than a single no-op operation
@@ -351,6 +425,56 @@ pub enum DependentCost {
     },
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DependentCost {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        /// Mirrors the variants of [`DependentCost`].
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "DependentCost")]
+        enum Variants {
+            LightOperation { base: Word, units_per_gas: Word },
+            HeavyOperation { base: Word, gas_per_unit: Word },
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum HumanReadable {
+            Variants(Variants),
+            Legacy { base: Word, dep_per_unit: Word },
+        }
+
+        let variants = if deserializer.is_human_readable() {
+            match HumanReadable::deserialize(deserializer)? {
+                HumanReadable::Variants(variants) => variants,
+                HumanReadable::Legacy { base, dep_per_unit } => {
+                    Variants::LightOperation {
+                        base,
+                        units_per_gas: dep_per_unit,
+                    }
+                }
+            }
+        } else {
+            Variants::deserialize(deserializer)?
+        };
+
+        Ok(match variants {
+            Variants::LightOperation {
+                base,
+                units_per_gas,
+            } => Self::LightOperation {
+                base,
+                units_per_gas,
+            },
+            Variants::HeavyOperation { base, gas_per_unit } => {
+                Self::HeavyOperation { base, gas_per_unit }
+            }
+        })
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl GasCosts {
     /// Create costs that are all set to zero.
@@ -456,10 +580,14 @@ impl GasCostsValues {
             wqmm: 0,
             xor: 0,
             xori: 0,
+            bldd: DependentCost::free(),
+            bsiz: DependentCost::free(),
             call: DependentCost::free(),
             ccp: DependentCost::free(),
             croo: DependentCost::free(),
             csiz: DependentCost::free(),
+            ecop: DependentCost::free(),
+            epar: DependentCost::free(),
             k256: DependentCost::free(),
             ldc: DependentCost::free(),
             logd: DependentCost::free(),
@@ -574,10 +702,14 @@ impl GasCostsValues {
             wqmm: 1,
             xor: 1,
             xori: 1,
+            bldd: DependentCost::unit(),
+            bsiz: DependentCost::unit(),
             call: DependentCost::unit(),
             ccp: DependentCost::unit(),
             croo: DependentCost::unit(),
             csiz: DependentCost::unit(),
+            ecop: DependentCost::unit(),
+            epar: DependentCost::unit(),
             k256: DependentCost::unit(),
             ldc: DependentCost::unit(),
             logd: DependentCost::unit(),
@@ -602,6 +734,751 @@ impl GasCostsValues {
     }
 }
 
+/// A cost replacing one of the [`GasCostsValues`], looked up by its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GasCostOverride {
+    /// Replaces a cost that doesn't depend on the number of units.
+    Fixed(Word),
+    /// Replaces a cost that depends on the number of units.
+    Dependent(DependentCost),
+}
+
+/// The error returned when overriding [`GasCostsValues`] by name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum GasCostOverrideError {
+    /// There is no cost with this name.
+    #[display(fmt = "Unknown gas cost `{}`", _0)]
+    UnknownCost(String),
+    /// The override is fixed while the cost is dependent, or vice versa.
+    #[display(fmt = "The override of the gas cost `{}` has the wrong kind", _0)]
+    MismatchedCost(String),
+}
+
+impl fmt::Display for GasCostOverride {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(cost) => write!(f, "{cost}"),
+            Self::Dependent(cost) => write!(f, "{cost}"),
+        }
+    }
+}
+
+impl GasCostOverride {
+    /// The components of the cost, named after the fields of [`DependentCost`].
+    fn components(&self) -> [Option<(Option<&'static str>, Word)>; 2] {
+        match *self {
+            Self::Fixed(cost) => [Some((None, cost)), None],
+            Self::Dependent(DependentCost::LightOperation {
+                base,
+                units_per_gas,
+            }) => [
+                Some((Some("base"), base)),
+                Some((Some("units_per_gas"), units_per_gas)),
+            ],
+            Self::Dependent(DependentCost::HeavyOperation { base, gas_per_unit }) => [
+                Some((Some("base"), base)),
+                Some((Some("gas_per_unit"), gas_per_unit)),
+            ],
+        }
+    }
+}
+
+/// The change of a component of a cost between two [`GasCostsValues`], see
+/// [`GasCostsValues::compare`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GasCostDelta {
+    /// The serialized name of the cost.
+    pub name: &'static str,
+    /// The changed field of a [`DependentCost`], `None` for a fixed cost.
+    pub component: Option<&'static str>,
+    /// The previous value, `None` if the component didn't exist.
+    pub old: Option<Word>,
+    /// The new value, `None` if the component no longer exists.
+    pub new: Option<Word>,
+}
+
+impl GasCostDelta {
+    fn push_deltas(
+        deltas: &mut Vec<Self>,
+        name: &'static str,
+        old: GasCostOverride,
+        new: GasCostOverride,
+    ) {
+        let (old, new) = (old.components(), new.components());
+        let find = |components: &[Option<(Option<&'static str>, Word)>; 2],
+                    component: Option<&'static str>| {
+            components
+                .iter()
+                .flatten()
+                .find(|(name, _)| *name == component)
+                .map(|(_, value)| *value)
+        };
+
+        for (component, value) in old.iter().flatten() {
+            let new_value = find(&new, *component);
+            if new_value != Some(*value) {
+                deltas.push(Self {
+                    name,
+                    component: *component,
+                    old: Some(*value),
+                    new: new_value,
+                });
+            }
+        }
+
+        // The components replaced by a change of the kind of a dependent cost
+        for (component, value) in new.iter().flatten() {
+            if find(&old, *component).is_none() {
+                deltas.push(Self {
+                    name,
+                    component: *component,
+                    old: None,
+                    new: Some(*value),
+                });
+            }
+        }
+    }
+}
+
+impl fmt::Display for GasCostDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_value = |f: &mut fmt::Formatter<'_>, value| match value {
+            Some(value) => write!(f, "{value}"),
+            None => write!(f, "-"),
+        };
+
+        write!(f, "{}", self.name)?;
+        if let Some(component) = self.component {
+            write!(f, ".{component}")?;
+        }
+        write!(f, ": ")?;
+        write_value(f, self.old)?;
+        write!(f, " -> ")?;
+        write_value(f, self.new)
+    }
+}
+
+trait OverridableCost: Sized {
+    fn from_override(cost: GasCostOverride) -> Option<Self>;
+
+    fn into_override(self) -> GasCostOverride;
+}
+
+impl OverridableCost for Word {
+    fn from_override(cost: GasCostOverride) -> Option<Self> {
+        match cost {
+            GasCostOverride::Fixed(cost) => Some(cost),
+            GasCostOverride::Dependent(_) => None,
+        }
+    }
+
+    fn into_override(self) -> GasCostOverride {
+        GasCostOverride::Fixed(self)
+    }
+}
+
+impl OverridableCost for DependentCost {
+    fn from_override(cost: GasCostOverride) -> Option<Self> {
+        match cost {
+            GasCostOverride::Dependent(cost) => Some(cost),
+            GasCostOverride::Fixed(_) => None,
+        }
+    }
+
+    fn into_override(self) -> GasCostOverride {
+        GasCostOverride::Dependent(self)
+    }
+}
+
+/// Generates a `with_*` setter for every cost, the override of the costs by their
+/// serialized names, the partial [`GasCostsOverrides`] of the costs, and the list of
+/// the costs by their serialized names used for display and comparison.
+macro_rules! gas_costs_fields {
+    ($($setter:ident, $field:ident: $ty:ty => $name:literal),* $(,)?) => {
+        /// A partial set of [`GasCostsValues`], where only the present costs are
+        /// replaced by [`GasCostsValues::merge`].
+        ///
+        /// Produced by [`GasCostsValues::diff`] to publish only the costs that changed.
+        #[allow(missing_docs)]
+        #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub struct GasCostsOverrides {
+            $(
+                #[cfg_attr(
+                    feature = "serde",
+                    serde(rename = $name, skip_serializing_if = "Option::is_none")
+                )]
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        impl GasCostsOverrides {
+            /// Returns `true` if no cost is overridden.
+            pub fn is_empty(&self) -> bool {
+                true $(&& self.$field.is_none())*
+            }
+        }
+
+        impl GasCostsValues {
+            /// The costs with their serialized names, in the declaration order.
+            fn named_costs(&self) -> [(&'static str, GasCostOverride); { [$($name),*].len() }] {
+                [$(($name, self.$field.into_override())),*]
+            }
+
+            $(
+                #[doc = concat!("Replace the `", $name, "` cost with the given argument")]
+                pub const fn $setter(mut self, $field: $ty) -> Self {
+                    self.$field = $field;
+                    self
+                }
+            )*
+
+            /// Replace the costs named as they are serialized with the given overrides.
+            ///
+            /// Fails on the first name that doesn't match any cost, or whose override
+            /// is of the wrong kind.
+            pub fn override_from<'a, I>(
+                mut self,
+                overrides: I,
+            ) -> Result<Self, GasCostOverrideError>
+            where
+                I: IntoIterator<Item = (&'a str, GasCostOverride)>,
+            {
+                for (name, cost) in overrides {
+                    match name {
+                        $(
+                            $name => {
+                                self.$field = <$ty>::from_override(cost).ok_or_else(
+                                    || GasCostOverrideError::MismatchedCost(name.into()),
+                                )?;
+                            }
+                        )*
+                        _ => return Err(GasCostOverrideError::UnknownCost(name.into())),
+                    }
+                }
+
+                Ok(self)
+            }
+
+            /// Checks that the JSON `value` of the entry `name` is a valid cost, unknown
+            /// entries are ignored.
+            #[cfg(feature = "serde")]
+            pub(crate) fn check_json_entry(
+                name: &str,
+                value: &serde_json::Value,
+            ) -> Result<(), serde_json::Error> {
+                match name {
+                    $(
+                        $name => <$ty as serde::Deserialize>::deserialize(value).map(drop),
+                    )*
+                    _ => Ok(()),
+                }
+            }
+
+            /// Replaces the costs present in the `overrides`, keeping the others.
+            pub fn merge(&mut self, overrides: &GasCostsOverrides) {
+                $(
+                    if let Some(cost) = overrides.$field {
+                        self.$field = cost;
+                    }
+                )*
+            }
+
+            /// Returns the minimal overrides turning `self` into `other` with
+            /// [`Self::merge`], i.e. the costs of `other` that differ from `self`.
+            pub fn diff(&self, other: &Self) -> GasCostsOverrides {
+                // Destructured to fail the compilation if a cost is missing above.
+                let Self { $($field),* } = other;
+
+                GasCostsOverrides {
+                    $(
+                        $field: (self.$field != *$field).then_some(*$field),
+                    )*
+                }
+            }
+        }
+    };
+}
+
+gas_costs_fields! {
+    with_add, add: Word => "add",
+    with_addi, addi: Word => "addi",
+    with_aloc, aloc: Word => "aloc",
+    with_and, and: Word => "and",
+    with_andi, andi: Word => "andi",
+    with_bal, bal: Word => "bal",
+    with_bhei, bhei: Word => "bhei",
+    with_bhsh, bhsh: Word => "bhsh",
+    with_burn, burn: Word => "burn",
+    with_cb, cb: Word => "cb",
+    with_cfei, cfei: Word => "cfei",
+    with_cfsi, cfsi: Word => "cfsi",
+    with_div, div: Word => "div",
+    with_divi, divi: Word => "divi",
+    with_eck1, eck1: Word => "eck1",
+    with_ecr1, ecr1: Word => "ecr1",
+    with_ed19, ed19: Word => "ed19",
+    with_eq, eq: Word => "eq",
+    with_exp, exp: Word => "exp",
+    with_expi, expi: Word => "expi",
+    with_flag, flag: Word => "flag",
+    with_gm, gm: Word => "gm",
+    with_gt, gt: Word => "gt",
+    with_gtf, gtf: Word => "gtf",
+    with_ji, ji: Word => "ji",
+    with_jmp, jmp: Word => "jmp",
+    with_jne, jne: Word => "jne",
+    with_jnei, jnei: Word => "jnei",
+    with_jnzi, jnzi: Word => "jnzi",
+    with_jmpf, jmpf: Word => "jmpf",
+    with_jmpb, jmpb: Word => "jmpb",
+    with_jnzf, jnzf: Word => "jnzf",
+    with_jnzb, jnzb: Word => "jnzb",
+    with_jnef, jnef: Word => "jnef",
+    with_jneb, jneb: Word => "jneb",
+    with_lb, lb: Word => "lb",
+    with_log, log: Word => "log",
+    with_lt, lt: Word => "lt",
+    with_lw, lw: Word => "lw",
+    with_mint, mint: Word => "mint",
+    with_mlog, mlog: Word => "mlog",
+    with_mod_op, mod_op: Word => "mod",
+    with_modi, modi: Word => "modi",
+    with_move_op, move_op: Word => "move",
+    with_movi, movi: Word => "movi",
+    with_mroo, mroo: Word => "mroo",
+    with_mul, mul: Word => "mul",
+    with_muli, muli: Word => "muli",
+    with_mldv, mldv: Word => "mldv",
+    with_noop, noop: Word => "noop",
+    with_not, not: Word => "not",
+    with_or, or: Word => "or",
+    with_ori, ori: Word => "ori",
+    with_poph, poph: Word => "poph",
+    with_popl, popl: Word => "popl",
+    with_pshh, pshh: Word => "pshh",
+    with_pshl, pshl: Word => "pshl",
+    with_ret, ret: Word => "ret_contract",
+    with_rvrt, rvrt: Word => "rvrt_contract",
+    with_sb, sb: Word => "sb",
+    with_sll, sll: Word => "sll",
+    with_slli, slli: Word => "slli",
+    with_srl, srl: Word => "srl",
+    with_srli, srli: Word => "srli",
+    with_srw, srw: Word => "srw",
+    with_sub, sub: Word => "sub",
+    with_subi, subi: Word => "subi",
+    with_sw, sw: Word => "sw",
+    with_sww, sww: Word => "sww",
+    with_time, time: Word => "time",
+    with_tr, tr: Word => "tr",
+    with_tro, tro: Word => "tro",
+    with_wdcm, wdcm: Word => "wdcm",
+    with_wqcm, wqcm: Word => "wqcm",
+    with_wdop, wdop: Word => "wdop",
+    with_wqop, wqop: Word => "wqop",
+    with_wdml, wdml: Word => "wdml",
+    with_wqml, wqml: Word => "wqml",
+    with_wddv, wddv: Word => "wddv",
+    with_wqdv, wqdv: Word => "wqdv",
+    with_wdmd, wdmd: Word => "wdmd",
+    with_wqmd, wqmd: Word => "wqmd",
+    with_wdam, wdam: Word => "wdam",
+    with_wqam, wqam: Word => "wqam",
+    with_wdmm, wdmm: Word => "wdmm",
+    with_wqmm, wqmm: Word => "wqmm",
+    with_xor, xor: Word => "xor",
+    with_xori, xori: Word => "xori",
+    with_bldd, bldd: DependentCost => "bldd",
+    with_bsiz, bsiz: DependentCost => "bsiz",
+    with_call, call: DependentCost => "call",
+    with_ccp, ccp: DependentCost => "ccp",
+    with_croo, croo: DependentCost => "croo",
+    with_csiz, csiz: DependentCost => "csiz",
+    with_ecop, ecop: DependentCost => "ecop",
+    with_epar, epar: DependentCost => "epar",
+    with_k256, k256: DependentCost => "k256",
+    with_ldc, ldc: DependentCost => "ldc",
+    with_logd, logd: DependentCost => "logd",
+    with_mcl, mcl: DependentCost => "mcl",
+    with_mcli, mcli: DependentCost => "mcli",
+    with_mcp, mcp: DependentCost => "mcp",
+    with_mcpi, mcpi: DependentCost => "mcpi",
+    with_meq, meq: DependentCost => "meq",
+    with_retd, retd: DependentCost => "retd_contract",
+    with_s256, s256: DependentCost => "s256",
+    with_scwq, scwq: DependentCost => "scwq",
+    with_smo, smo: DependentCost => "smo",
+    with_srwq, srwq: DependentCost => "srwq",
+    with_swwq, swwq: DependentCost => "swwq",
+    with_contract_root, contract_root: DependentCost => "contract_root",
+    with_state_root, state_root: DependentCost => "state_root",
+    with_new_storage_per_byte, new_storage_per_byte: Word => "new_storage_per_byte",
+    with_vm_initialization, vm_initialization: DependentCost => "vm_initialization",
+}
+
+impl GasCostsValues {
+    /// Returns the changes from `self` to `other`, one per differing component of a
+    /// cost, in the declaration order of the costs.
+    pub fn compare(&self, other: &Self) -> Vec<GasCostDelta> {
+        let mut deltas = Vec::new();
+        for ((name, old), (_, new)) in
+            self.named_costs().into_iter().zip(other.named_costs())
+        {
+            GasCostDelta::push_deltas(&mut deltas, name, old, new);
+        }
+        deltas
+    }
+}
+
+/// Formats the costs as a table of their serialized names and values, one per line.
+impl fmt::Display for GasCostsValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let costs = self.named_costs();
+        let width = costs.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (name, cost) in costs {
+            writeln!(f, "{name:<width$}  {cost}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when pricing an instruction with [`GasCostsValues::cost_of`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum InstructionCostError {
+    /// The opcode byte doesn't correspond to any instruction.
+    #[display(fmt = "Unknown opcode {:#04x}", _0)]
+    UnknownOpcode(u8),
+    /// The cost of the opcode depends on a number of units that wasn't supplied.
+    #[display(fmt = "The cost of {:?} depends on a number of units", _0)]
+    MissingDependentUnits(Opcode),
+}
+
+impl GasCostsValues {
+    /// The gas charged by the interpreter for executing the `instruction`.
+    ///
+    /// Instructions with a [`DependentCost`] are resolved with `dependent_units`, the
+    /// number of units they process, and fail without it. The units are ignored for
+    /// the other instructions. `ECAL` is free, its cost is charged by the external
+    /// handler.
+    pub fn cost_of(
+        &self,
+        instruction: &Instruction,
+        dependent_units: Option<Word>,
+    ) -> Result<Word, InstructionCostError> {
+        // Destructuring without `..` forces every new cost to be handled here.
+        let Self {
+            add,
+            addi,
+            aloc,
+            and,
+            andi,
+            bal,
+            bhei,
+            bhsh,
+            burn,
+            cb,
+            cfei,
+            cfsi,
+            div,
+            divi,
+            eck1,
+            ecr1,
+            ed19,
+            eq,
+            exp,
+            expi,
+            flag,
+            gm,
+            gt,
+            gtf,
+            ji,
+            jmp,
+            jne,
+            jnei,
+            jnzi,
+            jmpf,
+            jmpb,
+            jnzf,
+            jnzb,
+            jnef,
+            jneb,
+            lb,
+            log,
+            lt,
+            lw,
+            mint,
+            mlog,
+            mod_op,
+            modi,
+            move_op,
+            movi,
+            mroo,
+            mul,
+            muli,
+            mldv,
+            noop,
+            not,
+            or,
+            ori,
+            poph,
+            popl,
+            pshh,
+            pshl,
+            ret,
+            rvrt,
+            sb,
+            sll,
+            slli,
+            srl,
+            srli,
+            srw,
+            sub,
+            subi,
+            sw,
+            sww,
+            time,
+            tr,
+            tro,
+            wdcm,
+            wqcm,
+            wdml,
+            wqml,
+            wddv,
+            wqdv,
+            wdmd,
+            wqmd,
+            wdam,
+            wqam,
+            wdmm,
+            wqmm,
+            xor,
+            xori,
+            bldd,
+            bsiz,
+            call,
+            ccp,
+            croo,
+            csiz,
+            ecop,
+            epar,
+            k256,
+            ldc,
+            logd,
+            mcl,
+            mcli,
+            mcp,
+            mcpi,
+            meq,
+            retd,
+            s256,
+            scwq,
+            smo,
+            srwq,
+            swwq,
+            // Charged as `wdcm` and `wqcm` by the interpreter
+            wdop: _,
+            wqop: _,
+            // Non-opcode costs
+            contract_root: _,
+            state_root: _,
+            new_storage_per_byte: _,
+            vm_initialization: _,
+        } = self;
+
+        let opcode = instruction.opcode();
+        let dependent = |cost: &DependentCost| {
+            dependent_units
+                .map(|units| cost.resolve(units))
+                .ok_or(InstructionCostError::MissingDependentUnits(opcode))
+        };
+
+        let cost = match opcode {
+            Opcode::ADD => *add,
+            Opcode::AND => *and,
+            Opcode::DIV => *div,
+            Opcode::EQ => *eq,
+            Opcode::EXP => *exp,
+            Opcode::GT => *gt,
+            Opcode::LT => *lt,
+            Opcode::MLOG => *mlog,
+            Opcode::MROO => *mroo,
+            Opcode::MOD => *mod_op,
+            Opcode::MOVE => *move_op,
+            Opcode::MUL => *mul,
+            Opcode::NOT => *not,
+            Opcode::OR => *or,
+            Opcode::SLL => *sll,
+            Opcode::SRL => *srl,
+            Opcode::SUB => *sub,
+            Opcode::XOR => *xor,
+            Opcode::MLDV => *mldv,
+            Opcode::RET => *ret,
+            Opcode::ALOC => *aloc,
+            Opcode::BHSH => *bhsh,
+            Opcode::BHEI => *bhei,
+            Opcode::BURN => *burn,
+            Opcode::CB => *cb,
+            Opcode::LOG => *log,
+            Opcode::MINT => *mint,
+            Opcode::RVRT => *rvrt,
+            Opcode::SRW => *srw,
+            Opcode::SWW => *sww,
+            Opcode::TR => *tr,
+            Opcode::TRO => *tro,
+            Opcode::ECK1 => *eck1,
+            Opcode::ECR1 => *ecr1,
+            Opcode::ED19 => *ed19,
+            Opcode::TIME => *time,
+            Opcode::NOOP => *noop,
+            Opcode::FLAG => *flag,
+            Opcode::BAL => *bal,
+            Opcode::JMP => *jmp,
+            Opcode::JNE => *jne,
+            Opcode::ADDI => *addi,
+            Opcode::ANDI => *andi,
+            Opcode::DIVI => *divi,
+            Opcode::EXPI => *expi,
+            Opcode::MODI => *modi,
+            Opcode::MULI => *muli,
+            Opcode::ORI => *ori,
+            Opcode::SLLI => *slli,
+            Opcode::SRLI => *srli,
+            Opcode::SUBI => *subi,
+            Opcode::XORI => *xori,
+            Opcode::JNEI => *jnei,
+            Opcode::LB => *lb,
+            Opcode::LW => *lw,
+            Opcode::SB => *sb,
+            Opcode::SW => *sw,
+            Opcode::GTF => *gtf,
+            Opcode::GM => *gm,
+            Opcode::MOVI => *movi,
+            Opcode::JNZI => *jnzi,
+            Opcode::JMPF => *jmpf,
+            Opcode::JMPB => *jmpb,
+            Opcode::JNZF => *jnzf,
+            Opcode::JNZB => *jnzb,
+            Opcode::JNEF => *jnef,
+            Opcode::JNEB => *jneb,
+            Opcode::JI => *ji,
+            Opcode::CFEI | Opcode::CFE => *cfei,
+            Opcode::CFSI | Opcode::CFS => *cfsi,
+            Opcode::PSHL => *pshl,
+            Opcode::PSHH => *pshh,
+            Opcode::POPL => *popl,
+            Opcode::POPH => *poph,
+            Opcode::WDCM | Opcode::WDOP => *wdcm,
+            Opcode::WQCM | Opcode::WQOP => *wqcm,
+            Opcode::WDML => *wdml,
+            Opcode::WQML => *wqml,
+            Opcode::WDDV => *wddv,
+            Opcode::WQDV => *wqdv,
+            Opcode::WDMD => *wdmd,
+            Opcode::WQMD => *wqmd,
+            Opcode::WDAM => *wdam,
+            Opcode::WQAM => *wqam,
+            Opcode::WDMM => *wdmm,
+            Opcode::WQMM => *wqmm,
+            Opcode::RETD => dependent(retd)?,
+            Opcode::MCL => dependent(mcl)?,
+            Opcode::MCP => dependent(mcp)?,
+            Opcode::MEQ => dependent(meq)?,
+            Opcode::CALL => dependent(call)?,
+            Opcode::CCP => dependent(ccp)?,
+            Opcode::CROO => dependent(croo)?,
+            Opcode::CSIZ => dependent(csiz)?,
+            Opcode::LDC => dependent(ldc)?,
+            Opcode::LOGD => dependent(logd)?,
+            Opcode::SCWQ => dependent(scwq)?,
+            Opcode::SRWQ => dependent(srwq)?,
+            Opcode::SWWQ => dependent(swwq)?,
+            Opcode::K256 => dependent(k256)?,
+            Opcode::S256 => dependent(s256)?,
+            Opcode::SMO => dependent(smo)?,
+            Opcode::MCPI => dependent(mcpi)?,
+            Opcode::MCLI => dependent(mcli)?,
+            Opcode::BSIZ => dependent(bsiz)?,
+            Opcode::BLDD => dependent(bldd)?,
+            Opcode::ECOP => dependent(ecop)?,
+            Opcode::EPAR => dependent(epar)?,
+            Opcode::ECAL => 0,
+        };
+
+        Ok(cost)
+    }
+
+    /// Decodes the `raw` instruction and returns its cost, see [`Self::cost_of`].
+    pub fn cost_of_raw(
+        &self,
+        raw: RawInstruction,
+        dependent_units: Option<Word>,
+    ) -> Result<Word, InstructionCostError> {
+        let instruction = Instruction::try_from(raw)
+            .map_err(|_| InstructionCostError::UnknownOpcode(raw.to_be_bytes()[0]))?;
+        self.cost_of(&instruction, dependent_units)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl GasCostsValues {
+    /// Deserialize the gas costs, filling absent entries from
+    /// [`GasCostsValues::default`].
+    ///
+    /// If `deny_unknown_fields` is set, an entry that doesn't correspond to any
+    /// known cost is an error. Otherwise, the names of such entries are returned
+    /// alongside the costs so the caller can log them.
+    ///
+    /// Requires a self-describing format, such as JSON.
+    pub fn deserialize_with_unknown_fields<'de, D>(
+        deserializer: D,
+        deny_unknown_fields: bool,
+    ) -> Result<(Self, Vec<String>), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        struct WithUnknownFields {
+            #[serde(flatten)]
+            values: GasCostsValues,
+            #[serde(flatten)]
+            unknown: BTreeMap<String, serde::de::IgnoredAny>,
+        }
+
+        let WithUnknownFields { values, unknown } =
+            serde::Deserialize::deserialize(deserializer)?;
+        let unknown: Vec<String> = unknown.into_keys().collect();
+
+        if deny_unknown_fields {
+            if let Some(field) = unknown.first() {
+                return Err(serde::de::Error::custom(format!(
+                    "unknown gas cost `{field}`"
+                )))
+            }
+        }
+
+        Ok((values, unknown))
+    }
+}
+
+impl fmt::Display for DependentCost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LightOperation {
+                base,
+                units_per_gas,
+            } => write!(f, "{base} + 1 per {units_per_gas} units"),
+            Self::HeavyOperation { base, gas_per_unit } => {
+                write!(f, "{base} + {gas_per_unit} per unit")
+            }
+        }
+    }
+}
+
 impl DependentCost {
     /// Create costs that make operations free.
     pub fn free() -> Self {
@@ -648,12 +1525,18 @@ impl DependentCost {
         };
     }
 
+    /// The total cost of processing `units`, saturating at `Word::MAX`.
+    ///
+    /// Every charge of a dependent cost must go through this method or
+    /// [`Self::resolve_without_base`], so a malformed config can't panic or wrap.
     pub fn resolve(&self, units: Word) -> Word {
         let base = self.base();
         let dependent_value = self.resolve_without_base(units);
-        base + dependent_value
+        base.saturating_add(dependent_value)
     }
 
+    /// The cost of processing `units` without the base cost, saturating at
+    /// `Word::MAX`.
     pub fn resolve_without_base(&self, units: Word) -> Word {
         match self {
             DependentCost::LightOperation { units_per_gas, .. } => {
@@ -662,7 +1545,14 @@ impl DependentCost {
                 // where:
                 //   x is the number of units
                 //   1/m is the gas_per_unit
-                units.saturating_div(*units_per_gas)
+                // rounding down, so a partial gas isn't charged. A zero `units_per_gas`
+                // can't be created by the constructor and is treated as free instead of
+                // dividing by zero.
+                debug_assert!(
+                    *units_per_gas > 0,
+                    "Dependent gas cost with per-0-gas ratio"
+                );
+                units.checked_div(*units_per_gas).unwrap_or(0)
             }
             DependentCost::HeavyOperation { gas_per_unit, .. } => {
                 // Apply the linear transformation:
@@ -699,7 +1589,23 @@ impl From<GasCosts> for GasCostsValues {
 
 #[cfg(test)]
 mod tests {
-    use crate::DependentCost;
+    #![allow(non_snake_case)]
+
+    use crate::{
+        DependentCost,
+        GasCostDelta,
+        GasCostOverride,
+        GasCostOverrideError,
+        GasCosts,
+        GasCostsOverrides,
+        GasCostsValues,
+        InstructionCostError,
+        Word,
+    };
+    use fuel_asm::{
+        op,
+        Opcode,
+    };
 
     #[test]
     fn light_operation_gas_cost_resolves_correctly() {
@@ -740,4 +1646,563 @@ mod tests {
         let total = cost.resolve(721);
         assert_eq!(total, 7_210);
     }
+
+    #[quickcheck_macros::quickcheck]
+    fn light_operation_resolve_is_monotonic(
+        base: Word,
+        units_per_gas: Word,
+        a: Word,
+        b: Word,
+    ) -> bool {
+        let cost = DependentCost::from_units_per_gas(base, units_per_gas.max(1));
+        let (low, high) = (a.min(b), a.max(b));
+        cost.resolve(low) <= cost.resolve(high)
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn heavy_operation_resolve_is_monotonic(
+        base: Word,
+        gas_per_unit: Word,
+        a: Word,
+        b: Word,
+    ) -> bool {
+        let cost = DependentCost::from_gas_per_unit(base, gas_per_unit);
+        let (low, high) = (a.min(b), a.max(b));
+        cost.resolve(low) <= cost.resolve(high)
+    }
+
+    #[test]
+    fn dependent_cost_resolve_saturates() {
+        let heavy = DependentCost::from_gas_per_unit(Word::MAX - 1, 2);
+        assert_eq!(heavy.resolve(0), Word::MAX - 1);
+        assert_eq!(heavy.resolve(1), Word::MAX);
+        assert_eq!(heavy.resolve(Word::MAX), Word::MAX);
+    }
+
+    #[test]
+    fn dependent_cost_resolve__saturates_at_boundary_values() {
+        let heavy = DependentCost::from_gas_per_unit(0, Word::MAX);
+        assert_eq!(heavy.resolve(1), Word::MAX);
+        assert_eq!(heavy.resolve(2), Word::MAX);
+        assert_eq!(heavy.resolve(Word::MAX), Word::MAX);
+        assert_eq!(heavy.resolve_without_base(Word::MAX), Word::MAX);
+
+        let heavy = DependentCost::from_gas_per_unit(Word::MAX, Word::MAX);
+        assert_eq!(heavy.resolve(0), Word::MAX);
+        assert_eq!(heavy.resolve(Word::MAX), Word::MAX);
+
+        let light = DependentCost::from_units_per_gas(Word::MAX, 1);
+        assert_eq!(light.resolve(Word::MAX), Word::MAX);
+
+        let light = DependentCost::from_units_per_gas(1, Word::MAX);
+        assert_eq!(light.resolve(Word::MAX), 2);
+        assert_eq!(light.resolve(Word::MAX - 1), 1);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "per-0-gas ratio"))]
+    fn dependent_cost_resolve__zero_units_per_gas_is_free() {
+        let light = DependentCost::LightOperation {
+            base: 5,
+            units_per_gas: 0,
+        };
+        assert_eq!(light.resolve(Word::MAX), 5);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn dependent_cost__deserializes_legacy_form() {
+        // Given
+        let json = r#"{ "base": 13, "dep_per_unit": 4 }"#;
+
+        // When
+        let cost: DependentCost = serde_json::from_str(json).expect("Legacy form");
+
+        // Then
+        assert_eq!(cost, DependentCost::from_units_per_gas(13, 4));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn dependent_cost__serde_roundtrip() {
+        for cost in [
+            DependentCost::from_units_per_gas(13, 4),
+            DependentCost::from_gas_per_unit(7, 3),
+        ] {
+            let json = serde_json::to_string(&cost).expect("Serialization failed");
+            let decoded: DependentCost =
+                serde_json::from_str(&json).expect("Deserialization failed");
+            assert_eq!(decoded, cost);
+
+            let bytes = bincode::serialize(&cost).expect("Serialization failed");
+            let decoded: DependentCost =
+                bincode::deserialize(&bytes).expect("Deserialization failed");
+            assert_eq!(decoded, cost);
+        }
+    }
+
+    #[test]
+    fn gas_costs_values__setters_only_change_the_given_costs() {
+        // Given
+        let defaults = GasCostsValues::default();
+        let call = DependentCost::from_gas_per_unit(123, 4);
+
+        // When
+        let costs = GasCostsValues::default()
+            .with_sww(80)
+            .with_mod_op(3)
+            .with_call(call);
+
+        // Then
+        let expected = GasCostsValues {
+            sww: 80,
+            mod_op: 3,
+            call,
+            ..defaults
+        };
+        assert_eq!(costs, expected);
+    }
+
+    #[test]
+    fn gas_costs_values__override_from_only_changes_the_given_costs() {
+        // Given
+        let defaults = GasCostsValues::default();
+        let overrides = [
+            ("sww", GasCostOverride::Fixed(0)),
+            ("swwq", GasCostOverride::Dependent(DependentCost::free())),
+            ("new_storage_per_byte", GasCostOverride::Fixed(0)),
+            (
+                "retd_contract",
+                GasCostOverride::Dependent(DependentCost::unit()),
+            ),
+        ];
+
+        // When
+        let costs = GasCostsValues::default()
+            .override_from(overrides)
+            .expect("All the costs exist");
+
+        // Then
+        let expected = GasCostsValues {
+            sww: 0,
+            swwq: DependentCost::free(),
+            new_storage_per_byte: 0,
+            retd: DependentCost::unit(),
+            ..defaults
+        };
+        assert_eq!(costs, expected);
+    }
+
+    #[test]
+    fn gas_costs_values__override_from_rejects_invalid_overrides() {
+        // When
+        let unknown = GasCostsValues::default().override_from([
+            ("sww", GasCostOverride::Fixed(0)),
+            ("swwx", GasCostOverride::Fixed(0)),
+        ]);
+        let mismatched = GasCostsValues::default()
+            .override_from([("call", GasCostOverride::Fixed(0))]);
+
+        // Then
+        assert_eq!(
+            unknown,
+            Err(GasCostOverrideError::UnknownCost("swwx".into()))
+        );
+        assert_eq!(
+            mismatched,
+            Err(GasCostOverrideError::MismatchedCost("call".into()))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gas_costs_values__missing_fields_fall_back_to_defaults() {
+        // Given
+        let costs = GasCostsValues::unit();
+        let mut json = serde_json::to_value(&costs).expect("Serialization failed");
+        let map = json.as_object_mut().expect("Costs are serialized as a map");
+        for field in ["add", "mod", "vm_initialization"] {
+            map.remove(field).expect("Field is serialized");
+        }
+
+        // When
+        let decoded: GasCostsValues =
+            serde_json::from_value(json).expect("Deserialization failed");
+
+        // Then
+        let defaults = GasCostsValues::default();
+        let expected = GasCostsValues {
+            add: defaults.add,
+            mod_op: defaults.mod_op,
+            vm_initialization: defaults.vm_initialization,
+            ..costs
+        };
+        assert_eq!(decoded, expected);
+
+        let reencoded = serde_json::to_value(&decoded).expect("Serialization failed");
+        let reencoded = reencoded
+            .as_object()
+            .expect("Costs are serialized as a map");
+        for field in ["add", "mod", "vm_initialization"] {
+            assert!(reencoded.contains_key(field), "`{field}` is not serialized");
+        }
+        let roundtrip: GasCostsValues = serde_json::from_value(reencoded.clone().into())
+            .expect("Deserialization failed");
+        assert_eq!(roundtrip, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gas_costs_values__config_without_curve_costs_uses_their_defaults() {
+        // Given
+        let costs = GasCostsValues::unit();
+        let mut json = serde_json::to_value(&costs).expect("Serialization failed");
+        let map = json.as_object_mut().expect("Costs are serialized as a map");
+        map.remove("ecop").expect("Field is serialized");
+        map.remove("epar").expect("Field is serialized");
+
+        // When
+        let decoded: GasCostsValues =
+            serde_json::from_value(json).expect("Deserialization failed");
+
+        // Then
+        let defaults = GasCostsValues::default();
+        assert_eq!(decoded.ecop, defaults.ecop);
+        assert_eq!(decoded.epar, defaults.epar);
+        assert_eq!(
+            decoded,
+            costs.with_ecop(defaults.ecop).with_epar(defaults.epar)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gas_costs_values__curve_costs_roundtrip() {
+        // Given
+        let costs = GasCostsValues::free()
+            .with_ecop(DependentCost::from_gas_per_unit(3, 5))
+            .with_epar(DependentCost::from_units_per_gas(7, 11));
+
+        // When
+        let json = serde_json::to_value(&costs).expect("Serialization failed");
+        let decoded: GasCostsValues =
+            serde_json::from_value(json.clone()).expect("Deserialization failed");
+
+        // Then
+        assert_eq!(decoded, costs);
+        assert_eq!(
+            json["ecop"],
+            serde_json::json!({ "HeavyOperation": { "base": 3, "gas_per_unit": 5 } })
+        );
+        assert_eq!(
+            json["epar"],
+            serde_json::json!({ "LightOperation": { "base": 7, "units_per_gas": 11 } })
+        );
+    }
+
+    #[test]
+    fn gas_costs_values__curve_costs_are_overridable_by_name() {
+        // When
+        let costs = GasCostsValues::free()
+            .override_from([
+                ("ecop", GasCostOverride::Dependent(DependentCost::unit())),
+                ("epar", GasCostOverride::Dependent(DependentCost::unit())),
+            ])
+            .expect("The curve costs exist");
+
+        // Then
+        assert_eq!(costs.ecop, DependentCost::unit());
+        assert_eq!(costs.epar, DependentCost::unit());
+        assert_eq!(
+            GasCostsValues::free().override_from([("epar", GasCostOverride::Fixed(1))]),
+            Err(GasCostOverrideError::MismatchedCost("epar".into()))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gas_costs_values__unknown_fields_are_reported_or_rejected() {
+        // Given
+        let costs = GasCostsValues::unit();
+        let mut json = serde_json::to_value(&costs).expect("Serialization failed");
+        json.as_object_mut()
+            .expect("Costs are serialized as a map")
+            .insert("future_op".into(), 42.into());
+
+        // When
+        let lenient: GasCostsValues =
+            serde_json::from_value(json.clone()).expect("Deserialization failed");
+        let (reported, unknown) =
+            GasCostsValues::deserialize_with_unknown_fields(json.clone(), false)
+                .expect("Deserialization failed");
+        let strict = GasCostsValues::deserialize_with_unknown_fields(json, true);
+
+        // Then
+        assert_eq!(lenient, costs);
+        assert_eq!(reported, costs);
+        assert_eq!(unknown, vec!["future_op".to_string()]);
+        let err = strict.expect_err("Unknown field must be rejected");
+        assert!(err.to_string().contains("future_op"));
+
+        let reencoded = serde_json::to_value(&reported).expect("Serialization failed");
+        assert!(reencoded.get("future_op").is_none());
+        let roundtrip: GasCostsValues =
+            serde_json::from_value(reencoded).expect("Deserialization failed");
+        assert_eq!(roundtrip, costs);
+    }
+
+    #[test]
+    fn cost_of__scalar_opcode_ignores_units() {
+        let gas_costs = GasCosts::new(GasCostsValues::default().with_add(7));
+        let instruction = op::add(0x10, 0x11, 0x12);
+
+        assert_eq!(gas_costs.cost_of(&instruction, None), Ok(7));
+        assert_eq!(gas_costs.cost_of(&instruction, Some(1_000)), Ok(7));
+    }
+
+    #[test]
+    fn cost_of__dependent_opcode_resolves_units() {
+        let mcp = DependentCost::from_units_per_gas(10, 4);
+        let gas_costs = GasCosts::new(GasCostsValues::default().with_mcp(mcp));
+        let instruction = op::mcp(0x10, 0x11, 0x12);
+
+        assert_eq!(
+            gas_costs.cost_of(&instruction, Some(100)),
+            Ok(mcp.resolve(100))
+        );
+        assert_eq!(
+            gas_costs.cost_of(&instruction, None),
+            Err(InstructionCostError::MissingDependentUnits(Opcode::MCP))
+        );
+    }
+
+    #[test]
+    fn cost_of_raw__rejects_unknown_opcode() {
+        let gas_costs = GasCosts::default();
+        let raw = u32::from_be_bytes([0xff, 0, 0, 0]);
+
+        assert_eq!(
+            gas_costs.cost_of_raw(raw, None),
+            Err(InstructionCostError::UnknownOpcode(0xff))
+        );
+    }
+
+    #[test]
+    fn cost_of_raw__matches_decoded_instruction() {
+        let gas_costs = GasCosts::default();
+        let instruction = op::sww(0x10, 0x11, 0x12);
+
+        assert_eq!(
+            gas_costs.cost_of_raw(instruction.into(), None),
+            Ok(gas_costs.sww)
+        );
+    }
+
+    #[test]
+    fn merge__replaces_only_present_costs() {
+        // Given
+        let mut costs = GasCostsValues::unit();
+        let overrides = GasCostsOverrides {
+            add: Some(42),
+            call: Some(DependentCost::from_gas_per_unit(7, 3)),
+            ..Default::default()
+        };
+
+        // When
+        costs.merge(&overrides);
+
+        // Then
+        let expected = GasCostsValues::unit()
+            .with_add(42)
+            .with_call(DependentCost::from_gas_per_unit(7, 3));
+        assert_eq!(costs, expected);
+    }
+
+    #[test]
+    fn diff__is_empty_for_equal_costs() {
+        let costs = GasCostsValues::default();
+
+        assert!(costs.diff(&costs.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff__contains_only_changed_costs() {
+        // Given
+        let a = GasCostsValues::unit();
+        let b = GasCostsValues::unit().with_sww(1).with_ret(9);
+
+        // When
+        let diff = a.diff(&b);
+
+        // Then
+        assert_eq!(
+            diff,
+            GasCostsOverrides {
+                ret: Some(9),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn gas_costs_overrides__serializes_only_present_costs() {
+        // Given
+        let overrides = GasCostsOverrides {
+            ret: Some(9),
+            ..Default::default()
+        };
+
+        // When
+        let json = serde_json::to_value(&overrides).expect("Serialization failed");
+
+        // Then
+        assert_eq!(json, serde_json::json!({ "ret_contract": 9 }));
+        let decoded: GasCostsOverrides =
+            serde_json::from_value(json).expect("Deserialization failed");
+        assert_eq!(decoded, overrides);
+    }
+
+    /// Replaces about half of the numbers of the default costs with random values.
+    #[cfg(feature = "serde")]
+    fn random_costs(seed: u64) -> GasCostsValues {
+        use rand::{
+            rngs::StdRng,
+            Rng,
+            SeedableRng,
+        };
+
+        fn randomize(value: &mut serde_json::Value, rng: &mut StdRng) {
+            match value {
+                serde_json::Value::Number(number) if rng.gen() => {
+                    *number = rng.gen::<u32>().into();
+                }
+                serde_json::Value::Object(map) => {
+                    map.values_mut().for_each(|value| randomize(value, rng))
+                }
+                _ => {}
+            }
+        }
+
+        let rng = &mut StdRng::seed_from_u64(seed);
+        let mut json = serde_json::to_value(GasCostsValues::default())
+            .expect("Serialization failed");
+        randomize(&mut json, rng);
+        serde_json::from_value(json).expect("Deserialization failed")
+    }
+
+    #[cfg(feature = "serde")]
+    #[quickcheck_macros::quickcheck]
+    fn merge__of_diff_roundtrips(seed_a: u64, seed_b: u64) -> bool {
+        let a = random_costs(seed_a);
+        let b = random_costs(seed_b);
+
+        let mut merged = a.clone();
+        merged.merge(&a.diff(&b));
+
+        merged == b
+    }
+
+    #[test]
+    fn gas_costs_values__display_snapshot() {
+        insta::assert_snapshot!(GasCostsValues::default().to_string());
+    }
+
+    #[test]
+    fn gas_costs_values__display_lists_every_cost() {
+        // Given
+        let costs = GasCostsValues::unit();
+
+        // When
+        let table = costs.to_string();
+
+        // Then
+        assert_eq!(table.lines().count(), costs.named_costs().len());
+        assert!(table.lines().any(|line| line.starts_with("ret_contract ")));
+        assert!(table
+            .lines()
+            .any(|line| line.starts_with("vm_initialization ")));
+    }
+
+    #[test]
+    fn compare__of_equal_costs_is_empty() {
+        let costs = GasCostsValues::default();
+
+        assert_eq!(costs.compare(&costs.clone()), vec![]);
+    }
+
+    #[test]
+    fn compare__returns_changed_components_in_declaration_order() {
+        // Given
+        let old = GasCostsValues::free();
+        let new = old
+            .clone()
+            .with_sww(3)
+            .with_add(2)
+            .with_call(DependentCost::from_gas_per_unit(0, 5));
+
+        // When
+        let deltas = old.compare(&new);
+
+        // Then
+        assert_eq!(
+            deltas,
+            vec![
+                GasCostDelta {
+                    name: "add",
+                    component: None,
+                    old: Some(0),
+                    new: Some(2),
+                },
+                GasCostDelta {
+                    name: "sww",
+                    component: None,
+                    old: Some(0),
+                    new: Some(3),
+                },
+                GasCostDelta {
+                    name: "call",
+                    component: Some("gas_per_unit"),
+                    old: Some(0),
+                    new: Some(5),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn compare__reports_components_of_changed_dependent_cost_kind() {
+        // Given
+        let old = GasCostsValues::free().with_ldc(DependentCost::from_gas_per_unit(1, 2));
+        let new = old
+            .clone()
+            .with_ldc(DependentCost::from_units_per_gas(1, 4));
+
+        // When
+        let deltas = old.compare(&new);
+
+        // Then
+        assert_eq!(
+            deltas,
+            vec![
+                GasCostDelta {
+                    name: "ldc",
+                    component: Some("gas_per_unit"),
+                    old: Some(2),
+                    new: None,
+                },
+                GasCostDelta {
+                    name: "ldc",
+                    component: Some("units_per_gas"),
+                    old: None,
+                    new: Some(4),
+                },
+            ]
+        );
+        let lines: Vec<_> = deltas.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            lines,
+            ["ldc.gas_per_unit: 2 -> -", "ldc.units_per_gas: - -> 4"]
+        );
+    }
 }
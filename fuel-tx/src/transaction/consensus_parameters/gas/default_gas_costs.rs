@@ -99,6 +99,16 @@ pub fn default_gas_costs() -> GasCostsValues {
             base: 2,
             units_per_gas: 214,
         },
+        // Not benchmarked yet, priced like `ldc` and `csiz` which also read a stored
+        // bytecode.
+        bldd: DependentCost::LightOperation {
+            base: 15,
+            units_per_gas: 272,
+        },
+        bsiz: DependentCost::LightOperation {
+            base: 17,
+            units_per_gas: 790,
+        },
         call: DependentCost::LightOperation {
             base: 144,
             units_per_gas: 214,
@@ -115,6 +125,16 @@ pub fn default_gas_costs() -> GasCostsValues {
             base: 17,
             units_per_gas: 790,
         },
+        // Not benchmarked yet, conservative. Charged per group operation, so a scalar
+        // multiplication costs 256 units.
+        ecop: DependentCost::HeavyOperation {
+            base: 1_000,
+            gas_per_unit: 50,
+        },
+        epar: DependentCost::HeavyOperation {
+            base: 10_000,
+            gas_per_unit: 50_000,
+        },
         ldc: DependentCost::LightOperation {
             base: 15,
             units_per_gas: 272,
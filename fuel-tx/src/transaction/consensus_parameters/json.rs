@@ -0,0 +1,225 @@
+//! Loading of the gas costs and the consensus parameters from JSON configs at runtime.
+
+use super::{
+    gas::{
+        GasCosts,
+        GasCostsValues,
+    },
+    ConsensusParameters,
+    ContractParameters,
+    FeeParameters,
+    PredicateParameters,
+    ScriptParameters,
+    TxParameters,
+};
+use alloc::{
+    format,
+    string::{
+        String,
+        ToString,
+    },
+};
+use fuel_types::{
+    Address,
+    AssetId,
+    ChainId,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// The path of the root of a JSON document.
+const ROOT: &str = "$";
+
+/// The error returned when loading parameters from an invalid JSON config.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::Display)]
+#[display(fmt = "Invalid value at `{}`: {}", path, message)]
+pub struct JsonConfigError {
+    path: String,
+    message: String,
+}
+
+impl JsonConfigError {
+    fn new(path: &str, error: serde_json::Error) -> Self {
+        Self {
+            path: path.to_string(),
+            message: error.to_string(),
+        }
+    }
+
+    /// The JSON path of the invalid value, e.g. `$.V1.gas_costs.call`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+fn parse(json: &str) -> Result<Value, JsonConfigError> {
+    serde_json::from_str(json).map_err(|error| JsonConfigError::new(ROOT, error))
+}
+
+fn check<'a, T: Deserialize<'a>>(
+    value: &'a Value,
+    path: &str,
+) -> Result<(), JsonConfigError> {
+    T::deserialize(value)
+        .map(drop)
+        .map_err(|error| JsonConfigError::new(path, error))
+}
+
+impl GasCostsValues {
+    fn from_json_value(value: &Value, path: &str) -> Result<Self, JsonConfigError> {
+        if let Value::Object(entries) = value {
+            for (name, entry) in entries {
+                Self::check_json_entry(name, entry).map_err(|error| {
+                    JsonConfigError::new(&format!("{path}.{name}"), error)
+                })?;
+            }
+        }
+
+        Self::deserialize(value).map_err(|error| JsonConfigError::new(path, error))
+    }
+}
+
+impl GasCosts {
+    /// Loads the gas costs from a JSON object of the costs by their serialized names.
+    ///
+    /// Absent costs fall back to their default value, see [`GasCostsValues`].
+    pub fn from_json_str(json: &str) -> Result<Self, JsonConfigError> {
+        let value = parse(json)?;
+        GasCostsValues::from_json_value(&value, ROOT).map(Self::new)
+    }
+
+    /// Serializes the gas costs to the JSON format of [`Self::from_json_str`].
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(self).expect("The gas costs are serializable")
+    }
+}
+
+impl ConsensusParameters {
+    /// Loads the consensus parameters from a JSON object tagged with their version,
+    /// e.g. `{ "V1": { .. } }`.
+    ///
+    /// Absent parameters fall back to their standard value.
+    pub fn from_json_str(json: &str) -> Result<Self, JsonConfigError> {
+        let value = parse(json)?;
+
+        if let Some(Value::Object(entries)) = value.get("V1") {
+            let path = format!("{ROOT}.V1");
+            for (name, entry) in entries {
+                let path = format!("{path}.{name}");
+                match name.as_str() {
+                    "tx_params" => check::<TxParameters>(entry, &path)?,
+                    "predicate_params" => check::<PredicateParameters>(entry, &path)?,
+                    "script_params" => check::<ScriptParameters>(entry, &path)?,
+                    "contract_params" => check::<ContractParameters>(entry, &path)?,
+                    "fee_params" => check::<FeeParameters>(entry, &path)?,
+                    "chain_id" => check::<ChainId>(entry, &path)?,
+                    "gas_costs" => {
+                        GasCostsValues::from_json_value(entry, &path)?;
+                    }
+                    "base_asset_id" => check::<AssetId>(entry, &path)?,
+                    "privileged_address" => check::<Address>(entry, &path)?,
+                    _ => {}
+                }
+            }
+        }
+
+        Self::deserialize(&value).map_err(|error| JsonConfigError::new(ROOT, error))
+    }
+
+    /// Serializes the consensus parameters to the JSON format of
+    /// [`Self::from_json_str`].
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(self)
+            .expect("The consensus parameters are serializable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::DependentCost;
+
+    const DEFAULT_GAS_COSTS_JSON: &str = include_str!("gas/default_gas_costs.json");
+
+    #[test]
+    fn gas_costs__from_json_str__parses_default_gas_costs_fixture() {
+        let gas_costs =
+            GasCosts::from_json_str(DEFAULT_GAS_COSTS_JSON).expect("Valid fixture");
+
+        assert_eq!(gas_costs, GasCosts::default());
+    }
+
+    #[test]
+    fn gas_costs__to_json_string__roundtrips() {
+        let gas_costs = GasCosts::new(
+            GasCostsValues::unit().with_call(DependentCost::from_gas_per_unit(3, 7)),
+        );
+
+        let json = gas_costs.to_json_string();
+
+        assert_eq!(GasCosts::from_json_str(&json), Ok(gas_costs));
+    }
+
+    #[test]
+    fn gas_costs__from_json_str__reports_path_of_invalid_cost() {
+        let json = r#"{ "add": 1, "call": { "HeavyOperation": { "base": "x" } } }"#;
+
+        let error = GasCosts::from_json_str(json).expect_err("Invalid cost");
+
+        assert_eq!(error.path(), "$.call");
+    }
+
+    #[test]
+    fn gas_costs__from_json_str__reports_syntax_errors_at_root() {
+        let error = GasCosts::from_json_str("{ \"add\": ").expect_err("Invalid JSON");
+
+        assert_eq!(error.path(), "$");
+    }
+
+    #[test]
+    fn consensus_parameters__to_json_string__roundtrips() {
+        let mut params = ConsensusParameters::standard_with_id(ChainId::new(9));
+        params.set_gas_costs(GasCosts::unit());
+
+        let json = params.to_json_string();
+
+        assert_eq!(ConsensusParameters::from_json_str(&json), Ok(params));
+    }
+
+    #[test]
+    fn consensus_parameters__from_json_str__reports_path_of_invalid_field() {
+        let json = r#"{ "V1": { "tx_params": { "max_inputs": -1 } } }"#;
+
+        let error = ConsensusParameters::from_json_str(json).expect_err("Invalid field");
+
+        assert_eq!(error.path(), "$.V1.tx_params");
+        assert!(error.message().contains("-1"), "{}", error.message());
+    }
+
+    #[test]
+    fn consensus_parameters__from_json_str__reports_path_of_invalid_gas_cost() {
+        let json = r#"{ "V1": { "gas_costs": { "sww": "cheap" } } }"#;
+
+        let error = ConsensusParameters::from_json_str(json).expect_err("Invalid cost");
+
+        assert_eq!(error.path(), "$.V1.gas_costs.sww");
+    }
+
+    #[test]
+    fn consensus_parameters__from_json_str__rejects_unknown_version() {
+        let json = r#"{ "V2": {} }"#;
+
+        let error =
+            ConsensusParameters::from_json_str(json).expect_err("Unknown version");
+
+        assert_eq!(error.path(), "$");
+        assert!(error.message().contains("V2"), "{}", error.message());
+    }
+}
@@ -18,11 +18,11 @@ use crate::{
     },
     policies::PolicyType,
     FeeParameters,
+    FeeParametersError,
     GasCosts,
     Input,
 };
 use fuel_asm::Word;
-use fuel_types::canonical::Serialize;
 use hashbrown::HashSet;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -86,7 +86,7 @@ impl TransactionFee {
 
     /// Attempt to create a transaction fee from parameters and transaction internals
     ///
-    /// Will return `None` if arithmetic overflow occurs.
+    /// Will return `None` if arithmetic overflow occurs or the `params` are invalid.
     pub fn checked_from_tx<T>(
         gas_costs: &GasCosts,
         params: &FeeParameters,
@@ -96,10 +96,15 @@ impl TransactionFee {
     where
         T: Chargeable,
     {
-        let min_gas = tx.min_gas(gas_costs, params);
-        let max_gas = tx.max_gas(gas_costs, params);
-        let min_fee = tx.min_fee(gas_costs, params, gas_price).try_into().ok()?;
-        let max_fee = tx.max_fee(gas_costs, params, gas_price).try_into().ok()?;
+        let breakdown = tx.fee_breakdown(gas_costs, params, gas_price)?;
+        let min_gas = breakdown.min_gas()?;
+        let max_gas = breakdown.max_gas()?;
+        let min_fee = gas_to_fee(min_gas, gas_price, params.gas_price_factor)
+            .ok()?
+            .checked_add(breakdown.tip as u128)?
+            .try_into()
+            .ok()?;
+        let max_fee = breakdown.total;
 
         if min_fee > max_fee {
             return None;
@@ -109,83 +114,283 @@ impl TransactionFee {
     }
 }
 
-fn gas_to_fee(gas: Word, gas_price: Word, factor: Word) -> u128 {
+/// The components of the maximum fee of a transaction, see [`Chargeable::fee_breakdown`].
+///
+/// The gas components sum up to the [`Chargeable::max_gas`], and the `total` is the fee
+/// of this gas plus the `tip`, the same as the [`Chargeable::max_fee`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeeBreakdown {
+    /// The gas charged per metered byte of the transaction.
+    pub bytes_gas: Word,
+    /// The gas of the initialization of the VM for the transaction.
+    pub vm_initialization_gas: Word,
+    /// The gas of the recovery of the signatures of the signed inputs.
+    pub signatures_gas: Word,
+    /// The gas of the predicates: their roots, their execution and the initialization
+    /// of the VM for them.
+    pub predicates_gas: Word,
+    /// The gas of the computation of the metadata, like the transaction id.
+    pub metadata_gas: Word,
+    /// The gas reserved for the witnesses that may be added up to the witness limit.
+    pub witness_limit_gas: Word,
+    /// The gas reserved for the execution, the gas limit of a script.
+    pub execution_gas: Word,
+    /// The tip paid to the block producer.
+    pub tip: Word,
+    /// The maximum fee of the transaction.
+    pub total: Word,
+}
+
+impl FeeBreakdown {
+    /// The minimum gas of the transaction, see [`Chargeable::min_gas`].
+    ///
+    /// Returns `None` if arithmetic overflow occurs.
+    pub fn min_gas(&self) -> Option<Word> {
+        self.bytes_gas
+            .checked_add(self.vm_initialization_gas)?
+            .checked_add(self.signatures_gas)?
+            .checked_add(self.predicates_gas)?
+            .checked_add(self.metadata_gas)
+    }
+
+    /// The maximum gas of the transaction, see [`Chargeable::max_gas`].
+    ///
+    /// Returns `None` if arithmetic overflow occurs.
+    pub fn max_gas(&self) -> Option<Word> {
+        self.min_gas()?
+            .checked_add(self.witness_limit_gas)?
+            .checked_add(self.execution_gas)
+    }
+}
+
+/// The [`Chargeable::min_gas`] and [`Chargeable::max_gas`] of a transaction memoized by
+/// [`Chargeable::precompute_gas`] for the gas costs and the fee parameters.
+///
+/// It is a part of the metadata of the transaction, so it is dropped by a mutation the
+/// same way as the cached id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GasMetadata {
+    gas_costs: GasCosts,
+    fee: FeeParameters,
+    min_gas: Word,
+    max_gas: Word,
+}
+
+impl GasMetadata {
+    /// Returns `true` if the gas is computed for the `gas_costs` and the `fee`.
+    pub fn is_computed_for(&self, gas_costs: &GasCosts, fee: &FeeParameters) -> bool {
+        &self.fee == fee && &self.gas_costs == gas_costs
+    }
+
+    /// The memoized [`Chargeable::min_gas`].
+    pub const fn min_gas(&self) -> Word {
+        self.min_gas
+    }
+
+    /// The memoized [`Chargeable::max_gas`].
+    pub const fn max_gas(&self) -> Word {
+        self.max_gas
+    }
+}
+
+/// Returns the gas of the recovery of the signatures, charged once per witness.
+fn signatures_gas<T: Chargeable + ?Sized>(tx: &T, gas_costs: &GasCosts) -> Option<Word> {
+    let mut witness_cache: HashSet<u8> = HashSet::new();
+    tx.inputs()
+        .iter()
+        .filter(|input| match input {
+            // Include signed inputs of unique witness indices
+            Input::CoinSigned(CoinSigned { witness_index, .. })
+            | Input::MessageCoinSigned(MessageCoinSigned { witness_index, .. })
+            | Input::MessageDataSigned(MessageDataSigned { witness_index, .. }) => {
+                witness_cache.insert(*witness_index)
+            }
+            // Ignore all other inputs
+            _ => false,
+        })
+        // Charge EC recovery cost for signed inputs
+        .try_fold(0, |acc: Word, _| acc.checked_add(gas_costs.ecr1))
+}
+
+/// Returns the gas of the predicates of the inputs.
+fn predicates_gas<T: Chargeable + ?Sized>(tx: &T, gas_costs: &GasCosts) -> Option<Word> {
+    tx.inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinPredicate(CoinPredicate {
+                predicate,
+                predicate_gas_used,
+                ..
+            })
+            | Input::MessageCoinPredicate(MessageCoinPredicate {
+                predicate,
+                predicate_gas_used,
+                ..
+            })
+            | Input::MessageDataPredicate(MessageDataPredicate {
+                predicate,
+                predicate_gas_used,
+                ..
+            }) => Some((predicate, *predicate_gas_used)),
+            // Charge nothing for all other inputs
+            _ => None,
+        })
+        .try_fold(0, |acc: Word, (predicate, predicate_gas_used)| {
+            // Charge the cost of the contract root for predicate inputs
+            let bytes_size = tx.metered_bytes_size();
+            let vm_initialization_gas =
+                gas_costs.vm_initialization.resolve(bytes_size as Word);
+            let predicate_gas = gas_costs
+                .contract_root
+                .resolve(predicate.len() as u64)
+                .checked_add(predicate_gas_used)?
+                .checked_add(vm_initialization_gas)?;
+            acc.checked_add(predicate_gas)
+        })
+}
+
+fn uncached_min_gas<T: Chargeable + ?Sized>(
+    tx: &T,
+    gas_costs: &GasCosts,
+    fee: &FeeParameters,
+) -> Word {
+    let bytes_size = tx.metered_bytes_size();
+
+    let vm_initialization_gas = gas_costs.vm_initialization.resolve(bytes_size as Word);
+
+    let bytes_gas = bytes_size as u64 * fee.gas_per_byte;
+    // It's okay to saturate because we have the `max_gas_per_tx` rule for transaction
+    // validity. In the production, the value always will be lower than
+    // `u64::MAX`.
+    tx.gas_used_by_inputs(gas_costs)
+        .saturating_add(tx.gas_used_by_metadata(gas_costs))
+        .saturating_add(bytes_gas)
+        .saturating_add(vm_initialization_gas)
+}
+
+fn uncached_max_gas<T: Chargeable + ?Sized>(
+    tx: &T,
+    gas_costs: &GasCosts,
+    fee: &FeeParameters,
+) -> Word {
+    let remaining_allowed_witness_gas = tx
+        .witness_limit()
+        .saturating_sub(tx.witnesses_size() as u64)
+        .saturating_mul(fee.gas_per_byte);
+
+    uncached_min_gas(tx, gas_costs, fee)
+        .saturating_add(remaining_allowed_witness_gas)
+        .saturating_add(tx.execution_gas_limit())
+}
+
+/// Converts the `gas` into the fee, rounding up.
+///
+/// The fields of [`FeeParameters`] are public, so the zero `factor` is rejected here too.
+pub(crate) fn gas_to_fee(
+    gas: Word,
+    gas_price: Word,
+    factor: Word,
+) -> Result<u128, FeeParametersError> {
+    if factor == 0 {
+        return Err(FeeParametersError::ZeroGasPriceFactor)
+    }
     let total_price = (gas as u128)
         .checked_mul(gas_price as u128)
         .expect("Impossible to overflow because multiplication of two `u64` <= `u128`");
-    total_price.div_ceil(factor as u128)
+    Ok(total_price.div_ceil(factor as u128))
 }
 
 /// Means that the blockchain charges fee for the transaction.
-pub trait Chargeable: field::Inputs + field::Witnesses + field::Policies {
+pub trait Chargeable:
+    field::Inputs + field::Witnesses + field::Policies + field::ChargeableMetadata
+{
     /// Returns the minimum gas required to start transaction execution.
+    ///
+    /// It is memoized by [`Self::precompute_gas`].
     fn min_gas(&self, gas_costs: &GasCosts, fee: &FeeParameters) -> Word {
-        let bytes_size = self.metered_bytes_size();
-
-        let vm_initialization_gas =
-            gas_costs.vm_initialization.resolve(bytes_size as Word);
-
-        let bytes_gas = bytes_size as u64 * fee.gas_per_byte;
-        // It's okay to saturate because we have the `max_gas_per_tx` rule for transaction
-        // validity. In the production, the value always will be lower than
-        // `u64::MAX`.
-        self.gas_used_by_inputs(gas_costs)
-            .saturating_add(self.gas_used_by_metadata(gas_costs))
-            .saturating_add(bytes_gas)
-            .saturating_add(vm_initialization_gas)
+        match self.gas_metadata() {
+            Some(gas) if gas.is_computed_for(gas_costs, fee) => gas.min_gas,
+            _ => uncached_min_gas(self, gas_costs, fee),
+        }
     }
 
     /// Returns the maximum possible gas after the end of transaction execution.
     ///
-    /// The function guarantees that the value is not less than [Self::min_gas].
+    /// The function guarantees that the value is not less than [Self::min_gas]. It is
+    /// memoized by [`Self::precompute_gas`].
     fn max_gas(&self, gas_costs: &GasCosts, fee: &FeeParameters) -> Word {
-        let remaining_allowed_witness_gas = self
-            .witness_limit()
-            .saturating_sub(self.witnesses().size_dynamic() as u64)
-            .saturating_mul(fee.gas_per_byte);
+        match self.gas_metadata() {
+            Some(gas) if gas.is_computed_for(gas_costs, fee) => gas.max_gas,
+            _ => uncached_max_gas(self, gas_costs, fee),
+        }
+    }
 
-        self.min_gas(gas_costs, fee)
-            .saturating_add(remaining_allowed_witness_gas)
+    /// Memoizes the [`Self::min_gas`] and the [`Self::max_gas`] for the `gas_costs` and
+    /// the `fee` in the metadata, so the later calls with the same parameters don't
+    /// recompute them.
+    ///
+    /// Does nothing if the transaction isn't precomputed, see
+    /// [`Cacheable::precompute`](crate::Cacheable::precompute).
+    fn precompute_gas(&mut self, gas_costs: &GasCosts, fee: &FeeParameters) {
+        let min_gas = uncached_min_gas(self, gas_costs, fee);
+        let max_gas = uncached_max_gas(self, gas_costs, fee);
+
+        self.set_gas_metadata(GasMetadata {
+            gas_costs: gas_costs.clone(),
+            fee: *fee,
+            min_gas,
+            max_gas,
+        });
+    }
+
+    /// Returns the gas reserved for the execution of the transaction, beyond the
+    /// [Self::min_gas].
+    fn execution_gas_limit(&self) -> Word {
+        0
     }
 
     /// Returns the minimum fee required to start transaction execution.
+    ///
+    /// Returns an error if the `fee` parameters are invalid.
     fn min_fee(
         &self,
         gas_costs: &GasCosts,
         fee: &FeeParameters,
         gas_price: Word,
-    ) -> u128 {
+    ) -> Result<u128, FeeParametersError> {
         let tip = self.tip();
         let gas_fee = gas_to_fee(
             self.min_gas(gas_costs, fee),
             gas_price,
             fee.gas_price_factor,
-        );
-        gas_fee.saturating_add(tip as u128)
+        )?;
+        Ok(gas_fee.saturating_add(tip as u128))
     }
 
     /// Returns the maximum possible fee after the end of transaction execution.
     ///
-    /// The function guarantees that the value is not less than [Self::min_fee].
+    /// The function guarantees that the value is not less than [Self::min_fee]. Returns
+    /// an error if the `fee` parameters are invalid.
     fn max_fee(
         &self,
         gas_costs: &GasCosts,
         fee: &FeeParameters,
         gas_price: Word,
-    ) -> u128 {
+    ) -> Result<u128, FeeParametersError> {
         let tip = self.tip();
         let gas_fee = gas_to_fee(
             self.max_gas(gas_costs, fee),
             gas_price,
             fee.gas_price_factor,
-        );
-        gas_fee.saturating_add(tip as u128)
+        )?;
+        Ok(gas_fee.saturating_add(tip as u128))
     }
 
     /// Returns the fee amount that can be refunded back based on the `used_gas` and
     /// current state of the transaction.
     ///
-    /// Return `None` if overflow occurs.
+    /// Return `None` if overflow occurs or the `fee` parameters are invalid.
     fn refund_fee(
         &self,
         gas_costs: &GasCosts,
@@ -200,10 +405,12 @@ pub trait Chargeable: field::Inputs + field::Witnesses + field::Policies {
         let total_used_gas = min_gas.saturating_add(used_gas);
         let tip = self.policies().get(PolicyType::Tip).unwrap_or(0);
         let used_fee = gas_to_fee(total_used_gas, gas_price, fee.gas_price_factor)
+            .ok()?
             .saturating_add(tip as u128);
 
         let refund = self
             .max_fee(gas_costs, fee, gas_price)
+            .ok()?
             .saturating_sub(used_fee);
         // It is okay to saturate everywhere above because it only can decrease the value
         // of `refund`. But here, because we need to return the amount we
@@ -211,65 +418,51 @@ pub trait Chargeable: field::Inputs + field::Witnesses + field::Policies {
         refund.try_into().ok()
     }
 
+    /// Returns the components of the [Self::max_fee], computed with checked arithmetic.
+    ///
+    /// Returns `None` if arithmetic overflow occurs, in which case the saturating
+    /// [Self::max_fee] isn't the fee of the transaction, or if the `fee` parameters are
+    /// invalid.
+    fn fee_breakdown(
+        &self,
+        gas_costs: &GasCosts,
+        fee: &FeeParameters,
+        gas_price: Word,
+    ) -> Option<FeeBreakdown> {
+        let bytes_size = self.metered_bytes_size() as Word;
+        let witness_limit_bytes = self
+            .witness_limit()
+            .saturating_sub(self.witnesses_size() as u64);
+
+        let mut breakdown = FeeBreakdown {
+            bytes_gas: bytes_size.checked_mul(fee.gas_per_byte)?,
+            vm_initialization_gas: gas_costs.vm_initialization.resolve(bytes_size),
+            signatures_gas: signatures_gas(self, gas_costs)?,
+            predicates_gas: predicates_gas(self, gas_costs)?,
+            metadata_gas: self.gas_used_by_metadata(gas_costs),
+            witness_limit_gas: witness_limit_bytes.checked_mul(fee.gas_per_byte)?,
+            execution_gas: self.execution_gas_limit(),
+            tip: self.tip(),
+            total: 0,
+        };
+        let gas_fee =
+            gas_to_fee(breakdown.max_gas()?, gas_price, fee.gas_price_factor).ok()?;
+        breakdown.total = Word::try_from(gas_fee).ok()?.checked_add(breakdown.tip)?;
+
+        Some(breakdown)
+    }
+
     /// Used for accounting purposes when charging byte based fees.
     fn metered_bytes_size(&self) -> usize;
 
     /// Returns the gas used by the inputs.
     fn gas_used_by_inputs(&self, gas_costs: &GasCosts) -> Word {
-        let mut witness_cache: HashSet<u8> = HashSet::new();
-        self.inputs()
-            .iter()
-            .filter(|input| match input {
-                // Include signed inputs of unique witness indices
-                Input::CoinSigned(CoinSigned { witness_index, .. })
-                | Input::MessageCoinSigned(MessageCoinSigned { witness_index, .. })
-                | Input::MessageDataSigned(MessageDataSigned { witness_index, .. })
-                    if !witness_cache.contains(witness_index) =>
-                {
-                    witness_cache.insert(*witness_index);
-                    true
-                }
-                // Include all predicates
-                Input::CoinPredicate(_)
-                | Input::MessageCoinPredicate(_)
-                | Input::MessageDataPredicate(_) => true,
-                // Ignore all other inputs
-                _ => false,
-            })
-            .map(|input| match input {
-                // Charge EC recovery cost for signed inputs
-                Input::CoinSigned(_)
-                | Input::MessageCoinSigned(_)
-                | Input::MessageDataSigned(_) => gas_costs.ecr1,
-                // Charge the cost of the contract root for predicate inputs
-                Input::CoinPredicate(CoinPredicate {
-                    predicate,
-                    predicate_gas_used,
-                    ..
-                })
-                | Input::MessageCoinPredicate(MessageCoinPredicate {
-                    predicate,
-                    predicate_gas_used,
-                    ..
-                })
-                | Input::MessageDataPredicate(MessageDataPredicate {
-                    predicate,
-                    predicate_gas_used,
-                    ..
-                }) => {
-                    let bytes_size = self.metered_bytes_size();
-                    let vm_initialization_gas =
-                        gas_costs.vm_initialization.resolve(bytes_size as Word);
-                    gas_costs
-                        .contract_root
-                        .resolve(predicate.len() as u64)
-                        .saturating_add(*predicate_gas_used)
-                        .saturating_add(vm_initialization_gas)
-                }
-                // Charge nothing for all other inputs
-                _ => 0,
-            })
-            .fold(0, |acc, cost| acc.saturating_add(cost))
+        // A saturating sum of the gas is `Word::MAX` exactly when the checked one
+        // overflows
+        signatures_gas(self, gas_costs)
+            .zip(predicates_gas(self, gas_costs))
+            .and_then(|(signatures, predicates)| signatures.checked_add(predicates))
+            .unwrap_or(Word::MAX)
     }
 
     /// Used for accounting purposes when charging for metadata creation.
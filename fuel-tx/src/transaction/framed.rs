@@ -0,0 +1,193 @@
+use crate::Transaction;
+use alloc::vec::Vec;
+use fuel_types::canonical::{
+    self,
+    Deserialize,
+    Serialize,
+};
+
+/// The magic bytes starting the framed encoding of a transaction.
+pub const FRAMED_TX_MAGIC: [u8; 3] = *b"FTX";
+
+/// The size of the header of the framed encoding: the magic bytes followed by the
+/// version byte.
+pub const FRAMED_TX_HEADER_SIZE: usize = FRAMED_TX_MAGIC.len() + 1;
+
+/// The version of the canonical encoding of a framed transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum EncodingVersion {
+    /// The canonical encoding of the current protocol.
+    V1 = 1,
+}
+
+impl EncodingVersion {
+    /// The version used by [`Transaction::to_framed_bytes`].
+    pub const CURRENT: Self = Self::V1;
+}
+
+impl TryFrom<u8> for EncodingVersion {
+    type Error = DecodeError;
+
+    fn try_from(found: u8) -> Result<Self, Self::Error> {
+        match found {
+            1 => Ok(Self::V1),
+            _ => Err(DecodeError::UnsupportedEncodingVersion { found }),
+        }
+    }
+}
+
+/// The error returned when decoding a framed transaction.
+#[derive(Debug, PartialEq, Eq, derive_more::Display)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The bytes don't start with the [`FRAMED_TX_MAGIC`], e.g. they are unframed.
+    #[display(fmt = "The bytes don't start with the framed transaction magic")]
+    InvalidMagic,
+    /// The version of the encoding is not supported.
+    #[display(fmt = "Unsupported encoding version {}", found)]
+    UnsupportedEncodingVersion {
+        /// The version byte of the header.
+        found: u8,
+    },
+    /// The bytes continue after the encoded transaction.
+    #[display(fmt = "{} trailing bytes after the transaction", count)]
+    TrailingBytes {
+        /// The number of bytes after the transaction.
+        count: usize,
+    },
+    /// The transaction can't be decoded with the canonical decoder of its version.
+    #[display(fmt = "Invalid canonical encoding: {}", _0)]
+    Canonical(canonical::Error),
+}
+
+impl From<canonical::Error> for DecodeError {
+    fn from(error: canonical::Error) -> Self {
+        Self::Canonical(error)
+    }
+}
+
+impl Transaction {
+    /// Encodes the transaction canonically, prefixed with the [`FRAMED_TX_MAGIC`] and
+    /// the [`EncodingVersion::CURRENT`] byte.
+    ///
+    /// Only meant for storage, consensus uses the raw unframed encoding.
+    pub fn to_framed_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(FRAMED_TX_HEADER_SIZE + self.size());
+        bytes.extend_from_slice(&FRAMED_TX_MAGIC);
+        bytes.push(EncodingVersion::CURRENT as u8);
+        bytes.extend(self.to_bytes());
+        bytes
+    }
+
+    /// Decodes a transaction encoded with [`Self::to_framed_bytes`], using the decoder
+    /// of the version from the header.
+    ///
+    /// The transaction must span the rest of the bytes, otherwise
+    /// [`DecodeError::TrailingBytes`] is returned.
+    pub fn from_framed_bytes(
+        bytes: &[u8],
+    ) -> Result<(EncodingVersion, Transaction), DecodeError> {
+        let payload = bytes
+            .strip_prefix(&FRAMED_TX_MAGIC)
+            .ok_or(DecodeError::InvalidMagic)?;
+        let (version, mut payload) = payload
+            .split_first()
+            .ok_or(canonical::Error::BufferIsTooShort)?;
+
+        match EncodingVersion::try_from(*version)? {
+            EncodingVersion::V1 => {
+                let tx = Transaction::decode(&mut payload)?;
+                if !payload.is_empty() {
+                    return Err(DecodeError::TrailingBytes {
+                        count: payload.len(),
+                    })
+                }
+                Ok((EncodingVersion::V1, tx))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::TransactionBuilder;
+    use fuel_types::AssetId;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn sample_tx() -> Transaction {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        TransactionBuilder::script(vec![0x10; 32], vec![0x11; 16])
+            .script_gas_limit(1_000)
+            .add_unsigned_coin_input(
+                fuel_crypto::SecretKey::random(rng),
+                rng.gen(),
+                rng.gen(),
+                AssetId::default(),
+                rng.gen(),
+            )
+            .finalize_as_transaction()
+    }
+
+    #[test]
+    fn framed_bytes__roundtrip() {
+        let tx = sample_tx();
+
+        let bytes = tx.to_framed_bytes();
+        let decoded = Transaction::from_framed_bytes(&bytes).expect("Valid framing");
+
+        assert_eq!(&bytes[..FRAMED_TX_MAGIC.len()], &FRAMED_TX_MAGIC);
+        assert_eq!(&bytes[FRAMED_TX_HEADER_SIZE..], tx.to_bytes());
+        assert_eq!(decoded, (EncodingVersion::V1, tx));
+    }
+
+    #[test]
+    fn framed_bytes__rejects_unframed_bytes() {
+        let bytes = sample_tx().to_bytes();
+
+        let result = Transaction::from_framed_bytes(&bytes);
+
+        assert_eq!(result, Err(DecodeError::InvalidMagic));
+    }
+
+    #[test]
+    fn framed_bytes__rejects_unsupported_version() {
+        let mut bytes = sample_tx().to_framed_bytes();
+        bytes[FRAMED_TX_MAGIC.len()] = 42;
+
+        let result = Transaction::from_framed_bytes(&bytes);
+
+        assert_eq!(
+            result,
+            Err(DecodeError::UnsupportedEncodingVersion { found: 42 })
+        );
+    }
+
+    #[test]
+    fn framed_bytes__rejects_missing_version() {
+        let result = Transaction::from_framed_bytes(&FRAMED_TX_MAGIC);
+
+        assert_eq!(
+            result,
+            Err(DecodeError::Canonical(canonical::Error::BufferIsTooShort))
+        );
+    }
+
+    #[test]
+    fn framed_bytes__rejects_trailing_bytes() {
+        let mut bytes = sample_tx().to_framed_bytes();
+        bytes.extend_from_slice(&[0; 3]);
+
+        let result = Transaction::from_framed_bytes(&bytes);
+
+        assert_eq!(result, Err(DecodeError::TrailingBytes { count: 3 }));
+    }
+}
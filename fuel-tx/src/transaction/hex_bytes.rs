@@ -0,0 +1,220 @@
+//! The serde representation of the byte payloads of the transaction: `0x`-prefixed
+//! lowercase hex strings in the human-readable formats, and the compact sequence of
+//! bytes in the other formats.
+//!
+//! The human-readable deserialization also accepts the sequence of bytes, so the
+//! payloads serialized before keep deserializing.
+
+use crate::input::{
+    AsField,
+    Empty,
+};
+use alloc::{
+    string::String,
+    vec::Vec,
+};
+use core::fmt;
+use serde::{
+    de::{
+        DeserializeOwned,
+        Error,
+    },
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+/// The byte payload, or the [`Empty`] field of the sub-types of the specification.
+pub trait Bytes: AsField<Vec<u8>> + Serialize + DeserializeOwned {
+    /// Creates the payload from the `bytes`, or `None` if the field is [`Empty`].
+    fn from_bytes(bytes: Vec<u8>) -> Option<Self>;
+}
+
+impl Bytes for Vec<u8> {
+    fn from_bytes(bytes: Vec<u8>) -> Option<Self> {
+        Some(bytes)
+    }
+}
+
+impl Bytes for Empty<Vec<u8>> {
+    fn from_bytes(_: Vec<u8>) -> Option<Self> {
+        None
+    }
+}
+
+/// Formats the bytes as a `0x`-prefixed lowercase hex.
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+/// Decodes the hex string, with or without the `0x` prefix.
+fn decode(s: &str) -> Option<Vec<u8>> {
+    fn hex_val(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+    let pairs = s.chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None
+    }
+
+    pairs
+        .map(|pair| Some(hex_val(pair[0])? << 4 | hex_val(pair[1])?))
+        .collect()
+}
+
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Bytes,
+    S: Serializer,
+{
+    match value.as_field() {
+        Some(bytes) if serializer.is_human_readable() => {
+            serializer.collect_str(&Hex(bytes))
+        }
+        _ => value.serialize(serializer),
+    }
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Bytes,
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum HexOrRaw<T> {
+        Hex(String),
+        Raw(T),
+    }
+
+    if !deserializer.is_human_readable() {
+        return T::deserialize(deserializer)
+    }
+
+    match HexOrRaw::<T>::deserialize(deserializer)? {
+        HexOrRaw::Hex(s) => {
+            let bytes = decode(&s).ok_or_else(|| {
+                D::Error::custom("expected a hex string with an even length")
+            })?;
+            T::from_bytes(bytes)
+                .ok_or_else(|| D::Error::custom("expected an empty field"))
+        }
+        HexOrRaw::Raw(value) => Ok(value),
+    }
+}
+
+/// The representation of an optional byte payload, like the data of the receipts,
+/// which is `null` if absent.
+pub mod option {
+    use super::{
+        decode,
+        Hex,
+    };
+    use alloc::{
+        string::String,
+        vec::Vec,
+    };
+    use serde::{
+        de::Error,
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    pub fn serialize<S>(value: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(bytes) if serializer.is_human_readable() => {
+                serializer.collect_str(&Hex(bytes))
+            }
+            _ => value.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Vec<u8>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum HexOrRaw {
+            Hex(String),
+            Raw(Option<Vec<u8>>),
+        }
+
+        if !deserializer.is_human_readable() {
+            return Option::deserialize(deserializer)
+        }
+
+        match HexOrRaw::deserialize(deserializer)? {
+            HexOrRaw::Hex(s) => decode(&s).map(Some).ok_or_else(|| {
+                D::Error::custom("expected a hex string with an even length")
+            }),
+            HexOrRaw::Raw(value) => Ok(value),
+        }
+    }
+}
+
+/// The representation of the ids of the receipts, `0x`-prefixed in the
+/// human-readable formats like the byte payloads. The deserialization is the one of
+/// the id, which accepts them with or without the prefix.
+pub mod id {
+    use core::fmt;
+    use serde::{
+        Serialize,
+        Serializer,
+    };
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::LowerHex + Serialize,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{value:#x}"))
+        } else {
+            value.serialize(serializer)
+        }
+    }
+
+    /// The representation of an optional id, which is `null` if absent.
+    pub mod option {
+        use core::fmt;
+        use serde::{
+            Serialize,
+            Serializer,
+        };
+
+        pub fn serialize<T, S>(
+            value: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            T: fmt::LowerHex + Serialize,
+            S: Serializer,
+        {
+            match value {
+                Some(value) if serializer.is_human_readable() => {
+                    serializer.collect_str(&format_args!("{value:#x}"))
+                }
+                _ => value.serialize(serializer),
+            }
+        }
+    }
+}
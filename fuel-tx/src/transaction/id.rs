@@ -8,13 +8,13 @@ use crate::{
         },
     },
     Input,
+    InputSignatureError,
     Transaction,
+    Witness,
 };
 use fuel_crypto::{
-    Message,
     PublicKey,
     SecretKey,
-    Signature,
 };
 use fuel_types::{
     Bytes32,
@@ -37,6 +37,9 @@ impl UniqueIdentifier for Transaction {
             Transaction::Script(script) => script.id(chain_id),
             Transaction::Create(create) => create.id(chain_id),
             Self::Mint(mint) => mint.id(chain_id),
+            Self::Upgrade(upgrade) => upgrade.id(chain_id),
+            Self::Upload(upload) => upload.id(chain_id),
+            Self::Blob(blob) => blob.id(chain_id),
         }
     }
 
@@ -45,6 +48,9 @@ impl UniqueIdentifier for Transaction {
             Transaction::Script(script) => script.cached_id(),
             Transaction::Create(create) => create.cached_id(),
             Self::Mint(mint) => mint.cached_id(),
+            Self::Upgrade(upgrade) => upgrade.cached_id(),
+            Self::Upload(upload) => upload.cached_id(),
+            Self::Blob(blob) => blob.cached_id(),
         }
     }
 }
@@ -53,8 +59,26 @@ impl UniqueIdentifier for Transaction {
 ///
 /// # Note: Autogenerated transactions are not signable.
 pub trait Signable: UniqueIdentifier {
+    /// The 32-byte digest signed by the owners of the signed inputs, the message passed
+    /// to [`Signature::sign`] by [`Self::sign_inputs`].
+    ///
+    /// It allows to sign the transaction outside, e.g. on an offline device, and to
+    /// attach the signature as a witness.
+    fn signing_hash(&self, chain_id: &ChainId) -> Bytes32 {
+        self.id(chain_id)
+    }
+
     /// Signs inputs of the transaction.
     fn sign_inputs(&mut self, secret: &SecretKey, chain_id: &ChainId);
+
+    /// Verifies the signatures of all the signed inputs against the
+    /// [`Self::signing_hash`], see [`Input::verify_signature`].
+    ///
+    /// Returns the index of the first invalid input along with the error.
+    fn verify_input_signatures(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<(), (usize, InputSignatureError)>;
 }
 
 impl<T> Signable for T
@@ -68,11 +92,9 @@ where
 
         let pk = PublicKey::from(secret);
         let pk = Input::owner(&pk);
-        let id = self.id(chain_id);
+        let signing_hash = self.signing_hash(chain_id);
 
-        let message = Message::from_bytes_ref(&id);
-
-        let signature = Signature::sign(secret, message);
+        let signature = Witness::sign(secret, &signing_hash);
 
         let inputs = self.inputs();
 
@@ -101,10 +123,26 @@ where
 
         for w in witness_indexes {
             if let Some(w) = self.witnesses_mut().get_mut(w) {
-                *w = signature.as_ref().into();
+                *w = signature.clone();
             }
         }
     }
+
+    fn verify_input_signatures(
+        &self,
+        chain_id: &ChainId,
+    ) -> Result<(), (usize, InputSignatureError)> {
+        let signing_hash = self.signing_hash(chain_id);
+
+        self.inputs()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, input)| {
+                input
+                    .verify_signature(&signing_hash, self.witnesses())
+                    .map_err(|err| (index, err))
+            })
+    }
 }
 
 #[cfg(test)]
@@ -134,11 +172,14 @@ mod tests {
             },
         },
         output,
+        policies::Policies,
         Buildable,
+        Cacheable,
         Input,
         Output,
         StorageSlot,
         Transaction,
+        UniqueIdentifier,
         UtxoId,
     };
     use fuel_tx_test_helpers::{
@@ -732,4 +773,33 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn cached_id_is_dropped_by_mutation() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let chain_id = ChainId::default();
+
+        // Given
+        let mut tx = Transaction::script(
+            rng.gen(),
+            generate_bytes(rng),
+            generate_bytes(rng),
+            Policies::new().with_tip(rng.gen()),
+            vec![],
+            vec![],
+            vec![],
+        );
+        tx.precompute(&chain_id)
+            .expect("Should be able to calculate cache");
+        assert_eq!(tx.cached_id(), Some(tx.id(&chain_id)));
+
+        // When
+        tx.set_tip(tx.tip().wrapping_add(1));
+
+        // Then
+        assert_eq!(tx.cached_id(), None);
+        tx.precompute(&chain_id)
+            .expect("Should be able to calculate cache");
+        assert_eq!(tx.cached_id(), Some(tx.id(&chain_id)));
+    }
 }
@@ -7,6 +7,7 @@ use fuel_types::{
 
 use crate::{
     field,
+    GasMetadata,
     UniqueIdentifier,
     ValidityError,
 };
@@ -20,6 +21,14 @@ pub trait Cacheable {
 
     /// Computes the cache for the entity.
     fn precompute(&mut self, chain_id: &ChainId) -> Result<(), ValidityError>;
+
+    /// Drops the cache of the entity, so the cached values, like the
+    /// [`UniqueIdentifier::cached_id`](crate::UniqueIdentifier::cached_id), are `None`
+    /// until the next [`Self::precompute`].
+    ///
+    /// The `field::*` mutable accessors of the transactions call it, so a mutated
+    /// transaction never returns a stale cache.
+    fn invalidate_cache(&mut self);
 }
 
 impl Cacheable for super::Transaction {
@@ -28,6 +37,20 @@ impl Cacheable for super::Transaction {
             Self::Script(script) => script.is_computed(),
             Self::Create(create) => create.is_computed(),
             Self::Mint(mint) => mint.is_computed(),
+            Self::Upgrade(upgrade) => upgrade.is_computed(),
+            Self::Upload(upload) => upload.is_computed(),
+            Self::Blob(blob) => blob.is_computed(),
+        }
+    }
+
+    fn invalidate_cache(&mut self) {
+        match self {
+            Self::Script(script) => script.invalidate_cache(),
+            Self::Create(create) => create.invalidate_cache(),
+            Self::Mint(mint) => mint.invalidate_cache(),
+            Self::Upgrade(upgrade) => upgrade.invalidate_cache(),
+            Self::Upload(upload) => upload.invalidate_cache(),
+            Self::Blob(blob) => blob.invalidate_cache(),
         }
     }
 
@@ -36,11 +59,14 @@ impl Cacheable for super::Transaction {
             Self::Script(script) => script.precompute(chain_id),
             Self::Create(create) => create.precompute(chain_id),
             Self::Mint(mint) => mint.precompute(chain_id),
+            Self::Upgrade(upgrade) => upgrade.precompute(chain_id),
+            Self::Upload(upload) => upload.precompute(chain_id),
+            Self::Blob(blob) => blob.precompute(chain_id),
         }
     }
 }
 
-/// Common metadata for `Script` and `Create` transactions.
+/// Common metadata for `Script`, `Create`, `Upgrade`, `Upload` and `Blob` transactions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct CommonMetadata {
     pub id: Bytes32,
@@ -51,6 +77,7 @@ pub(crate) struct CommonMetadata {
     pub outputs_offset_at: Vec<usize>,
     pub witnesses_offset: usize,
     pub witnesses_offset_at: Vec<usize>,
+    pub gas: Option<GasMetadata>,
 }
 
 impl CommonMetadata {
@@ -122,6 +149,7 @@ impl CommonMetadata {
             outputs_offset_at,
             witnesses_offset,
             witnesses_offset_at,
+            gas: None,
         }
     }
 }
@@ -33,6 +33,8 @@ bitflags::bitflags! {
         const Maturity = 1 << 2;
         /// If set, the max fee is present in the policies.
         const MaxFee = 1 << 3;
+        /// If set, the gas price floor is present in the policies.
+        const GasPriceFloor = 1 << 4;
     }
 }
 
@@ -54,6 +56,7 @@ pub enum PolicyType {
     WitnessLimit,
     Maturity,
     MaxFee,
+    GasPriceFloor,
 }
 
 impl PolicyType {
@@ -63,6 +66,7 @@ impl PolicyType {
             PolicyType::WitnessLimit => 1,
             PolicyType::Maturity => 2,
             PolicyType::MaxFee => 3,
+            PolicyType::GasPriceFloor => 4,
         }
     }
 
@@ -72,6 +76,7 @@ impl PolicyType {
             PolicyType::WitnessLimit => PoliciesBits::WitnessLimit,
             PolicyType::Maturity => PoliciesBits::Maturity,
             PolicyType::MaxFee => PoliciesBits::MaxFee,
+            PolicyType::GasPriceFloor => PoliciesBits::GasPriceFloor,
         }
     }
 }
@@ -138,6 +143,12 @@ impl Policies {
         self
     }
 
+    /// Sets the `gas_price_floor` policy.
+    pub fn with_gas_price_floor(mut self, gas_price_floor: Word) -> Self {
+        self.set(PolicyType::GasPriceFloor, Some(gas_price_floor));
+        self
+    }
+
     /// Returns a policy's value if the corresponding bit is set.
     pub fn get(&self, policy_type: PolicyType) -> Option<Word> {
         if self.bits.contains(policy_type.bit()) {
@@ -324,7 +335,8 @@ pub mod typescript {
 #[test]
 fn values_for_bitmask_produces_expected_values() {
     const MAX_BITMASK: u32 = 1 << POLICIES_NUMBER;
-    const VALUES: [Word; POLICIES_NUMBER] = [0x1000001, 0x2000001, 0x3000001, 0x4000001];
+    const VALUES: [Word; POLICIES_NUMBER] =
+        [0x1000001, 0x2000001, 0x3000001, 0x4000001, 0x5000001];
 
     // Given
     let mut set = hashbrown::HashSet::new();
@@ -343,7 +355,8 @@ fn values_for_bitmask_produces_expected_values() {
 #[test]
 fn canonical_serialization_deserialization_for_any_combination_of_values_works() {
     const MAX_BITMASK: u32 = 1 << POLICIES_NUMBER;
-    const VALUES: [Word; POLICIES_NUMBER] = [0x1000001, 0x2000001, 0x3000001, 0x4000001];
+    const VALUES: [Word; POLICIES_NUMBER] =
+        [0x1000001, 0x2000001, 0x3000001, 0x4000001, 0x5000001];
 
     for bitmask in 0..MAX_BITMASK {
         let bits =
@@ -8,6 +8,9 @@ pub enum TransactionRepr {
     Script = 0x00,
     Create = 0x01,
     Mint = 0x02,
+    Upgrade = 0x03,
+    Upload = 0x04,
+    Blob = 0x05,
 }
 
 impl From<&Transaction> for TransactionRepr {
@@ -16,6 +19,49 @@ impl From<&Transaction> for TransactionRepr {
             Transaction::Script { .. } => Self::Script,
             Transaction::Create { .. } => Self::Create,
             Transaction::Mint { .. } => Self::Mint,
+            Transaction::Upgrade { .. } => Self::Upgrade,
+            Transaction::Upload { .. } => Self::Upload,
+            Transaction::Blob { .. } => Self::Blob,
         }
     }
 }
+
+/// The type of a [`Transaction`], returned by [`Transaction::tx_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransactionType {
+    Script,
+    Create,
+    Mint,
+    Upgrade,
+    Upload,
+    Blob,
+}
+
+impl From<&Transaction> for TransactionType {
+    fn from(tx: &Transaction) -> Self {
+        match tx {
+            Transaction::Script { .. } => Self::Script,
+            Transaction::Create { .. } => Self::Create,
+            Transaction::Mint { .. } => Self::Mint,
+            Transaction::Upgrade { .. } => Self::Upgrade,
+            Transaction::Upload { .. } => Self::Upload,
+            Transaction::Blob { .. } => Self::Blob,
+        }
+    }
+}
+
+/// The error returned by the conversion of a [`Transaction`] into a variant of another
+/// type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[display(
+    fmt = "Expected the `{}` transaction, but found the `{}` transaction",
+    expected,
+    found
+)]
+pub struct WrongTransactionType {
+    /// The type of the requested variant.
+    pub expected: TransactionType,
+    /// The type of the converted transaction.
+    pub found: TransactionType,
+}
@@ -0,0 +1,27 @@
+//! snapshot tests to ensure the predicate context seen by predicates doesn't change
+
+use super::{
+    tests::{
+        malleable_create,
+        malleable_script,
+    },
+    *,
+};
+
+#[test]
+fn script_predicate_context_snapshot() {
+    let tx: Transaction = malleable_script().into();
+
+    let bytes = tx.predicate_context_bytes(&ChainId::new(9));
+    let hex = hex::encode(bytes);
+    insta::assert_snapshot!(hex);
+}
+
+#[test]
+fn create_predicate_context_snapshot() {
+    let tx: Transaction = malleable_create().into();
+
+    let bytes = tx.predicate_context_bytes(&ChainId::new(9));
+    let hex = hex::encode(bytes);
+    insta::assert_snapshot!(hex);
+}
@@ -1,18 +1,41 @@
+mod blob;
 mod create;
 pub mod input;
 mod mint;
 pub mod output;
 mod script;
 mod storage;
+mod upgrade;
+mod upload;
 mod utxo_id;
 mod witness;
 
+pub use blob::{
+    Blob,
+    BlobBody,
+    BlobIdExt,
+};
 pub use create::Create;
 pub use mint::Mint;
 pub use script::Script;
 pub use storage::StorageSlot;
-pub use utxo_id::UtxoId;
-pub use witness::Witness;
+pub use upgrade::{
+    Upgrade,
+    UpgradePurpose,
+};
+pub use upload::{
+    SplitError,
+    Upload,
+    UploadSubsection,
+};
+pub use utxo_id::{
+    UtxoId,
+    UtxoIdParseError,
+};
+pub use witness::{
+    SignatureError,
+    Witness,
+};
 
 pub fn compute_transaction_id<T: fuel_types::canonical::Serialize>(
     chain_id: &fuel_types::ChainId,
@@ -0,0 +1,639 @@
+use crate::{
+    policies::Policies,
+    transaction::{
+        field::{
+            BlobId as BlobIdField,
+            BytecodeWitnessIndex,
+            Inputs,
+            Outputs,
+            Policies as PoliciesField,
+            Witnesses,
+        },
+        metadata::CommonMetadata,
+        validity::{
+            check_common_part,
+            FormatValidityChecks,
+        },
+        Chargeable,
+    },
+    ConsensusParameters,
+    GasCosts,
+    Input,
+    Output,
+    TransactionRepr,
+    ValidityError,
+    Witness,
+};
+use derivative::Derivative;
+use fuel_crypto::Hasher;
+use fuel_types::{
+    bytes,
+    bytes::WORD_SIZE,
+    canonical::Serialize,
+    BlobId,
+    BlockHeight,
+    ChainId,
+    Word,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+/// Computation of the [`BlobId`] of a payload.
+pub trait BlobIdExt {
+    /// The id of the blob with the `payload`: the hash of the payload.
+    fn compute(payload: &[u8]) -> Self;
+}
+
+impl BlobIdExt for BlobId {
+    fn compute(payload: &[u8]) -> Self {
+        Self::new(*Hasher::hash(payload))
+    }
+}
+
+/// The body of a [`Blob`] transaction: the id of the blob and the index of the witness
+/// with its payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlobBody {
+    /// The id of the blob, see [`BlobIdExt::compute`].
+    pub id: BlobId,
+    /// The index of the witness with the payload of the blob.
+    pub witness_index: u8,
+}
+
+/// The transaction posting an opaque payload, the blob, paid per byte like any other
+/// witness.
+#[derive(Default, Debug, Clone, Derivative)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
+#[canonical(prefix = TransactionRepr::Blob)]
+#[derivative(Eq, PartialEq, Hash)]
+pub struct Blob {
+    pub(crate) id: BlobId,
+    pub(crate) witness_index: u8,
+    pub(crate) policies: Policies,
+    pub(crate) inputs: Vec<Input>,
+    pub(crate) outputs: Vec<Output>,
+    pub(crate) witnesses: Vec<Witness>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(PartialEq = "ignore", Hash = "ignore")]
+    #[canonical(skip)]
+    pub(crate) metadata: Option<CommonMetadata>,
+}
+
+impl Blob {
+    /// Prepare blob for predicate verification by clearing malleable fields of the
+    /// inputs and outputs.
+    pub fn prepare_init_predicate(&mut self) {
+        self.inputs_mut()
+            .iter_mut()
+            .for_each(Input::prepare_init_execute);
+        self.outputs_mut()
+            .iter_mut()
+            .for_each(Output::prepare_init_execute);
+    }
+}
+
+impl crate::UniqueIdentifier for Blob {
+    fn id(&self, chain_id: &ChainId) -> crate::TxId {
+        if let Some(id) = self.cached_id() {
+            return id;
+        }
+
+        let mut clone = self.clone();
+
+        // Empties fields that should be zero during the signing.
+        clone.inputs_mut().iter_mut().for_each(Input::prepare_sign);
+        clone
+            .outputs_mut()
+            .iter_mut()
+            .for_each(Output::prepare_sign);
+        clone.witnesses_mut().clear();
+
+        crate::transaction::compute_transaction_id(chain_id, &mut clone)
+    }
+
+    fn cached_id(&self) -> Option<crate::TxId> {
+        self.metadata.as_ref().map(|m| m.id)
+    }
+}
+
+impl Chargeable for Blob {
+    #[inline(always)]
+    fn metered_bytes_size(&self) -> usize {
+        Serialize::size(self)
+    }
+
+    fn gas_used_by_metadata(&self, gas_costs: &GasCosts) -> Word {
+        let bytes = Serialize::size(self);
+        // Gas required to calculate the `tx_id`.
+        let tx_id_gas = gas_costs.s256.resolve(bytes as u64);
+
+        // Gas required to verify the blob id against the payload.
+        let payload_len = self
+            .witnesses
+            .get(self.witness_index as usize)
+            .map(|w| w.as_ref().len())
+            .unwrap_or(0);
+        let blob_id_gas = gas_costs.s256.resolve(payload_len as Word);
+
+        tx_id_gas.saturating_add(blob_id_gas)
+    }
+}
+
+impl FormatValidityChecks for Blob {
+    fn check_signatures(&self, chain_id: &ChainId) -> Result<(), ValidityError> {
+        use crate::UniqueIdentifier;
+
+        let id = self.id(chain_id);
+
+        // There will be at most len(witnesses) - 1 signatures to cache, as one of the
+        // witnesses will be the payload
+        let mut recovery_cache = Some(HashMap::with_capacity(core::cmp::max(
+            self.witnesses().len().saturating_sub(1),
+            1,
+        )));
+
+        self.inputs()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, input)| {
+                input.check_signature(index, &id, &self.witnesses, &mut recovery_cache)
+            })?;
+
+        Ok(())
+    }
+
+    fn check_without_signatures(
+        &self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+    ) -> Result<(), ValidityError> {
+        let tx_params = consensus_params.tx_params();
+        let base_asset_id = consensus_params.base_asset_id();
+
+        check_common_part(self, block_height, consensus_params)?;
+
+        let payload = self
+            .witnesses
+            .get(self.witness_index as usize)
+            .ok_or(ValidityError::TransactionBlobWitnessIndex)?;
+
+        if payload.as_ref().len() as u64 > tx_params.max_blob_size {
+            return Err(ValidityError::TransactionBlobTooLarge);
+        }
+
+        if BlobId::compute(payload.as_ref()) != self.id {
+            return Err(ValidityError::TransactionBlobIdVerificationFailed);
+        }
+
+        self.inputs
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, input)| match input {
+                Input::Contract(_) => {
+                    Err(ValidityError::TransactionBlobInputContract { index })
+                }
+                Input::MessageDataSigned(_) | Input::MessageDataPredicate(_) => {
+                    Err(ValidityError::TransactionBlobMessageData { index })
+                }
+                _ => Ok(()),
+            })?;
+
+        self.outputs
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, output)| match output {
+                Output::Variable { .. } => {
+                    Err(ValidityError::TransactionBlobOutputVariable { index })
+                }
+
+                Output::ContractCreated { .. } => {
+                    Err(ValidityError::TransactionBlobOutputContractCreated { index })
+                }
+
+                Output::Change { asset_id, .. } if asset_id != base_asset_id => {
+                    Err(ValidityError::TransactionBlobOutputChangeNotBaseAsset { index })
+                }
+
+                _ => Ok(()),
+            })?;
+
+        Ok(())
+    }
+}
+
+impl crate::Cacheable for Blob {
+    fn is_computed(&self) -> bool {
+        self.metadata.is_some()
+    }
+
+    fn invalidate_cache(&mut self) {
+        self.metadata = None;
+    }
+
+    fn precompute(&mut self, chain_id: &ChainId) -> Result<(), ValidityError> {
+        self.metadata = None;
+        self.metadata = Some(CommonMetadata::compute(self, chain_id));
+        Ok(())
+    }
+}
+
+mod field {
+    use super::*;
+    use crate::{
+        field::ChargeableMetadata,
+        Cacheable,
+        GasMetadata,
+    };
+
+    impl BlobIdField for Blob {
+        #[inline(always)]
+        fn blob_id(&self) -> &BlobId {
+            &self.id
+        }
+
+        #[inline(always)]
+        fn blob_id_mut(&mut self) -> &mut BlobId {
+            self.invalidate_cache();
+            &mut self.id
+        }
+
+        #[inline(always)]
+        fn blob_id_offset_static() -> usize {
+            WORD_SIZE // `Transaction` enum discriminant
+        }
+    }
+
+    impl BytecodeWitnessIndex for Blob {
+        #[inline(always)]
+        fn bytecode_witness_index(&self) -> &u8 {
+            &self.witness_index
+        }
+
+        #[inline(always)]
+        fn bytecode_witness_index_mut(&mut self) -> &mut u8 {
+            self.invalidate_cache();
+            &mut self.witness_index
+        }
+
+        #[inline(always)]
+        fn bytecode_witness_index_offset_static() -> usize {
+            Self::blob_id_offset_static() + BlobId::LEN
+        }
+    }
+
+    impl PoliciesField for Blob {
+        #[inline(always)]
+        fn policies(&self) -> &Policies {
+            &self.policies
+        }
+
+        #[inline(always)]
+        fn policies_mut(&mut self) -> &mut Policies {
+            self.invalidate_cache();
+            &mut self.policies
+        }
+
+        #[inline(always)]
+        fn policies_offset(&self) -> usize {
+            Self::bytecode_witness_index_offset_static()
+                + WORD_SIZE // Witness index
+                + WORD_SIZE // Policies size
+                + WORD_SIZE // Inputs size
+                + WORD_SIZE // Outputs size
+                + WORD_SIZE // Witnesses size
+        }
+    }
+
+    impl Inputs for Blob {
+        #[inline(always)]
+        fn inputs(&self) -> &Vec<Input> {
+            &self.inputs
+        }
+
+        #[inline(always)]
+        fn inputs_mut(&mut self) -> &mut Vec<Input> {
+            self.invalidate_cache();
+            &mut self.inputs
+        }
+
+        #[inline(always)]
+        fn inputs_offset(&self) -> usize {
+            if let Some(CommonMetadata { inputs_offset, .. }) = &self.metadata {
+                return *inputs_offset;
+            }
+
+            self.policies_offset() + self.policies.size_dynamic()
+        }
+
+        #[inline(always)]
+        fn inputs_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                inputs_offset_at, ..
+            }) = &self.metadata
+            {
+                return inputs_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.inputs.len() {
+                Some(
+                    self.inputs_offset()
+                        + self
+                            .inputs()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+
+        #[inline(always)]
+        fn inputs_predicate_offset_at(&self, idx: usize) -> Option<(usize, usize)> {
+            if let Some(CommonMetadata {
+                inputs_predicate_offset_at,
+                ..
+            }) = &self.metadata
+            {
+                return inputs_predicate_offset_at.get(idx).cloned().unwrap_or(None);
+            }
+
+            self.inputs().get(idx).and_then(|input| {
+                input
+                    .predicate_offset()
+                    .and_then(|predicate| {
+                        self.inputs_offset_at(idx).map(|inputs| inputs + predicate)
+                    })
+                    .zip(input.predicate_len().map(bytes::padded_len_usize))
+            })
+        }
+    }
+
+    impl Outputs for Blob {
+        #[inline(always)]
+        fn outputs(&self) -> &Vec<Output> {
+            &self.outputs
+        }
+
+        #[inline(always)]
+        fn outputs_mut(&mut self) -> &mut Vec<Output> {
+            self.invalidate_cache();
+            &mut self.outputs
+        }
+
+        #[inline(always)]
+        fn outputs_offset(&self) -> usize {
+            if let Some(CommonMetadata { outputs_offset, .. }) = &self.metadata {
+                return *outputs_offset;
+            }
+
+            self.inputs_offset() + self.inputs().iter().map(|i| i.size()).sum::<usize>()
+        }
+
+        #[inline(always)]
+        fn outputs_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                outputs_offset_at, ..
+            }) = &self.metadata
+            {
+                return outputs_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.outputs.len() {
+                Some(
+                    self.outputs_offset()
+                        + self
+                            .outputs()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    impl Witnesses for Blob {
+        #[inline(always)]
+        fn witnesses(&self) -> &Vec<Witness> {
+            &self.witnesses
+        }
+
+        #[inline(always)]
+        fn witnesses_mut(&mut self) -> &mut Vec<Witness> {
+            self.invalidate_cache();
+            &mut self.witnesses
+        }
+
+        #[inline(always)]
+        fn witnesses_offset(&self) -> usize {
+            if let Some(CommonMetadata {
+                witnesses_offset, ..
+            }) = &self.metadata
+            {
+                return *witnesses_offset;
+            }
+
+            self.outputs_offset() + self.outputs().iter().map(|i| i.size()).sum::<usize>()
+        }
+
+        #[inline(always)]
+        fn witnesses_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                witnesses_offset_at,
+                ..
+            }) = &self.metadata
+            {
+                return witnesses_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.witnesses.len() {
+                Some(
+                    self.witnesses_offset()
+                        + self
+                            .witnesses()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    impl ChargeableMetadata for Blob {
+        #[inline(always)]
+        fn gas_metadata(&self) -> Option<&GasMetadata> {
+            self.metadata.as_ref().and_then(|m| m.gas.as_ref())
+        }
+    }
+
+    impl crate::field::private::SetGasMetadata for Blob {
+        #[inline(always)]
+        fn set_gas_metadata(&mut self, gas: GasMetadata) {
+            if let Some(metadata) = &mut self.metadata {
+                metadata.gas = Some(gas);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        builder::Finalizable,
+        FeeParameters,
+        TransactionBuilder,
+        TxParameters,
+    };
+    use fuel_crypto::SecretKey;
+    use fuel_types::{
+        canonical::Deserialize,
+        AssetId,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn signed_blob(rng: &mut StdRng, payload_len: usize) -> Blob {
+        let payload = (0..payload_len)
+            .map(|_| rng.gen::<u8>())
+            .collect::<Vec<_>>();
+        let mut builder = TransactionBuilder::blob(BlobBody {
+            id: BlobId::compute(&payload),
+            witness_index: 0,
+        });
+        builder.add_witness(payload.into());
+        builder.add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1_000_000,
+            AssetId::default(),
+            rng.gen(),
+        );
+        builder.finalize()
+    }
+
+    #[test]
+    fn finalize__produces_valid_signed_blob() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+
+        // When
+        let tx = signed_blob(rng, 1000);
+
+        // Then
+        assert_eq!(
+            tx.check(Default::default(), &ConsensusParameters::standard()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check__rejects_id_not_matching_the_payload() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let mut tx = signed_blob(rng, 1000);
+
+        // When
+        tx.id = rng.gen();
+
+        // Then
+        assert_eq!(
+            tx.check_without_signatures(
+                Default::default(),
+                &ConsensusParameters::standard()
+            ),
+            Err(ValidityError::TransactionBlobIdVerificationFailed)
+        );
+    }
+
+    #[test]
+    fn check__rejects_missing_payload_witness() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let mut tx = signed_blob(rng, 1000);
+
+        // When
+        tx.witness_index = 2;
+
+        // Then
+        assert_eq!(
+            tx.check_without_signatures(
+                Default::default(),
+                &ConsensusParameters::standard()
+            ),
+            Err(ValidityError::TransactionBlobWitnessIndex)
+        );
+    }
+
+    #[test]
+    fn check__rejects_larger_payload_than_allowed() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let tx = signed_blob(rng, 1000);
+        let mut params = ConsensusParameters::standard();
+        params.set_tx_params(TxParameters::DEFAULT.with_max_blob_size(999));
+
+        // When
+        let result = tx.check(Default::default(), &params);
+
+        // Then
+        assert_eq!(result, Err(ValidityError::TransactionBlobTooLarge));
+    }
+
+    #[test]
+    fn min_fee__grows_with_the_payload_size() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let gas_costs = GasCosts::default();
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero");
+        let small = signed_blob(rng, 1000);
+        let large = signed_blob(rng, 2000);
+
+        // When
+        let small_fee = small.min_fee(&gas_costs, &fee_params, 1);
+        let large_fee = large.min_fee(&gas_costs, &fee_params, 1);
+
+        // Then
+        let payload_fee = u128::from(1000 * fee_params.gas_per_byte);
+        assert!(large_fee.unwrap() - small_fee.unwrap() >= payload_fee);
+    }
+
+    #[test]
+    fn blob__roundtrips_through_the_transaction_serialization() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let tx = signed_blob(rng, 1000);
+
+        // When
+        let bytes = crate::Transaction::from(tx.clone()).to_bytes();
+
+        // Then
+        assert_eq!(bytes, tx.to_bytes());
+        let decoded = crate::Transaction::from_bytes(&bytes).expect("Valid blob");
+        assert_eq!(decoded.as_blob(), Some(&tx));
+        let id_offset = tx.blob_id_offset();
+        assert_eq!(&bytes[id_offset..][..BlobId::LEN], tx.id.as_ref());
+        let input_offset = tx.inputs_offset_at(0).expect("The input exists");
+        assert_eq!(
+            Input::decode(&mut &bytes[input_offset..]),
+            Ok(tx.inputs[0].clone())
+        );
+    }
+}
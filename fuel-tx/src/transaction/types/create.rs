@@ -20,6 +20,7 @@ use crate::{
     ConsensusParameters,
     Contract,
     GasCosts,
+    GasMetadata,
     Input,
     Output,
     StorageSlot,
@@ -28,6 +29,7 @@ use crate::{
     Witness,
 };
 use derivative::Derivative;
+use fuel_asm::Instruction;
 use fuel_types::{
     bytes,
     bytes::WORD_SIZE,
@@ -44,6 +46,7 @@ use fuel_types::{
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+use core::cmp::Ordering;
 use hashbrown::HashMap;
 
 #[cfg(all(test, feature = "std"))]
@@ -63,6 +66,7 @@ pub struct CreateMetadata {
     pub outputs_offset_at: Vec<usize>,
     pub witnesses_offset: usize,
     pub witnesses_offset_at: Vec<usize>,
+    pub gas: Option<GasMetadata>,
 }
 
 impl CreateMetadata {
@@ -79,13 +83,13 @@ impl CreateMetadata {
             outputs_offset_at,
             witnesses_offset,
             witnesses_offset_at,
+            gas,
         } = CommonMetadata::compute(tx, chain_id);
 
         let salt = tx.salt();
-        let storage_slots = tx.storage_slots();
         let contract = Contract::try_from(tx)?;
         let contract_root = contract.root();
-        let state_root = Contract::initial_state_root(storage_slots.iter());
+        let state_root = tx.state_root();
         let contract_id = contract.id(salt, &contract_root, &state_root);
 
         Ok(Self {
@@ -100,13 +104,14 @@ impl CreateMetadata {
             outputs_offset_at,
             witnesses_offset,
             witnesses_offset_at,
+            gas,
         })
     }
 }
 
 #[derive(Default, Debug, Clone, Derivative)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
+#[derive(fuel_types::canonical::Serialize)]
 #[canonical(prefix = TransactionRepr::Create)]
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
 #[derivative(Eq, PartialEq, Hash)]
@@ -129,6 +134,91 @@ impl Create {
     pub fn metadata(&self) -> &Option<CreateMetadata> {
         &self.metadata
     }
+
+    /// The code root of the deployed contract, computed from the bytecode witness.
+    ///
+    /// Returns `None` if the `bytecode_witness_index` is out of bounds.
+    pub fn code_root(&self) -> Option<Bytes32> {
+        self.witnesses
+            .get(self.bytecode_witness_index as usize)
+            .map(Contract::root_from_code)
+    }
+
+    /// The state root of the deployed contract, computed from the storage slots.
+    pub fn state_root(&self) -> Bytes32 {
+        Contract::initial_state_root(self.storage_slots.iter())
+    }
+
+    /// The id of the deployed contract, computed from the salt, the [`Self::code_root`]
+    /// and the [`Self::state_root`]. It is the contract id expected by the
+    /// `ContractCreated` output.
+    ///
+    /// Returns `None` if the `bytecode_witness_index` is out of bounds.
+    pub fn contract_id(&self) -> Option<ContractId> {
+        let contract = Contract::try_from(self).ok()?;
+        let code_root = contract.root();
+
+        Some(contract.id(&self.salt, &code_root, &self.state_root()))
+    }
+
+    /// Returns the key shared by two adjacent storage slots, if any. Since the slots are
+    /// sorted by key, it finds any duplicated key of a valid transaction.
+    #[cfg(feature = "builder")]
+    pub(crate) fn duplicated_storage_slot_key(&self) -> Option<&Bytes32> {
+        self.storage_slots
+            .windows(2)
+            .find(|s| s[0].key() == s[1].key())
+            .map(|s| s[0].key())
+    }
+}
+
+impl canonical::Deserialize for Create {
+    fn decode_static<I: canonical::Input + ?Sized>(
+        buffer: &mut I,
+    ) -> Result<Self, canonical::Error> {
+        let prefix = <TransactionRepr as canonical::Deserialize>::decode_static(buffer);
+        if prefix != Ok(TransactionRepr::Create) {
+            return Err(canonical::Error::InvalidPrefix)
+        }
+
+        Ok(Self {
+            bytecode_length: canonical::Deserialize::decode_static(buffer)?,
+            bytecode_witness_index: canonical::Deserialize::decode_static(buffer)?,
+            policies: canonical::Deserialize::decode_static(buffer)?,
+            storage_slots: canonical::Deserialize::decode_static(buffer)?,
+            inputs: canonical::Deserialize::decode_static(buffer)?,
+            outputs: canonical::Deserialize::decode_static(buffer)?,
+            witnesses: canonical::Deserialize::decode_static(buffer)?,
+            salt: canonical::Deserialize::decode_static(buffer)?,
+            metadata: None,
+        })
+    }
+
+    fn decode_dynamic<I: canonical::Input + ?Sized>(
+        &mut self,
+        buffer: &mut I,
+    ) -> Result<(), canonical::Error> {
+        self.policies.decode_dynamic(buffer)?;
+        self.storage_slots.decode_dynamic(buffer)?;
+        self.inputs.decode_dynamic(buffer)?;
+        self.outputs.decode_dynamic(buffer)?;
+        self.witnesses.decode_dynamic(buffer)?;
+        self.metadata = None;
+
+        // Only the builder sorts the storage slots, the ones received from the wire
+        // must already be sorted
+        if self
+            .storage_slots
+            .windows(2)
+            .any(|s| s[0].key() > s[1].key())
+        {
+            return Err(canonical::Error::Unknown(
+                "The storage slots are not sorted by key",
+            ))
+        }
+
+        Ok(())
+    }
 }
 
 impl crate::UniqueIdentifier for Create {
@@ -223,12 +313,9 @@ impl FormatValidityChecks for Create {
         block_height: BlockHeight,
         consensus_params: &ConsensusParameters,
     ) -> Result<(), ValidityError> {
-        let ConsensusParameters {
-            contract_params,
-            chain_id,
-            base_asset_id,
-            ..
-        } = consensus_params;
+        let contract_params = consensus_params.contract_params();
+        let chain_id = &consensus_params.chain_id();
+        let base_asset_id = consensus_params.base_asset_id();
 
         check_common_part(self, block_height, consensus_params)?;
 
@@ -238,8 +325,11 @@ impl FormatValidityChecks for Create {
             .map(|w| w.as_ref().len() as Word)
             .ok_or(ValidityError::TransactionCreateBytecodeWitnessIndex)?;
 
+        let padded_bytecode_len =
+            self.bytecode_length.checked_mul(Instruction::SIZE as Word);
+
         if bytecode_witness_len > contract_params.contract_max_size
-            || bytecode_witness_len / 4 != self.bytecode_length
+            || padded_bytecode_len != Some(bytecode_witness_len)
         {
             return Err(ValidityError::TransactionCreateBytecodeLen);
         }
@@ -250,15 +340,26 @@ impl FormatValidityChecks for Create {
             return Err(ValidityError::TransactionCreateStorageSlotMax);
         }
 
-        // Verify storage slots are sorted
-        if !self
-            .storage_slots
-            .as_slice()
+        // Verify storage slots are sorted and unique
+        self.storage_slots
             .windows(2)
-            .all(|s| s[0] < s[1])
-        {
-            return Err(ValidityError::TransactionCreateStorageSlotOrder);
-        }
+            .enumerate()
+            .try_for_each(|(i, s)| {
+                // The index of the second slot of the pair
+                let index = i + 1;
+                match s[0].key().cmp(s[1].key()) {
+                    Ordering::Less => Ok(()),
+                    Ordering::Equal => {
+                        Err(ValidityError::TransactionCreateStorageSlotsDuplicated {
+                            index,
+                            key: *s[0].key(),
+                        })
+                    }
+                    Ordering::Greater => {
+                        Err(ValidityError::TransactionCreateStorageSlotOrder { index })
+                    }
+                }
+            })?;
 
         self.inputs
             .iter()
@@ -342,6 +443,10 @@ impl crate::Cacheable for Create {
         self.metadata.is_some()
     }
 
+    fn invalidate_cache(&mut self) {
+        self.metadata = None;
+    }
+
     fn precompute(&mut self, chain_id: &ChainId) -> Result<(), ValidityError> {
         self.metadata = None;
         self.metadata = Some(CreateMetadata::compute(self, chain_id)?);
@@ -351,7 +456,13 @@ impl crate::Cacheable for Create {
 
 mod field {
     use super::*;
-    use crate::field::StorageSlotRef;
+    use crate::{
+        field::{
+            ChargeableMetadata,
+            StorageSlotRef,
+        },
+        Cacheable,
+    };
     use fuel_types::canonical::Serialize;
 
     impl BytecodeLength for Create {
@@ -362,6 +473,7 @@ mod field {
 
         #[inline(always)]
         fn bytecode_length_mut(&mut self) -> &mut Word {
+            self.invalidate_cache();
             &mut self.bytecode_length
         }
 
@@ -379,6 +491,7 @@ mod field {
 
         #[inline(always)]
         fn bytecode_witness_index_mut(&mut self) -> &mut u8 {
+            self.invalidate_cache();
             &mut self.bytecode_witness_index
         }
 
@@ -394,6 +507,7 @@ mod field {
         }
 
         fn policies_mut(&mut self) -> &mut Policies {
+            self.invalidate_cache();
             &mut self.policies
         }
 
@@ -410,6 +524,7 @@ mod field {
 
         #[inline(always)]
         fn salt_mut(&mut self) -> &mut Salt {
+            self.invalidate_cache();
             &mut self.salt
         }
 
@@ -432,6 +547,7 @@ mod field {
 
         #[inline(always)]
         fn storage_slots_mut(&mut self) -> StorageSlotRef {
+            self.invalidate_cache();
             StorageSlotRef {
                 storage_slots: &mut self.storage_slots,
             }
@@ -459,6 +575,7 @@ mod field {
 
         #[inline(always)]
         fn inputs_mut(&mut self) -> &mut Vec<Input> {
+            self.invalidate_cache();
             &mut self.inputs
         }
 
@@ -522,6 +639,7 @@ mod field {
 
         #[inline(always)]
         fn outputs_mut(&mut self) -> &mut Vec<Output> {
+            self.invalidate_cache();
             &mut self.outputs
         }
 
@@ -568,6 +686,7 @@ mod field {
 
         #[inline(always)]
         fn witnesses_mut(&mut self) -> &mut Vec<Witness> {
+            self.invalidate_cache();
             &mut self.witnesses
         }
 
@@ -608,6 +727,22 @@ mod field {
             }
         }
     }
+
+    impl ChargeableMetadata for Create {
+        #[inline(always)]
+        fn gas_metadata(&self) -> Option<&GasMetadata> {
+            self.metadata.as_ref().and_then(|m| m.gas.as_ref())
+        }
+    }
+
+    impl crate::field::private::SetGasMetadata for Create {
+        #[inline(always)]
+        fn set_gas_metadata(&mut self, gas: GasMetadata) {
+            if let Some(metadata) = &mut self.metadata {
+                metadata.gas = Some(gas);
+            }
+        }
+    }
 }
 
 impl TryFrom<&Create> for Contract {
@@ -658,7 +793,10 @@ mod tests {
             .check(0.into(), &ConsensusParameters::standard())
             .expect_err("Expected erroneous transaction");
 
-        assert_eq!(ValidityError::TransactionCreateStorageSlotOrder, err);
+        assert_eq!(
+            ValidityError::TransactionCreateStorageSlotOrder { index: 1 },
+            err
+        );
     }
 
     #[test]
@@ -678,6 +816,116 @@ mod tests {
         .check(0.into(), &ConsensusParameters::standard())
         .expect_err("Expected erroneous transaction");
 
-        assert_eq!(ValidityError::TransactionCreateStorageSlotOrder, err);
+        assert_eq!(
+            ValidityError::TransactionCreateStorageSlotsDuplicated {
+                index: 1,
+                key: Bytes32::zeroed()
+            },
+            err
+        );
+    }
+
+    #[test]
+    fn storage_slots_with_duplicated_key_and_distinct_values_are_rejected() {
+        let key = Bytes32::from([7u8; 32]);
+        let storage_slots = vec![
+            StorageSlot::new(Bytes32::zeroed(), Bytes32::zeroed()),
+            StorageSlot::new(key, Bytes32::from([1u8; 32])),
+            StorageSlot::new(key, Bytes32::from([2u8; 32])),
+        ];
+        let builder = crate::TransactionBuilder::create(
+            vec![].into(),
+            Salt::zeroed(),
+            storage_slots,
+        )
+        .add_random_fee_input()
+        .clone();
+
+        let err = builder
+            .finalize()
+            .check(0.into(), &ConsensusParameters::standard())
+            .expect_err("Expected erroneous transaction");
+        assert_eq!(
+            ValidityError::TransactionCreateStorageSlotsDuplicated { index: 2, key },
+            err
+        );
+
+        let err = builder
+            .try_finalize()
+            .expect_err("Expected erroneous transaction");
+        assert_eq!(crate::BuilderError::StorageSlotsDuplicated { key }, err);
+    }
+
+    #[test]
+    fn thousand_unique_storage_slots_are_valid() {
+        let storage_slots = (0..1000u64)
+            .rev()
+            .map(|i| {
+                let mut key = Bytes32::zeroed();
+                key[..8].copy_from_slice(&i.to_be_bytes());
+                StorageSlot::new(key, Bytes32::from([1u8; 32]))
+            })
+            .collect::<Vec<StorageSlot>>();
+        let mut params = ConsensusParameters::standard();
+        params.set_contract_params(
+            crate::ContractParameters::DEFAULT.with_max_storage_slots(1000),
+        );
+
+        let tx = crate::TransactionBuilder::create(
+            vec![].into(),
+            Salt::zeroed(),
+            storage_slots,
+        )
+        .add_random_fee_input()
+        .try_finalize()
+        .expect("Unique storage slots");
+
+        assert_eq!(tx.check(0.into(), &params), Ok(()));
+        let decoded = <Create as canonical::Deserialize>::from_bytes(
+            &canonical::Serialize::to_bytes(&tx),
+        );
+        assert_eq!(decoded, Ok(tx));
+    }
+
+    #[test]
+    fn contract_id_matches_the_expected_contract_created_output() {
+        let storage_slots = (0..10u8)
+            .map(|i| StorageSlot::new([i; 32].into(), [i + 1; 32].into()))
+            .collect::<Vec<StorageSlot>>();
+        let mut builder = crate::TransactionBuilder::create(
+            vec![0xfa; 64].into(),
+            Salt::from([2u8; 32]),
+            storage_slots,
+        );
+        builder.add_random_fee_input();
+        let tx = builder.finalize_without_signature();
+
+        let contract_id = tx.contract_id().expect("The bytecode witness exists");
+        let state_root = tx.state_root();
+        let tx = builder
+            .add_output(Output::contract_created(contract_id, state_root))
+            .finalize();
+
+        assert_eq!(tx.check(0.into(), &ConsensusParameters::standard()), Ok(()));
+        let metadata = CreateMetadata::compute(&tx, &ChainId::default())
+            .expect("The bytecode witness exists");
+        assert_eq!(tx.code_root(), Some(metadata.contract_root));
+        assert_eq!(state_root, metadata.state_root);
+        assert_eq!(contract_id, metadata.contract_id);
+    }
+
+    #[test]
+    fn contract_id_is_none_without_bytecode_witness() {
+        let mut tx = crate::TransactionBuilder::create(
+            vec![0xfa; 64].into(),
+            Salt::zeroed(),
+            vec![],
+        )
+        .finalize_without_signature();
+
+        tx.bytecode_witness_index = 1;
+
+        assert_eq!(tx.code_root(), None);
+        assert_eq!(tx.contract_id(), None);
     }
 }
@@ -22,3 +22,25 @@ fn test_create_serialization() {
     let create2 = Create::from_bytes(&bytes).unwrap();
     assert_eq!(create, create2);
 }
+
+#[test]
+fn test_create_deserialization_rejects_unsorted_storage_slots() {
+    let create = Create {
+        storage_slots: vec![
+            StorageSlot::new(Bytes32::from([3u8; 32]), Bytes32::from([4u8; 32])),
+            StorageSlot::new(Bytes32::from([1u8; 32]), Bytes32::from([2u8; 32])),
+        ],
+
+        ..Default::default()
+    };
+    let bytes = create.to_bytes();
+    let result = Create::from_bytes(&bytes);
+    assert_eq!(
+        result,
+        Err(canonical::Error::Unknown(
+            "The storage slots are not sorted by key"
+        ))
+    );
+    let result = crate::Transaction::from_bytes(&bytes);
+    assert!(result.is_err());
+}
@@ -1,6 +1,9 @@
 use crate::{
+    BudgetKind,
+    PredicateParameters,
     TxPointer,
     UtxoId,
+    ValidityError,
 };
 use alloc::{
     string::ToString,
@@ -202,6 +205,73 @@ impl Default for Input {
     }
 }
 
+/// Checks the predicate, its data and the owner the same way as
+/// [`Input::check_without_signature`] and [`Input::check_signature`], and also requires
+/// the predicate to be word aligned. The index of the input is unknown, so the errors
+/// don't have one.
+fn check_predicate(
+    owner: &Address,
+    predicate: &[u8],
+    predicate_data: &[u8],
+    predicate_params: &PredicateParameters,
+) -> Result<(), ValidityError> {
+    if predicate.is_empty() {
+        return Err(ValidityError::PredicateEmpty);
+    }
+
+    if predicate.len() as u64 > predicate_params.max_predicate_length {
+        return Err(ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateLength,
+            used: predicate.len() as u64,
+            max: predicate_params.max_predicate_length,
+            index: None,
+        });
+    }
+
+    if predicate_data.len() as u64 > predicate_params.max_predicate_data_length {
+        return Err(ValidityError::BudgetExceeded {
+            budget: BudgetKind::PredicateDataLength,
+            used: predicate_data.len() as u64,
+            max: predicate_params.max_predicate_data_length,
+            index: None,
+        });
+    }
+
+    if predicate.len() % bytes::WORD_SIZE != 0 {
+        return Err(ValidityError::PredicateNotWordAligned {
+            length: predicate.len(),
+        });
+    }
+
+    if !Input::is_predicate_owner_valid(owner, predicate) {
+        return Err(ValidityError::PredicateOwnerMismatch);
+    }
+
+    Ok(())
+}
+
+/// Checks the data of a message input the same way as
+/// [`Input::check_without_signature`].
+fn check_message_data(
+    data: &[u8],
+    predicate_params: &PredicateParameters,
+) -> Result<(), ValidityError> {
+    if data.is_empty() {
+        return Err(ValidityError::MessageDataEmpty);
+    }
+
+    if data.len() as u64 > predicate_params.max_message_data_length {
+        return Err(ValidityError::BudgetExceeded {
+            budget: BudgetKind::MessageDataLength,
+            used: data.len() as u64,
+            max: predicate_params.max_message_data_length,
+            index: None,
+        });
+    }
+
+    Ok(())
+}
+
 impl Input {
     pub const fn repr(&self) -> InputRepr {
         InputRepr::from_input(self)
@@ -359,6 +429,92 @@ impl Input {
         })
     }
 
+    /// Creates the coin predicate input like [`Self::coin_predicate`], but fails if
+    /// the predicate is empty or not word aligned, if the predicate or its data exceed
+    /// the limits of the `predicate_params`, or if the `owner` isn't the root of the
+    /// predicate.
+    pub fn checked_coin_predicate(
+        utxo_id: UtxoId,
+        owner: Address,
+        amount: Word,
+        asset_id: AssetId,
+        tx_pointer: TxPointer,
+        predicate_gas_used: Word,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+        predicate_params: &PredicateParameters,
+    ) -> Result<Self, ValidityError> {
+        check_predicate(&owner, &predicate, &predicate_data, predicate_params)?;
+
+        Ok(Self::coin_predicate(
+            utxo_id,
+            owner,
+            amount,
+            asset_id,
+            tx_pointer,
+            predicate_gas_used,
+            predicate,
+            predicate_data,
+        ))
+    }
+
+    /// Creates the message coin predicate input like [`Self::message_coin_predicate`],
+    /// but fails if the predicate is empty or not word aligned, if the predicate or its
+    /// data exceed the limits of the `predicate_params`, or if the `recipient` isn't the
+    /// root of the predicate.
+    pub fn checked_message_coin_predicate(
+        sender: Address,
+        recipient: Address,
+        amount: Word,
+        nonce: Nonce,
+        predicate_gas_used: Word,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+        predicate_params: &PredicateParameters,
+    ) -> Result<Self, ValidityError> {
+        check_predicate(&recipient, &predicate, &predicate_data, predicate_params)?;
+
+        Ok(Self::message_coin_predicate(
+            sender,
+            recipient,
+            amount,
+            nonce,
+            predicate_gas_used,
+            predicate,
+            predicate_data,
+        ))
+    }
+
+    /// Creates the message data predicate input like [`Self::message_data_predicate`],
+    /// but fails if the predicate is empty or not word aligned, if the predicate or its
+    /// data exceed the limits of the `predicate_params`, if the `recipient` isn't the
+    /// root of the predicate, or if the `data` is empty or exceeds its limit.
+    pub fn checked_message_data_predicate(
+        sender: Address,
+        recipient: Address,
+        amount: Word,
+        nonce: Nonce,
+        predicate_gas_used: Word,
+        data: Vec<u8>,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+        predicate_params: &PredicateParameters,
+    ) -> Result<Self, ValidityError> {
+        check_predicate(&recipient, &predicate, &predicate_data, predicate_params)?;
+        check_message_data(&data, predicate_params)?;
+
+        Ok(Self::message_data_predicate(
+            sender,
+            recipient,
+            amount,
+            nonce,
+            predicate_gas_used,
+            data,
+            predicate,
+            predicate_data,
+        ))
+    }
+
     pub const fn utxo_id(&self) -> Option<&UtxoId> {
         match self {
             Self::CoinSigned(CoinSigned { utxo_id, .. })
@@ -553,6 +709,12 @@ impl Input {
         }
     }
 
+    /// Returns the length of the data of the message input, zero for the message
+    /// coins, or `None` for the other inputs.
+    pub fn message_data_len(&self) -> Option<usize> {
+        self.input_data_len()
+    }
+
     pub fn input_predicate(&self) -> Option<&[u8]> {
         match self {
             Input::CoinPredicate(CoinPredicate { predicate, .. })
@@ -645,10 +807,36 @@ impl Input {
         matches!(self, Input::MessageDataPredicate(_))
     }
 
+    /// Returns `true` for the message inputs with data, which are retryable in case of
+    /// revert, unlike the message coins.
+    pub const fn is_retryable_message(&self) -> bool {
+        self.is_message_data_signed() | self.is_message_data_predicate()
+    }
+
     pub const fn is_contract(&self) -> bool {
         matches!(self, Input::Contract(_))
     }
 
+    /// Returns `true` if the input is authorized by a signature in the witnesses.
+    pub const fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            Input::CoinSigned(_)
+                | Input::MessageCoinSigned(_)
+                | Input::MessageDataSigned(_)
+        )
+    }
+
+    /// Returns `true` if the input is authorized by a predicate.
+    pub const fn is_predicate(&self) -> bool {
+        matches!(
+            self,
+            Input::CoinPredicate(_)
+                | Input::MessageCoinPredicate(_)
+                | Input::MessageDataPredicate(_)
+        )
+    }
+
     pub const fn coin_predicate_offset() -> usize {
         INPUT_COIN_FIXED_SIZE
     }
@@ -109,8 +109,22 @@ where
     pub witness_index: Specification::Witness,
     #[derivative(Debug(format_with = "fmt_as_field"))]
     pub predicate_gas_used: Specification::PredicateGasUsed,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::transaction::hex_bytes",
+            bound = "Specification::Predicate: crate::transaction::hex_bytes::Bytes"
+        )
+    )]
     #[derivative(Debug(format_with = "fmt_as_field"))]
     pub predicate: Specification::Predicate,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::transaction::hex_bytes",
+            bound = "Specification::PredicateData: crate::transaction::hex_bytes::Bytes"
+        )
+    )]
     #[derivative(Debug(format_with = "fmt_as_field"))]
     pub predicate_data: Specification::PredicateData,
 }
@@ -158,10 +158,31 @@ where
     pub witness_index: Specification::Witness,
     #[derivative(Debug(format_with = "fmt_as_field"))]
     pub predicate_gas_used: Specification::PredicateGasUsed,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::transaction::hex_bytes",
+            bound = "Specification::Data: crate::transaction::hex_bytes::Bytes"
+        )
+    )]
     #[derivative(Debug(format_with = "fmt_as_field"))]
     pub data: Specification::Data,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::transaction::hex_bytes",
+            bound = "Specification::Predicate: crate::transaction::hex_bytes::Bytes"
+        )
+    )]
     #[derivative(Debug(format_with = "fmt_as_field"))]
     pub predicate: Specification::Predicate,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            with = "crate::transaction::hex_bytes",
+            bound = "Specification::PredicateData: crate::transaction::hex_bytes::Bytes"
+        )
+    )]
     #[derivative(Debug(format_with = "fmt_as_field"))]
     pub predicate_data: Specification::PredicateData,
 }
@@ -403,3 +424,93 @@ pub fn compute_message_id(
 
     (*hasher.finalize()).into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Input;
+
+    const SENDER: Address = Address::new([1u8; 32]);
+    const RECIPIENT: Address = Address::new([2u8; 32]);
+    const NONCE: Nonce = Nonce::new([3u8; 32]);
+    const AMOUNT: Word = 4;
+
+    // sha256(sender || recipient || nonce || amount as big-endian u64 || data)
+    const MESSAGE_DATA_ID: &str =
+        "c2ea5ddb04288a066432b790c6efeb2e3793a40145c460fc859ada2ba5cf80f5";
+    const MESSAGE_COIN_ID: &str =
+        "67739d8c13a69646d3ce1a59cba24ba008d6ca10f9af265bff8079edad30f449";
+
+    fn message_id(hex: &str) -> MessageId {
+        hex.parse().expect("The id is a valid hex")
+    }
+
+    #[test]
+    fn compute_message_id_matches_known_ids() {
+        assert_eq!(
+            compute_message_id(&SENDER, &RECIPIENT, &NONCE, AMOUNT, &[5, 6, 7]),
+            message_id(MESSAGE_DATA_ID)
+        );
+        assert_eq!(
+            compute_message_id(&SENDER, &RECIPIENT, &NONCE, AMOUNT, &[]),
+            message_id(MESSAGE_COIN_ID)
+        );
+    }
+
+    #[test]
+    fn message_inputs_have_the_known_message_id() {
+        let data = vec![5, 6, 7];
+        let data_inputs = [
+            Input::message_data_signed(SENDER, RECIPIENT, AMOUNT, NONCE, 0, data.clone()),
+            Input::message_data_predicate(
+                SENDER,
+                RECIPIENT,
+                AMOUNT,
+                NONCE,
+                0,
+                data,
+                vec![8u8; 8],
+                vec![],
+            ),
+        ];
+        let coin_inputs = [
+            Input::message_coin_signed(SENDER, RECIPIENT, AMOUNT, NONCE, 0),
+            Input::message_coin_predicate(
+                SENDER,
+                RECIPIENT,
+                AMOUNT,
+                NONCE,
+                0,
+                vec![8u8; 8],
+                vec![],
+            ),
+        ];
+
+        for input in data_inputs {
+            assert_eq!(input.message_id(), Some(message_id(MESSAGE_DATA_ID)));
+            assert_eq!(input.message_data_len(), Some(3));
+            assert!(input.is_retryable_message());
+        }
+        for input in coin_inputs {
+            assert_eq!(input.message_id(), Some(message_id(MESSAGE_COIN_ID)));
+            assert_eq!(input.message_data_len(), Some(0));
+            assert!(!input.is_retryable_message());
+        }
+    }
+
+    #[test]
+    fn non_message_inputs_have_no_message_id() {
+        let input = Input::coin_signed(
+            Default::default(),
+            RECIPIENT,
+            AMOUNT,
+            Default::default(),
+            Default::default(),
+            0,
+        );
+
+        assert_eq!(input.message_id(), None);
+        assert_eq!(input.message_data_len(), None);
+        assert!(!input.is_retryable_message());
+    }
+}
@@ -90,12 +90,10 @@ impl crate::UniqueIdentifier for Mint {
     }
 }
 
-impl FormatValidityChecks for Mint {
-    fn check_signatures(&self, _: &ChainId) -> Result<(), ValidityError> {
-        Ok(())
-    }
-
-    fn check_without_signatures(
+impl Mint {
+    /// Performs the checks of [`FormatValidityChecks::check_without_signatures`],
+    /// except that the `mint_asset_id` is the base asset.
+    pub fn check_without_base_asset(
         &self,
         block_height: BlockHeight,
         consensus_params: &ConsensusParameters,
@@ -110,8 +108,24 @@ impl FormatValidityChecks for Mint {
             return Err(ValidityError::TransactionMintIncorrectOutputIndex);
         }
 
+        Ok(())
+    }
+}
+
+impl FormatValidityChecks for Mint {
+    fn check_signatures(&self, _: &ChainId) -> Result<(), ValidityError> {
+        Ok(())
+    }
+
+    fn check_without_signatures(
+        &self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+    ) -> Result<(), ValidityError> {
+        self.check_without_base_asset(block_height, consensus_params)?;
+
         // It is temporary check until https://github.com/FuelLabs/fuel-core/issues/1205
-        if self.mint_asset_id != consensus_params.base_asset_id {
+        if self.mint_asset_id != *consensus_params.base_asset_id() {
             return Err(ValidityError::TransactionMintNonBaseAsset);
         }
 
@@ -124,6 +138,10 @@ impl crate::Cacheable for Mint {
         self.metadata.is_some()
     }
 
+    fn invalidate_cache(&mut self) {
+        self.metadata = None;
+    }
+
     fn precompute(&mut self, chain_id: &ChainId) -> Result<(), ValidityError> {
         self.metadata = None;
         self.metadata = Some(MintMetadata::compute(self, chain_id));
@@ -133,12 +151,15 @@ impl crate::Cacheable for Mint {
 
 mod field {
     use super::*;
-    use crate::field::{
-        InputContract,
-        MintAmount,
-        MintAssetId,
-        MintGasPrice,
-        OutputContract,
+    use crate::{
+        field::{
+            InputContract,
+            MintAmount,
+            MintAssetId,
+            MintGasPrice,
+            OutputContract,
+        },
+        Cacheable,
     };
 
     impl TxPointerField for Mint {
@@ -149,6 +170,7 @@ mod field {
 
         #[inline(always)]
         fn tx_pointer_mut(&mut self) -> &mut TxPointer {
+            self.invalidate_cache();
             &mut self.tx_pointer
         }
 
@@ -166,6 +188,7 @@ mod field {
 
         #[inline(always)]
         fn input_contract_mut(&mut self) -> &mut input::contract::Contract {
+            self.invalidate_cache();
             &mut self.input_contract
         }
 
@@ -183,6 +206,7 @@ mod field {
 
         #[inline(always)]
         fn output_contract_mut(&mut self) -> &mut output::contract::Contract {
+            self.invalidate_cache();
             &mut self.output_contract
         }
 
@@ -200,6 +224,7 @@ mod field {
 
         #[inline(always)]
         fn mint_amount_mut(&mut self) -> &mut fuel_types::Word {
+            self.invalidate_cache();
             &mut self.mint_amount
         }
 
@@ -217,6 +242,7 @@ mod field {
 
         #[inline(always)]
         fn mint_asset_id_mut(&mut self) -> &mut AssetId {
+            self.invalidate_cache();
             &mut self.mint_asset_id
         }
 
@@ -234,6 +260,7 @@ mod field {
 
         #[inline(always)]
         fn gas_price_mut(&mut self) -> &mut Word {
+            self.invalidate_cache();
             &mut self.gas_price
         }
 
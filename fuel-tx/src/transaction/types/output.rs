@@ -51,6 +51,14 @@ pub enum Output {
     },
 }
 
+/// The error returned when the transaction has no `Output::Change` for the asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[display(fmt = "The change output for the asset {} is not found", asset_id)]
+pub struct OutputNotFound {
+    /// The asset of the missing change output.
+    pub asset_id: AssetId,
+}
+
 impl Default for Output {
     fn default() -> Self {
         Self::ContractCreated {
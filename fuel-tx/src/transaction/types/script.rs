@@ -1,5 +1,4 @@
 use crate::{
-    field::WitnessLimit,
     policies::Policies,
     transaction::{
         consensus_parameters::TxParameters,
@@ -20,11 +19,12 @@ use crate::{
         },
         Chargeable,
     },
+    BudgetKind,
     ConsensusParameters,
-    FeeParameters,
     GasCosts,
     Input,
     Output,
+    Receipt,
     TransactionRepr,
     ValidityError,
     Witness,
@@ -59,8 +59,10 @@ pub(crate) struct ScriptMetadata {
 #[derivative(Eq, PartialEq, Hash, Debug)]
 pub struct Script {
     pub(crate) script_gas_limit: Word,
+    #[cfg_attr(feature = "serde", serde(with = "crate::transaction::hex_bytes"))]
     #[derivative(Debug(format_with = "fmt_truncated_hex::<16>"))]
     pub(crate) script: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::transaction::hex_bytes"))]
     #[derivative(Debug(format_with = "fmt_truncated_hex::<16>"))]
     pub(crate) script_data: Vec<u8>,
     pub(crate) policies: Policies,
@@ -101,6 +103,28 @@ impl Default for Script {
 }
 
 impl Script {
+    /// The root of no receipts, the SHA-256 hash of an empty input.
+    pub const EMPTY_RECEIPTS_ROOT: Bytes32 = Bytes32::new([
+        0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99,
+        0x6f, 0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95,
+        0x99, 0x1b, 0x78, 0x52, 0xb8, 0x55,
+    ]);
+
+    /// Computes the `receipts_root` of the script that produced the `receipts`: the
+    /// root of the binary Merkle tree with the canonical bytes of the receipts as leaves,
+    /// the same as computed by the VM.
+    ///
+    /// The root of no receipts is [`Self::EMPTY_RECEIPTS_ROOT`].
+    pub fn compute_receipts_root(receipts: &[Receipt]) -> Bytes32 {
+        crate::receipts_root(receipts)
+    }
+
+    /// Sets the `receipts_root` to the root of the `receipts`, see
+    /// [`Self::compute_receipts_root`].
+    pub fn set_receipts_root_from(&mut self, receipts: &[Receipt]) {
+        *self.receipts_root_mut() = Self::compute_receipts_root(receipts);
+    }
+
     /// Prepare script for execution by clearing malleable fields.
     pub fn prepare_init_execute(&mut self) {
         *self.receipts_root_mut() = Default::default();
@@ -111,6 +135,12 @@ impl Script {
             .iter_mut()
             .for_each(Output::prepare_init_execute);
     }
+
+    /// Prepare script for predicate verification by clearing malleable fields, the
+    /// same as for the execution.
+    pub fn prepare_init_predicate(&mut self) {
+        self.prepare_init_execute()
+    }
 }
 
 impl crate::UniqueIdentifier for Script {
@@ -140,16 +170,8 @@ impl crate::UniqueIdentifier for Script {
 
 impl Chargeable for Script {
     #[inline(always)]
-    fn max_gas(&self, gas_costs: &GasCosts, fee: &FeeParameters) -> fuel_asm::Word {
-        // The basic implementation of the `max_gas` + `gas_limit`.
-        let remaining_allowed_witness = self
-            .witness_limit()
-            .saturating_sub(self.witnesses().size_dynamic() as u64)
-            .saturating_mul(fee.gas_per_byte);
-
-        self.min_gas(gas_costs, fee)
-            .saturating_add(remaining_allowed_witness)
-            .saturating_add(self.script_gas_limit)
+    fn execution_gas_limit(&self) -> Word {
+        self.script_gas_limit
     }
 
     #[inline(always)]
@@ -192,11 +214,21 @@ impl FormatValidityChecks for Script {
         check_common_part(self, block_height, consensus_params)?;
         let script_params = consensus_params.script_params();
         if self.script.len() as u64 > script_params.max_script_length {
-            Err(ValidityError::TransactionScriptLength)?;
+            Err(ValidityError::BudgetExceeded {
+                budget: BudgetKind::ScriptLength,
+                used: self.script.len() as u64,
+                max: script_params.max_script_length,
+                index: None,
+            })?;
         }
 
         if self.script_data.len() as u64 > script_params.max_script_data_length {
-            Err(ValidityError::TransactionScriptDataLength)?;
+            Err(ValidityError::BudgetExceeded {
+                budget: BudgetKind::ScriptDataLength,
+                used: self.script_data.len() as u64,
+                max: script_params.max_script_data_length,
+                index: None,
+            })?;
         }
 
         self.outputs
@@ -218,6 +250,10 @@ impl crate::Cacheable for Script {
         self.metadata.is_some()
     }
 
+    fn invalidate_cache(&mut self) {
+        self.metadata = None;
+    }
+
     fn precompute(&mut self, chain_id: &ChainId) -> Result<(), ValidityError> {
         self.metadata = None;
         self.metadata = Some(ScriptMetadata {
@@ -230,6 +266,11 @@ impl crate::Cacheable for Script {
 
 mod field {
     use super::*;
+    use crate::{
+        field::ChargeableMetadata,
+        Cacheable,
+        GasMetadata,
+    };
 
     impl ScriptGasLimit for Script {
         #[inline(always)]
@@ -239,6 +280,7 @@ mod field {
 
         #[inline(always)]
         fn script_gas_limit_mut(&mut self) -> &mut Word {
+            self.invalidate_cache();
             &mut self.script_gas_limit
         }
 
@@ -256,6 +298,7 @@ mod field {
 
         #[inline(always)]
         fn receipts_root_mut(&mut self) -> &mut Bytes32 {
+            self.invalidate_cache();
             &mut self.receipts_root
         }
 
@@ -279,6 +322,7 @@ mod field {
 
         #[inline(always)]
         fn script_mut(&mut self) -> &mut Vec<u8> {
+            self.invalidate_cache();
             &mut self.script
         }
 
@@ -296,6 +340,7 @@ mod field {
 
         #[inline(always)]
         fn script_data_mut(&mut self) -> &mut Vec<u8> {
+            self.invalidate_cache();
             &mut self.script_data
         }
 
@@ -320,6 +365,7 @@ mod field {
 
         #[inline(always)]
         fn policies_mut(&mut self) -> &mut Policies {
+            self.invalidate_cache();
             &mut self.policies
         }
 
@@ -337,6 +383,7 @@ mod field {
 
         #[inline(always)]
         fn inputs_mut(&mut self) -> &mut Vec<Input> {
+            self.invalidate_cache();
             &mut self.inputs
         }
 
@@ -414,6 +461,7 @@ mod field {
 
         #[inline(always)]
         fn outputs_mut(&mut self) -> &mut Vec<Output> {
+            self.invalidate_cache();
             &mut self.outputs
         }
 
@@ -467,6 +515,7 @@ mod field {
 
         #[inline(always)]
         fn witnesses_mut(&mut self) -> &mut Vec<Witness> {
+            self.invalidate_cache();
             &mut self.witnesses
         }
 
@@ -515,4 +564,96 @@ mod field {
             }
         }
     }
+
+    impl ChargeableMetadata for Script {
+        #[inline(always)]
+        fn gas_metadata(&self) -> Option<&GasMetadata> {
+            self.metadata.as_ref().and_then(|m| m.common.gas.as_ref())
+        }
+    }
+
+    impl crate::field::private::SetGasMetadata for Script {
+        #[inline(always)]
+        fn set_gas_metadata(&mut self, gas: GasMetadata) {
+            if let Some(metadata) = &mut self.metadata {
+                metadata.common.gas = Some(gas);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::ScriptExecutionResult;
+    use fuel_crypto::Hasher;
+    use fuel_merkle::binary::in_memory::MerkleTree;
+    use fuel_types::ContractId;
+
+    fn receipts(count: u8) -> Vec<Receipt> {
+        (0..count)
+            .map(|i| Receipt::ret(ContractId::new([i; 32]), i.into(), 0, 0))
+            .collect()
+    }
+
+    #[test]
+    fn compute_receipts_root__of_no_receipts_is_empty_root() {
+        // When
+        let root = Script::compute_receipts_root(&[]);
+
+        // Then
+        assert_eq!(root, Script::EMPTY_RECEIPTS_ROOT);
+        assert_eq!(root, Hasher::default().digest());
+        assert_eq!(root, Bytes32::from(MerkleTree::new().root()));
+    }
+
+    #[test]
+    fn compute_receipts_root__of_one_receipt_is_its_leaf_hash() {
+        // Given
+        let receipt = Receipt::script_result(ScriptExecutionResult::Success, 100);
+
+        // When
+        let root = Script::compute_receipts_root(core::slice::from_ref(&receipt));
+
+        // Then
+        let expected_root = Hasher::default()
+            .chain([0x00])
+            .chain(receipt.to_bytes())
+            .digest();
+        assert_eq!(root, expected_root);
+    }
+
+    #[test]
+    fn compute_receipts_root__matches_fuel_merkle() {
+        // Given
+        let receipts = receipts(10);
+
+        // When
+        let root = Script::compute_receipts_root(&receipts);
+
+        // Then
+        let mut tree = MerkleTree::new();
+        for receipt in &receipts {
+            tree.push(receipt.to_bytes().as_slice());
+        }
+        assert_eq!(root, Bytes32::from(tree.root()));
+    }
+
+    #[test]
+    fn set_receipts_root_from__sets_the_computed_root() {
+        // Given
+        let receipts = receipts(3);
+        let mut script = Script::default();
+
+        // When
+        script.set_receipts_root_from(&receipts);
+
+        // Then
+        assert_eq!(
+            *script.receipts_root(),
+            Script::compute_receipts_root(&receipts)
+        );
+    }
 }
@@ -42,6 +42,12 @@ impl StorageSlot {
     }
 }
 
+impl From<(Bytes32, Bytes32)> for StorageSlot {
+    fn from((key, value): (Bytes32, Bytes32)) -> Self {
+        Self::new(key, value)
+    }
+}
+
 impl From<&StorageSlot> for Bytes64 {
     fn from(s: &StorageSlot) -> Self {
         let mut buf = [0u8; StorageSlot::SLOT_SIZE];
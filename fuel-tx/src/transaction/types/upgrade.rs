@@ -0,0 +1,678 @@
+use crate::{
+    policies::Policies,
+    transaction::{
+        field::{
+            Inputs,
+            Outputs,
+            Policies as PoliciesField,
+            UpgradePurpose as UpgradePurposeField,
+            Witnesses,
+        },
+        metadata::CommonMetadata,
+        validity::{
+            check_common_part,
+            FormatValidityChecks,
+        },
+        Chargeable,
+    },
+    ConsensusParameters,
+    GasCosts,
+    Input,
+    Output,
+    TransactionRepr,
+    ValidityError,
+    Witness,
+};
+use derivative::Derivative;
+use fuel_crypto::Hasher;
+use fuel_types::{
+    bytes,
+    bytes::WORD_SIZE,
+    canonical::Serialize,
+    BlockHeight,
+    Bytes32,
+    ChainId,
+    Word,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+/// The purpose of the [`Upgrade`] transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
+pub enum UpgradePurpose {
+    /// Replaces the consensus parameters with the canonically serialized
+    /// [`ConsensusParameters`] of the witness at `witness_index`.
+    ConsensusParameters {
+        /// The index of the witness with the serialized parameters.
+        witness_index: u8,
+        /// The hash of the serialized parameters, see [`ConsensusParameters::hash`].
+        checksum: Bytes32,
+    },
+    /// Replaces the state transition function with the bytecode of the `root`.
+    StateTransition {
+        /// The Merkle root of the new state transition bytecode.
+        root: Bytes32,
+    },
+}
+
+impl Default for UpgradePurpose {
+    fn default() -> Self {
+        Self::StateTransition {
+            root: Bytes32::zeroed(),
+        }
+    }
+}
+
+/// The transaction upgrading the network, authorized by an input owned by
+/// [`ConsensusParameters::privileged_address`].
+#[derive(Default, Debug, Clone, Derivative)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
+#[canonical(prefix = TransactionRepr::Upgrade)]
+#[derivative(Eq, PartialEq, Hash)]
+pub struct Upgrade {
+    pub(crate) upgrade_purpose: UpgradePurpose,
+    pub(crate) policies: Policies,
+    pub(crate) inputs: Vec<Input>,
+    pub(crate) outputs: Vec<Output>,
+    pub(crate) witnesses: Vec<Witness>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(PartialEq = "ignore", Hash = "ignore")]
+    #[canonical(skip)]
+    pub(crate) metadata: Option<CommonMetadata>,
+}
+
+impl Upgrade {
+    /// Prepare upgrade for predicate verification by clearing malleable fields of the
+    /// inputs and outputs.
+    pub fn prepare_init_predicate(&mut self) {
+        self.inputs_mut()
+            .iter_mut()
+            .for_each(Input::prepare_init_execute);
+        self.outputs_mut()
+            .iter_mut()
+            .for_each(Output::prepare_init_execute);
+    }
+}
+
+impl crate::UniqueIdentifier for Upgrade {
+    fn id(&self, chain_id: &ChainId) -> crate::TxId {
+        if let Some(id) = self.cached_id() {
+            return id;
+        }
+
+        let mut clone = self.clone();
+
+        // Empties fields that should be zero during the signing.
+        clone.inputs_mut().iter_mut().for_each(Input::prepare_sign);
+        clone
+            .outputs_mut()
+            .iter_mut()
+            .for_each(Output::prepare_sign);
+        clone.witnesses_mut().clear();
+
+        crate::transaction::compute_transaction_id(chain_id, &mut clone)
+    }
+
+    fn cached_id(&self) -> Option<crate::TxId> {
+        self.metadata.as_ref().map(|m| m.id)
+    }
+}
+
+impl Chargeable for Upgrade {
+    #[inline(always)]
+    fn metered_bytes_size(&self) -> usize {
+        Serialize::size(self)
+    }
+
+    fn gas_used_by_metadata(&self, gas_costs: &GasCosts) -> Word {
+        let bytes = Serialize::size(self);
+        // Gas required to calculate the `tx_id`.
+        let tx_id_gas = gas_costs.s256.resolve(bytes as u64);
+
+        // Gas required to verify the checksum of the consensus parameters.
+        let checksum_gas = match self.upgrade_purpose {
+            UpgradePurpose::ConsensusParameters { witness_index, .. } => {
+                let len = self
+                    .witnesses
+                    .get(witness_index as usize)
+                    .map(|w| w.as_ref().len())
+                    .unwrap_or(0);
+                gas_costs.s256.resolve(len as Word)
+            }
+            UpgradePurpose::StateTransition { .. } => 0,
+        };
+
+        tx_id_gas.saturating_add(checksum_gas)
+    }
+}
+
+impl FormatValidityChecks for Upgrade {
+    fn check_signatures(&self, chain_id: &ChainId) -> Result<(), ValidityError> {
+        use crate::UniqueIdentifier;
+
+        let id = self.id(chain_id);
+
+        // There will be at most len(witnesses) signatures to cache
+        let mut recovery_cache = Some(HashMap::with_capacity(self.witnesses().len()));
+
+        self.inputs()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, input)| {
+                input.check_signature(index, &id, &self.witnesses, &mut recovery_cache)
+            })?;
+
+        Ok(())
+    }
+
+    fn check_without_signatures(
+        &self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+    ) -> Result<(), ValidityError> {
+        let base_asset_id = consensus_params.base_asset_id();
+        let privileged_address = consensus_params.privileged_address();
+
+        check_common_part(self, block_height, consensus_params)?;
+
+        if let UpgradePurpose::ConsensusParameters {
+            witness_index,
+            checksum,
+        } = &self.upgrade_purpose
+        {
+            let serialized_params = self
+                .witnesses
+                .get(*witness_index as usize)
+                .ok_or(ValidityError::TransactionUpgradeConsensusParametersWitnessIndex)?
+                .as_ref();
+
+            if &Hasher::hash(serialized_params) != checksum {
+                return Err(
+                    ValidityError::TransactionUpgradeConsensusParametersChecksumMismatch,
+                );
+            }
+
+            ConsensusParameters::from_canonical_bytes(serialized_params).map_err(
+                |_| ValidityError::TransactionUpgradeConsensusParametersDeserialization,
+            )?;
+        }
+
+        self.inputs
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, input)| match input {
+                Input::MessageDataSigned(_) | Input::MessageDataPredicate(_) => {
+                    Err(ValidityError::TransactionUpgradeMessageData { index })
+                }
+                _ if input.asset_id(base_asset_id) != Some(base_asset_id) => {
+                    Err(ValidityError::TransactionUpgradeInputNotBaseAsset { index })
+                }
+                _ => Ok(()),
+            })?;
+
+        self.outputs
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, output)| match output {
+                Output::Variable { .. } => {
+                    Err(ValidityError::TransactionUpgradeOutputVariable { index })
+                }
+
+                Output::ContractCreated { .. } => {
+                    Err(ValidityError::TransactionUpgradeOutputContractCreated { index })
+                }
+
+                Output::Change { asset_id, .. } if asset_id != base_asset_id => {
+                    Err(ValidityError::TransactionUpgradeOutputChangeNotBaseAsset {
+                        index,
+                    })
+                }
+
+                _ => Ok(()),
+            })?;
+
+        let is_authorized = self.inputs.iter().any(|input| {
+            input.input_owner().or_else(|| input.recipient()) == Some(privileged_address)
+        });
+
+        if !is_authorized {
+            return Err(ValidityError::TransactionUpgradeNoPrivilegedAddress);
+        }
+
+        Ok(())
+    }
+}
+
+impl crate::Cacheable for Upgrade {
+    fn is_computed(&self) -> bool {
+        self.metadata.is_some()
+    }
+
+    fn invalidate_cache(&mut self) {
+        self.metadata = None;
+    }
+
+    fn precompute(&mut self, chain_id: &ChainId) -> Result<(), ValidityError> {
+        self.metadata = None;
+        self.metadata = Some(CommonMetadata::compute(self, chain_id));
+        Ok(())
+    }
+}
+
+mod field {
+    use super::*;
+    use crate::{
+        field::ChargeableMetadata,
+        Cacheable,
+        GasMetadata,
+    };
+
+    impl UpgradePurposeField for Upgrade {
+        #[inline(always)]
+        fn upgrade_purpose(&self) -> &UpgradePurpose {
+            &self.upgrade_purpose
+        }
+
+        #[inline(always)]
+        fn upgrade_purpose_mut(&mut self) -> &mut UpgradePurpose {
+            self.invalidate_cache();
+            &mut self.upgrade_purpose
+        }
+
+        #[inline(always)]
+        fn upgrade_purpose_offset_static() -> usize {
+            WORD_SIZE // `Transaction` enum discriminant
+        }
+    }
+
+    impl PoliciesField for Upgrade {
+        #[inline(always)]
+        fn policies(&self) -> &Policies {
+            &self.policies
+        }
+
+        #[inline(always)]
+        fn policies_mut(&mut self) -> &mut Policies {
+            self.invalidate_cache();
+            &mut self.policies
+        }
+
+        #[inline(always)]
+        fn policies_offset(&self) -> usize {
+            Self::upgrade_purpose_offset_static()
+                + self.upgrade_purpose.size()
+                + WORD_SIZE // Policies size
+                + WORD_SIZE // Inputs size
+                + WORD_SIZE // Outputs size
+                + WORD_SIZE // Witnesses size
+        }
+    }
+
+    impl Inputs for Upgrade {
+        #[inline(always)]
+        fn inputs(&self) -> &Vec<Input> {
+            &self.inputs
+        }
+
+        #[inline(always)]
+        fn inputs_mut(&mut self) -> &mut Vec<Input> {
+            self.invalidate_cache();
+            &mut self.inputs
+        }
+
+        #[inline(always)]
+        fn inputs_offset(&self) -> usize {
+            if let Some(CommonMetadata { inputs_offset, .. }) = &self.metadata {
+                return *inputs_offset;
+            }
+
+            self.policies_offset() + self.policies.size_dynamic()
+        }
+
+        #[inline(always)]
+        fn inputs_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                inputs_offset_at, ..
+            }) = &self.metadata
+            {
+                return inputs_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.inputs.len() {
+                Some(
+                    self.inputs_offset()
+                        + self
+                            .inputs()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+
+        #[inline(always)]
+        fn inputs_predicate_offset_at(&self, idx: usize) -> Option<(usize, usize)> {
+            if let Some(CommonMetadata {
+                inputs_predicate_offset_at,
+                ..
+            }) = &self.metadata
+            {
+                return inputs_predicate_offset_at.get(idx).cloned().unwrap_or(None);
+            }
+
+            self.inputs().get(idx).and_then(|input| {
+                input
+                    .predicate_offset()
+                    .and_then(|predicate| {
+                        self.inputs_offset_at(idx).map(|inputs| inputs + predicate)
+                    })
+                    .zip(input.predicate_len().map(bytes::padded_len_usize))
+            })
+        }
+    }
+
+    impl Outputs for Upgrade {
+        #[inline(always)]
+        fn outputs(&self) -> &Vec<Output> {
+            &self.outputs
+        }
+
+        #[inline(always)]
+        fn outputs_mut(&mut self) -> &mut Vec<Output> {
+            self.invalidate_cache();
+            &mut self.outputs
+        }
+
+        #[inline(always)]
+        fn outputs_offset(&self) -> usize {
+            if let Some(CommonMetadata { outputs_offset, .. }) = &self.metadata {
+                return *outputs_offset;
+            }
+
+            self.inputs_offset() + self.inputs().iter().map(|i| i.size()).sum::<usize>()
+        }
+
+        #[inline(always)]
+        fn outputs_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                outputs_offset_at, ..
+            }) = &self.metadata
+            {
+                return outputs_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.outputs.len() {
+                Some(
+                    self.outputs_offset()
+                        + self
+                            .outputs()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    impl Witnesses for Upgrade {
+        #[inline(always)]
+        fn witnesses(&self) -> &Vec<Witness> {
+            &self.witnesses
+        }
+
+        #[inline(always)]
+        fn witnesses_mut(&mut self) -> &mut Vec<Witness> {
+            self.invalidate_cache();
+            &mut self.witnesses
+        }
+
+        #[inline(always)]
+        fn witnesses_offset(&self) -> usize {
+            if let Some(CommonMetadata {
+                witnesses_offset, ..
+            }) = &self.metadata
+            {
+                return *witnesses_offset;
+            }
+
+            self.outputs_offset() + self.outputs().iter().map(|i| i.size()).sum::<usize>()
+        }
+
+        #[inline(always)]
+        fn witnesses_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                witnesses_offset_at,
+                ..
+            }) = &self.metadata
+            {
+                return witnesses_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.witnesses.len() {
+                Some(
+                    self.witnesses_offset()
+                        + self
+                            .witnesses()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    impl ChargeableMetadata for Upgrade {
+        #[inline(always)]
+        fn gas_metadata(&self) -> Option<&GasMetadata> {
+            self.metadata.as_ref().and_then(|m| m.gas.as_ref())
+        }
+    }
+
+    impl crate::field::private::SetGasMetadata for Upgrade {
+        #[inline(always)]
+        fn set_gas_metadata(&mut self, gas: GasMetadata) {
+            if let Some(metadata) = &mut self.metadata {
+                metadata.gas = Some(gas);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        builder::Finalizable,
+        TransactionBuilder,
+        UniqueIdentifier,
+    };
+    use fuel_crypto::SecretKey;
+    use fuel_types::{
+        canonical::Deserialize,
+        Address,
+        AssetId,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    /// The consensus parameters privileging the owner of the `key`.
+    fn params(key: &SecretKey) -> ConsensusParameters {
+        let mut params = ConsensusParameters::standard();
+        params.set_privileged_address(Input::owner(&key.public_key()));
+        params
+    }
+
+    fn builder(rng: &mut StdRng, key: SecretKey) -> TransactionBuilder<Upgrade> {
+        let mut builder = TransactionBuilder::upgrade_consensus_parameters(
+            &ConsensusParameters::standard(),
+        );
+        builder.with_params(params(&key)).add_unsigned_coin_input(
+            key,
+            rng.gen(),
+            1_000_000,
+            AssetId::default(),
+            rng.gen(),
+        );
+        builder
+    }
+
+    #[test]
+    fn check__accepts_upgrade_signed_by_the_privileged_address() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let tx = builder(rng, key).finalize();
+
+        // When
+        let result = tx.check(Default::default(), &params(&key));
+
+        // Then
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn check__rejects_checksum_not_matching_the_witness() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let mut tx = builder(rng, key).finalize();
+        *tx.upgrade_purpose_mut() = UpgradePurpose::ConsensusParameters {
+            witness_index: 0,
+            checksum: rng.gen(),
+        };
+
+        // When
+        let result = tx.check_without_signatures(Default::default(), &params(&key));
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ValidityError::TransactionUpgradeConsensusParametersChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn check__rejects_witness_not_encoding_consensus_parameters() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let serialized_params = vec![0xff; 64];
+        let mut builder =
+            TransactionBuilder::upgrade(UpgradePurpose::ConsensusParameters {
+                witness_index: 0,
+                checksum: Hasher::hash(&serialized_params),
+            });
+        builder
+            .with_params(params(&key))
+            .add_witness(serialized_params.into())
+            .add_unsigned_coin_input(
+                key,
+                rng.gen(),
+                1_000_000,
+                AssetId::default(),
+                rng.gen(),
+            );
+        let tx = builder.finalize();
+
+        // When
+        let result = tx.check(Default::default(), &params(&key));
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ValidityError::TransactionUpgradeConsensusParametersDeserialization)
+        );
+    }
+
+    #[test]
+    fn check__rejects_upgrade_without_input_of_the_privileged_address() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let tx = builder(rng, key).finalize();
+        let mut params = params(&key);
+        params.set_privileged_address(rng.gen::<Address>());
+
+        // When
+        let result = tx.check(Default::default(), &params);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ValidityError::TransactionUpgradeNoPrivilegedAddress)
+        );
+    }
+
+    #[test]
+    fn check__rejects_non_base_asset_input() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let mut builder = builder(rng, key);
+        builder.add_unsigned_coin_input(key, rng.gen(), 1_000, rng.gen(), rng.gen());
+        let tx = builder.finalize();
+
+        // When
+        let result = tx.check(Default::default(), &params(&key));
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ValidityError::TransactionUpgradeInputNotBaseAsset { index: 1 })
+        );
+    }
+
+    #[test]
+    fn id__is_stable_across_reordering_of_unrelated_witnesses() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let chain_id = params(&key).chain_id();
+        let mut tx = builder(rng, key).finalize_without_signature();
+        tx.metadata = None;
+        tx.witnesses.push(vec![1; 32].into());
+        tx.witnesses.push(vec![2; 32].into());
+        let expected_id = tx.id(&chain_id);
+
+        // When
+        tx.witnesses.swap(1, 3);
+
+        // Then
+        assert_eq!(tx.id(&chain_id), expected_id);
+    }
+
+    #[test]
+    fn upgrade__canonical_roundtrip() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let key = SecretKey::random(rng);
+        let mut tx = builder(rng, key).finalize();
+        *tx.upgrade_purpose_mut() = UpgradePurpose::StateTransition { root: rng.gen() };
+        let tx = crate::Transaction::from(tx);
+
+        // When
+        let decoded = crate::Transaction::from_bytes(&tx.to_bytes());
+
+        // Then
+        assert_eq!(decoded, Ok(tx));
+    }
+}
@@ -0,0 +1,811 @@
+use crate::{
+    policies::Policies,
+    transaction::{
+        field::{
+            BytecodeRoot,
+            BytecodeWitnessIndex,
+            Inputs,
+            Outputs,
+            Policies as PoliciesField,
+            ProofSet,
+            SubsectionIndex,
+            SubsectionsNumber,
+            Witnesses,
+        },
+        metadata::CommonMetadata,
+        validity::{
+            check_common_part,
+            FormatValidityChecks,
+        },
+        Chargeable,
+    },
+    ConsensusParameters,
+    GasCosts,
+    Input,
+    Output,
+    TransactionRepr,
+    ValidityError,
+    Witness,
+};
+use derivative::Derivative;
+use fuel_types::{
+    bytes,
+    bytes::WORD_SIZE,
+    canonical::Serialize,
+    BlockHeight,
+    Bytes32,
+    ChainId,
+    Word,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
+/// A subsection of the bytecode uploaded by an [`Upload`] transaction, with the proof of
+/// its inclusion into the bytecode.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UploadSubsection {
+    /// The Merkle root of the whole bytecode.
+    pub root: Bytes32,
+    /// The bytes of the subsection.
+    pub subsection: Vec<u8>,
+    /// The index of the subsection in the bytecode.
+    pub subsection_index: u16,
+    /// The number of subsections of the bytecode.
+    pub subsections_number: u16,
+    /// The proof of the inclusion of the subsection into the `root`.
+    pub proof_set: Vec<Bytes32>,
+}
+
+/// The error returned when the bytecode can't be split into subsections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum SplitError {
+    /// The size of the subsections is zero.
+    #[display(fmt = "The subsection size can't be zero")]
+    ZeroSubsectionSize,
+    /// The bytecode requires more than `u16::MAX` subsections.
+    #[display(fmt = "The bytecode requires more than `u16::MAX` subsections")]
+    TooManySubsections,
+}
+
+impl UploadSubsection {
+    /// Splits the `bytecode` into subsections of `subsection_size` bytes, the last one
+    /// may be shorter, with the proofs of their inclusion into the Merkle root of the
+    /// subsections.
+    pub fn split_bytecode(
+        bytecode: &[u8],
+        subsection_size: usize,
+    ) -> Result<Vec<Self>, SplitError> {
+        if subsection_size == 0 {
+            return Err(SplitError::ZeroSubsectionSize);
+        }
+
+        let subsections = bytecode.chunks(subsection_size).collect::<Vec<_>>();
+        let subsections_number = u16::try_from(subsections.len())
+            .map_err(|_| SplitError::TooManySubsections)?;
+
+        let mut tree = fuel_merkle::binary::in_memory::MerkleTree::new();
+        subsections
+            .iter()
+            .for_each(|subsection| tree.push(subsection));
+        let root = tree.root().into();
+
+        let subsections = (0..subsections_number)
+            .zip(subsections)
+            .map(|(subsection_index, subsection)| {
+                let (_, proof_set) = tree
+                    .prove(u64::from(subsection_index))
+                    .expect("The subsection is in the tree");
+
+                Self {
+                    root,
+                    subsection: subsection.to_vec(),
+                    subsection_index,
+                    subsections_number,
+                    proof_set: proof_set.into_iter().map(Bytes32::from).collect(),
+                }
+            })
+            .collect();
+
+        Ok(subsections)
+    }
+}
+
+/// The transaction uploading a subsection of a bytecode too large to fit into a single
+/// transaction, see [`UploadSubsection::split_bytecode`].
+#[derive(Default, Debug, Clone, Derivative)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
+#[canonical(prefix = TransactionRepr::Upload)]
+#[derivative(Eq, PartialEq, Hash)]
+pub struct Upload {
+    pub(crate) root: Bytes32,
+    pub(crate) witness_index: u8,
+    pub(crate) subsection_index: u16,
+    pub(crate) subsections_number: u16,
+    pub(crate) proof_set: Vec<Bytes32>,
+    pub(crate) policies: Policies,
+    pub(crate) inputs: Vec<Input>,
+    pub(crate) outputs: Vec<Output>,
+    pub(crate) witnesses: Vec<Witness>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[derivative(PartialEq = "ignore", Hash = "ignore")]
+    #[canonical(skip)]
+    pub(crate) metadata: Option<CommonMetadata>,
+}
+
+impl Upload {
+    /// Prepare upload for predicate verification by clearing malleable fields of the
+    /// inputs and outputs.
+    pub fn prepare_init_predicate(&mut self) {
+        self.inputs_mut()
+            .iter_mut()
+            .for_each(Input::prepare_init_execute);
+        self.outputs_mut()
+            .iter_mut()
+            .for_each(Output::prepare_init_execute);
+    }
+}
+
+impl crate::UniqueIdentifier for Upload {
+    fn id(&self, chain_id: &ChainId) -> crate::TxId {
+        if let Some(id) = self.cached_id() {
+            return id;
+        }
+
+        let mut clone = self.clone();
+
+        // Empties fields that should be zero during the signing.
+        clone.inputs_mut().iter_mut().for_each(Input::prepare_sign);
+        clone
+            .outputs_mut()
+            .iter_mut()
+            .for_each(Output::prepare_sign);
+        clone.witnesses_mut().clear();
+
+        crate::transaction::compute_transaction_id(chain_id, &mut clone)
+    }
+
+    fn cached_id(&self) -> Option<crate::TxId> {
+        self.metadata.as_ref().map(|m| m.id)
+    }
+}
+
+impl Chargeable for Upload {
+    #[inline(always)]
+    fn metered_bytes_size(&self) -> usize {
+        Serialize::size(self)
+    }
+
+    fn gas_used_by_metadata(&self, gas_costs: &GasCosts) -> Word {
+        let bytes = Serialize::size(self);
+        // Gas required to calculate the `tx_id`.
+        let tx_id_gas = gas_costs.s256.resolve(bytes as u64);
+
+        // Gas required to verify the subsection against the root: the hash of the leaf
+        // and of a node per proof.
+        let subsection_len = self
+            .witnesses
+            .get(self.witness_index as usize)
+            .map(|w| w.as_ref().len())
+            .unwrap_or(0);
+        let leaf_gas = gas_costs.s256.resolve(subsection_len as Word);
+        let node_gas = gas_costs
+            .s256
+            .resolve((Bytes32::LEN * 2) as Word)
+            .saturating_mul(self.proof_set.len() as Word);
+
+        tx_id_gas.saturating_add(leaf_gas).saturating_add(node_gas)
+    }
+}
+
+impl FormatValidityChecks for Upload {
+    fn check_signatures(&self, chain_id: &ChainId) -> Result<(), ValidityError> {
+        use crate::UniqueIdentifier;
+
+        let id = self.id(chain_id);
+
+        // There will be at most len(witnesses) - 1 signatures to cache, as one of the
+        // witnesses will be the subsection
+        let mut recovery_cache = Some(HashMap::with_capacity(core::cmp::max(
+            self.witnesses().len().saturating_sub(1),
+            1,
+        )));
+
+        self.inputs()
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, input)| {
+                input.check_signature(index, &id, &self.witnesses, &mut recovery_cache)
+            })?;
+
+        Ok(())
+    }
+
+    fn check_without_signatures(
+        &self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+    ) -> Result<(), ValidityError> {
+        let tx_params = consensus_params.tx_params();
+        let base_asset_id = consensus_params.base_asset_id();
+
+        check_common_part(self, block_height, consensus_params)?;
+
+        if self.subsections_number > tx_params.max_bytecode_subsections {
+            return Err(ValidityError::TransactionUploadTooManyBytecodeSubsections);
+        }
+
+        if self.proof_set.len() > tx_params.max_proof_set_length as usize {
+            return Err(ValidityError::TransactionUploadProofSetTooLong);
+        }
+
+        if self.subsection_index >= self.subsections_number {
+            return Err(ValidityError::TransactionUploadSubsectionIndexOutOfBounds);
+        }
+
+        let subsection = self
+            .witnesses
+            .get(self.witness_index as usize)
+            .ok_or(ValidityError::TransactionUploadBytecodeWitnessIndex)?;
+
+        let proof_set = self
+            .proof_set
+            .iter()
+            .map(|proof| **proof)
+            .collect::<Vec<_>>();
+
+        if !fuel_merkle::binary::verify(
+            &self.root,
+            subsection,
+            &proof_set,
+            u64::from(self.subsection_index),
+            u64::from(self.subsections_number),
+        ) {
+            return Err(ValidityError::TransactionUploadRootVerificationFailed);
+        }
+
+        self.inputs
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, input)| match input {
+                Input::Contract(_) => {
+                    Err(ValidityError::TransactionUploadInputContract { index })
+                }
+                Input::MessageDataSigned(_) | Input::MessageDataPredicate(_) => {
+                    Err(ValidityError::TransactionUploadMessageData { index })
+                }
+                _ => Ok(()),
+            })?;
+
+        self.outputs
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, output)| match output {
+                Output::Variable { .. } => {
+                    Err(ValidityError::TransactionUploadOutputVariable { index })
+                }
+
+                Output::ContractCreated { .. } => {
+                    Err(ValidityError::TransactionUploadOutputContractCreated { index })
+                }
+
+                Output::Change { asset_id, .. } if asset_id != base_asset_id => {
+                    Err(ValidityError::TransactionUploadOutputChangeNotBaseAsset {
+                        index,
+                    })
+                }
+
+                _ => Ok(()),
+            })?;
+
+        Ok(())
+    }
+}
+
+impl crate::Cacheable for Upload {
+    fn is_computed(&self) -> bool {
+        self.metadata.is_some()
+    }
+
+    fn invalidate_cache(&mut self) {
+        self.metadata = None;
+    }
+
+    fn precompute(&mut self, chain_id: &ChainId) -> Result<(), ValidityError> {
+        self.metadata = None;
+        self.metadata = Some(CommonMetadata::compute(self, chain_id));
+        Ok(())
+    }
+}
+
+mod field {
+    use super::*;
+    use crate::{
+        field::ChargeableMetadata,
+        Cacheable,
+        GasMetadata,
+    };
+
+    impl BytecodeRoot for Upload {
+        #[inline(always)]
+        fn bytecode_root(&self) -> &Bytes32 {
+            &self.root
+        }
+
+        #[inline(always)]
+        fn bytecode_root_mut(&mut self) -> &mut Bytes32 {
+            self.invalidate_cache();
+            &mut self.root
+        }
+
+        #[inline(always)]
+        fn bytecode_root_offset_static() -> usize {
+            WORD_SIZE // `Transaction` enum discriminant
+        }
+    }
+
+    impl BytecodeWitnessIndex for Upload {
+        #[inline(always)]
+        fn bytecode_witness_index(&self) -> &u8 {
+            &self.witness_index
+        }
+
+        #[inline(always)]
+        fn bytecode_witness_index_mut(&mut self) -> &mut u8 {
+            self.invalidate_cache();
+            &mut self.witness_index
+        }
+
+        #[inline(always)]
+        fn bytecode_witness_index_offset_static() -> usize {
+            Self::bytecode_root_offset_static() + Bytes32::LEN
+        }
+    }
+
+    impl SubsectionIndex for Upload {
+        #[inline(always)]
+        fn subsection_index(&self) -> &u16 {
+            &self.subsection_index
+        }
+
+        #[inline(always)]
+        fn subsection_index_mut(&mut self) -> &mut u16 {
+            self.invalidate_cache();
+            &mut self.subsection_index
+        }
+
+        #[inline(always)]
+        fn subsection_index_offset_static() -> usize {
+            Self::bytecode_witness_index_offset_static() + WORD_SIZE
+        }
+    }
+
+    impl SubsectionsNumber for Upload {
+        #[inline(always)]
+        fn subsections_number(&self) -> &u16 {
+            &self.subsections_number
+        }
+
+        #[inline(always)]
+        fn subsections_number_mut(&mut self) -> &mut u16 {
+            self.invalidate_cache();
+            &mut self.subsections_number
+        }
+
+        #[inline(always)]
+        fn subsections_number_offset_static() -> usize {
+            Self::subsection_index_offset_static() + WORD_SIZE
+        }
+    }
+
+    impl ProofSet for Upload {
+        #[inline(always)]
+        fn proof_set(&self) -> &Vec<Bytes32> {
+            &self.proof_set
+        }
+
+        #[inline(always)]
+        fn proof_set_mut(&mut self) -> &mut Vec<Bytes32> {
+            self.invalidate_cache();
+            &mut self.proof_set
+        }
+
+        #[inline(always)]
+        fn proof_set_offset_static() -> usize {
+            Self::subsections_number_offset_static() + WORD_SIZE
+                + WORD_SIZE // Proof set size
+                + WORD_SIZE // Policies size
+                + WORD_SIZE // Inputs size
+                + WORD_SIZE // Outputs size
+                + WORD_SIZE // Witnesses size
+        }
+    }
+
+    impl PoliciesField for Upload {
+        #[inline(always)]
+        fn policies(&self) -> &Policies {
+            &self.policies
+        }
+
+        #[inline(always)]
+        fn policies_mut(&mut self) -> &mut Policies {
+            self.invalidate_cache();
+            &mut self.policies
+        }
+
+        #[inline(always)]
+        fn policies_offset(&self) -> usize {
+            self.proof_set_offset() + self.proof_set.len() * Bytes32::LEN
+        }
+    }
+
+    impl Inputs for Upload {
+        #[inline(always)]
+        fn inputs(&self) -> &Vec<Input> {
+            &self.inputs
+        }
+
+        #[inline(always)]
+        fn inputs_mut(&mut self) -> &mut Vec<Input> {
+            self.invalidate_cache();
+            &mut self.inputs
+        }
+
+        #[inline(always)]
+        fn inputs_offset(&self) -> usize {
+            if let Some(CommonMetadata { inputs_offset, .. }) = &self.metadata {
+                return *inputs_offset;
+            }
+
+            self.policies_offset() + self.policies.size_dynamic()
+        }
+
+        #[inline(always)]
+        fn inputs_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                inputs_offset_at, ..
+            }) = &self.metadata
+            {
+                return inputs_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.inputs.len() {
+                Some(
+                    self.inputs_offset()
+                        + self
+                            .inputs()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+
+        #[inline(always)]
+        fn inputs_predicate_offset_at(&self, idx: usize) -> Option<(usize, usize)> {
+            if let Some(CommonMetadata {
+                inputs_predicate_offset_at,
+                ..
+            }) = &self.metadata
+            {
+                return inputs_predicate_offset_at.get(idx).cloned().unwrap_or(None);
+            }
+
+            self.inputs().get(idx).and_then(|input| {
+                input
+                    .predicate_offset()
+                    .and_then(|predicate| {
+                        self.inputs_offset_at(idx).map(|inputs| inputs + predicate)
+                    })
+                    .zip(input.predicate_len().map(bytes::padded_len_usize))
+            })
+        }
+    }
+
+    impl Outputs for Upload {
+        #[inline(always)]
+        fn outputs(&self) -> &Vec<Output> {
+            &self.outputs
+        }
+
+        #[inline(always)]
+        fn outputs_mut(&mut self) -> &mut Vec<Output> {
+            self.invalidate_cache();
+            &mut self.outputs
+        }
+
+        #[inline(always)]
+        fn outputs_offset(&self) -> usize {
+            if let Some(CommonMetadata { outputs_offset, .. }) = &self.metadata {
+                return *outputs_offset;
+            }
+
+            self.inputs_offset() + self.inputs().iter().map(|i| i.size()).sum::<usize>()
+        }
+
+        #[inline(always)]
+        fn outputs_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                outputs_offset_at, ..
+            }) = &self.metadata
+            {
+                return outputs_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.outputs.len() {
+                Some(
+                    self.outputs_offset()
+                        + self
+                            .outputs()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    impl Witnesses for Upload {
+        #[inline(always)]
+        fn witnesses(&self) -> &Vec<Witness> {
+            &self.witnesses
+        }
+
+        #[inline(always)]
+        fn witnesses_mut(&mut self) -> &mut Vec<Witness> {
+            self.invalidate_cache();
+            &mut self.witnesses
+        }
+
+        #[inline(always)]
+        fn witnesses_offset(&self) -> usize {
+            if let Some(CommonMetadata {
+                witnesses_offset, ..
+            }) = &self.metadata
+            {
+                return *witnesses_offset;
+            }
+
+            self.outputs_offset() + self.outputs().iter().map(|i| i.size()).sum::<usize>()
+        }
+
+        #[inline(always)]
+        fn witnesses_offset_at(&self, idx: usize) -> Option<usize> {
+            if let Some(CommonMetadata {
+                witnesses_offset_at,
+                ..
+            }) = &self.metadata
+            {
+                return witnesses_offset_at.get(idx).cloned();
+            }
+
+            if idx < self.witnesses.len() {
+                Some(
+                    self.witnesses_offset()
+                        + self
+                            .witnesses()
+                            .iter()
+                            .take(idx)
+                            .map(|i| i.size())
+                            .sum::<usize>(),
+                )
+            } else {
+                None
+            }
+        }
+    }
+
+    impl ChargeableMetadata for Upload {
+        #[inline(always)]
+        fn gas_metadata(&self) -> Option<&GasMetadata> {
+            self.metadata.as_ref().and_then(|m| m.gas.as_ref())
+        }
+    }
+
+    impl crate::field::private::SetGasMetadata for Upload {
+        #[inline(always)]
+        fn set_gas_metadata(&mut self, gas: GasMetadata) {
+            if let Some(metadata) = &mut self.metadata {
+                metadata.gas = Some(gas);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+    use crate::{
+        builder::Finalizable,
+        TransactionBuilder,
+        TxParameters,
+    };
+    use fuel_crypto::SecretKey;
+    use fuel_types::{
+        canonical::Deserialize,
+        AssetId,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    fn bytecode(rng: &mut StdRng) -> Vec<u8> {
+        (0..1000).map(|_| rng.gen::<u8>()).collect()
+    }
+
+    fn signed_upload(rng: &mut StdRng, subsection: UploadSubsection) -> Upload {
+        let mut builder = TransactionBuilder::upload(subsection);
+        builder.add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            1_000_000,
+            AssetId::default(),
+            rng.gen(),
+        );
+        builder.finalize()
+    }
+
+    #[test]
+    fn split_bytecode__every_subsection_verifies_against_the_root() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let bytecode = bytecode(rng);
+
+        // When
+        let subsections =
+            UploadSubsection::split_bytecode(&bytecode, 64).expect("Valid size");
+
+        // Then
+        assert_eq!(subsections.len(), 16);
+        let concatenated = subsections
+            .iter()
+            .flat_map(|subsection| subsection.subsection.clone())
+            .collect::<Vec<u8>>();
+        assert_eq!(concatenated, bytecode);
+        for subsection in &subsections {
+            let proof_set = subsection.proof_set.iter().map(|p| **p).collect();
+            assert!(fuel_merkle::binary::verify(
+                &subsection.root,
+                &subsection.subsection,
+                &proof_set,
+                u64::from(subsection.subsection_index),
+                u64::from(subsection.subsections_number),
+            ));
+            assert_eq!(subsection.root, subsections[0].root);
+        }
+    }
+
+    #[test]
+    fn split_bytecode__rejects_zero_subsection_size() {
+        let result = UploadSubsection::split_bytecode(&[1, 2, 3], 0);
+
+        assert_eq!(result, Err(SplitError::ZeroSubsectionSize));
+    }
+
+    #[test]
+    fn split_bytecode__rejects_more_than_u16_max_subsections() {
+        let bytecode = vec![0; u16::MAX as usize + 1];
+
+        let result = UploadSubsection::split_bytecode(&bytecode, 1);
+
+        assert_eq!(result, Err(SplitError::TooManySubsections));
+    }
+
+    #[test]
+    fn finalize__produces_valid_signed_upload_of_every_subsection() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let subsections =
+            UploadSubsection::split_bytecode(&bytecode(rng), 64).expect("Valid size");
+
+        for subsection in subsections {
+            // When
+            let tx = signed_upload(rng, subsection);
+
+            // Then
+            assert_eq!(
+                tx.check(Default::default(), &ConsensusParameters::standard()),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn check__rejects_tampered_subsection() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let subsections =
+            UploadSubsection::split_bytecode(&bytecode(rng), 64).expect("Valid size");
+        let mut tx = signed_upload(rng, subsections[3].clone());
+
+        // When
+        tx.witnesses[0].as_vec_mut()[10] ^= 1;
+
+        // Then
+        assert_eq!(
+            tx.check(Default::default(), &ConsensusParameters::standard()),
+            Err(ValidityError::TransactionUploadRootVerificationFailed)
+        );
+    }
+
+    #[test]
+    fn check__rejects_more_subsections_than_allowed() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let subsections =
+            UploadSubsection::split_bytecode(&bytecode(rng), 64).expect("Valid size");
+        let tx = signed_upload(rng, subsections[0].clone());
+        let mut params = ConsensusParameters::standard();
+        params.set_tx_params(TxParameters::DEFAULT.with_max_bytecode_subsections(15));
+
+        // When
+        let result = tx.check(Default::default(), &params);
+
+        // Then
+        assert_eq!(
+            result,
+            Err(ValidityError::TransactionUploadTooManyBytecodeSubsections)
+        );
+    }
+
+    #[test]
+    fn check__rejects_longer_proof_set_than_allowed() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let subsections =
+            UploadSubsection::split_bytecode(&bytecode(rng), 64).expect("Valid size");
+        let tx = signed_upload(rng, subsections[0].clone());
+        let mut params = ConsensusParameters::standard();
+        params.set_tx_params(TxParameters::DEFAULT.with_max_proof_set_length(3));
+
+        // When
+        let result = tx.check(Default::default(), &params);
+
+        // Then
+        assert_eq!(result, Err(ValidityError::TransactionUploadProofSetTooLong));
+    }
+
+    #[test]
+    fn upload__field_offsets_match_the_serialization() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let subsections =
+            UploadSubsection::split_bytecode(&bytecode(rng), 64).expect("Valid size");
+        let tx = signed_upload(rng, subsections[5].clone());
+
+        // When
+        let bytes = tx.to_bytes();
+
+        // Then
+        let root_offset = tx.bytecode_root_offset();
+        assert_eq!(&bytes[root_offset..][..Bytes32::LEN], tx.root.as_ref());
+        let index_offset = tx.subsection_index_offset();
+        assert_eq!(bytes[index_offset + WORD_SIZE - 1], 5);
+        for (idx, proof) in tx.proof_set.iter().enumerate() {
+            let offset = tx.proof_set_offset_at(idx).expect("The proof exists");
+            assert_eq!(&bytes[offset..][..Bytes32::LEN], proof.as_ref());
+        }
+        let input_offset = tx.inputs_offset_at(0).expect("The input exists");
+        assert_eq!(
+            Input::decode(&mut &bytes[input_offset..]),
+            Ok(tx.inputs[0].clone())
+        );
+        assert_eq!(
+            crate::Transaction::from_bytes(&bytes),
+            Ok(crate::Transaction::from(tx))
+        );
+    }
+}
@@ -5,6 +5,7 @@ use fuel_types::Bytes32;
 use core::{
     fmt,
     str,
+    str::FromStr,
 };
 
 #[cfg(feature = "random")]
@@ -19,7 +20,6 @@ use rand::{
 /// Identification of unspend transaction output.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub struct UtxoId {
     /// transaction id
@@ -80,6 +80,24 @@ impl fmt::UpperHex for UtxoId {
     }
 }
 
+/// The error returned by the parsing of an [`UtxoId`] from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum UtxoIdParseError {
+    /// The transaction id is not a valid hex.
+    #[display(fmt = "The transaction id is not a valid hex")]
+    InvalidHex,
+    /// The transaction id doesn't have `2 * TxId::LEN` hex characters.
+    #[display(fmt = "The transaction id has {} hex characters instead of 64", length)]
+    InvalidLength {
+        /// The number of characters of the transaction id.
+        length: usize,
+    },
+    /// The output index is not a `u8`.
+    #[display(fmt = "The output index is not a valid u8")]
+    InvalidOutputIndex,
+}
+
 impl core::fmt::Display for UtxoId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if f.alternate() {
@@ -91,34 +109,149 @@ impl core::fmt::Display for UtxoId {
 }
 
 impl str::FromStr for UtxoId {
-    type Err = &'static str;
+    type Err = UtxoIdParseError;
 
-    /// UtxoId is encoded as hex string with optional 0x prefix, where
-    /// the last two characters are the output index and the part
-    /// optionally preceeding it is the transaction id.
+    /// UtxoId is encoded as the hex transaction id with optional 0x prefix and the
+    /// decimal output index, separated by `:`.
+    ///
+    /// The legacy encoding without the separator, where the last two hex characters
+    /// are the output index and the part optionally preceeding it is the
+    /// transaction id, is also accepted.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const ERR: &str = "Invalid encoded byte";
-        let s = s.trim_start_matches("0x");
+        let s = s.strip_prefix("0x").unwrap_or(s);
+
+        let Some((tx_id, output_index)) = s.split_once(':') else {
+            return Self::from_legacy_str(s)
+        };
+
+        if tx_id.len() != 2 * TxId::LEN {
+            return Err(UtxoIdParseError::InvalidLength {
+                length: tx_id.len(),
+            })
+        }
+
+        let tx_id = Bytes32::from_str(tx_id).map_err(|_| UtxoIdParseError::InvalidHex)?;
+        if output_index.is_empty() || !output_index.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(UtxoIdParseError::InvalidOutputIndex)
+        }
+        let output_index = output_index
+            .parse()
+            .map_err(|_| UtxoIdParseError::InvalidOutputIndex)?;
+
+        Ok(UtxoId::new(tx_id, output_index))
+    }
+}
+
+impl UtxoId {
+    fn from_legacy_str(s: &str) -> Result<Self, UtxoIdParseError> {
+        let parse_output_index = |output_index: &str| {
+            if !output_index.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(UtxoIdParseError::InvalidOutputIndex)
+            }
+            u8::from_str_radix(output_index, 16)
+                .map_err(|_| UtxoIdParseError::InvalidOutputIndex)
+        };
 
         Ok(if s.is_empty() {
             UtxoId::new(Bytes32::default(), 0)
         } else if s.len() <= 2 {
-            UtxoId::new(TxId::default(), u8::from_str_radix(s, 16).map_err(|_| ERR)?)
+            UtxoId::new(TxId::default(), parse_output_index(s)?)
         } else {
             let i = s.len() - 2;
             if !s.is_char_boundary(i) {
-                return Err(ERR)
+                return Err(UtxoIdParseError::InvalidHex)
             }
             let (tx_id, output_index) = s.split_at(i);
 
             UtxoId::new(
-                Bytes32::from_str(tx_id)?,
-                u8::from_str_radix(output_index, 16).map_err(|_| ERR)?,
+                Bytes32::from_str(tx_id).map_err(|_| UtxoIdParseError::InvalidHex)?,
+                parse_output_index(output_index)?,
             )
         })
     }
 }
 
+/// The layout of the `UtxoId` in the serde formats that are not human-readable, also
+/// accepted from the human-readable ones written before the string form.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "UtxoId")]
+struct UtxoIdRepr {
+    tx_id: TxId,
+    output_index: u8,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for UtxoId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer
+                .collect_str(&format_args!("{:#x}:{}", self.tx_id, self.output_index))
+        } else {
+            UtxoIdRepr {
+                tx_id: self.tx_id,
+                output_index: self.output_index,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UtxoId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{
+            value::MapAccessDeserializer,
+            Error,
+            MapAccess,
+            Visitor,
+        };
+
+        struct HumanReadableVisitor;
+
+        impl<'de> Visitor<'de> for HumanReadableVisitor {
+            type Value = UtxoIdRepr;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an encoded `UtxoId` or its fields")
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                let utxo_id: UtxoId = value.parse().map_err(E::custom)?;
+                Ok(UtxoIdRepr {
+                    tx_id: utxo_id.tx_id,
+                    output_index: utxo_id.output_index,
+                })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<Self::Value, A::Error> {
+                <UtxoIdRepr as serde::Deserialize>::deserialize(
+                    MapAccessDeserializer::new(map),
+                )
+            }
+        }
+
+        let UtxoIdRepr {
+            tx_id,
+            output_index,
+        } = if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HumanReadableVisitor)?
+        } else {
+            UtxoIdRepr::deserialize(deserializer)?
+        };
+        Ok(UtxoId::new(tx_id, output_index))
+    }
+}
+
 #[cfg(feature = "typescript")]
 pub mod typescript {
     use super::*;
@@ -136,7 +269,7 @@ pub mod typescript {
         #[wasm_bindgen(constructor)]
         pub fn typescript_new(value: &str) -> Result<UtxoId, js_sys::Error> {
             use core::str::FromStr;
-            UtxoId::from_str(value).map_err(js_sys::Error::new)
+            UtxoId::from_str(value).map_err(|e| js_sys::Error::new(&format!("{}", e)))
         }
 
         #[wasm_bindgen(js_name = toString)]
@@ -187,7 +320,7 @@ mod tests {
     }
 
     #[test]
-    fn from_str_utxo_id() -> Result<(), &'static str> {
+    fn from_str_utxo_id() -> Result<(), UtxoIdParseError> {
         let utxo_id = UtxoId::from_str(
             "0x0c0000000000000000000000000000000000000000000000000000000000000b1a",
         )?;
@@ -204,4 +337,62 @@ mod tests {
         UtxoId::from_str("0x00😎").expect_err("Should fail on incorrect input");
         UtxoId::from_str("0x000😎").expect_err("Should fail on incorrect input");
     }
+
+    #[test]
+    fn display_utxo_id() {
+        let utxo_id = UtxoId::new([0x0c; 32].into(), 26);
+
+        assert_eq!(format!("{utxo_id:#}"), format!("0x{}1a", "0c".repeat(32)));
+        assert_eq!(format!("{utxo_id}"), format!("{}1a", "0c".repeat(32)));
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn display_from_str_roundtrip(tx_id: Vec<u8>, output_index: u8) -> bool {
+        let mut bytes = [0u8; 32];
+        bytes.iter_mut().zip(tx_id).for_each(|(b, t)| *b = t);
+        let utxo_id = UtxoId::new(bytes.into(), output_index);
+
+        UtxoId::from_str(&format!("{utxo_id}")) == Ok(utxo_id)
+            && UtxoId::from_str(&format!("{utxo_id:#}")) == Ok(utxo_id)
+    }
+
+    #[rstest::rstest]
+    #[case(&format!("0x{}:2", "0g".repeat(32)), UtxoIdParseError::InvalidHex)]
+    #[case(&format!("{}😎:2", "00".repeat(30)), UtxoIdParseError::InvalidHex)]
+    #[case(&format!("0x{}:2", "00".repeat(31)), UtxoIdParseError::InvalidLength { length: 62 })]
+    #[case(&format!("{}:2", "00".repeat(33)), UtxoIdParseError::InvalidLength { length: 66 })]
+    #[case(":2", UtxoIdParseError::InvalidLength { length: 0 })]
+    #[case(&format!("{}:", "00".repeat(32)), UtxoIdParseError::InvalidOutputIndex)]
+    #[case(&format!("{}:256", "00".repeat(32)), UtxoIdParseError::InvalidOutputIndex)]
+    #[case(&format!("{}:0x1", "00".repeat(32)), UtxoIdParseError::InvalidOutputIndex)]
+    #[case(&format!("{}:1:2", "00".repeat(32)), UtxoIdParseError::InvalidOutputIndex)]
+    #[case("0x0g", UtxoIdParseError::InvalidOutputIndex)]
+    #[case("+1", UtxoIdParseError::InvalidOutputIndex)]
+    #[case(&format!("{}+1", "00".repeat(32)), UtxoIdParseError::InvalidOutputIndex)]
+    #[case(&format!("{}:+1", "00".repeat(32)), UtxoIdParseError::InvalidOutputIndex)]
+    #[case(&format!("{}:-1", "00".repeat(32)), UtxoIdParseError::InvalidOutputIndex)]
+    fn from_str_malformed_utxo_id(#[case] s: &str, #[case] expected: UtxoIdParseError) {
+        assert_eq!(UtxoId::from_str(s), Err(expected));
+    }
+
+    #[test]
+    fn serde_utxo_id_is_a_string_in_human_readable_formats() {
+        let utxo_id = UtxoId::new([0x0c; 32].into(), 26);
+
+        let json = serde_json::to_string(&utxo_id).expect("Serializable");
+        assert_eq!(json, format!("\"0x{}:26\"", "0c".repeat(32)));
+        assert_eq!(serde_json::from_str::<UtxoId>(&json).ok(), Some(utxo_id));
+
+        let bytes = bincode::serialize(&utxo_id).expect("Serializable");
+        assert_eq!(bytes.len(), TxId::LEN + 1);
+        assert_eq!(bincode::deserialize::<UtxoId>(&bytes).ok(), Some(utxo_id));
+    }
+
+    #[test]
+    fn serde_utxo_id_accepts_the_legacy_struct_in_human_readable_formats() {
+        let utxo_id = UtxoId::new([0x0c; 32].into(), 26);
+        let json = format!(r#"{{"tx_id":"{}","output_index":26}}"#, "0c".repeat(32));
+
+        assert_eq!(serde_json::from_str::<UtxoId>(&json).ok(), Some(utxo_id));
+    }
 }
@@ -10,8 +10,13 @@ use crate::{
 };
 use fuel_crypto::{
     Message,
+    SecretKey,
     Signature,
 };
+use fuel_types::{
+    Address,
+    Bytes32,
+};
 
 #[cfg(feature = "random")]
 use rand::{
@@ -25,7 +30,6 @@ use rand::{
 #[derive(Derivative, Default, Clone, PartialEq, Eq, Hash)]
 #[derivative(Debug)]
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(fuel_types::canonical::Deserialize, fuel_types::canonical::Serialize)]
 pub struct Witness {
     #[derivative(Debug(format_with = "fmt_truncated_hex::<16>"))]
@@ -45,25 +49,72 @@ impl Witness {
         self.data
     }
 
+    /// Creates the signature witness of the `message` by the `secret` key.
+    pub fn sign(secret: &SecretKey, message: &Bytes32) -> Self {
+        let signature = Signature::sign(secret, Message::from_bytes_ref(message));
+
+        signature.as_ref().into()
+    }
+
+    /// Returns the signature stored in the witness, or `None` if the witness is not
+    /// exactly [`Signature::LEN`] bytes.
+    pub fn as_signature(&self) -> Option<Signature> {
+        <[u8; Signature::LEN]>::try_from(self.as_ref())
+            .ok()
+            .map(Signature::from_bytes)
+    }
+
+    /// Recovers the owner of the key that signed the `message`.
+    pub fn recover_owner(&self, message: &Bytes32) -> Result<Address, SignatureError> {
+        self.as_signature()
+            .ok_or(SignatureError::Malformed)?
+            .recover(Message::from_bytes_ref(message))
+            .map(|pk| Input::owner(&pk))
+            .map_err(|_| SignatureError::Malformed)
+    }
+
+    /// Verifies that the witness is the signature of the `message` by the `owner`.
+    pub fn verify_signature(
+        &self,
+        message: &Bytes32,
+        owner: &Address,
+    ) -> Result<(), SignatureError> {
+        let recovered = self.recover_owner(message)?;
+
+        if owner != &recovered {
+            return Err(SignatureError::OwnerMismatch { recovered })
+        }
+
+        Ok(())
+    }
+
     /// ECRecover an address from a witness
     pub fn recover_witness(
         &self,
         txhash: &TxId,
         input_index: usize,
     ) -> Result<fuel_types::Address, ValidityError> {
-        let bytes = <[u8; Signature::LEN]>::try_from(self.as_ref())
-            .map_err(|_| ValidityError::InputInvalidSignature { index: input_index })?;
-        let signature = Signature::from_bytes(bytes);
-
-        let message = Message::from_bytes_ref(txhash);
-
-        signature
-            .recover(message)
+        self.recover_owner(txhash)
             .map_err(|_| ValidityError::InputInvalidSignature { index: input_index })
-            .map(|pk| Input::owner(&pk))
     }
 }
 
+/// The error returned by the verification of a signature [`Witness`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum SignatureError {
+    /// The witness is not a 64-byte signature from which a public key can be
+    /// recovered.
+    #[display(fmt = "The witness is not a valid signature")]
+    Malformed,
+    /// The signature is made by another key than the one of the owner.
+    #[display(fmt = "The witness is signed by {}", recovered)]
+    OwnerMismatch {
+        /// The owner of the key recovered from the signature.
+        recovered: Address,
+    },
+}
+
 impl From<Vec<u8>> for Witness {
     fn from(data: Vec<u8>) -> Self {
         Self { data }
@@ -94,6 +145,59 @@ impl Extend<u8> for Witness {
     }
 }
 
+/// The witness is the hex string of its data in the human-readable formats, and the
+/// struct with the `data` field otherwise.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Witness {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        if serializer.is_human_readable() {
+            crate::transaction::hex_bytes::serialize(&self.data, serializer)
+        } else {
+            let mut witness = serializer.serialize_struct("Witness", 1)?;
+            witness.serialize_field("data", &self.data)?;
+            witness.end()
+        }
+    }
+}
+
+/// Also accepts the struct with the `data` field in the human-readable formats.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Witness {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Witness")]
+        struct WitnessRepr {
+            #[serde(with = "crate::transaction::hex_bytes")]
+            data: Vec<u8>,
+        }
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum HumanReadable {
+            Data(#[serde(with = "crate::transaction::hex_bytes")] Vec<u8>),
+            Struct(WitnessRepr),
+        }
+
+        let data = if deserializer.is_human_readable() {
+            match HumanReadable::deserialize(deserializer)? {
+                HumanReadable::Data(data)
+                | HumanReadable::Struct(WitnessRepr { data }) => data,
+            }
+        } else {
+            WitnessRepr::deserialize(deserializer)?.data
+        };
+
+        Ok(data.into())
+    }
+}
+
 #[cfg(feature = "random")]
 impl Distribution<Witness> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Witness {
@@ -146,3 +250,61 @@ pub mod typescript {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    #[test]
+    fn sign_and_verify_signature_roundtrip() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let secret = SecretKey::random(rng);
+        let owner = Input::owner(&secret.public_key());
+        let message: Bytes32 = rng.gen();
+
+        let witness = Witness::sign(&secret, &message);
+
+        assert_eq!(witness.as_vec().len(), Signature::LEN);
+        assert_eq!(witness.recover_owner(&message), Ok(owner));
+        assert_eq!(witness.verify_signature(&message, &owner), Ok(()));
+    }
+
+    #[test]
+    fn verify_signature_fails_for_wrong_owner() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let secret = SecretKey::random(rng);
+        let signer = Input::owner(&secret.public_key());
+        let message: Bytes32 = rng.gen();
+        let witness = Witness::sign(&secret, &message);
+
+        let result = witness.verify_signature(&message, &rng.gen());
+
+        assert_eq!(
+            result,
+            Err(SignatureError::OwnerMismatch { recovered: signer })
+        );
+    }
+
+    #[test]
+    fn as_signature_requires_exactly_64_bytes() {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let message: Bytes32 = rng.gen();
+        let signature = Witness::sign(&SecretKey::random(rng), &message);
+        let short: Witness = signature.as_vec()[..Signature::LEN - 1].into();
+        let mut long = signature.clone();
+        long.extend([0]);
+
+        assert!(signature.as_signature().is_some());
+        assert_eq!(short.as_signature(), None);
+        assert_eq!(long.as_signature(), None);
+        assert_eq!(
+            short.verify_signature(&message, &rng.gen()),
+            Err(SignatureError::Malformed)
+        );
+    }
+}
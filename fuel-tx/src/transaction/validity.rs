@@ -26,27 +26,33 @@ use crate::{
     ConsensusParameters,
     Input,
     Output,
+    SignatureError,
     Transaction,
     Witness,
 };
 use core::hash::Hash;
 use fuel_types::{
     canonical,
-    canonical::Serialize,
     Address,
     BlockHeight,
     Bytes32,
     ChainId,
 };
-use hashbrown::HashMap;
-use itertools::Itertools;
+use hashbrown::{
+    HashMap,
+    HashSet,
+};
 
 mod error;
 
 #[cfg(test)]
 mod tests;
 
-pub use error::ValidityError;
+pub use error::{
+    BudgetKind,
+    InputSignatureError,
+    ValidityError,
+};
 
 impl Input {
     pub fn check(
@@ -89,9 +95,12 @@ impl Input {
             }) => {
                 // Helper function for recovering the address from a witness
                 let recover_address = || -> Result<Address, ValidityError> {
-                    let witness = witnesses
-                        .get(*witness_index as usize)
-                        .ok_or(ValidityError::InputWitnessIndexBounds { index })?;
+                    let witness = witnesses.get(*witness_index as usize).ok_or(
+                        ValidityError::InputWitnessIndexBounds {
+                            index,
+                            witness_index: *witness_index,
+                        },
+                    )?;
 
                     witness.recover_witness(txhash, index)
                 };
@@ -139,6 +148,67 @@ impl Input {
         }
     }
 
+    /// Verifies the signature of the signed `input` at `index`, recovered from its
+    /// witness and the `signing_hash` of the transaction, see
+    /// [`Signable::signing_hash`](crate::Signable::signing_hash).
+    ///
+    /// The other inputs have no signature and are checked the same way as by
+    /// [`Self::check_signature`].
+    pub fn verify_coin_signature(
+        input: &Input,
+        index: usize,
+        signing_hash: &Bytes32,
+        witnesses: &[Witness],
+    ) -> Result<(), ValidityError> {
+        input.check_signature(index, signing_hash, witnesses, &mut None)
+    }
+
+    /// Verifies that the witness of the signed input is the signature of the `tx_id`
+    /// by the owner of the input. A signature made by another key is reported along
+    /// with the owner recovered from it.
+    ///
+    /// The other inputs have no signature, so they are always valid.
+    pub fn verify_signature(
+        &self,
+        tx_id: &Bytes32,
+        witnesses: &[Witness],
+    ) -> Result<(), InputSignatureError> {
+        let (owner, witness_index) = match self {
+            Self::CoinSigned(CoinSigned {
+                owner,
+                witness_index,
+                ..
+            })
+            | Self::MessageCoinSigned(MessageCoinSigned {
+                recipient: owner,
+                witness_index,
+                ..
+            })
+            | Self::MessageDataSigned(MessageDataSigned {
+                recipient: owner,
+                witness_index,
+                ..
+            }) => (owner, *witness_index),
+            _ => return Ok(()),
+        };
+
+        witnesses
+            .get(witness_index as usize)
+            .ok_or(InputSignatureError::MissingWitness { witness_index })?
+            .verify_signature(tx_id, owner)
+            .map_err(|err| match err {
+                SignatureError::Malformed => {
+                    InputSignatureError::MalformedSignature { witness_index }
+                }
+                SignatureError::OwnerMismatch { recovered } => {
+                    InputSignatureError::OwnerMismatch {
+                        owner: *owner,
+                        recovered,
+                    }
+                }
+            })
+    }
+
     pub fn check_without_signature(
         &self,
         index: usize,
@@ -160,7 +230,12 @@ impl Input {
             | Self::MessageDataPredicate(MessageDataPredicate { predicate, .. })
                 if predicate.len() as u64 > predicate_params.max_predicate_length =>
             {
-                Err(ValidityError::InputPredicateLength { index })
+                Err(ValidityError::BudgetExceeded {
+                    budget: BudgetKind::PredicateLength,
+                    used: predicate.len() as u64,
+                    max: predicate_params.max_predicate_length,
+                    index: Some(index),
+                })
             }
 
             Self::CoinPredicate(CoinPredicate { predicate_data, .. })
@@ -172,7 +247,12 @@ impl Input {
             }) if predicate_data.len() as u64
                 > predicate_params.max_predicate_data_length =>
             {
-                Err(ValidityError::InputPredicateDataLength { index })
+                Err(ValidityError::BudgetExceeded {
+                    budget: BudgetKind::PredicateDataLength,
+                    used: predicate_data.len() as u64,
+                    max: predicate_params.max_predicate_data_length,
+                    index: Some(index),
+                })
             }
 
             Self::CoinSigned(CoinSigned { witness_index, .. })
@@ -180,7 +260,10 @@ impl Input {
             | Self::MessageDataSigned(MessageDataSigned { witness_index, .. })
                 if *witness_index as usize >= witnesses.len() =>
             {
-                Err(ValidityError::InputWitnessIndexBounds { index })
+                Err(ValidityError::InputWitnessIndexBounds {
+                    index,
+                    witness_index: *witness_index,
+                })
             }
 
             // ∀ inputContract ∃! outputContract : outputContract.inputIndex =
@@ -202,10 +285,35 @@ impl Input {
 
             Self::MessageDataSigned(MessageDataSigned { data, .. })
             | Self::MessageDataPredicate(MessageDataPredicate { data, .. })
-                if data.is_empty()
-                    || data.len() as u64 > predicate_params.max_message_data_length =>
+                if data.is_empty() =>
+            {
+                Err(ValidityError::InputMessageDataEmpty { index })
+            }
+
+            Self::MessageDataSigned(MessageDataSigned { data, .. })
+            | Self::MessageDataPredicate(MessageDataPredicate { data, .. })
+                if data.len() as u64 > predicate_params.max_message_data_length =>
             {
-                Err(ValidityError::InputMessageDataLength { index })
+                Err(ValidityError::BudgetExceeded {
+                    budget: BudgetKind::MessageDataLength,
+                    used: data.len() as u64,
+                    max: predicate_params.max_message_data_length,
+                    index: Some(index),
+                })
+            }
+
+            Self::CoinPredicate(CoinPredicate {
+                predicate_gas_used, ..
+            })
+            | Self::MessageCoinPredicate(MessageCoinPredicate {
+                predicate_gas_used,
+                ..
+            })
+            | Self::MessageDataPredicate(MessageDataPredicate {
+                predicate_gas_used,
+                ..
+            }) if *predicate_gas_used > predicate_params.max_gas_per_predicate => {
+                Err(ValidityError::InputPredicateGasExceeded { index })
             }
 
             // TODO: If h is the block height the UTXO being spent was created,
@@ -272,6 +380,9 @@ impl FormatValidityChecks for Transaction {
             Transaction::Script(script) => script.check_signatures(chain_id),
             Transaction::Create(create) => create.check_signatures(chain_id),
             Transaction::Mint(mint) => mint.check_signatures(chain_id),
+            Transaction::Upgrade(upgrade) => upgrade.check_signatures(chain_id),
+            Transaction::Upload(upload) => upload.check_signatures(chain_id),
+            Transaction::Blob(blob) => blob.check_signatures(chain_id),
         }
     }
 
@@ -290,6 +401,15 @@ impl FormatValidityChecks for Transaction {
             Transaction::Mint(mint) => {
                 mint.check_without_signatures(block_height, consensus_params)
             }
+            Transaction::Upgrade(upgrade) => {
+                upgrade.check_without_signatures(block_height, consensus_params)
+            }
+            Transaction::Upload(upload) => {
+                upload.check_without_signatures(block_height, consensus_params)
+            }
+            Transaction::Blob(blob) => {
+                blob.check_without_signatures(block_height, consensus_params)
+            }
         }
     }
 }
@@ -302,13 +422,30 @@ pub(crate) fn check_size<T>(tx: &T, tx_params: &TxParameters) -> Result<(), Vali
 where
     T: canonical::Serialize,
 {
-    if tx.size() as u64 > tx_params.max_size {
-        Err(ValidityError::TransactionSizeLimitExceeded)?;
+    let size = tx.size() as u64;
+    if size > tx_params.max_size {
+        Err(ValidityError::BudgetExceeded {
+            budget: BudgetKind::TransactionSize,
+            used: size,
+            max: tx_params.max_size,
+            index: None,
+        })?;
     }
 
     Ok(())
 }
 
+/// The number of signatures recovered by `check_signatures`, one per distinct witness
+/// used by the signed inputs.
+pub(crate) fn signature_checks(inputs: &[Input]) -> usize {
+    inputs
+        .iter()
+        .filter(|input| input.is_signed())
+        .filter_map(Input::witness_index)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
 pub(crate) fn check_common_part<T>(
     tx: &T,
     block_height: BlockHeight,
@@ -317,14 +454,11 @@ pub(crate) fn check_common_part<T>(
 where
     T: canonical::Serialize + Chargeable + field::Outputs,
 {
-    let ConsensusParameters {
-        tx_params,
-        predicate_params,
-        base_asset_id,
-        gas_costs,
-        fee_params,
-        ..
-    } = consensus_params;
+    let tx_params = consensus_params.tx_params();
+    let predicate_params = consensus_params.predicate_params();
+    let base_asset_id = consensus_params.base_asset_id();
+    let gas_costs = consensus_params.gas_costs();
+    let fee_params = consensus_params.fee_params();
 
     check_size(tx, tx_params)?;
 
@@ -333,9 +467,14 @@ where
     }
 
     if let Some(witness_limit) = tx.policies().get(PolicyType::WitnessLimit) {
-        let witness_size = tx.witnesses().size_dynamic();
+        let witness_size = tx.witnesses_size();
         if witness_size as u64 > witness_limit {
-            Err(ValidityError::TransactionWitnessLimitExceeded)?
+            Err(ValidityError::BudgetExceeded {
+                budget: BudgetKind::WitnessLimit,
+                used: witness_size as u64,
+                max: witness_limit,
+                index: None,
+            })?
         }
     }
 
@@ -364,6 +503,16 @@ where
         Err(ValidityError::TransactionWitnessesMax)?
     }
 
+    // Signatures are recovered once per witness, so the number of checks performed by
+    // `check_signatures` is known upfront.
+    let signature_checks = signature_checks(tx.inputs());
+    if signature_checks > tx_params.max_signature_checks as usize {
+        Err(ValidityError::TooManySignatureChecks {
+            count: signature_checks,
+            max: tx_params.max_signature_checks,
+        })?
+    }
+
     let any_spendable_input = tx.inputs().iter().find(|input| match input {
         Input::CoinSigned(_)
         | Input::CoinPredicate(_)
@@ -381,52 +530,54 @@ where
     tx.input_asset_ids_unique(base_asset_id)
         .try_for_each(|input_asset_id| {
             // check for duplicate change outputs
-            if tx
+            let duplicate = tx
                 .outputs()
                 .iter()
-                .filter_map(|output| match output {
-                    Output::Change { asset_id, .. } if input_asset_id == asset_id => {
-                        Some(())
-                    }
-                    Output::Change { asset_id, .. }
-                        if asset_id != base_asset_id && input_asset_id == asset_id =>
-                    {
-                        Some(())
-                    }
-                    _ => None,
+                .enumerate()
+                .filter(|(_, output)| {
+                    matches!(output, Output::Change { asset_id, .. } if input_asset_id == asset_id)
                 })
-                .count()
-                > 1
-            {
-                return Err(ValidityError::TransactionOutputChangeAssetIdDuplicated(
-                    *input_asset_id,
-                ));
+                .nth(1);
+
+            if let Some((index, _)) = duplicate {
+                return Err(ValidityError::TransactionOutputChangeAssetIdDuplicated {
+                    index,
+                    asset_id: *input_asset_id,
+                });
             }
 
             Ok(())
         })?;
 
     // Check for duplicated input utxo id
-    let duplicated_utxo_id = tx
-        .inputs()
-        .iter()
-        .filter_map(|i| i.is_coin().then(|| i.utxo_id()).flatten());
+    let utxo_ids = tx.inputs().iter().enumerate().filter_map(|(index, i)| {
+        i.is_coin().then(|| Some((index, *i.utxo_id()?))).flatten()
+    });
 
-    if let Some(utxo_id) = next_duplicate(duplicated_utxo_id).copied() {
-        return Err(ValidityError::DuplicateInputUtxoId { utxo_id });
+    if let Some((index, utxo_id)) = next_duplicate(utxo_ids) {
+        return Err(ValidityError::DuplicateInputUtxoId { index, utxo_id });
     }
 
     // Check for duplicated input contract id
-    let duplicated_contract_id = tx.inputs().iter().filter_map(Input::contract_id);
+    let contract_ids = tx
+        .inputs()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, i)| Some((index, *i.contract_id()?)));
 
-    if let Some(contract_id) = next_duplicate(duplicated_contract_id).copied() {
-        return Err(ValidityError::DuplicateInputContractId { contract_id });
+    if let Some((index, contract_id)) = next_duplicate(contract_ids) {
+        return Err(ValidityError::DuplicateInputContractId { index, contract_id });
     }
 
     // Check for duplicated input message id
-    let duplicated_message_id = tx.inputs().iter().filter_map(Input::message_id);
-    if let Some(message_id) = next_duplicate(duplicated_message_id) {
-        return Err(ValidityError::DuplicateMessageInputId { message_id });
+    let message_ids = tx
+        .inputs()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, i)| Some((index, i.message_id()?)));
+
+    if let Some((index, message_id)) = next_duplicate(message_ids) {
+        return Err(ValidityError::DuplicateMessageInputId { index, message_id });
     }
 
     // Validate the inputs without checking signature
@@ -453,9 +604,10 @@ where
                     .input_asset_ids(base_asset_id)
                     .any(|input_asset_id| input_asset_id == asset_id)
                 {
-                    return Err(ValidityError::TransactionOutputChangeAssetIdNotFound(
-                        *asset_id,
-                    ));
+                    return Err(ValidityError::TransactionOutputChangeAssetIdNotFound {
+                        index,
+                        asset_id: *asset_id,
+                    });
                 }
             }
 
@@ -464,9 +616,10 @@ where
                     .input_asset_ids(base_asset_id)
                     .any(|input_asset_id| input_asset_id == asset_id)
                 {
-                    return Err(ValidityError::TransactionOutputCoinAssetIdNotFound(
-                        *asset_id,
-                    ));
+                    return Err(ValidityError::TransactionOutputCoinAssetIdNotFound {
+                        index,
+                        asset_id: *asset_id,
+                    });
                 }
             }
 
@@ -477,23 +630,16 @@ where
 }
 
 // TODO https://github.com/FuelLabs/fuel-tx/issues/148
-pub(crate) fn next_duplicate<U>(iter: impl Iterator<Item = U>) -> Option<U>
+/// Returns the first item whose value was already yielded by a previous item, along
+/// with the index of that second occurrence.
+pub(crate) fn next_duplicate<U>(
+    mut iter: impl Iterator<Item = (usize, U)>,
+) -> Option<(usize, U)>
 where
-    U: PartialEq + Ord + Copy + Hash,
+    U: Eq + Hash + Copy,
 {
-    #[cfg(not(feature = "std"))]
-    {
-        iter.sorted()
-            .as_slice()
-            .windows(2)
-            .filter_map(|u| (u[0] == u[1]).then(|| u[0]))
-            .next()
-    }
-
-    #[cfg(feature = "std")]
-    {
-        iter.duplicates().next()
-    }
+    let mut seen = HashSet::new();
+    iter.find_map(|(index, u)| (!seen.insert(u)).then_some((index, u)))
 }
 
 #[cfg(feature = "typescript")]
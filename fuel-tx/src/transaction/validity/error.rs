@@ -1,6 +1,8 @@
 use crate::UtxoId;
 use fuel_types::{
+    Address,
     AssetId,
+    Bytes32,
     ContractId,
     MessageId,
 };
@@ -12,70 +14,147 @@ use fuel_types::{
 pub enum ValidityError {
     /// Transaction doesn't have spendable input message or coin.
     NoSpendableInput,
+    #[display(
+        fmt = "Input {} refers to the missing witness {}",
+        index,
+        witness_index
+    )]
     InputWitnessIndexBounds {
         index: usize,
+        /// The witness index of the input.
+        witness_index: u8,
     },
+    #[display(fmt = "Input {} has an empty predicate", index)]
     InputPredicateEmpty {
         index: usize,
     },
-    InputPredicateLength {
-        index: usize,
-    },
-    InputPredicateDataLength {
+    #[display(fmt = "The owner of input {} doesn't match its predicate", index)]
+    InputPredicateOwner {
         index: usize,
     },
-    InputPredicateOwner {
+    /// The `predicate_gas_used` of the predicate input exceeds
+    /// `PredicateParameters::max_gas_per_predicate`.
+    #[display(fmt = "The predicate gas used by input {} exceeds the maximum", index)]
+    InputPredicateGasExceeded {
         index: usize,
     },
+    #[display(fmt = "Input {} has an invalid signature", index)]
     InputInvalidSignature {
         index: usize,
     },
+    #[display(fmt = "Input {} has no unique associated contract output", index)]
     InputContractAssociatedOutputContract {
         index: usize,
     },
-    InputMessageDataLength {
+    /// The data of the message input is empty.
+    #[display(fmt = "Input {} has empty message data", index)]
+    InputMessageDataEmpty {
         index: usize,
     },
+    /// The length of the predicate is not a multiple of the word size.
+    #[display(
+        fmt = "The predicate length {} is not a multiple of the word size",
+        length
+    )]
+    PredicateNotWordAligned {
+        length: usize,
+    },
+    /// The predicate of the input being constructed is empty, see
+    /// [`Self::InputPredicateEmpty`].
+    #[display(fmt = "The predicate is empty")]
+    PredicateEmpty,
+    /// The owner of the input being constructed doesn't match the root of its
+    /// predicate, see [`Self::InputPredicateOwner`].
+    #[display(fmt = "The owner doesn't match the predicate")]
+    PredicateOwnerMismatch,
+    /// The data of the message input being constructed is empty, see
+    /// [`Self::InputMessageDataEmpty`].
+    #[display(fmt = "The message data is empty")]
+    MessageDataEmpty,
+    /// The coin input at `index` spends a `utxo_id` already spent by a previous input.
+    #[display(fmt = "Input {} spends the duplicated utxo id {}", index, utxo_id)]
     DuplicateInputUtxoId {
+        index: usize,
         utxo_id: UtxoId,
     },
+    /// The message input at `index` spends a message already spent by a previous
+    /// input.
+    #[display(fmt = "Input {} spends the duplicated message {}", index, message_id)]
     DuplicateMessageInputId {
+        index: usize,
         message_id: MessageId,
     },
+    /// The contract input at `index` uses a `contract_id` already used by a previous
+    /// input.
+    #[display(
+        fmt = "Input {} uses the duplicated contract id {}",
+        index,
+        contract_id
+    )]
     DuplicateInputContractId {
+        index: usize,
         contract_id: ContractId,
     },
+    #[display(fmt = "Output {} refers to an invalid contract input", index)]
     OutputContractInputIndex {
         index: usize,
     },
+    #[display(fmt = "Input {} of the create transaction is a contract", index)]
     TransactionCreateInputContract {
         index: usize,
     },
     /// The `Create` transaction contains (retryable) message input.
+    #[display(
+        fmt = "Input {} of the create transaction is a message with data",
+        index
+    )]
     TransactionCreateMessageData {
         index: usize,
     },
+    #[display(fmt = "Output {} of the create transaction is a contract", index)]
     TransactionCreateOutputContract {
         index: usize,
     },
+    #[display(fmt = "Output {} of the create transaction is variable", index)]
     TransactionCreateOutputVariable {
         index: usize,
     },
+    #[display(
+        fmt = "Output {} of the create transaction is a change of a non-base asset",
+        index
+    )]
     TransactionCreateOutputChangeNotBaseAsset {
         index: usize,
     },
+    #[display(
+        fmt = "Output {} of the create transaction doesn't match the created contract",
+        index
+    )]
     TransactionCreateOutputContractCreatedDoesntMatch {
         index: usize,
     },
+    #[display(
+        fmt = "Output {} of the create transaction creates a second contract",
+        index
+    )]
     TransactionCreateOutputContractCreatedMultiple {
         index: usize,
     },
     TransactionCreateBytecodeLen,
     TransactionCreateBytecodeWitnessIndex,
     TransactionCreateStorageSlotMax,
-    TransactionCreateStorageSlotOrder,
-    TransactionScriptLength,
-    TransactionScriptDataLength,
+    /// The storage slot at `index` is not sorted by key after the previous one.
+    #[display(fmt = "Storage slot {} is not sorted by key", index)]
+    TransactionCreateStorageSlotOrder {
+        index: usize,
+    },
+    /// The storage slot at `index` shares the `key` with the previous one.
+    #[display(fmt = "Storage slot {} duplicates the key {}", index, key)]
+    TransactionCreateStorageSlotsDuplicated {
+        index: usize,
+        key: Bytes32,
+    },
+    #[display(fmt = "Output {} of the script transaction creates a contract", index)]
     TransactionScriptOutputContractCreated {
         index: usize,
     },
@@ -86,11 +165,117 @@ pub enum ValidityError {
     TransactionMintIncorrectOutputIndex,
     /// The `Output.mint_base_asset` is not base asset.
     TransactionMintNonBaseAsset,
-    /// The transaction exceeded the size limit.
-    TransactionSizeLimitExceeded,
+    /// The `Upgrade` transaction spends a non-base asset input.
+    #[display(
+        fmt = "Input {} of the upgrade transaction spends a non-base asset",
+        index
+    )]
+    TransactionUpgradeInputNotBaseAsset {
+        index: usize,
+    },
+    /// The `Upgrade` transaction contains (retryable) message input.
+    #[display(
+        fmt = "Input {} of the upgrade transaction is a message with data",
+        index
+    )]
+    TransactionUpgradeMessageData {
+        index: usize,
+    },
+    #[display(fmt = "Output {} of the upgrade transaction is variable", index)]
+    TransactionUpgradeOutputVariable {
+        index: usize,
+    },
+    #[display(fmt = "Output {} of the upgrade transaction creates a contract", index)]
+    TransactionUpgradeOutputContractCreated {
+        index: usize,
+    },
+    #[display(
+        fmt = "Output {} of the upgrade transaction is a change of a non-base asset",
+        index
+    )]
+    TransactionUpgradeOutputChangeNotBaseAsset {
+        index: usize,
+    },
+    /// No input of the `Upgrade` transaction is owned by the privileged address.
+    TransactionUpgradeNoPrivilegedAddress,
+    /// The witness with the serialized consensus parameters doesn't exist.
+    TransactionUpgradeConsensusParametersWitnessIndex,
+    /// The checksum of the `Upgrade` transaction doesn't match the hash of the
+    /// serialized consensus parameters.
+    TransactionUpgradeConsensusParametersChecksumMismatch,
+    /// The witness with the consensus parameters isn't their canonical serialization.
+    TransactionUpgradeConsensusParametersDeserialization,
+    /// The `Upload` transaction contains contract input.
+    #[display(fmt = "Input {} of the upload transaction is a contract", index)]
+    TransactionUploadInputContract {
+        index: usize,
+    },
+    /// The `Upload` transaction contains (retryable) message input.
+    #[display(
+        fmt = "Input {} of the upload transaction is a message with data",
+        index
+    )]
+    TransactionUploadMessageData {
+        index: usize,
+    },
+    #[display(fmt = "Output {} of the upload transaction is variable", index)]
+    TransactionUploadOutputVariable {
+        index: usize,
+    },
+    #[display(fmt = "Output {} of the upload transaction creates a contract", index)]
+    TransactionUploadOutputContractCreated {
+        index: usize,
+    },
+    #[display(
+        fmt = "Output {} of the upload transaction is a change of a non-base asset",
+        index
+    )]
+    TransactionUploadOutputChangeNotBaseAsset {
+        index: usize,
+    },
+    /// The number of subsections exceeds `TxParameters::max_bytecode_subsections`.
+    TransactionUploadTooManyBytecodeSubsections,
+    /// The proof set is longer than `TxParameters::max_proof_set_length`.
+    TransactionUploadProofSetTooLong,
+    /// The index of the subsection isn't less than the number of subsections.
+    TransactionUploadSubsectionIndexOutOfBounds,
+    /// The witness with the subsection doesn't exist.
+    TransactionUploadBytecodeWitnessIndex,
+    /// The subsection and its proof set don't match the bytecode root.
+    TransactionUploadRootVerificationFailed,
+    /// The `Blob` transaction contains contract input.
+    #[display(fmt = "Input {} of the blob transaction is a contract", index)]
+    TransactionBlobInputContract {
+        index: usize,
+    },
+    /// The `Blob` transaction contains (retryable) message input.
+    #[display(fmt = "Input {} of the blob transaction is a message with data", index)]
+    TransactionBlobMessageData {
+        index: usize,
+    },
+    #[display(fmt = "Output {} of the blob transaction is variable", index)]
+    TransactionBlobOutputVariable {
+        index: usize,
+    },
+    #[display(fmt = "Output {} of the blob transaction creates a contract", index)]
+    TransactionBlobOutputContractCreated {
+        index: usize,
+    },
+    #[display(
+        fmt = "Output {} of the blob transaction is a change of a non-base asset",
+        index
+    )]
+    TransactionBlobOutputChangeNotBaseAsset {
+        index: usize,
+    },
+    /// The witness with the blob payload doesn't exist.
+    TransactionBlobWitnessIndex,
+    /// The payload is larger than `TxParameters::max_blob_size`.
+    TransactionBlobTooLarge,
+    /// The hash of the payload doesn't match the blob id.
+    TransactionBlobIdVerificationFailed,
     /// Max gas per tx exceeded
     TransactionMaxGasExceeded,
-    TransactionWitnessLimitExceeded,
     TransactionPoliciesAreInvalid,
     TransactionNoGasPricePolicy,
     TransactionMaturity,
@@ -98,11 +283,38 @@ pub enum ValidityError {
     TransactionInputsMax,
     TransactionOutputsMax,
     TransactionWitnessesMax,
-    TransactionOutputChangeAssetIdDuplicated(AssetId),
-    TransactionOutputChangeAssetIdNotFound(AssetId),
+    /// The change output at `index` is for the same `asset_id` as a previous one.
+    #[display(
+        fmt = "Change output {} duplicates the change of asset {}",
+        index,
+        asset_id
+    )]
+    TransactionOutputChangeAssetIdDuplicated {
+        index: usize,
+        asset_id: AssetId,
+    },
+    /// The change output at `index` is for an `asset_id` that doesn't exist in the
+    /// inputs.
+    #[display(
+        fmt = "Change output {} is for the asset {} missing in the inputs",
+        index,
+        asset_id
+    )]
+    TransactionOutputChangeAssetIdNotFound {
+        index: usize,
+        asset_id: AssetId,
+    },
     /// This error happens when a transaction attempts to create a coin output for an
     /// asset type that doesn't exist in the coin inputs.
-    TransactionOutputCoinAssetIdNotFound(AssetId),
+    #[display(
+        fmt = "Coin output {} is for the asset {} missing in the inputs",
+        index,
+        asset_id
+    )]
+    TransactionOutputCoinAssetIdNotFound {
+        index: usize,
+        asset_id: AssetId,
+    },
     /// The transaction doesn't provide enough input amount of the native chain asset to
     /// cover all potential execution fees
     #[display(
@@ -132,8 +344,96 @@ pub enum ValidityError {
         /// The total amount provided by coin inputs
         provided: u64,
     },
+    /// The signed inputs require more signature recoveries than allowed.
+    #[display(
+        fmt = "Too many signature checks: {} exceeds the maximum of {}",
+        count,
+        max
+    )]
+    TooManySignatureChecks {
+        /// The number of signatures to recover.
+        count: usize,
+        /// The maximum allowed by the transaction parameters.
+        max: u16,
+    },
+    /// The transaction, or one of its inputs, uses more bytes than allowed.
+    #[display(fmt = "The {} budget is exceeded: used {}, max {}", budget, used, max)]
+    BudgetExceeded {
+        /// The exceeded byte budget.
+        budget: BudgetKind,
+        /// The number of bytes used.
+        used: u64,
+        /// The maximum number of bytes allowed.
+        max: u64,
+        /// The index of the input, for the budgets of the inputs.
+        index: Option<usize>,
+    },
     /// The given coins is too large
     BalanceOverflow,
     /// The given gas costs is are too large
     GasCostsCoinsOverflow,
 }
+
+/// The error returned by [`Input::verify_signature`](crate::Input::verify_signature).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum InputSignatureError {
+    /// The transaction has no witness at the witness index of the input.
+    #[display(fmt = "The witness {} is missing", witness_index)]
+    MissingWitness {
+        /// The witness index of the input.
+        witness_index: u8,
+    },
+    /// The witness is not a 64-byte signature from which a public key can be
+    /// recovered.
+    #[display(fmt = "The witness {} is not a valid signature", witness_index)]
+    MalformedSignature {
+        /// The witness index of the input.
+        witness_index: u8,
+    },
+    /// The signature is made by another key than the one of the owner of the input.
+    #[display(
+        fmt = "The input is owned by {}, but is signed by {}",
+        owner,
+        recovered
+    )]
+    OwnerMismatch {
+        /// The owner of the input.
+        owner: Address,
+        /// The owner of the key recovered from the signature.
+        recovered: Address,
+    },
+}
+
+/// The byte budget exceeded by a transaction, see [`ValidityError::BudgetExceeded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum BudgetKind {
+    /// The size of the witnesses, limited by the `WitnessLimit` policy.
+    #[display(fmt = "witness limit")]
+    WitnessLimit,
+    /// The size of the transaction, limited by `TxParameters::max_size`.
+    #[display(fmt = "transaction size")]
+    TransactionSize,
+    /// The length of a predicate, limited by
+    /// `PredicateParameters::max_predicate_length`.
+    #[display(fmt = "predicate length")]
+    PredicateLength,
+    /// The length of a predicate data, limited by
+    /// `PredicateParameters::max_predicate_data_length`.
+    #[display(fmt = "predicate data length")]
+    PredicateDataLength,
+    /// The length of a message data, limited by
+    /// `PredicateParameters::max_message_data_length`.
+    #[display(fmt = "message data length")]
+    MessageDataLength,
+    /// The length of the script, limited by `ScriptParameters::max_script_length`.
+    #[display(fmt = "script length")]
+    ScriptLength,
+    /// The length of the script data, limited by
+    /// `ScriptParameters::max_script_data_length`.
+    #[display(fmt = "script data length")]
+    ScriptDataLength,
+}
@@ -40,5 +40,13 @@ fn check_size_returns_transaction_size_limit_exceeded_for_invalid_size() {
 
     // Then
     let err = result.expect_err("Expected check_size to return err");
-    assert_eq!(err, ValidityError::TransactionSizeLimitExceeded);
+    assert_eq!(
+        err,
+        ValidityError::BudgetExceeded {
+            budget: BudgetKind::TransactionSize,
+            used: f.size() as u64,
+            max: 32,
+            index: None,
+        }
+    );
 }
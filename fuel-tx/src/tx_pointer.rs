@@ -25,7 +25,6 @@ use rand::{
 /// Identification of unspend transaction output.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "typescript", wasm_bindgen::prelude::wasm_bindgen)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Deserialize, Serialize)]
 pub struct TxPointer {
     /// Block height
@@ -60,6 +59,22 @@ impl Distribution<TxPointer> for Standard {
     }
 }
 
+/// The error returned by the parsing of a [`TxPointer`] from a string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, derive_more::Display)]
+#[non_exhaustive]
+pub enum TxPointerParseError {
+    /// The block height is not a valid hex.
+    #[display(fmt = "The block height is not a valid hex")]
+    InvalidHex,
+    /// The block height or the transaction index doesn't have 8 or 4 hex
+    /// characters.
+    #[display(fmt = "The tx pointer is not 8 and 4 hex characters")]
+    InvalidLength,
+    /// The transaction index is not a valid hex.
+    #[display(fmt = "The transaction index is not a valid hex")]
+    InvalidTxIndex,
+}
+
 impl fmt::Display for TxPointer {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::LowerHex::fmt(self, f)
@@ -79,27 +94,123 @@ impl fmt::UpperHex for TxPointer {
 }
 
 impl str::FromStr for TxPointer {
-    type Err = &'static str;
+    type Err = TxPointerParseError;
 
-    /// TxPointer is encoded as 12 hex characters:
-    /// - 8 characters for block height
-    /// - 4 characters for tx index
+    /// TxPointer is encoded as 8 hex characters for the block height and 4 hex
+    /// characters for the tx index, separated by `#`.
+    ///
+    /// The legacy encoding without the separator is also accepted.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const ERR: &str = "Invalid encoded byte";
-
-        if s.len() != 12 || !s.is_char_boundary(8) {
-            return Err(ERR)
+        let (block_height, tx_index) = match s.split_once('#') {
+            Some(parts) => parts,
+            None if s.len() == 12 && s.is_char_boundary(8) => s.split_at(8),
+            None => return Err(TxPointerParseError::InvalidLength),
+        };
+
+        if block_height.len() != 8 || tx_index.len() != 4 {
+            return Err(TxPointerParseError::InvalidLength)
         }
 
-        let (block_height, tx_index) = s.split_at(8);
+        // `from_str_radix` accepts a leading sign.
+        if !block_height.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(TxPointerParseError::InvalidHex)
+        }
+        if !tx_index.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(TxPointerParseError::InvalidTxIndex)
+        }
 
-        let block_height = u32::from_str_radix(block_height, 16).map_err(|_| ERR)?;
-        let tx_index = u16::from_str_radix(tx_index, 16).map_err(|_| ERR)?;
+        let block_height = u32::from_str_radix(block_height, 16)
+            .map_err(|_| TxPointerParseError::InvalidHex)?;
+        let tx_index = u16::from_str_radix(tx_index, 16)
+            .map_err(|_| TxPointerParseError::InvalidTxIndex)?;
 
         Ok(Self::new(block_height.into(), tx_index))
     }
 }
 
+/// The layout of the `TxPointer` in the serde formats that are not human-readable, also
+/// accepted from the human-readable ones written before the string form.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "TxPointer")]
+struct TxPointerRepr {
+    block_height: BlockHeight,
+    tx_index: u16,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TxPointer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!(
+                "{:08x}#{:04x}",
+                self.block_height, self.tx_index
+            ))
+        } else {
+            TxPointerRepr {
+                block_height: self.block_height,
+                tx_index: self.tx_index,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TxPointer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::{
+            value::MapAccessDeserializer,
+            Error,
+            MapAccess,
+            Visitor,
+        };
+
+        struct HumanReadableVisitor;
+
+        impl<'de> Visitor<'de> for HumanReadableVisitor {
+            type Value = TxPointerRepr;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an encoded `TxPointer` or its fields")
+            }
+
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                let tx_pointer: TxPointer = value.parse().map_err(E::custom)?;
+                Ok(TxPointerRepr {
+                    block_height: tx_pointer.block_height,
+                    tx_index: tx_pointer.tx_index,
+                })
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<Self::Value, A::Error> {
+                <TxPointerRepr as serde::Deserialize>::deserialize(
+                    MapAccessDeserializer::new(map),
+                )
+            }
+        }
+
+        let TxPointerRepr {
+            block_height,
+            tx_index,
+        } = if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HumanReadableVisitor)?
+        } else {
+            TxPointerRepr::deserialize(deserializer)?
+        };
+        Ok(TxPointer::new(block_height, tx_index))
+    }
+}
+
 #[cfg(feature = "typescript")]
 pub mod typescript {
     use super::*;
@@ -117,7 +228,7 @@ pub mod typescript {
         #[wasm_bindgen(constructor)]
         pub fn typescript_new(value: &str) -> Result<TxPointer, js_sys::Error> {
             use core::str::FromStr;
-            TxPointer::from_str(value).map_err(js_sys::Error::new)
+            TxPointer::from_str(value).map_err(|e| js_sys::Error::new(&format!("{}", e)))
         }
 
         #[wasm_bindgen(js_name = toString)]
@@ -174,3 +285,72 @@ fn decode_bug() {
     use core::str::FromStr;
     TxPointer::from_str("00000😎000").expect_err("Should fail on incorrect input");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    #[test]
+    fn display_from_str_roundtrip() {
+        let tx_pointer = TxPointer::new(0x1234.into(), 7);
+        let s = format!("{tx_pointer}");
+
+        assert_eq!(s, "000012340007");
+        assert_eq!(TxPointer::from_str(&s), Ok(tx_pointer));
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn display_from_str_roundtrip_any(block_height: u32, tx_index: u16) -> bool {
+        let tx_pointer = TxPointer::new(block_height.into(), tx_index);
+
+        TxPointer::from_str(&format!("{tx_pointer}")) == Ok(tx_pointer)
+    }
+
+    #[rstest::rstest]
+    #[case("0000123g#0007", TxPointerParseError::InvalidHex)]
+    #[case("-0001234#0007", TxPointerParseError::InvalidHex)]
+    #[case("+0001234#0007", TxPointerParseError::InvalidHex)]
+    #[case("+00012340007", TxPointerParseError::InvalidHex)]
+    #[case("00001234#000g", TxPointerParseError::InvalidTxIndex)]
+    #[case("00001234#+007", TxPointerParseError::InvalidTxIndex)]
+    #[case("00001234-007", TxPointerParseError::InvalidTxIndex)]
+    #[case("00001234#0007#", TxPointerParseError::InvalidLength)]
+    #[case("1234#7", TxPointerParseError::InvalidLength)]
+    #[case("000001234#0007", TxPointerParseError::InvalidLength)]
+    #[case("00001234#00007", TxPointerParseError::InvalidLength)]
+    #[case("00001234", TxPointerParseError::InvalidLength)]
+    #[case("", TxPointerParseError::InvalidLength)]
+    fn from_str_malformed(#[case] s: &str, #[case] expected: TxPointerParseError) {
+        assert_eq!(TxPointer::from_str(s), Err(expected));
+    }
+
+    #[test]
+    fn serde_is_a_string_in_human_readable_formats() {
+        let tx_pointer = TxPointer::new(0x1234.into(), 7);
+
+        let json = serde_json::to_string(&tx_pointer).expect("Serializable");
+        assert_eq!(json, "\"00001234#0007\"");
+        assert_eq!(
+            serde_json::from_str::<TxPointer>(&json).ok(),
+            Some(tx_pointer)
+        );
+
+        let bytes = bincode::serialize(&tx_pointer).expect("Serializable");
+        assert_eq!(
+            bincode::deserialize::<TxPointer>(&bytes).ok(),
+            Some(tx_pointer)
+        );
+    }
+
+    #[test]
+    fn serde_accepts_the_legacy_struct_in_human_readable_formats() {
+        let tx_pointer = TxPointer::new(0x1234.into(), 7);
+        let json = r#"{"block_height":4660,"tx_index":7}"#;
+
+        assert_eq!(
+            serde_json::from_str::<TxPointer>(json).ok(),
+            Some(tx_pointer)
+        );
+    }
+}
@@ -124,6 +124,9 @@ mod use_std {
                         Transaction::Script(_) => (),
                         Transaction::Create(_) => (),
                         Transaction::Mint(_) => (),
+                        Transaction::Upgrade(_) => (),
+                        Transaction::Upload(_) => (),
+                        Transaction::Blob(_) => (),
                     })
                     .unwrap_or(());
 
@@ -366,6 +366,7 @@ key!(Bytes32, 32);
 key!(Nonce, 32);
 key!(MessageId, 32);
 key!(Salt, 32);
+key!(BlobId, 32);
 
 key_with_big_array!(Bytes64, 64);
 
@@ -57,6 +57,7 @@ fn from_slice_unchecked_safety() {
         check_consistency!(Nonce, rng, bytes);
         check_consistency!(MessageId, rng, bytes);
         check_consistency!(Salt, rng, bytes);
+        check_consistency!(BlobId, rng, bytes);
         check_consistency!(Bytes64, rng, bytes);
     }
 }
@@ -129,6 +130,7 @@ fn hex_encoding() {
     encode_decode::<Nonce>(rng.gen());
     encode_decode::<MessageId>(rng.gen());
     encode_decode::<Salt>(rng.gen());
+    encode_decode::<BlobId>(rng.gen());
     encode_decode::<Bytes64>(rng.gen());
 }
 
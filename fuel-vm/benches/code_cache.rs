@@ -0,0 +1,150 @@
+use std::{
+    iter,
+    sync::Arc,
+};
+
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_vm::{
+    checked_transaction::Checked,
+    fuel_asm::{
+        op,
+        GTFArgs,
+        Instruction,
+        RegId,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        Contract,
+        Create,
+        Finalizable,
+        Input,
+        Output,
+        Script,
+        TransactionBuilder,
+    },
+    fuel_types::{
+        canonical::Serialize,
+        ContractId,
+    },
+    interpreter::InterpreterParams,
+    prelude::{
+        Call,
+        IntoChecked,
+        LruContractCodeCache,
+        MemoryStorage,
+        Transactor,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// Number of the calls made by the script.
+const CALLS: usize = 100;
+/// Number of the instructions of the called contract.
+const CONTRACT_LEN: usize = 1024;
+
+fn deploy_contract(
+    rng: &mut StdRng,
+    params: &ConsensusParameters,
+) -> (Checked<Create>, ContractId) {
+    let program: Vec<u8> = iter::once(op::ret(RegId::ONE))
+        .chain(iter::repeat(op::noop()).take(CONTRACT_LEN - 1))
+        .flat_map(Instruction::to_bytes)
+        .collect();
+    let salt = rng.gen();
+    let contract = Contract::from(program.as_slice());
+    let storage_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &storage_root);
+
+    let create = TransactionBuilder::create(program.into(), salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, storage_root))
+        .finalize()
+        .into_checked(Default::default(), params)
+        .expect("Should successfully convert into Checked");
+
+    (create, contract_id)
+}
+
+/// Script calling the contract [`CALLS`] times.
+fn call_script(
+    rng: &mut StdRng,
+    params: &ConsensusParameters,
+    contract_id: ContractId,
+) -> Checked<Script> {
+    let script: Vec<u8> =
+        iter::once(op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData))
+            .chain(
+                iter::repeat(op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS)).take(CALLS),
+            )
+            .chain(iter::once(op::ret(RegId::ONE)))
+            .collect();
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let mut builder = TransactionBuilder::script(script, script_data);
+    builder
+        .script_gas_limit(10_000_000)
+        .add_input(Input::contract(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            contract_id,
+        ))
+        .add_output(Output::contract(0, rng.gen(), rng.gen()))
+        .add_random_fee_input();
+    builder
+        .finalize()
+        .into_checked(Default::default(), params)
+        .expect("Should successfully convert into Checked")
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let consensus_params = ConsensusParameters::standard();
+    let params = InterpreterParams::new(0, &consensus_params);
+    let (create, contract_id) = deploy_contract(rng, &consensus_params);
+    let script = call_script(rng, &consensus_params, contract_id);
+
+    let mut storage_vm =
+        Transactor::<_, Script>::new(MemoryStorage::default(), params.clone());
+    storage_vm
+        .deploy(create.clone())
+        .expect("Should deploy the contract");
+
+    let mut cached_vm =
+        Transactor::<_, Script>::new(MemoryStorage::default(), params.clone());
+    cached_vm
+        .with_code_cache(Arc::new(LruContractCodeCache::default()))
+        .deploy(create)
+        .expect("Should deploy the contract");
+
+    let mut group = c.benchmark_group("code_cache");
+
+    group.bench_function("storage", |b| {
+        b.iter(|| {
+            storage_vm.transact(script.clone());
+            black_box(storage_vm.receipts().is_some())
+        })
+    });
+
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            cached_vm.transact(script.clone());
+            black_box(cached_vm.receipts().is_some())
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
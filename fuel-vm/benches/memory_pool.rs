@@ -0,0 +1,78 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_vm::{
+    checked_transaction::Checked,
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        Finalizable,
+        Script,
+        TransactionBuilder,
+    },
+    interpreter::InterpreterParams,
+    pool::MemoryPool,
+    prelude::{
+        IntoChecked,
+        MemoryStorage,
+        Transactor,
+    },
+};
+
+fn small_script(params: &ConsensusParameters) -> Checked<Script> {
+    let script = [
+        op::movi(0x10, 64),
+        op::aloc(0x10),
+        op::cfei(64),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+
+    TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), params)
+        .expect("Should successfully convert into Checked")
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let consensus_params = ConsensusParameters::standard();
+    let params = InterpreterParams::new(0, &consensus_params);
+    let checked = small_script(&consensus_params);
+    let mut group = c.benchmark_group("memory_pool");
+
+    group.bench_function("fresh", |b| {
+        b.iter(|| {
+            let mut vm =
+                Transactor::<_, Script>::new(MemoryStorage::default(), params.clone());
+            vm.transact(checked.clone());
+            black_box(vm.receipts().is_some())
+        })
+    });
+
+    let pool = MemoryPool::new();
+    group.bench_function("pooled", |b| {
+        b.iter(|| {
+            let mut vm = Transactor::<_, Script>::with_memory_pool(
+                &pool,
+                MemoryStorage::default(),
+                params.clone(),
+            );
+            vm.transact(checked.clone());
+            black_box(vm.receipts().is_some())
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,114 @@
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+use fuel_vm::{
+    checked_transaction::{
+        CheckPredicateParams,
+        EstimatePredicates,
+    },
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        Finalizable,
+        Input,
+        Script,
+        TransactionBuilder,
+    },
+    interpreter::Interpreter,
+    prelude::IntoChecked,
+    storage::PredicateStorage,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+fn countdown_predicate(iterations: u16) -> Vec<u8> {
+    let counter = RegId::new(0x20);
+    [
+        op::movi(counter, iterations.into()),
+        op::subi(counter, counter, 1),
+        op::jnzb(counter, RegId::ZERO, 0),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn script_with_predicates(rng: &mut StdRng, count: usize) -> Script {
+    let predicate = countdown_predicate(10_000);
+    let owner = Input::predicate_owner(&predicate);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.script_gas_limit(1_000_000);
+    for _ in 0..count {
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            0,
+            rng.gen(),
+            rng.gen(),
+            0,
+            predicate.clone(),
+            vec![],
+        ));
+    }
+    builder.finalize()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let params = ConsensusParameters::standard();
+    let check_params = CheckPredicateParams::from(&params);
+    let mut group = c.benchmark_group("check_predicates");
+
+    for count in [1, 8, 64] {
+        let rng = &mut StdRng::seed_from_u64(8586);
+        let mut script = script_with_predicates(rng, count);
+        script
+            .estimate_predicates(&check_params)
+            .expect("Should estimate predicates");
+        let checked = script
+            .into_checked_basic(Default::default(), &params)
+            .expect("Should successfully convert into Checked");
+
+        group.bench_with_input(
+            BenchmarkId::new("sequential", count),
+            &checked,
+            |b, checked| {
+                b.iter(|| {
+                    black_box(Interpreter::<PredicateStorage, _>::check_predicates(
+                        checked,
+                        &check_params,
+                    ))
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("parallel", count),
+            &checked,
+            |b, checked| {
+                b.iter(|| {
+                    black_box(
+                        Interpreter::<PredicateStorage, _>::check_predicates_parallel(
+                            checked,
+                            &check_params,
+                        ),
+                    )
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+use std::{
+    alloc::{
+        GlobalAlloc,
+        Layout,
+        System,
+    },
+    sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    },
+};
+
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use fuel_vm::{
+    checked_transaction::Checked,
+    fuel_asm::{
+        op,
+        RegId,
+    },
+    fuel_tx::{
+        ConsensusParameters,
+        Finalizable,
+        Script,
+        TransactionBuilder,
+    },
+    interpreter::InterpreterParams,
+    prelude::{
+        IntoChecked,
+        MemoryStorage,
+        Transactor,
+    },
+};
+
+/// Number of the scripts executed by an iteration.
+const SCRIPTS: usize = 1000;
+
+/// Counts the allocations, to compare the setups beyond their timings.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn small_script(params: &ConsensusParameters) -> Checked<Script> {
+    let script = [op::movi(0x10, 64), op::aloc(0x10), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect();
+
+    TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), params)
+        .expect("Should successfully convert into Checked")
+}
+
+fn fresh(params: &InterpreterParams, scripts: &[Checked<Script>]) {
+    for script in scripts {
+        let mut vm =
+            Transactor::<_, Script>::new(MemoryStorage::default(), params.clone());
+        vm.transact(script.clone());
+        black_box(vm.receipts().is_some());
+    }
+}
+
+fn reused(params: &InterpreterParams, scripts: &[Checked<Script>]) {
+    let mut vm = Transactor::<MemoryStorage, Script>::builder()
+        .with_params(params.clone())
+        .build();
+    black_box(vm.transact_many(scripts.iter().cloned()));
+}
+
+/// The number of the allocations done by `f`.
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - allocations
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let consensus_params = ConsensusParameters::standard();
+    let params = InterpreterParams::new(0, &consensus_params);
+    let scripts: Vec<_> = (0..SCRIPTS)
+        .map(|_| small_script(&consensus_params))
+        .collect();
+
+    let fresh_allocations = count_allocations(|| fresh(&params, &scripts));
+    let reused_allocations = count_allocations(|| reused(&params, &scripts));
+    assert!(
+        reused_allocations < fresh_allocations,
+        "The reused transactor allocates less than the fresh ones"
+    );
+
+    let mut group = c.benchmark_group("transactor");
+    group.sample_size(10);
+    group.bench_function("fresh", |b| b.iter(|| fresh(&params, &scripts)));
+    group.bench_function("reused", |b| b.iter(|| reused(&params, &scripts)));
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -47,8 +47,8 @@ use fuel_vm::{
 pub struct FileReadEcal;
 
 impl EcalHandler for FileReadEcal {
-    fn ecal<S, Tx>(
-        vm: &mut Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        vm: &mut Interpreter<S, Tx, Self, Observer>,
         a: RegId,
         b: RegId,
         c: RegId,
@@ -99,8 +99,8 @@ fn example_file_read() {
     .into_iter()
     .collect();
 
-    let mut client = MemoryClient::from_txtor(vm.into());
     let consensus_params = ConsensusParameters::standard();
+    let mut client = MemoryClient::from_txtor(vm.into(), consensus_params.clone());
     let tx = TransactionBuilder::script(script, script_data)
         .script_gas_limit(1_000_000)
         .maturity(Default::default())
@@ -126,8 +126,8 @@ pub struct CounterEcal {
 }
 
 impl EcalHandler for CounterEcal {
-    fn ecal<S, Tx>(
-        vm: &mut Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        vm: &mut Interpreter<S, Tx, Self, Observer>,
         a: RegId,
         _b: RegId,
         _c: RegId,
@@ -158,8 +158,8 @@ fn example_counter() {
     .into_iter()
     .collect();
 
-    let mut client = MemoryClient::from_txtor(vm.into());
     let consensus_params = ConsensusParameters::standard();
+    let mut client = MemoryClient::from_txtor(vm.into(), consensus_params.clone());
     let tx = TransactionBuilder::script(script, script_data)
         .script_gas_limit(1_000_000)
         .maturity(Default::default())
@@ -186,8 +186,8 @@ pub struct SharedCounterEcal {
 }
 
 impl EcalHandler for SharedCounterEcal {
-    fn ecal<S, Tx>(
-        vm: &mut Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        vm: &mut Interpreter<S, Tx, Self, Observer>,
         a: RegId,
         _b: RegId,
         _c: RegId,
@@ -221,8 +221,8 @@ fn example_shared_counter() {
     .into_iter()
     .collect();
 
-    let mut client = MemoryClient::from_txtor(vm.into());
     let consensus_params = ConsensusParameters::standard();
+    let mut client = MemoryClient::from_txtor(vm.into(), consensus_params.clone());
     let tx = TransactionBuilder::script(script, script_data)
         .script_gas_limit(1_000_000)
         .maturity(Default::default())
@@ -13,6 +13,7 @@ use crate::{
     interpreter::{
         InitialBalances,
         Interpreter,
+        PanicLocation,
     },
 };
 use derivative::Derivative;
@@ -35,20 +36,22 @@ pub struct Backtrace {
     memory: Vec<u8>,
     result: ScriptExecutionResult,
     initial_balances: InitialBalances,
+    panic_location: Option<PanicLocation>,
 }
 
 impl Backtrace {
     /// Create a backtrace from a vm instance and instruction result.
     ///
     /// This isn't copy-free and shouldn't be provided by default.
-    pub fn from_vm_error<S, Tx, Ecal>(
-        vm: &Interpreter<S, Tx, Ecal>,
+    pub fn from_vm_error<S, Tx, Ecal, Observer>(
+        vm: &Interpreter<S, Tx, Ecal, Observer>,
         result: ScriptExecutionResult,
     ) -> Self {
         let call_stack = vm.call_stack().to_owned();
         let contract = vm.internal_contract_or_default();
         let memory = vm.memory().to_owned();
         let initial_balances = vm.initial_balances().clone();
+        let panic_location = vm.panic_location().copied();
         let mut registers = [0; VM_REGISTER_COUNT];
 
         registers.copy_from_slice(vm.registers());
@@ -60,6 +63,7 @@ impl Backtrace {
             memory,
             result,
             initial_balances,
+            panic_location,
         }
     }
 
@@ -93,6 +97,11 @@ impl Backtrace {
         &self.initial_balances
     }
 
+    /// Location of the instruction that caused the panic.
+    pub const fn panic_location(&self) -> Option<&PanicLocation> {
+        self.panic_location.as_ref()
+    }
+
     /// Expose the internal attributes of the backtrace.
     pub fn into_inner(
         self,
@@ -111,6 +120,7 @@ impl Backtrace {
             memory,
             result,
             initial_balances,
+            ..
         } = self;
 
         (
@@ -8,10 +8,14 @@
 #![allow(non_upper_case_globals)]
 
 use fuel_tx::{
+    Blob,
     Create,
+    FeeParametersError,
     Mint,
     Script,
     Transaction,
+    Upgrade,
+    Upload,
     ValidityError,
 };
 use fuel_types::{
@@ -30,7 +34,11 @@ use core::{
 };
 
 use fuel_tx::{
-    field::MaxFeeLimit,
+    field::{
+        GasPriceFloor,
+        Inputs,
+        MaxFeeLimit,
+    },
     ConsensusParameters,
 };
 
@@ -48,6 +56,7 @@ use crate::{
 bitflags::bitflags! {
     /// Possible types of transaction checks.
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct Checks: u32 {
         /// Basic checks defined in the specification for each transaction:
         /// https://github.com/FuelLabs/fuel-specs/blob/master/src/tx-format/transaction.md#transaction
@@ -66,6 +75,34 @@ impl core::fmt::Display for Checks {
     }
 }
 
+/// Relaxations of the transaction checks, used to simulate transactions, e.g. the
+/// dry-run of a transaction that is not funded yet.
+///
+/// The default policy doesn't relax any check. The [`Checked`] transaction records
+/// the policy it was checked with, so a relaxed transaction can be refused from the
+/// block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckPolicy {
+    /// Skips the checks that the inputs cover the fee and the coin outputs, and that
+    /// the fee is covered by the max fee and the gas price floor policies.
+    pub skip_fee_checks: bool,
+    /// The gas price used to compute the fee instead of the one provided to
+    /// [`Checked::into_ready`].
+    pub gas_price_override: Option<Word>,
+    /// Allows the `Mint` transaction to mint an asset other than the base asset.
+    pub allow_non_base_mint_asset: bool,
+}
+
+impl CheckPolicy {
+    /// Returns `true` if any check is relaxed.
+    pub fn is_relaxed(&self) -> bool {
+        self.skip_fee_checks
+            || self.gas_price_override.is_some()
+            || self.allow_non_base_mint_asset
+    }
+}
+
 /// The type describes that the inner transaction was already checked.
 ///
 /// All fields are private, and there is no constructor, so it is impossible to create the
@@ -85,19 +122,30 @@ pub struct Checked<Tx: IntoChecked> {
     transaction: Tx,
     metadata: Tx::Metadata,
     checks_bitmask: Checks,
+    policy: CheckPolicy,
 }
 
 impl<Tx: IntoChecked> Checked<Tx> {
-    fn new(transaction: Tx, metadata: Tx::Metadata, checks_bitmask: Checks) -> Self {
+    fn new(
+        transaction: Tx,
+        metadata: Tx::Metadata,
+        checks_bitmask: Checks,
+        policy: CheckPolicy,
+    ) -> Self {
         Checked {
             transaction,
             metadata,
             checks_bitmask,
+            policy,
         }
     }
 
-    pub(crate) fn basic(transaction: Tx, metadata: Tx::Metadata) -> Self {
-        Checked::new(transaction, metadata, Checks::Basic)
+    pub(crate) fn basic(
+        transaction: Tx,
+        metadata: Tx::Metadata,
+        policy: CheckPolicy,
+    ) -> Self {
+        Checked::new(transaction, metadata, Checks::Basic, policy)
     }
 
     /// Returns reference on inner transaction.
@@ -115,6 +163,25 @@ impl<Tx: IntoChecked> Checked<Tx> {
         &self.checks_bitmask
     }
 
+    /// Returns the relaxations applied during the check.
+    pub fn policy(&self) -> &CheckPolicy {
+        &self.policy
+    }
+
+    /// Performs basic checks, if not yet done.
+    pub fn check_basic(
+        mut self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+    ) -> Result<Self, CheckError> {
+        if !self.checks_bitmask.contains(Checks::Basic) {
+            self.transaction
+                .check_without_signatures(block_height, consensus_params)?;
+            self.checks_bitmask.insert(Checks::Basic);
+        }
+        Ok(self)
+    }
+
     /// Performs check of signatures, if not yet done.
     pub fn check_signatures(mut self, chain_id: &ChainId) -> Result<Self, CheckError> {
         if !self.checks_bitmask.contains(Checks::Signatures) {
@@ -123,6 +190,39 @@ impl<Tx: IntoChecked> Checked<Tx> {
         }
         Ok(self)
     }
+
+    /// Returns the transaction only if it passed all `Checks` without relaxations.
+    ///
+    /// The checks may be performed independently and in any order, e.g. the
+    /// signatures may be verified upstream and only the predicates re-checked.
+    pub fn into_fully_checked(self) -> Result<Self, CheckError> {
+        let missing = Checks::all().difference(self.checks_bitmask);
+        if !missing.is_empty() {
+            return Err(CheckError::MissingChecks { missing })
+        }
+        if self.policy.is_relaxed() {
+            return Err(CheckError::RelaxedChecks {
+                policy: self.policy,
+            })
+        }
+        Ok(self)
+    }
+}
+
+impl<Tx: IntoChecked + Inputs> Checked<Tx> {
+    /// The gas used by the predicates of the transaction, as declared by its inputs.
+    ///
+    /// Returns `None` until the predicates are checked, because the declared gas is
+    /// only known to match the gas actually used once [`Checks::Predicates`] passed.
+    pub fn predicate_gas_used(&self) -> Option<Word> {
+        self.checks_bitmask.contains(Checks::Predicates).then(|| {
+            self.transaction
+                .inputs()
+                .iter()
+                .filter_map(Input::predicate_gas_used)
+                .fold(0, Word::saturating_add)
+        })
+    }
 }
 
 /// Transaction that has checks for all dynamic values, e.g. `gas_price`
@@ -132,6 +232,7 @@ pub struct Ready<Tx: IntoChecked> {
     transaction: Tx,
     metadata: Tx::Metadata,
     checks_bitmask: Checks,
+    policy: CheckPolicy,
 }
 
 impl<Tx: IntoChecked> Ready<Tx> {
@@ -142,8 +243,9 @@ impl<Tx: IntoChecked> Ready<Tx> {
             transaction,
             metadata,
             checks_bitmask,
+            policy,
         } = self;
-        let checked = Checked::new(transaction, metadata, checks_bitmask);
+        let checked = Checked::new(transaction, metadata, checks_bitmask, policy);
         (gas_price, checked)
     }
 
@@ -151,10 +253,18 @@ impl<Tx: IntoChecked> Ready<Tx> {
     pub fn gas_price(&self) -> Word {
         self.gas_price
     }
+
+    /// Returns the relaxations applied during the check.
+    pub fn policy(&self) -> &CheckPolicy {
+        &self.policy
+    }
 }
 
 impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
     /// Run final checks on `Checked` using dynamic values, e.g. `gas_price`
+    ///
+    /// The [`CheckPolicy::gas_price_override`], if any, takes precedence over the
+    /// `gas_price`.
     pub fn into_ready(
         self,
         gas_price: Word,
@@ -165,7 +275,22 @@ impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
             transaction,
             metadata,
             checks_bitmask,
+            policy,
         } = self;
+        let gas_price = policy.gas_price_override.unwrap_or(gas_price);
+
+        let floor = transaction.gas_price_floor();
+        if !policy.skip_fee_checks && gas_price < floor {
+            return Err(CheckError::GasPriceBelowFloor {
+                floor,
+                actual: gas_price,
+            });
+        }
+
+        fee_parameters
+            .validate()
+            .map_err(CheckError::InvalidFeeParameters)?;
+
         let fee = TransactionFee::checked_from_tx(
             gas_costs,
             fee_parameters,
@@ -177,7 +302,7 @@ impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
         let max_fee_from_policies = transaction.max_fee_limit();
         let max_fee_from_gas_price = fee.max_fee();
 
-        if max_fee_from_gas_price > max_fee_from_policies {
+        if !policy.skip_fee_checks && max_fee_from_gas_price > max_fee_from_policies {
             Err(CheckError::InsufficientMaxFee {
                 max_fee_from_policies,
                 max_fee_from_gas_price,
@@ -188,6 +313,7 @@ impl<Tx: IntoChecked + Chargeable> Checked<Tx> {
                 transaction,
                 metadata,
                 checks_bitmask,
+                policy,
             })
         }
     }
@@ -261,6 +387,27 @@ pub enum CheckError {
         /// The max fee calculated from the gas price and gas used by the transaction.
         max_fee_from_gas_price: Word,
     },
+    /// The gas price is lower than the floor the transaction committed to in its
+    /// policies.
+    GasPriceBelowFloor {
+        /// The gas price floor from the policies defined by the user.
+        floor: Word,
+        /// The gas price used during the check.
+        actual: Word,
+    },
+    /// The fee parameters used during the check are invalid.
+    InvalidFeeParameters(FeeParametersError),
+    /// The transaction is required to be fully checked, but some checks were skipped.
+    MissingChecks {
+        /// The checks that were not performed.
+        missing: Checks,
+    },
+    /// The transaction is required to be fully checked, but it was checked with
+    /// relaxations.
+    RelaxedChecks {
+        /// The relaxations applied during the check.
+        policy: CheckPolicy,
+    },
 }
 
 /// Performs checks for a transaction
@@ -274,12 +421,30 @@ pub trait IntoChecked: FormatValidityChecks + Sized {
         block_height: BlockHeight,
         consensus_params: &ConsensusParameters,
     ) -> Result<Checked<Self>, CheckError>
+    where
+        Checked<Self>: CheckPredicates,
+    {
+        self.into_checked_with_policy(
+            block_height,
+            consensus_params,
+            CheckPolicy::default(),
+        )
+    }
+
+    /// Returns transaction that passed all `Checks` with the relaxations of the
+    /// `policy`.
+    fn into_checked_with_policy(
+        self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+        policy: CheckPolicy,
+    ) -> Result<Checked<Self>, CheckError>
     where
         Checked<Self>: CheckPredicates,
     {
         let check_predicate_params = consensus_params.into();
-        self.into_checked_basic(block_height, consensus_params)?
-            .check_signatures(&consensus_params.chain_id)?
+        self.into_checked_basic_with_policy(block_height, consensus_params, policy)?
+            .check_signatures(&consensus_params.chain_id())?
             .check_predicates(&check_predicate_params)
     }
 
@@ -288,6 +453,21 @@ pub trait IntoChecked: FormatValidityChecks + Sized {
         self,
         block_height: BlockHeight,
         consensus_params: &ConsensusParameters,
+    ) -> Result<Checked<Self>, CheckError> {
+        self.into_checked_basic_with_policy(
+            block_height,
+            consensus_params,
+            CheckPolicy::default(),
+        )
+    }
+
+    /// Returns transaction that passed only `Checks::Basic` with the relaxations of
+    /// the `policy`.
+    fn into_checked_basic_with_policy(
+        self,
+        block_height: BlockHeight,
+        consensus_params: &ConsensusParameters,
+        policy: CheckPolicy,
     ) -> Result<Checked<Self>, CheckError>;
 }
 
@@ -306,6 +486,12 @@ pub struct CheckPredicateParams {
     pub max_inputs: u8,
     /// Maximum size of the contract in bytes
     pub contract_max_size: u64,
+    /// Maximum depth of the nested contract calls
+    pub max_call_depth: u64,
+    /// Maximum number of receipts emitted by a script
+    pub max_receipts_count: u64,
+    /// Maximum total size of the receipts emitted by a script, in bytes
+    pub max_receipts_size: u64,
     /// Maximum length of the message data
     pub max_message_data_length: u64,
     /// Offset of the transaction data in the memory
@@ -314,6 +500,9 @@ pub struct CheckPredicateParams {
     pub fee_params: FeeParameters,
     /// Base Asset ID
     pub base_asset_id: AssetId,
+    /// Maximum number of instructions executed per predicate, regardless of the
+    /// gas. `None` means no limit.
+    pub max_instructions: Option<u64>,
 }
 
 impl Default for CheckPredicateParams {
@@ -332,15 +521,19 @@ impl From<&ConsensusParameters> for CheckPredicateParams {
     fn from(value: &ConsensusParameters) -> Self {
         CheckPredicateParams {
             gas_costs: value.gas_costs().clone(),
-            chain_id: value.chain_id,
+            chain_id: value.chain_id(),
             max_gas_per_predicate: value.predicate_params().max_gas_per_predicate,
             max_gas_per_tx: value.tx_params().max_gas_per_tx,
             max_inputs: value.tx_params().max_inputs,
             contract_max_size: value.contract_params().contract_max_size,
+            max_call_depth: value.contract_params().max_call_depth,
+            max_receipts_count: value.script_params().max_receipts_count,
+            max_receipts_size: value.script_params().max_receipts_size,
             max_message_data_length: value.predicate_params().max_message_data_length,
             tx_offset: value.tx_params().tx_offset(),
             fee_params: *(value.fee_params()),
-            base_asset_id: value.base_asset_id,
+            base_asset_id: *value.base_asset_id(),
+            max_instructions: None,
         }
     }
 }
@@ -351,11 +544,27 @@ pub trait CheckPredicates: Sized {
     /// Performs predicates verification of the transaction.
     fn check_predicates(self, params: &CheckPredicateParams) -> Result<Self, CheckError>;
 
+    /// Performs predicates verification of the transaction, notifying the `observer`
+    /// around every instruction executed by the predicates.
+    fn check_predicates_with_observer<Observer: InstructionObserver>(
+        self,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<Self, CheckError>;
+
     /// Performs predicates verification of the transaction in parallel.
     async fn check_predicates_async<E: ParallelExecutor>(
         self,
         params: &CheckPredicateParams,
     ) -> Result<Self, CheckError>;
+
+    /// Performs predicates verification of the transaction in parallel on the rayon
+    /// thread pool.
+    #[cfg(feature = "rayon")]
+    fn check_predicates_parallel(
+        self,
+        params: &CheckPredicateParams,
+    ) -> Result<Self, CheckError>;
 }
 
 /// Provides predicate estimation functionality for the transaction.
@@ -367,11 +576,26 @@ pub trait EstimatePredicates: Sized {
         params: &CheckPredicateParams,
     ) -> Result<(), CheckError>;
 
+    /// Estimates predicates of the transaction, notifying the `observer` around every
+    /// instruction executed by the predicates.
+    fn estimate_predicates_with_observer<Observer: InstructionObserver>(
+        &mut self,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<(), CheckError>;
+
     /// Estimates predicates of the transaction in parallel.
     async fn estimate_predicates_async<E: ParallelExecutor>(
         &mut self,
         params: &CheckPredicateParams,
     ) -> Result<(), CheckError>;
+
+    /// Estimates predicates of the transaction in parallel on the rayon thread pool.
+    #[cfg(feature = "rayon")]
+    fn estimate_predicates_parallel(
+        &mut self,
+        params: &CheckPredicateParams,
+    ) -> Result<(), CheckError>;
 }
 
 /// Executes CPU-heavy tasks in parallel.
@@ -399,12 +623,19 @@ where
     Tx: ExecutableTransaction + Send + Sync + 'static,
     <Tx as IntoChecked>::Metadata: crate::interpreter::CheckedMetadata + Send + Sync,
 {
-    fn check_predicates(
+    fn check_predicates(self, params: &CheckPredicateParams) -> Result<Self, CheckError> {
+        self.check_predicates_with_observer(params, &mut NoopObserver)
+    }
+
+    fn check_predicates_with_observer<Observer: InstructionObserver>(
         mut self,
         params: &CheckPredicateParams,
+        observer: &mut Observer,
     ) -> Result<Self, CheckError> {
         if !self.checks_bitmask.contains(Checks::Predicates) {
-            Interpreter::<PredicateStorage, _>::check_predicates(&self, params)?;
+            Interpreter::<PredicateStorage, _>::check_predicates_with_observer(
+                &self, params, observer,
+            )?;
             self.checks_bitmask.insert(Checks::Predicates);
         }
         Ok(self)
@@ -430,6 +661,18 @@ where
             Ok(self)
         }
     }
+
+    #[cfg(feature = "rayon")]
+    fn check_predicates_parallel(
+        mut self,
+        params: &CheckPredicateParams,
+    ) -> Result<Self, CheckError> {
+        if !self.checks_bitmask.contains(Checks::Predicates) {
+            Interpreter::<PredicateStorage, _>::check_predicates_parallel(&self, params)?;
+            self.checks_bitmask.insert(Checks::Predicates);
+        }
+        Ok(self)
+    }
 }
 
 #[async_trait::async_trait]
@@ -438,7 +681,17 @@ impl<Tx: ExecutableTransaction + Send + Sync + 'static> EstimatePredicates for T
         &mut self,
         params: &CheckPredicateParams,
     ) -> Result<(), CheckError> {
-        Interpreter::<PredicateStorage, _>::estimate_predicates(self, params)?;
+        self.estimate_predicates_with_observer(params, &mut NoopObserver)
+    }
+
+    fn estimate_predicates_with_observer<Observer: InstructionObserver>(
+        &mut self,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<(), CheckError> {
+        Interpreter::<PredicateStorage, _>::estimate_predicates_with_observer(
+            self, params, observer,
+        )?;
         Ok(())
     }
 
@@ -454,6 +707,15 @@ impl<Tx: ExecutableTransaction + Send + Sync + 'static> EstimatePredicates for T
 
         Ok(())
     }
+
+    #[cfg(feature = "rayon")]
+    fn estimate_predicates_parallel(
+        &mut self,
+        params: &CheckPredicateParams,
+    ) -> Result<(), CheckError> {
+        Interpreter::<PredicateStorage, _>::estimate_predicates_parallel(self, params)?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -465,6 +727,34 @@ impl EstimatePredicates for Transaction {
         match self {
             Transaction::Script(script) => script.estimate_predicates(params),
             Transaction::Create(create) => create.estimate_predicates(params),
+            Transaction::Upgrade(upgrade) => upgrade.estimate_predicates(params),
+            Transaction::Upload(upload) => upload.estimate_predicates(params),
+            Transaction::Blob(blob) => blob.estimate_predicates(params),
+            Transaction::Mint(_) => Ok(()),
+        }
+    }
+
+    fn estimate_predicates_with_observer<Observer: InstructionObserver>(
+        &mut self,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<(), CheckError> {
+        match self {
+            Transaction::Script(script) => {
+                script.estimate_predicates_with_observer(params, observer)
+            }
+            Transaction::Create(create) => {
+                create.estimate_predicates_with_observer(params, observer)
+            }
+            Transaction::Upgrade(upgrade) => {
+                upgrade.estimate_predicates_with_observer(params, observer)
+            }
+            Transaction::Upload(upload) => {
+                upload.estimate_predicates_with_observer(params, observer)
+            }
+            Transaction::Blob(blob) => {
+                blob.estimate_predicates_with_observer(params, observer)
+            }
             Transaction::Mint(_) => Ok(()),
         }
     }
@@ -480,6 +770,28 @@ impl EstimatePredicates for Transaction {
             Transaction::Create(create) => {
                 create.estimate_predicates_async::<E>(params).await
             }
+            Transaction::Upgrade(upgrade) => {
+                upgrade.estimate_predicates_async::<E>(params).await
+            }
+            Transaction::Upload(upload) => {
+                upload.estimate_predicates_async::<E>(params).await
+            }
+            Transaction::Blob(blob) => blob.estimate_predicates_async::<E>(params).await,
+            Transaction::Mint(_) => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    fn estimate_predicates_parallel(
+        &mut self,
+        params: &CheckPredicateParams,
+    ) -> Result<(), CheckError> {
+        match self {
+            Transaction::Script(script) => script.estimate_predicates_parallel(params),
+            Transaction::Create(create) => create.estimate_predicates_parallel(params),
+            Transaction::Upgrade(upgrade) => upgrade.estimate_predicates_parallel(params),
+            Transaction::Upload(upload) => upload.estimate_predicates_parallel(params),
+            Transaction::Blob(blob) => blob.estimate_predicates_parallel(params),
             Transaction::Mint(_) => Ok(()),
         }
     }
@@ -495,6 +807,14 @@ impl CheckPredicates for Checked<Mint> {
         Ok(self)
     }
 
+    fn check_predicates_with_observer<Observer: InstructionObserver>(
+        self,
+        params: &CheckPredicateParams,
+        _observer: &mut Observer,
+    ) -> Result<Self, CheckError> {
+        self.check_predicates(params)
+    }
+
     async fn check_predicates_async<E: ParallelExecutor>(
         mut self,
         _params: &CheckPredicateParams,
@@ -502,21 +822,53 @@ impl CheckPredicates for Checked<Mint> {
         self.checks_bitmask.insert(Checks::Predicates);
         Ok(self)
     }
+
+    #[cfg(feature = "rayon")]
+    fn check_predicates_parallel(
+        mut self,
+        _params: &CheckPredicateParams,
+    ) -> Result<Self, CheckError> {
+        self.checks_bitmask.insert(Checks::Predicates);
+        Ok(self)
+    }
 }
 
 #[async_trait::async_trait]
 impl CheckPredicates for Checked<Transaction> {
     fn check_predicates(self, params: &CheckPredicateParams) -> Result<Self, CheckError> {
+        self.check_predicates_with_observer(params, &mut NoopObserver)
+    }
+
+    fn check_predicates_with_observer<Observer: InstructionObserver>(
+        self,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<Self, CheckError> {
         let checked_transaction: CheckedTransaction = self.into();
         let checked_transaction: CheckedTransaction = match checked_transaction {
             CheckedTransaction::Script(tx) => {
-                CheckPredicates::check_predicates(tx, params)?.into()
+                CheckPredicates::check_predicates_with_observer(tx, params, observer)?
+                    .into()
             }
             CheckedTransaction::Create(tx) => {
-                CheckPredicates::check_predicates(tx, params)?.into()
+                CheckPredicates::check_predicates_with_observer(tx, params, observer)?
+                    .into()
             }
             CheckedTransaction::Mint(tx) => {
-                CheckPredicates::check_predicates(tx, params)?.into()
+                CheckPredicates::check_predicates_with_observer(tx, params, observer)?
+                    .into()
+            }
+            CheckedTransaction::Upgrade(tx) => {
+                CheckPredicates::check_predicates_with_observer(tx, params, observer)?
+                    .into()
+            }
+            CheckedTransaction::Upload(tx) => {
+                CheckPredicates::check_predicates_with_observer(tx, params, observer)?
+                    .into()
+            }
+            CheckedTransaction::Blob(tx) => {
+                CheckPredicates::check_predicates_with_observer(tx, params, observer)?
+                    .into()
             }
         };
         Ok(checked_transaction.into())
@@ -547,10 +899,54 @@ impl CheckPredicates for Checked<Transaction> {
                     .await?
                     .into()
             }
+            CheckedTransaction::Upgrade(tx) => {
+                CheckPredicates::check_predicates_async::<E>(tx, params)
+                    .await?
+                    .into()
+            }
+            CheckedTransaction::Upload(tx) => {
+                CheckPredicates::check_predicates_async::<E>(tx, params)
+                    .await?
+                    .into()
+            }
+            CheckedTransaction::Blob(tx) => {
+                CheckPredicates::check_predicates_async::<E>(tx, params)
+                    .await?
+                    .into()
+            }
         };
 
         Ok(checked_transaction.into())
     }
+
+    #[cfg(feature = "rayon")]
+    fn check_predicates_parallel(
+        self,
+        params: &CheckPredicateParams,
+    ) -> Result<Self, CheckError> {
+        let checked_transaction: CheckedTransaction = self.into();
+        let checked_transaction: CheckedTransaction = match checked_transaction {
+            CheckedTransaction::Script(tx) => {
+                CheckPredicates::check_predicates_parallel(tx, params)?.into()
+            }
+            CheckedTransaction::Create(tx) => {
+                CheckPredicates::check_predicates_parallel(tx, params)?.into()
+            }
+            CheckedTransaction::Mint(tx) => {
+                CheckPredicates::check_predicates_parallel(tx, params)?.into()
+            }
+            CheckedTransaction::Upgrade(tx) => {
+                CheckPredicates::check_predicates_parallel(tx, params)?.into()
+            }
+            CheckedTransaction::Upload(tx) => {
+                CheckPredicates::check_predicates_parallel(tx, params)?.into()
+            }
+            CheckedTransaction::Blob(tx) => {
+                CheckPredicates::check_predicates_parallel(tx, params)?.into()
+            }
+        };
+        Ok(checked_transaction.into())
+    }
 }
 
 /// The Enum version of `Checked<Transaction>` allows getting the inner variant without
@@ -564,6 +960,24 @@ pub enum CheckedTransaction {
     Script(Checked<Script>),
     Create(Checked<Create>),
     Mint(Checked<Mint>),
+    Upgrade(Checked<Upgrade>),
+    Upload(Checked<Upload>),
+    Blob(Checked<Blob>),
+}
+
+impl CheckedTransaction {
+    /// The gas used by the predicates of the transaction, see
+    /// [`Checked::predicate_gas_used`]. `Mint` transactions have no predicates.
+    pub fn predicate_gas_used(&self) -> Option<Word> {
+        match self {
+            Self::Script(checked) => checked.predicate_gas_used(),
+            Self::Create(checked) => checked.predicate_gas_used(),
+            Self::Mint(_) => Some(0),
+            Self::Upgrade(checked) => checked.predicate_gas_used(),
+            Self::Upload(checked) => checked.predicate_gas_used(),
+            Self::Blob(checked) => checked.predicate_gas_used(),
+        }
+    }
 }
 
 impl From<Checked<Transaction>> for CheckedTransaction {
@@ -572,18 +986,28 @@ impl From<Checked<Transaction>> for CheckedTransaction {
             transaction,
             metadata,
             checks_bitmask,
+            policy,
         } = checked;
 
         // # Dev note: Avoid wildcard pattern to be sure that all variants are covered.
         match (transaction, metadata) {
             (Transaction::Script(transaction), CheckedMetadata::Script(metadata)) => {
-                Self::Script(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Script(Checked::new(transaction, metadata, checks_bitmask, policy))
             }
             (Transaction::Create(transaction), CheckedMetadata::Create(metadata)) => {
-                Self::Create(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Create(Checked::new(transaction, metadata, checks_bitmask, policy))
             }
             (Transaction::Mint(transaction), CheckedMetadata::Mint(metadata)) => {
-                Self::Mint(Checked::new(transaction, metadata, checks_bitmask))
+                Self::Mint(Checked::new(transaction, metadata, checks_bitmask, policy))
+            }
+            (Transaction::Upgrade(transaction), CheckedMetadata::Upgrade(metadata)) => {
+                Self::Upgrade(Checked::new(transaction, metadata, checks_bitmask, policy))
+            }
+            (Transaction::Upload(transaction), CheckedMetadata::Upload(metadata)) => {
+                Self::Upload(Checked::new(transaction, metadata, checks_bitmask, policy))
+            }
+            (Transaction::Blob(transaction), CheckedMetadata::Blob(metadata)) => {
+                Self::Blob(Checked::new(transaction, metadata, checks_bitmask, policy))
             }
             // The code should produce the `CheckedMetadata` for the corresponding
             // transaction variant. It is done in the implementation of the
@@ -592,6 +1016,9 @@ impl From<Checked<Transaction>> for CheckedTransaction {
             (Transaction::Script(_), _) => unreachable!(),
             (Transaction::Create(_), _) => unreachable!(),
             (Transaction::Mint(_), _) => unreachable!(),
+            (Transaction::Upgrade(_), _) => unreachable!(),
+            (Transaction::Upload(_), _) => unreachable!(),
+            (Transaction::Blob(_), _) => unreachable!(),
         }
     }
 }
@@ -614,6 +1041,24 @@ impl From<Checked<Mint>> for CheckedTransaction {
     }
 }
 
+impl From<Checked<Upgrade>> for CheckedTransaction {
+    fn from(checked: Checked<Upgrade>) -> Self {
+        Self::Upgrade(checked)
+    }
+}
+
+impl From<Checked<Upload>> for CheckedTransaction {
+    fn from(checked: Checked<Upload>) -> Self {
+        Self::Upload(checked)
+    }
+}
+
+impl From<Checked<Blob>> for CheckedTransaction {
+    fn from(checked: Checked<Blob>) -> Self {
+        Self::Blob(checked)
+    }
+}
+
 impl From<CheckedTransaction> for Checked<Transaction> {
     fn from(checked: CheckedTransaction) -> Self {
         match checked {
@@ -621,17 +1066,50 @@ impl From<CheckedTransaction> for Checked<Transaction> {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                policy,
+            }) => {
+                Checked::new(transaction.into(), metadata.into(), checks_bitmask, policy)
+            }
             CheckedTransaction::Create(Checked {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                policy,
+            }) => {
+                Checked::new(transaction.into(), metadata.into(), checks_bitmask, policy)
+            }
             CheckedTransaction::Mint(Checked {
                 transaction,
                 metadata,
                 checks_bitmask,
-            }) => Checked::new(transaction.into(), metadata.into(), checks_bitmask),
+                policy,
+            }) => {
+                Checked::new(transaction.into(), metadata.into(), checks_bitmask, policy)
+            }
+            CheckedTransaction::Upgrade(Checked {
+                transaction,
+                metadata,
+                checks_bitmask,
+                policy,
+            }) => {
+                Checked::new(transaction.into(), metadata.into(), checks_bitmask, policy)
+            }
+            CheckedTransaction::Upload(Checked {
+                transaction,
+                metadata,
+                checks_bitmask,
+                policy,
+            }) => {
+                Checked::new(transaction.into(), metadata.into(), checks_bitmask, policy)
+            }
+            CheckedTransaction::Blob(Checked {
+                transaction,
+                metadata,
+                checks_bitmask,
+                policy,
+            }) => {
+                Checked::new(transaction.into(), metadata.into(), checks_bitmask, policy)
+            }
         }
     }
 }
@@ -643,6 +1121,9 @@ pub enum CheckedMetadata {
     Script(<Script as IntoChecked>::Metadata),
     Create(<Create as IntoChecked>::Metadata),
     Mint(<Mint as IntoChecked>::Metadata),
+    Upgrade(<Upgrade as IntoChecked>::Metadata),
+    Upload(<Upload as IntoChecked>::Metadata),
+    Blob(<Blob as IntoChecked>::Metadata),
 }
 
 impl From<<Script as IntoChecked>::Metadata> for CheckedMetadata {
@@ -663,35 +1144,96 @@ impl From<<Mint as IntoChecked>::Metadata> for CheckedMetadata {
     }
 }
 
+impl From<<Upgrade as IntoChecked>::Metadata> for CheckedMetadata {
+    fn from(metadata: <Upgrade as IntoChecked>::Metadata) -> Self {
+        Self::Upgrade(metadata)
+    }
+}
+
+impl From<<Upload as IntoChecked>::Metadata> for CheckedMetadata {
+    fn from(metadata: <Upload as IntoChecked>::Metadata) -> Self {
+        Self::Upload(metadata)
+    }
+}
+
+impl From<<Blob as IntoChecked>::Metadata> for CheckedMetadata {
+    fn from(metadata: <Blob as IntoChecked>::Metadata) -> Self {
+        Self::Blob(metadata)
+    }
+}
+
 impl IntoChecked for Transaction {
     type Metadata = CheckedMetadata;
 
-    fn into_checked_basic(
+    fn into_checked_basic_with_policy(
         self,
         block_height: BlockHeight,
         consensus_params: &ConsensusParameters,
+        policy: CheckPolicy,
     ) -> Result<Checked<Self>, CheckError> {
         match self {
             Transaction::Script(script) => {
                 let (transaction, metadata) = script
-                    .into_checked_basic(block_height, consensus_params)?
+                    .into_checked_basic_with_policy(
+                        block_height,
+                        consensus_params,
+                        policy,
+                    )?
                     .into();
                 Ok((transaction.into(), metadata.into()))
             }
             Transaction::Create(create) => {
                 let (transaction, metadata) = create
-                    .into_checked_basic(block_height, consensus_params)?
+                    .into_checked_basic_with_policy(
+                        block_height,
+                        consensus_params,
+                        policy,
+                    )?
                     .into();
                 Ok((transaction.into(), metadata.into()))
             }
             Transaction::Mint(mint) => {
                 let (transaction, metadata) = mint
-                    .into_checked_basic(block_height, consensus_params)?
+                    .into_checked_basic_with_policy(
+                        block_height,
+                        consensus_params,
+                        policy,
+                    )?
+                    .into();
+                Ok((transaction.into(), metadata.into()))
+            }
+            Transaction::Upgrade(upgrade) => {
+                let (transaction, metadata) = upgrade
+                    .into_checked_basic_with_policy(
+                        block_height,
+                        consensus_params,
+                        policy,
+                    )?
+                    .into();
+                Ok((transaction.into(), metadata.into()))
+            }
+            Transaction::Upload(upload) => {
+                let (transaction, metadata) = upload
+                    .into_checked_basic_with_policy(
+                        block_height,
+                        consensus_params,
+                        policy,
+                    )?
+                    .into();
+                Ok((transaction.into(), metadata.into()))
+            }
+            Transaction::Blob(blob) => {
+                let (transaction, metadata) = blob
+                    .into_checked_basic_with_policy(
+                        block_height,
+                        consensus_params,
+                        policy,
+                    )?
                     .into();
                 Ok((transaction.into(), metadata.into()))
             }
         }
-        .map(|(transaction, metadata)| Checked::basic(transaction, metadata))
+        .map(|(transaction, metadata)| Checked::basic(transaction, metadata, policy))
     }
 }
 
@@ -724,6 +1266,7 @@ mod tests {
             WitnessLimit,
             Witnesses,
         },
+        Contract,
         Script,
         TransactionBuilder,
         ValidityError,
@@ -743,7 +1286,10 @@ mod tests {
             PredicateParameters::default(),
             ScriptParameters::default(),
             ContractParameters::default(),
-            FeeParameters::default().with_gas_price_factor(factor),
+            FeeParameters::default()
+                .with_gas_price_factor(factor)
+                .expect("The gas price factor is not zero"),
+            Default::default(),
             Default::default(),
             Default::default(),
             Default::default(),
@@ -908,7 +1454,9 @@ mod tests {
 
         let rng = &mut StdRng::seed_from_u64(seed);
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(gas_price_factor);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(gas_price_factor)
+            .expect("The gas price factor is not zero");
         let predicate_gas_used = rng.gen();
         let tx = predicate_tx(
             rng,
@@ -944,7 +1492,9 @@ mod tests {
         }
         let rng = &mut StdRng::seed_from_u64(seed);
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(gas_price_factor);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(gas_price_factor)
+            .expect("The gas price factor is not zero");
         let predicate_gas_used = rng.gen();
         let tx = predicate_tx(
             rng,
@@ -979,7 +1529,9 @@ mod tests {
 
         let rng = &mut StdRng::seed_from_u64(seed);
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(gas_price_factor);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(gas_price_factor)
+            .expect("The gas price factor is not zero");
         let tx = predicate_message_coin_tx(rng, gas_limit, input_amount, tip);
 
         if let Ok(valid) = is_valid_max_fee(&tx, gas_price, &gas_costs, &fee_params) {
@@ -1006,7 +1558,9 @@ mod tests {
 
         let rng = &mut StdRng::seed_from_u64(seed);
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(gas_price_factor);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(gas_price_factor)
+            .expect("The gas price factor is not zero");
         let tx = predicate_message_coin_tx(rng, gas_limit, input_amount, tip);
 
         // Given
@@ -1015,8 +1569,12 @@ mod tests {
         // When
         let refund = tx.refund_fee(&gas_costs, &fee_params, used_gas, gas_price);
 
-        let min_fee = tx.min_fee(&gas_costs, &fee_params, gas_price);
-        let max_fee = tx.max_fee(&gas_costs, &fee_params, gas_price);
+        let min_fee = tx
+            .min_fee(&gas_costs, &fee_params, gas_price)
+            .expect("The fee parameters are valid");
+        let max_fee = tx
+            .max_fee(&gas_costs, &fee_params, gas_price)
+            .expect("The fee parameters are valid");
 
         // Then
         if let Some(refund) = refund {
@@ -1045,7 +1603,9 @@ mod tests {
         }
         let rng = &mut StdRng::seed_from_u64(seed);
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(gas_price_factor);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(gas_price_factor)
+            .expect("The gas price factor is not zero");
         let tx = predicate_message_coin_tx(rng, gas_limit, input_amount, tip);
 
         if let Ok(valid) = is_valid_min_fee(&tx, &gas_costs, &fee_params, gas_price) {
@@ -1061,7 +1621,9 @@ mod tests {
         let gas_price = 100;
         let gas_limit = 1000;
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(1);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero");
         let tx = TransactionBuilder::script(vec![], vec![])
             .script_gas_limit(gas_limit)
             // Set up 3 signed inputs
@@ -1105,35 +1667,145 @@ mod tests {
     }
 
     #[test]
-    fn fee_multiple_signed_inputs_single_owner() {
+    fn fee_breakdown__total_matches_checked_fee_of_script_with_predicates_and_tip() {
+        // Given
         let rng = &mut StdRng::seed_from_u64(2322u64);
         let gas_price = 100;
         let gas_limit = 1000;
-        let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(1);
-        let secret = SecretKey::random(rng);
-        let tx = TransactionBuilder::script(vec![], vec![])
+        let tip = 123;
+        let consensus_params = params(1);
+        let gas_costs = consensus_params.gas_costs();
+        let fee_params = consensus_params.fee_params();
+        let predicate_1 = random_bytes::<1024, _>(rng).to_vec();
+        let predicate_2 = random_bytes::<2048, _>(rng).to_vec();
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder
+            .tip(tip)
             .script_gas_limit(gas_limit)
-            // Set up 3 signed inputs
-            .add_unsigned_message_input(
-                secret,
+            .add_input(Input::message_coin_predicate(
                 rng.gen(),
+                Input::predicate_owner(&predicate_1),
+                1_000_000,
                 rng.gen(),
-                rng.gen::<u32>() as u64,
+                50,
+                predicate_1.clone(),
                 vec![],
-            )
-            .add_unsigned_message_input(
-                secret,
+            ))
+            .add_input(Input::message_coin_predicate(
                 rng.gen(),
+                Input::predicate_owner(&predicate_2),
+                1_000_000,
                 rng.gen(),
-                rng.gen::<u32>() as u64,
+                100,
+                predicate_2.clone(),
                 vec![],
-            )
-            .add_unsigned_message_input(
-                secret,
+            ))
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
                 rng.gen(),
+                1_000_000,
+                AssetId::default(),
                 rng.gen(),
-                rng.gen::<u32>() as u64,
+            );
+        let tx = builder.finalize();
+
+        // When
+        let breakdown = tx
+            .fee_breakdown(gas_costs, fee_params, gas_price)
+            .expect("No overflow");
+
+        // Then
+        let bytes_size = tx.metered_bytes_size() as u64;
+        let predicate_vm_initialization = gas_costs.vm_initialization.resolve(bytes_size);
+        let expected_predicates_gas =
+            gas_costs.contract_root.resolve(predicate_1.len() as u64)
+                + gas_costs.contract_root.resolve(predicate_2.len() as u64)
+                + 2 * predicate_vm_initialization
+                + 50
+                + 100;
+        assert_eq!(breakdown.predicates_gas, expected_predicates_gas);
+        assert_eq!(breakdown.signatures_gas, gas_costs.ecr1);
+        assert_eq!(breakdown.bytes_gas, bytes_size * fee_params.gas_per_byte);
+        assert_eq!(breakdown.execution_gas, gas_limit);
+        assert_eq!(breakdown.tip, tip);
+        assert_eq!(breakdown.min_gas(), Some(tx.min_gas(gas_costs, fee_params)));
+        assert_eq!(breakdown.max_gas(), Some(tx.max_gas(gas_costs, fee_params)));
+        assert_eq!(
+            Ok(breakdown.total as u128),
+            tx.max_fee(gas_costs, fee_params, gas_price)
+        );
+
+        let checked = |max_fee_limit| {
+            let mut tx = tx.clone();
+            tx.set_max_fee_limit(max_fee_limit);
+            tx.into_checked_basic(Default::default(), &consensus_params)
+                .expect("Valid transaction")
+                .into_ready(gas_price, gas_costs, fee_params)
+        };
+        assert!(checked(breakdown.total).is_ok());
+        assert_eq!(
+            checked(breakdown.total - 1).expect_err("Insufficient max fee"),
+            CheckError::InsufficientMaxFee {
+                max_fee_from_policies: breakdown.total - 1,
+                max_fee_from_gas_price: breakdown.total,
+            }
+        );
+    }
+
+    #[test]
+    fn fee_zero_gas_price_factor_does_not_panic() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let gas_price = 100;
+        // bypasses the validation of the constructors
+        let fee_params = FeeParameters {
+            gas_price_factor: 0,
+            ..FeeParameters::DEFAULT
+        };
+        let tx = base_asset_tx(rng, 1_000_000, 1000, 0);
+
+        // used to divide by zero
+        let fee = TransactionFee::checked_from_tx(
+            &GasCosts::default(),
+            &fee_params,
+            &tx,
+            gas_price,
+        );
+
+        assert_eq!(fee, None);
+    }
+
+    #[test]
+    fn fee_multiple_signed_inputs_single_owner() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let gas_price = 100;
+        let gas_limit = 1000;
+        let gas_costs = GasCosts::default();
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero");
+        let secret = SecretKey::random(rng);
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(gas_limit)
+            // Set up 3 signed inputs
+            .add_unsigned_message_input(
+                secret,
+                rng.gen(),
+                rng.gen(),
+                rng.gen::<u32>() as u64,
+                vec![],
+            )
+            .add_unsigned_message_input(
+                secret,
+                rng.gen(),
+                rng.gen(),
+                rng.gen::<u32>() as u64,
+                vec![],
+            )
+            .add_unsigned_message_input(
+                secret,
+                rng.gen(),
+                rng.gen(),
+                rng.gen::<u32>() as u64,
                 vec![],
             )
             .finalize();
@@ -1171,7 +1843,9 @@ mod tests {
         let gas_price = 100;
         let gas_limit = 1000;
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(1);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero");
         let predicate_1 = random_bytes::<1024, _>(rng);
         let predicate_2 = random_bytes::<2048, _>(rng);
         let predicate_3 = random_bytes::<4096, _>(rng);
@@ -1235,7 +1909,9 @@ mod tests {
         let gas_price = 100;
         let gas_limit = 1000;
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(1);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero");
         let predicate_1 = random_bytes::<1024, _>(rng);
         let predicate_2 = random_bytes::<2048, _>(rng);
         let predicate_3 = random_bytes::<4096, _>(rng);
@@ -1322,14 +1998,16 @@ mod tests {
         let gas_price = 100;
         let witness_limit = 1000;
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(1);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero");
         let gen_storage_slot = || rng.gen::<StorageSlot>();
         let storage_slots = core::iter::repeat_with(gen_storage_slot)
             .take(100)
             .collect::<Vec<_>>();
         let storage_slots_len = storage_slots.len();
         let bytecode = rng.gen::<Witness>();
-        let bytecode_len = bytecode.as_ref().len();
+        let bytecode_len = Contract::padded_bytecode_len(bytecode.as_ref().len());
         let salt = rng.gen::<Salt>();
         let tx = TransactionBuilder::create(bytecode.clone(), salt, storage_slots)
             .witness_limit(witness_limit)
@@ -1362,7 +2040,9 @@ mod tests {
         let gas_price = 100;
         let witness_limit = 1000;
         let gas_costs = GasCosts::default();
-        let fee_params = FeeParameters::DEFAULT.with_gas_price_factor(1);
+        let fee_params = FeeParameters::DEFAULT
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero");
         let bytecode: Witness = Vec::<u8>::new().into();
         let salt = rng.gen::<Salt>();
         let tx = TransactionBuilder::create(bytecode.clone(), salt, vec![])
@@ -1577,6 +2257,123 @@ mod tests {
         assert!(matches!(err, CheckError::InsufficientMaxFee { .. }));
     }
 
+    #[test]
+    fn into_checked_basic_with_policy__underfunded_tx_passes_only_with_skipped_fee_checks(
+    ) {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let consensus_params = params(1);
+
+        // given
+        let input_amount = 10;
+        let max_fee_limit = 1_000;
+        let transaction = base_asset_tx(rng, input_amount, 0, max_fee_limit);
+        let policy = CheckPolicy {
+            skip_fee_checks: true,
+            ..Default::default()
+        };
+
+        // when
+        let err = transaction
+            .clone()
+            .into_checked_basic(Default::default(), &consensus_params)
+            .expect_err("underfunded tx should fail");
+        let checked = transaction
+            .into_checked_basic_with_policy(Default::default(), &consensus_params, policy)
+            .expect("fee checks are skipped");
+
+        // then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::InsufficientFeeAmount {
+                expected: max_fee_limit,
+                provided: input_amount,
+            })
+        );
+        assert_eq!(checked.policy(), &policy);
+        assert_eq!(
+            checked.metadata().non_retryable_balances[&AssetId::default()],
+            0
+        );
+    }
+
+    #[test]
+    fn into_ready__uses_overridden_gas_price() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let gas_costs = GasCosts::default();
+        let consensus_params = params(1);
+        let fee_params = consensus_params.fee_params();
+
+        // given
+        let zero_fee_limit = 0;
+        let transaction = base_asset_tx(rng, 1_000, 0, zero_fee_limit);
+        let gas_price_override = 100;
+        let expected_fee = TransactionFee::checked_from_tx(
+            &gas_costs,
+            fee_params,
+            &transaction,
+            gas_price_override,
+        )
+        .unwrap();
+        let policy = CheckPolicy {
+            gas_price_override: Some(gas_price_override),
+            ..Default::default()
+        };
+
+        // when
+        let err = transaction
+            .clone()
+            .into_checked_with_policy(Default::default(), &consensus_params, policy)
+            .unwrap()
+            .into_ready(0, &gas_costs, fee_params)
+            .expect_err("fee at the overridden gas price exceeds the limit");
+        let ready = transaction
+            .into_checked_with_policy(
+                Default::default(),
+                &consensus_params,
+                CheckPolicy {
+                    skip_fee_checks: true,
+                    ..policy
+                },
+            )
+            .unwrap()
+            .into_ready(0, &gas_costs, fee_params)
+            .expect("fee checks are skipped");
+
+        // then
+        assert_eq!(
+            err,
+            CheckError::InsufficientMaxFee {
+                max_fee_from_policies: zero_fee_limit,
+                max_fee_from_gas_price: expected_fee.max_fee(),
+            }
+        );
+        assert_eq!(ready.gas_price(), gas_price_override);
+        assert!(ready.policy().is_relaxed());
+    }
+
+    #[test]
+    fn into_fully_checked__fails_for_relaxed_checks() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let consensus_params = params(1);
+
+        // given
+        let policy = CheckPolicy {
+            skip_fee_checks: true,
+            ..Default::default()
+        };
+        let checked = base_asset_tx(rng, 10, 0, 1_000)
+            .into_checked_with_policy(Default::default(), &consensus_params, policy)
+            .unwrap();
+
+        // when
+        let err = checked
+            .into_fully_checked()
+            .expect_err("relaxed checks are not full");
+
+        // then
+        assert_eq!(err, CheckError::RelaxedChecks { policy });
+    }
+
     #[test]
     fn into_ready__tx_fails_if_tip_not_covered() {
         let rng = &mut StdRng::seed_from_u64(2322u64);
@@ -1633,6 +2430,81 @@ mod tests {
             .expect("Should be valid");
     }
 
+    #[test]
+    fn into_ready__fails_if_gas_price_below_floor() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // Given
+        let floor = 10;
+        let input_amount = 1_000_000;
+        let consensus_params = params(1);
+        let checked = TransactionBuilder::script(vec![], vec![])
+            .max_fee_limit(input_amount)
+            .min_gas_price(floor)
+            .script_gas_limit(1_000)
+            .add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                input_amount,
+                AssetId::default(),
+                rng.gen(),
+            )
+            .finalize()
+            .into_checked(Default::default(), &consensus_params)
+            .expect("Valid transaction");
+        let ready = |gas_price| {
+            checked.clone().into_ready(
+                gas_price,
+                &GasCosts::default(),
+                consensus_params.fee_params(),
+            )
+        };
+
+        // When
+        let below = ready(floor - 1);
+        let equal = ready(floor);
+        let above = ready(floor + 1);
+
+        // Then
+        assert_eq!(
+            below.expect_err("Gas price is below the floor"),
+            CheckError::GasPriceBelowFloor {
+                floor,
+                actual: floor - 1,
+            }
+        );
+        assert_eq!(equal.expect("Gas price is at the floor").gas_price(), floor);
+        assert_eq!(
+            above.expect("Gas price is above the floor").gas_price(),
+            floor + 1
+        );
+    }
+
+    #[test]
+    fn into_ready__fails_if_gas_price_factor_is_zero() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+
+        // Given
+        let consensus_params = params(1);
+        let checked = base_asset_tx(rng, 1_000_000, 1000, 0)
+            .into_checked(Default::default(), &consensus_params)
+            .expect("Valid transaction");
+        // bypasses the validation of the constructors
+        let fee_params = FeeParameters {
+            gas_price_factor: 0,
+            ..FeeParameters::DEFAULT
+        };
+
+        // When
+        let result = checked.into_ready(1, &GasCosts::default(), &fee_params);
+
+        // Then
+        assert_eq!(
+            result.expect_err("The gas price factor is zero"),
+            CheckError::InvalidFeeParameters(FeeParametersError::ZeroGasPriceFactor)
+        );
+    }
+
     #[test]
     fn into_ready__return_overflow_error_if_gas_price_too_high() {
         let rng = &mut StdRng::seed_from_u64(2322u64);
@@ -1738,6 +2610,59 @@ mod tests {
             .contains(Checks::Basic | Checks::Signatures));
     }
 
+    fn signed_coin_tx(rng: &mut StdRng, signers: usize) -> Script {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.script_gas_limit(100).max_fee_limit(0);
+        for _ in 0..signers {
+            builder.add_unsigned_coin_input(
+                SecretKey::random(rng),
+                rng.gen(),
+                10,
+                AssetId::default(),
+                rng.gen(),
+            );
+        }
+        builder.finalize()
+    }
+
+    #[test]
+    fn into_checked_accepts_signature_checks_at_max() {
+        let rng = &mut StdRng::seed_from_u64(1);
+        let mut params = ConsensusParameters::standard();
+        params.set_tx_params(params.tx_params().with_max_signature_checks(3));
+
+        // Given
+        let tx = signed_coin_tx(rng, 3);
+
+        // When
+        let result = tx.into_checked(1.into(), &params);
+
+        // Then
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn into_checked_rejects_signature_checks_above_max() {
+        let rng = &mut StdRng::seed_from_u64(1);
+        let mut params = ConsensusParameters::standard();
+        params.set_tx_params(params.tx_params().with_max_signature_checks(3));
+
+        // Given
+        let tx = signed_coin_tx(rng, 4);
+
+        // When
+        let result = tx.into_checked(1.into(), &params);
+
+        // Then
+        assert_eq!(
+            result.unwrap_err(),
+            CheckError::Validity(ValidityError::TooManySignatureChecks {
+                count: 4,
+                max: 3
+            })
+        );
+    }
+
     #[test]
     fn predicates_check_marks_predicate_flag() {
         let mut rng = StdRng::seed_from_u64(1);
@@ -1746,10 +2671,8 @@ mod tests {
 
         let tx = predicate_tx(&mut rng, 1000000, 1000000, 1000000, gas_costs.ret);
 
-        let consensus_params = ConsensusParameters {
-            gas_costs,
-            ..ConsensusParameters::standard()
-        };
+        let mut consensus_params = ConsensusParameters::standard();
+        consensus_params.set_gas_costs(gas_costs);
 
         let check_predicate_params = CheckPredicateParams::from(&consensus_params);
 
@@ -1768,6 +2691,264 @@ mod tests {
             .contains(Checks::Basic | Checks::Predicates));
     }
 
+    #[test]
+    fn into_checked_basic__fails_on_basic_violation() {
+        // Given
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(100)
+            .finalize();
+
+        // When
+        let err = tx
+            .into_checked_basic(Default::default(), &ConsensusParameters::standard())
+            .expect_err("Expected invalid transaction");
+
+        // Then
+        assert_eq!(err, CheckError::Validity(ValidityError::NoSpendableInput));
+    }
+
+    #[test]
+    fn check_signatures__fails_on_invalid_signature_after_basic_check() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let asset = AssetId::default();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(100)
+            .add_input(Input::coin_signed(
+                rng.gen(),
+                rng.gen(),
+                1_000,
+                asset,
+                rng.gen(),
+                Default::default(),
+            ))
+            .add_output(Output::change(rng.gen(), 0, asset))
+            .add_witness(Default::default())
+            .finalize();
+        let chain_id = ChainId::default();
+        let checked = tx
+            .into_checked_basic(
+                Default::default(),
+                &ConsensusParameters::standard_with_id(chain_id),
+            )
+            .expect("Basic checks don't verify signatures");
+
+        // When
+        let err = checked
+            .check_signatures(&chain_id)
+            .expect_err("Expected invalid signature");
+
+        // Then
+        assert!(matches!(
+            err,
+            CheckError::Validity(ValidityError::InputInvalidSignature { .. })
+        ));
+    }
+
+    #[test]
+    fn check_predicates__fails_on_false_predicate_when_signatures_are_skipped() {
+        // Given
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let asset = AssetId::default();
+        let predicate = vec![op::ret(0)].into_iter().collect::<Vec<u8>>();
+        let owner = Input::predicate_owner(&predicate);
+        let gas_costs = GasCosts::default();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(100)
+            .max_fee_limit(0)
+            .add_input(Input::coin_predicate(
+                rng.gen(),
+                owner,
+                1_000,
+                asset,
+                rng.gen(),
+                gas_costs.ret,
+                predicate,
+                vec![],
+            ))
+            .add_output(Output::change(rng.gen(), 0, asset))
+            .finalize();
+        let mut consensus_params = ConsensusParameters::standard();
+        consensus_params.set_gas_costs(gas_costs);
+        let checked = tx
+            .into_checked_basic(Default::default(), &consensus_params)
+            .expect("Basic checks don't execute predicates");
+
+        // When
+        let err = checked
+            .check_predicates(&CheckPredicateParams::from(&consensus_params))
+            .expect_err("Expected predicate failure");
+
+        // Then
+        assert!(matches!(err, CheckError::PredicateVerificationFailed(_)));
+    }
+
+    #[test]
+    fn into_fully_checked__fails_until_all_checks_are_performed() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(1);
+        let gas_costs = GasCosts::default();
+        let tx = predicate_tx(&mut rng, 1000000, 1000000, 1000000, gas_costs.ret);
+        let mut consensus_params = ConsensusParameters::standard();
+        consensus_params.set_gas_costs(gas_costs);
+        let checked = tx
+            .into_checked_basic(Default::default(), &consensus_params)
+            .unwrap();
+
+        // When
+        let err = checked
+            .clone()
+            .into_fully_checked()
+            .expect_err("Expected missing checks");
+        let fully_checked = checked
+            .check_predicates(&CheckPredicateParams::from(&consensus_params))
+            .unwrap()
+            .check_signatures(&consensus_params.chain_id())
+            .unwrap()
+            .into_fully_checked();
+
+        // Then
+        assert_eq!(
+            err,
+            CheckError::MissingChecks {
+                missing: Checks::Signatures | Checks::Predicates
+            }
+        );
+        assert_eq!(fully_checked.unwrap().checks(), &Checks::all());
+    }
+
+    fn mint_tx(
+        rng: &mut StdRng,
+        block_height: BlockHeight,
+        output_input_index: u8,
+        mint_asset_id: AssetId,
+    ) -> Mint {
+        TransactionBuilder::mint(
+            block_height,
+            rng.gen(),
+            rng.gen(),
+            fuel_tx::output::contract::Contract {
+                input_index: output_input_index,
+                balance_root: rng.gen(),
+                state_root: rng.gen(),
+            },
+            rng.gen(),
+            mint_asset_id,
+            rng.gen(),
+        )
+        .finalize()
+    }
+
+    #[test]
+    fn into_checked__mint_accepts_valid_mint() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let block_height = 1000.into();
+        let consensus_params = ConsensusParameters::standard();
+
+        // given
+        let mint = mint_tx(rng, block_height, 0, *consensus_params.base_asset_id());
+
+        // when
+        let checked = mint
+            .into_checked(block_height, &consensus_params)
+            .expect("valid mint");
+
+        // then
+        assert_eq!(checked.checks(), &Checks::all());
+        assert!(checked.into_fully_checked().is_ok());
+    }
+
+    #[test]
+    fn into_checked__mint_fails_for_non_base_asset() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let block_height = 1000.into();
+        let consensus_params = ConsensusParameters::standard();
+
+        // given
+        let non_base_asset = rng.gen();
+        let mint = mint_tx(rng, block_height, 0, non_base_asset);
+
+        // when
+        let err = mint
+            .into_checked(block_height, &consensus_params)
+            .expect_err("non-base asset mint");
+
+        // then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::TransactionMintNonBaseAsset)
+        );
+    }
+
+    #[test]
+    fn into_checked_with_policy__mint_accepts_allowed_non_base_asset() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let block_height = 1000.into();
+        let consensus_params = ConsensusParameters::standard();
+        let policy = CheckPolicy {
+            allow_non_base_mint_asset: true,
+            ..Default::default()
+        };
+
+        // given
+        let non_base_asset = rng.gen();
+        let mint = mint_tx(rng, block_height, 0, non_base_asset);
+
+        // when
+        let checked = mint
+            .into_checked_with_policy(block_height, &consensus_params, policy)
+            .expect("non-base asset is allowed");
+
+        // then
+        assert!(checked.policy().is_relaxed());
+        assert_eq!(
+            checked.into_fully_checked(),
+            Err(CheckError::RelaxedChecks { policy })
+        );
+    }
+
+    #[test]
+    fn into_checked__mint_fails_for_output_contract_of_other_input() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let block_height = 1000.into();
+        let consensus_params = ConsensusParameters::standard();
+
+        // given
+        let mint = mint_tx(rng, block_height, 1, *consensus_params.base_asset_id());
+
+        // when
+        let err = mint
+            .into_checked(block_height, &consensus_params)
+            .expect_err("output contract doesn't refer to the input contract");
+
+        // then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::TransactionMintIncorrectOutputIndex)
+        );
+    }
+
+    #[test]
+    fn into_checked__mint_fails_for_other_block_height() {
+        let rng = &mut StdRng::seed_from_u64(2322u64);
+        let block_height: BlockHeight = 1000.into();
+        let consensus_params = ConsensusParameters::standard();
+
+        // given
+        let mint = mint_tx(rng, block_height, 0, *consensus_params.base_asset_id());
+
+        // when
+        let err = mint
+            .into_checked(block_height.succ().unwrap(), &consensus_params)
+            .expect_err("mint of other block");
+
+        // then
+        assert_eq!(
+            err,
+            CheckError::Validity(ValidityError::TransactionMintIncorrectBlockHeight)
+        );
+    }
+
     fn is_valid_max_fee(
         tx: &Script,
         gas_price: u64,
@@ -1808,7 +2989,7 @@ mod tests {
 
         let max_fee_with_tip = max_fee.saturating_add(tx.tip() as u128);
 
-        let result = max_fee_with_tip == tx.max_fee(gas_costs, fee_params, gas_price);
+        let result = Ok(max_fee_with_tip) == tx.max_fee(gas_costs, fee_params, gas_price);
         Ok(result)
     }
 
@@ -1847,7 +3028,7 @@ mod tests {
         let min_fee = rounded_fee;
         let calculated_min_fee = tx.min_fee(gas_costs, fee_params, gas_price);
 
-        Ok(min_fee == calculated_min_fee)
+        Ok(Ok(min_fee) == calculated_min_fee)
     }
 
     fn valid_coin_tx(
@@ -22,12 +22,18 @@ use fuel_types::{
     Word,
 };
 
+use crate::checked_transaction::CheckPolicy;
 use alloc::collections::BTreeMap;
 use fuel_tx::policies::PolicyType;
 
+/// Computes the free balances of the transaction.
+///
+/// With the [`CheckPolicy::skip_fee_checks`], the balances that don't cover the fee or
+/// the coin outputs saturate at zero instead of failing.
 pub(crate) fn initial_free_balances<T>(
     tx: &T,
     base_asset_id: &AssetId,
+    policy: &CheckPolicy,
 ) -> Result<AvailableBalances, ValidityError>
 where
     T: Chargeable + field::Inputs + field::Outputs,
@@ -39,9 +45,19 @@ where
         .policies()
         .get(PolicyType::MaxFee)
         .ok_or(ValidityError::TransactionMaxFeeNotSet)?;
-    deduct_max_fee_from_base_asset(&mut non_retryable_balances, base_asset_id, max_fee)?;
+    let skip_fee_checks = policy.skip_fee_checks;
+    deduct_max_fee_from_base_asset(
+        &mut non_retryable_balances,
+        base_asset_id,
+        max_fee,
+        skip_fee_checks,
+    )?;
 
-    reduce_free_balances_by_coin_outputs(&mut non_retryable_balances, tx)?;
+    reduce_free_balances_by_coin_outputs(
+        &mut non_retryable_balances,
+        tx,
+        skip_fee_checks,
+    )?;
 
     Ok(AvailableBalances {
         non_retryable_balances,
@@ -90,8 +106,13 @@ fn deduct_max_fee_from_base_asset(
     non_retryable_balances: &mut BTreeMap<AssetId, Word>,
     base_asset_id: &AssetId,
     max_fee: Word,
+    saturating: bool,
 ) -> Result<(), ValidityError> {
     let base_asset_balance = non_retryable_balances.entry(*base_asset_id).or_default();
+    if saturating {
+        *base_asset_balance = base_asset_balance.saturating_sub(max_fee);
+        return Ok(())
+    }
     *base_asset_balance = base_asset_balance.checked_sub(max_fee).ok_or(
         ValidityError::InsufficientFeeAmount {
             expected: max_fee,
@@ -105,21 +126,27 @@ fn deduct_max_fee_from_base_asset(
 fn reduce_free_balances_by_coin_outputs(
     non_retryable_balances: &mut BTreeMap<AssetId, Word>,
     transaction: &impl field::Outputs,
+    saturating: bool,
 ) -> Result<(), ValidityError> {
     // reduce free balances by coin outputs
-    for (asset_id, amount) in
-        transaction
-            .outputs()
-            .iter()
-            .filter_map(|output| match output {
-                Output::Coin {
-                    asset_id, amount, ..
-                } => Some((asset_id, amount)),
-                _ => None,
-            })
-    {
+    for (index, asset_id, amount) in transaction.outputs().iter().enumerate().filter_map(
+        |(index, output)| match output {
+            Output::Coin {
+                asset_id, amount, ..
+            } => Some((index, asset_id, amount)),
+            _ => None,
+        },
+    ) {
+        if saturating {
+            let balance = non_retryable_balances.entry(*asset_id).or_default();
+            *balance = balance.saturating_sub(*amount);
+            continue
+        }
         let balance = non_retryable_balances.get_mut(asset_id).ok_or(
-            ValidityError::TransactionOutputCoinAssetIdNotFound(*asset_id),
+            ValidityError::TransactionOutputCoinAssetIdNotFound {
+                index,
+                asset_id: *asset_id,
+            },
         )?;
         *balance = balance.checked_sub(*amount).ok_or(
             ValidityError::InsufficientInputAmount {
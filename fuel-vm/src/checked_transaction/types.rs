@@ -1,8 +1,11 @@
 //! Implementation for different transaction types, groupd in submodules.
 
 pub use self::{
+    blob::CheckedMetadata as BlobCheckedMetadata,
     create::CheckedMetadata as CreateCheckedMetadata,
     script::CheckedMetadata as ScriptCheckedMetadata,
+    upgrade::CheckedMetadata as UpgradeCheckedMetadata,
+    upload::CheckedMetadata as UploadCheckedMetadata,
 };
 use alloc::collections::BTreeMap;
 use fuel_types::{
@@ -14,6 +17,7 @@ use fuel_types::{
 /// More information about it in the specification:
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/tx-validity.md#sufficient-balance>
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonRetryableFreeBalances(pub(crate) BTreeMap<AssetId, Word>);
 
 impl From<NonRetryableFreeBalances> for BTreeMap<AssetId, Word> {
@@ -34,6 +38,7 @@ impl core::ops::Deref for NonRetryableFreeBalances {
 /// More information about it in the specification:
 /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/protocol/tx-validity.md#sufficient-balance>
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RetryableAmount {
     pub(crate) amount: Word,
     pub(crate) base_asset_id: AssetId,
@@ -65,6 +70,7 @@ pub mod create {
     };
     use crate::checked_transaction::{
         CheckError,
+        CheckPolicy,
         NonRetryableFreeBalances,
     };
     use fuel_tx::{
@@ -87,10 +93,11 @@ pub mod create {
     impl IntoChecked for Create {
         type Metadata = CheckedMetadata;
 
-        fn into_checked_basic(
+        fn into_checked_basic_with_policy(
             mut self,
             block_height: BlockHeight,
             consensus_params: &ConsensusParameters,
+            policy: CheckPolicy,
         ) -> Result<Checked<Self>, CheckError> {
             let chain_id = consensus_params.chain_id();
             self.precompute(&chain_id)?;
@@ -100,7 +107,7 @@ pub mod create {
             let AvailableBalances {
                 non_retryable_balances,
                 retryable_balance,
-            } = initial_free_balances(&self, consensus_params.base_asset_id())?;
+            } = initial_free_balances(&self, consensus_params.base_asset_id(), &policy)?;
             debug_assert_eq!(
                 retryable_balance, 0,
                 "The `check_without_signatures` should return `TransactionCreateMessageData` above"
@@ -111,7 +118,7 @@ pub mod create {
                 block_height,
             };
 
-            Ok(Checked::basic(self, metadata))
+            Ok(Checked::basic(self, metadata, policy))
         }
     }
 }
@@ -122,7 +129,10 @@ pub mod mint {
         Checked,
         IntoChecked,
     };
-    use crate::checked_transaction::CheckError;
+    use crate::checked_transaction::{
+        CheckError,
+        CheckPolicy,
+    };
     use fuel_tx::{
         Cacheable,
         ConsensusParameters,
@@ -134,16 +144,21 @@ pub mod mint {
     impl IntoChecked for Mint {
         type Metadata = ();
 
-        fn into_checked_basic(
+        fn into_checked_basic_with_policy(
             mut self,
             block_height: BlockHeight,
             consensus_params: &ConsensusParameters,
+            policy: CheckPolicy,
         ) -> Result<Checked<Self>, CheckError> {
             let chain_id = consensus_params.chain_id();
             self.precompute(&chain_id)?;
-            self.check_without_signatures(block_height, consensus_params)?;
+            if policy.allow_non_base_mint_asset {
+                self.check_without_base_asset(block_height, consensus_params)?;
+            } else {
+                self.check_without_signatures(block_height, consensus_params)?;
+            }
 
-            Ok(Checked::basic(self, ()))
+            Ok(Checked::basic(self, (), policy))
         }
     }
 }
@@ -160,6 +175,7 @@ pub mod script {
     };
     use crate::checked_transaction::{
         CheckError,
+        CheckPolicy,
         NonRetryableFreeBalances,
         RetryableAmount,
     };
@@ -185,10 +201,11 @@ pub mod script {
     impl IntoChecked for Script {
         type Metadata = CheckedMetadata;
 
-        fn into_checked_basic(
+        fn into_checked_basic_with_policy(
             mut self,
             block_height: BlockHeight,
             consensus_params: &ConsensusParameters,
+            policy: CheckPolicy,
         ) -> Result<Checked<Self>, CheckError> {
             let chain_id = consensus_params.chain_id();
             self.precompute(&chain_id)?;
@@ -198,18 +215,213 @@ pub mod script {
             let AvailableBalances {
                 non_retryable_balances,
                 retryable_balance,
-            } = initial_free_balances(&self, consensus_params.base_asset_id())?;
+            } = initial_free_balances(&self, consensus_params.base_asset_id(), &policy)?;
 
             let metadata = CheckedMetadata {
                 non_retryable_balances: NonRetryableFreeBalances(non_retryable_balances),
                 retryable_balance: RetryableAmount {
                     amount: retryable_balance,
-                    base_asset_id: consensus_params.base_asset_id,
+                    base_asset_id: *consensus_params.base_asset_id(),
                 },
                 block_height,
             };
 
-            Ok(Checked::basic(self, metadata))
+            Ok(Checked::basic(self, metadata, policy))
+        }
+    }
+}
+
+/// For [`fuel_tx::Upgrade`]
+pub mod upgrade {
+    use super::super::{
+        balances::{
+            initial_free_balances,
+            AvailableBalances,
+        },
+        Checked,
+        IntoChecked,
+    };
+    use crate::checked_transaction::{
+        CheckError,
+        CheckPolicy,
+        NonRetryableFreeBalances,
+    };
+    use fuel_tx::{
+        Cacheable,
+        ConsensusParameters,
+        FormatValidityChecks,
+        Upgrade,
+    };
+    use fuel_types::BlockHeight;
+
+    /// Metdata produced by checking [`fuel_tx::Upgrade`].
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct CheckedMetadata {
+        /// See [`NonRetryableFreeBalances`].
+        pub free_balances: NonRetryableFreeBalances,
+        /// The block height this tx was verified with
+        pub block_height: BlockHeight,
+    }
+
+    impl IntoChecked for Upgrade {
+        type Metadata = CheckedMetadata;
+
+        fn into_checked_basic_with_policy(
+            mut self,
+            block_height: BlockHeight,
+            consensus_params: &ConsensusParameters,
+            policy: CheckPolicy,
+        ) -> Result<Checked<Self>, CheckError> {
+            let chain_id = consensus_params.chain_id();
+            self.precompute(&chain_id)?;
+            self.check_without_signatures(block_height, consensus_params)?;
+
+            // validate fees and compute free balances
+            let AvailableBalances {
+                non_retryable_balances,
+                retryable_balance,
+            } = initial_free_balances(&self, consensus_params.base_asset_id(), &policy)?;
+            debug_assert_eq!(
+                retryable_balance, 0,
+                "The `check_without_signatures` should return `TransactionUpgradeMessageData` above"
+            );
+
+            let metadata = CheckedMetadata {
+                free_balances: NonRetryableFreeBalances(non_retryable_balances),
+                block_height,
+            };
+
+            Ok(Checked::basic(self, metadata, policy))
+        }
+    }
+}
+
+/// For [`fuel_tx::Upload`]
+pub mod upload {
+    use super::super::{
+        balances::{
+            initial_free_balances,
+            AvailableBalances,
+        },
+        Checked,
+        IntoChecked,
+    };
+    use crate::checked_transaction::{
+        CheckError,
+        CheckPolicy,
+        NonRetryableFreeBalances,
+    };
+    use fuel_tx::{
+        Cacheable,
+        ConsensusParameters,
+        FormatValidityChecks,
+        Upload,
+    };
+    use fuel_types::BlockHeight;
+
+    /// Metdata produced by checking [`fuel_tx::Upload`].
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct CheckedMetadata {
+        /// See [`NonRetryableFreeBalances`].
+        pub free_balances: NonRetryableFreeBalances,
+        /// The block height this tx was verified with
+        pub block_height: BlockHeight,
+    }
+
+    impl IntoChecked for Upload {
+        type Metadata = CheckedMetadata;
+
+        fn into_checked_basic_with_policy(
+            mut self,
+            block_height: BlockHeight,
+            consensus_params: &ConsensusParameters,
+            policy: CheckPolicy,
+        ) -> Result<Checked<Self>, CheckError> {
+            let chain_id = consensus_params.chain_id();
+            self.precompute(&chain_id)?;
+            self.check_without_signatures(block_height, consensus_params)?;
+
+            // validate fees and compute free balances
+            let AvailableBalances {
+                non_retryable_balances,
+                retryable_balance,
+            } = initial_free_balances(&self, consensus_params.base_asset_id(), &policy)?;
+            debug_assert_eq!(
+                retryable_balance, 0,
+                "The `check_without_signatures` should return `TransactionUploadMessageData` above"
+            );
+
+            let metadata = CheckedMetadata {
+                free_balances: NonRetryableFreeBalances(non_retryable_balances),
+                block_height,
+            };
+
+            Ok(Checked::basic(self, metadata, policy))
+        }
+    }
+}
+
+/// For [`fuel_tx::Blob`]
+pub mod blob {
+    use super::super::{
+        balances::{
+            initial_free_balances,
+            AvailableBalances,
+        },
+        Checked,
+        IntoChecked,
+    };
+    use crate::checked_transaction::{
+        CheckError,
+        CheckPolicy,
+        NonRetryableFreeBalances,
+    };
+    use fuel_tx::{
+        Blob,
+        Cacheable,
+        ConsensusParameters,
+        FormatValidityChecks,
+    };
+    use fuel_types::BlockHeight;
+
+    /// Metdata produced by checking [`fuel_tx::Blob`].
+    #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+    pub struct CheckedMetadata {
+        /// See [`NonRetryableFreeBalances`].
+        pub free_balances: NonRetryableFreeBalances,
+        /// The block height this tx was verified with
+        pub block_height: BlockHeight,
+    }
+
+    impl IntoChecked for Blob {
+        type Metadata = CheckedMetadata;
+
+        fn into_checked_basic_with_policy(
+            mut self,
+            block_height: BlockHeight,
+            consensus_params: &ConsensusParameters,
+            policy: CheckPolicy,
+        ) -> Result<Checked<Self>, CheckError> {
+            let chain_id = consensus_params.chain_id();
+            self.precompute(&chain_id)?;
+            self.check_without_signatures(block_height, consensus_params)?;
+
+            // validate fees and compute free balances
+            let AvailableBalances {
+                non_retryable_balances,
+                retryable_balance,
+            } = initial_free_balances(&self, consensus_params.base_asset_id(), &policy)?;
+            debug_assert_eq!(
+                retryable_balance, 0,
+                "The `check_without_signatures` should return `TransactionBlobMessageData` above"
+            );
+
+            let metadata = CheckedMetadata {
+                free_balances: NonRetryableFreeBalances(non_retryable_balances),
+                block_height,
+            };
+
+            Ok(Checked::basic(self, metadata, policy))
         }
     }
 }
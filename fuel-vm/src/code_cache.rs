@@ -0,0 +1,247 @@
+//! Cache of the contract codes shared across the executions.
+
+use alloc::sync::Arc;
+use core::fmt;
+use fuel_tx::Contract;
+use fuel_types::ContractId;
+
+/// Cache of the contract codes, consulted by `CALL`, `LDC`, `CCP` and `CSIZ` before
+/// reading the code from the storage.
+///
+/// A contract id commits to the code of the contract, so the cached code can't get
+/// stale. The interpreter only uses the cached code of a contract existing in its
+/// storage, so a cache may outlive the reverts of the storage and be shared between
+/// storages.
+pub trait ContractCodeCache: Send + Sync {
+    /// The cached code of the `contract`.
+    fn get(&self, contract: &ContractId) -> Option<Arc<Contract>>;
+
+    /// Cache the `code` of the `contract`.
+    fn insert(&self, contract: ContractId, code: Arc<Contract>);
+
+    /// Remove the `contract` from the cache.
+    fn invalidate(&self, contract: &ContractId);
+}
+
+/// The cache installed into the interpreter.
+#[derive(Default, Clone)]
+pub(crate) struct CodeCache(Option<Arc<dyn ContractCodeCache>>);
+
+impl CodeCache {
+    pub(crate) fn set(&mut self, cache: Arc<dyn ContractCodeCache>) {
+        self.0 = Some(cache);
+    }
+
+    pub(crate) fn get(&self, contract: &ContractId) -> Option<Arc<Contract>> {
+        self.0.as_ref()?.get(contract)
+    }
+
+    /// Cache the code returned by `code`, which is only called if the cache is set.
+    pub(crate) fn insert_with<F>(&self, contract: ContractId, code: F)
+    where
+        F: FnOnce() -> Contract,
+    {
+        if let Some(cache) = &self.0 {
+            cache.insert(contract, Arc::new(code()));
+        }
+    }
+}
+
+impl fmt::Debug for CodeCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => write!(f, "CodeCache(enabled)"),
+            None => write!(f, "CodeCache(disabled)"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+pub use lru::LruContractCodeCache;
+
+#[cfg(feature = "std")]
+mod lru {
+    use super::ContractCodeCache;
+
+    use alloc::{
+        collections::BTreeMap,
+        sync::Arc,
+    };
+    use fuel_tx::Contract;
+    use fuel_types::ContractId;
+    use hashbrown::HashMap;
+    use std::sync::{
+        Mutex,
+        MutexGuard,
+    };
+
+    /// Contract code cache bounded by the total size of the cached codes, evicting
+    /// the least recently used codes first.
+    ///
+    /// The clones share the cached codes, so a clone can be installed into every
+    /// interpreter executing the transactions of a block.
+    #[derive(Debug, Clone)]
+    pub struct LruContractCodeCache {
+        max_bytes: usize,
+        state: Arc<Mutex<State>>,
+    }
+
+    #[derive(Debug, Default)]
+    struct State {
+        entries: HashMap<ContractId, (Arc<Contract>, u64)>,
+        /// The cached contracts by the tick of their latest use.
+        recency: BTreeMap<u64, ContractId>,
+        bytes: usize,
+        tick: u64,
+    }
+
+    impl State {
+        fn touch(&mut self, contract: &ContractId) -> Option<Arc<Contract>> {
+            self.tick = self.tick.wrapping_add(1);
+            let tick = self.tick;
+            let (code, last_used) = self.entries.get_mut(contract)?;
+            self.recency.remove(last_used);
+            self.recency.insert(tick, *contract);
+            *last_used = tick;
+            Some(code.clone())
+        }
+
+        fn remove(&mut self, contract: &ContractId) {
+            if let Some((code, last_used)) = self.entries.remove(contract) {
+                self.recency.remove(&last_used);
+                self.bytes = self.bytes.saturating_sub(code.as_ref().len());
+            }
+        }
+    }
+
+    impl LruContractCodeCache {
+        /// Default maximum total size of the cached codes.
+        pub const DEFAULT_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+        /// Create a new empty cache holding at most `max_bytes` of contract codes.
+        pub fn new(max_bytes: usize) -> Self {
+            Self {
+                max_bytes,
+                state: Default::default(),
+            }
+        }
+
+        /// Number of cached contracts.
+        pub fn len(&self) -> usize {
+            self.lock().entries.len()
+        }
+
+        /// Returns `true` if no contract is cached.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Total size of the cached codes.
+        pub fn bytes(&self) -> usize {
+            self.lock().bytes
+        }
+
+        fn lock(&self) -> MutexGuard<'_, State> {
+            self.state.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    impl Default for LruContractCodeCache {
+        fn default() -> Self {
+            Self::new(Self::DEFAULT_MAX_BYTES)
+        }
+    }
+
+    impl ContractCodeCache for LruContractCodeCache {
+        fn get(&self, contract: &ContractId) -> Option<Arc<Contract>> {
+            self.lock().touch(contract)
+        }
+
+        fn insert(&self, contract: ContractId, code: Arc<Contract>) {
+            let len = code.as_ref().len();
+            if len > self.max_bytes {
+                return
+            }
+
+            let mut state = self.lock();
+            state.remove(&contract);
+            while state.bytes.saturating_add(len) > self.max_bytes {
+                let Some((_, evicted)) = state.recency.pop_first() else {
+                    break
+                };
+                state.remove(&evicted);
+            }
+
+            state.tick = state.tick.wrapping_add(1);
+            let tick = state.tick;
+            state.entries.insert(contract, (code, tick));
+            state.recency.insert(tick, contract);
+            state.bytes = state.bytes.saturating_add(len);
+        }
+
+        fn invalidate(&self, contract: &ContractId) {
+            self.lock().remove(contract);
+        }
+    }
+
+    #[cfg(test)]
+    #[allow(non_snake_case)]
+    mod tests {
+        use super::*;
+
+        fn code(len: usize) -> Arc<Contract> {
+            Arc::new(Contract::from(vec![0u8; len]))
+        }
+
+        #[test]
+        fn insert__evicts_least_recently_used_code() {
+            // Given
+            let cache = LruContractCodeCache::new(100);
+            let (a, b, c) = (
+                ContractId::from([1; 32]),
+                ContractId::from([2; 32]),
+                ContractId::from([3; 32]),
+            );
+            cache.insert(a, code(40));
+            cache.insert(b, code(40));
+            cache.get(&a);
+
+            // When
+            cache.insert(c, code(40));
+
+            // Then
+            assert!(cache.get(&a).is_some());
+            assert!(cache.get(&b).is_none());
+            assert!(cache.get(&c).is_some());
+            assert_eq!(cache.bytes(), 80);
+        }
+
+        #[test]
+        fn insert__skips_code_larger_than_the_cache() {
+            // Given
+            let cache = LruContractCodeCache::new(100);
+            let contract = ContractId::from([1; 32]);
+
+            // When
+            cache.insert(contract, code(101));
+
+            // Then
+            assert!(cache.is_empty());
+        }
+
+        #[test]
+        fn invalidate__removes_the_code() {
+            // Given
+            let cache = LruContractCodeCache::new(100);
+            let contract = ContractId::from([1; 32]);
+            cache.insert(contract, code(40));
+
+            // When
+            cache.invalidate(&contract);
+
+            // Then
+            assert!(cache.get(&contract).is_none());
+            assert_eq!(cache.bytes(), 0);
+        }
+    }
+}
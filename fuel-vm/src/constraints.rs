@@ -13,10 +13,7 @@ use fuel_types::ContractId;
 #[cfg(test)]
 use fuel_types::canonical::Deserialize;
 
-use crate::{
-    consts::MEM_SIZE,
-    prelude::MemoryRange,
-};
+use crate::prelude::MemoryRange;
 
 pub mod reg_key;
 
@@ -42,12 +39,12 @@ impl<T> CheckedMemValue<T> {
     }
 
     /// Try to read a value of type `T` from memory.
-    pub fn try_from(self, memory: &[u8; MEM_SIZE]) -> Result<T, PanicReason>
+    pub fn try_from(self, memory: &[u8]) -> Result<T, PanicReason>
     where
         T: for<'a> TryFrom<&'a [u8]>,
         PanicReason: for<'a> From<<T as TryFrom<&'a [u8]>>::Error>,
     {
-        Ok(T::try_from(&memory[self.0.usizes()])?)
+        Ok(T::try_from(self.0.read(memory)?)?)
     }
 
     /// The start of the range.
@@ -62,7 +59,7 @@ impl<T> CheckedMemValue<T> {
 
     #[cfg(test)]
     /// Inspect a value of type `T` from memory.
-    pub fn inspect(self, memory: &[u8; MEM_SIZE]) -> T
+    pub fn inspect(self, memory: &[u8]) -> T
     where
         T: Deserialize,
     {
@@ -77,18 +74,20 @@ impl<const LEN: usize> CheckedMemConstLen<LEN> {
         Ok(Self(MemoryRange::new_const::<_, LEN>(address)?))
     }
 
-    /// Get the memory slice for this range.
-    pub fn read(self, memory: &[u8; MEM_SIZE]) -> &[u8; LEN] {
-        (&memory[self.0.usizes()]).try_into().expect(
+    /// Get the memory slice for this range. Fails with `MemoryOverflow` if the
+    /// range is outside of the memory.
+    pub fn read(self, memory: &[u8]) -> Result<&[u8; LEN], PanicReason> {
+        Ok(self.0.read(memory)?.try_into().expect(
             "This is always correct as the address and LEN are checked on construction.",
-        )
+        ))
     }
 
-    /// Get the mutable memory slice for this range.
-    pub fn write(self, memory: &mut [u8; MEM_SIZE]) -> &mut [u8; LEN] {
-        (&mut memory[self.0.usizes()]).try_into().expect(
+    /// Get the mutable memory slice for this range. Fails with `MemoryOverflow` if
+    /// the range is outside of the memory.
+    pub fn write(self, memory: &mut [u8]) -> Result<&mut [u8; LEN], PanicReason> {
+        Ok(self.0.write(memory)?.try_into().expect(
             "This is always correct as the address and LEN are checked on construction.",
-        )
+        ))
     }
 }
 
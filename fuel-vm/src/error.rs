@@ -254,6 +254,28 @@ pub enum PredicateVerificationFailed {
     /// The transaction doesn't contain enough gas to evaluate the predicate
     #[display(fmt = "Insufficient gas available for single predicate")]
     OutOfGas,
+    /// The predicate of the input ran out of gas during the estimation.
+    #[display(
+        fmt = "Predicate of the input {index} exceeds the gas available to it {limit}"
+    )]
+    PredicateGasExceeded {
+        /// The index of the input of the predicate.
+        index: usize,
+        /// The gas available to the predicate during the estimation, the lower of
+        /// `max_gas_per_predicate` and `max_gas_per_tx`.
+        limit: Word,
+    },
+    /// The predicate of the input executed too many instructions during the
+    /// estimation, the `max_instructions` limit is exhausted.
+    #[display(
+        fmt = "Predicate of the input {index} exceeds the instruction limit {limit}"
+    )]
+    PredicateInstructionLimitExceeded {
+        /// The index of the input of the predicate.
+        index: usize,
+        /// The maximum number of instructions.
+        limit: Word,
+    },
     /// The predicate owner does not correspond to the predicate code
     #[display(fmt = "Predicate owner invalid, doesn't match code root")]
     InvalidOwner,
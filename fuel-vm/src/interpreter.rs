@@ -3,13 +3,22 @@
 use crate::{
     call::CallFrame,
     checked_transaction::CheckPredicateParams,
+    code_cache::CodeCache,
     constraints::reg_key::*,
     consts::*,
     context::Context,
     error::SimpleResult,
-    state::Debugger,
+    observer::NoopObserver,
+    state::{
+        self,
+        Debugger,
+        MessageOutSummary,
+    },
+};
+use alloc::{
+    collections::BTreeSet,
+    vec::Vec,
 };
-use alloc::vec::Vec;
 use core::{
     mem,
     ops::Index,
@@ -18,6 +27,7 @@ use core::{
 use fuel_asm::{
     Flags,
     PanicReason,
+    RegId,
 };
 use fuel_tx::{
     field::{
@@ -27,6 +37,7 @@ use fuel_tx::{
         ReceiptsRoot,
     },
     output,
+    Blob,
     Chargeable,
     ContractParameters,
     Create,
@@ -38,10 +49,13 @@ use fuel_tx::{
     PredicateParameters,
     Receipt,
     Script,
+    ScriptParameters,
     Transaction,
     TransactionRepr,
     TxParameters,
     UniqueIdentifier,
+    Upgrade,
+    Upload,
     ValidityError,
 };
 use fuel_types::{
@@ -54,11 +68,14 @@ use fuel_types::{
 
 mod alu;
 mod balances;
+mod blob;
 mod blockchain;
+mod call_gas;
 mod constructors;
 pub mod contract;
 mod crypto;
 pub mod diff;
+mod elliptic_curve;
 mod executors;
 mod flow;
 mod gas;
@@ -67,8 +84,10 @@ mod internal;
 mod log;
 mod memory;
 mod metadata;
+mod metrics;
 mod post_execution;
 mod receipts;
+mod snapshot;
 
 mod debug;
 mod ecal;
@@ -79,22 +98,38 @@ use crate::profiler::Profiler;
 use crate::profiler::InstructionLocation;
 
 pub use balances::RuntimeBalances;
+pub use call_gas::CallGas;
 pub use ecal::{
     EcalHandler,
     PredicateErrorEcal,
 };
-pub use memory::MemoryRange;
+pub use gas::OutOfGas;
+pub use memory::{
+    MemoryInstance,
+    MemoryRange,
+};
+pub use metrics::ExecutionMetrics;
+pub use snapshot::{
+    InvalidSnapshot,
+    VmSnapshot,
+};
+
+#[cfg(feature = "serde")]
+pub(crate) use snapshot::serde_registers;
 
 use crate::checked_transaction::{
+    BlobCheckedMetadata,
     CreateCheckedMetadata,
     EstimatePredicates,
     IntoChecked,
     NonRetryableFreeBalances,
     RetryableAmount,
     ScriptCheckedMetadata,
+    UpgradeCheckedMetadata,
+    UploadCheckedMetadata,
 };
 
-use self::memory::Memory;
+pub use self::receipts::ReceiptListener;
 
 #[cfg(feature = "test-helpers")]
 pub use self::receipts::ReceiptsCtx;
@@ -115,9 +150,9 @@ pub struct NotSupportedEcal;
 /// These can be obtained with the help of a [`crate::transactor::Transactor`]
 /// or a client implementation.
 #[derive(Debug, Clone)]
-pub struct Interpreter<S, Tx = (), Ecal = NotSupportedEcal> {
+pub struct Interpreter<S, Tx = (), Ecal = NotSupportedEcal, Observer = NoopObserver> {
     registers: [Word; VM_REGISTER_COUNT],
-    memory: Memory<MEM_SIZE>,
+    memory: MemoryInstance,
     frames: Vec<CallFrame>,
     receipts: ReceiptsCtx,
     tx: Tx,
@@ -127,11 +162,34 @@ pub struct Interpreter<S, Tx = (), Ecal = NotSupportedEcal> {
     context: Context,
     balances: RuntimeBalances,
     profiler: Profiler,
+    #[cfg(feature = "trace")]
+    tracer: crate::tracer::Tracer,
+    metrics: ExecutionMetrics,
+    /// Gas of the contract calls made by the current transaction.
+    call_gas: call_gas::CallGasRecords,
+    code_cache: CodeCache,
     interpreter_params: InterpreterParams,
     /// `PanicContext` after the latest execution. It is consumed by
     /// `append_panic_receipt` and is `PanicContext::None` after consumption.
     panic_context: PanicContext,
+    /// Location of the instruction that caused the latest panic.
+    panic_location: Option<PanicLocation>,
+    /// Number of instructions executed by the current transaction.
+    instructions_executed: u64,
+    /// Gas limit of the current execution.
+    gas_limit: Word,
+    /// Gas used by the predicates of the current transaction, read from its inputs
+    /// before their malleable fields are cleared.
+    predicate_gas_used: Word,
+    /// Whether the dry runs may use the contracts not declared in the transaction
+    /// inputs, see [`Self::allow_undeclared_contracts`].
+    allow_undeclared_contracts: bool,
+    /// Contracts touched by the current transaction. `Some` only if the contracts not
+    /// declared in the transaction inputs are allowed.
+    touched_contracts: Option<BTreeSet<ContractId>>,
     ecal_state: Ecal,
+    /// Observer notified around each executed instruction.
+    observer: Observer,
 }
 
 /// Interpreter parameters
@@ -145,6 +203,12 @@ pub struct InterpreterParams {
     pub max_inputs: u8,
     /// Maximum size of the contract in bytes
     pub contract_max_size: u64,
+    /// Maximum depth of the nested contract calls
+    pub max_call_depth: u64,
+    /// Maximum number of receipts emitted by a script
+    pub max_receipts_count: u64,
+    /// Maximum total size of the receipts emitted by a script, in bytes
+    pub max_receipts_size: u64,
     /// Offset of the transaction data in the memory
     pub tx_offset: usize,
     /// Maximum length of the message data
@@ -155,6 +219,12 @@ pub struct InterpreterParams {
     pub fee_params: FeeParameters,
     /// Base Asset ID
     pub base_asset_id: AssetId,
+    /// Maximum amount of memory addressable by the VM, in bytes. The heap grows
+    /// downwards from this address. Values above [`VM_MAX_RAM`] are clamped to it.
+    pub memory_limit: u64,
+    /// Maximum number of instructions executed per transaction, regardless of the
+    /// gas. `None` means no limit.
+    pub max_instructions: Option<u64>,
 }
 
 impl Default for InterpreterParams {
@@ -164,11 +234,16 @@ impl Default for InterpreterParams {
             gas_costs: Default::default(),
             max_inputs: TxParameters::DEFAULT.max_inputs,
             contract_max_size: ContractParameters::DEFAULT.contract_max_size,
+            max_call_depth: ContractParameters::DEFAULT.max_call_depth,
+            max_receipts_count: ScriptParameters::DEFAULT.max_receipts_count,
+            max_receipts_size: ScriptParameters::DEFAULT.max_receipts_size,
             tx_offset: TxParameters::DEFAULT.tx_offset(),
             max_message_data_length: PredicateParameters::DEFAULT.max_message_data_length,
             chain_id: ChainId::default(),
             fee_params: FeeParameters::default(),
             base_asset_id: Default::default(),
+            memory_limit: VM_MAX_RAM,
+            max_instructions: None,
         }
     }
 }
@@ -182,11 +257,16 @@ impl InterpreterParams {
             gas_costs: params.gas_costs,
             max_inputs: params.max_inputs,
             contract_max_size: params.contract_max_size,
+            max_call_depth: params.max_call_depth,
+            max_receipts_count: params.max_receipts_count,
+            max_receipts_size: params.max_receipts_size,
             tx_offset: params.tx_offset,
             max_message_data_length: params.max_message_data_length,
             chain_id: params.chain_id,
             fee_params: params.fee_params,
             base_asset_id: params.base_asset_id,
+            memory_limit: VM_MAX_RAM,
+            max_instructions: params.max_instructions,
         }
     }
 }
@@ -196,6 +276,7 @@ impl InterpreterParams {
 // TODO: Move this enum into `fuel-tx` and use it inside of the `Receipt::Panic` as meta
 //  information. Maybe better to have `Vec<PanicContext>` to provide more information.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum PanicContext {
     /// No additional information.
     None,
@@ -203,36 +284,200 @@ pub(crate) enum PanicContext {
     ContractId(ContractId),
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
+/// Location of the instruction that caused a panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PanicLocation {
+    contract: Option<ContractId>,
+    pc: Word,
+    is: Word,
+}
+
+impl PanicLocation {
+    /// Create a new location of the instruction at `pc`, relative to the start of the
+    /// code `is` of the `contract`.
+    pub const fn new(contract: Option<ContractId>, pc: Word, is: Word) -> Self {
+        Self { contract, pc, is }
+    }
+
+    /// The contract executing the instruction, or `None` for the script and the
+    /// predicates.
+    pub const fn contract(&self) -> Option<&ContractId> {
+        self.contract.as_ref()
+    }
+
+    /// Offset of the instruction from the start of the code.
+    pub const fn pc(&self) -> Word {
+        self.pc
+    }
+
+    /// Start of the code in the memory, the `$is` register.
+    pub const fn is(&self) -> Word {
+        self.is
+    }
+
+    /// Address of the instruction in the memory.
+    pub const fn absolute_pc(&self) -> Word {
+        self.is.saturating_add(self.pc)
+    }
+}
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
     /// Returns the current state of the VM memory
     pub fn memory(&self) -> &[u8] {
-        self.memory.as_slice()
+        self.memory.as_ref()
     }
 
     /// Returns mutable access to the vm memory
     pub fn memory_mut(&mut self) -> &mut [u8] {
+        self.memory.mark_dirty();
         self.memory.as_mut()
     }
 
+    pub(crate) fn replace_memory(&mut self, memory: MemoryInstance) -> MemoryInstance {
+        mem::replace(&mut self.memory, memory)
+    }
+
+    pub(crate) fn replace_storage(&mut self, storage: S) -> S {
+        mem::replace(&mut self.storage, storage)
+    }
+
+    pub(crate) fn set_gas_price(&mut self, gas_price: Word) -> Word {
+        mem::replace(&mut self.interpreter_params.gas_price, gas_price)
+    }
+
+    /// Sets the gas costs charged for the instructions of the next executions,
+    /// returning the previous ones.
+    ///
+    /// The fee of a transaction is computed with the gas costs passed to
+    /// [`Checked::into_ready`](crate::checked_transaction::Checked::into_ready), so
+    /// a `Ready` transaction keeps its fee; only the gas charged during the
+    /// execution changes.
+    pub fn set_gas_costs(&mut self, gas_costs: GasCosts) -> GasCosts {
+        mem::replace(&mut self.interpreter_params.gas_costs, gas_costs)
+    }
+
     /// Returns the current state of the registers
-    pub const fn registers(&self) -> &[Word] {
+    pub const fn registers(&self) -> &[Word; VM_REGISTER_COUNT] {
         &self.registers
     }
 
+    /// Returns the current value of the register `reg`.
+    ///
+    /// Together with [`Self::memory_range`], it allows to inspect the VM during the
+    /// execution, e.g. from a breakpoint, or after it.
+    ///
+    /// ```
+    /// use fuel_asm::{
+    ///     op,
+    ///     RegId,
+    /// };
+    /// use fuel_vm::{
+    ///     interpreter::InterpreterParams,
+    ///     prelude::*,
+    /// };
+    ///
+    /// let consensus_params = ConsensusParameters::standard();
+    /// let script = [
+    ///     op::movi(0x10, 42),
+    ///     op::movi(0x11, 8),
+    ///     op::aloc(0x11),
+    ///     op::sw(RegId::HP, 0x10, 0),
+    ///     op::ret(RegId::ONE),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let tx = TransactionBuilder::script(script, vec![])
+    ///     .script_gas_limit(1_000_000)
+    ///     .add_random_fee_input()
+    ///     .finalize()
+    ///     .into_checked(Default::default(), &consensus_params)
+    ///     .expect("failed to check the transaction");
+    ///
+    /// let mut transactor = Transactor::<_, Script>::new(
+    ///     MemoryStorage::default(),
+    ///     InterpreterParams::new(0, &consensus_params),
+    /// );
+    /// transactor.transact(tx);
+    ///
+    /// let vm = transactor.interpreter();
+    /// assert_eq!(vm.register(RegId::new(0x10)), 42);
+    /// let heap = vm.register(RegId::HP);
+    /// assert_eq!(vm.memory_range(heap, 8), Ok(&42u64.to_be_bytes()[..]));
+    /// ```
+    pub fn register(&self, reg: RegId) -> Word {
+        self.registers[reg]
+    }
+
     /// Returns mutable access to the registers
     pub fn registers_mut(&mut self) -> &mut [Word] {
         &mut self.registers
     }
 
-    pub(crate) fn call_stack(&self) -> &[CallFrame] {
+    /// Returns the address of the next instruction to be executed
+    pub fn pc(&self) -> Word {
+        self.registers[RegId::PC]
+    }
+
+    /// Returns the call frames of the contracts being executed, the innermost last.
+    pub fn call_stack(&self) -> &[CallFrame] {
         self.frames.as_slice()
     }
 
+    /// Returns the context of the execution.
+    pub const fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Returns the instruction observer.
+    pub const fn observer(&self) -> &Observer {
+        &self.observer
+    }
+
+    /// Returns mutable access to the instruction observer.
+    pub fn observer_mut(&mut self) -> &mut Observer {
+        &mut self.observer
+    }
+
+    /// Location of the instruction that caused the latest panic of the current
+    /// transaction.
+    pub const fn panic_location(&self) -> Option<&PanicLocation> {
+        self.panic_location.as_ref()
+    }
+
+    /// Number of instructions executed by the current transaction.
+    pub const fn instructions_executed(&self) -> u64 {
+        self.instructions_executed
+    }
+
+    /// Contracts touched by the last dry run, declared in its inputs or not. Always
+    /// empty unless [`Self::allow_undeclared_contracts`] is enabled, and emptied by
+    /// [`Self::transact`].
+    pub fn touched_contracts(&self) -> impl Iterator<Item = &ContractId> + '_ {
+        self.touched_contracts.iter().flatten()
+    }
+
     /// Debug handler
     pub const fn debugger(&self) -> &Debugger {
         &self.debugger
     }
 
+    /// Storage accesses performed by the current transaction.
+    pub const fn metrics(&self) -> &ExecutionMetrics {
+        &self.metrics
+    }
+
+    /// Gas forwarded to and consumed by the contract calls of the current
+    /// transaction, as a tree of the calls made by the script.
+    ///
+    /// The gas consumed by a call excludes the gas consumed by its nested calls.
+    /// The calls that didn't return, like after a panic, consumed all the gas they
+    /// didn't give back to their caller.
+    pub fn call_gas_breakdown(&self) -> Vec<CallGas> {
+        self.call_gas
+            .breakdown(&self.frames, self.registers[RegId::CGAS])
+    }
+
     /// The current transaction.
     pub fn transaction(&self) -> &Tx {
         &self.tx
@@ -273,6 +518,11 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
         self.interpreter_params.contract_max_size
     }
 
+    /// Get max_call_depth value
+    pub fn max_call_depth(&self) -> u64 {
+        self.interpreter_params.max_call_depth
+    }
+
     /// Get tx_offset value
     pub fn tx_offset(&self) -> usize {
         self.interpreter_params.tx_offset
@@ -283,16 +533,32 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
         self.interpreter_params.max_message_data_length
     }
 
+    /// Get the maximum amount of memory addressable by the VM
+    pub fn memory_limit(&self) -> u64 {
+        self.interpreter_params.memory_limit.min(VM_MAX_RAM)
+    }
+
     /// Get the chain id
     pub fn chain_id(&self) -> ChainId {
         self.interpreter_params.chain_id
     }
 
-    /// Receipts generated by a transaction execution.
+    /// Receipts generated by a transaction execution. Empty if the receipts are
+    /// discarded after their delivery to the listener, see
+    /// [`Self::with_receipt_listener`].
     pub fn receipts(&self) -> &[Receipt] {
         self.receipts.as_ref().as_slice()
     }
 
+    /// The L2→L1 messages sent by the `SMO` instructions executed so far, or none if
+    /// the execution reverted.
+    ///
+    /// The messages are decoded from the receipts, so they aren't available if the
+    /// receipts are discarded, see [`Self::with_receipt_listener`].
+    pub fn outgoing_messages(&self) -> impl Iterator<Item = MessageOutSummary<'_>> {
+        state::outgoing_messages(self.receipts())
+    }
+
     /// Compute current receipts root
     pub fn compute_receipts_root(&self) -> Bytes32 {
         self.receipts.root()
@@ -313,6 +579,40 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
     pub const fn profiler(&self) -> &Profiler {
         &self.profiler
     }
+
+    /// Gas used by the executed instructions, per opcode and contract
+    #[cfg(feature = "profile-gas")]
+    pub fn gas_profile(&self) -> crate::profiler::GasProfile {
+        self.profiler.data().gas().profile()
+    }
+
+    /// Record the execution of the `opcode` at the current location
+    #[cfg(feature = "profile-gas")]
+    pub(crate) fn profile_execution(&mut self, opcode: fuel_asm::Opcode) {
+        let location = current_location(
+            self.contract_id(),
+            self.registers.pc(),
+            self.registers.is(),
+        );
+        self.profiler.add_execution(location, opcode);
+    }
+
+    /// The contract and the offset in its code of the current instruction,
+    /// attributing the code loaded by `LDC` to the loaded contract
+    #[cfg(feature = "profile-coverage")]
+    pub(crate) fn coverage_location(&self) -> (Option<ContractId>, Word) {
+        let pc = self.registers[RegId::PC];
+        match self
+            .profiler
+            .loaded_code_location(self.registers[RegId::FP], pc)
+        {
+            Some((contract, offset)) => (Some(contract), offset),
+            None => (
+                self.contract_id(),
+                pc.saturating_sub(self.registers[RegId::IS]),
+            ),
+        }
+    }
 }
 
 pub(crate) fn flags(flag: Reg<FLAG>) -> Flags {
@@ -336,13 +636,13 @@ fn current_location(
     InstructionLocation::new(current_contract, *pc - *is)
 }
 
-impl<S, Tx, Ecal> AsRef<S> for Interpreter<S, Tx, Ecal> {
+impl<S, Tx, Ecal, Observer> AsRef<S> for Interpreter<S, Tx, Ecal, Observer> {
     fn as_ref(&self) -> &S {
         &self.storage
     }
 }
 
-impl<S, Tx, Ecal> AsMut<S> for Interpreter<S, Tx, Ecal> {
+impl<S, Tx, Ecal, Observer> AsMut<S> for Interpreter<S, Tx, Ecal, Observer> {
     fn as_mut(&mut self) -> &mut S {
         &mut self.storage
     }
@@ -489,6 +789,10 @@ pub trait ExecutableTransaction:
 
     /// Prepares the transaction for execution.
     fn prepare_init_execute(&mut self);
+
+    /// Prepares the transaction for predicate verification and estimation, see
+    /// [`fuel_tx::Transaction::prepare_predicate_context`].
+    fn prepare_init_predicate(&mut self);
 }
 
 impl ExecutableTransaction for Create {
@@ -513,6 +817,8 @@ impl ExecutableTransaction for Create {
     }
 
     fn prepare_init_execute(&mut self) {}
+
+    fn prepare_init_predicate(&mut self) {}
 }
 
 impl ExecutableTransaction for Script {
@@ -545,10 +851,99 @@ impl ExecutableTransaction for Script {
             .iter_mut()
             .for_each(Output::prepare_init_execute);
     }
+
+    fn prepare_init_predicate(&mut self) {
+        Script::prepare_init_predicate(self)
+    }
+}
+
+impl ExecutableTransaction for Upgrade {
+    fn as_script(&self) -> Option<&Script> {
+        None
+    }
+
+    fn as_script_mut(&mut self) -> Option<&mut Script> {
+        None
+    }
+
+    fn as_create(&self) -> Option<&Create> {
+        None
+    }
+
+    fn as_create_mut(&mut self) -> Option<&mut Create> {
+        None
+    }
+
+    fn transaction_type() -> Word {
+        TransactionRepr::Upgrade as Word
+    }
+
+    fn prepare_init_execute(&mut self) {}
+
+    fn prepare_init_predicate(&mut self) {
+        Upgrade::prepare_init_predicate(self)
+    }
+}
+
+impl ExecutableTransaction for Upload {
+    fn as_script(&self) -> Option<&Script> {
+        None
+    }
+
+    fn as_script_mut(&mut self) -> Option<&mut Script> {
+        None
+    }
+
+    fn as_create(&self) -> Option<&Create> {
+        None
+    }
+
+    fn as_create_mut(&mut self) -> Option<&mut Create> {
+        None
+    }
+
+    fn transaction_type() -> Word {
+        TransactionRepr::Upload as Word
+    }
+
+    fn prepare_init_execute(&mut self) {}
+
+    fn prepare_init_predicate(&mut self) {
+        Upload::prepare_init_predicate(self)
+    }
+}
+
+impl ExecutableTransaction for Blob {
+    fn as_script(&self) -> Option<&Script> {
+        None
+    }
+
+    fn as_script_mut(&mut self) -> Option<&mut Script> {
+        None
+    }
+
+    fn as_create(&self) -> Option<&Create> {
+        None
+    }
+
+    fn as_create_mut(&mut self) -> Option<&mut Create> {
+        None
+    }
+
+    fn transaction_type() -> Word {
+        TransactionRepr::Blob as Word
+    }
+
+    fn prepare_init_execute(&mut self) {}
+
+    fn prepare_init_predicate(&mut self) {
+        Blob::prepare_init_predicate(self)
+    }
 }
 
 /// The initial balances of the transaction.
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitialBalances {
     /// See [`NonRetryableFreeBalances`].
     pub non_retryable: NonRetryableFreeBalances,
@@ -580,9 +975,37 @@ impl CheckedMetadata for CreateCheckedMetadata {
     }
 }
 
+impl CheckedMetadata for UpgradeCheckedMetadata {
+    fn balances(&self) -> InitialBalances {
+        InitialBalances {
+            non_retryable: self.free_balances.clone(),
+            retryable: None,
+        }
+    }
+}
+
+impl CheckedMetadata for UploadCheckedMetadata {
+    fn balances(&self) -> InitialBalances {
+        InitialBalances {
+            non_retryable: self.free_balances.clone(),
+            retryable: None,
+        }
+    }
+}
+
+impl CheckedMetadata for BlobCheckedMetadata {
+    fn balances(&self) -> InitialBalances {
+        InitialBalances {
+            non_retryable: self.free_balances.clone(),
+            retryable: None,
+        }
+    }
+}
+
 pub(crate) struct InputContracts<'vm, I> {
     tx_input_contracts: I,
     panic_context: &'vm mut PanicContext,
+    touched_contracts: Option<&'vm mut BTreeSet<ContractId>>,
 }
 
 impl<'vm, I: Iterator<Item = &'vm ContractId>> InputContracts<'vm, I> {
@@ -590,12 +1013,26 @@ impl<'vm, I: Iterator<Item = &'vm ContractId>> InputContracts<'vm, I> {
         Self {
             tx_input_contracts,
             panic_context,
+            touched_contracts: None,
         }
     }
 
+    /// Accepts the contracts not declared in the transaction inputs, recording every
+    /// checked contract into `touched_contracts` instead. `None` keeps the check.
+    pub fn with_touched_contracts(
+        mut self,
+        touched_contracts: Option<&'vm mut BTreeSet<ContractId>>,
+    ) -> Self {
+        self.touched_contracts = touched_contracts;
+        self
+    }
+
     /// Checks that the contract is declared in the transaction inputs.
     pub fn check(&mut self, contract: &ContractId) -> SimpleResult<()> {
-        if !self.tx_input_contracts.any(|input| input == contract) {
+        if let Some(touched_contracts) = self.touched_contracts.as_mut() {
+            touched_contracts.insert(*contract);
+            Ok(())
+        } else if !self.tx_input_contracts.any(|input| input == contract) {
             *self.panic_context = PanicContext::ContractId(*contract);
             Err(PanicReason::ContractNotInInputs.into())
         } else {
@@ -22,7 +22,7 @@ mod wideint;
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
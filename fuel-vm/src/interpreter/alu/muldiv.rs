@@ -15,7 +15,7 @@ use fuel_types::{
     Word,
 };
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
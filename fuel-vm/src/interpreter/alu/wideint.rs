@@ -66,7 +66,7 @@ macro_rules! wideint_ops {
                 $t::from_le_bytes(truncated)
             }
 
-            impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+            impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
             where
                 Tx: ExecutableTransaction,
             {
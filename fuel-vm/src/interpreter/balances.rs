@@ -9,6 +9,7 @@ use crate::{
 };
 
 use fuel_asm::{
+    PanicReason,
     RegId,
     Word,
 };
@@ -23,6 +24,7 @@ use hashbrown::HashMap;
 use super::MemoryRange;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct Balance {
     value: Word,
     offset: usize,
@@ -58,6 +60,7 @@ impl Balance {
 
 /// Structure to encapsulate asset balances for VM runtime
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RuntimeBalances {
     state: HashMap<AssetId, Balance>,
 }
@@ -108,9 +111,17 @@ impl RuntimeBalances {
         self.state.get(asset).map(Balance::value)
     }
 
+    /// Iterate over the balances, ordered by the asset id.
+    pub fn iter(&self) -> impl Iterator<Item = (AssetId, Word)> + '_ {
+        self.state
+            .iter()
+            .map(|(asset, balance)| (*asset, balance.value()))
+            .sorted_by_key(|(asset, _)| *asset)
+    }
+
     fn set_memory_balance_inner(
         balance: &Balance,
-        memory: &mut [u8; MEM_SIZE],
+        memory: &mut [u8],
     ) -> SimpleResult<Word> {
         let value = balance.value();
         let offset = balance.offset();
@@ -118,7 +129,7 @@ impl RuntimeBalances {
         let offset = offset + AssetId::LEN;
         let range = MemoryRange::new_const::<_, WORD_SIZE>(offset)?;
 
-        range.write(memory).copy_from_slice(&value.to_be_bytes());
+        range.write(memory)?.copy_from_slice(&value.to_be_bytes());
 
         Ok(value)
     }
@@ -133,7 +144,7 @@ impl RuntimeBalances {
     /// ordered, as in the protocol.
     pub fn checked_balance_add(
         &mut self,
-        memory: &mut [u8; MEM_SIZE],
+        memory: &mut [u8],
         asset: &AssetId,
         value: Word,
     ) -> Option<Word> {
@@ -148,7 +159,7 @@ impl RuntimeBalances {
     /// appropriate offset
     pub fn checked_balance_sub(
         &mut self,
-        memory: &mut [u8; MEM_SIZE],
+        memory: &mut [u8],
         asset: &AssetId,
         value: Word,
     ) -> Option<Word> {
@@ -160,27 +171,37 @@ impl RuntimeBalances {
     }
 
     /// Write all assets into the VM memory.
-    pub fn to_vm<S, Tx, Ecal>(self, vm: &mut Interpreter<S, Tx, Ecal>)
+    ///
+    /// Fails with `MemoryOverflow` if the balances don't fit below the memory limit.
+    pub fn to_vm<S, Tx, Ecal, Observer>(
+        self,
+        vm: &mut Interpreter<S, Tx, Ecal, Observer>,
+    ) -> Result<(), PanicReason>
     where
         Tx: ExecutableTransaction,
     {
         let len = (vm.max_inputs() as usize * (AssetId::LEN + WORD_SIZE)) as Word;
 
         vm.registers[RegId::SP] += len;
-        vm.reserve_stack(len).expect(
-            "consensus parameters won't allow stack overflow for VM initialization",
-        );
+        vm.reserve_stack(len)?;
 
-        self.state.iter().for_each(|(asset, balance)| {
+        for (asset, balance) in self.state.iter() {
             let value = balance.value();
-            let ofs = balance.offset();
-
-            vm.memory[ofs..ofs + AssetId::LEN].copy_from_slice(asset.as_ref());
-            vm.memory[ofs + AssetId::LEN..ofs + AssetId::LEN + WORD_SIZE]
+            let (asset_range, value_range) =
+                MemoryRange::new(balance.offset(), AssetId::LEN + WORD_SIZE)?
+                    .split_at_offset(AssetId::LEN);
+
+            asset_range
+                .write(vm.memory.as_mut())?
+                .copy_from_slice(asset.as_ref());
+            value_range
+                .write(vm.memory.as_mut())?
                 .copy_from_slice(&value.to_be_bytes());
-        });
+        }
 
         vm.balances = self;
+
+        Ok(())
     }
 }
 
@@ -222,6 +243,7 @@ fn writes_to_memory_correctly() {
 
     let rng = &mut StdRng::seed_from_u64(2322u64);
     let mut interpreter = Interpreter::<_, Script>::without_storage();
+    interpreter.registers_mut()[RegId::HP] = interpreter.memory_limit();
 
     let base = AssetId::zeroed();
     let base_balance = 950;
@@ -242,7 +264,8 @@ fn writes_to_memory_correctly() {
 
     RuntimeBalances::try_from_iter(balances)
         .expect("failed to generate balances")
-        .to_vm(&mut interpreter);
+        .to_vm(&mut interpreter)
+        .expect("failed to write balances");
 
     let memory = interpreter.memory();
     assets_sorted
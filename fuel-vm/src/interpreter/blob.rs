@@ -0,0 +1,100 @@
+//! The instructions reading the blobs posted by [`fuel_tx::Blob`] transactions.
+
+use super::{
+    internal::inc_pc,
+    memory::{
+        copy_from_slice_zero_fill_noownerchecks,
+        read_bytes,
+    },
+    Interpreter,
+    MemoryRange,
+};
+use crate::{
+    constraints::reg_key::*,
+    error::{
+        IoResult,
+        RuntimeError,
+    },
+    storage::InterpreterStorage,
+};
+
+use fuel_asm::PanicReason;
+use fuel_types::{
+    BlobId,
+    RegisterId,
+    Word,
+};
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
+where
+    S: InterpreterStorage,
+{
+    /// `BSIZ`: sets `ra` to the size of the blob with the id at `blob_id_ptr`.
+    pub(crate) fn blob_size(
+        &mut self,
+        ra: RegisterId,
+        blob_id_ptr: Word,
+    ) -> IoResult<(), S::DataError> {
+        let gas_cost = self.gas_costs().bsiz;
+        // Charge the `base` before reading the storage, the size is charged once
+        // known.
+        self.gas_charge(gas_cost.base())?;
+
+        let blob_id = BlobId::from(read_bytes(self.memory.as_ref(), blob_id_ptr)?);
+        let size = self
+            .storage
+            .storage_blob_size(&blob_id)
+            .map_err(RuntimeError::Storage)?
+            .ok_or(PanicReason::BlobNotFound)? as Word;
+        self.gas_charge(gas_cost.resolve_without_base(size))?;
+
+        let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
+        w[WriteRegKey::try_from(ra)?] = size;
+
+        Ok(inc_pc(pc)?)
+    }
+
+    /// `BLDD`: copies `len` bytes of the blob with the id at `blob_id_ptr`,
+    /// starting at `offset`, to `dst_ptr`. The bytes past the end of the blob are
+    /// zeroes.
+    pub(crate) fn blob_load_data(
+        &mut self,
+        dst_ptr: Word,
+        blob_id_ptr: Word,
+        offset: Word,
+        len: Word,
+    ) -> IoResult<(), S::DataError> {
+        let gas_cost = self.gas_costs().bldd;
+        self.gas_charge(gas_cost.base())?;
+
+        let offset: usize = offset.try_into().map_err(|_| PanicReason::MemoryOverflow)?;
+        let dst = MemoryRange::new(dst_ptr, len)?;
+        self.ownership_registers().verify_ownership(&dst)?;
+
+        let blob_id = BlobId::from(read_bytes(self.memory.as_ref(), blob_id_ptr)?);
+        let size = self
+            .storage
+            .storage_blob_size(&blob_id)
+            .map_err(RuntimeError::Storage)?
+            .ok_or(PanicReason::BlobNotFound)? as Word;
+        // The whole blob is read from the storage, even for a shorter range, so it is
+        // charged before being read.
+        self.gas_charge(gas_cost.resolve_without_base(size.max(len)))?;
+        let blob = self
+            .storage
+            .read_blob(&blob_id)
+            .map_err(RuntimeError::Storage)?
+            .ok_or(PanicReason::BlobNotFound)?;
+
+        // Owner checks already performed above
+        copy_from_slice_zero_fill_noownerchecks(
+            self.memory.as_mut(),
+            &blob,
+            dst_ptr,
+            offset,
+            len,
+        )?;
+
+        Ok(inc_pc(self.registers.pc_mut())?)
+    }
+}
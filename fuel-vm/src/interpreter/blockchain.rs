@@ -1,5 +1,6 @@
 use crate::{
     call::CallFrame,
+    code_cache::CodeCache,
     constraints::{
         reg_key::*,
         CheckedMemConstLen,
@@ -17,6 +18,7 @@ use crate::{
         contract::{
             balance,
             balance_decrease,
+            cached_code,
             contract_size,
         },
         gas::{
@@ -40,6 +42,7 @@ use crate::{
         },
         receipts::ReceiptsCtx,
         ExecutableTransaction,
+        ExecutionMetrics,
         InputContracts,
         Interpreter,
         MemoryRange,
@@ -83,7 +86,7 @@ mod smo_tests;
 #[cfg(test)]
 mod test;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
@@ -107,6 +110,8 @@ where
         // Charge only for the `base` execution.
         // We will charge for the contracts size in the `load_contract_code`.
         self.gas_charge(gas_cost.base())?;
+        #[cfg(feature = "profile-coverage")]
+        let code_start = self.registers[fuel_asm::RegId::SSP];
         let contract_max_size = self.contract_max_size();
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?
@@ -128,12 +133,15 @@ where
         let input = LoadContractCodeCtx {
             memory: &mut self.memory,
             profiler: &mut self.profiler,
+            metrics: &mut self.metrics,
+            code_cache: &self.code_cache,
             storage: &mut self.storage,
             contract_max_size,
             input_contracts: InputContracts::new(
                 self.tx.input_contracts(),
                 &mut self.panic_context,
-            ),
+            )
+            .with_touched_contracts(self.touched_contracts.as_mut()),
             gas_cost,
             current_contract,
             cgas,
@@ -145,7 +153,20 @@ where
             pc,
             is: is.as_ref(),
         };
-        input.load_contract_code(contract_id_addr, contract_offset, length_unpadded)
+        input.load_contract_code(contract_id_addr, contract_offset, length_unpadded)?;
+
+        #[cfg(feature = "profile-coverage")]
+        if self.profiler.has_coverage_receiver() {
+            let contract = ContractId::from(read_bytes(&self.memory, contract_id_addr)?);
+            self.profiler.on_load_contract_code(
+                self.registers[fuel_asm::RegId::FP],
+                code_start..self.registers[fuel_asm::RegId::SSP],
+                contract,
+                contract_offset,
+            );
+        }
+
+        Ok(())
     }
 
     pub(crate) fn burn(&mut self, a: Word, b: Word) -> IoResult<(), S::DataError> {
@@ -219,9 +240,12 @@ where
             input_contracts: InputContracts::new(
                 self.tx.input_contracts(),
                 &mut self.panic_context,
-            ),
+            )
+            .with_touched_contracts(self.touched_contracts.as_mut()),
             storage: &mut self.storage,
             profiler: &mut self.profiler,
+            metrics: &mut self.metrics,
+            code_cache: &self.code_cache,
             current_contract,
             owner,
             gas_cost,
@@ -283,7 +307,8 @@ where
             input_contracts: InputContracts::new(
                 self.tx.input_contracts(),
                 &mut self.panic_context,
-            ),
+            )
+            .with_touched_contracts(self.touched_contracts.as_mut()),
             current_contract,
             cgas,
             ggas,
@@ -318,10 +343,13 @@ where
             storage: &mut self.storage,
             gas_cost,
             profiler: &mut self.profiler,
+            metrics: &mut self.metrics,
+            code_cache: &self.code_cache,
             input_contracts: InputContracts::new(
                 self.tx.input_contracts(),
                 &mut self.panic_context,
-            ),
+            )
+            .with_touched_contracts(self.touched_contracts.as_mut()),
             current_contract,
             cgas,
             ggas,
@@ -348,7 +376,10 @@ where
             ..
         } = self;
 
-        state_clear_qword(&contract_id?, storage, memory, pc, result, input)
+        state_clear_qword(&contract_id?, storage, memory, pc, result, input)?;
+        self.metrics.record_storage_write(c);
+
+        Ok(())
     }
 
     pub(crate) fn state_read_word(
@@ -381,7 +412,10 @@ where
             result,
             got_result,
             c,
-        )
+        )?;
+        self.metrics.record_storage_read(1);
+
+        Ok(())
     }
 
     pub(crate) fn state_read_qword(
@@ -403,7 +437,10 @@ where
             ..
         } = self;
 
-        state_read_qword(&contract_id?, storage, memory, pc, result, input)
+        state_read_qword(&contract_id?, storage, memory, pc, result, input)?;
+        self.metrics.record_storage_read(d);
+
+        Ok(())
     }
 
     pub(crate) fn state_write_word(
@@ -448,7 +485,10 @@ where
             a,
             exists,
             c,
-        )
+        )?;
+        self.metrics.record_storage_write(1);
+
+        Ok(())
     }
 
     pub(crate) fn state_write_qword(
@@ -488,7 +528,10 @@ where
             pc,
             result,
             input,
-        )
+        )?;
+        self.metrics.record_storage_write(d);
+
+        Ok(())
     }
 
     pub(crate) fn timestamp(
@@ -527,14 +570,19 @@ where
             msg_data_len: c,
             amount_coins_to_send: d,
         };
-        input.message_output()
+        input.message_output()?;
+        self.metrics.record_message_output(c);
+
+        Ok(())
     }
 }
 
 struct LoadContractCodeCtx<'vm, S, I> {
     contract_max_size: u64,
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     profiler: &'vm mut Profiler,
+    metrics: &'vm mut ExecutionMetrics,
+    code_cache: &'vm CodeCache,
     input_contracts: InputContracts<'vm, I>,
     storage: &'vm S,
     current_contract: Option<ContractId>,
@@ -599,8 +647,13 @@ where
         self.input_contracts.check(&contract_id)?;
 
         // Fetch the storage contract
-        let contract = super::contract::contract(self.storage, &contract_id)?;
-        let contract_bytes = contract.as_ref().as_ref();
+        let contract = super::contract::cached_contract(
+            self.storage,
+            self.code_cache,
+            self.metrics,
+            &contract_id,
+        )?;
+        let contract_bytes = contract.as_ref();
         let contract_len = contract_bytes.len();
         let profiler = ProfileGas {
             pc: self.pc.as_ref(),
@@ -640,7 +693,9 @@ where
             )?;
 
             let old_code_size = Word::from_be_bytes(
-                self.memory[fp_code_size.usizes()]
+                fp_code_size
+                    .clone()
+                    .read(self.memory)?
                     .try_into()
                     .expect("`fp_code_size_end` is `WORD_SIZE`"),
             );
@@ -649,7 +704,8 @@ where
                 .checked_add(length as Word)
                 .ok_or(PanicReason::MemoryOverflow)?;
 
-            self.memory[fp_code_size.usizes()]
+            fp_code_size
+                .write(self.memory)?
                 .copy_from_slice(&new_code_size.to_be_bytes());
         }
 
@@ -662,7 +718,7 @@ where
 struct BurnCtx<'vm, S> {
     storage: &'vm mut S,
     context: &'vm Context,
-    memory: &'vm [u8; MEM_SIZE],
+    memory: &'vm [u8],
     receipts: &'vm mut ReceiptsCtx,
     fp: Reg<'vm, FP>,
     pc: RegMut<'vm, PC>,
@@ -677,9 +733,9 @@ where
         let range = internal_contract_bounds(self.context, self.fp)?;
         let sub_id_range = CheckedMemConstLen::<{ Bytes32::LEN }>::new(b)?;
 
-        let sub_id = Bytes32::from_bytes_ref(sub_id_range.read(self.memory));
+        let sub_id = Bytes32::from_bytes_ref(sub_id_range.read(self.memory)?);
 
-        let contract_id = ContractId::from_bytes_ref(range.read(self.memory));
+        let contract_id = ContractId::from_bytes_ref(range.read(self.memory)?);
         let asset_id = contract_id.asset_id(sub_id);
 
         let balance = balance(self.storage, contract_id, &asset_id)?;
@@ -687,15 +743,17 @@ where
             .checked_sub(a)
             .ok_or(PanicReason::NotEnoughBalance)?;
 
+        // The receipt is emitted before the balance changes, so a burn is never
+        // applied without its receipt.
+        let receipt = Receipt::burn(*sub_id, *contract_id, a, *self.pc, *self.is);
+
+        self.receipts.push(receipt)?;
+
         let _ = self
             .storage
             .contract_asset_id_balance_insert(contract_id, &asset_id, balance)
             .map_err(RuntimeError::Storage)?;
 
-        let receipt = Receipt::burn(*sub_id, *contract_id, a, *self.pc, *self.is);
-
-        self.receipts.push(receipt)?;
-
         Ok(inc_pc(self.pc)?)
     }
 }
@@ -703,7 +761,7 @@ where
 struct MintCtx<'vm, S> {
     storage: &'vm mut S,
     context: &'vm Context,
-    memory: &'vm [u8; MEM_SIZE],
+    memory: &'vm [u8],
     profiler: &'vm mut Profiler,
     receipts: &'vm mut ReceiptsCtx,
     new_storage_gas_per_byte: Word,
@@ -722,14 +780,20 @@ where
         let range = internal_contract_bounds(self.context, self.fp)?;
         let sub_id_range = CheckedMemConstLen::<{ Bytes32::LEN }>::new(b)?;
 
-        let sub_id = Bytes32::from_bytes_ref(sub_id_range.read(self.memory));
+        let sub_id = Bytes32::from_bytes_ref(sub_id_range.read(self.memory)?);
 
-        let contract_id = ContractId::from_bytes_ref(range.read(self.memory));
+        let contract_id = ContractId::from_bytes_ref(range.read(self.memory)?);
         let asset_id = contract_id.asset_id(sub_id);
 
         let balance = balance(self.storage, contract_id, &asset_id)?;
         let balance = balance.checked_add(a).ok_or(PanicReason::BalanceOverflow)?;
 
+        // The receipt is emitted before the balance changes, so a mint is never
+        // applied without its receipt.
+        let receipt = Receipt::mint(*sub_id, *contract_id, a, *self.pc, *self.is);
+
+        self.receipts.push(receipt)?;
+
         let old_value = self
             .storage
             .contract_asset_id_balance_insert(contract_id, &asset_id, balance)
@@ -751,19 +815,17 @@ where
             )?;
         }
 
-        let receipt = Receipt::mint(*sub_id, *contract_id, a, *self.pc, *self.is);
-
-        self.receipts.push(receipt)?;
-
         Ok(inc_pc(self.pc)?)
     }
 }
 
 struct CodeCopyCtx<'vm, S, I> {
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     input_contracts: InputContracts<'vm, I>,
     storage: &'vm S,
     profiler: &'vm mut Profiler,
+    metrics: &'vm mut ExecutionMetrics,
+    code_cache: &'vm CodeCache,
     current_contract: Option<ContractId>,
     owner: OwnershipRegisters,
     gas_cost: DependentCost,
@@ -803,8 +865,13 @@ where
 
         self.input_contracts.check(&contract_id)?;
 
-        let contract = super::contract::contract(self.storage, &contract_id)?;
-        let contract_bytes = contract.as_ref().as_ref();
+        let contract = super::contract::cached_contract(
+            self.storage,
+            self.code_cache,
+            self.metrics,
+            &contract_id,
+        )?;
+        let contract_bytes = contract.as_ref();
         let contract_len = contract_bytes.len();
         let profiler = ProfileGas {
             pc: self.pc.as_ref(),
@@ -823,7 +890,7 @@ where
         // Owner checks already performed above
         copy_from_slice_zero_fill_noownerchecks(
             self.memory,
-            contract.as_ref().as_ref(),
+            contract_bytes,
             dst_addr,
             offset,
             length,
@@ -835,7 +902,7 @@ where
 
 pub(crate) fn block_hash<S: InterpreterStorage>(
     storage: &S,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -869,7 +936,7 @@ pub(crate) fn block_height(
 
 pub(crate) fn coinbase<S: InterpreterStorage>(
     storage: &S,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -882,7 +949,7 @@ pub(crate) fn coinbase<S: InterpreterStorage>(
 
 struct CodeRootCtx<'vm, S, I> {
     storage: &'vm S,
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     gas_cost: DependentCost,
     profiler: &'vm mut Profiler,
     input_contracts: InputContracts<'vm, I>,
@@ -902,7 +969,7 @@ impl<'vm, S, I: Iterator<Item = &'vm ContractId>> CodeRootCtx<'vm, S, I> {
         MemoryRange::new(a, Bytes32::LEN)?;
         let contract_id = CheckedMemConstLen::<{ ContractId::LEN }>::new(b)?;
 
-        let contract_id = ContractId::from_bytes_ref(contract_id.read(self.memory));
+        let contract_id = ContractId::from_bytes_ref(contract_id.read(self.memory)?);
 
         self.input_contracts.check(contract_id)?;
 
@@ -936,9 +1003,11 @@ impl<'vm, S, I: Iterator<Item = &'vm ContractId>> CodeRootCtx<'vm, S, I> {
 
 struct CodeSizeCtx<'vm, S, I> {
     storage: &'vm S,
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     gas_cost: DependentCost,
     profiler: &'vm mut Profiler,
+    metrics: &'vm mut ExecutionMetrics,
+    code_cache: &'vm CodeCache,
     input_contracts: InputContracts<'vm, I>,
     current_contract: Option<ContractId>,
     cgas: RegMut<'vm, CGAS>,
@@ -958,11 +1027,21 @@ impl<'vm, S, I: Iterator<Item = &'vm ContractId>> CodeSizeCtx<'vm, S, I> {
     {
         let contract_id = CheckedMemConstLen::<{ ContractId::LEN }>::new(b)?;
 
-        let contract_id = ContractId::from_bytes_ref(contract_id.read(self.memory));
+        let contract_id = ContractId::from_bytes_ref(contract_id.read(self.memory)?);
 
         self.input_contracts.check(contract_id)?;
 
-        let len = contract_size(self.storage, contract_id)? as Word;
+        let len = match cached_code(self.storage, self.code_cache, contract_id)? {
+            Some(code) => {
+                self.metrics.record_contract_code_cache_hit();
+                code.as_ref().as_ref().len() as Word
+            }
+            None => {
+                let len = contract_size(self.storage, contract_id)? as Word;
+                self.metrics.record_contract_code_load(len);
+                len
+            }
+        };
         let profiler = ProfileGas {
             pc: self.pc.as_ref(),
             is: self.is,
@@ -984,7 +1063,7 @@ impl<'vm, S, I: Iterator<Item = &'vm ContractId>> CodeSizeCtx<'vm, S, I> {
 
 pub(crate) struct StateReadWordCtx<'vm, S> {
     pub storage: &'vm mut S,
-    pub memory: &'vm [u8; MEM_SIZE],
+    pub memory: &'vm [u8],
     pub context: &'vm Context,
     pub fp: Reg<'vm, FP>,
     pub pc: RegMut<'vm, PC>,
@@ -1007,7 +1086,7 @@ pub(crate) fn state_read_word<S: InterpreterStorage>(
 
     let contract = internal_contract(context, fp, memory)?;
 
-    let key = Bytes32::from_bytes_ref(key.read(memory));
+    let key = Bytes32::from_bytes_ref(key.read(memory)?);
 
     let value = storage
         .contract_state(contract, key)
@@ -1028,7 +1107,7 @@ pub(crate) fn state_read_word<S: InterpreterStorage>(
 
 pub(crate) struct StateWriteWordCtx<'vm, S> {
     pub storage: &'vm mut S,
-    pub memory: &'vm [u8; MEM_SIZE],
+    pub memory: &'vm [u8],
     pub context: &'vm Context,
     pub profiler: &'vm mut Profiler,
     pub new_storage_gas_per_byte: Word,
@@ -1063,8 +1142,8 @@ pub(crate) fn state_write_word<S: InterpreterStorage>(
     let contract = internal_contract_bounds(context, fp)?;
 
     // Safety: Memory bounds logically verified by the interpreter
-    let contract = ContractId::from_bytes_ref(contract.read(memory));
-    let key = Bytes32::from_bytes_ref(key.read(memory));
+    let contract = ContractId::from_bytes_ref(contract.read(memory)?);
+    let key = Bytes32::from_bytes_ref(key.read(memory)?);
 
     let mut value = Bytes32::zeroed();
     value.as_mut()[..WORD_SIZE].copy_from_slice(&c.to_be_bytes());
@@ -1118,7 +1197,7 @@ where
 {
     base_asset_id: AssetId,
     max_message_data_length: u64,
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     receipts: &'vm mut ReceiptsCtx,
     balances: &'vm mut RuntimeBalances,
     storage: &'vm mut S,
@@ -1172,12 +1251,12 @@ where
 
         let sender = CheckedMemConstLen::<{ Address::LEN }>::new(*self.fp)?;
         let txid = tx_id(self.memory);
-        let msg_data = msg_data_range.read(self.memory).to_vec();
-        let sender = Address::from_bytes_ref(sender.read(self.memory));
+        let msg_data = msg_data_range.read(self.memory)?.to_vec();
+        let sender = Address::from_bytes_ref(sender.read(self.memory)?);
 
         let receipt = Receipt::message_out(
             txid,
-            self.receipts.len() as Word,
+            self.receipts.produced_count() as Word,
             *sender,
             recipient,
             self.amount_coins_to_send,
@@ -1226,12 +1305,12 @@ impl StateReadQWord {
 fn state_read_qword<S: InterpreterStorage>(
     contract_id: &ContractId,
     storage: &S,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     pc: RegMut<PC>,
     result_register: &mut Word,
     input: StateReadQWord,
 ) -> IoResult<(), S::DataError> {
-    let origin_key = Bytes32::from_bytes_ref(input.origin_key_memory_range.read(memory));
+    let origin_key = Bytes32::from_bytes_ref(input.origin_key_memory_range.read(memory)?);
 
     let mut all_set = true;
     let result: Vec<u8> = storage
@@ -1249,7 +1328,10 @@ fn state_read_qword<S: InterpreterStorage>(
 
     *result_register = all_set as Word;
 
-    memory[input.destination_address_memory_range.usizes()].copy_from_slice(&result);
+    input
+        .destination_address_memory_range
+        .write(memory)?
+        .copy_from_slice(&result);
 
     inc_pc(pc)?;
 
@@ -1290,7 +1372,7 @@ impl StateWriteQWord {
 fn state_write_qword<'vm, S: InterpreterStorage>(
     contract_id: &ContractId,
     storage: &mut S,
-    memory: &[u8; MEM_SIZE],
+    memory: &[u8],
     profiler: &'vm mut Profiler,
     new_storage_gas_per_byte: Word,
     current_contract: Option<ContractId>,
@@ -1302,10 +1384,12 @@ fn state_write_qword<'vm, S: InterpreterStorage>(
     input: StateWriteQWord,
 ) -> IoResult<(), S::DataError> {
     let destination_key =
-        Bytes32::from_bytes_ref(input.starting_storage_key_memory_range.read(memory));
+        Bytes32::from_bytes_ref(input.starting_storage_key_memory_range.read(memory)?);
 
-    let values =
-        memory[input.source_address_memory_range.usizes()].chunks_exact(Bytes32::LEN);
+    let values = input
+        .source_address_memory_range
+        .read(memory)?
+        .chunks_exact(Bytes32::LEN);
 
     let unset_count = storage
         .contract_state_insert_range(contract_id, destination_key, values)
@@ -1363,13 +1447,13 @@ impl StateClearQWord {
 fn state_clear_qword<S: InterpreterStorage>(
     contract_id: &ContractId,
     storage: &mut S,
-    memory: &[u8; MEM_SIZE],
+    memory: &[u8],
     pc: RegMut<PC>,
     result_register: &mut Word,
     input: StateClearQWord,
 ) -> IoResult<(), S::DataError> {
     let start_key =
-        Bytes32::from_bytes_ref(input.start_storage_key_memory_range.read(memory));
+        Bytes32::from_bytes_ref(input.start_storage_key_memory_range.read(memory)?);
 
     let all_previously_set = storage
         .contract_state_remove_range(contract_id, start_key, input.num_slots)
@@ -5,10 +5,7 @@ use alloc::vec;
 
 use super::*;
 use crate::{
-    interpreter::{
-        memory::Memory,
-        PanicContext,
-    },
+    interpreter::PanicContext,
     storage::MemoryStorage,
 };
 use fuel_tx::Contract;
@@ -16,7 +13,7 @@ use fuel_tx::Contract;
 #[test]
 fn test_load_contract() -> IoResult<(), Infallible> {
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 4;
     let hp = 2000;
     let mut cgas = 1000;
@@ -50,6 +47,8 @@ fn test_load_contract() -> IoResult<(), Infallible> {
         storage: &storage,
         memory: &mut memory,
         profiler: &mut Profiler::default(),
+        metrics: &mut Default::default(),
+        code_cache: &Default::default(),
         input_contracts: InputContracts::new(input_contracts.iter(), &mut panic_context),
         current_contract: None,
         gas_cost: DependentCost::from_units_per_gas(13, 1),
@@ -73,7 +72,7 @@ fn test_load_contract() -> IoResult<(), Infallible> {
 #[test]
 fn test_code_copy() -> IoResult<(), Infallible> {
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut cgas = 1000;
     let mut ggas = 1000;
     let mut pc = 4;
@@ -104,12 +103,15 @@ fn test_code_copy() -> IoResult<(), Infallible> {
         memory: &mut memory,
         input_contracts: InputContracts::new(input_contracts.iter(), &mut panic_context),
         profiler: &mut Profiler::default(),
+        metrics: &mut Default::default(),
+        code_cache: &Default::default(),
         current_contract: None,
         owner: OwnershipRegisters {
             sp: 1000,
             ssp: 1000,
             hp: 2000,
             prev_hp: VM_MAX_RAM - 1,
+            memory_limit: VM_MAX_RAM,
             context: Context::Call {
                 block_height: Default::default(),
             },
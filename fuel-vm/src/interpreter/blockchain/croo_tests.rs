@@ -1,9 +1,6 @@
 use super::*;
 use crate::{
-    interpreter::{
-        memory::Memory,
-        PanicContext,
-    },
+    interpreter::PanicContext,
     storage::MemoryStorage,
 };
 use fuel_tx::{
@@ -39,6 +36,7 @@ fn initialize_ownership_registers() -> OwnershipRegisters {
         ssp: 1,
         hp: 2000,
         prev_hp: 3000,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -55,7 +53,7 @@ fn test_code_root() {
     let contract_id = new_contract_id();
 
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[0..ContractId::LEN].copy_from_slice(contract_id.as_slice());
 
     let data = alloc::vec![0xffu8; CONTRACT_LEN];
@@ -115,7 +113,7 @@ fn test_code_root_contract_not_found() {
     let contract_id = new_contract_id();
 
     let storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[0..ContractId::LEN].copy_from_slice(contract_id.as_slice());
 
     let gas_cost = GasCosts::default().croo;
@@ -162,7 +160,7 @@ fn test_code_root_contract_not_in_inputs() {
     let contract_id = new_contract_id();
 
     let storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[0..ContractId::LEN].copy_from_slice(contract_id.as_slice());
 
     let gas_cost = GasCosts::default().croo;
@@ -1,9 +1,6 @@
 use alloc::vec;
 
-use crate::{
-    interpreter::memory::Memory,
-    storage::MemoryStorage,
-};
+use crate::storage::MemoryStorage;
 use core::{
     convert::Infallible,
     iter,
@@ -31,7 +28,7 @@ fn test_burn(
     sub_id: [u8; 32],
 ) -> IoResult<(), Infallible> {
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let contract_id = ContractId::from([3u8; 32]);
     memory[0..ContractId::LEN].copy_from_slice(contract_id.as_slice());
     memory[ContractId::LEN..ContractId::LEN + Bytes32::LEN]
@@ -109,7 +106,7 @@ fn test_mint(
     sub_id: [u8; 32],
 ) -> IoResult<(), Infallible> {
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let contract_id = ContractId::from([3u8; 32]);
     memory[0..ContractId::LEN].copy_from_slice(contract_id.as_slice());
     memory[ContractId::LEN..ContractId::LEN + Bytes32::LEN]
@@ -177,12 +174,13 @@ fn test_mint(
 #[test]
 fn test_block_hash() {
     let storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let owner = OwnershipRegisters {
         sp: 1000,
         ssp: 1,
         hp: 2000,
         prev_hp: 3000,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -208,12 +206,13 @@ fn test_block_height() {
 #[test]
 fn test_coinbase() {
     let storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let owner = OwnershipRegisters {
         sp: 1000,
         ssp: 1,
         hp: 2000,
         prev_hp: 3000,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -228,7 +227,7 @@ fn test_coinbase() {
 fn test_code_size() {
     let contract_id = ContractId::new([3u8; ContractId::LEN]);
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[0..ContractId::LEN].copy_from_slice(contract_id.as_slice());
     StorageAsMut::storage::<ContractsRawCode>(&mut storage)
         .write(&ContractId::from([3u8; 32]), &[1u8; 100])
@@ -244,6 +243,8 @@ fn test_code_size() {
         memory: &mut memory,
         gas_cost: DependentCost::free(),
         profiler: &mut Profiler::default(),
+        metrics: &mut Default::default(),
+        code_cache: &Default::default(),
         input_contracts: InputContracts::new(input_contract.iter(), &mut panic_context),
         current_contract: None,
         cgas: RegMut::new(&mut cgas),
@@ -263,6 +264,8 @@ fn test_code_size() {
         gas_cost: DependentCost::free(),
         input_contracts: InputContracts::new(input_contract.iter(), &mut panic_context),
         profiler: &mut Profiler::default(),
+        metrics: &mut Default::default(),
+        code_cache: &Default::default(),
         current_contract: None,
         cgas: RegMut::new(&mut cgas),
         ggas: RegMut::new(&mut ggas),
@@ -280,6 +283,8 @@ fn test_code_size() {
         gas_cost: DependentCost::free(),
         input_contracts: InputContracts::new(iter::empty(), &mut panic_context),
         profiler: &mut Profiler::default(),
+        metrics: &mut Default::default(),
+        code_cache: &Default::default(),
         current_contract: None,
         cgas: RegMut::new(&mut cgas),
         ggas: RegMut::new(&mut ggas),
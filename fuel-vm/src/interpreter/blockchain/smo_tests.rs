@@ -6,10 +6,7 @@ use alloc::{
 };
 
 use crate::{
-    interpreter::{
-        contract::balance as contract_balance,
-        memory::Memory,
-    },
+    interpreter::contract::balance as contract_balance,
     storage::MemoryStorage,
 };
 
@@ -211,7 +208,7 @@ fn test_smo(
     let mut rng = StdRng::seed_from_u64(100);
     let base_asset_id = rng.gen();
 
-    let mut memory: Memory<MEM_SIZE> = vec![0; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![0; MEM_SIZE].into();
     for (offset, bytes) in mem {
         memory[offset..offset + bytes.len()].copy_from_slice(bytes.as_slice());
     }
@@ -11,7 +11,6 @@ use core::{
 
 use crate::{
     context::Context,
-    interpreter::memory::Memory,
     storage::{
         ContractsStateData,
         MemoryStorage,
@@ -25,10 +24,10 @@ mod scwq;
 mod srwq;
 mod swwq;
 
-fn mem(chains: &[&[u8]]) -> Memory<MEM_SIZE> {
+fn mem(chains: &[&[u8]]) -> Box<[u8]> {
     let mut vec: Vec<_> = chains.iter().flat_map(|i| i.iter().copied()).collect();
     vec.resize(MEM_SIZE, 0);
-    vec.try_into().unwrap()
+    vec.into()
 }
 const fn key(k: u8) -> [u8; 32] {
     [
@@ -48,6 +47,7 @@ impl OwnershipRegisters {
             ssp: stack.start,
             hp: heap.start,
             prev_hp: heap.end,
+            memory_limit: VM_MAX_RAM,
             context,
         }
     }
@@ -68,7 +68,7 @@ fn test_state_read_word(
     key: Word,
 ) -> Result<(Word, Word), RuntimeError<Infallible>> {
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[0..ContractId::LEN].copy_from_slice(&[3u8; ContractId::LEN][..]);
     memory[32..64].copy_from_slice(&[4u8; 32][..]);
     let is = 4;
@@ -138,7 +138,7 @@ fn test_state_write_word(
     key: Word,
 ) -> Result<Word, RuntimeError<Infallible>> {
     let mut storage = MemoryStorage::new(Default::default(), Default::default());
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[0..ContractId::LEN].copy_from_slice(&[3u8; ContractId::LEN][..]);
     memory[32..64].copy_from_slice(&[4u8; 32][..]);
     let mut pc = 4;
@@ -14,7 +14,7 @@ use test_case::test_case;
 struct SRWQInput {
     input: StateReadQWord,
     storage_slots: Vec<([u8; 32], ContractsStateData)>,
-    memory: Memory<MEM_SIZE>,
+    memory: Box<[u8]>,
 }
 
 impl StateReadQWord {
@@ -28,6 +28,7 @@ impl StateReadQWord {
             ssp: 0,
             hp: u64::MAX / 2 + 1,
             prev_hp: u64::MAX,
+            memory_limit: VM_MAX_RAM,
             context: crate::context::Context::Call {
                 block_height: Default::default(),
             },
@@ -97,7 +98,7 @@ impl StateReadQWord {
         memory: mem(&[&key(27)]),
     } => (mem(&[&[0; 32], &[6; 32], &[7; 32]]), false)
 )]
-fn test_state_read_qword(input: SRWQInput) -> (Memory<MEM_SIZE>, bool) {
+fn test_state_read_qword(input: SRWQInput) -> (Box<[u8]>, bool) {
     let SRWQInput {
         input,
         storage_slots,
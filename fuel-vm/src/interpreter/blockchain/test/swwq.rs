@@ -18,7 +18,7 @@ use test_case::test_case;
 struct SWWQInput {
     input: StateWriteQWord,
     storage_slots: Vec<([u8; 32], ContractsStateData)>,
-    memory: Memory<MEM_SIZE>,
+    memory: Box<[u8]>,
 }
 
 #[test_case(
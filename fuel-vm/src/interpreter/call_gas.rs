@@ -0,0 +1,128 @@
+//! Gas forwarded to and consumed by the contract calls of an execution.
+
+use alloc::vec::Vec;
+
+use fuel_types::{
+    ContractId,
+    Word,
+};
+
+use crate::call::CallFrame;
+
+/// Gas of a contract call made by the current transaction, and of the calls it
+/// made in turn.
+///
+/// Returned by
+/// [`Interpreter::call_gas_breakdown`](super::Interpreter::call_gas_breakdown).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CallGas {
+    /// The called contract.
+    pub contract_id: ContractId,
+    /// Gas forwarded to the call, the initial `$cgas` of its frame.
+    pub gas_forwarded: Word,
+    /// Gas consumed by the call itself. The gas consumed by its nested calls is
+    /// attributed to them only.
+    pub gas_consumed: Word,
+    /// Calls made by this call, in execution order.
+    pub calls: Vec<CallGas>,
+}
+
+impl CallGas {
+    /// Gas consumed by the call, including its nested calls.
+    pub fn total_gas_consumed(&self) -> Word {
+        self.calls.iter().fold(self.gas_consumed, |total, call| {
+            total.saturating_add(call.total_gas_consumed())
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CallRecord {
+    /// Number of call frames on the stack when the call was made.
+    depth: usize,
+    contract_id: ContractId,
+    gas_forwarded: Word,
+    /// `$cgas` when the call returned, `None` while its frame is on the stack.
+    gas_remaining: Option<Word>,
+}
+
+/// Calls made by the current transaction, in the order they were made.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct CallGasRecords {
+    records: Vec<CallRecord>,
+    /// Indices of the records of the calls whose frames are on the stack, the
+    /// innermost last.
+    open: Vec<usize>,
+}
+
+impl CallGasRecords {
+    pub(crate) fn record_call(&mut self, contract_id: ContractId, gas_forwarded: Word) {
+        self.open.push(self.records.len());
+        self.records.push(CallRecord {
+            depth: self.open.len() - 1,
+            contract_id,
+            gas_forwarded,
+            gas_remaining: None,
+        });
+    }
+
+    pub(crate) fn record_return(&mut self, gas_remaining: Word) {
+        if let Some(index) = self.open.pop() {
+            self.records[index].gas_remaining = Some(gas_remaining);
+        }
+    }
+
+    /// Builds the tree of the calls. The calls still on the stack, like after a
+    /// panic, consume the gas they didn't return: `cgas` for the innermost one,
+    /// which is unwound through the `frames` for the outer ones.
+    pub(crate) fn breakdown(&self, frames: &[CallFrame], cgas: Word) -> Vec<CallGas> {
+        let mut remaining = self
+            .records
+            .iter()
+            .map(|record| record.gas_remaining)
+            .collect::<Vec<_>>();
+        let mut unwound = cgas;
+        for (depth, index) in self.open.iter().enumerate().rev() {
+            remaining[*index] = Some(unwound);
+            let caller_gas = frames.get(depth).map_or(0, CallFrame::context_gas);
+            unwound = unwound.saturating_add(caller_gas);
+        }
+
+        let mut roots = Vec::new();
+        let mut stack: Vec<CallGas> = Vec::new();
+        for (record, remaining) in self.records.iter().zip(remaining) {
+            while stack.len() > record.depth {
+                close_call(&mut stack, &mut roots);
+            }
+            stack.push(CallGas {
+                contract_id: record.contract_id,
+                gas_forwarded: record.gas_forwarded,
+                gas_consumed: record
+                    .gas_forwarded
+                    .saturating_sub(remaining.unwrap_or_default()),
+                calls: Vec::new(),
+            });
+        }
+        while !stack.is_empty() {
+            close_call(&mut stack, &mut roots);
+        }
+
+        roots
+    }
+}
+
+/// Pops the innermost call of the `stack` and removes the gas it consumed from
+/// its caller.
+fn close_call(stack: &mut Vec<CallGas>, roots: &mut Vec<CallGas>) {
+    let Some(call) = stack.pop() else { return };
+    match stack.last_mut() {
+        Some(caller) => {
+            caller.gas_consumed = caller
+                .gas_consumed
+                .saturating_sub(call.total_gas_consumed());
+            caller.calls.push(call);
+        }
+        None => roots.push(call),
+    }
+}
@@ -8,27 +8,37 @@ use super::{
     RuntimeBalances,
 };
 use crate::{
+    code_cache::ContractCodeCache,
     consts::*,
     context::Context,
     interpreter::{
         InterpreterParams,
+        MemoryInstance,
         PanicContext,
+        ReceiptListener,
     },
     state::Debugger,
     storage::MemoryStorage,
 };
 
-use alloc::vec;
+use alloc::{
+    sync::Arc,
+    vec,
+};
 
 #[cfg(feature = "profile-any")]
 use crate::profiler::ProfileReceiver;
 
+#[cfg(feature = "profile-coverage")]
+use crate::profiler::CoverageReceiver;
+
 use crate::profiler::Profiler;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: Default,
     Ecal: Default,
+    Observer: Default,
 {
     /// Create a new interpreter instance out of a storage implementation.
     ///
@@ -40,9 +50,10 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: Default,
+    Observer: Default,
 {
     /// Create a new interpreter instance out of a storage implementation.
     ///
@@ -54,11 +65,53 @@ where
         interpreter_params: InterpreterParams,
         ecal_state: Ecal,
     ) -> Self {
+        Self::with_memory_and_ecal(
+            MemoryInstance::with_limit(interpreter_params.memory_limit),
+            storage,
+            interpreter_params,
+            ecal_state,
+        )
+    }
+
+    /// Create a new interpreter instance executing in the provided `memory`, e.g.
+    /// taken from a [`crate::pool::MemoryPool`].
+    ///
+    /// The memory is zeroed before each execution, and reallocated if its size
+    /// differs from the memory limit of the `interpreter_params`.
+    pub fn with_memory_and_ecal(
+        memory: MemoryInstance,
+        storage: S,
+        interpreter_params: InterpreterParams,
+        ecal_state: Ecal,
+    ) -> Self {
+        Self::with_memory_ecal_and_observer(
+            memory,
+            storage,
+            interpreter_params,
+            ecal_state,
+            Observer::default(),
+        )
+    }
+}
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
+where
+    Tx: Default,
+{
+    /// Create a new interpreter instance executing in the provided `memory` and
+    /// notifying the provided `observer`.
+    pub(crate) fn with_memory_ecal_and_observer(
+        memory: MemoryInstance,
+        storage: S,
+        interpreter_params: InterpreterParams,
+        ecal_state: Ecal,
+        observer: Observer,
+    ) -> Self {
+        let mut memory = memory;
+        memory.resize(interpreter_params.memory_limit);
         Self {
             registers: [0; VM_REGISTER_COUNT],
-            memory: vec![0; MEM_SIZE]
-                .try_into()
-                .expect("Failed to allocate memory"),
+            memory,
             frames: vec![],
             receipts: Default::default(),
             tx: Default::default(),
@@ -68,14 +121,26 @@ where
             context: Context::default(),
             balances: RuntimeBalances::default(),
             profiler: Profiler::default(),
+            #[cfg(feature = "trace")]
+            tracer: Default::default(),
+            metrics: Default::default(),
+            call_gas: Default::default(),
+            code_cache: Default::default(),
             interpreter_params,
             panic_context: PanicContext::None,
+            panic_location: None,
+            instructions_executed: 0,
+            gas_limit: 0,
+            predicate_gas_used: 0,
+            allow_undeclared_contracts: false,
+            touched_contracts: None,
             ecal_state,
+            observer,
         }
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
     /// Sets a profiler for the VM
     #[cfg(feature = "profile-any")]
     pub fn with_profiler<P>(&mut self, receiver: P) -> &mut Self
@@ -85,13 +150,71 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
         self.profiler.set_receiver(alloc::boxed::Box::new(receiver));
         self
     }
+
+    /// Sets a tracer receiving the VM state around every executed instruction
+    #[cfg(feature = "trace")]
+    pub fn with_tracer<T>(&mut self, tracer: T) -> &mut Self
+    where
+        T: crate::tracer::ExecutionTracer + Send + Sync + 'static,
+    {
+        self.tracer.set(alloc::boxed::Box::new(tracer));
+        self
+    }
+
+    /// Sets a listener receiving every receipt as soon as it is created. If
+    /// `discard_receipts` is `true`, the receipts are only delivered to the listener
+    /// and [`Self::receipts`] stays empty, while the receipts root still covers all of
+    /// them.
+    ///
+    /// The listener is shared with the clones of the interpreter.
+    pub fn with_receipt_listener(
+        &mut self,
+        listener: Arc<dyn ReceiptListener>,
+        discard_receipts: bool,
+    ) -> &mut Self {
+        self.receipts.set_listener(listener);
+        self.receipts.set_discard_receipts(discard_receipts);
+        self
+    }
+
+    /// Sets a cache of the contract codes consulted before reading the codes from the
+    /// storage. The cache is kept across the executed transactions.
+    pub fn with_code_cache(&mut self, cache: Arc<dyn ContractCodeCache>) -> &mut Self {
+        self.code_cache.set(cache);
+        self
+    }
+
+    /// Allows the dry runs, see [`Self::dry_run`], to use the contracts not declared in
+    /// the transaction inputs, loading them from the storage anyway, and records the
+    /// touched contracts, see [`Self::touched_contracts`]. Meant for the simulations
+    /// building the inputs of a transaction.
+    ///
+    /// The executions are no longer valid with respect to the consensus rules, so
+    /// [`Self::transact`], used by the block production and validation, always
+    /// rejects the undeclared contracts.
+    pub fn allow_undeclared_contracts(&mut self, allow: bool) -> &mut Self {
+        self.allow_undeclared_contracts = allow;
+        self
+    }
+
+    /// Sets a receiver of the program counters of the executed instructions
+    #[cfg(feature = "profile-coverage")]
+    pub fn set_coverage_receiver<C>(&mut self, receiver: C) -> &mut Self
+    where
+        C: CoverageReceiver + Send + Sync + 'static,
+    {
+        self.profiler
+            .set_coverage_receiver(alloc::boxed::Box::new(receiver));
+        self
+    }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: Clone,
     Tx: ExecutableTransaction,
     Ecal: Clone,
+    Observer: Clone,
 {
     /// Build the interpreter
     pub fn build(&mut self) -> Self {
@@ -99,14 +222,15 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Default for Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Default for Interpreter<S, Tx, Ecal, Observer>
 where
     S: Default,
     Tx: ExecutableTransaction,
     Ecal: EcalHandler + Default,
+    Observer: Default,
 {
     fn default() -> Self {
-        Interpreter::<S, Tx, Ecal>::with_storage(
+        Interpreter::<S, Tx, Ecal, Observer>::with_storage(
             Default::default(),
             InterpreterParams::default(),
         )
@@ -114,10 +238,11 @@ where
 }
 
 #[cfg(test)]
-impl<Tx, Ecal> Interpreter<(), Tx, Ecal>
+impl<Tx, Ecal, Observer> Interpreter<(), Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     Ecal: EcalHandler + Default,
+    Observer: Default,
 {
     /// Create a new interpreter without a storage backend.
     ///
@@ -127,10 +252,11 @@ where
     }
 }
 
-impl<Tx, Ecal> Interpreter<MemoryStorage, Tx, Ecal>
+impl<Tx, Ecal, Observer> Interpreter<MemoryStorage, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     Ecal: EcalHandler + Default,
+    Observer: Default,
 {
     /// Create a new storage with a provided in-memory storage.
     ///
@@ -140,16 +266,17 @@ where
     }
 }
 
-impl<Tx, Ecal> Interpreter<MemoryStorage, Tx, Ecal>
+impl<Tx, Ecal, Observer> Interpreter<MemoryStorage, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     Ecal: EcalHandler,
+    Observer: Default,
 {
     /// Create a new storage with a provided in-memory storage.
     ///
     /// It will have full capabilities.
     pub fn with_memory_storage_and_ecal(ecal: Ecal) -> Self {
-        Interpreter::<MemoryStorage, Tx, Ecal>::with_storage_and_ecal(
+        Interpreter::<MemoryStorage, Tx, Ecal, Observer>::with_storage_and_ecal(
             Default::default(),
             InterpreterParams::default(),
             ecal,
@@ -17,6 +17,7 @@ use super::{
     RuntimeBalances,
 };
 use crate::{
+    code_cache::CodeCache,
     constraints::{
         reg_key::*,
         CheckedMemConstLen,
@@ -30,6 +31,7 @@ use crate::{
     },
     interpreter::{
         receipts::ReceiptsCtx,
+        ExecutionMetrics,
         InputContracts,
         PanicContext,
     },
@@ -40,12 +42,16 @@ use crate::{
         InterpreterStorage,
     },
 };
+use alloc::collections::BTreeSet;
 use fuel_asm::{
     PanicReason,
     RegisterId,
     Word,
 };
-use fuel_storage::StorageSize;
+use fuel_storage::{
+    StorageInspect,
+    StorageSize,
+};
 use fuel_tx::{
     Contract,
     Output,
@@ -58,12 +64,15 @@ use fuel_types::{
     ContractId,
 };
 
-use alloc::borrow::Cow;
+use alloc::{
+    borrow::Cow,
+    sync::Arc,
+};
 
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
@@ -83,7 +92,8 @@ where
             input_contracts: InputContracts::new(
                 self.tx.input_contracts(),
                 &mut self.panic_context,
-            ),
+            )
+            .with_touched_contracts(self.touched_contracts.as_mut()),
         };
         input.contract_balance(result, b, c)?;
         Ok(())
@@ -124,7 +134,13 @@ where
             is: is.as_ref(),
             pc,
         };
-        input.transfer(&mut self.panic_context, a, b, c)
+        input.transfer(
+            &mut self.panic_context,
+            self.touched_contracts.as_mut(),
+            a,
+            b,
+            c,
+        )
     }
 
     pub(crate) fn transfer_output(
@@ -189,9 +205,68 @@ where
         .ok_or_else(|| PanicReason::ContractNotFound.into())
 }
 
+/// Code of a contract read from the storage or taken from the [`CodeCache`].
+pub(crate) enum ContractCode<'s> {
+    Storage(Cow<'s, Contract>),
+    Cached(Arc<Contract>),
+}
+
+impl AsRef<[u8]> for ContractCode<'_> {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Storage(code) => code.as_ref().as_ref(),
+            Self::Cached(code) => code.as_ref().as_ref(),
+        }
+    }
+}
+
+/// The code of the `contract` cached in the `code_cache`, if the contract exists in the
+/// `storage`. The cache outlives the reverts of the storage and may be shared with
+/// other storages, so a cached contract may no longer exist.
+pub(crate) fn cached_code<S>(
+    storage: &S,
+    code_cache: &CodeCache,
+    contract: &ContractId,
+) -> IoResult<Option<Arc<Contract>>, S::Error>
+where
+    S: StorageInspect<ContractsRawCode> + ?Sized,
+{
+    let Some(code) = code_cache.get(contract) else {
+        return Ok(None)
+    };
+    let exists = storage
+        .contains_key(contract)
+        .map_err(RuntimeError::Storage)?;
+    Ok(exists.then_some(code))
+}
+
+/// The code of the `contract`, taken from the `code_cache` if it's cached there, see
+/// [`cached_code`]. The code read from the storage is added to
+/// the cache.
+pub(crate) fn cached_contract<'s, S>(
+    storage: &'s S,
+    code_cache: &CodeCache,
+    metrics: &mut ExecutionMetrics,
+    contract: &ContractId,
+) -> IoResult<ContractCode<'s>, S::DataError>
+where
+    S: InterpreterStorage,
+{
+    if let Some(code) = cached_code(storage, code_cache, contract)? {
+        metrics.record_contract_code_cache_hit();
+        return Ok(ContractCode::Cached(code))
+    }
+
+    let code = self::contract(storage, contract)?;
+    metrics.record_contract_code_load(code.as_ref().as_ref().len() as Word);
+    code_cache.insert_with(*contract, || code.as_ref().clone());
+
+    Ok(ContractCode::Storage(code))
+}
+
 struct ContractBalanceCtx<'vm, S, I> {
     storage: &'vm S,
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     pc: RegMut<'vm, PC>,
     input_contracts: InputContracts<'vm, I>,
 }
@@ -210,8 +285,8 @@ impl<'vm, S, I> ContractBalanceCtx<'vm, S, I> {
         let asset_id = CheckedMemConstLen::<{ AssetId::LEN }>::new(b)?;
         let contract = CheckedMemConstLen::<{ ContractId::LEN }>::new(c)?;
 
-        let asset_id = AssetId::from_bytes_ref(asset_id.read(self.memory));
-        let contract = ContractId::from_bytes_ref(contract.read(self.memory));
+        let asset_id = AssetId::from_bytes_ref(asset_id.read(self.memory)?);
+        let contract = ContractId::from_bytes_ref(contract.read(self.memory)?);
 
         self.input_contracts.check(contract)?;
 
@@ -224,7 +299,7 @@ impl<'vm, S, I> ContractBalanceCtx<'vm, S, I> {
 }
 struct TransferCtx<'vm, S, Tx> {
     storage: &'vm mut S,
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     context: &'vm Context,
     balances: &'vm mut RuntimeBalances,
     receipts: &'vm mut ReceiptsCtx,
@@ -248,6 +323,7 @@ impl<'vm, S, Tx> TransferCtx<'vm, S, Tx> {
     pub(crate) fn transfer(
         self,
         panic_context: &mut PanicContext,
+        touched_contracts: Option<&mut BTreeSet<ContractId>>,
         recipient_contract_id_offset: Word,
         transfer_amount: Word,
         asset_id_offset: Word,
@@ -262,6 +338,7 @@ impl<'vm, S, Tx> TransferCtx<'vm, S, Tx> {
         let asset_id = AssetId::from(read_bytes(self.memory, asset_id_offset)?);
 
         InputContracts::new(self.tx.input_contracts(), panic_context)
+            .with_touched_contracts(touched_contracts)
             .check(&destination)?;
 
         if amount == 0 {
@@ -3,10 +3,7 @@ use core::convert::Infallible;
 
 use alloc::vec;
 
-use crate::{
-    interpreter::memory::Memory,
-    storage::MemoryStorage,
-};
+use crate::storage::MemoryStorage;
 
 use super::*;
 use crate::interpreter::internal::absolute_output_mem_range;
@@ -23,7 +20,7 @@ use test_case::test_case;
 
 #[test_case(0, 32 => Ok(()); "Can read contract balance")]
 fn test_contract_balance(b: Word, c: Word) -> IoResult<(), Infallible> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[b as usize..(b as usize + AssetId::LEN)]
         .copy_from_slice(&[2u8; AssetId::LEN][..]);
     memory[c as usize..(c as usize + ContractId::LEN)]
@@ -88,7 +85,7 @@ fn test_transfer(
     let mut cgas = 10_000;
     let mut ggas = 10_000;
 
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[real_contract_id_offset as usize
         ..(real_contract_id_offset as usize + ContractId::LEN)]
         .copy_from_slice(RECIPIENT_CONTRACT_ID.as_ref());
@@ -155,6 +152,7 @@ fn test_transfer(
 
     transfer_ctx.transfer(
         &mut panic_context,
+        None,
         requested_contract_id_offset,
         transfer_amount,
         requested_asset_id_offset,
@@ -230,7 +228,7 @@ fn test_transfer_output(
     let mut cgas = 10_000;
     let mut ggas = 10_000;
 
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
 
     memory
         [real_recipient_offset as usize..(real_recipient_offset as usize + Address::LEN)]
@@ -15,7 +15,6 @@ use super::{
 };
 use crate::{
     constraints::reg_key::*,
-    consts::*,
     error::SimpleResult,
     prelude::MemoryRange,
 };
@@ -35,7 +34,7 @@ use fuel_types::{
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
@@ -83,7 +82,7 @@ where
 }
 
 pub(crate) fn secp256k1_recover(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     err: RegMut<ERR>,
     pc: RegMut<PC>,
@@ -112,7 +111,7 @@ pub(crate) fn secp256k1_recover(
 }
 
 pub(crate) fn secp256r1_recover(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     err: RegMut<ERR>,
     pc: RegMut<PC>,
@@ -139,7 +138,7 @@ pub(crate) fn secp256r1_recover(
 }
 
 pub(crate) fn ed25519_verify(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     err: RegMut<ERR>,
     pc: RegMut<PC>,
     a: Word,
@@ -161,7 +160,7 @@ pub(crate) fn ed25519_verify(
 }
 
 pub(crate) fn keccak256(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -175,7 +174,7 @@ pub(crate) fn keccak256(
     let src_range = MemoryRange::new(b, c)?;
 
     let mut h = Keccak256::new();
-    h.update(&memory[src_range.usizes()]);
+    h.update(src_range.read(memory)?);
 
     try_mem_write(a, h.finalize().as_slice(), owner, memory)?;
 
@@ -183,7 +182,7 @@ pub(crate) fn keccak256(
 }
 
 pub(crate) fn sha256(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -194,7 +193,7 @@ pub(crate) fn sha256(
 
     try_mem_write(
         a,
-        Hasher::hash(&memory[src_range.usizes()]).as_ref(),
+        Hasher::hash(src_range.read(memory)?).as_ref(),
         owner,
         memory,
     )?;
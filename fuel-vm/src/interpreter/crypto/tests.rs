@@ -8,8 +8,8 @@ use rand::{
 };
 
 use crate::{
+    consts::*,
     context::Context,
-    interpreter::memory::Memory,
 };
 
 use super::*;
@@ -17,12 +17,13 @@ use super::*;
 #[cfg(feature = "random")]
 #[test]
 fn test_recover_secp256k1() -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let owner = OwnershipRegisters {
         sp: 1000,
         ssp: 1000,
         hp: 2000,
         prev_hp: VM_MAX_RAM - 1,
+        memory_limit: VM_MAX_RAM,
         context: Context::Call {
             block_height: Default::default(),
         },
@@ -67,12 +68,13 @@ fn test_recover_secp256r1() -> SimpleResult<()> {
 
     let mut rng = &mut StdRng::seed_from_u64(8586);
 
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let owner = OwnershipRegisters {
         sp: 1000,
         ssp: 1000,
         hp: 2000,
         prev_hp: VM_MAX_RAM - 1,
+        memory_limit: VM_MAX_RAM,
         context: Context::Call {
             block_height: Default::default(),
         },
@@ -116,7 +118,7 @@ fn test_recover_secp256r1() -> SimpleResult<()> {
 fn test_verify_ed25519() -> SimpleResult<()> {
     use ed25519_dalek::Signer;
 
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut err = 0;
     let mut pc = 4;
 
@@ -151,12 +153,13 @@ fn test_verify_ed25519() -> SimpleResult<()> {
 
 #[test]
 fn test_keccak256() -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let owner = OwnershipRegisters {
         sp: 1000,
         ssp: 1000,
         hp: 2000,
         prev_hp: VM_MAX_RAM - 1,
+        memory_limit: VM_MAX_RAM,
         context: Context::Call {
             block_height: Default::default(),
         },
@@ -180,12 +183,13 @@ fn test_keccak256() -> SimpleResult<()> {
 
 #[test]
 fn test_sha256() -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let owner = OwnershipRegisters {
         sp: 1000,
         ssp: 1000,
         hp: 2000,
         prev_hp: VM_MAX_RAM - 1,
+        memory_limit: VM_MAX_RAM,
         context: Context::Call {
             block_height: Default::default(),
         },
@@ -1,14 +1,34 @@
 #[cfg(test)]
-use alloc::{
-    vec,
-    vec::Vec,
-};
+use alloc::vec;
+use alloc::vec::Vec;
 
-use super::Interpreter;
-use crate::prelude::*;
-use fuel_asm::RegId;
+#[cfg(test)]
+use crate::state::ExecuteState;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+use super::{
+    elliptic_curve::{
+        ecop_input_len,
+        ECOP_OUTPUT_LEN,
+        EPAR_PAIR_LEN,
+    },
+    Interpreter,
+    MemoryRange,
+};
+use crate::{
+    consts::{
+        VM_REGISTER_COUNT,
+        WORD_SIZE,
+    },
+    prelude::*,
+    state::WatchKind,
+};
+use fuel_asm::{
+    Instruction,
+    RawInstruction,
+    RegId,
+};
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
@@ -45,13 +65,47 @@ where
         self.debugger.remove_breakpoint(breakpoint)
     }
 
-    pub(crate) fn eval_debugger_state(&mut self) -> DebugEval {
+    /// Watch the memory `range` for accesses of the provided `kind`.
+    pub fn add_watchpoint(&mut self, range: MemoryRange, kind: WatchKind) {
+        self.debugger.add_watchpoint(range, kind)
+    }
+
+    /// Remove a previously set watchpoint.
+    pub fn remove_watchpoint(&mut self, range: &MemoryRange, kind: WatchKind) {
+        self.debugger.remove_watchpoint(range, kind)
+    }
+
+    /// Clear all set watchpoints.
+    pub fn clear_watchpoints(&mut self) {
+        self.debugger.clear_watchpoints();
+    }
+
+    pub(crate) fn eval_debugger_state(&mut self, raw: RawInstruction) -> DebugEval {
         let debugger = &mut self.debugger;
 
         let contract = self.frames.last().map(CallFrame::to);
         let pc = self.registers[RegId::PC].saturating_sub(self.registers[RegId::IS]);
 
-        debugger.eval_state(contract, pc)
+        if !debugger.has_watchpoints() {
+            return debugger.eval_state(contract, pc)
+        }
+
+        let accesses = Instruction::try_from(raw)
+            .map(|instruction| memory_accesses(&self.registers, instruction))
+            .unwrap_or_default();
+
+        debugger.eval_state_with_accesses(contract, pc, &accesses)
+    }
+
+    /// Record the current location as the last state of the debugger, so resuming
+    /// from it doesn't stop on a breakpoint set at this location.
+    pub(crate) fn debugger_set_current_location(&mut self) {
+        let contract = self.frames.last().map(CallFrame::to).copied();
+        let pc = self.registers[RegId::PC].saturating_sub(self.registers[RegId::IS]);
+        let location = Breakpoint::raw(contract.unwrap_or_default(), pc);
+
+        self.debugger
+            .set_last_state(ProgramState::RunProgram(location.into()))
     }
 
     pub(crate) fn debugger_set_last_state(&mut self, state: ProgramState) {
@@ -63,6 +117,251 @@ where
     }
 }
 
+/// Memory ranges accessed by the `instruction`, computed from the current
+/// `registers` before it is executed. Ranges that are out of the VM memory are
+/// skipped, since the instruction will panic before accessing them.
+///
+/// The call frame and the code written to the stack by `CALL` aren't reported,
+/// since their size depends on the called contract.
+fn memory_accesses(
+    registers: &[Word; VM_REGISTER_COUNT],
+    instruction: Instruction,
+) -> Vec<(MemoryRange, WatchKind)> {
+    let range = |addr: Word, len: Word| MemoryRange::new(addr, len).ok();
+    let offset = |addr: Word, offset: Word| addr.checked_add(offset);
+    let bytes32 = |addr: Word| range(addr, Bytes32::LEN as Word);
+    let slots = |count: Word| (Bytes32::LEN as Word).saturating_mul(count);
+
+    let (reads, writes): ([Option<MemoryRange>; 3], _) = match instruction {
+        Instruction::LB(lb) => {
+            let (_, b, imm) = lb.unpack();
+            let addr = offset(registers[b], imm.into());
+            ([addr.and_then(|addr| range(addr, 1)), None, None], None)
+        }
+        Instruction::LW(lw) => {
+            let (_, b, imm) = lw.unpack();
+            let addr = offset(registers[b], Word::from(imm).saturating_mul(8));
+            ([addr.and_then(|addr| range(addr, 8)), None, None], None)
+        }
+        Instruction::SB(sb) => {
+            let (a, _, imm) = sb.unpack();
+            let addr = offset(registers[a], imm.into());
+            ([None, None, None], addr.and_then(|addr| range(addr, 1)))
+        }
+        Instruction::SW(sw) => {
+            let (a, _, imm) = sw.unpack();
+            let addr = offset(registers[a], Word::from(imm).saturating_mul(8));
+            ([None, None, None], addr.and_then(|addr| range(addr, 8)))
+        }
+        Instruction::MCL(mcl) => {
+            let (a, b) = mcl.unpack();
+            ([None, None, None], range(registers[a], registers[b]))
+        }
+        Instruction::MCLI(mcli) => {
+            let (a, imm) = mcli.unpack();
+            ([None, None, None], range(registers[a], imm.into()))
+        }
+        Instruction::MCP(mcp) => {
+            let (a, b, c) = mcp.unpack();
+            (
+                [range(registers[b], registers[c]), None, None],
+                range(registers[a], registers[c]),
+            )
+        }
+        Instruction::MCPI(mcpi) => {
+            let (a, b, imm) = mcpi.unpack();
+            (
+                [range(registers[b], imm.into()), None, None],
+                range(registers[a], imm.into()),
+            )
+        }
+        Instruction::MEQ(meq) => {
+            let (_, b, c, d) = meq.unpack();
+            (
+                [
+                    range(registers[b], registers[d]),
+                    range(registers[c], registers[d]),
+                    None,
+                ],
+                None,
+            )
+        }
+        Instruction::CCP(ccp) => {
+            let (a, b, _, d) = ccp.unpack();
+            (
+                [range(registers[b], ContractId::LEN as Word), None, None],
+                range(registers[a], registers[d]),
+            )
+        }
+        Instruction::CROO(croo) => {
+            let (a, b) = croo.unpack();
+            ([bytes32(registers[b]), None, None], bytes32(registers[a]))
+        }
+        Instruction::LDC(ldc) => {
+            let (a, _, c) = ldc.unpack();
+            let len = registers[c].checked_next_multiple_of(WORD_SIZE as Word);
+            (
+                [bytes32(registers[a]), None, None],
+                len.and_then(|len| range(registers[RegId::SSP], len)),
+            )
+        }
+        Instruction::BHSH(bhsh) => {
+            let (a, _) = bhsh.unpack();
+            ([None, None, None], bytes32(registers[a]))
+        }
+        Instruction::SRWQ(srwq) => {
+            let (a, _, c, d) = srwq.unpack();
+            (
+                [bytes32(registers[c]), None, None],
+                range(registers[a], slots(registers[d])),
+            )
+        }
+        Instruction::SWWQ(swwq) => {
+            let (a, _, c, d) = swwq.unpack();
+            (
+                [
+                    bytes32(registers[a]),
+                    range(registers[c], slots(registers[d])),
+                    None,
+                ],
+                None,
+            )
+        }
+        Instruction::SCWQ(scwq) => {
+            let (a, _, _) = scwq.unpack();
+            ([bytes32(registers[a]), None, None], None)
+        }
+        Instruction::CALL(call) => {
+            let (a, _, c, _) = call.unpack();
+            (
+                [
+                    range(registers[a], Call::LEN as Word),
+                    bytes32(registers[c]),
+                    None,
+                ],
+                None,
+            )
+        }
+        Instruction::TR(tr) => {
+            let (a, _, c) = tr.unpack();
+            ([bytes32(registers[a]), bytes32(registers[c]), None], None)
+        }
+        Instruction::TRO(tro) => {
+            let (a, _, _, d) = tro.unpack();
+            ([bytes32(registers[a]), bytes32(registers[d]), None], None)
+        }
+        Instruction::SMO(smo) => {
+            let (a, b, c, _) = smo.unpack();
+            (
+                [
+                    bytes32(registers[a]),
+                    range(registers[b], registers[c]),
+                    None,
+                ],
+                None,
+            )
+        }
+        Instruction::MINT(mint) => {
+            let (_, b) = mint.unpack();
+            ([bytes32(registers[b]), None, None], None)
+        }
+        Instruction::BURN(burn) => {
+            let (_, b) = burn.unpack();
+            ([bytes32(registers[b]), None, None], None)
+        }
+        Instruction::ECK1(eck1) => {
+            let (a, b, c) = eck1.unpack();
+            (
+                [
+                    range(registers[b], Bytes64::LEN as Word),
+                    bytes32(registers[c]),
+                    None,
+                ],
+                range(registers[a], Bytes64::LEN as Word),
+            )
+        }
+        Instruction::ECR1(ecr1) => {
+            let (a, b, c) = ecr1.unpack();
+            (
+                [
+                    range(registers[b], Bytes64::LEN as Word),
+                    bytes32(registers[c]),
+                    None,
+                ],
+                range(registers[a], Bytes64::LEN as Word),
+            )
+        }
+        Instruction::ED19(ed19) => {
+            let (a, b, c) = ed19.unpack();
+            (
+                [
+                    bytes32(registers[a]),
+                    range(registers[b], Bytes64::LEN as Word),
+                    bytes32(registers[c]),
+                ],
+                None,
+            )
+        }
+        Instruction::ECOP(ecop) => {
+            let (a, _, c, d) = ecop.unpack();
+            let len = ecop_input_len(registers[c]);
+            (
+                [
+                    len.and_then(|len| range(registers[d], len as Word)),
+                    None,
+                    None,
+                ],
+                range(registers[a], ECOP_OUTPUT_LEN as Word),
+            )
+        }
+        Instruction::EPAR(epar) => {
+            let (_, _, c, d) = epar.unpack();
+            let len = (EPAR_PAIR_LEN as Word).saturating_mul(registers[c]);
+            ([range(registers[d], len), None, None], None)
+        }
+        Instruction::S256(s256) => {
+            let (a, b, c) = s256.unpack();
+            (
+                [range(registers[b], registers[c]), None, None],
+                bytes32(registers[a]),
+            )
+        }
+        Instruction::K256(k256) => {
+            let (a, b, c) = k256.unpack();
+            (
+                [range(registers[b], registers[c]), None, None],
+                bytes32(registers[a]),
+            )
+        }
+        Instruction::BSIZ(bsiz) => {
+            let (_, b) = bsiz.unpack();
+            ([bytes32(registers[b]), None, None], None)
+        }
+        Instruction::BLDD(bldd) => {
+            let (a, b, _, d) = bldd.unpack();
+            (
+                [bytes32(registers[b]), None, None],
+                range(registers[a], registers[d]),
+            )
+        }
+        Instruction::LOGD(logd) => {
+            let (_, _, c, d) = logd.unpack();
+            ([range(registers[c], registers[d]), None, None], None)
+        }
+        Instruction::RETD(retd) => {
+            let (a, b) = retd.unpack();
+            ([range(registers[a], registers[b]), None, None], None)
+        }
+        _ => ([None, None, None], None),
+    };
+
+    writes
+        .map(|r| (r, WatchKind::Write))
+        .into_iter()
+        .chain(reads.into_iter().flatten().map(|r| (r, WatchKind::Read)))
+        .collect()
+}
+
 #[test]
 fn breakpoint_script() {
     use fuel_asm::op;
@@ -95,8 +394,8 @@ fn breakpoint_script() {
         .expect("failed to generate checked tx")
         .into_ready(
             gas_price,
-            &consensus_params.gas_costs,
-            &consensus_params.fee_params,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
         )
         .unwrap();
 
@@ -174,8 +473,8 @@ fn single_stepping() {
         .expect("failed to generate checked tx")
         .into_ready(
             gas_price,
-            &consensus_params.gas_costs,
-            &consensus_params.fee_params,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
         )
         .unwrap();
 
@@ -200,3 +499,688 @@ fn single_stepping() {
 
     assert_eq!(stops, vec![0, 4, 8, 4, 8, 4, 8, 4, 8, 4, 8, 12]);
 }
+
+#[cfg(test)]
+fn ready_script(
+    script: Vec<fuel_asm::Instruction>,
+    gas_limit: Word,
+) -> crate::checked_transaction::Ready<Script> {
+    let consensus_params = ConsensusParameters::standard();
+
+    TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(gas_limit)
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate checked tx")
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap()
+}
+
+#[cfg(test)]
+fn paused_at_start(
+    script: Vec<fuel_asm::Instruction>,
+    gas_limit: Word,
+) -> Interpreter<MemoryStorage, Script> {
+    let mut vm = Interpreter::<_, _>::with_memory_storage();
+    vm.set_breakpoint(Breakpoint::script(0));
+
+    let state = vm
+        .transact(ready_script(script, gas_limit))
+        .map(ProgramState::from)
+        .expect("Failed to execute script!");
+    assert!(state.is_debug());
+
+    vm
+}
+
+/// The receipts of the `script` executed without a debugger.
+#[cfg(test)]
+fn full_run_receipts(
+    script: Vec<fuel_asm::Instruction>,
+    gas_limit: Word,
+) -> Vec<Receipt> {
+    let mut vm = Interpreter::<_, _>::with_memory_storage();
+
+    vm.transact(ready_script(script, gas_limit))
+        .expect("Failed to execute script!")
+        .receipts()
+        .to_vec()
+}
+
+#[test]
+fn single_step_register_trace_matches_full_run() {
+    use fuel_asm::op;
+
+    let script = vec![
+        op::addi(0x10, RegId::ZERO, 3),
+        op::addi(0x11, 0x10, 4),
+        op::mul(0x12, 0x10, 0x11),
+        op::sub(0x13, 0x12, 0x10),
+        op::slli(0x14, 0x13, 2),
+        op::move_(0x15, 0x14),
+        op::xor(0x16, 0x15, 0x11),
+        op::noop(),
+        op::add(0x17, 0x16, 0x12),
+        op::ret(0x17),
+    ];
+
+    // Full run, stopping before every instruction
+    let mut vm = paused_at_start(script.clone(), 1_000_000);
+    vm.set_single_stepping(true);
+    let mut expected = Vec::new();
+    let mut state = vm.resume().expect("Failed to resume");
+    while state.is_debug() {
+        expected.push(vm.registers().to_vec());
+        state = vm.resume().expect("Failed to resume");
+    }
+    expected.push(vm.registers().to_vec());
+
+    // Single step the same script
+    let mut vm = paused_at_start(script.clone(), 1_000_000);
+    let mut trace = Vec::new();
+    let mut last = ExecuteState::Proceed;
+    while last == ExecuteState::Proceed {
+        assert!(vm.next_instruction().is_some());
+        last = vm.single_step().expect("Failed to step");
+        trace.push(vm.registers().to_vec());
+    }
+
+    assert_eq!(trace.len(), 10);
+    assert_eq!(trace, expected);
+    assert_eq!(last, ExecuteState::Return(vm.registers()[0x17]));
+    assert_eq!(vm.receipts(), full_run_receipts(script, 1_000_000));
+    assert_eq!(
+        vm.resume().expect("Failed to resume"),
+        ProgramState::Return(vm.registers()[0x17])
+    );
+}
+
+#[test]
+fn inspection_accessors_read_state_mid_execution() {
+    use crate::context::Context;
+    use fuel_asm::{
+        op,
+        PanicReason,
+    };
+
+    let script = vec![
+        op::movi(0x10, 7),
+        op::movi(0x11, 8),
+        op::aloc(0x11),
+        op::sw(RegId::HP, 0x10, 0),
+        op::ret(RegId::ONE),
+    ];
+    let mut vm = paused_at_start(script, 1_000_000);
+    for _ in 0..4 {
+        assert_eq!(vm.single_step().unwrap(), ExecuteState::Proceed);
+    }
+
+    assert_eq!(vm.register(RegId::new(0x10)), 7);
+    assert_eq!(vm.registers()[0x11], 8);
+    let heap = vm.register(RegId::HP);
+    assert_eq!(vm.memory_range(heap, 8), Ok(&7u64.to_be_bytes()[..]));
+    assert_eq!(vm.memory_range(heap, 9), Err(PanicReason::MemoryOverflow));
+    assert!(vm.call_stack().is_empty());
+    assert!(matches!(vm.context(), Context::Script { .. }));
+}
+
+#[test]
+fn single_step_terminates_on_revert() {
+    use fuel_asm::op;
+
+    let script = vec![op::addi(0x10, RegId::ZERO, 7), op::rvrt(0x10)];
+    let mut vm = paused_at_start(script.clone(), 1_000_000);
+
+    assert_eq!(vm.next_instruction(), Some(op::addi(0x10, RegId::ZERO, 7)));
+    assert_eq!(vm.single_step().unwrap(), ExecuteState::Proceed);
+    assert_eq!(vm.next_instruction(), Some(op::rvrt(0x10)));
+    assert_eq!(vm.single_step().unwrap(), ExecuteState::Revert(7));
+    assert_eq!(vm.resume().unwrap(), ProgramState::Revert(7));
+    assert_eq!(vm.receipts(), full_run_receipts(script, 1_000_000));
+}
+
+#[test]
+fn single_step_panics_when_out_of_gas() {
+    use fuel_asm::op;
+
+    // Loops forever
+    let script = vec![op::addi(0x10, 0x10, 1), op::jmpb(RegId::ZERO, 0)];
+    let mut vm = paused_at_start(script.clone(), 100);
+
+    let error = (0..1_000)
+        .find_map(|_| vm.single_step().err())
+        .expect("Expected the script to run out of gas");
+
+    assert_eq!(error.panic_reason(), Some(PanicReason::OutOfGas));
+    assert_eq!(vm.resume().unwrap(), ProgramState::Revert(0));
+
+    // The `Panic` and `ScriptResult` receipts are the ones of a full run
+    let receipts = full_run_receipts(script, 100);
+    assert!(matches!(
+        receipts.as_slice(),
+        [Receipt::Panic { .. }, Receipt::ScriptResult { .. }]
+    ));
+    assert_eq!(vm.receipts(), receipts);
+}
+
+#[test]
+fn step_over_runs_call_until_it_returns() {
+    use crate::{
+        interpreter::InterpreterParams,
+        script_with_data_offset,
+    };
+    use fuel_asm::{
+        op,
+        Opcode,
+    };
+    use fuel_types::{
+        canonical::Serialize,
+        Immediate18,
+    };
+
+    let step_into = |step_over: bool| {
+        let mut test_context = TestBuilder::new(2322u64);
+        let contract = vec![
+            op::addi(0x10, RegId::ZERO, 5),
+            op::addi(0x11, RegId::ZERO, 6),
+            op::ret(RegId::ONE),
+        ];
+        let contract_id = test_context
+            .setup_contract(contract, None, None)
+            .contract_id;
+
+        let (script, _) = script_with_data_offset!(
+            data_offset,
+            vec![
+                op::movi(0x10, data_offset as Immediate18),
+                op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            test_context.get_tx_params().tx_offset()
+        );
+        let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+        let consensus_params = ConsensusParameters::standard();
+        let tx = test_context
+            .start_script(script, script_data)
+            .script_gas_limit(1_000_000)
+            .contract_input(contract_id)
+            .fee_input()
+            .contract_output(&contract_id)
+            .build()
+            .into_ready(
+                0,
+                consensus_params.gas_costs(),
+                consensus_params.fee_params(),
+            )
+            .unwrap();
+
+        let mut vm = Interpreter::<_, Script>::with_storage(
+            test_context.get_storage().clone(),
+            InterpreterParams::new(0, &consensus_params),
+        );
+        vm.set_breakpoint(Breakpoint::script(1));
+        let state = vm.transact(tx).map(ProgramState::from).unwrap();
+        assert!(state.is_debug());
+
+        assert_eq!(
+            vm.next_instruction().map(|i| i.opcode()),
+            Some(Opcode::CALL)
+        );
+        let states = if step_over {
+            vec![vm.step_over().unwrap()]
+        } else {
+            (0..4).map(|_| vm.single_step().unwrap()).collect()
+        };
+        (vm, states)
+    };
+
+    // Stepping into the call executes the contract instructions one by one
+    let (vm, states) = step_into(false);
+    assert_eq!(
+        states,
+        vec![
+            ExecuteState::Proceed,
+            ExecuteState::Proceed,
+            ExecuteState::Proceed,
+            ExecuteState::Return(1),
+        ]
+    );
+    assert!(vm.call_stack().is_empty());
+    assert_eq!(vm.next_instruction(), Some(op::ret(RegId::ONE)));
+
+    // Stepping over the call runs the contract until it returns
+    let (mut vm, states) = step_into(true);
+    assert_eq!(states, vec![ExecuteState::Proceed]);
+    assert!(vm.call_stack().is_empty());
+    assert_eq!(vm.next_instruction(), Some(op::ret(RegId::ONE)));
+    assert_eq!(vm.step_over().unwrap(), ExecuteState::Return(1));
+}
+
+#[cfg(test)]
+fn watched_script() -> crate::checked_transaction::Ready<Script> {
+    use fuel_asm::op;
+
+    let script = [
+        op::movi(0x10, 16),
+        op::aloc(0x10),
+        op::move_(0x11, RegId::HP),
+        op::sw(0x11, RegId::ONE, 1),
+        op::movi(0x12, 8),
+        op::add(0x13, 0x11, 0x12),
+        op::mcp(0x13, 0x11, 0x12),
+        op::lw(0x14, 0x11, 1),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+
+    let consensus_params = ConsensusParameters::standard();
+
+    TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate checked tx")
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn watchpoint_hits_before_sw_and_mcp_writes() {
+    use crate::consts::VM_MAX_RAM;
+
+    let mut vm = Interpreter::<_, _>::with_memory_storage();
+
+    // The last word of the heap allocated by the script
+    let watched = MemoryRange::new(VM_MAX_RAM - 8, 8).unwrap();
+    vm.add_watchpoint(watched.clone(), WatchKind::Write);
+
+    let state = vm
+        .transact(watched_script())
+        .map(ProgramState::from)
+        .expect("Failed to execute script!");
+
+    // `SW` is the fourth instruction
+    assert_eq!(
+        state,
+        ProgramState::RunProgram(DebugEval::WatchpointHit {
+            start: watched.start as Word,
+            len: 8,
+            pc: 12,
+            access: WatchKind::Write,
+        })
+    );
+    assert_eq!(vm.memory()[watched.usizes()], [0; 8]);
+
+    // `MCP` is the seventh instruction
+    let state = vm.resume().expect("Failed to resume");
+    assert_eq!(
+        state,
+        ProgramState::RunProgram(DebugEval::WatchpointHit {
+            start: watched.start as Word,
+            len: 8,
+            pc: 24,
+            access: WatchKind::Write,
+        })
+    );
+    assert_eq!(vm.memory()[watched.usizes()], 1u64.to_be_bytes());
+
+    // `LW` only reads the watched memory
+    let state = vm.resume().expect("Failed to resume");
+    assert_eq!(state, ProgramState::Return(1));
+    assert_eq!(vm.memory()[watched.usizes()], [0; 8]);
+}
+
+#[test]
+fn watchpoint_hits_on_read_access() {
+    use crate::consts::VM_MAX_RAM;
+
+    let mut vm = Interpreter::<_, _>::with_memory_storage();
+
+    let watched = MemoryRange::new(VM_MAX_RAM - 1, 1).unwrap();
+    vm.add_watchpoint(watched.clone(), WatchKind::Read);
+
+    let state = vm
+        .transact(watched_script())
+        .map(ProgramState::from)
+        .expect("Failed to execute script!");
+
+    // `MCP` reads the first word of the allocation, so only `LW` hits
+    assert_eq!(
+        state,
+        ProgramState::RunProgram(DebugEval::WatchpointHit {
+            start: watched.start as Word,
+            len: 1,
+            pc: 28,
+            access: WatchKind::Read,
+        })
+    );
+
+    vm.clear_watchpoints();
+    assert_eq!(vm.resume().unwrap(), ProgramState::Return(1));
+}
+
+#[cfg(test)]
+fn watched_blob_script() -> (
+    Interpreter<MemoryStorage, Script>,
+    crate::checked_transaction::Ready<Script>,
+) {
+    use crate::storage::BlobData;
+    use fuel_asm::{
+        op,
+        GTFArgs,
+    };
+    use fuel_storage::StorageMutate;
+    use fuel_types::BlobId;
+
+    const PAYLOAD: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    let blob_id = BlobId::compute(&PAYLOAD);
+
+    // Copies the blob id to the heap, then loads the blob after it
+    let script = [
+        op::movi(0x10, 40),
+        op::aloc(0x10),
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::mcpi(RegId::HP, 0x10, 32),
+        op::addi(0x11, RegId::HP, 32),
+        op::bsiz(0x12, RegId::HP),
+        op::bldd(0x11, RegId::HP, RegId::ZERO, 0x12),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+
+    let consensus_params = ConsensusParameters::standard();
+    let tx = TransactionBuilder::script(script, blob_id.to_vec())
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate checked tx")
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap();
+
+    let mut vm = Interpreter::<_, _>::with_memory_storage();
+    StorageMutate::<BlobData>::insert(vm.as_mut(), &blob_id, &PAYLOAD).unwrap();
+
+    (vm, tx)
+}
+
+#[test]
+fn watchpoint_hits_when_bsiz_reads_the_blob_id() {
+    use crate::consts::VM_MAX_RAM;
+
+    // Given
+    let (mut vm, tx) = watched_blob_script();
+    let blob_id = MemoryRange::new(VM_MAX_RAM - 40, 32).unwrap();
+    vm.add_watchpoint(blob_id.clone(), WatchKind::Read);
+
+    // When
+    let state = vm
+        .transact(tx)
+        .map(ProgramState::from)
+        .expect("Failed to execute script!");
+
+    // Then
+    // `BSIZ` is the sixth instruction
+    assert_eq!(
+        state,
+        ProgramState::RunProgram(DebugEval::WatchpointHit {
+            start: blob_id.start as Word,
+            len: 32,
+            pc: 20,
+            access: WatchKind::Read,
+        })
+    );
+}
+
+#[test]
+fn watchpoint_hits_before_bldd_writes_the_blob() {
+    use crate::consts::VM_MAX_RAM;
+
+    // Given
+    let (mut vm, tx) = watched_blob_script();
+    let watched = MemoryRange::new(VM_MAX_RAM - 8, 8).unwrap();
+    vm.add_watchpoint(watched.clone(), WatchKind::Write);
+
+    // When
+    let state = vm
+        .transact(tx)
+        .map(ProgramState::from)
+        .expect("Failed to execute script!");
+
+    // Then
+    // `BLDD` is the seventh instruction
+    assert_eq!(
+        state,
+        ProgramState::RunProgram(DebugEval::WatchpointHit {
+            start: watched.start as Word,
+            len: 8,
+            pc: 24,
+            access: WatchKind::Write,
+        })
+    );
+    assert_eq!(vm.memory()[watched.usizes()], [0; 8]);
+
+    let state = vm.resume().expect("Failed to resume");
+    assert_eq!(state, ProgramState::Return(1));
+    assert_eq!(vm.memory()[watched.usizes()], [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[cfg(test)]
+fn accesses_of(
+    instruction: Instruction,
+    values: &[(u8, Word)],
+) -> Vec<(core::ops::Range<usize>, WatchKind)> {
+    let mut registers = [0; VM_REGISTER_COUNT];
+    for (reg, value) in values {
+        registers[*reg as usize] = *value;
+    }
+
+    memory_accesses(&registers, instruction)
+        .into_iter()
+        .map(|(range, kind)| (range.usizes(), kind))
+        .collect()
+}
+
+#[test]
+fn memory_accesses_of_state_qword_instructions() {
+    use fuel_asm::op;
+
+    // `SRWQ` reads the key and writes the slots
+    assert_eq!(
+        accesses_of(
+            op::srwq(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x12, 500), (0x13, 2)]
+        ),
+        vec![(100..164, WatchKind::Write), (500..532, WatchKind::Read)]
+    );
+
+    // `SWWQ` reads the key and the slots
+    assert_eq!(
+        accesses_of(
+            op::swwq(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x12, 500), (0x13, 2)]
+        ),
+        vec![(100..132, WatchKind::Read), (500..564, WatchKind::Read)]
+    );
+
+    // `SCWQ` only reads the key
+    assert_eq!(
+        accesses_of(op::scwq(0x10, 0x11, 0x12), &[(0x10, 100), (0x12, 2)]),
+        vec![(100..132, WatchKind::Read)]
+    );
+}
+
+#[test]
+fn memory_accesses_of_block_and_code_instructions() {
+    use fuel_asm::op;
+
+    // `BHSH` writes the hash of the block
+    assert_eq!(
+        accesses_of(op::bhsh(0x10, 0x11), &[(0x10, 100), (0x11, 500)]),
+        vec![(100..132, WatchKind::Write)]
+    );
+
+    // `CROO` reads the contract id and writes its code root
+    assert_eq!(
+        accesses_of(op::croo(0x10, 0x11), &[(0x10, 100), (0x11, 500)]),
+        vec![(100..132, WatchKind::Write), (500..532, WatchKind::Read)]
+    );
+
+    // `LDC` reads the contract id and writes the padded code to the stack
+    assert_eq!(
+        accesses_of(
+            op::ldc(0x10, 0x11, 0x12),
+            &[(RegId::SSP.into(), 1000), (0x10, 100), (0x12, 9)]
+        ),
+        vec![(1000..1016, WatchKind::Write), (100..132, WatchKind::Read)]
+    );
+}
+
+#[test]
+fn memory_accesses_of_signature_instructions() {
+    use fuel_asm::op;
+
+    let values = [(0x10, 100), (0x11, 500), (0x12, 900)];
+    let recovered = vec![
+        (100..164, WatchKind::Write),
+        (500..564, WatchKind::Read),
+        (900..932, WatchKind::Read),
+    ];
+
+    // `ECK1` and `ECR1` read the signature and the message and write the key
+    assert_eq!(accesses_of(op::eck1(0x10, 0x11, 0x12), &values), recovered);
+    assert_eq!(accesses_of(op::ecr1(0x10, 0x11, 0x12), &values), recovered);
+
+    // `ED19` reads the key, the signature and the message
+    assert_eq!(
+        accesses_of(op::ed19(0x10, 0x11, 0x12), &values),
+        vec![
+            (100..132, WatchKind::Read),
+            (500..564, WatchKind::Read),
+            (900..932, WatchKind::Read),
+        ]
+    );
+}
+
+#[test]
+fn memory_accesses_of_elliptic_curve_instructions() {
+    use fuel_asm::op;
+
+    // `ECOP` reads the points of the addition and writes the result
+    assert_eq!(
+        accesses_of(
+            op::ecop(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x12, 0), (0x13, 500)]
+        ),
+        vec![(100..164, WatchKind::Write), (500..628, WatchKind::Read)]
+    );
+
+    // `ECOP` reads the point and the scalar of the multiplication
+    assert_eq!(
+        accesses_of(
+            op::ecop(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x12, 1), (0x13, 500)]
+        ),
+        vec![(100..164, WatchKind::Write), (500..596, WatchKind::Read)]
+    );
+
+    // `EPAR` reads the pairs of points
+    assert_eq!(
+        accesses_of(op::epar(0x10, 0x11, 0x12, 0x13), &[(0x12, 2), (0x13, 500)]),
+        vec![(500..884, WatchKind::Read)]
+    );
+}
+
+#[test]
+fn memory_accesses_of_call_and_transfer_instructions() {
+    use fuel_asm::op;
+
+    // `CALL` reads the call parameters and the asset id
+    assert_eq!(
+        accesses_of(
+            op::call(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x11, 5), (0x12, 500), (0x13, 10)]
+        ),
+        vec![(100..148, WatchKind::Read), (500..532, WatchKind::Read)]
+    );
+
+    // `TR` reads the contract id and the asset id
+    assert_eq!(
+        accesses_of(
+            op::tr(0x10, 0x11, 0x12),
+            &[(0x10, 100), (0x11, 5), (0x12, 500)]
+        ),
+        vec![(100..132, WatchKind::Read), (500..532, WatchKind::Read)]
+    );
+
+    // `TRO` reads the address and the asset id
+    assert_eq!(
+        accesses_of(
+            op::tro(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x11, 0), (0x12, 5), (0x13, 500)]
+        ),
+        vec![(100..132, WatchKind::Read), (500..532, WatchKind::Read)]
+    );
+
+    // `SMO` reads the recipient and the message data
+    assert_eq!(
+        accesses_of(
+            op::smo(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x11, 500), (0x12, 10), (0x13, 5)]
+        ),
+        vec![(100..132, WatchKind::Read), (500..510, WatchKind::Read)]
+    );
+}
+
+#[test]
+fn memory_accesses_of_mint_and_burn() {
+    use fuel_asm::op;
+
+    // `MINT` and `BURN` read the sub id
+    let values = [(0x10, 5), (0x11, 500)];
+    assert_eq!(
+        accesses_of(op::mint(0x10, 0x11), &values),
+        vec![(500..532, WatchKind::Read)]
+    );
+    assert_eq!(
+        accesses_of(op::burn(0x10, 0x11), &values),
+        vec![(500..532, WatchKind::Read)]
+    );
+}
+
+#[test]
+fn memory_accesses_of_blob_instructions() {
+    use fuel_asm::op;
+
+    // `BSIZ` reads the blob id
+    assert_eq!(
+        accesses_of(op::bsiz(0x10, 0x11), &[(0x11, 500)]),
+        vec![(500..532, WatchKind::Read)]
+    );
+
+    // `BLDD` reads the blob id and writes the loaded bytes
+    assert_eq!(
+        accesses_of(
+            op::bldd(0x10, 0x11, 0x12, 0x13),
+            &[(0x10, 100), (0x11, 500), (0x12, 4), (0x13, 10)]
+        ),
+        vec![(100..110, WatchKind::Write), (500..532, WatchKind::Read)]
+    );
+}
@@ -316,7 +316,7 @@ where
         .map(|((index, a), b)| (index, a.cloned(), b.cloned()))
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
     /// The diff function generates a diff of VM state, represented by the Diff struct,
     /// between two VMs internal states.
     pub fn diff(&self, other: &Self) -> Diff<Deltas>
@@ -405,6 +405,8 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
             }
             Change::Balance(Previous(value)) => invert_map(self.balances.as_mut(), value),
             Change::Memory(Previous(Memory { start, bytes })) => {
+                self.memory
+                    .record_stack_pointer((*start + bytes.len()) as Word);
                 self.memory[*start..(*start + bytes.len())].copy_from_slice(&bytes[..])
             }
             Change::Context(Previous(value)) => self.context = value.clone(),
@@ -468,7 +470,7 @@ fn invert_receipts_ctx(ctx: &mut ReceiptsCtx, value: &VecState<Option<Receipt>>)
     invert_vec(ctx_mut.receipts_mut(), value);
 }
 
-impl<S, Tx, Ecal> PartialEq for Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> PartialEq for Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: PartialEq,
 {
@@ -74,7 +74,7 @@ pub struct Record<S>(pub(super) S, pub(super) Vec<StorageDelta>)
 where
     S: InterpreterStorage;
 
-impl<S, Tx, Ecal> Interpreter<Record<S>, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<Record<S>, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
@@ -82,7 +82,7 @@ where
     /// Remove the [`Recording`] wrapper from the storage.
     /// Recording storage changes has an overhead so it's
     /// useful to be able to remove it once the diff is generated.
-    pub fn remove_recording(self) -> Interpreter<S, Tx, Ecal> {
+    pub fn remove_recording(self) -> Interpreter<S, Tx, Ecal, Observer> {
         Interpreter {
             registers: self.registers,
             memory: self.memory,
@@ -95,9 +95,21 @@ where
             context: self.context,
             balances: self.balances,
             panic_context: self.panic_context,
+            panic_location: self.panic_location,
+            instructions_executed: self.instructions_executed,
+            gas_limit: self.gas_limit,
+            predicate_gas_used: self.predicate_gas_used,
+            allow_undeclared_contracts: self.allow_undeclared_contracts,
+            touched_contracts: self.touched_contracts,
             profiler: self.profiler,
+            #[cfg(feature = "trace")]
+            tracer: self.tracer,
+            metrics: self.metrics,
+            call_gas: self.call_gas,
+            code_cache: self.code_cache,
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
+            observer: self.observer,
         }
     }
 
@@ -139,7 +151,7 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
@@ -148,7 +160,7 @@ where
     /// record any changes this VM makes to it's storage.
     /// Recording storage changes has an overhead so should
     /// be used in production.
-    pub fn add_recording(self) -> Interpreter<Record<S>, Tx, Ecal> {
+    pub fn add_recording(self) -> Interpreter<Record<S>, Tx, Ecal, Observer> {
         Interpreter {
             registers: self.registers,
             memory: self.memory,
@@ -161,9 +173,21 @@ where
             context: self.context,
             balances: self.balances,
             panic_context: self.panic_context,
+            panic_location: self.panic_location,
+            instructions_executed: self.instructions_executed,
+            gas_limit: self.gas_limit,
+            predicate_gas_used: self.predicate_gas_used,
+            allow_undeclared_contracts: self.allow_undeclared_contracts,
+            touched_contracts: self.touched_contracts,
             profiler: self.profiler,
+            #[cfg(feature = "trace")]
+            tracer: self.tracer,
+            metrics: self.metrics,
+            call_gas: self.call_gas,
+            code_cache: self.code_cache,
             interpreter_params: self.interpreter_params,
             ecal_state: self.ecal_state,
+            observer: self.observer,
         }
     }
 
@@ -29,8 +29,8 @@ where
     const INC_PC: bool = true;
 
     /// ECAL opcode handler
-    fn ecal<S, Tx>(
-        vm: &mut Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        vm: &mut Interpreter<S, Tx, Self, Observer>,
         a: RegId,
         b: RegId,
         c: RegId,
@@ -40,8 +40,8 @@ where
 
 /// Default ECAL opcode handler function, which charges for `noop` and does nothing.
 impl EcalHandler for NotSupportedEcal {
-    fn ecal<S, Tx>(
-        _: &mut Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        _: &mut Interpreter<S, Tx, Self, Observer>,
         _: RegId,
         _: RegId,
         _: RegId,
@@ -57,8 +57,8 @@ pub struct PredicateErrorEcal;
 
 /// ECAL is not allowed in predicates
 impl EcalHandler for PredicateErrorEcal {
-    fn ecal<S, Tx>(
-        _vm: &mut Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        _vm: &mut Interpreter<S, Tx, Self, Observer>,
         _: RegId,
         _: RegId,
         _: RegId,
@@ -68,7 +68,7 @@ impl EcalHandler for PredicateErrorEcal {
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Ecal: EcalHandler,
 {
@@ -0,0 +1,231 @@
+//! The `ECOP` and `EPAR` instructions, operating on the points of an elliptic
+//! curve.
+//!
+//! The only curve is BN254, also known as alt_bn128, with the id `0`. Every other
+//! curve id panics with [`PanicReason::UnsupportedCurveId`]. The support of the curve is
+//! part of the consensus, so it isn't behind a feature.
+//!
+//! # Memory layout
+//!
+//! The points are in affine coordinates, every integer is 32 bytes big-endian:
+//!
+//! - A point of G1 is 64 bytes, its `x` and `y` coordinates, elements of the base field
+//!   lower than its modulus.
+//! - A point of G2 is 128 bytes, its `x` and `y` coordinates, elements of the quadratic
+//!   extension of the base field, each encoded as its imaginary part followed by its real
+//!   part.
+//! - The point at infinity is encoded with zeroes for both coordinates.
+//! - A scalar is any 32-byte integer, reduced modulo the order of the groups.
+//!
+//! `ECOP` reads at `points_addr`, depending on `operation`:
+//!
+//! - `0`, addition: two points of G1, 128 bytes.
+//! - `1`, scalar multiplication: a point of G1 followed by a scalar, 96 bytes.
+//!
+//! and writes the resulting point of G1, 64 bytes, at `dst_addr`.
+//!
+//! `EPAR` reads `pairs` pairs of a point of G1 followed by a point of G2, 192
+//! bytes each, at `points_addr`. It sets `success` to `1` if the product of their
+//! pairings is one, and to `0` otherwise.
+//!
+//! Both panic with [`PanicReason::InvalidEllipticCurvePoint`] if a point isn't on
+//! the curve or in its group.
+//!
+//! # Gas
+//!
+//! `ECOP` is charged with the `ecop` dependent cost per group operation: one for an
+//! addition, and one per bit of the scalar for a multiplication. `EPAR` is charged with
+//! the `epar` dependent cost per pair.
+
+use super::{
+    internal::inc_pc,
+    memory::try_mem_write,
+    Interpreter,
+    MemoryRange,
+};
+use crate::{
+    constraints::reg_key::*,
+    error::SimpleResult,
+};
+
+use fuel_asm::PanicReason;
+use fuel_types::{
+    RegisterId,
+    Word,
+};
+
+/// The id of the BN254 curve.
+const BN254: Word = 0;
+
+/// The `ECOP` operation adding two points.
+const ADD: Word = 0;
+/// The `ECOP` operation multiplying a point by a scalar.
+const MUL: Word = 1;
+
+/// The group operations charged for an `ECOP` addition.
+const ADD_UNITS: Word = 1;
+/// The group operations charged for an `ECOP` scalar multiplication, one per bit of
+/// the scalar.
+const MUL_UNITS: Word = (SCALAR_LEN * 8) as Word;
+
+const G1_LEN: usize = 64;
+const G2_LEN: usize = 128;
+const SCALAR_LEN: usize = 32;
+
+/// The length of the point written by `ECOP`.
+pub(super) const ECOP_OUTPUT_LEN: usize = G1_LEN;
+/// The length of a pair of points read by `EPAR`.
+pub(super) const EPAR_PAIR_LEN: usize = G1_LEN + G2_LEN;
+
+/// The length of the points read by the `ECOP` `operation`, or `None` if the
+/// operation is unsupported.
+pub(super) const fn ecop_input_len(operation: Word) -> Option<usize> {
+    match operation {
+        ADD => Some(2 * G1_LEN),
+        MUL => Some(G1_LEN + SCALAR_LEN),
+        _ => None,
+    }
+}
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
+    /// `ECOP`, charged per group operation.
+    pub(crate) fn ec_operation(
+        &mut self,
+        dst_addr: Word,
+        curve_id: Word,
+        operation: Word,
+        points_addr: Word,
+    ) -> SimpleResult<()> {
+        if curve_id != BN254 {
+            return Err(PanicReason::UnsupportedCurveId.into())
+        }
+        let (units, len) = match (operation, ecop_input_len(operation)) {
+            (ADD, Some(len)) => (ADD_UNITS, len),
+            (MUL, Some(len)) => (MUL_UNITS, len),
+            _ => return Err(PanicReason::UnsupportedOperationType.into()),
+        };
+        self.dependent_gas_charge(self.gas_costs().ecop, units)?;
+
+        let input = MemoryRange::new(points_addr, len)?.read(self.memory.as_ref())?;
+        let result = match operation {
+            ADD => bn254::add(input)?,
+            _ => bn254::mul(input)?,
+        };
+
+        let owner = self.ownership_registers();
+        try_mem_write(dst_addr, &result, owner, self.memory.as_mut())?;
+
+        Ok(inc_pc(self.registers.pc_mut())?)
+    }
+
+    /// `EPAR`, charged per pair.
+    pub(crate) fn ec_pairing(
+        &mut self,
+        success: RegisterId,
+        curve_id: Word,
+        pairs: Word,
+        points_addr: Word,
+    ) -> SimpleResult<()> {
+        let success = WriteRegKey::try_from(success)?;
+        if curve_id != BN254 {
+            return Err(PanicReason::UnsupportedCurveId.into())
+        }
+        self.dependent_gas_charge(self.gas_costs().epar, pairs)?;
+
+        let len = pairs
+            .checked_mul(EPAR_PAIR_LEN as Word)
+            .ok_or(PanicReason::MemoryOverflow)?;
+        let input = MemoryRange::new(points_addr, len)?.read(self.memory.as_ref())?;
+        let result = bn254::pairing_check(input)?;
+
+        let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
+        w[success] = result as Word;
+
+        Ok(inc_pc(pc)?)
+    }
+}
+
+mod bn254 {
+    use super::{
+        G1_LEN,
+        G2_LEN,
+    };
+
+    use alloc::vec::Vec;
+    use fuel_asm::PanicReason;
+    use substrate_bn::{
+        arith::U256,
+        pairing_batch,
+        AffineG1,
+        AffineG2,
+        Fq,
+        Fq2,
+        Fr,
+        Group,
+        Gt,
+        G1,
+        G2,
+    };
+
+    fn read_fq(bytes: &[u8]) -> Result<Fq, PanicReason> {
+        Fq::from_slice(bytes).map_err(|_| PanicReason::InvalidEllipticCurvePoint)
+    }
+
+    fn read_g1(bytes: &[u8]) -> Result<G1, PanicReason> {
+        let x = read_fq(&bytes[..32])?;
+        let y = read_fq(&bytes[32..G1_LEN])?;
+        if x.is_zero() && y.is_zero() {
+            return Ok(G1::zero())
+        }
+        AffineG1::new(x, y)
+            .map(Into::into)
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)
+    }
+
+    fn read_g2(bytes: &[u8]) -> Result<G2, PanicReason> {
+        let x = Fq2::new(read_fq(&bytes[32..64])?, read_fq(&bytes[..32])?);
+        let y = Fq2::new(read_fq(&bytes[96..G2_LEN])?, read_fq(&bytes[64..96])?);
+        if x.is_zero() && y.is_zero() {
+            return Ok(G2::zero())
+        }
+        AffineG2::new(x, y)
+            .map(Into::into)
+            .map_err(|_| PanicReason::InvalidEllipticCurvePoint)
+    }
+
+    fn write_g1(point: G1) -> [u8; G1_LEN] {
+        let mut bytes = [0u8; G1_LEN];
+        // The point at infinity has no affine coordinates, and is left as zeroes
+        if let Some(point) = AffineG1::from_jacobian(point) {
+            point
+                .x()
+                .to_big_endian(&mut bytes[..32])
+                .expect("The coordinate is 32 bytes");
+            point
+                .y()
+                .to_big_endian(&mut bytes[32..])
+                .expect("The coordinate is 32 bytes");
+        }
+        bytes
+    }
+
+    pub(super) fn add(input: &[u8]) -> Result<[u8; G1_LEN], PanicReason> {
+        let lhs = read_g1(&input[..G1_LEN])?;
+        let rhs = read_g1(&input[G1_LEN..])?;
+        Ok(write_g1(lhs + rhs))
+    }
+
+    pub(super) fn mul(input: &[u8]) -> Result<[u8; G1_LEN], PanicReason> {
+        let point = read_g1(&input[..G1_LEN])?;
+        let scalar = U256::from_slice(&input[G1_LEN..]).expect("The scalar is 32 bytes");
+        Ok(write_g1(point * Fr::new_mul_factor(scalar)))
+    }
+
+    pub(super) fn pairing_check(input: &[u8]) -> Result<bool, PanicReason> {
+        let pairs = input
+            .chunks_exact(G1_LEN + G2_LEN)
+            .map(|pair| Ok((read_g1(&pair[..G1_LEN])?, read_g2(&pair[G1_LEN..])?)))
+            .collect::<Result<Vec<_>, PanicReason>>()?;
+        Ok(pairing_batch(&pairs) == Gt::one())
+    }
+}
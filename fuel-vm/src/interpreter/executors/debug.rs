@@ -5,15 +5,22 @@ use crate::{
         ExecutableTransaction,
         Interpreter,
     },
-    state::ProgramState,
+    observer::InstructionObserver,
+    state::{
+        ExecuteState,
+        ProgramState,
+    },
     storage::InterpreterStorage,
 };
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+use fuel_asm::Instruction;
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     Ecal: EcalHandler,
+    Observer: InstructionObserver,
 {
     /// Continue the execution from a previously interrupted program flow.
     pub fn resume(&mut self) -> Result<ProgramState, InterpreterError<S::DataError>> {
@@ -28,15 +35,96 @@ where
 
             ProgramState::Revert(w) => Ok(ProgramState::Revert(w)),
 
-            ProgramState::RunProgram(_) => self.run_program(),
+            ProgramState::RunProgram(_) => match self.run_program() {
+                Ok(state) if state.is_debug() => Ok(state),
+                program => self.finalize_program(program),
+            },
 
             ProgramState::VerifyPredicate(_) => unimplemented!(),
         }?;
 
-        if state.is_debug() {
-            self.debugger_set_last_state(state);
-        }
+        self.debugger_set_last_state(state);
 
         Ok(state)
     }
+
+    /// Peek the instruction located in `$m[$pc]`, that will be executed by the next
+    /// step.
+    ///
+    /// Returns `None` if the memory at `$pc` isn't executable or doesn't contain a
+    /// valid instruction.
+    pub fn next_instruction(&self) -> Option<Instruction> {
+        let raw = self.fetch_instruction().ok()?;
+        Instruction::try_from(raw).ok()
+    }
+
+    /// Execute exactly one instruction located in `$m[$pc]`, stepping into the
+    /// call frame if the instruction is a `CALL`.
+    ///
+    /// Breakpoints and single-stepping mode are ignored. Gas is charged and
+    /// receipts are produced as during the regular execution. When the script
+    /// terminates, its `Panic` and `ScriptResult` receipts are produced as by a full
+    /// run; a panic, including running out of gas, is then returned as an error.
+    pub fn single_step(
+        &mut self,
+    ) -> Result<ExecuteState, InterpreterError<S::DataError>> {
+        // Check whether the instruction will be executed in a call context
+        let in_call = !self.frames.is_empty();
+
+        let result = self.fetch_instruction().and_then(|raw| {
+            self.instruction_inner(raw)
+                .map_err(|e| InterpreterError::from_runtime(e, raw))
+        });
+
+        let (state, program) = match result {
+            Ok(state @ ExecuteState::Return(r)) if !in_call => {
+                (state, ProgramState::Return(r))
+            }
+            Ok(state @ ExecuteState::ReturnData(d)) if !in_call => {
+                (state, ProgramState::ReturnData(d))
+            }
+            Ok(state @ ExecuteState::Revert(r)) => (state, ProgramState::Revert(r)),
+            Ok(state) => {
+                self.debugger_set_current_location();
+                return Ok(state)
+            }
+            Err(e) => match e.instruction_result() {
+                // The panic is caught into a receipt as by a full run, then returned
+                Some(panic) => {
+                    let program = self.finalize_program(Err(e))?;
+                    self.debugger_set_last_state(program);
+                    return Err(InterpreterError::PanicInstruction(panic))
+                }
+                None => return Err(e),
+            },
+        };
+
+        // The script terminated, so it is finalized the same way as by a full run
+        let program = self.finalize_program(Ok(program))?;
+        self.debugger_set_last_state(program);
+
+        Ok(state)
+    }
+
+    /// Execute the instruction located in `$m[$pc]`. If it enters a new call
+    /// frame, the execution continues until that frame returns.
+    pub fn step_over(&mut self) -> Result<ExecuteState, InterpreterError<S::DataError>> {
+        let depth = self.frames.len();
+
+        let mut state = self.single_step()?;
+        if self.frames.len() <= depth {
+            return Ok(state)
+        }
+
+        while self.frames.len() > depth {
+            // A revert terminates the execution from any call context
+            if let ExecuteState::Revert(_) = state {
+                return Ok(state)
+            }
+            state = self.single_step()?;
+        }
+
+        // The entered call frame returned to the caller
+        Ok(ExecuteState::Proceed)
+    }
 }
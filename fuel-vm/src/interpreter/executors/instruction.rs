@@ -15,10 +15,17 @@ use crate::{
         ExecutableTransaction,
         Interpreter,
     },
+    observer::{
+        InstructionObserver,
+        VmStateView,
+    },
     state::ExecuteState,
     storage::InterpreterStorage,
 };
 
+#[cfg(feature = "trace")]
+use crate::tracer::InstructionResult;
+
 use fuel_asm::{
     wideint,
     Instruction,
@@ -31,11 +38,12 @@ use fuel_types::Word;
 
 use core::ops::Div;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     Ecal: EcalHandler,
+    Observer: InstructionObserver,
 {
     /// Execute the current instruction located in `$m[$pc]`.
     pub fn execute(&mut self) -> Result<ExecuteState, InterpreterError<S::DataError>> {
@@ -47,16 +55,12 @@ where
     /// returning `None` if the location is unreadable.
     fn fetch_instruction_bytes(&self, addr: Word) -> Option<RawInstruction> {
         let start: usize = addr.try_into().ok()?;
-        let end = start.checked_add(Instruction::SIZE)?;
-        let bytes = self.memory.get(start..end)?;
-        Some(RawInstruction::from_be_bytes(
-            bytes.try_into().expect("Slice len mismatch"),
-        ))
+        fuel_asm::raw_instruction_at(self.memory.as_ref(), start).ok()
     }
 
     /// Reads the current instruction located in `$m[$pc]`,
     /// performing memory boundary checks.
-    fn fetch_instruction(
+    pub(crate) fn fetch_instruction(
         &self,
     ) -> Result<RawInstruction, InterpreterError<S::DataError>> {
         let pc = self.registers[RegId::PC];
@@ -81,7 +85,7 @@ where
         raw: R,
     ) -> Result<ExecuteState, InterpreterError<S::DataError>> {
         if self.debugger.is_active() {
-            let debug = self.eval_debugger_state();
+            let debug = self.eval_debugger_state(raw.into());
             if !debug.should_continue() {
                 return Ok(debug.into())
             }
@@ -91,7 +95,151 @@ where
             .map_err(|e| InterpreterError::from_runtime(e, raw.into()))
     }
 
-    fn instruction_inner(
+    pub(crate) fn instruction_inner(
+        &mut self,
+        raw: RawInstruction,
+    ) -> IoResult<ExecuteState, S::DataError> {
+        #[cfg(feature = "profile-coverage")]
+        let location = self
+            .profiler
+            .has_coverage_receiver()
+            .then(|| self.coverage_location());
+
+        #[cfg(feature = "trace")]
+        let traced = self.trace_before_instruction(raw);
+
+        if Observer::ENABLED {
+            self.observe_before_instruction(raw);
+        }
+
+        let registers = (
+            self.registers[RegId::PC],
+            self.registers[RegId::IS],
+            self.registers[RegId::FP],
+            self.context.is_internal(),
+        );
+
+        let result = self
+            .count_instruction()
+            .and_then(|_| self.dispatch_instruction(raw));
+
+        // The registers may be already updated by the failed instruction, so the
+        // location is taken from their values before the execution
+        if result.is_err() {
+            let (pc, is, fp, internal) = registers;
+            self.panic_location = Some(self.panic_location_at(pc, is, fp, internal));
+        }
+
+        // The instructions only write below the stack pointer and above the heap
+        // pointer, so the memory can be reset by zeroing only up to these bounds
+        self.memory
+            .record_pointers(self.registers[RegId::SP], self.registers[RegId::HP]);
+
+        #[cfg(feature = "profile-coverage")]
+        if let (Ok(_), Some((contract, pc))) = (&result, location) {
+            self.profiler.coverage_hit(contract, pc);
+        }
+
+        #[cfg(feature = "trace")]
+        if traced {
+            self.trace_after_instruction(raw, &result);
+        }
+
+        if Observer::ENABLED {
+            self.observe_after_instruction(raw, &result);
+        }
+
+        result
+    }
+
+    fn count_instruction(&mut self) -> IoResult<(), S::DataError> {
+        if let Some(max_instructions) = self.interpreter_params.max_instructions {
+            if self.instructions_executed >= max_instructions {
+                return Err(PanicReason::InstructionLimitExceeded.into())
+            }
+        }
+        self.instructions_executed = self.instructions_executed.saturating_add(1);
+        Ok(())
+    }
+
+    /// Returns `true` if the tracer is set.
+    #[cfg(feature = "trace")]
+    fn trace_before_instruction(&mut self, raw: RawInstruction) -> bool {
+        let contract = self.contract_id();
+        let Some(tracer) = self.tracer.get_mut() else {
+            return false
+        };
+        tracer.before_instruction(&VmStateView::new(
+            raw,
+            &self.registers,
+            self.memory.as_ref(),
+            &self.context,
+            contract,
+        ));
+        true
+    }
+
+    #[cfg(feature = "trace")]
+    fn trace_after_instruction(
+        &mut self,
+        raw: RawInstruction,
+        result: &IoResult<ExecuteState, S::DataError>,
+    ) {
+        let result = match result {
+            Ok(_) => InstructionResult::Success,
+            Err(RuntimeError::Recoverable(reason)) => InstructionResult::Panic(*reason),
+            Err(_) => InstructionResult::Halt,
+        };
+        let contract = self.contract_id();
+        if let Some(tracer) = self.tracer.get_mut() {
+            tracer.after_instruction(
+                &VmStateView::new(
+                    raw,
+                    &self.registers,
+                    self.memory.as_ref(),
+                    &self.context,
+                    contract,
+                ),
+                &result,
+            );
+        }
+    }
+
+    fn observe_before_instruction(&mut self, raw: RawInstruction) {
+        let contract = self.contract_id();
+        self.observer.will_execute(&VmStateView::new(
+            raw,
+            &self.registers,
+            self.memory.as_ref(),
+            &self.context,
+            contract,
+        ));
+    }
+
+    fn observe_after_instruction(
+        &mut self,
+        raw: RawInstruction,
+        result: &IoResult<ExecuteState, S::DataError>,
+    ) {
+        let result = match result {
+            Ok(state) => Ok(*state),
+            Err(RuntimeError::Recoverable(reason)) => Err(*reason),
+            Err(_) => return,
+        };
+        let contract = self.contract_id();
+        self.observer.did_execute(
+            &VmStateView::new(
+                raw,
+                &self.registers,
+                self.memory.as_ref(),
+                &self.context,
+                contract,
+            ),
+            &result,
+        );
+    }
+
+    fn dispatch_instruction(
         &mut self,
         raw: RawInstruction,
     ) -> IoResult<ExecuteState, S::DataError> {
@@ -104,6 +252,9 @@ where
             return Err(PanicReason::ContractInstructionNotAllowed.into())
         }
 
+        #[cfg(feature = "profile-gas")]
+        self.profile_execution(instruction.opcode());
+
         // Short-hand for retrieving the value from the register with the given ID.
         // We use a macro to "close over" `self.registers` without taking ownership of it.
         macro_rules! r {
@@ -899,6 +1050,30 @@ where
                 self.transfer_output(r!(a), r!(b), r!(c), r!(d))?;
             }
 
+            Instruction::BSIZ(bsiz) => {
+                // We charge for the gas inside of the `blob_size` function.
+                let (a, b) = bsiz.unpack();
+                self.blob_size(a.into(), r!(b))?;
+            }
+
+            Instruction::BLDD(bldd) => {
+                // We charge for the gas inside of the `blob_load_data` function.
+                let (a, b, c, d) = bldd.unpack();
+                self.blob_load_data(r!(a), r!(b), r!(c), r!(d))?;
+            }
+
+            Instruction::ECOP(ecop) => {
+                // We charge for the gas inside of the `ec_operation` function.
+                let (a, b, c, d) = ecop.unpack();
+                self.ec_operation(r!(a), r!(b), r!(c), r!(d))?;
+            }
+
+            Instruction::EPAR(epar) => {
+                // We charge for the gas inside of the `ec_pairing` function.
+                let (a, b, c, d) = epar.unpack();
+                self.ec_pairing(a.into(), r!(b), r!(c), r!(d))?;
+            }
+
             Instruction::ECAL(ecal) => {
                 let (a, b, c, d) = ecal.unpack();
                 self.external_call(a, b, c, d)?;
@@ -49,11 +49,11 @@ fn cant_write_to_reserved_registers(raw_random_instruction: u32) -> TestResult {
         _ => (),
     }
 
-    let fee_params = FeeParameters::default().with_gas_price_factor(1);
-    let consensus_params = ConsensusParameters {
-        fee_params,
-        ..Default::default()
-    };
+    let fee_params = FeeParameters::default()
+        .with_gas_price_factor(1)
+        .expect("The gas price factor is not zero");
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_fee_params(fee_params);
 
     let mut vm = Interpreter::<_, _>::with_storage(
         MemoryStorage::default(),
@@ -236,6 +236,10 @@ fn writes_to_ra(opcode: Opcode) -> bool {
         Opcode::CFE => false,
         Opcode::CFS => false,
         Opcode::ECAL => true,
+        Opcode::BSIZ => true,
+        Opcode::BLDD => false,
+        Opcode::ECOP => false,
+        Opcode::EPAR => true,
     }
 }
 
@@ -351,5 +355,9 @@ fn writes_to_rb(opcode: Opcode) -> bool {
         Opcode::CFE => false,
         Opcode::CFS => false,
         Opcode::ECAL => true,
+        Opcode::BSIZ => false,
+        Opcode::BLDD => false,
+        Opcode::ECOP => false,
+        Opcode::EPAR => false,
     }
 }
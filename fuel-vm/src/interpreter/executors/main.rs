@@ -24,14 +24,21 @@ use crate::{
         ExecutableTransaction,
         InitialBalances,
         Interpreter,
+        MemoryInstance,
+        NotSupportedEcal,
         RuntimeBalances,
     },
+    observer::{
+        InstructionObserver,
+        NoopObserver,
+    },
     predicate::RuntimePredicate,
     prelude::{
         BugVariant,
         RuntimeError,
     },
     state::{
+        DryRun,
         ExecuteState,
         ProgramState,
         StateTransition,
@@ -40,6 +47,7 @@ use crate::{
     storage::{
         InterpreterStorage,
         PredicateStorage,
+        TransactionalStorage,
     },
 };
 
@@ -138,9 +146,26 @@ where
     ) -> Result<PredicatesChecked, PredicateVerificationFailed>
     where
         <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        Self::check_predicates_with_observer(checked, params, &mut NoopObserver)
+    }
+
+    /// Initialize the VM with the provided transaction and check all predicates defined
+    /// in the inputs, notifying the `observer` around every instruction they execute.
+    ///
+    /// The storage provider is not used since contract opcodes are not allowed for
+    /// predicates.
+    pub fn check_predicates_with_observer<Observer>(
+        checked: &Checked<Tx>,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed>
+    where
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+        Observer: InstructionObserver,
     {
         let tx = checked.transaction();
-        Self::run_predicate(PredicateRunKind::Verifying(tx), params)
+        Self::run_predicate(PredicateRunKind::Verifying(tx), params, observer)
     }
 
     /// Initialize the VM with the provided transaction and check all predicates defined
@@ -166,6 +191,27 @@ where
         Ok(predicates_checked)
     }
 
+    /// Initialize the VM with the provided transaction and check all predicates defined
+    /// in the inputs in parallel on the rayon thread pool.
+    ///
+    /// Returns the same result as the sequential [`Self::check_predicates`], including
+    /// the error of the predicate with the lowest input index.
+    ///
+    /// The storage provider is not used since contract opcodes are not allowed for
+    /// predicates.
+    #[cfg(feature = "rayon")]
+    pub fn check_predicates_parallel(
+        checked: &Checked<Tx>,
+        params: &CheckPredicateParams,
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed>
+    where
+        Tx: Send + Sync,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        let tx = checked.transaction();
+        Self::run_predicate_parallel(PredicateRunKind::Verifying(tx), params)
+    }
+
     /// Initialize the VM with the provided transaction, check all predicates defined in
     /// the inputs and set the predicate_gas_used to be the actual gas consumed during
     /// execution for each predicate.
@@ -176,8 +222,29 @@ where
         transaction: &mut Tx,
         params: &CheckPredicateParams,
     ) -> Result<PredicatesChecked, PredicateVerificationFailed> {
-        let predicates_checked =
-            Self::run_predicate(PredicateRunKind::Estimating(transaction), params)?;
+        Self::estimate_predicates_with_observer(transaction, params, &mut NoopObserver)
+    }
+
+    /// Initialize the VM with the provided transaction, check all predicates defined in
+    /// the inputs and set the predicate_gas_used to be the actual gas consumed during
+    /// execution for each predicate, notifying the `observer` around every instruction
+    /// they execute.
+    ///
+    /// The storage provider is not used since contract opcodes are not allowed for
+    /// predicates.
+    pub fn estimate_predicates_with_observer<Observer>(
+        transaction: &mut Tx,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed>
+    where
+        Observer: InstructionObserver,
+    {
+        let predicates_checked = Self::run_predicate(
+            PredicateRunKind::Estimating(transaction),
+            params,
+            observer,
+        )?;
         Ok(predicates_checked)
     }
 
@@ -204,6 +271,25 @@ where
         Ok(predicates_checked)
     }
 
+    /// Initialize the VM with the provided transaction, check all predicates defined in
+    /// the inputs and set the predicate_gas_used to be the actual gas consumed during
+    /// execution for each predicate in parallel on the rayon thread pool.
+    ///
+    /// Returns the same result as the sequential [`Self::estimate_predicates`].
+    ///
+    /// The storage provider is not used since contract opcodes are not allowed for
+    /// predicates.
+    #[cfg(feature = "rayon")]
+    pub fn estimate_predicates_parallel(
+        transaction: &mut Tx,
+        params: &CheckPredicateParams,
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed>
+    where
+        Tx: Send + Sync,
+    {
+        Self::run_predicate_parallel(PredicateRunKind::Estimating(transaction), params)
+    }
+
     async fn run_predicate_async<E>(
         kind: PredicateRunKind<'_, Tx>,
         params: &CheckPredicateParams,
@@ -230,6 +316,7 @@ where
                         predicate_action,
                         predicate,
                         my_params,
+                        NoopObserver,
                     )
                 });
 
@@ -242,10 +329,47 @@ where
         Self::finalize_check_predicate(kind, checks, params)
     }
 
-    fn run_predicate(
+    #[cfg(feature = "rayon")]
+    fn run_predicate_parallel(
         kind: PredicateRunKind<'_, Tx>,
         params: &CheckPredicateParams,
-    ) -> Result<PredicatesChecked, PredicateVerificationFailed> {
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed>
+    where
+        Tx: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        let predicate_action = PredicateAction::from(&kind);
+        let tx = kind.tx();
+
+        // The indexed parallel iterator collects the results in the order of inputs, so
+        // the first error is the same as in the sequential run.
+        let checks = (0..tx.inputs().len())
+            .into_par_iter()
+            .filter_map(|index| {
+                let predicate = RuntimePredicate::from_tx(tx, params.tx_offset, index)?;
+                Some(Self::check_predicate(
+                    tx.clone(),
+                    index,
+                    predicate_action,
+                    predicate,
+                    params.clone(),
+                    NoopObserver,
+                ))
+            })
+            .collect();
+
+        Self::finalize_check_predicate(kind, checks, params)
+    }
+
+    fn run_predicate<Observer>(
+        kind: PredicateRunKind<'_, Tx>,
+        params: &CheckPredicateParams,
+        observer: &mut Observer,
+    ) -> Result<PredicatesChecked, PredicateVerificationFailed>
+    where
+        Observer: InstructionObserver,
+    {
         let predicate_action = PredicateAction::from(&kind);
         let mut checks = vec![];
 
@@ -261,6 +385,7 @@ where
                     predicate_action,
                     predicate,
                     params.clone(),
+                    &mut *observer,
                 ));
             }
         }
@@ -268,13 +393,17 @@ where
         Self::finalize_check_predicate(kind, checks, params)
     }
 
-    fn check_predicate(
+    fn check_predicate<Observer>(
         tx: Tx,
         index: usize,
         predicate_action: PredicateAction,
         predicate: RuntimePredicate,
         params: CheckPredicateParams,
-    ) -> Result<(Word, usize), PredicateVerificationFailed> {
+        observer: Observer,
+    ) -> Result<(Word, usize), PredicateVerificationFailed>
+    where
+        Observer: InstructionObserver,
+    {
         match &tx.inputs()[index] {
             Input::CoinPredicate(CoinPredicate {
                 owner: address,
@@ -300,10 +429,18 @@ where
 
         let max_gas_per_tx = params.max_gas_per_tx;
         let max_gas_per_predicate = params.max_gas_per_predicate;
+        let max_instructions = params.max_instructions;
         let zero_gas_price = 0;
         let interpreter_params = InterpreterParams::new(zero_gas_price, params);
 
-        let mut vm = Self::with_storage(PredicateStorage {}, interpreter_params);
+        let mut vm =
+            Interpreter::<_, Tx, NotSupportedEcal, _>::with_memory_ecal_and_observer(
+                MemoryInstance::new(),
+                PredicateStorage {},
+                interpreter_params,
+                NotSupportedEcal,
+                observer,
+            );
 
         let available_gas = match predicate_action {
             PredicateAction::Verifying => {
@@ -334,6 +471,30 @@ where
             .checked_sub(vm.remaining_gas())
             .ok_or_else(|| Bug::new(BugVariant::GlobalGasUnderflow))?;
 
+        if let PredicateAction::Estimating = predicate_action {
+            if let Err(PredicateVerificationFailed::OutOfGas) = result {
+                return Err(PredicateVerificationFailed::PredicateGasExceeded {
+                    index,
+                    limit: available_gas,
+                });
+            }
+
+            if let (
+                Err(PredicateVerificationFailed::PanicInstruction(panic)),
+                Some(limit),
+            ) = (&result, max_instructions)
+            {
+                if *panic.reason() == PanicReason::InstructionLimitExceeded {
+                    return Err(
+                        PredicateVerificationFailed::PredicateInstructionLimitExceeded {
+                            index,
+                            limit,
+                        },
+                    );
+                }
+            }
+        }
+
         if let PredicateAction::Verifying = predicate_action {
             if !is_successful {
                 result?;
@@ -399,7 +560,7 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
 {
@@ -466,11 +627,12 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     Ecal: EcalHandler,
+    Observer: InstructionObserver,
 {
     fn update_transaction_outputs(
         &mut self,
@@ -498,6 +660,23 @@ where
             )?;
             self.update_transaction_outputs()?;
             ProgramState::Return(1)
+        } else if self.transaction().as_script().is_none() {
+            // The `Upgrade`, `Upload` and `Blob` only spend their inputs, the upgrade,
+            // the upload and the blob themselves are applied by the block producer.
+            let balances = RuntimeBalances::try_from(self.initial_balances.clone())?;
+            Self::finalize_outputs(
+                &mut self.tx,
+                &gas_costs,
+                &fee_params,
+                &base_asset_id,
+                false,
+                0,
+                &self.initial_balances,
+                &balances,
+                gas_price,
+            )?;
+            self.update_transaction_outputs()?;
+            ProgramState::Return(1)
         } else {
             if self.transaction().inputs().iter().any(|input| {
                 if let Input::Contract(contract) = input {
@@ -511,11 +690,9 @@ where
                 return Err(InterpreterError::Panic(PanicReason::ContractNotInInputs));
             }
 
-            let gas_limit;
             let is_empty_script;
             if let Some(script) = self.transaction().as_script() {
                 let offset = (self.tx_offset() + script.script_offset()) as Word;
-                gas_limit = *script.script_gas_limit();
                 is_empty_script = script.script().is_empty();
 
                 self.registers[RegId::PC] = offset;
@@ -537,67 +714,98 @@ where
                 Ok(ProgramState::Return(return_val))
             };
 
-            let gas_used = gas_limit
-                .checked_sub(self.remaining_gas())
-                .ok_or_else(|| Bug::new(BugVariant::GlobalGasUnderflow))?;
-
-            // Catch VM panic and don't propagate, generating a receipt
-            let (status, program) = match program {
-                Ok(s) => {
-                    // either a revert or success
-                    let res = if let ProgramState::Revert(_) = &s {
-                        ScriptExecutionResult::Revert
-                    } else {
-                        ScriptExecutionResult::Success
-                    };
-                    (res, s)
+            // The paused program is finalized once the debugger resumes it to the end
+            match program {
+                Ok(state) if state.is_debug() => {
+                    self.debugger_set_last_state(state);
+                    state
                 }
+                program => self.finalize_program(program)?,
+            }
+        };
 
-                Err(e) => match e.instruction_result() {
-                    Some(result) => {
-                        self.append_panic_receipt(result);
-
-                        (ScriptExecutionResult::Panic, ProgramState::Revert(0))
-                    }
-
-                    // This isn't a specified case of an erroneous program and should be
-                    // propagated. If applicable, OS errors will fall into this category.
-                    None => return Err(e),
-                },
-            };
+        Ok(state)
+    }
 
-            let receipt = Receipt::script_result(status, gas_used);
+    /// Produce the receipts of the terminated script `program` and finalize its
+    /// outputs. A panic is caught into a `Panic` receipt and reverts the script.
+    pub(crate) fn finalize_program(
+        &mut self,
+        program: Result<ProgramState, InterpreterError<S::DataError>>,
+    ) -> Result<ProgramState, InterpreterError<S::DataError>> {
+        let Some(gas_limit) = self
+            .transaction()
+            .as_script()
+            .map(|script| *script.script_gas_limit())
+        else {
+            return program
+        };
+        let gas_costs = self.gas_costs().clone();
+        let fee_params = *self.fee_params();
+        let base_asset_id = *self.base_asset_id();
 
-            self.receipts.push(receipt)?;
+        let gas_used = gas_limit
+            .checked_sub(self.remaining_gas())
+            .ok_or_else(|| Bug::new(BugVariant::GlobalGasUnderflow))?;
 
-            if program.is_debug() {
-                self.debugger_set_last_state(program);
+        // Catch VM panic and don't propagate, generating a receipt
+        let (status, program) = match program {
+            Ok(s) => {
+                // either a revert or success
+                let res = if let ProgramState::Revert(_) = &s {
+                    ScriptExecutionResult::Revert
+                } else {
+                    ScriptExecutionResult::Success
+                };
+                (res, s)
             }
 
-            if let Some(script) = self.tx.as_script_mut() {
-                let receipts_root = self.receipts.root();
-                *script.receipts_root_mut() = receipts_root;
-            }
+            Err(e) => match e.instruction_result() {
+                Some(result) => {
+                    self.append_panic_receipt(result);
 
-            let revert = matches!(program, ProgramState::Revert(_));
-            let gas_price = self.gas_price();
-            Self::finalize_outputs(
-                &mut self.tx,
-                &gas_costs,
-                &fee_params,
-                &base_asset_id,
-                revert,
-                gas_used,
-                &self.initial_balances,
-                &self.balances,
-                gas_price,
-            )?;
-            self.update_transaction_outputs()?;
+                    (ScriptExecutionResult::Panic, ProgramState::Revert(0))
+                }
 
-            program
+                // This isn't a specified case of an erroneous program and should be
+                // propagated. If applicable, OS errors will fall into this category.
+                None => return Err(e),
+            },
         };
 
-        Ok(state)
+        let receipt = Receipt::script_result(status, gas_used);
+
+        self.receipts.push(receipt)?;
+
+        if let Some(script) = self.tx.as_script_mut() {
+            let receipts_root = self.receipts.root();
+            *script.receipts_root_mut() = receipts_root;
+        }
+
+        let revert = matches!(program, ProgramState::Revert(_));
+        let gas_price = self.gas_price();
+        Self::finalize_outputs(
+            &mut self.tx,
+            &gas_costs,
+            &fee_params,
+            &base_asset_id,
+            revert,
+            gas_used,
+            &self.initial_balances,
+            &self.balances,
+            gas_price,
+        )?;
+        if revert {
+            self.balances = RuntimeBalances::try_from_iter(
+                self.initial_balances
+                    .non_retryable
+                    .iter()
+                    .map(|(asset, amount)| (*asset, *amount)),
+            )?;
+        }
+        self.update_transaction_outputs()?;
+
+        Ok(program)
     }
 
     pub(crate) fn run_program(
@@ -638,12 +846,13 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     <Tx as IntoChecked>::Metadata: CheckedMetadata,
     Ecal: EcalHandler + Default,
+    Observer: InstructionObserver + Default,
 {
     /// Allocate internally a new instance of [`Interpreter`] with the provided
     /// storage, initialize it with the provided transaction and return the
@@ -663,21 +872,38 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     <Tx as IntoChecked>::Metadata: CheckedMetadata,
     Ecal: EcalHandler,
+    Observer: InstructionObserver,
 {
     /// Initialize a pre-allocated instance of [`Interpreter`] with the provided
     /// transaction and execute it. The result will be bound to the lifetime
     /// of the interpreter and will avoid unnecessary copy with the data
     /// that can be referenced from the interpreter instance itself.
+    ///
+    /// The contracts used by the transaction must be declared in its inputs, even if
+    /// [`Self::allow_undeclared_contracts`] is enabled.
     pub fn transact(
         &mut self,
         tx: Ready<Tx>,
     ) -> Result<StateTransitionRef<'_, Tx>, InterpreterError<S::DataError>> {
+        self.touched_contracts = None;
+        let state = self.execute_ready_tx(tx)?;
+        Ok(StateTransitionRef::new(
+            state,
+            self.transaction(),
+            self.receipts(),
+        ))
+    }
+
+    fn execute_ready_tx(
+        &mut self,
+        tx: Ready<Tx>,
+    ) -> Result<ProgramState, InterpreterError<S::DataError>> {
         self.verify_ready_tx(&tx)?;
 
         let state_result = self.init_script(tx).and_then(|_| self.run());
@@ -692,16 +918,44 @@ where
             self.profiler.on_transaction(r);
         }
 
-        let state = state_result?;
-        Ok(StateTransitionRef::new(
-            state,
-            self.transaction(),
-            self.receipts(),
-        ))
+        state_result
+    }
+
+    /// Execute the transaction without modifying the storage.
+    ///
+    /// The transaction is executed by a new instance of [`Interpreter`] with the same
+    /// parameters, over a [`TransactionalStorage`] borrowing the storage of this
+    /// instance. The storage writes are discarded after the execution, and returned
+    /// along with the result of the execution. The dry run isn't reported to the
+    /// observer of this instance.
+    ///
+    /// If [`Self::allow_undeclared_contracts`] is enabled, the transaction may use the
+    /// contracts not declared in its inputs, and the contracts it touched are
+    /// available from [`Self::touched_contracts`] afterwards.
+    pub fn dry_run(
+        &mut self,
+        tx: Ready<Tx>,
+    ) -> Result<DryRun<Tx>, InterpreterError<S::DataError>> {
+        let mut interpreter =
+            Interpreter::<_, Tx, Ecal, NoopObserver>::with_storage_and_ecal(
+                TransactionalStorage::new(&mut self.storage),
+                self.interpreter_params.clone(),
+                self.ecal_state.clone(),
+            );
+        interpreter.code_cache = self.code_cache.clone();
+        interpreter.touched_contracts =
+            self.allow_undeclared_contracts.then(Default::default);
+        let state = interpreter.execute_ready_tx(tx);
+        self.touched_contracts = interpreter.touched_contracts.take();
+        let state = state?;
+
+        let transition =
+            StateTransition::new(state, interpreter.tx, interpreter.receipts.into());
+        Ok(DryRun::new(transition, interpreter.storage.into_changes()))
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
 {
@@ -735,7 +989,7 @@ where
     }
 }
 
-impl<S: InterpreterStorage, Tx, Ecal> Interpreter<S, Tx, Ecal> {
+impl<S: InterpreterStorage, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
     fn verify_ready_tx<Tx2: IntoChecked>(
         &self,
         tx: &Ready<Tx2>,
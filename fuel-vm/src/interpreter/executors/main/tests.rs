@@ -10,6 +10,7 @@ use fuel_asm::{
     RegId,
 };
 use fuel_tx::{
+    field::Outputs,
     ConsensusParameters,
     TransactionBuilder,
 };
@@ -199,3 +200,74 @@ fn deploy__tx_with_wrong_gas_price_causes_error() {
         InterpreterError::ReadyTransactionWrongGasPrice { .. }
     ));
 }
+
+#[test]
+fn transact__charges_the_gas_costs_set_before_the_execution() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    // given
+    let mut params = ConsensusParameters::standard();
+    params.set_fee_params(
+        params
+            .fee_params()
+            .with_gas_price_factor(1)
+            .expect("The gas price factor is not zero"),
+    );
+    let gas_price = 1;
+    let input_amount = 1_000_000;
+    let script = vec![
+        op::addi(0x20, 0x20, 1),
+        op::addi(0x20, 0x20, 1),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let ready_tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000)
+        .max_fee_limit(input_amount)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            input_amount,
+            *params.base_asset_id(),
+            rng.gen(),
+        )
+        .add_output(Output::change(rng.gen(), 0, *params.base_asset_id()))
+        .finalize_checked(Default::default())
+        .into_ready(gas_price, params.gas_costs(), params.fee_params())
+        .unwrap();
+    let mut transactor = Transactor::<_, Script>::new(
+        MemoryStorage::default(),
+        InterpreterParams::new(gas_price, &params),
+    );
+
+    let mut execute = |gas_costs| {
+        transactor.set_gas_costs(gas_costs);
+        transactor.transact_ready_tx(ready_tx.clone());
+        let gas_used = match transactor.receipts().expect("tx was executed").last() {
+            Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+            _ => panic!("expected script result"),
+        };
+        let change = match transactor
+            .state_transition()
+            .expect("tx was executed")
+            .tx()
+            .outputs()[0]
+        {
+            Output::Change { amount, .. } => amount,
+            _ => panic!("expected a change output"),
+        };
+        (gas_used, input_amount - change)
+    };
+
+    // when
+    let (unit_gas_used, unit_fee) = execute(GasCosts::unit());
+    let (free_gas_used, free_fee) = execute(GasCosts::free());
+
+    // then
+    assert_eq!(unit_gas_used, 3);
+    assert_eq!(free_gas_used, 0);
+    // With a gas price of 1 and a factor of 1, the gas used is charged 1 to 1, and the
+    // rest of the fee is unchanged
+    assert_eq!(unit_fee - unit_gas_used, free_fee - free_gas_used);
+}
@@ -1,6 +1,7 @@
 use crate::{
     error::PredicateVerificationFailed,
     interpreter::EcalHandler,
+    observer::InstructionObserver,
     prelude::{
         ExecutableTransaction,
         Interpreter,
@@ -17,10 +18,11 @@ use fuel_asm::{
     RegId,
 };
 
-impl<Tx, Ecal> Interpreter<PredicateStorage, Tx, Ecal>
+impl<Tx, Ecal, Observer> Interpreter<PredicateStorage, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     Ecal: EcalHandler,
+    Observer: InstructionObserver,
 {
     /// Verify a predicate that has been initialized already
     pub(crate) fn verify_predicate(
@@ -3,6 +3,7 @@ use crate::{
         Call,
         CallFrame,
     },
+    code_cache::CodeCache,
     constraints::{
         reg_key::*,
         *,
@@ -18,6 +19,7 @@ use crate::{
         contract::{
             balance_decrease,
             balance_increase,
+            cached_code,
             contract_size,
         },
         gas::{
@@ -32,12 +34,15 @@ use crate::{
             internal_contract_or_default,
             set_frame_pointer,
         },
+        memory::read_bytes,
         receipts::ReceiptsCtx,
         ExecutableTransaction,
+        ExecutionMetrics,
         InputContracts,
         Interpreter,
         MemoryRange,
         PanicContext,
+        PanicLocation,
         RuntimeBalances,
     },
     prelude::{
@@ -64,6 +69,7 @@ use fuel_storage::{
     StorageSize,
 };
 use fuel_tx::{
+    Contract,
     DependentCost,
     PanicReason,
     Receipt,
@@ -83,19 +89,22 @@ mod ret_tests;
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
     pub(crate) fn jump(&mut self, args: JumpArgs) -> SimpleResult<()> {
+        let memory_limit = self.memory_limit();
         let (SystemRegisters { pc, is, .. }, _) = split_registers(&mut self.registers);
-        args.jump(is.as_ref(), pc)
+        args.jump(is.as_ref(), pc, memory_limit)
     }
 
     pub(crate) fn ret(&mut self, a: Word) -> SimpleResult<()> {
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?
                 .copied();
+        let gas_remaining = self.registers[RegId::CGAS];
+        let depth = self.frames.len();
         let input = RetCtx {
             receipts: &mut self.receipts,
             frames: &mut self.frames,
@@ -104,13 +113,17 @@ where
             context: &mut self.context,
             current_contract,
         };
-        input.ret(a)
+        let result = input.ret(a);
+        self.record_call_return(depth, gas_remaining);
+        result
     }
 
     pub(crate) fn ret_data(&mut self, a: Word, b: Word) -> SimpleResult<Bytes32> {
         let current_contract =
             current_contract(&self.context, self.registers.fp(), self.memory.as_ref())?
                 .copied();
+        let gas_remaining = self.registers[RegId::CGAS];
+        let depth = self.frames.len();
         let input = RetCtx {
             frames: &mut self.frames,
             registers: &mut self.registers,
@@ -119,7 +132,17 @@ where
             context: &mut self.context,
             current_contract,
         };
-        input.ret_data(a, b)
+        let result = input.ret_data(a, b);
+        self.record_call_return(depth, gas_remaining);
+        result
+    }
+
+    /// Records the gas remaining to the call returned by `RET` or `RETD`, if the
+    /// frame of the call was popped from the `depth` frames.
+    fn record_call_return(&mut self, depth: usize, gas_remaining: Word) {
+        if self.frames.len() < depth {
+            self.call_gas.record_return(gas_remaining);
+        }
     }
 
     pub(crate) fn revert(&mut self, a: Word) -> SimpleResult<()> {
@@ -135,12 +158,44 @@ where
         )
     }
 
-    pub(crate) fn append_panic_receipt(&mut self, result: PanicInstruction) {
-        let pc = self.registers[RegId::PC];
-        let is = self.registers[RegId::IS];
+    /// Location of the instruction at `pc` executed with the `$is` and `$fp`
+    /// registers, inside of a contract if `internal`.
+    pub(crate) fn panic_location_at(
+        &self,
+        pc: Word,
+        is: Word,
+        fp: Word,
+        internal: bool,
+    ) -> PanicLocation {
+        let contract = internal
+            .then(|| read_bytes(&self.memory, fp).ok().map(ContractId::from))
+            .flatten();
+
+        PanicLocation::new(contract, pc.saturating_sub(is), is)
+    }
 
-        let mut receipt =
-            Receipt::panic(self.internal_contract_or_default(), result, pc, is);
+    pub(crate) fn append_panic_receipt(&mut self, result: PanicInstruction) {
+        // The panics raised outside of the instruction execution, like the fetch of
+        // the instruction, happen at the current registers
+        let location = self.panic_location.unwrap_or_else(|| {
+            self.panic_location_at(
+                self.registers[RegId::PC],
+                self.registers[RegId::IS],
+                self.registers[RegId::FP],
+                self.context.is_internal(),
+            )
+        });
+        self.panic_location = Some(location);
+
+        let mut receipt = Receipt::panic(
+            location
+                .contract()
+                .copied()
+                .unwrap_or_else(ContractId::zeroed),
+            result,
+            location.absolute_pc(),
+            location.is(),
+        );
 
         match self.panic_context {
             PanicContext::None => {}
@@ -159,7 +214,7 @@ where
 struct RetCtx<'vm> {
     frames: &'vm mut Vec<CallFrame>,
     registers: &'vm mut [Word; VM_REGISTER_COUNT],
-    memory: &'vm [u8; MEM_SIZE],
+    memory: &'vm [u8],
     receipts: &'vm mut ReceiptsCtx,
     context: &'vm mut Context,
     current_contract: Option<ContractId>,
@@ -221,7 +276,7 @@ impl RetCtx<'_> {
             a,
             self.registers[RegId::PC],
             self.registers[RegId::IS],
-            self.memory[range.usizes()].to_vec(),
+            range.read(self.memory.as_ref())?.to_vec(),
         );
         let digest = *receipt
             .digest()
@@ -300,7 +355,14 @@ impl JumpArgs {
         self
     }
 
-    pub(crate) fn jump(&self, is: Reg<IS>, mut pc: RegMut<PC>) -> SimpleResult<()> {
+    /// Performs the jump, failing with `MemoryOverflow` if the target is at or above
+    /// the `memory_limit`.
+    pub(crate) fn jump(
+        &self,
+        is: Reg<IS>,
+        mut pc: RegMut<PC>,
+        memory_limit: Word,
+    ) -> SimpleResult<()> {
         if !self.condition {
             return Ok(inc_pc(pc)?)
         }
@@ -324,7 +386,7 @@ impl JumpArgs {
                 .ok_or(PanicReason::MemoryOverflow)?,
         };
 
-        if target_addr >= VM_MAX_RAM {
+        if target_addr >= memory_limit {
             return Err(PanicReason::MemoryOverflow.into())
         }
 
@@ -333,7 +395,7 @@ impl JumpArgs {
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
@@ -351,7 +413,12 @@ where
             self.registers[rb],
             self.registers[rc],
             self.registers[rd],
-        )
+        )?;
+
+        #[cfg(feature = "profile-coverage")]
+        self.profiler.on_call_frame(self.registers[RegId::FP]);
+
+        Ok(())
     }
 
     /// Prepare a call instruction for execution
@@ -370,6 +437,7 @@ where
         };
         let gas_cost = self.gas_costs().call;
         let new_storage_gas_per_byte = self.gas_costs().new_storage_per_byte;
+        let max_call_depth = self.max_call_depth();
         // Charge only for the `base` execution.
         // We will charge for the frame size in the `prepare_call`.
         self.gas_charge(gas_cost.base())?;
@@ -390,14 +458,25 @@ where
             input_contracts: InputContracts::new(
                 input_contracts.iter(),
                 &mut self.panic_context,
-            ),
+            )
+            .with_touched_contracts(self.touched_contracts.as_mut()),
             new_storage_gas_per_byte,
+            max_call_depth,
             receipts: &mut self.receipts,
             frames: &mut self.frames,
             current_contract,
             profiler: &mut self.profiler,
+            metrics: &mut self.metrics,
+            code_cache: &self.code_cache,
+        }
+        .prepare_call()?;
+
+        if let Some(frame) = self.frames.last() {
+            self.call_gas
+                .record_call(*frame.to(), self.registers[RegId::CGAS]);
         }
-        .prepare_call()
+
+        Ok(())
     }
 }
 
@@ -448,7 +527,7 @@ impl<'a> PrepareCallRegisters<'a> {
 }
 
 struct PrepareCallMemory<'a> {
-    memory: &'a mut [u8; MEM_SIZE],
+    memory: &'a mut [u8],
     call_params: CheckedMemValue<Call>,
     asset_id: CheckedMemValue<AssetId>,
 }
@@ -461,12 +540,15 @@ struct PrepareCallCtx<'vm, S, I> {
     gas_cost: DependentCost,
     runtime_balances: &'vm mut RuntimeBalances,
     new_storage_gas_per_byte: Word,
+    max_call_depth: Word,
     storage: &'vm mut S,
     input_contracts: InputContracts<'vm, I>,
     receipts: &'vm mut ReceiptsCtx,
     frames: &'vm mut Vec<CallFrame>,
     current_contract: Option<ContractId>,
     profiler: &'vm mut Profiler,
+    metrics: &'vm mut ExecutionMetrics,
+    code_cache: &'vm CodeCache,
 }
 
 impl<'vm, S, I> PrepareCallCtx<'vm, S, I>
@@ -481,15 +563,27 @@ where
             + StorageRead<ContractsRawCode>
             + StorageAsRef,
     {
+        if self.frames.len() as Word >= self.max_call_depth {
+            return Err(PanicReason::CallDepthExceeded.into())
+        }
+
         let call = self.memory.call_params.try_from(self.memory.memory)?;
         let asset_id = self.memory.asset_id.try_from(self.memory.memory)?;
 
+        let cached_code = cached_code(self.storage, self.code_cache, call.to())?;
         let mut frame = call_frame(
             self.registers.copy_registers(),
             &self.storage,
+            cached_code.as_deref(),
             call,
             asset_id,
         )?;
+        if cached_code.is_some() {
+            self.metrics.record_contract_code_cache_hit();
+        } else {
+            self.metrics
+                .record_contract_code_load(frame.code_size() as Word);
+        }
 
         let profiler = ProfileGas {
             pc: self.registers.system_registers.pc.as_ref(),
@@ -598,6 +692,8 @@ where
             code_frame_mem_range,
             self.memory.memory,
             self.storage,
+            cached_code.as_deref(),
+            self.code_cache,
         )?;
         *self.registers.system_registers.bal = self.params.amount_of_coins_to_forward;
         *self.registers.system_registers.pc = frame_end;
@@ -628,8 +724,10 @@ fn write_call_to_memory<S>(
     frame: &CallFrame,
     frame_bytes: Vec<u8>,
     code_mem_range: MemoryRange,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     storage: &S,
+    cached_code: Option<&Contract>,
+    code_cache: &CodeCache,
 ) -> IoResult<Word, S::Error>
 where
     S: StorageSize<ContractsRawCode> + StorageRead<ContractsRawCode> + StorageAsRef,
@@ -639,25 +737,31 @@ where
     code_frame_range.shrink_end(frame.code_size() + frame.code_size_padding());
     code_frame_range
         .clone()
-        .write(memory)
+        .write(memory)?
         .copy_from_slice(&frame_bytes);
 
     let mut code_range = code_mem_range.clone();
     code_range.grow_start(CallFrame::serialized_size());
     code_range.shrink_end(frame.code_size_padding());
-    let bytes_read = storage
-        .storage::<ContractsRawCode>()
-        .read(frame.to(), code_range.write(memory))
-        .map_err(RuntimeError::Storage)?
-        .ok_or(PanicReason::ContractNotFound)?;
-    if bytes_read != frame.code_size() {
-        return Err(PanicReason::ContractMismatch.into())
+    if let Some(code) = cached_code {
+        code_range.write(memory)?.copy_from_slice(code.as_ref());
+    } else {
+        let bytes_read = storage
+            .storage::<ContractsRawCode>()
+            .read(frame.to(), code_range.clone().write(memory)?)
+            .map_err(RuntimeError::Storage)?
+            .ok_or(PanicReason::ContractNotFound)?;
+        if bytes_read != frame.code_size() {
+            return Err(PanicReason::ContractMismatch.into())
+        }
+        let code = code_range.read(memory)?;
+        code_cache.insert_with(*frame.to(), || Contract::from(code.to_vec()));
     }
 
     if frame.code_size_padding() > 0 {
         let mut padding_range = code_mem_range;
         padding_range.grow_start(CallFrame::serialized_size() + frame.code_size());
-        padding_range.write(memory).fill(0);
+        padding_range.write(memory)?.fill(0);
     }
     Ok(code_frame_range.end as Word)
 }
@@ -665,6 +769,7 @@ where
 fn call_frame<S>(
     registers: [Word; VM_REGISTER_COUNT],
     storage: &S,
+    cached_code: Option<&Contract>,
     call: Call,
     asset_id: AssetId,
 ) -> IoResult<CallFrame, S::Error>
@@ -673,7 +778,10 @@ where
 {
     let (to, a, b) = call.into_inner();
 
-    let code_size = contract_size(storage, &to)?;
+    let code_size = match cached_code {
+        Some(code) => code.as_ref().len(),
+        None => contract_size(storage, &to)?,
+    };
 
     let frame = CallFrame::new(to, asset_id, registers, code_size, a, b);
 
@@ -749,13 +857,11 @@ impl<'reg> From<SystemRegisters<'reg>>
     }
 }
 
-impl<'mem> TryFrom<(&'mem mut [u8; MEM_SIZE], &PrepareCallParams)>
-    for PrepareCallMemory<'mem>
-{
+impl<'mem> TryFrom<(&'mem mut [u8], &PrepareCallParams)> for PrepareCallMemory<'mem> {
     type Error = PanicReason;
 
     fn try_from(
-        (memory, params): (&'mem mut [u8; MEM_SIZE], &PrepareCallParams),
+        (memory, params): (&'mem mut [u8], &PrepareCallParams),
     ) -> Result<Self, Self::Error> {
         Ok(Self {
             memory,
@@ -10,7 +10,7 @@ use crate::error::PanicOrBug;
 fn test_absolute_jump(is: Word, mut pc: Word, j: Word) -> SimpleResult<Word> {
     JumpArgs::new(JumpMode::Absolute)
         .to_address(j)
-        .jump(Reg::new(&is), RegMut::new(&mut pc))
+        .jump(Reg::new(&is), RegMut::new(&mut pc), VM_MAX_RAM)
         .map(|_| pc)
 }
 
@@ -20,7 +20,7 @@ fn test_absolute_jump(is: Word, mut pc: Word, j: Word) -> SimpleResult<Word> {
 fn test_relative_forwards_jump(is: Word, mut pc: Word, j: Word) -> SimpleResult<Word> {
     JumpArgs::new(JumpMode::RelativeForwards)
         .to_address(j)
-        .jump(Reg::new(&is), RegMut::new(&mut pc))
+        .jump(Reg::new(&is), RegMut::new(&mut pc), VM_MAX_RAM)
         .map(|_| pc)
 }
 
@@ -32,7 +32,7 @@ fn test_relative_forwards_jump(is: Word, mut pc: Word, j: Word) -> SimpleResult<
 fn test_relative_backwards_jump(is: Word, mut pc: Word, j: Word) -> SimpleResult<Word> {
     JumpArgs::new(JumpMode::RelativeBackwards)
         .to_address(j)
-        .jump(Reg::new(&is), RegMut::new(&mut pc))
+        .jump(Reg::new(&is), RegMut::new(&mut pc), VM_MAX_RAM)
         .map(|_| pc)
 }
 
@@ -51,6 +51,6 @@ fn test_not_performed_conditional_jump(
     JumpArgs::new(mode)
         .with_condition(false)
         .to_address(j)
-        .jump(Reg::new(&is), RegMut::new(&mut pc))
+        .jump(Reg::new(&is), RegMut::new(&mut pc), VM_MAX_RAM)
         .map(|_| pc)
 }
@@ -5,16 +5,14 @@ use alloc::{
     vec::Vec,
 };
 
-use crate::{
-    interpreter::memory::Memory,
-    storage::MemoryStorage,
-};
+use crate::storage::MemoryStorage;
 
 use super::*;
 use crate::crypto;
 use fuel_storage::StorageAsMut;
 use fuel_tx::{
     field::ReceiptsRoot,
+    ContractParameters,
     Script,
 };
 use fuel_types::{
@@ -30,7 +28,7 @@ struct Input {
     balance: Vec<(AssetId, Word)>,
     input_contracts: Vec<ContractId>,
     storage_balance: Vec<(AssetId, Word)>,
-    memory: Memory<MEM_SIZE>,
+    memory: Box<[u8]>,
     gas_cost: DependentCost,
     storage_contract: Vec<(ContractId, Vec<u8>)>,
     script: Option<Script>,
@@ -51,7 +49,7 @@ impl Default for Input {
             balance: Default::default(),
             input_contracts: vec![Default::default()],
             storage_balance: Default::default(),
-            memory: vec![0u8; MEM_SIZE].try_into().unwrap(),
+            memory: vec![0u8; MEM_SIZE].into(),
             gas_cost: DependentCost::from_units_per_gas(10, 10),
             storage_contract: vec![(ContractId::default(), vec![0u8; 10])],
             script: None,
@@ -75,7 +73,7 @@ struct RegInput {
 #[derive(PartialEq, Eq)]
 enum CheckMem {
     Check(Vec<(usize, Vec<u8>)>),
-    Mem(Memory<MEM_SIZE>),
+    Mem(Box<[u8]>),
 }
 
 #[derive(PartialEq, Eq)]
@@ -141,8 +139,8 @@ impl Default for Output {
     }
 }
 
-fn mem(set: &[(usize, Vec<u8>)]) -> Memory<MEM_SIZE> {
-    let mut memory: Memory<MEM_SIZE> = vec![0u8; MEM_SIZE].try_into().unwrap();
+fn mem(set: &[(usize, Vec<u8>)]) -> Box<[u8]> {
+    let mut memory: Box<[u8]> = vec![0u8; MEM_SIZE].into();
     for (addr, data) in set {
         memory[*addr..*addr + data.len()].copy_from_slice(data);
     }
@@ -379,10 +377,13 @@ fn test_prepare_call(input: Input) -> Result<Output, RuntimeError<Infallible>> {
         storage: &mut storage,
         input_contracts: InputContracts::new(input_contracts.iter(), &mut panic_context),
         new_storage_gas_per_byte: 0,
+        max_call_depth: ContractParameters::DEFAULT.max_call_depth,
         receipts: &mut receipts,
         frames: &mut frames,
         current_contract,
         profiler: &mut Profiler::default(),
+        metrics: &mut Default::default(),
+        code_cache: &Default::default(),
     };
     input.prepare_call().map(|_| Output {
         reg,
@@ -442,19 +443,21 @@ fn test_write_call_to_memory(
     StorageAsMut::storage::<ContractsRawCode>(&mut storage)
         .insert(call_frame.to(), &code)
         .unwrap();
-    let mut memory: Memory<MEM_SIZE> = vec![0u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![0u8; MEM_SIZE].into();
     let end = write_call_to_memory(
         &call_frame,
         frame_bytes,
         code_mem_range,
         memory.as_mut(),
         &storage,
+        None,
+        &Default::default(),
     )?;
     check_memory(memory, call_frame, code);
     Ok(end)
 }
 
-fn check_memory(result: Memory<MEM_SIZE>, expected: CallFrame, code: Vec<u8>) {
+fn check_memory(result: Box<[u8]>, expected: CallFrame, code: Vec<u8>) {
     let frame = CheckedMemValue::<CallFrame>::new::<{ CallFrame::serialized_size() }>(0)
         .unwrap()
         .inspect(&result);
@@ -25,12 +25,46 @@ use fuel_types::{
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
+/// Gas charge that failed because the execution ran out of gas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutOfGas {
+    /// Gas consumed by the execution before the failed charge.
+    pub consumed: Word,
+    /// Gas requested by the failed charge.
+    pub requested: Word,
+}
+
+impl OutOfGas {
+    /// The gas limit the execution needs to get past the failed charge, if it ran
+    /// out of the gas of the transaction rather than of the gas forwarded to a
+    /// call.
+    pub fn required_gas(&self) -> Word {
+        self.consumed.saturating_add(self.requested)
+    }
+}
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
     /// Global remaining gas amount
     pub fn remaining_gas(&self) -> Word {
         self.registers[RegId::GGAS]
     }
 
+    /// The gas charge that ran out of gas in the current execution, or `None` if
+    /// the execution didn't run out of gas.
+    ///
+    /// The whole gas of the context is consumed when it runs out of gas, so the
+    /// gas used by the execution includes the gas that remained before the failed
+    /// charge.
+    pub fn out_of_gas(&self) -> Option<OutOfGas> {
+        self.profiler
+            .out_of_gas()
+            .map(|(remaining, requested)| OutOfGas {
+                consumed: self.gas_limit.saturating_sub(remaining),
+                requested,
+            })
+    }
+
     /// Sets the amount of gas available for execution to both CGAS and GGAS.
     /// Only useful in contexts where CGAS and GGAS are the same,
     /// i.e. predicates and testing.
@@ -74,6 +108,27 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
     }
 }
 
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
+    /// Gas used by the predicates of the current transaction, as declared by its
+    /// inputs and verified before the execution.
+    pub const fn predicate_gas_used(&self) -> Word {
+        self.predicate_gas_used
+    }
+
+    /// Gas used by the execution of the current transaction so far, excluding its
+    /// predicates.
+    pub fn script_gas_used(&self) -> Word {
+        self.gas_limit.saturating_sub(self.remaining_gas())
+    }
+
+    /// Total gas charged to the current transaction: the gas used by its predicates
+    /// and the gas used by its execution so far.
+    pub fn total_gas_charged(&self) -> Word {
+        self.predicate_gas_used()
+            .saturating_add(self.script_gas_used())
+    }
+}
+
 pub(crate) fn dependent_gas_charge_without_base(
     mut cgas: RegMut<CGAS>,
     ggas: RegMut<GGAS>,
@@ -81,8 +136,13 @@ pub(crate) fn dependent_gas_charge_without_base(
     gas_cost: DependentCost,
     arg: Word,
 ) -> SimpleResult<()> {
+    let remaining = *ggas;
     let cost =
-        dependent_gas_charge_without_base_inner(cgas.as_mut(), ggas, gas_cost, arg)?;
+        dependent_gas_charge_without_base_inner(cgas.as_mut(), ggas, gas_cost, arg)
+            .map_err(|e| {
+                profiler.on_error(&e, remaining, gas_cost.resolve_without_base(arg));
+                e
+            })?;
     profiler.profile(cgas.as_ref(), cost);
     Ok(())
 }
@@ -104,7 +164,12 @@ pub(crate) fn dependent_gas_charge(
     gas_cost: DependentCost,
     arg: Word,
 ) -> SimpleResult<()> {
-    let cost = dependent_gas_charge_inner(cgas.as_mut(), ggas, gas_cost, arg)?;
+    let remaining = *ggas;
+    let cost =
+        dependent_gas_charge_inner(cgas.as_mut(), ggas, gas_cost, arg).map_err(|e| {
+            profiler.on_error(&e, remaining, gas_cost.resolve(arg));
+            e
+        })?;
     profiler.profile(cgas.as_ref(), cost);
     Ok(())
 }
@@ -126,7 +191,11 @@ pub(crate) fn gas_charge(
     gas: Word,
 ) -> SimpleResult<()> {
     profiler.profile(cgas.as_ref(), gas);
-    gas_charge_inner(cgas, ggas, gas)
+    let remaining = *ggas;
+    gas_charge_inner(cgas, ggas, gas).map_err(|e| {
+        profiler.on_error(&e, remaining, gas);
+        e
+    })
 }
 
 fn gas_charge_inner(
@@ -181,4 +250,12 @@ impl<'a> ProfileGas<'a> {
             self.profiler.add_gas(location, gas_use);
         }
     }
+
+    /// Records the charge of `requested` gas with `remaining` global gas if it
+    /// failed with `error` because of running out of gas.
+    fn on_error(&mut self, error: &PanicOrBug, remaining: Word, requested: Word) {
+        if *error == PanicOrBug::Panic(PanicReason::OutOfGas) {
+            self.profiler.on_out_of_gas(remaining, requested);
+        }
+    }
 }
@@ -9,38 +9,65 @@ use crate::{
         IntoChecked,
         Ready,
     },
-    consts::*,
     context::Context,
     error::InterpreterError,
     prelude::RuntimeError,
     storage::InterpreterStorage,
 };
-use fuel_asm::RegId;
+use fuel_asm::{
+    PanicReason,
+    RegId,
+};
 use fuel_tx::field::ScriptGasLimit;
 use fuel_types::Word;
 
 use crate::interpreter::CheckedMetadata;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
 {
-    /// Initialize the VM with a given transaction
+    /// Initialize the VM with a given transaction, with its malleable fields already
+    /// cleared
     fn init_inner(
         &mut self,
-        mut tx: Tx,
+        tx: Tx,
         initial_balances: InitialBalances,
         runtime_balances: RuntimeBalances,
         gas_limit: Word,
     ) -> Result<(), RuntimeError<S::DataError>> {
-        tx.prepare_init_execute();
         self.tx = tx;
 
         self.initial_balances = initial_balances.clone();
 
+        // Zero the memory written by the previous execution, including the stack
+        // pushed by a failed initialization
+        self.memory.record_stack_pointer(
+            self.registers[RegId::SP].max(self.registers[RegId::SSP]),
+        );
+        self.memory.reset();
+        self.memory.resize(self.memory_limit());
+
         self.frames.clear();
         self.receipts.clear();
+        self.receipts.set_limits(
+            self.interpreter_params.max_receipts_count,
+            self.interpreter_params.max_receipts_size,
+        );
+        self.metrics = Default::default();
+        self.call_gas = Default::default();
+        self.panic_location = None;
+        self.instructions_executed = 0;
+        self.gas_limit = gas_limit;
+        if let Some(touched_contracts) = self.touched_contracts.as_mut() {
+            touched_contracts.clear();
+        }
+        self.profiler.clear_out_of_gas();
+        #[cfg(feature = "profile-gas")]
+        self.profiler.set_predicate(self.context.is_predicate());
+        #[cfg(feature = "profile-coverage")]
+        self.profiler.clear_loaded_code();
 
         // Optimized for memset
         self.registers.iter_mut().for_each(|r| *r = 0);
@@ -49,11 +76,11 @@ where
         self.registers[RegId::SSP] = 0;
 
         // Set heap area
-        self.registers[RegId::HP] = VM_MAX_RAM;
+        self.registers[RegId::HP] = self.memory_limit();
 
         self.push_stack(self.transaction().id(&self.chain_id()).as_ref())?;
 
-        runtime_balances.to_vm(self);
+        runtime_balances.to_vm(self)?;
 
         let tx_size = self.transaction().size() as Word;
         self.set_gas(gas_limit);
@@ -64,13 +91,18 @@ where
 
         self.push_stack(tx_bytes.as_slice())?;
 
+        // The transaction must fit below the heap
+        if self.registers[RegId::SSP] > self.registers[RegId::HP] {
+            return Err(PanicReason::MemoryOverflow.into())
+        }
+
         self.registers[RegId::SP] = self.registers[RegId::SSP];
 
         Ok(())
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
@@ -79,17 +111,19 @@ where
     pub fn init_predicate(
         &mut self,
         context: Context,
-        tx: Tx,
+        mut tx: Tx,
         gas_limit: Word,
     ) -> Result<(), InterpreterError<S::DataError>> {
         self.context = context;
+        self.predicate_gas_used = 0;
+        tx.prepare_init_predicate();
         let initial_balances: InitialBalances = Default::default();
         let runtime_balances = initial_balances.clone().try_into()?;
         Ok(self.init_inner(tx, initial_balances, runtime_balances, gas_limit)?)
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     <S as InterpreterStorage>::DataError: From<S::DataError>,
@@ -109,7 +143,13 @@ where
         self.context = Context::Script { block_height };
 
         let (_, checked) = ready_tx.decompose();
-        let (tx, metadata): (Tx, Tx::Metadata) = checked.into();
+        let (mut tx, metadata): (Tx, Tx::Metadata) = checked.into();
+        self.predicate_gas_used = tx
+            .inputs()
+            .iter()
+            .filter_map(|input| input.predicate_gas_used())
+            .fold(0, Word::saturating_add);
+        tx.prepare_init_execute();
 
         let gas_limit = tx
             .as_script()
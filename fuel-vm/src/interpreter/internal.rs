@@ -9,7 +9,6 @@ use crate::{
         reg_key::*,
         CheckedMemConstLen,
     },
-    consts::*,
     context::Context,
     error::SimpleResult,
 };
@@ -40,7 +39,7 @@ mod message_tests;
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
@@ -54,7 +53,7 @@ where
 /// and the serialized tx in vm memory.
 pub(crate) fn set_variable_output<Tx: ExecutableTransaction>(
     tx: &mut Tx,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     tx_offset: usize,
     idx: usize,
     variable: Output,
@@ -85,13 +84,13 @@ pub(crate) fn absolute_output_mem_range<Tx: Outputs>(
 
 pub(crate) fn update_memory_output<Tx: ExecutableTransaction>(
     tx: &mut Tx,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     tx_offset: usize,
     idx: usize,
 ) -> SimpleResult<()> {
     let mem_range = absolute_output_mem_range(tx, tx_offset, idx)?
         .ok_or(PanicReason::OutputNotFound)?;
-    let mut mem = mem_range.write(memory);
+    let mut mem = mem_range.write(memory)?;
     let output = tx
         .outputs_mut()
         .get_mut(idx)
@@ -102,11 +101,14 @@ pub(crate) fn update_memory_output<Tx: ExecutableTransaction>(
     Ok(())
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
     pub(crate) fn reserve_stack(&mut self, len: Word) -> Result<Word, PanicReason> {
         let (ssp, overflow) = self.registers[RegId::SSP].overflowing_add(len);
 
-        if overflow || !self.is_external_context() && ssp > self.registers[RegId::SP] {
+        if overflow
+            || ssp > self.registers[RegId::HP]
+            || !self.is_external_context() && ssp > self.registers[RegId::SP]
+        {
             Err(PanicReason::MemoryOverflow)
         } else {
             Ok(mem::replace(&mut self.registers[RegId::SSP], ssp))
@@ -114,12 +116,10 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
     }
 
     pub(crate) fn push_stack(&mut self, data: &[u8]) -> SimpleResult<()> {
-        let old_ssp = usize::try_from(self.reserve_stack(data.len() as Word)?)
-            .expect("SSP couldn't be more than `usize`");
-        let new_ssp = usize::try_from(self.registers[RegId::SSP])
-            .expect("SSP couldn't be more than `usize`");
-
-        self.memory[old_ssp..new_ssp].copy_from_slice(data);
+        let old_ssp = self.reserve_stack(data.len() as Word)?;
+        MemoryRange::new(old_ssp, data.len())?
+            .write(self.memory.as_mut())?
+            .copy_from_slice(data);
 
         Ok(())
     }
@@ -129,15 +129,12 @@ impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
         set_flag(flag, pc, a)
     }
 
-    pub(crate) const fn context(&self) -> &Context {
-        &self.context
-    }
-
     pub(crate) const fn is_external_context(&self) -> bool {
         self.context().is_external()
     }
 
-    pub(crate) const fn is_predicate(&self) -> bool {
+    /// Check if the VM is executing a predicate
+    pub const fn is_predicate(&self) -> bool {
         matches!(
             self.context,
             Context::PredicateEstimation { .. } | Context::PredicateVerification { .. }
@@ -191,10 +188,10 @@ pub(crate) fn inc_pc(mut pc: RegMut<PC>) -> Result<(), PanicReason> {
         .map(|i| *pc = i)
 }
 
-pub(crate) fn tx_id(memory: &[u8; MEM_SIZE]) -> &Bytes32 {
+pub(crate) fn tx_id(memory: &[u8]) -> &Bytes32 {
     let memory = (&memory[..Bytes32::LEN])
         .try_into()
-        .expect("Bytes32::LEN < MEM_SIZE");
+        .expect("Bytes32::LEN < memory limit");
     // Safety: vm parameters guarantees enough space for txid
     Bytes32::from_bytes_ref(memory)
 }
@@ -203,7 +200,7 @@ pub(crate) fn tx_id(memory: &[u8; MEM_SIZE]) -> &Bytes32 {
 pub(crate) fn base_asset_balance_sub(
     base_asset_id: &AssetId,
     balances: &mut RuntimeBalances,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     value: Word,
 ) -> SimpleResult<()> {
     external_asset_id_balance_sub(balances, memory, base_asset_id, value)
@@ -212,7 +209,7 @@ pub(crate) fn base_asset_balance_sub(
 /// Reduces the unspent balance of a given asset ID
 pub(crate) fn external_asset_id_balance_sub(
     balances: &mut RuntimeBalances,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     asset_id: &AssetId,
     value: Word,
 ) -> SimpleResult<()> {
@@ -226,7 +223,7 @@ pub(crate) fn external_asset_id_balance_sub(
 pub(crate) fn internal_contract_or_default(
     context: &Context,
     register: Reg<FP>,
-    memory: &[u8; MEM_SIZE],
+    memory: &[u8],
 ) -> ContractId {
     internal_contract(context, register, memory)
         .map_or(Default::default(), |contract| *contract)
@@ -235,7 +232,7 @@ pub(crate) fn internal_contract_or_default(
 pub(crate) fn current_contract<'a>(
     context: &Context,
     fp: Reg<FP>,
-    memory: &'a [u8; MEM_SIZE],
+    memory: &'a [u8],
 ) -> Result<Option<&'a ContractId>, PanicReason> {
     if context.is_internal() {
         Ok(Some(internal_contract(context, fp, memory)?))
@@ -247,12 +244,12 @@ pub(crate) fn current_contract<'a>(
 pub(crate) fn internal_contract<'a>(
     context: &Context,
     register: Reg<FP>,
-    memory: &'a [u8; MEM_SIZE],
+    memory: &'a [u8],
 ) -> Result<&'a ContractId, PanicReason> {
     let range = internal_contract_bounds(context, register)?;
 
     // Safety: Memory bounds logically verified by the interpreter
-    let contract = ContractId::from_bytes_ref(range.read(memory));
+    let contract = ContractId::from_bytes_ref(range.read(memory)?);
 
     Ok(contract)
 }
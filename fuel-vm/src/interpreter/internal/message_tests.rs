@@ -4,8 +4,8 @@ use alloc::{
 };
 
 use crate::{
+    consts::*,
     error::PanicOrBug,
-    interpreter::memory::Memory,
 };
 
 use super::*;
@@ -34,11 +34,11 @@ fn test_absolute_output_offset(
 }
 
 #[test_case(
-    0 => with |r: Result<_, _>| check_memory(r.unwrap(), &[(96, Output::default().to_bytes())])
+    0 => with |r: SimpleResult<Box<[u8]>>| check_memory(&r.unwrap(), &[(96, Output::default().to_bytes())])
     ; "Output at start of memory"
 )]
 #[test_case(
-    200 => with |r: Result<_, _>| check_memory(r.unwrap(), &[(200 + 96, Output::default().to_bytes())])
+    200 => with |r: SimpleResult<Box<[u8]>>| check_memory(&r.unwrap(), &[(200 + 96, Output::default().to_bytes())])
     ; "Output at 200 in memory"
 )]
 #[test_case(
@@ -49,14 +49,14 @@ fn test_absolute_output_offset(
     MEM_SIZE - 1 - 112 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow))
     ; "Output at MEM_SIZE - 1 - output_size should overflow"
 )]
-fn test_update_memory_output(tx_offset: usize) -> SimpleResult<Memory<MEM_SIZE>> {
+fn test_update_memory_output(tx_offset: usize) -> SimpleResult<Box<[u8]>> {
     let mut tx = Create::default();
     *tx.outputs_mut() = vec![Output::default()];
-    let mut memory: Memory<MEM_SIZE> = vec![0; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![0; MEM_SIZE].into();
     update_memory_output(&mut tx, &mut memory, tx_offset, 0).map(|_| memory)
 }
 
-fn check_memory(result: Memory<MEM_SIZE>, expected: &[(usize, Vec<u8>)]) {
+fn check_memory(result: &[u8], expected: &[(usize, Vec<u8>)]) {
     for (offset, bytes) in expected {
         assert_eq!(
             &result[*offset..*offset + bytes.len()],
@@ -10,7 +10,6 @@ use super::{
 };
 use crate::{
     constraints::reg_key::*,
-    consts::*,
     context::Context,
     error::SimpleResult,
 };
@@ -21,7 +20,7 @@ use fuel_types::Word;
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
@@ -61,7 +60,7 @@ where
 }
 
 struct LogInput<'vm> {
-    memory: &'vm mut [u8; MEM_SIZE],
+    memory: &'vm mut [u8],
     context: &'vm Context,
     receipts: &'vm mut ReceiptsCtx,
     fp: Reg<'vm, FP>,
@@ -96,7 +95,7 @@ impl LogInput<'_> {
             c,
             *self.pc,
             *self.is,
-            self.memory[range.usizes()].to_vec(),
+            range.read(self.memory)?.to_vec(),
         );
 
         self.receipts.push(receipt)?;
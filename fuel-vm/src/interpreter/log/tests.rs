@@ -1,12 +1,12 @@
 use alloc::vec;
 
-use crate::interpreter::memory::Memory;
+use crate::consts::*;
 
 use super::*;
 
 #[test]
 fn test_log() -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let context = Context::Script {
         block_height: Default::default(),
     };
@@ -19,13 +19,243 @@ use fuel_types::{
     Word,
 };
 
-use alloc::boxed::Box;
+use alloc::{
+    boxed::Box,
+    vec,
+};
 use core::{
+    fmt,
     ops,
     ops::Range,
 };
 
-pub type Memory<const SIZE: usize> = Box<[u8; SIZE]>;
+/// The memory of the VM.
+///
+/// The memory is as large as the memory limit of the interpreter, see
+/// [`InterpreterParams::memory_limit`](crate::interpreter::InterpreterParams::memory_limit),
+/// so the accesses above the limit fail with [`PanicReason::MemoryOverflow`].
+///
+/// The execution can only write below the stack pointer and above the heap pointer,
+/// so the memory keeps the highest stack pointer and the lowest heap pointer it has
+/// seen. [`Self::reset`] zeroes only the memory between these bounds and the ends of
+/// the memory, instead of the whole buffer.
+pub struct MemoryInstance {
+    /// Always `Some`, except while the memory is returned to its pool.
+    buffer: Option<Box<[u8]>>,
+    /// The memory below this address may be dirty.
+    stack_end: usize,
+    /// The memory above this address may be dirty.
+    heap_start: usize,
+    /// The pool the memory is returned to when dropped.
+    #[cfg(feature = "std")]
+    pool: Option<crate::pool::MemoryPool>,
+}
+
+/// The length of a memory of `memory_limit` bytes. Limits above [`VM_MAX_RAM`] are
+/// clamped to it, the same way as
+/// [`Interpreter::memory_limit`](crate::interpreter::Interpreter::memory_limit).
+fn clamped_len(memory_limit: Word) -> usize {
+    usize::try_from(memory_limit.min(VM_MAX_RAM)).unwrap_or(MEM_SIZE)
+}
+
+impl MemoryInstance {
+    /// Allocate a new zeroed memory of [`VM_MAX_RAM`] bytes.
+    pub fn new() -> Self {
+        Self::with_limit(VM_MAX_RAM)
+    }
+
+    /// Allocate a new zeroed memory of `memory_limit` bytes, clamped to
+    /// [`VM_MAX_RAM`].
+    pub fn with_limit(memory_limit: Word) -> Self {
+        let len = clamped_len(memory_limit);
+        Self {
+            buffer: Some(vec![0; len].into_boxed_slice()),
+            stack_end: 0,
+            heap_start: len,
+            #[cfg(feature = "std")]
+            pool: None,
+        }
+    }
+
+    /// Resize the memory to `memory_limit` bytes, clamped to [`VM_MAX_RAM`],
+    /// allocating a new zeroed buffer if the size changes.
+    pub(crate) fn resize(&mut self, memory_limit: Word) {
+        let len = clamped_len(memory_limit);
+        if self.len() != len {
+            self.buffer = Some(vec![0; len].into_boxed_slice());
+            self.stack_end = 0;
+            self.heap_start = len;
+        }
+    }
+
+    /// Zero the memory written since the last reset, making it indistinguishable
+    /// from a newly allocated memory.
+    pub fn reset(&mut self) {
+        let (stack_end, heap_start) = (self.stack_end, self.heap_start);
+        let memory = self.as_mut();
+        if stack_end >= heap_start {
+            memory.fill(0);
+        } else {
+            memory[..stack_end].fill(0);
+            memory[heap_start..].fill(0);
+        }
+        self.stack_end = 0;
+        self.heap_start = self.len();
+    }
+
+    /// Returns `true` if no byte was written since the last reset.
+    pub fn is_clean(&self) -> bool {
+        self.stack_end == 0 && self.heap_start == self.len()
+    }
+
+    /// Record the stack and heap pointers, so the memory below the `sp` and above
+    /// the `hp` is zeroed on the next reset.
+    pub(crate) fn record_pointers(&mut self, sp: Word, hp: Word) {
+        self.record_stack_pointer(sp);
+        let hp = usize::try_from(hp).unwrap_or(usize::MAX).min(self.len());
+        self.heap_start = self.heap_start.min(hp);
+    }
+
+    /// Record the stack pointer, so the memory below the `sp` is zeroed on the next
+    /// reset.
+    pub(crate) fn record_stack_pointer(&mut self, sp: Word) {
+        let sp = usize::try_from(sp).unwrap_or(usize::MAX).min(self.len());
+        self.stack_end = self.stack_end.max(sp);
+    }
+
+    /// Mark the whole memory as dirty, after it was written outside of the
+    /// execution.
+    pub(crate) fn mark_dirty(&mut self) {
+        self.stack_end = self.len();
+    }
+
+    /// The parts of the memory that may be dirty: the memory below the highest
+    /// stack pointer and above the lowest heap pointer. The rest is zeroed.
+    pub(crate) fn dirty_parts(&self) -> (&[u8], &[u8]) {
+        let memory = self.as_ref();
+        if self.stack_end >= self.heap_start {
+            (memory, &[])
+        } else {
+            (&memory[..self.stack_end], &memory[self.heap_start..])
+        }
+    }
+
+    /// Reset the memory to the `stack` at its start and the `heap` at its end, as
+    /// returned by [`Self::dirty_parts`].
+    ///
+    /// Returns `false`, leaving the memory untouched, if the parts overlap.
+    pub(crate) fn restore_dirty_parts(&mut self, stack: &[u8], heap: &[u8]) -> bool {
+        let Some(heap_start) = self.len().checked_sub(heap.len()) else {
+            return false
+        };
+        if stack.len() > heap_start {
+            return false
+        }
+
+        self.reset();
+        let memory = self.as_mut();
+        memory[..stack.len()].copy_from_slice(stack);
+        memory[heap_start..].copy_from_slice(heap);
+        self.stack_end = stack.len();
+        self.heap_start = heap_start;
+        true
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn set_pool(&mut self, pool: crate::pool::MemoryPool) {
+        self.pool = Some(pool);
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn take_buffer(&mut self) -> Option<Box<[u8]>> {
+        self.buffer.take()
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn from_clean_buffer(buffer: Box<[u8]>) -> Self {
+        Self {
+            heap_start: buffer.len(),
+            buffer: Some(buffer),
+            stack_end: 0,
+            pool: None,
+        }
+    }
+}
+
+impl Default for MemoryInstance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for MemoryInstance {
+    /// The clone isn't returned to the pool of the original memory.
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer.clone(),
+            stack_end: self.stack_end,
+            heap_start: self.heap_start,
+            #[cfg(feature = "std")]
+            pool: None,
+        }
+    }
+}
+
+impl fmt::Debug for MemoryInstance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryInstance")
+            .field("stack_end", &self.stack_end)
+            .field("heap_start", &self.heap_start)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for MemoryInstance {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Eq for MemoryInstance {}
+
+impl ops::Deref for MemoryInstance {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer
+            .as_deref()
+            .expect("The buffer is only taken when the memory is dropped")
+    }
+}
+
+impl ops::DerefMut for MemoryInstance {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buffer
+            .as_deref_mut()
+            .expect("The buffer is only taken when the memory is dropped")
+    }
+}
+
+impl AsRef<[u8]> for MemoryInstance {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl AsMut<[u8]> for MemoryInstance {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for MemoryInstance {
+    fn drop(&mut self) {
+        if let Some(pool) = self.pool.take() {
+            pool.recycle(self);
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests;
@@ -73,7 +303,8 @@ impl ToAddr for i32 {
     }
 }
 
-/// Memory range representation for the VM, checked to be in-bounds on construction.
+/// Memory range representation for the VM, checked to be within [`VM_MAX_RAM`] on
+/// construction. The reads and writes also check the range against the memory limit.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryRange(ops::Range<usize>);
@@ -156,7 +387,10 @@ impl MemoryRange {
     /// Return an owned memory slice with a relative address to the heap space
     /// defined in `r[$hp]`. Panics if the range is not within the heap space.
     #[cfg(test)]
-    pub fn to_heap<S, Tx, Ecal>(self, vm: &Interpreter<S, Tx, Ecal>) -> Self {
+    pub fn to_heap<S, Tx, Ecal, Observer>(
+        self,
+        vm: &Interpreter<S, Tx, Ecal, Observer>,
+    ) -> Self {
         let hp = usize::try_from(vm.registers()[RegId::HP]).expect("Truncate");
         let start = self.start.checked_add(hp).expect("Overflow");
         let end = self.end.checked_add(hp).expect("Overflow");
@@ -185,23 +419,52 @@ impl MemoryRange {
         self.0 = self.0.start.saturating_add(by)..self.0.end;
     }
 
-    /// Get the memory slice for this range.
-    pub fn read(self, memory: &[u8; MEM_SIZE]) -> &[u8] {
-        &memory[self.0]
+    /// Get the memory slice for this range. Fails with `MemoryOverflow` if the
+    /// range is outside of the memory, i.e. above the memory limit.
+    pub fn read(self, memory: &[u8]) -> Result<&[u8], PanicReason> {
+        memory.get(self.0).ok_or(PanicReason::MemoryOverflow)
     }
 
-    /// Get the mutable memory slice for this range.
-    pub fn write(self, memory: &mut [u8; MEM_SIZE]) -> &mut [u8] {
-        &mut memory[self.0]
+    /// Get the mutable memory slice for this range. Fails with `MemoryOverflow` if
+    /// the range is outside of the memory, i.e. above the memory limit.
+    pub fn write(self, memory: &mut [u8]) -> Result<&mut [u8], PanicReason> {
+        memory.get_mut(self.0).ok_or(PanicReason::MemoryOverflow)
     }
 }
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal> {
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer> {
     /// Return the registers used to determine ownership.
     pub(crate) fn ownership_registers(&self) -> OwnershipRegisters {
         OwnershipRegisters::new(self)
     }
 
+    /// Reads `len` bytes of the memory starting at `addr`. Panics with
+    /// `MemoryOverflow` if the range is outside of the VM memory.
+    ///
+    /// Intended for the ECAL handlers, which can't use the internal helpers.
+    pub fn read_memory(&self, addr: Word, len: Word) -> SimpleResult<&[u8]> {
+        let range = MemoryRange::new(addr, len)?;
+        Ok(range.read(&self.memory)?)
+    }
+
+    /// Returns the `len` bytes of the memory starting at `addr`, or
+    /// [`PanicReason::MemoryOverflow`] if the range is outside of the VM memory.
+    ///
+    /// See [`Self::register`] for an example.
+    pub fn memory_range(&self, addr: Word, len: Word) -> Result<&[u8], PanicReason> {
+        let range = MemoryRange::new(addr, len)?;
+        range.read(&self.memory)
+    }
+
+    /// Writes the `data` into the memory starting at `addr`, checking that the
+    /// current context owns the range just like the store instructions do.
+    ///
+    /// Intended for the ECAL handlers, which can't use the internal helpers.
+    pub fn write_memory(&mut self, addr: Word, data: &[u8]) -> SimpleResult<()> {
+        let owner = self.ownership_registers();
+        try_mem_write(addr, data, owner, &mut self.memory)
+    }
+
     pub(crate) fn stack_pointer_overflow<F>(&mut self, f: F, v: Word) -> SimpleResult<()>
     where
         F: FnOnce(Word, Word) -> (Word, bool),
@@ -366,7 +629,7 @@ where
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn push_selected_registers(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     sp: RegMut<SP>,
     ssp: Reg<SSP>,
     hp: Reg<HP>,
@@ -383,7 +646,7 @@ pub(crate) fn push_selected_registers(
     try_update_stack_pointer(sp, ssp, hp, stack_range.words().end)?;
 
     // Write the registers to the stack
-    let mut it = memory[stack_range.usizes()].chunks_exact_mut(8);
+    let mut it = stack_range.write(memory)?.chunks_exact_mut(8);
     for (i, reg) in program_regs.segment(segment).iter().enumerate() {
         if (bitmask & (1 << i)) != 0 {
             let item = it
@@ -398,7 +661,7 @@ pub(crate) fn push_selected_registers(
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn pop_selected_registers(
-    memory: &[u8; MEM_SIZE],
+    memory: &[u8],
     sp: RegMut<SP>,
     ssp: Reg<SSP>,
     hp: Reg<HP>,
@@ -416,10 +679,10 @@ pub(crate) fn pop_selected_registers(
         .checked_sub(size_in_stack)
         .ok_or(PanicReason::MemoryOverflow)?;
     try_update_stack_pointer(sp, ssp, hp, new_sp)?;
-    let stack_range = MemoryRange::new(new_sp, size_in_stack)?.usizes();
+    let stack_range = MemoryRange::new(new_sp, size_in_stack)?;
 
     // Restore registers from the stack
-    let mut it = memory[stack_range].chunks_exact(8);
+    let mut it = stack_range.read(memory)?.chunks_exact(8);
     for (i, reg) in program_regs.segment_mut(segment).iter_mut().enumerate() {
         if (bitmask & (1 << i)) != 0 {
             let mut buf = [0u8; 8];
@@ -432,19 +695,19 @@ pub(crate) fn pop_selected_registers(
 }
 
 pub(crate) fn load_byte(
-    memory: &[u8; MEM_SIZE],
+    memory: &[u8],
     pc: RegMut<PC>,
     result: &mut Word,
     b: Word,
     c: Word,
 ) -> SimpleResult<()> {
     let range = MemoryRange::new_overflowing_op(Word::overflowing_add, b, c, 1u64)?;
-    *result = memory[range.start] as Word;
+    *result = range.read(memory)?[0] as Word;
     Ok(inc_pc(pc)?)
 }
 
 pub(crate) fn load_word(
-    memory: &[u8; MEM_SIZE],
+    memory: &[u8],
     pc: RegMut<PC>,
     result: &mut Word,
     b: Word,
@@ -459,7 +722,7 @@ pub(crate) fn load_word(
 
 #[allow(clippy::cast_possible_truncation)]
 pub(crate) fn store_byte(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -471,7 +734,7 @@ pub(crate) fn store_byte(
 }
 
 pub(crate) fn store_word(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -507,7 +770,7 @@ pub(crate) fn malloc(
 }
 
 pub(crate) fn memclear(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -515,12 +778,12 @@ pub(crate) fn memclear(
 ) -> SimpleResult<()> {
     let range = MemoryRange::new(a, b)?;
     owner.verify_ownership(&range)?;
-    memory[range.usizes()].fill(0);
+    range.write(memory)?.fill(0);
     Ok(inc_pc(pc)?)
 }
 
 pub(crate) fn memcopy(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     pc: RegMut<PC>,
     a: Word,
@@ -532,6 +795,10 @@ pub(crate) fn memcopy(
 
     owner.verify_ownership(&dst_range)?;
 
+    if dst_range.end.max(src_range.end) > memory.len() {
+        return Err(PanicReason::MemoryOverflow.into())
+    }
+
     if dst_range.start <= src_range.start && src_range.start < dst_range.end
         || src_range.start <= dst_range.start && dst_range.start < src_range.end
         || dst_range.start < src_range.end && src_range.end <= dst_range.end
@@ -553,7 +820,7 @@ pub(crate) fn memcopy(
 }
 
 pub(crate) fn memeq(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     result: &mut Word,
     pc: RegMut<PC>,
     b: Word,
@@ -562,7 +829,7 @@ pub(crate) fn memeq(
 ) -> SimpleResult<()> {
     let range1 = MemoryRange::new(b, d)?;
     let range2 = MemoryRange::new(c, d)?;
-    *result = (memory[range1.usizes()] == memory[range2.usizes()]) as Word;
+    *result = (range1.read(memory)? == range2.read(memory)?) as Word;
     Ok(inc_pc(pc)?)
 }
 
@@ -572,11 +839,14 @@ pub struct OwnershipRegisters {
     pub(crate) ssp: u64,
     pub(crate) hp: u64,
     pub(crate) prev_hp: u64,
+    pub(crate) memory_limit: u64,
     pub(crate) context: Context,
 }
 
 impl OwnershipRegisters {
-    pub(crate) fn new<S, Tx, Ecal>(vm: &Interpreter<S, Tx, Ecal>) -> Self {
+    pub(crate) fn new<S, Tx, Ecal, Observer>(
+        vm: &Interpreter<S, Tx, Ecal, Observer>,
+    ) -> Self {
         OwnershipRegisters {
             sp: vm.registers[RegId::SP],
             ssp: vm.registers[RegId::SSP],
@@ -586,6 +856,7 @@ impl OwnershipRegisters {
                 .last()
                 .map(|frame| frame.registers()[RegId::HP])
                 .unwrap_or(0),
+            memory_limit: vm.memory_limit(),
             context: vm.context.clone(),
         }
     }
@@ -624,7 +895,7 @@ impl OwnershipRegisters {
             return false
         }
 
-        if range.end > VM_MAX_RAM {
+        if range.end > self.memory_limit {
             return false
         }
 
@@ -643,7 +914,7 @@ impl OwnershipRegisters {
         }
 
         let heap_end = if self.context.is_external() {
-            VM_MAX_RAM
+            self.memory_limit
         } else {
             self.prev_hp
         };
@@ -656,11 +927,11 @@ pub(crate) fn try_mem_write<A: ToAddr>(
     addr: A,
     data: &[u8],
     owner: OwnershipRegisters,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
 ) -> SimpleResult<()> {
     let range = MemoryRange::new(addr, data.len())?;
     owner.verify_ownership(&range)?;
-    memory[range.usizes()].copy_from_slice(data);
+    range.write(memory)?.copy_from_slice(data);
     Ok(())
 }
 
@@ -668,43 +939,42 @@ pub(crate) fn try_zeroize<A: ToAddr, B: ToAddr>(
     addr: A,
     len: B,
     owner: OwnershipRegisters,
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
 ) -> SimpleResult<()> {
     let range = MemoryRange::new(addr, len)?;
     owner.verify_ownership(&range)?;
-    memory[range.usizes()].fill(0);
+    range.write(memory)?.fill(0);
     Ok(())
 }
 
 /// Reads a constant-sized byte array from memory, performing overflow and memory range
 /// checks.
 pub(crate) fn read_bytes<const COUNT: usize>(
-    memory: &[u8; MEM_SIZE],
+    memory: &[u8],
     addr: Word,
 ) -> Result<[u8; COUNT], PanicReason> {
     let range = MemoryRange::new_const::<_, COUNT>(addr)?;
-    Ok(<[u8; COUNT]>::try_from(&memory[range.usizes()])
-        .unwrap_or_else(|_| unreachable!()))
+    Ok(<[u8; COUNT]>::try_from(range.read(memory)?).unwrap_or_else(|_| unreachable!()))
 }
 
 /// Writes a constant-sized byte array to memory, performing overflow, memory range and
 /// ownership checks.
 pub(crate) fn write_bytes<const COUNT: usize>(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     owner: OwnershipRegisters,
     addr: Word,
     bytes: [u8; COUNT],
 ) -> SimpleResult<()> {
     let range = MemoryRange::new_const::<_, COUNT>(addr)?;
     owner.verify_ownership(&range)?;
-    memory[range.usizes()].copy_from_slice(&bytes);
+    range.write(memory)?.copy_from_slice(&bytes);
     Ok(())
 }
 
 /// Attempt copy from slice to memory, filling zero bytes when exceeding slice boundaries.
 /// Performs overflow and memory range checks, but no ownership checks.
 pub(crate) fn copy_from_slice_zero_fill_noownerchecks<A: ToAddr, B: ToAddr>(
-    memory: &mut [u8; MEM_SIZE],
+    memory: &mut [u8],
     src: &[u8],
     dst_addr: A,
     src_offset: usize,
@@ -715,8 +985,8 @@ pub(crate) fn copy_from_slice_zero_fill_noownerchecks<A: ToAddr, B: ToAddr>(
     let src_end = src_offset.saturating_add(range.len()).min(src.len());
     let data = src.get(src_offset..src_end).unwrap_or_default();
     let (r_data, r_zero) = range.split_at_offset(data.len());
-    memory[r_data.usizes()].copy_from_slice(data);
-    memory[r_zero.usizes()].fill(0);
+    r_data.write(memory)?.copy_from_slice(data);
+    r_zero.write(memory)?.fill(0);
 
     Ok(())
 }
@@ -29,13 +29,14 @@ fn test_malloc(mut hp: Word, sp: Word, a: Word) -> SimpleResult<Word> {
 #[test_case(true, MEM_SIZE as Word - 10, 10 => Ok(()); "Memory range ends at last address")]
 #[test_case(true, 1, VM_MAX_RAM + 1 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "Memory range size exceeds limit")]
 fn test_memclear(has_ownership: bool, a: Word, b: Word) -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 4;
     let mut owner = OwnershipRegisters {
         sp: 0,
         ssp: 0,
         hp: MEM_SIZE as Word,
         prev_hp: MEM_SIZE as Word,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -63,7 +64,7 @@ fn test_memclear(has_ownership: bool, a: Word, b: Word) -> SimpleResult<()> {
 #[test_case(true, 21, 22, 10 => Err(PanicReason::MemoryWriteOverlap.into()); "a <= b < ac")]
 #[test_case(true, 21, 20, 10 => Err(PanicReason::MemoryWriteOverlap.into()); "a < bc <= ac")]
 fn test_memcopy(has_ownership: bool, a: Word, b: Word, c: Word) -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[b as usize..b as usize + c as usize].copy_from_slice(&vec![2u8; c as usize]);
     let mut pc = 4;
     let mut owner = OwnershipRegisters {
@@ -71,6 +72,7 @@ fn test_memcopy(has_ownership: bool, a: Word, b: Word, c: Word) -> SimpleResult<
         ssp: 0,
         hp: 0,
         prev_hp: 0,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -98,7 +100,7 @@ fn test_memcopy(has_ownership: bool, a: Word, b: Word, c: Word) -> SimpleResult<
 #[test_case(0, 0, 0 => Ok(()); "smallest input values")]
 #[test_case(0, VM_MAX_RAM/2, VM_MAX_RAM/2 => Ok(()); "maximum range of addressable memory")]
 fn test_memeq(b: Word, c: Word, d: Word) -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let r = (b as usize).min(MEM_SIZE)
         ..((b as usize).min(MEM_SIZE) + (d as usize).min(MEM_SIZE)).min(MEM_SIZE);
     memory[r].fill(2u8);
@@ -163,7 +165,7 @@ fn test_stack_pointer_overflow(
 #[test_case(0, VM_MAX_RAM - 1 => Ok(()); "c eq VM_MAX_RAM - 1")]
 #[test_case(u32::MAX as u64, u32::MAX as u64 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "b + c overflow")]
 fn test_load_byte(b: Word, c: Word) -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     memory[((b + c) as usize).min(MEM_SIZE - 1)] = 2;
     let mut pc = 4;
     let mut result = 0;
@@ -180,7 +182,7 @@ fn test_load_byte(b: Word, c: Word) -> SimpleResult<()> {
 #[test_case(VM_MAX_RAM, 1 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "b + 8 * c gteq VM_MAX_RAM")]
 fn test_load_word(b: Word, c: Word) -> SimpleResult<()> {
     // create a mutable memory with size `MEM_SIZE`
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
 
     // calculate start location where 8 bytes of value will be stored based on `b` and `c`
     // values.
@@ -211,13 +213,14 @@ fn test_load_word(b: Word, c: Word) -> SimpleResult<()> {
 #[test_case(false, 0, 100, VM_MAX_RAM - 1 => Err(PanicOrBug::Panic(PanicReason::MemoryOwnership)); "Memory overflow on stack")]
 #[test_case(true, VM_MAX_RAM, 1, 1 => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "Memory overflow by address range")]
 fn test_store_byte(has_ownership: bool, a: Word, b: Word, c: Word) -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 4;
     let mut owner = OwnershipRegisters {
         sp: 0,
         ssp: 0,
         hp: VM_MAX_RAM,
         prev_hp: VM_MAX_RAM,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -241,7 +244,7 @@ fn test_store_byte_more(
     #[values(0, 1, 0xff, 0x100)] b: Word,
     #[values(0, 1, 2)] c: Word,
 ) -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 4;
 
     // Full ownership in heap
@@ -250,6 +253,7 @@ fn test_store_byte_more(
         ssp: 0,
         hp: 0,
         prev_hp: VM_MAX_RAM,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -274,13 +278,14 @@ fn test_store_byte_more(
 #[test_case(true, 20, 30, VM_MAX_RAM => Err(PanicOrBug::Panic(PanicReason::MemoryOverflow)); "Fails due to memory overflow")]
 #[test_case(false, 20, 30, 40 => Err(PanicOrBug::Panic(PanicReason::MemoryOwnership)); "Fails due to not having ownership of the range")]
 fn test_store_word(has_ownership: bool, a: Word, b: Word, c: Word) -> SimpleResult<()> {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 4;
     let mut owner = OwnershipRegisters {
         sp: 0,
         ssp: 0,
         hp: VM_MAX_RAM,
         prev_hp: VM_MAX_RAM,
+        memory_limit: VM_MAX_RAM,
         context: Context::Script {
             block_height: Default::default(),
         },
@@ -12,7 +12,6 @@ use crate::{
     interpreter::memory::{
         pop_selected_registers,
         push_selected_registers,
-        Memory,
     },
 };
 
@@ -32,7 +31,7 @@ fn test_push_pop(
     )]
     bitmask: u32,
 ) {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 0;
     let mut sp = 0;
 
@@ -91,7 +90,7 @@ fn test_push_pop(
 
 #[test]
 fn test_push_stack_overflow() {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 0;
     let mut sp = 10;
     let hp = 14;
@@ -115,7 +114,7 @@ fn test_push_stack_overflow() {
 
 #[test]
 fn test_pop_from_empty_stack() {
-    let memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 0;
     let mut sp = 32;
     let ssp = 16;
@@ -139,7 +138,7 @@ fn test_pop_from_empty_stack() {
 
 #[test]
 fn test_pop_sp_overflow() {
-    let memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let mut pc = 0;
     let mut sp = 16;
     let ssp = 0;
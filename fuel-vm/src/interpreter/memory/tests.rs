@@ -1,3 +1,4 @@
+#![allow(non_snake_case)]
 #![allow(clippy::cast_possible_truncation)]
 
 use alloc::vec;
@@ -18,10 +19,8 @@ fn memcopy() {
     let tx_params = TxParameters::default().with_max_gas_per_tx(Word::MAX / 2);
     let zero_gas_price = 0;
 
-    let consensus_params = ConsensusParameters {
-        tx_params,
-        ..Default::default()
-    };
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_tx_params(tx_params);
 
     let mut vm = Interpreter::<_, _>::with_storage(
         MemoryStorage::default(),
@@ -37,8 +36,8 @@ fn memcopy() {
         .expect("default tx should produce a valid checked transaction")
         .into_ready(
             zero_gas_price,
-            &consensus_params.gas_costs,
-            &consensus_params.fee_params,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
         )
         .unwrap();
 
@@ -102,8 +101,8 @@ fn memrange() {
         .expect("Empty script should be valid")
         .into_ready(
             gas_price,
-            &consensus_params.gas_costs,
-            &consensus_params.fee_params,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
         )
         .unwrap();
     let mut vm = Interpreter::<_, _>::with_memory_storage();
@@ -140,8 +139,8 @@ fn stack_alloc_ownership() {
         .expect("Empty script should be valid")
         .into_ready(
             gas_price,
-            &consensus_params.gas_costs,
-            &consensus_params.fee_params,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
         )
         .unwrap();
     vm.init_script(tx).expect("Failed to init VM");
@@ -278,7 +277,7 @@ fn test_mem_write(
     data: &[u8],
     registers: OwnershipRegisters,
 ) -> (bool, [u8; 100]) {
-    let mut memory: Memory<MEM_SIZE> = vec![0u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![0u8; MEM_SIZE].into();
     let r = try_mem_write(addr, data, registers, &mut memory).is_ok();
     let memory: [u8; 100] = memory[..100].try_into().unwrap();
     (r, memory)
@@ -334,7 +333,7 @@ fn test_try_zeroize(
     len: usize,
     registers: OwnershipRegisters,
 ) -> (bool, [u8; 100]) {
-    let mut memory: Memory<MEM_SIZE> = vec![1u8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![1u8; MEM_SIZE].into();
     let r = try_zeroize(addr, len, registers, &mut memory).is_ok();
     let memory: [u8; 100] = memory[..100].try_into().unwrap();
     (r, memory)
@@ -371,7 +370,7 @@ fn test_copy_from_slice_zero_fill_noownerchecks(
     src_offset: usize,
     src_data: &[u8],
 ) -> (bool, [u8; 5]) {
-    let mut memory: Memory<MEM_SIZE> = vec![0xffu8; MEM_SIZE].try_into().unwrap();
+    let mut memory: Box<[u8]> = vec![0xffu8; MEM_SIZE].into();
     let r = copy_from_slice_zero_fill_noownerchecks(
         &mut memory,
         src_data,
@@ -383,3 +382,25 @@ fn test_copy_from_slice_zero_fill_noownerchecks(
     let memory: [u8; 5] = memory[..5].try_into().unwrap();
     (r, memory)
 }
+
+#[test_case(64 * 1024 => 64 * 1024; "below the maximum")]
+#[test_case(VM_MAX_RAM => MEM_SIZE; "at the maximum")]
+#[test_case(VM_MAX_RAM + 1 => MEM_SIZE; "above the maximum")]
+#[test_case(Word::MAX => MEM_SIZE; "at the maximum word")]
+fn memory_instance__with_limit_clamps_to_max_ram(memory_limit: Word) -> usize {
+    MemoryInstance::with_limit(memory_limit).len()
+}
+
+#[test_case(64 * 1024 => 64 * 1024; "below the maximum")]
+#[test_case(VM_MAX_RAM + 1 => MEM_SIZE; "above the maximum")]
+#[test_case(Word::MAX => MEM_SIZE; "at the maximum word")]
+fn memory_instance__resize_clamps_to_max_ram(memory_limit: Word) -> usize {
+    // Given
+    let mut memory = MemoryInstance::with_limit(1024);
+
+    // When
+    memory.resize(memory_limit);
+
+    // Then
+    memory.len()
+}
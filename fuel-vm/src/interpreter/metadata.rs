@@ -46,7 +46,7 @@ use fuel_types::{
 #[cfg(test)]
 mod tests;
 
-impl<S, Tx, Ecal> Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
 {
@@ -56,9 +56,18 @@ where
         imm: Immediate18,
     ) -> SimpleResult<()> {
         let chain_id = self.chain_id();
+        let max_call_depth = self.max_call_depth();
         let (SystemRegisters { pc, .. }, mut w) = split_registers(&mut self.registers);
         let result = &mut w[WriteRegKey::try_from(ra)?];
-        metadata(&self.context, &self.frames, pc, result, imm, chain_id)
+        metadata(
+            &self.context,
+            &self.frames,
+            pc,
+            result,
+            imm,
+            chain_id,
+            max_call_depth,
+        )
     }
 
     pub(crate) fn get_transaction_field(
@@ -86,11 +95,14 @@ pub(crate) fn metadata(
     result: &mut Word,
     imm: Immediate18,
     chain_id: ChainId,
+    max_call_depth: Word,
 ) -> SimpleResult<()> {
     let external = context.is_external();
     let args = GMArgs::try_from(imm)?;
 
-    if external {
+    if args == GMArgs::GetRemainingCallDepth {
+        *result = max_call_depth.saturating_sub(frames.len() as Word);
+    } else if external {
         match args {
             GMArgs::GetVerifyingPredicate => {
                 *result = context
@@ -177,6 +189,10 @@ impl<Tx> GTFInput<'_, Tx> {
                 .policies()
                 .get(PolicyType::MaxFee)
                 .ok_or(PanicReason::PolicyIsNotSet)?,
+            GTFArgs::PolicyGasPriceFloor => tx
+                .policies()
+                .get(PolicyType::GasPriceFloor)
+                .ok_or(PanicReason::PolicyIsNotSet)?,
             GTFArgs::ScriptInputsCount | GTFArgs::CreateInputsCount => {
                 tx.inputs().len() as Word
             }
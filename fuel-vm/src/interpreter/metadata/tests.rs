@@ -1,6 +1,9 @@
 use alloc::vec;
 
-use fuel_tx::Script;
+use fuel_tx::{
+    ContractParameters,
+    Script,
+};
 use fuel_types::BlockHeight;
 use test_case::test_case;
 
@@ -22,6 +25,7 @@ fn test_metadata() {
         &mut result,
         imm,
         ChainId::default(),
+        ContractParameters::DEFAULT.max_call_depth,
     )
     .unwrap();
     assert_eq!(pc, 8);
@@ -66,8 +70,33 @@ fn get_chain_id(context: Context, chain_id: u64) {
         &mut result,
         imm,
         chain_id.into(),
+        ContractParameters::DEFAULT.max_call_depth,
     )
     .unwrap();
 
     assert_eq!(result, chain_id);
 }
+
+#[test_case(Context::Script { block_height: BlockHeight::default() }, 0 => 10; "script")]
+#[test_case(Context::Call { block_height: BlockHeight::default() }, 1 => 9; "call")]
+#[test_case(Context::Call { block_height: BlockHeight::default() }, 10 => 0; "deepest call")]
+fn get_remaining_call_depth(context: Context, depth: usize) -> Word {
+    let frames = vec![CallFrame::default(); depth];
+    let mut pc = 4;
+    let mut result = 1;
+    let imm = GMArgs::GetRemainingCallDepth as Immediate18;
+
+    metadata(
+        &context,
+        &frames,
+        RegMut::new(&mut pc),
+        &mut result,
+        imm,
+        ChainId::default(),
+        10,
+    )
+    .unwrap();
+
+    assert_eq!(pc, 8);
+    result
+}
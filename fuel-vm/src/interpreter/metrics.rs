@@ -0,0 +1,67 @@
+//! Counters of the storage accesses performed by an execution.
+
+use fuel_types::{
+    Bytes32,
+    Word,
+};
+
+/// Storage accesses performed by the current transaction.
+///
+/// The counters are reset when a transaction is initialized and are not reverted
+/// with the state, so they include the work of the reverted and panicked
+/// transactions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionMetrics {
+    /// Number of executed `SRW` and `SRWQ` instructions.
+    pub storage_reads: u64,
+    /// Total size of the storage slots read by `SRW` and `SRWQ`.
+    pub storage_read_bytes: u64,
+    /// Number of executed `SWW`, `SWWQ` and `SCWQ` instructions.
+    pub storage_writes: u64,
+    /// Total size of the storage slots written or cleared by `SWW`, `SWWQ` and
+    /// `SCWQ`.
+    pub storage_write_bytes: u64,
+    /// Number of contract codes loaded from the storage by `CALL`, `LDC`, `CCP` and
+    /// `CSIZ`.
+    pub contract_code_loads: u64,
+    /// Total size of the contract codes loaded from the storage.
+    pub contract_code_bytes: u64,
+    /// Number of contract codes taken from the contract code cache instead of the
+    /// storage by `CALL`, `LDC`, `CCP` and `CSIZ`.
+    pub contract_code_cache_hits: u64,
+    /// Number of messages output by `SMO`.
+    pub message_outputs: u64,
+    /// Total size of the data of the messages output by `SMO`.
+    pub message_output_bytes: u64,
+}
+
+impl ExecutionMetrics {
+    pub(crate) fn record_storage_read(&mut self, slots: Word) {
+        self.storage_reads = self.storage_reads.saturating_add(1);
+        self.storage_read_bytes = self
+            .storage_read_bytes
+            .saturating_add(slots.saturating_mul(Bytes32::LEN as Word));
+    }
+
+    pub(crate) fn record_storage_write(&mut self, slots: Word) {
+        self.storage_writes = self.storage_writes.saturating_add(1);
+        self.storage_write_bytes = self
+            .storage_write_bytes
+            .saturating_add(slots.saturating_mul(Bytes32::LEN as Word));
+    }
+
+    pub(crate) fn record_contract_code_load(&mut self, len: Word) {
+        self.contract_code_loads = self.contract_code_loads.saturating_add(1);
+        self.contract_code_bytes = self.contract_code_bytes.saturating_add(len);
+    }
+
+    pub(crate) fn record_contract_code_cache_hit(&mut self) {
+        self.contract_code_cache_hits = self.contract_code_cache_hits.saturating_add(1);
+    }
+
+    pub(crate) fn record_message_output(&mut self, data_len: Word) {
+        self.message_outputs = self.message_outputs.saturating_add(1);
+        self.message_output_bytes = self.message_output_bytes.saturating_add(data_len);
+    }
+}
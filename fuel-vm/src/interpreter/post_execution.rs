@@ -12,6 +12,7 @@ use crate::interpreter::{
     RuntimeBalances,
 };
 use fuel_tx::{
+    field::ScriptGasLimit,
     FeeParameters,
     GasCosts,
 };
@@ -20,7 +21,7 @@ use fuel_types::{
     Word,
 };
 
-impl<S, T, Ecal> Interpreter<S, T, Ecal>
+impl<S, T, Ecal, Observer> Interpreter<S, T, Ecal, Observer>
 where
     S: InterpreterStorage,
 {
@@ -66,3 +67,39 @@ where
         Ok(())
     }
 }
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
+where
+    Tx: ExecutableTransaction,
+{
+    /// The free balances after the execution of the transaction, ordered by the asset
+    /// id. The balances of a reverted transaction are reset to the inputs.
+    pub fn balances(&self) -> impl Iterator<Item = (AssetId, Word)> + '_ {
+        self.balances.iter()
+    }
+
+    /// The script gas not used by the execution of the transaction. The transactions
+    /// without a script don't have unused gas.
+    pub fn unused_gas(&self) -> Word {
+        self.tx
+            .as_script()
+            .map(|_| self.remaining_gas())
+            .unwrap_or_default()
+    }
+
+    /// The fee refunded to the base asset change output for the unused gas and the
+    /// unused part of the max fee, as computed after the execution of the
+    /// transaction.
+    ///
+    /// Returns `None` if the computation overflows.
+    pub fn refund_amount(&self, gas_price: Word) -> Option<Word> {
+        let used_gas = self
+            .tx
+            .as_script()
+            .map(|script| script.script_gas_limit().saturating_sub(self.unused_gas()))
+            .unwrap_or_default();
+
+        self.tx
+            .refund_fee(self.gas_costs(), self.fee_params(), used_gas, gas_price)
+    }
+}
@@ -1,5 +1,9 @@
-use alloc::vec::Vec;
+use alloc::{
+    sync::Arc,
+    vec::Vec,
+};
 use core::{
+    fmt,
     mem,
     ops::Index,
 };
@@ -20,11 +24,69 @@ use crate::{
     },
 };
 
+/// Receives the receipts as soon as they are created, before the execution of the
+/// transaction ends.
+///
+/// The listener is shared by the clones of the interpreter, so it receives the
+/// receipts of the transactions executed by any of them.
+pub trait ReceiptListener: Send + Sync {
+    /// Called with every receipt added to the context, in order.
+    fn on_receipt(&self, receipt: &Receipt);
+}
+
+/// The listener installed into the receipts context.
+#[derive(Default, Clone)]
+struct Listener(Option<Arc<dyn ReceiptListener>>);
+
+impl fmt::Debug for Listener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => write!(f, "Listener(enabled)"),
+            None => write!(f, "Listener(disabled)"),
+        }
+    }
+}
+
+/// The receipts of a [`ReceiptsCtx`], without its listener.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct ReceiptsState {
+    receipts: Vec<Receipt>,
+    receipts_tree: MerkleTree,
+    count: usize,
+    size: u64,
+}
+
+/// Limits of the receipts emitted by the program, which don't apply to the panic
+/// and the script result receipts ending the execution.
+#[derive(Debug, Clone, Copy)]
+struct Limits {
+    count: u64,
+    size: u64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            count: u64::MAX,
+            size: u64::MAX,
+        }
+    }
+}
+
 /// Receipts and the associated Merkle tree
 #[derive(Debug, Default, Clone)]
 pub struct ReceiptsCtx {
     receipts: Vec<Receipt>,
     receipts_tree: MerkleTree,
+    /// Number of receipts added since the last reset, stored or not
+    count: usize,
+    /// Total size of the receipts added since the last reset, including their data
+    size: u64,
+    limits: Limits,
+    listener: Listener,
+    /// Only deliver the receipts to the listener, without storing them
+    discard_receipts: bool,
 }
 
 impl ReceiptsCtx {
@@ -35,15 +97,28 @@ impl ReceiptsCtx {
     /// Add a new receipt, updating the Merkle tree as well.
     /// Returns a panic if the context is full.
     pub fn push(&mut self, receipt: Receipt) -> SimpleResult<()> {
-        if self.receipts.len() == Self::MAX_RECEIPTS {
+        if self.count == Self::MAX_RECEIPTS {
             return Err(Bug::new(BugVariant::ReceiptsCtxFull).into())
         }
 
+        let bytes = receipt.to_bytes();
+        let size = receipt_size(&receipt, &bytes);
+        let ends_execution = matches!(
+            receipt,
+            Receipt::ScriptResult { .. } | Receipt::Panic { .. }
+        );
+        if !ends_execution
+            && (self.count as u64 >= self.limits.count
+                || self.size.saturating_add(size) > self.limits.size)
+        {
+            return Err(PanicReason::TooManyReceipts.into())
+        }
+
         // Last two slots can be only used for ending the script,
         // with a script result optinally preceded by a panic
-        if (self.receipts.len() == Self::MAX_RECEIPTS - 1
+        if (self.count == Self::MAX_RECEIPTS - 1
             && !matches!(receipt, Receipt::ScriptResult { .. }))
-            || (self.receipts.len() == Self::MAX_RECEIPTS - 2
+            || (self.count == Self::MAX_RECEIPTS - 2
                 && !matches!(
                     receipt,
                     Receipt::ScriptResult { .. } | Receipt::Panic { .. }
@@ -52,25 +127,90 @@ impl ReceiptsCtx {
             return Err(PanicReason::TooManyReceipts.into())
         }
 
-        self.receipts_tree.push(receipt.to_bytes().as_slice());
-        self.receipts.push(receipt);
+        self.receipts_tree.push(bytes.as_slice());
+        self.count = self.count.saturating_add(1);
+        self.size = self.size.saturating_add(size);
+        if let Some(listener) = &self.listener.0 {
+            listener.on_receipt(&receipt);
+        }
+        if !self.discard_receipts {
+            self.receipts.push(receipt);
+        }
         Ok(())
     }
 
-    /// Reset the context to an empty state
+    /// Reset the context to an empty state. The listener is kept.
     pub fn clear(&mut self) {
         self.receipts_tree = MerkleTree::new();
         self.receipts.clear();
+        self.count = 0;
+        self.size = 0;
+    }
+
+    /// Limit the number and the total size of the receipts emitted by the program.
+    /// Adding a receipt beyond the limits fails with
+    /// [`PanicReason::TooManyReceipts`], except for the panic and the script result
+    /// receipts ending the execution, so the limits apply only to the receipts of
+    /// the program.
+    pub fn set_limits(&mut self, max_count: u64, max_size: u64) {
+        self.limits = Limits {
+            count: max_count,
+            size: max_size,
+        };
     }
 
-    /// Return how many receipts are in this context
+    /// Total size of the receipts added to this context, including their data
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Return how many receipts are stored in this context. The receipts discarded
+    /// after their delivery to the listener aren't counted, see
+    /// [`Self::produced_count`].
     pub fn len(&self) -> usize {
         self.receipts.len()
     }
 
-    /// Returns `true` if the context has no receipts.
+    /// Returns `true` if the context stores no receipts.
     pub fn is_empty(&self) -> bool {
-        self.receipts.len() == 0
+        self.receipts.is_empty()
+    }
+
+    /// Return how many receipts were added to this context, including the receipts
+    /// discarded after their delivery to the listener
+    pub fn produced_count(&self) -> usize {
+        self.count
+    }
+
+    /// Set the listener receiving every receipt added to the context.
+    pub fn set_listener(&mut self, listener: Arc<dyn ReceiptListener>) {
+        self.listener.0 = Some(listener);
+    }
+
+    /// If `discard` is `true`, the receipts are only delivered to the listener and
+    /// aren't stored in the context. The Merkle root is still computed over all
+    /// of them.
+    pub fn set_discard_receipts(&mut self, discard: bool) {
+        self.discard_receipts = discard;
+    }
+
+    /// The receipts added to the context, to be restored with [`Self::restore`].
+    pub(crate) fn state(&self) -> ReceiptsState {
+        ReceiptsState {
+            receipts: self.receipts.clone(),
+            receipts_tree: self.receipts_tree.clone(),
+            count: self.count,
+            size: self.size,
+        }
+    }
+
+    /// Replace the receipts with the `state`, without delivering them to the
+    /// listener again.
+    pub(crate) fn restore(&mut self, state: &ReceiptsState) {
+        self.receipts.clone_from(&state.receipts);
+        self.receipts_tree = state.receipts_tree.clone();
+        self.count = state.count;
+        self.size = state.size;
     }
 
     /// Return current Merkle root of the receipts
@@ -86,13 +226,29 @@ impl ReceiptsCtx {
     /// Recalculates the Merkle root of the receipts from scratch. This should
     /// only be used when the list of receipts has been mutated externally.
     fn recalculate_root(&mut self) {
+        // The discarded receipts are already accumulated in the tree
+        if self.discard_receipts {
+            return
+        }
+
         self.receipts_tree = MerkleTree::new();
+        self.size = 0;
         for receipt in &self.receipts {
-            self.receipts_tree.push(receipt.to_bytes().as_slice())
+            let bytes = receipt.to_bytes();
+            self.receipts_tree.push(bytes.as_slice());
+            self.size = self.size.saturating_add(receipt_size(receipt, &bytes));
         }
+        self.count = self.receipts.len();
     }
 }
 
+/// Size of the `receipt` encoded into `bytes`, including its data, which isn't
+/// part of the encoding.
+fn receipt_size(receipt: &Receipt, bytes: &[u8]) -> u64 {
+    let data_len = receipt.data().map_or(0, <[u8]>::len);
+    (bytes.len() as u64).saturating_add(data_len as u64)
+}
+
 impl Index<usize> for ReceiptsCtx {
     type Output = Receipt;
 
@@ -223,4 +379,19 @@ mod tests {
         let expected_root = ephemeral_merkle_root(leaves);
         assert_eq!(root, expected_root)
     }
+
+    #[test]
+    fn discarded_receipts_are_only_counted_as_produced() {
+        let mut ctx = ReceiptsCtx::default();
+        ctx.set_discard_receipts(true);
+
+        for receipt in iter::repeat(create_receipt()).take(5) {
+            ctx.push(receipt).expect("context not full");
+        }
+
+        assert_eq!(ctx.len(), 0);
+        assert!(ctx.is_empty());
+        assert!(ctx.as_ref().iter().next().is_none());
+        assert_eq!(ctx.produced_count(), 5);
+    }
 }
@@ -0,0 +1,165 @@
+//! Snapshots of the VM state, to fork the execution.
+
+use super::{
+    receipts::ReceiptsState,
+    ExecutionMetrics,
+    InitialBalances,
+    Interpreter,
+    PanicContext,
+    PanicLocation,
+    RuntimeBalances,
+};
+use crate::{
+    call::CallFrame,
+    consts::VM_REGISTER_COUNT,
+    context::Context,
+};
+
+use alloc::vec::Vec;
+use fuel_types::Word;
+
+/// Snapshot of the VM state during the execution of a transaction, see
+/// [`Interpreter::snapshot`].
+///
+/// The snapshot doesn't include the storage. To fork the execution, the caller must
+/// pair the snapshot with a snapshot of a transactional storage, restoring both
+/// together.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VmSnapshot<Tx> {
+    #[cfg_attr(feature = "serde", serde(with = "serde_registers"))]
+    registers: [Word; VM_REGISTER_COUNT],
+    /// The memory below the highest stack pointer, the rest of the memory up to the
+    /// `heap` being zeroed.
+    stack: Vec<u8>,
+    /// The memory above the lowest heap pointer.
+    heap: Vec<u8>,
+    frames: Vec<CallFrame>,
+    receipts: ReceiptsState,
+    tx: Tx,
+    initial_balances: InitialBalances,
+    balances: RuntimeBalances,
+    context: Context,
+    panic_context: PanicContext,
+    panic_location: Option<PanicLocation>,
+    instructions_executed: u64,
+    metrics: ExecutionMetrics,
+}
+
+impl<Tx> VmSnapshot<Tx> {
+    /// The registers, including the remaining gas.
+    pub const fn registers(&self) -> &[Word; VM_REGISTER_COUNT] {
+        &self.registers
+    }
+
+    /// The size of the captured memory, the rest of the memory being zeroed.
+    pub fn memory_size(&self) -> usize {
+        self.stack.len().saturating_add(self.heap.len())
+    }
+
+    /// The transaction being executed.
+    pub const fn transaction(&self) -> &Tx {
+        &self.tx
+    }
+}
+
+/// The snapshot can't be restored because its memory is larger than the VM memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+#[display(fmt = "The memory of the snapshot doesn't fit into the VM memory")]
+pub struct InvalidSnapshot;
+
+impl<S, Tx, Ecal, Observer> Interpreter<S, Tx, Ecal, Observer>
+where
+    Tx: Clone,
+{
+    /// Capture the state of the VM: the registers, the memory, the call frames, the
+    /// receipts, the transaction, the balances and the context.
+    ///
+    /// Only the parts of the memory that may have been written are copied. The
+    /// storage, the listeners and the parameters of the interpreter aren't captured.
+    pub fn snapshot(&self) -> VmSnapshot<Tx> {
+        let (stack, heap) = self.memory.dirty_parts();
+
+        VmSnapshot {
+            registers: self.registers,
+            stack: stack.to_vec(),
+            heap: heap.to_vec(),
+            frames: self.frames.clone(),
+            receipts: self.receipts.state(),
+            tx: self.tx.clone(),
+            initial_balances: self.initial_balances.clone(),
+            balances: self.balances.clone(),
+            context: self.context.clone(),
+            panic_context: self.panic_context.clone(),
+            panic_location: self.panic_location,
+            instructions_executed: self.instructions_executed,
+            metrics: self.metrics,
+        }
+    }
+
+    /// Restore the state of the VM captured by [`Self::snapshot`], so the execution
+    /// continues from it.
+    ///
+    /// The storage isn't restored, see [`VmSnapshot`]. The receipts of the snapshot
+    /// aren't delivered to the receipt listener again.
+    pub fn restore(&mut self, snapshot: &VmSnapshot<Tx>) -> Result<(), InvalidSnapshot> {
+        if !self
+            .memory
+            .restore_dirty_parts(&snapshot.stack, &snapshot.heap)
+        {
+            return Err(InvalidSnapshot)
+        }
+
+        self.registers = snapshot.registers;
+        self.frames.clone_from(&snapshot.frames);
+        self.receipts.restore(&snapshot.receipts);
+        self.tx.clone_from(&snapshot.tx);
+        self.initial_balances.clone_from(&snapshot.initial_balances);
+        self.balances.clone_from(&snapshot.balances);
+        self.context.clone_from(&snapshot.context);
+        self.panic_context.clone_from(&snapshot.panic_context);
+        self.panic_location = snapshot.panic_location;
+        self.instructions_executed = snapshot.instructions_executed;
+        self.metrics = snapshot.metrics;
+        Ok(())
+    }
+}
+
+/// Serde representation of the registers as a sequence, since serde only supports
+/// the arrays of up to 32 elements.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_registers {
+    use crate::consts::VM_REGISTER_COUNT;
+
+    use alloc::vec::Vec;
+    use fuel_types::Word;
+    use serde::{
+        de::Error,
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub fn serialize<S>(
+        registers: &[Word; VM_REGISTER_COUNT],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(registers)
+    }
+
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<[Word; VM_REGISTER_COUNT], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::<Word>::deserialize(deserializer)?.try_into().map_err(
+            |registers: Vec<Word>| {
+                D::Error::invalid_length(registers.len(), &"64 registers")
+            },
+        )
+    }
+}
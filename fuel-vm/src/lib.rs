@@ -4,7 +4,8 @@
 #![warn(missing_docs)]
 #![deny(unsafe_code)]
 #![deny(unused_must_use)]
-#![deny(unused_crate_dependencies)]
+// The dev-dependencies of the benches are unused by the unit tests
+#![cfg_attr(not(test), deny(unused_crate_dependencies))]
 #![deny(clippy::cast_possible_truncation)]
 #![deny(clippy::string_slice)]
 
@@ -17,6 +18,7 @@ extern crate libm as _; // Not needed with stdlib
 pub mod backtrace;
 pub mod call;
 pub mod checked_transaction;
+pub mod code_cache;
 pub mod constraints;
 pub mod consts;
 pub mod context;
@@ -25,12 +27,18 @@ pub mod crypto;
 pub mod error;
 pub mod interpreter;
 pub mod memory_client;
+pub mod observer;
+#[cfg(feature = "std")]
+pub mod pool;
 pub mod predicate;
 pub mod state;
 pub mod storage;
 pub mod transactor;
 pub mod util;
 
+#[cfg(feature = "trace")]
+pub mod tracer;
+
 #[cfg(feature = "profile-any")]
 pub mod profiler;
 
@@ -44,14 +52,47 @@ pub mod profiler {
 
     /// Placeholder profiler.
     #[derive(Default, Debug, Clone)]
-    pub struct Profiler;
+    pub struct Profiler {
+        /// The global gas remaining before the gas charge that ran out of gas, and
+        /// the gas requested by the charge
+        out_of_gas: Option<(fuel_types::Word, fuel_types::Word)>,
+    }
 
     impl Profiler {
+        /// Records the gas charge of `requested` gas that ran out of gas, with
+        /// `remaining` global gas before the charge
+        pub(crate) fn on_out_of_gas(
+            &mut self,
+            remaining: fuel_types::Word,
+            requested: fuel_types::Word,
+        ) {
+            self.out_of_gas = Some((remaining, requested));
+        }
+
+        /// The global gas remaining before the gas charge that ran out of gas, and
+        /// the gas requested by the charge
+        pub(crate) fn out_of_gas(&self) -> Option<(fuel_types::Word, fuel_types::Word)> {
+            self.out_of_gas
+        }
+
+        /// Forgets the gas charge that ran out of gas
+        pub(crate) fn clear_out_of_gas(&mut self) {
+            self.out_of_gas = None;
+        }
+
         /// Set the current coverage location.
         pub fn set_coverage(&mut self, _location: InstructionLocation) {}
 
         /// Add gas to the current coverage location.
         pub fn add_gas(&mut self, _location: InstructionLocation, _gas_use: u64) {}
+
+        /// Record an execution of the `opcode` at the location.
+        pub fn add_execution(
+            &mut self,
+            _location: InstructionLocation,
+            _opcode: fuel_asm::Opcode,
+        ) {
+        }
     }
 }
 
@@ -122,6 +163,7 @@ pub mod prelude {
             Call,
             CallFrame,
         },
+        code_cache::ContractCodeCache,
         context::Context,
         error::{
             Bug,
@@ -130,15 +172,34 @@ pub mod prelude {
             RuntimeError,
         },
         interpreter::{
+            CallGas,
             ExecutableTransaction,
+            ExecutionMetrics,
             Interpreter,
             MemoryRange,
+            OutOfGas,
+            PanicLocation,
+            ReceiptListener,
+            VmSnapshot,
+        },
+        memory_client::{
+            MemoryClient,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        observer::{
+            InstructionCounter,
+            InstructionObserver,
+            NoopObserver,
+            VmStateView,
         },
-        memory_client::MemoryClient,
         predicate::RuntimePredicate,
         state::{
             Debugger,
+            DryRun,
+            MessageOutSummary,
             ProgramState,
+            ScriptFailure,
             StateTransition,
             StateTransitionRef,
         },
@@ -146,13 +207,20 @@ pub mod prelude {
             InterpreterStorage,
             MemoryStorage,
             PredicateStorage,
+            StorageChanges,
+            TransactionalStorage,
+        },
+        transactor::{
+            DefaultStorage,
+            Transactor,
+            TransactorBuilder,
         },
-        transactor::Transactor,
     };
 
     pub use crate::state::{
         Breakpoint,
         DebugEval,
+        WatchKind,
     };
 
     #[cfg(any(test, feature = "test-helpers"))]
@@ -171,11 +239,34 @@ pub mod prelude {
     ))]
     pub use crate::util::gas_profiling::GasProfiler;
 
+    #[cfg(feature = "std")]
+    pub use crate::code_cache::LruContractCodeCache;
+
+    #[cfg(all(feature = "trace", feature = "std"))]
+    pub use crate::tracer::{
+        ExecutionTrace,
+        TraceRecorder,
+        TraceStep,
+    };
+    #[cfg(feature = "trace")]
+    pub use crate::tracer::{
+        ExecutionTracer,
+        InstructionResult,
+    };
+
+    #[cfg(all(feature = "profile-coverage", feature = "std"))]
+    pub use crate::profiler::CoverageCollector;
+    #[cfg(feature = "profile-coverage")]
+    pub use crate::profiler::CoverageReceiver;
     pub use crate::profiler::Profiler;
     #[cfg(feature = "profile-any")]
     pub use crate::profiler::{
         CoverageProfilingData,
+        GasContext,
+        GasProfile,
+        GasProfileEntry,
         GasProfilingData,
+        GasUsage,
         InstructionLocation,
         PerLocationIter,
         PerLocationKeys,
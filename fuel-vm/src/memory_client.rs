@@ -2,9 +2,24 @@
 
 use crate::{
     backtrace::Backtrace,
-    checked_transaction::Checked,
-    state::StateTransitionRef,
-    storage::MemoryStorage,
+    checked_transaction::{
+        CheckPredicateParams,
+        Checked,
+        CheckedTransaction,
+        EstimatePredicates,
+        IntoChecked,
+    },
+    code_cache::ContractCodeCache,
+    error::InterpreterError,
+    state::{
+        DryRun,
+        StateTransitionRef,
+    },
+    storage::{
+        ContractsAssetsStorage,
+        InterpreterStorage,
+        MemoryStorage,
+    },
     transactor::Transactor,
 };
 
@@ -13,23 +28,115 @@ use crate::interpreter::{
     InterpreterParams,
     NotSupportedEcal,
 };
+#[cfg(feature = "std")]
+use crate::pool::MemoryPool;
+use alloc::{
+    sync::Arc,
+    vec::Vec,
+};
+use core::convert::Infallible;
+use fuel_asm::{
+    op,
+    PanicReason,
+    RegId,
+};
 use fuel_tx::{
+    field::{
+        InputContract,
+        MintAmount,
+        MintAssetId,
+    },
+    ConsensusParameters,
     Create,
+    Finalizable,
     GasCosts,
+    Input,
+    Mint,
     Receipt,
     Script,
+    ScriptExecutionResult,
+    StorageSlot,
+    Transaction,
+    TransactionBuilder,
+    TxId,
+    UniqueIdentifier,
+    ValidityError,
+};
+use fuel_types::{
+    BlockHeight,
+    ContractId,
+    Salt,
+    Word,
 };
 
 #[derive(Debug)]
 /// Client implementation with in-memory storage backend.
 pub struct MemoryClient<Ecal = NotSupportedEcal> {
     transactor: Transactor<MemoryStorage, Script, Ecal>,
+    consensus_params: ConsensusParameters,
+}
+
+/// Outcome of a transaction executed by [`MemoryClient::execute_block`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionExecutionStatus {
+    /// The transaction was executed and its changes were committed.
+    Success,
+    /// The script reverted, so its changes were discarded.
+    Revert,
+    /// The script panicked, so its changes were discarded.
+    Panic,
+    /// The transaction failed the checks or couldn't be executed, so it was skipped.
+    Rejected,
+    /// The transaction type isn't executed by the client, so it was skipped.
+    NotExecuted,
+}
+
+/// Result of a transaction executed by [`MemoryClient::execute_block`].
+#[derive(Debug)]
+pub struct TransactionExecutionResult {
+    /// The id of the transaction.
+    pub id: TxId,
+    /// The outcome of the transaction.
+    pub status: TransactionExecutionStatus,
+    /// The receipts produced by the transaction, empty if it wasn't executed.
+    pub receipts: Vec<Receipt>,
+    /// The gas used by the script, or `0` for the other transactions.
+    pub gas_used: Word,
+    /// The gas used by the predicates of the transaction, not included in
+    /// [`Self::gas_used`].
+    pub predicate_gas_used: Word,
+    /// The reason the transaction was rejected, if it was.
+    pub error: Option<InterpreterError<Infallible>>,
+}
+
+impl TransactionExecutionResult {
+    fn new(id: TxId, status: TransactionExecutionStatus) -> Self {
+        Self {
+            id,
+            status,
+            receipts: Vec::new(),
+            gas_used: 0,
+            predicate_gas_used: 0,
+            error: None,
+        }
+    }
+
+    fn rejected(id: TxId, error: InterpreterError<Infallible>) -> Self {
+        Self {
+            error: Some(error),
+            ..Self::new(id, TransactionExecutionStatus::Rejected)
+        }
+    }
 }
 
 #[cfg(any(test, feature = "test-helpers"))]
 impl Default for MemoryClient {
     fn default() -> Self {
-        Self::new(MemoryStorage::default(), InterpreterParams::default())
+        Self::new(
+            MemoryStorage::default(),
+            InterpreterParams::default(),
+            ConsensusParameters::default(),
+        )
     }
 }
 
@@ -47,17 +154,49 @@ impl<Ecal: EcalHandler> AsMut<MemoryStorage> for MemoryClient<Ecal> {
 
 impl<Ecal: EcalHandler + Default> MemoryClient<Ecal> {
     /// Create a new instance of the memory client out of a provided storage.
-    pub fn new(storage: MemoryStorage, interpreter_params: InterpreterParams) -> Self {
-        Self {
-            transactor: Transactor::new(storage, interpreter_params),
-        }
+    ///
+    /// The transactions built and checked by the client, see
+    /// [`MemoryClient::execute_block`], are checked against the `consensus_params`.
+    pub fn new(
+        storage: MemoryStorage,
+        interpreter_params: InterpreterParams,
+        consensus_params: ConsensusParameters,
+    ) -> Self {
+        Self::from_txtor(
+            Transactor::new(storage, interpreter_params),
+            consensus_params,
+        )
+    }
+
+    /// Create a new instance of the memory client executing the transactions in a
+    /// memory taken from the `pool`.
+    #[cfg(feature = "std")]
+    pub fn with_memory_pool(
+        pool: &MemoryPool,
+        storage: MemoryStorage,
+        interpreter_params: InterpreterParams,
+        consensus_params: ConsensusParameters,
+    ) -> Self {
+        Self::from_txtor(
+            Transactor::with_memory_pool(pool, storage, interpreter_params),
+            consensus_params,
+        )
     }
 }
 
 impl<Ecal: EcalHandler> MemoryClient<Ecal> {
-    /// Create a new instance of the memory client out of a provided storage.
-    pub fn from_txtor(transactor: Transactor<MemoryStorage, Script, Ecal>) -> Self {
-        Self { transactor }
+    /// Create a new instance of the memory client out of a provided transactor.
+    ///
+    /// The transactions built and checked by the client, see
+    /// [`Self::execute_block`], are checked against the `consensus_params`.
+    pub fn from_txtor(
+        transactor: Transactor<MemoryStorage, Script, Ecal>,
+        consensus_params: ConsensusParameters,
+    ) -> Self {
+        Self {
+            transactor,
+            consensus_params,
+        }
     }
 
     /// If a transaction was executed and produced a VM panic, returns the
@@ -77,9 +216,83 @@ impl<Ecal: EcalHandler> MemoryClient<Ecal> {
         self.transactor.state_transition()
     }
 
-    /// Deploys a `Create` transaction.
-    pub fn deploy(&mut self, tx: Checked<Create>) -> Option<Create> {
-        self.transactor.deploy(tx).ok()
+    /// Sets a cache of the contract codes shared by the executed transactions, see
+    /// [`crate::interpreter::Interpreter::with_code_cache`].
+    pub fn with_code_cache(&mut self, cache: Arc<dyn ContractCodeCache>) -> &mut Self {
+        self.transactor.with_code_cache(cache);
+        self
+    }
+
+    /// Deploys a `Create` transaction, writing the code, the initial storage slots
+    /// and the roots of its contract into the storage, and returns the id of the
+    /// contract.
+    ///
+    /// The deployment is committed, so it is kept when the next transactions revert.
+    /// Like the consensus rules, it fails if a contract with the same id already
+    /// exists.
+    ///
+    /// Unlike the previous versions, which returned the deployed `Create` transaction
+    /// as an `Option` and left the deployment uncommitted, the error preventing the
+    /// deployment is returned and the storage is reverted on failure.
+    pub fn deploy(
+        &mut self,
+        tx: Checked<Create>,
+    ) -> Result<ContractId, InterpreterError<Infallible>> {
+        let contract_id = self.transactor.deploy(tx).and_then(|create| {
+            create
+                .contract_id()
+                .ok_or(ValidityError::TransactionCreateBytecodeWitnessIndex)
+                .map_err(|e| InterpreterError::CheckError(e.into()))
+        });
+
+        match contract_id {
+            Ok(_) => self.as_mut().commit(),
+            Err(_) => self.as_mut().revert(),
+        }
+
+        contract_id
+    }
+
+    /// Builds a `Create` transaction deploying `bytecode` with the given `salt` and
+    /// initial `storage_slots`, checks it against the consensus parameters of the
+    /// client and deploys it, see [`Self::deploy`].
+    ///
+    /// The transaction is funded by an empty coin owned by an always-true predicate
+    /// and has a zero max fee, so the gas price of the client must be zero.
+    pub fn deploy_contract(
+        &mut self,
+        bytecode: Vec<u8>,
+        salt: Salt,
+        storage_slots: Vec<StorageSlot>,
+    ) -> Result<ContractId, InterpreterError<Infallible>> {
+        let predicate: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        let fee_input = Input::coin_predicate(
+            Default::default(),
+            owner,
+            0,
+            *self.consensus_params.base_asset_id(),
+            Default::default(),
+            0,
+            predicate,
+            Vec::new(),
+        );
+
+        let mut create = TransactionBuilder::create(bytecode.into(), salt, storage_slots)
+            .with_params(self.consensus_params.clone())
+            .max_fee_limit(0)
+            .add_contract_created_output()
+            .add_input(fee_input)
+            .finalize();
+        create
+            .estimate_predicates(&CheckPredicateParams::from(&self.consensus_params))
+            .map_err(InterpreterError::CheckError)?;
+
+        let block_height = self.as_ref().block_height()?;
+        let checked = create
+            .into_checked(block_height, &self.consensus_params)
+            .map_err(InterpreterError::CheckError)?;
+        self.deploy(checked)
     }
 
     /// Execute a transaction.
@@ -107,6 +320,153 @@ impl<Ecal: EcalHandler> MemoryClient<Ecal> {
         self.transactor.receipts().unwrap_or_default()
     }
 
+    /// Execute a transaction without modifying the storage.
+    ///
+    /// Returns the receipts and the storage writes the transaction would have done.
+    pub fn dry_run(
+        &mut self,
+        tx: Checked<Script>,
+    ) -> Result<DryRun<Script>, InterpreterError<Infallible>> {
+        self.transactor.dry_run(tx)
+    }
+
+    /// Execute the transactions of a block at `block_height` one after another,
+    /// and return their results in order.
+    ///
+    /// Every transaction is checked against the consensus parameters of the client
+    /// and executed at `gas_price`. The changes of a transaction are committed before
+    /// the next one is executed, so it sees the contracts deployed and the storage
+    /// written by the previous ones. The transactions failing the checks are skipped
+    /// and reported as [`TransactionExecutionStatus::Rejected`].
+    ///
+    /// The scripts are executed and the contracts of the `Create` transactions are
+    /// deployed. If `apply_mint` is set, the `Mint` transactions credit the minted
+    /// amount to their contract; otherwise they are reported as
+    /// [`TransactionExecutionStatus::NotExecuted`], like the other transaction types.
+    pub fn execute_block(
+        &mut self,
+        txs: Vec<Transaction>,
+        block_height: BlockHeight,
+        gas_price: Word,
+        apply_mint: bool,
+    ) -> Vec<TransactionExecutionResult> {
+        self.as_mut().block_height = block_height;
+        let previous_gas_price = self.transactor.set_gas_price(gas_price);
+
+        let results = txs
+            .into_iter()
+            .map(|tx| self.execute_block_tx(tx, block_height, apply_mint))
+            .collect();
+
+        self.transactor.set_gas_price(previous_gas_price);
+        results
+    }
+
+    fn execute_block_tx(
+        &mut self,
+        tx: Transaction,
+        block_height: BlockHeight,
+        apply_mint: bool,
+    ) -> TransactionExecutionResult {
+        let id = tx.id(&self.consensus_params.chain_id());
+        let checked = match tx.into_checked(block_height, &self.consensus_params) {
+            Ok(checked) => checked,
+            Err(e) => {
+                return TransactionExecutionResult::rejected(
+                    id,
+                    InterpreterError::CheckError(e),
+                )
+            }
+        };
+
+        let checked = CheckedTransaction::from(checked);
+        let predicate_gas_used = checked.predicate_gas_used().unwrap_or_default();
+        let mut result = match checked {
+            CheckedTransaction::Script(tx) => self.execute_block_script(id, tx),
+            CheckedTransaction::Create(tx) => match self.transactor.deploy(tx) {
+                Ok(_) => TransactionExecutionResult::new(
+                    id,
+                    TransactionExecutionStatus::Success,
+                ),
+                Err(e) => TransactionExecutionResult::rejected(id, e),
+            },
+            CheckedTransaction::Mint(tx) if apply_mint => {
+                match self.apply_mint(tx.transaction()) {
+                    Ok(()) => TransactionExecutionResult::new(
+                        id,
+                        TransactionExecutionStatus::Success,
+                    ),
+                    Err(e) => TransactionExecutionResult::rejected(id, e),
+                }
+            }
+            _ => TransactionExecutionResult::new(
+                id,
+                TransactionExecutionStatus::NotExecuted,
+            ),
+        };
+
+        result.predicate_gas_used = predicate_gas_used;
+        match result.status {
+            TransactionExecutionStatus::Success => self.as_mut().commit(),
+            _ => self.as_mut().revert(),
+        }
+
+        result
+    }
+
+    fn execute_block_script(
+        &mut self,
+        id: TxId,
+        tx: Checked<Script>,
+    ) -> TransactionExecutionResult {
+        let state = match self.transactor.transact_many([tx]).pop() {
+            Some(Ok(state)) => state,
+            Some(Err(e)) => return TransactionExecutionResult::rejected(id, e),
+            None => {
+                return TransactionExecutionResult::new(
+                    id,
+                    TransactionExecutionStatus::Rejected,
+                )
+            }
+        };
+
+        let mut result =
+            TransactionExecutionResult::new(id, TransactionExecutionStatus::Success);
+        if let Some(Receipt::ScriptResult {
+            result: script_result,
+            gas_used,
+        }) = state.receipts().last()
+        {
+            result.gas_used = *gas_used;
+            result.status = match script_result {
+                ScriptExecutionResult::Success => TransactionExecutionStatus::Success,
+                ScriptExecutionResult::Revert => TransactionExecutionStatus::Revert,
+                _ => TransactionExecutionStatus::Panic,
+            };
+        }
+        if state.should_revert() && result.status == TransactionExecutionStatus::Success {
+            result.status = TransactionExecutionStatus::Revert;
+        }
+        result.receipts = state.receipts().to_vec();
+        result
+    }
+
+    fn apply_mint(&mut self, tx: &Mint) -> Result<(), InterpreterError<Infallible>> {
+        let contract = &tx.input_contract().contract_id;
+        let storage = self.as_mut();
+        let balance = storage
+            .contract_asset_id_balance(contract, tx.mint_asset_id())?
+            .unwrap_or_default()
+            .checked_add(*tx.mint_amount())
+            .ok_or(InterpreterError::Panic(PanicReason::BalanceOverflow))?;
+        storage.contract_asset_id_balance_insert(
+            contract,
+            tx.mint_asset_id(),
+            balance,
+        )?;
+        Ok(())
+    }
+
     /// Persist the changes caused by [`Self::transact`].
     pub fn persist(&mut self) {
         self.as_mut().persist();
@@ -125,7 +485,11 @@ impl<Ecal: EcalHandler> MemoryClient<Ecal> {
 
 impl<Ecal: EcalHandler + Default> From<MemoryStorage> for MemoryClient<Ecal> {
     fn from(s: MemoryStorage) -> Self {
-        Self::new(s, InterpreterParams::default())
+        Self::new(
+            s,
+            InterpreterParams::default(),
+            ConsensusParameters::default(),
+        )
     }
 }
 
@@ -0,0 +1,210 @@
+//! Observation of the executed instructions by the embedders of the VM.
+//!
+//! An [`InstructionObserver`] is a generic parameter of the
+//! [`Interpreter`](crate::interpreter::Interpreter), called around every executed
+//! instruction of the scripts, the called contracts and the predicates. The default
+//! [`NoopObserver`] is disabled, so the calls are removed at compile time.
+
+use crate::{
+    consts::VM_REGISTER_COUNT,
+    context::Context,
+    state::ExecuteState,
+};
+
+use core::fmt;
+use fuel_asm::{
+    PanicReason,
+    RawInstruction,
+    RegId,
+};
+use fuel_types::{
+    ContractId,
+    Word,
+};
+
+/// Read-only view of the VM state around the execution of an instruction.
+#[derive(Clone, Copy)]
+pub struct VmStateView<'a> {
+    instruction: RawInstruction,
+    registers: &'a [Word; VM_REGISTER_COUNT],
+    memory: &'a [u8],
+    context: &'a Context,
+    contract: Option<ContractId>,
+}
+
+impl<'a> VmStateView<'a> {
+    /// Maximum length of the memory window returned by [`Self::memory`].
+    pub const MAX_MEMORY_WINDOW: usize = 4096;
+
+    pub(crate) const fn new(
+        instruction: RawInstruction,
+        registers: &'a [Word; VM_REGISTER_COUNT],
+        memory: &'a [u8],
+        context: &'a Context,
+        contract: Option<ContractId>,
+    ) -> Self {
+        Self {
+            instruction,
+            registers,
+            memory,
+            context,
+            contract,
+        }
+    }
+
+    /// The executed instruction.
+    pub const fn instruction(&self) -> RawInstruction {
+        self.instruction
+    }
+
+    /// The registers.
+    pub const fn registers(&self) -> &'a [Word; VM_REGISTER_COUNT] {
+        self.registers
+    }
+
+    /// The program counter.
+    pub const fn pc(&self) -> Word {
+        self.registers[RegId::PC.to_u8() as usize]
+    }
+
+    /// The context of the execution: a predicate, the script or a contract call.
+    pub const fn context(&self) -> &'a Context {
+        self.context
+    }
+
+    /// The contract being executed, or `None` for the script and the predicates.
+    pub const fn contract(&self) -> Option<&ContractId> {
+        self.contract.as_ref()
+    }
+
+    /// The `len` bytes of the memory starting at `addr`, or `None` if the range is
+    /// outside of the memory or longer than [`Self::MAX_MEMORY_WINDOW`].
+    pub fn memory(&self, addr: usize, len: usize) -> Option<&'a [u8]> {
+        if len > Self::MAX_MEMORY_WINDOW {
+            return None
+        }
+
+        self.memory.get(addr..addr.checked_add(len)?)
+    }
+}
+
+impl fmt::Debug for VmStateView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VmStateView")
+            .field("instruction", &self.instruction)
+            .field("registers", &self.registers)
+            .field("context", &self.context)
+            .field("contract", &self.contract)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Observes the VM state before and after the execution of every instruction.
+pub trait InstructionObserver {
+    /// Whether the observer is called. The interpreter doesn't build the views of
+    /// the VM state if it is `false`.
+    const ENABLED: bool = true;
+
+    /// Called before the instruction is executed.
+    fn will_execute(&mut self, vm: &VmStateView);
+
+    /// Called after the instruction is executed, with its result. Not called if the
+    /// instruction halts the VM with an error that isn't a panic, like a storage
+    /// error.
+    fn did_execute(
+        &mut self,
+        vm: &VmStateView,
+        result: &Result<ExecuteState, PanicReason>,
+    );
+}
+
+impl<O> InstructionObserver for &mut O
+where
+    O: InstructionObserver,
+{
+    const ENABLED: bool = O::ENABLED;
+
+    fn will_execute(&mut self, vm: &VmStateView) {
+        (**self).will_execute(vm)
+    }
+
+    fn did_execute(
+        &mut self,
+        vm: &VmStateView,
+        result: &Result<ExecuteState, PanicReason>,
+    ) {
+        (**self).did_execute(vm, result)
+    }
+}
+
+/// Observer doing nothing, used by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoopObserver;
+
+impl InstructionObserver for NoopObserver {
+    const ENABLED: bool = false;
+
+    fn will_execute(&mut self, _: &VmStateView) {}
+
+    fn did_execute(&mut self, _: &VmStateView, _: &Result<ExecuteState, PanicReason>) {}
+}
+
+/// Counts the executed instructions, in total and per context.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InstructionCounter {
+    predicates: u64,
+    script: u64,
+    calls: u64,
+    panics: u64,
+}
+
+impl InstructionCounter {
+    /// Number of executed instructions.
+    pub const fn total(&self) -> u64 {
+        self.predicates
+            .saturating_add(self.script)
+            .saturating_add(self.calls)
+    }
+
+    /// Number of instructions executed by the predicates.
+    pub const fn predicates(&self) -> u64 {
+        self.predicates
+    }
+
+    /// Number of instructions executed by the script.
+    pub const fn script(&self) -> u64 {
+        self.script
+    }
+
+    /// Number of instructions executed by the called contracts.
+    pub const fn calls(&self) -> u64 {
+        self.calls
+    }
+
+    /// Number of executed instructions that panicked.
+    pub const fn panics(&self) -> u64 {
+        self.panics
+    }
+}
+
+impl InstructionObserver for InstructionCounter {
+    fn will_execute(&mut self, vm: &VmStateView) {
+        let counter = match vm.context() {
+            Context::PredicateEstimation { .. }
+            | Context::PredicateVerification { .. } => &mut self.predicates,
+            Context::Call { .. } => &mut self.calls,
+            Context::Script { .. } | Context::NotInitialized => &mut self.script,
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    fn did_execute(
+        &mut self,
+        _: &VmStateView,
+        result: &Result<ExecuteState, PanicReason>,
+    ) {
+        if result.is_err() {
+            self.panics = self.panics.saturating_add(1);
+        }
+    }
+}
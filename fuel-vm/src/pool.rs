@@ -0,0 +1,56 @@
+//! Pool of VM memories reused across executions.
+
+use crate::interpreter::MemoryInstance;
+
+use std::sync::{
+    Arc,
+    Mutex,
+    MutexGuard,
+};
+
+/// Pool of VM memories.
+///
+/// Allocating and zeroing the whole VM memory dominates the execution of small
+/// transactions. The memories taken from the pool are returned to it when dropped,
+/// zeroing only the part written by the executions.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryPool {
+    free: Arc<Mutex<Vec<MemoryInstance>>>,
+}
+
+impl MemoryPool {
+    /// Create a new empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take a zeroed memory from the pool, allocating a new one if the pool is
+    /// empty. The memory is returned to the pool when dropped.
+    pub fn get(&self) -> MemoryInstance {
+        let mut memory = self.lock().pop().unwrap_or_default();
+        memory.set_pool(self.clone());
+        memory
+    }
+
+    /// Number of memories available in the pool.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if no memory is available in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Zero the `memory` and put its buffer back into the pool.
+    pub(crate) fn recycle(&self, memory: &mut MemoryInstance) {
+        memory.reset();
+        if let Some(buffer) = memory.take_buffer() {
+            self.lock().push(MemoryInstance::from_clean_buffer(buffer));
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<MemoryInstance>> {
+        self.free.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
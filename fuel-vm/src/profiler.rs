@@ -14,6 +14,7 @@ use hashbrown::HashMap;
 
 use dyn_clone::DynClone;
 
+use fuel_asm::Opcode;
 use fuel_types::ContractId;
 
 use crate::prelude::*;
@@ -176,6 +177,104 @@ impl ProfileReceiver for StderrReceiver {
     }
 }
 
+/// Receives the program counter of every instruction retired by the interpreter
+#[cfg(feature = "profile-coverage")]
+pub trait CoverageReceiver: DynClone {
+    /// Called after the instruction at the offset `pc` of the code of the `contract`
+    /// (or of the script or predicate if `None`) has been executed successfully
+    fn hit(&mut self, contract: Option<ContractId>, pc: Word);
+}
+
+#[cfg(feature = "profile-coverage")]
+dyn_clone::clone_trait_object!(CoverageReceiver);
+
+#[cfg(all(feature = "profile-coverage", feature = "std"))]
+type CoverageHits = HashMap<(Option<ContractId>, Word), u64>;
+
+/// Counts the hits per contract and instruction offset. The clones share the
+/// collected hits, so a clone can be installed into the interpreter while the
+/// original is used to read the results.
+#[cfg(all(feature = "profile-coverage", feature = "std"))]
+#[derive(Debug, Clone, Default)]
+pub struct CoverageCollector {
+    hits: std::sync::Arc<std::sync::Mutex<CoverageHits>>,
+}
+
+#[cfg(all(feature = "profile-coverage", feature = "std"))]
+impl CoverageCollector {
+    /// Number of times the instruction at the offset `pc` of the `contract` was
+    /// executed
+    pub fn hits(&self, contract: Option<ContractId>, pc: Word) -> u64 {
+        self.lock().get(&(contract, pc)).copied().unwrap_or(0)
+    }
+
+    /// The executed instruction offsets of the `contract`, sorted
+    pub fn hit_pcs(&self, contract: Option<ContractId>) -> Vec<Word> {
+        let mut pcs: Vec<_> = self
+            .lock()
+            .keys()
+            .filter(|(c, _)| *c == contract)
+            .map(|(_, pc)| *pc)
+            .collect();
+        pcs.sort_unstable();
+        pcs
+    }
+
+    /// Exports the hits in the LCOV format, using the contract id (or `script`)
+    /// as the source file and the instruction offset as the line number.
+    pub fn to_lcov_like_report(&self) -> String {
+        use core::fmt::Write;
+
+        let mut hits: Vec<_> = self
+            .lock()
+            .iter()
+            .map(|(&(contract, pc), &count)| (contract, pc, count))
+            .collect();
+        hits.sort_unstable();
+
+        let mut report = String::new();
+        for (i, (contract, pc, count)) in hits.iter().enumerate() {
+            if i == 0 || hits[i - 1].0 != *contract {
+                match contract {
+                    Some(contract) => writeln!(report, "SF:{contract}"),
+                    None => writeln!(report, "SF:script"),
+                }
+                .expect("Writing to a string can't fail");
+            }
+            writeln!(report, "DA:{pc},{count}").expect("Writing to a string can't fail");
+            if hits.get(i + 1).map(|next| next.0) != Some(*contract) {
+                report.push_str("end_of_record\n");
+            }
+        }
+        report
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, CoverageHits> {
+        self.hits.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(all(feature = "profile-coverage", feature = "std"))]
+impl CoverageReceiver for CoverageCollector {
+    fn hit(&mut self, contract: Option<ContractId>, pc: Word) {
+        *self.lock().entry((contract, pc)).or_default() += 1;
+    }
+}
+
+/// Contract code copied to the stack by `LDC`, used to attribute the instructions
+/// executed from it to the loaded contract.
+#[cfg(feature = "profile-coverage")]
+#[derive(Debug, Clone)]
+struct LoadedCode {
+    /// Frame pointer of the call frame that loaded the code
+    fp: Word,
+    /// Memory occupied by the code
+    range: core::ops::Range<Word>,
+    contract: ContractId,
+    /// Offset in the contract code of the start of the loaded code
+    offset: Word,
+}
+
 /// Profiler
 #[derive(Default, Clone)]
 pub struct Profiler {
@@ -183,6 +282,18 @@ pub struct Profiler {
     receiver: Option<Box<dyn ProfileReceiver + Send + Sync>>,
     /// Collected profiling data
     data: ProfilingData,
+    /// The global gas remaining before the gas charge that ran out of gas, and the
+    /// gas requested by the charge
+    out_of_gas: Option<(Word, Word)>,
+    /// Receiver of the program counters of the executed instructions
+    #[cfg(feature = "profile-coverage")]
+    coverage_receiver: Option<Box<dyn CoverageReceiver + Send + Sync>>,
+    /// Code loaded by `LDC`, tracked only when the coverage receiver is set
+    #[cfg(feature = "profile-coverage")]
+    loaded_code: Vec<LoadedCode>,
+    /// Whether the gas is used by a predicate rather than by the script
+    #[cfg(feature = "profile-gas")]
+    predicate: bool,
 }
 
 impl Profiler {
@@ -210,6 +321,23 @@ impl Profiler {
     pub fn data_mut(&mut self) -> &mut ProfilingData {
         &mut self.data
     }
+
+    /// Records the gas charge of `requested` gas that ran out of gas, with
+    /// `remaining` global gas before the charge
+    pub(crate) fn on_out_of_gas(&mut self, remaining: Word, requested: Word) {
+        self.out_of_gas = Some((remaining, requested));
+    }
+
+    /// The global gas remaining before the gas charge that ran out of gas, and the
+    /// gas requested by the charge
+    pub(crate) fn out_of_gas(&self) -> Option<(Word, Word)> {
+        self.out_of_gas
+    }
+
+    /// Forgets the gas charge that ran out of gas
+    pub(crate) fn clear_out_of_gas(&mut self) {
+        self.out_of_gas = None;
+    }
 }
 
 impl Profiler {
@@ -220,8 +348,99 @@ impl Profiler {
 
     /// Add gas to the current coverage location.
     pub fn add_gas(&mut self, location: InstructionLocation, gas_use: u64) {
+        #[cfg(feature = "profile-gas")]
+        if self.predicate {
+            self.data_mut().gas_mut().add_predicate(location, gas_use);
+            return
+        }
         self.data_mut().gas_mut().add(location, gas_use);
     }
+
+    /// Record an execution of the `opcode` at the location.
+    #[cfg(feature = "profile-gas")]
+    pub fn add_execution(&mut self, location: InstructionLocation, opcode: Opcode) {
+        if self.predicate {
+            self.data_mut()
+                .gas_mut()
+                .add_predicate_execution(location, opcode);
+        } else {
+            self.data_mut().gas_mut().add_execution(location, opcode);
+        }
+    }
+
+    /// Attributes the gas used by the following executions to a predicate if
+    /// `predicate` is `true`, or to the script and the contracts otherwise
+    #[cfg(feature = "profile-gas")]
+    pub(crate) fn set_predicate(&mut self, predicate: bool) {
+        self.predicate = predicate;
+    }
+}
+
+#[cfg(feature = "profile-coverage")]
+impl Profiler {
+    /// Sets the receiver of the program counters of the executed instructions
+    pub fn set_coverage_receiver(
+        &mut self,
+        receiver: Box<dyn CoverageReceiver + Send + Sync>,
+    ) {
+        self.coverage_receiver = Some(receiver);
+    }
+
+    /// Whether the coverage receiver is set
+    pub fn has_coverage_receiver(&self) -> bool {
+        self.coverage_receiver.is_some()
+    }
+
+    /// Reports the executed instruction to the coverage receiver
+    pub(crate) fn coverage_hit(&mut self, contract: Option<ContractId>, pc: Word) {
+        if let Some(receiver) = &mut self.coverage_receiver {
+            receiver.hit(contract, pc);
+        }
+    }
+
+    /// Records the code of the `contract` starting at its `offset` loaded into the
+    /// memory `range` by the call frame at `fp`
+    pub(crate) fn on_load_contract_code(
+        &mut self,
+        fp: Word,
+        range: core::ops::Range<Word>,
+        contract: ContractId,
+        offset: Word,
+    ) {
+        if self.coverage_receiver.is_some() {
+            self.loaded_code.push(LoadedCode {
+                fp,
+                range,
+                contract,
+                offset,
+            });
+        }
+    }
+
+    /// Forgets the code loaded by the frames at or above `fp`, which is about to be
+    /// reused by a new call frame
+    pub(crate) fn on_call_frame(&mut self, fp: Word) {
+        self.loaded_code.retain(|code| code.fp < fp);
+    }
+
+    /// Forgets all the loaded code
+    pub(crate) fn clear_loaded_code(&mut self) {
+        self.loaded_code.clear();
+    }
+
+    /// The contract and the offset in its code of the instruction at `pc` if it
+    /// was loaded by the call frame at `fp`
+    pub(crate) fn loaded_code_location(
+        &self,
+        fp: Word,
+        pc: Word,
+    ) -> Option<(ContractId, Word)> {
+        self.loaded_code
+            .iter()
+            .rev()
+            .find(|code| code.fp == fp && code.range.contains(&pc))
+            .map(|code| (code.contract, code.offset + pc - code.range.start))
+    }
 }
 
 impl fmt::Debug for Profiler {
@@ -312,6 +531,12 @@ impl fmt::Display for CoverageProfilingData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GasProfilingData {
     gas_use: PerLocation<u64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    executions: PerLocation<(Opcode, u64)>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    predicate_gas_use: PerLocation<u64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    predicate_executions: PerLocation<(Opcode, u64)>,
 }
 
 impl<'a> GasProfilingData {
@@ -340,6 +565,211 @@ impl<'a> GasProfilingData {
     pub fn values(&'a self) -> PerLocationValues<'a, u64> {
         PerLocationValues(self.gas_use.values())
     }
+
+    /// Get the opcode executed at location and the number of its executions
+    pub fn get_executions(
+        &self,
+        location: &InstructionLocation,
+    ) -> Option<(Opcode, u64)> {
+        self.executions.get(location).copied()
+    }
+
+    /// Record an execution of the `opcode` at location
+    pub fn add_execution(&mut self, location: InstructionLocation, opcode: Opcode) {
+        add_execution(&mut self.executions, location, opcode);
+    }
+
+    /// Get total gas used by predicates at location
+    pub fn get_predicate(&self, location: &InstructionLocation) -> u64 {
+        self.predicate_gas_use.get(location).copied().unwrap_or(0)
+    }
+
+    /// Increase gas used by predicates at location
+    pub fn add_predicate(&mut self, location: InstructionLocation, amount: u64) {
+        *self.predicate_gas_use.entry(location).or_insert(0) += amount;
+    }
+
+    /// Record an execution of the `opcode` at location by a predicate
+    pub fn add_predicate_execution(
+        &mut self,
+        location: InstructionLocation,
+        opcode: Opcode,
+    ) {
+        add_execution(&mut self.predicate_executions, location, opcode);
+    }
+
+    /// Aggregate the gas used at the executed locations per opcode and context
+    pub fn profile(&self) -> GasProfile {
+        let mut usage = HashMap::<_, GasUsage>::new();
+        for (location, (opcode, count)) in self.executions.iter() {
+            let context = match location.context() {
+                Some(contract) => GasContext::Contract(contract),
+                None => GasContext::Script,
+            };
+            let entry = usage.entry((context, *opcode)).or_default();
+            entry.count += count;
+            entry.gas += self.get(location);
+        }
+        for (location, (opcode, count)) in self.predicate_executions.iter() {
+            let entry = usage.entry((GasContext::Predicate, *opcode)).or_default();
+            entry.count += count;
+            entry.gas += self.get_predicate(location);
+        }
+
+        let mut entries: Vec<_> = usage
+            .into_iter()
+            .map(|((context, opcode), usage)| GasProfileEntry {
+                context,
+                opcode,
+                usage,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            b.usage
+                .gas
+                .cmp(&a.usage.gas)
+                .then_with(|| a.context.cmp(&b.context))
+                .then_with(|| (a.opcode as u8).cmp(&(b.opcode as u8)))
+        });
+
+        GasProfile { entries }
+    }
+}
+
+fn add_execution(
+    executions: &mut PerLocation<(Opcode, u64)>,
+    location: InstructionLocation,
+    opcode: Opcode,
+) {
+    let (executed, count) = executions.entry(location).or_insert((opcode, 0));
+    *executed = opcode;
+    *count += 1;
+}
+
+/// Number of executions of an instruction and the gas they used, including the
+/// dependent costs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasUsage {
+    /// Number of executions
+    pub count: u64,
+    /// Total gas used
+    pub gas: u64,
+}
+
+impl GasUsage {
+    fn add(&mut self, other: &Self) {
+        self.count += other.count;
+        self.gas += other.gas;
+    }
+}
+
+/// Code executing the instructions of a [`GasProfileEntry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GasContext {
+    /// The script of the transaction
+    Script,
+    /// A predicate of the transaction
+    Predicate,
+    /// A contract called by the script
+    Contract(ContractId),
+}
+
+impl fmt::Display for GasContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Script => write!(f, "script"),
+            Self::Predicate => write!(f, "predicate"),
+            Self::Contract(contract_id) => write!(f, "{contract_id:#x}"),
+        }
+    }
+}
+
+/// Gas used by an opcode in a script, a predicate or a contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasProfileEntry {
+    /// Code executing the opcode
+    pub context: GasContext,
+    /// Executed opcode
+    pub opcode: Opcode,
+    /// Gas used by the opcode in the context
+    pub usage: GasUsage,
+}
+
+/// Gas used per opcode and context, sorted by the total gas
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasProfile {
+    entries: Vec<GasProfileEntry>,
+}
+
+impl GasProfile {
+    /// Gas used per opcode and context, sorted by the total gas
+    pub fn entries(&self) -> &[GasProfileEntry] {
+        &self.entries
+    }
+
+    /// Gas used per opcode in all the contexts, sorted by the total gas
+    pub fn by_opcode(&self) -> Vec<(Opcode, GasUsage)> {
+        let mut usage = Vec::<(Opcode, GasUsage)>::new();
+        for entry in &self.entries {
+            match usage.iter_mut().find(|(opcode, _)| *opcode == entry.opcode) {
+                Some((_, total)) => total.add(&entry.usage),
+                None => usage.push((entry.opcode, entry.usage)),
+            }
+        }
+        usage.sort_by(|(a_op, a), (b_op, b)| {
+            b.gas
+                .cmp(&a.gas)
+                .then_with(|| (*a_op as u8).cmp(&(*b_op as u8)))
+        });
+        usage
+    }
+
+    /// Gas used per script, predicate and contract, sorted by the total gas
+    pub fn by_context(&self) -> Vec<(GasContext, GasUsage)> {
+        let mut usage = Vec::<(GasContext, GasUsage)>::new();
+        for entry in &self.entries {
+            match usage
+                .iter_mut()
+                .find(|(context, _)| *context == entry.context)
+            {
+                Some((_, total)) => total.add(&entry.usage),
+                None => usage.push((entry.context, entry.usage)),
+            }
+        }
+        usage.sort_by(|(a_context, a), (b_context, b)| {
+            b.gas.cmp(&a.gas).then_with(|| a_context.cmp(b_context))
+        });
+        usage
+    }
+
+    /// Total gas used by the executed instructions
+    pub fn total_gas(&self) -> u64 {
+        self.entries.iter().map(|entry| entry.usage.gas).sum()
+    }
+}
+
+impl fmt::Display for GasProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<66} {:<6} {:>10} {:>12}",
+            "CONTEXT", "OPCODE", "COUNT", "GAS"
+        )?;
+        for entry in &self.entries {
+            let context = entry.context.to_string();
+            let opcode = format!("{:?}", entry.opcode);
+            writeln!(
+                f,
+                "{:<66} {:<6} {:>10} {:>12}",
+                context, opcode, entry.usage.count, entry.usage.gas
+            )?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for GasProfilingData {
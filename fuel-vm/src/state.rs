@@ -2,9 +2,19 @@
 
 use alloc::vec::Vec;
 
-use fuel_tx::Receipt;
+use crate::storage::StorageChanges;
+
+use fuel_asm::PanicReason;
+use fuel_tx::{
+    Input,
+    Receipt,
+    ScriptExecutionResult,
+};
 use fuel_types::{
+    Address,
     Bytes32,
+    MessageId,
+    Nonce,
     Word,
 };
 
@@ -15,6 +25,7 @@ mod debugger;
 pub use debug::{
     Breakpoint,
     DebugEval,
+    WatchKind,
 };
 
 pub use debugger::Debugger;
@@ -129,9 +140,18 @@ impl<Tx> StateTransition<Tx> {
 
     /// Flag whether the client should revert after execution.
     pub fn should_revert(&self) -> bool {
-        self.receipts
-            .iter()
-            .any(|r| matches!(r, Receipt::Revert { .. } | Receipt::Panic { .. }))
+        should_revert(&self.receipts)
+    }
+
+    /// The L2→L1 messages sent by the transaction, or none if it was reverted.
+    pub fn outgoing_messages(&self) -> impl Iterator<Item = MessageOutSummary<'_>> {
+        outgoing_messages(&self.receipts)
+    }
+
+    /// The revert or panic that ended the script, with its revert data. `None` if
+    /// the script didn't fail.
+    pub fn failure(&self) -> Option<ScriptFailure> {
+        ScriptFailure::from_receipts(&self.receipts)
     }
 
     /// Transaction receipts representing the state transition.
@@ -151,6 +171,44 @@ impl<Tx> From<StateTransition<Tx>> for ProgramState {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Representation of the result of a transaction executed without modifying the
+/// storage.
+pub struct DryRun<Tx> {
+    transition: StateTransition<Tx>,
+    changes: StorageChanges,
+}
+
+impl<Tx> DryRun<Tx> {
+    /// Create a new dry run representation.
+    pub const fn new(transition: StateTransition<Tx>, changes: StorageChanges) -> Self {
+        Self {
+            transition,
+            changes,
+        }
+    }
+
+    /// Result of the transaction execution.
+    pub const fn transition(&self) -> &StateTransition<Tx> {
+        &self.transition
+    }
+
+    /// Storage writes the execution would have done.
+    pub const fn changes(&self) -> &StorageChanges {
+        &self.changes
+    }
+
+    /// Transaction receipts representing the state transition.
+    pub fn receipts(&self) -> &[Receipt] {
+        self.transition.receipts()
+    }
+
+    /// Convert this instance into its internal attributes.
+    pub fn into_inner(self) -> (StateTransition<Tx>, StorageChanges) {
+        (self.transition, self.changes)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Zero-copy Representation of the result of a transaction execution bound to
 /// the lifetime of the VM.
@@ -187,9 +245,18 @@ impl<'a, Tx> StateTransitionRef<'a, Tx> {
 
     /// Flag whether the client should revert after execution.
     pub fn should_revert(&self) -> bool {
-        self.receipts
-            .iter()
-            .any(|r| matches!(r, Receipt::Revert { .. } | Receipt::Panic { .. }))
+        should_revert(self.receipts)
+    }
+
+    /// The L2→L1 messages sent by the transaction, or none if it was reverted.
+    pub fn outgoing_messages(&self) -> impl Iterator<Item = MessageOutSummary<'_>> {
+        outgoing_messages(self.receipts)
+    }
+
+    /// The revert or panic that ended the script, with its revert data. `None` if
+    /// the script didn't fail.
+    pub fn failure(&self) -> Option<ScriptFailure> {
+        ScriptFailure::from_receipts(self.receipts)
     }
 }
 
@@ -218,3 +285,151 @@ impl<'a, Tx: Clone> From<StateTransitionRef<'a, Tx>> for ProgramState {
         t.state
     }
 }
+
+/// L2→L1 message sent by the `SMO` instruction, decoded from its
+/// [`Receipt::MessageOut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageOutSummary<'a> {
+    /// The contract or the script sending the message.
+    pub sender: Address,
+    /// The recipient of the message on L1.
+    pub recipient: Address,
+    /// The amount of the base asset sent with the message.
+    pub amount: Word,
+    /// The nonce of the message.
+    pub nonce: Nonce,
+    /// The hash of the data of the message.
+    pub digest: Bytes32,
+    /// The data of the message.
+    pub data: &'a [u8],
+}
+
+impl<'a> MessageOutSummary<'a> {
+    /// Decodes the message of a [`Receipt::MessageOut`], or `None` for the other
+    /// receipts.
+    pub fn from_receipt(receipt: &'a Receipt) -> Option<Self> {
+        match receipt {
+            Receipt::MessageOut {
+                sender,
+                recipient,
+                amount,
+                nonce,
+                digest,
+                data,
+                ..
+            } => Some(Self {
+                sender: *sender,
+                recipient: *recipient,
+                amount: *amount,
+                nonce: *nonce,
+                digest: *digest,
+                data: data.as_deref().unwrap_or_default(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The id of the message.
+    pub fn message_id(&self) -> MessageId {
+        Input::compute_message_id(
+            &self.sender,
+            &self.recipient,
+            &self.nonce,
+            self.amount,
+            self.data,
+        )
+    }
+}
+
+/// Revert or panic that ended a script, decoded from its receipts.
+///
+/// A program attaches a payload to the failure by logging it with `LOGD` right
+/// before the `RVRT`, or the instruction that panicked: the data of that
+/// [`Receipt::LogData`], emitted by the failing context, is the revert data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScriptFailure {
+    /// The script, or a contract it called, executed `RVRT`.
+    Revert {
+        /// The code passed to `RVRT`.
+        code: Word,
+        /// The data logged right before the revert.
+        revert_data: Option<Vec<u8>>,
+    },
+    /// The script, or a contract it called, panicked.
+    Panic {
+        /// The reason of the panic.
+        reason: PanicReason,
+        /// The data logged right before the panic.
+        revert_data: Option<Vec<u8>>,
+    },
+}
+
+impl ScriptFailure {
+    /// Decodes the failure of the execution recorded by the `receipts`, or `None`
+    /// if it didn't revert.
+    pub fn from_receipts(receipts: &[Receipt]) -> Option<Self> {
+        let index = receipts
+            .iter()
+            .rposition(|r| matches!(r, Receipt::Revert { .. } | Receipt::Panic { .. }))?;
+        let failed = &receipts[index];
+        let revert_data = index
+            .checked_sub(1)
+            .map(|i| &receipts[i])
+            .filter(|log| {
+                matches!(log, Receipt::LogData { .. }) && log.id() == failed.id()
+            })
+            .and_then(Receipt::data)
+            .map(<[u8]>::to_vec);
+
+        match failed {
+            Receipt::Revert { ra, .. } => Some(Self::Revert {
+                code: *ra,
+                revert_data,
+            }),
+            Receipt::Panic { reason, .. } => Some(Self::Panic {
+                reason: *reason.reason(),
+                revert_data,
+            }),
+            _ => None,
+        }
+    }
+
+    /// The data attached to the failure, if any.
+    pub fn revert_data(&self) -> Option<&[u8]> {
+        match self {
+            Self::Revert { revert_data, .. } | Self::Panic { revert_data, .. } => {
+                revert_data.as_deref()
+            }
+        }
+    }
+}
+
+impl From<&ScriptFailure> for ScriptExecutionResult {
+    fn from(failure: &ScriptFailure) -> Self {
+        match failure {
+            ScriptFailure::Revert { .. } => Self::Revert,
+            ScriptFailure::Panic { .. } => Self::Panic,
+        }
+    }
+}
+
+/// Whether the execution recorded by the `receipts` reverted.
+pub(crate) fn should_revert(receipts: &[Receipt]) -> bool {
+    receipts
+        .iter()
+        .any(|r| matches!(r, Receipt::Revert { .. } | Receipt::Panic { .. }))
+}
+
+/// The messages sent by the execution recorded by the `receipts`, or none if it
+/// reverted, since its messages are discarded along with its other changes.
+pub(crate) fn outgoing_messages(
+    receipts: &[Receipt],
+) -> impl Iterator<Item = MessageOutSummary<'_>> {
+    let receipts = if should_revert(receipts) {
+        &[]
+    } else {
+        receipts
+    };
+
+    receipts.iter().filter_map(MessageOutSummary::from_receipt)
+}
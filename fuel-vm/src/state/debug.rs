@@ -54,6 +54,29 @@ impl Breakpoint {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Kind of the memory access observed by a watchpoint.
+pub enum WatchKind {
+    /// Memory is read.
+    Read,
+    /// Memory is written.
+    Write,
+    /// Memory is read or written.
+    ReadWrite,
+}
+
+impl WatchKind {
+    /// Flag whether a watchpoint of this kind is triggered by the `access`.
+    pub const fn matches(&self, access: Self) -> bool {
+        match self {
+            Self::Read => matches!(access, Self::Read | Self::ReadWrite),
+            Self::Write => matches!(access, Self::Write | Self::ReadWrite),
+            Self::ReadWrite => true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// State evaluation of the interpreter that will describe if a program should
@@ -62,6 +85,20 @@ pub enum DebugEval {
     /// This evaluation should break the program in the location described in
     /// `Breakpoint`.
     Breakpoint(Breakpoint),
+    /// This evaluation should break the program before the instruction at `$pc`
+    /// accesses the watched memory `start..start + len`.
+    ///
+    /// The `$pc` is relative to `$is`, as in [`Breakpoint::pc`].
+    WatchpointHit {
+        /// Start of the watched memory range.
+        start: Word,
+        /// Length of the watched memory range.
+        len: Word,
+        /// Program counter of the accessing instruction.
+        pc: Word,
+        /// Kind of the access performed by the instruction.
+        access: WatchKind,
+    },
     /// This evaluation should not break the program.
     Continue,
 }
@@ -1,7 +1,11 @@
-use crate::state::{
-    Breakpoint,
-    DebugEval,
-    ProgramState,
+use crate::{
+    interpreter::MemoryRange,
+    state::{
+        Breakpoint,
+        DebugEval,
+        ProgramState,
+        WatchKind,
+    },
 };
 
 use fuel_types::{
@@ -9,6 +13,7 @@ use fuel_types::{
     Word,
 };
 
+use alloc::vec::Vec;
 use hashbrown::{
     HashMap,
     HashSet,
@@ -22,6 +27,7 @@ pub struct Debugger {
     /// Single-stepping mode triggers a breakpoint after each instruction
     single_stepping: bool,
     breakpoints: HashMap<ContractId, HashSet<Word>>,
+    watchpoints: Vec<(MemoryRange, WatchKind)>,
     last_state: Option<ProgramState>,
 }
 
@@ -74,28 +80,82 @@ impl Debugger {
             .map(|set| set.remove(&breakpoint.pc()));
     }
 
+    /// Watch the memory `range` for accesses of the provided `kind`.
+    pub fn add_watchpoint(&mut self, range: MemoryRange, kind: WatchKind) {
+        self.is_active = true;
+        self.watchpoints.push((range, kind));
+    }
+
+    /// Remove a watchpoint, if existent.
+    pub fn remove_watchpoint(&mut self, range: &MemoryRange, kind: WatchKind) {
+        self.watchpoints.retain(|(r, k)| r != range || *k != kind);
+    }
+
+    /// Remove all the watchpoints.
+    pub fn clear_watchpoints(&mut self) {
+        self.watchpoints.clear();
+    }
+
+    /// Returns `true` if any watchpoint is set.
+    pub fn has_watchpoints(&self) -> bool {
+        !self.watchpoints.is_empty()
+    }
+
     /// Evaluate the current state of the interpreter whether or not a
     /// breakpoint was reached.
     pub fn eval_state(&mut self, contract: Option<&ContractId>, pc: Word) -> DebugEval {
+        self.eval_state_with_accesses(contract, pc, &[])
+    }
+
+    /// Evaluate the current state of the interpreter whether or not a
+    /// breakpoint was reached, or a watchpoint is hit by one of the memory
+    /// `accesses` of the instruction at `pc`.
+    pub fn eval_state_with_accesses(
+        &mut self,
+        contract: Option<&ContractId>,
+        pc: Word,
+        accesses: &[(MemoryRange, WatchKind)],
+    ) -> DebugEval {
         // Default contract address maps to unset contract target
         let contract = contract.copied().unwrap_or_default();
         let last_state = self.last_state.take();
 
         let current = Breakpoint::raw(contract, pc);
 
-        if self.single_stepping {
-            return match last_state {
-                Some(s) if s == current => DebugEval::Continue,
-                _ => current.into(),
-            }
+        // The execution is resumed from this location, so it shouldn't break again
+        let resumed = match last_state.as_ref().and_then(ProgramState::debug_ref) {
+            Some(DebugEval::Breakpoint(b)) => b == &current,
+            Some(DebugEval::WatchpointHit { pc: hit, .. }) => *hit == pc,
+            _ => false,
+        };
+        if resumed {
+            return DebugEval::Continue
         }
 
-        self.breakpoints
+        let breakpoint = self
+            .breakpoints
             .get(&contract)
-            .and_then(|set| set.get(&pc))
-            .map(|_| match last_state {
-                Some(s) if s == current => DebugEval::Continue,
-                _ => current.into(),
+            .is_some_and(|set| set.contains(&pc));
+        if self.single_stepping || breakpoint {
+            return current.into()
+        }
+
+        accesses
+            .iter()
+            .find_map(|(accessed, access)| {
+                self.watchpoints
+                    .iter()
+                    .find(|(range, kind)| {
+                        kind.matches(*access)
+                            && range.start < accessed.end
+                            && accessed.start < range.end
+                    })
+                    .map(|(range, _)| DebugEval::WatchpointHit {
+                        start: range.start as Word,
+                        len: range.len() as Word,
+                        pc,
+                        access: *access,
+                    })
             })
             .unwrap_or_default()
     }
@@ -4,12 +4,18 @@ use fuel_storage::Mappable;
 use fuel_tx::Contract;
 use fuel_types::ContractId;
 
+mod blob_data;
 mod contracts_assets;
 mod contracts_state;
 mod interpreter;
 mod memory;
 pub(crate) mod predicate;
+mod transactional;
 
+pub use blob_data::{
+    BlobBytes,
+    BlobData,
+};
 pub use contracts_assets::{
     ContractsAssetKey,
     ContractsAssets,
@@ -25,6 +31,10 @@ pub use interpreter::{
 };
 pub use memory::MemoryStorage;
 pub use predicate::PredicateStorage;
+pub use transactional::{
+    StorageChanges,
+    TransactionalStorage,
+};
 
 /// The storage table for contract's raw byte code.
 pub struct ContractsRawCode;
@@ -0,0 +1,51 @@
+use fuel_storage::Mappable;
+use fuel_types::{
+    fmt_truncated_hex,
+    BlobId,
+};
+
+use alloc::vec::Vec;
+use derivative::Derivative;
+
+/// The storage table for the payloads of the blobs posted by [`fuel_tx::Blob`]
+/// transactions.
+pub struct BlobData;
+
+impl Mappable for BlobData {
+    type Key = Self::OwnedKey;
+    type OwnedKey = BlobId;
+    type OwnedValue = BlobBytes;
+    type Value = [u8];
+}
+
+/// Storage type for the payload of a blob
+#[derive(Derivative, Default, Clone, PartialEq, Eq, Hash)]
+#[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlobBytes(
+    #[derivative(Debug(format_with = "fmt_truncated_hex::<16>"))] pub Vec<u8>,
+);
+
+impl From<Vec<u8>> for BlobBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<&[u8]> for BlobBytes {
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.into())
+    }
+}
+
+impl From<BlobBytes> for Vec<u8> {
+    fn from(bytes: BlobBytes) -> Vec<u8> {
+        bytes.0
+    }
+}
+
+impl AsRef<[u8]> for BlobBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
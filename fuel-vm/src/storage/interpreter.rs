@@ -14,6 +14,7 @@ use fuel_tx::{
 };
 use fuel_types::{
     AssetId,
+    BlobId,
     BlockHeight,
     Bytes32,
     ContractId,
@@ -26,6 +27,7 @@ use crate::{
         RuntimeError,
     },
     storage::{
+        BlobData,
         ContractsAssets,
         ContractsRawCode,
         ContractsState,
@@ -50,6 +52,8 @@ pub trait InterpreterStorage:
     + StorageWrite<ContractsState, Error = Self::DataError>
     + StorageSize<ContractsState, Error = Self::DataError>
     + StorageRead<ContractsState, Error = Self::DataError>
+    + StorageSize<BlobData, Error = Self::DataError>
+    + StorageRead<BlobData, Error = Self::DataError>
     + ContractsAssetsStorage<Error = Self::DataError>
 {
     /// Error implementation for reasons unspecified in the protocol.
@@ -119,6 +123,16 @@ pub trait InterpreterStorage:
         Ok(StorageRead::<ContractsRawCode>::read(self, id, writer)?.map(|r| r as Word))
     }
 
+    /// Fetch the size of a blob posted by a [`fuel_tx::Blob`] transaction.
+    fn storage_blob_size(&self, id: &BlobId) -> Result<Option<usize>, Self::DataError> {
+        StorageSize::<BlobData>::size_of_value(self, id)
+    }
+
+    /// Fetch the payload of a blob posted by a [`fuel_tx::Blob`] transaction.
+    fn read_blob(&self, id: &BlobId) -> Result<Option<Vec<u8>>, Self::DataError> {
+        StorageRead::<BlobData>::read_alloc(self, id)
+    }
+
     /// Append a contract to the chain, provided its identifier.
     ///
     /// Canonically, the identifier should be [`Contract::id`].
@@ -272,6 +286,14 @@ where
         <S as InterpreterStorage>::read_contract(self.deref(), id, writer)
     }
 
+    fn storage_blob_size(&self, id: &BlobId) -> Result<Option<usize>, Self::DataError> {
+        <S as InterpreterStorage>::storage_blob_size(self.deref(), id)
+    }
+
+    fn read_blob(&self, id: &BlobId) -> Result<Option<Vec<u8>>, Self::DataError> {
+        <S as InterpreterStorage>::read_blob(self.deref(), id)
+    }
+
     fn contract_state_range(
         &self,
         id: &ContractId,
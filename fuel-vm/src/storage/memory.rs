@@ -1,4 +1,6 @@
 use crate::storage::{
+    BlobBytes,
+    BlobData,
     ContractsAssetKey,
     ContractsAssets,
     ContractsRawCode,
@@ -20,6 +22,7 @@ use fuel_storage::{
 };
 use fuel_tx::Contract;
 use fuel_types::{
+    BlobId,
     BlockHeight,
     Bytes32,
     ContractId,
@@ -41,6 +44,7 @@ struct MemoryStorageInner {
     contracts: BTreeMap<ContractId, Contract>,
     balances: BTreeMap<ContractsAssetKey, Word>,
     contract_state: BTreeMap<ContractsStateKey, ContractsStateData>,
+    blobs: BTreeMap<BlobId, BlobBytes>,
 }
 
 #[derive(Debug, Clone)]
@@ -52,7 +56,7 @@ struct MemoryStorageInner {
 /// - transacted: will receive the committed `memory` state.
 /// - persisted: will receive the persisted `transacted` state.
 pub struct MemoryStorage {
-    block_height: BlockHeight,
+    pub(crate) block_height: BlockHeight,
     coinbase: ContractId,
     memory: MemoryStorageInner,
     transacted: MemoryStorageInner,
@@ -353,6 +357,52 @@ impl StorageRead<ContractsState> for MemoryStorage {
     }
 }
 
+impl StorageInspect<BlobData> for MemoryStorage {
+    type Error = Infallible;
+
+    fn get(&self, key: &BlobId) -> Result<Option<Cow<'_, BlobBytes>>, Infallible> {
+        Ok(self.memory.blobs.get(key).map(Cow::Borrowed))
+    }
+
+    fn contains_key(&self, key: &BlobId) -> Result<bool, Infallible> {
+        Ok(self.memory.blobs.contains_key(key))
+    }
+}
+
+impl StorageMutate<BlobData> for MemoryStorage {
+    fn insert(
+        &mut self,
+        key: &BlobId,
+        value: &[u8],
+    ) -> Result<Option<BlobBytes>, Infallible> {
+        Ok(self.memory.blobs.insert(*key, value.into()))
+    }
+
+    fn remove(&mut self, key: &BlobId) -> Result<Option<BlobBytes>, Infallible> {
+        Ok(self.memory.blobs.remove(key))
+    }
+}
+
+impl StorageSize<BlobData> for MemoryStorage {
+    fn size_of_value(&self, key: &BlobId) -> Result<Option<usize>, Infallible> {
+        Ok(self.memory.blobs.get(key).map(|b| b.as_ref().len()))
+    }
+}
+
+impl StorageRead<BlobData> for MemoryStorage {
+    fn read(&self, key: &BlobId, buf: &mut [u8]) -> Result<Option<usize>, Infallible> {
+        Ok(self.memory.blobs.get(key).map(|b| {
+            let len = buf.len().min(b.as_ref().len());
+            buf[..len].copy_from_slice(&b.as_ref()[..len]);
+            len
+        }))
+    }
+
+    fn read_alloc(&self, key: &BlobId) -> Result<Option<Vec<u8>>, Infallible> {
+        Ok(self.memory.blobs.get(key).map(|b| b.as_ref().to_vec()))
+    }
+}
+
 impl ContractsAssetsStorage for MemoryStorage {}
 
 impl InterpreterStorage for MemoryStorage {
@@ -475,7 +525,7 @@ impl InterpreterStorage for MemoryStorage {
     }
 }
 
-fn add_one(a: &mut [u8; 32]) -> bool {
+pub(super) fn add_one(a: &mut [u8; 32]) -> bool {
     let right = u128::from_be_bytes(a[16..].try_into().unwrap());
     let (right, of) = right.overflowing_add(1);
     a[16..].copy_from_slice(&right.to_be_bytes()[..]);
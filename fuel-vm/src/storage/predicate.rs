@@ -21,6 +21,7 @@ use fuel_storage::{
     StorageWrite,
 };
 use fuel_types::{
+    BlobId,
     BlockHeight,
     Bytes32,
     ContractId,
@@ -28,6 +29,7 @@ use fuel_types::{
 
 use super::{
     interpreter::ContractsAssetsStorage,
+    BlobData,
     ContractsRawCode,
     ContractsState,
     ContractsStateData,
@@ -193,6 +195,26 @@ impl StorageWrite<ContractsState> for PredicateStorage {
     }
 }
 
+impl StorageSize<BlobData> for PredicateStorage {
+    fn size_of_value(&self, _key: &BlobId) -> Result<Option<usize>, StorageUnavailable> {
+        Err(StorageUnavailable)
+    }
+}
+
+impl StorageRead<BlobData> for PredicateStorage {
+    fn read(
+        &self,
+        _key: &BlobId,
+        _buf: &mut [u8],
+    ) -> Result<Option<usize>, StorageUnavailable> {
+        Err(StorageUnavailable)
+    }
+
+    fn read_alloc(&self, _key: &BlobId) -> Result<Option<Vec<u8>>, StorageUnavailable> {
+        Err(StorageUnavailable)
+    }
+}
+
 impl ContractsAssetsStorage for PredicateStorage {}
 
 impl InterpreterStorage for PredicateStorage {
@@ -0,0 +1,625 @@
+//! Storage overlay buffering the writes of the interpreter.
+
+use crate::storage::{
+    BlobBytes,
+    BlobData,
+    ContractsAssetKey,
+    ContractsAssets,
+    ContractsRawCode,
+    ContractsState,
+    ContractsStateData,
+    ContractsStateKey,
+    InterpreterStorage,
+};
+
+use fuel_storage::{
+    Mappable,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_tx::Contract;
+use fuel_types::{
+    BlobId,
+    BlockHeight,
+    Bytes32,
+    ContractId,
+    Word,
+};
+
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    vec::Vec,
+};
+
+use super::{
+    interpreter::ContractsAssetsStorage,
+    memory::add_one,
+};
+
+/// The writes buffered by a [`TransactionalStorage`].
+///
+/// A `None` value means the entry was removed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StorageChanges {
+    contracts: BTreeMap<ContractId, Option<Contract>>,
+    balances: BTreeMap<ContractsAssetKey, Option<Word>>,
+    contract_state: BTreeMap<ContractsStateKey, Option<ContractsStateData>>,
+}
+
+impl StorageChanges {
+    /// Deployed or removed contract codes.
+    pub const fn contracts(&self) -> &BTreeMap<ContractId, Option<Contract>> {
+        &self.contracts
+    }
+
+    /// Updated or removed contract balances.
+    pub const fn balances(&self) -> &BTreeMap<ContractsAssetKey, Option<Word>> {
+        &self.balances
+    }
+
+    /// Updated or removed contract state slots.
+    pub const fn contract_state(
+        &self,
+    ) -> &BTreeMap<ContractsStateKey, Option<ContractsStateData>> {
+        &self.contract_state
+    }
+
+    /// Returns `true` if no write was buffered.
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty()
+            && self.balances.is_empty()
+            && self.contract_state.is_empty()
+    }
+}
+
+/// Storage adapter executing the interpreter over a borrowed backing store
+/// without modifying it.
+///
+/// The reads go through the buffered writes to the backing store. The writes are
+/// kept in memory until [`Self::commit`] applies them to the backing store, or
+/// [`Self::rollback`] discards them.
+#[derive(Debug)]
+pub struct TransactionalStorage<'a, S> {
+    storage: &'a mut S,
+    changes: StorageChanges,
+}
+
+impl<'a, S> TransactionalStorage<'a, S> {
+    /// Create a new overlay without buffered writes over the `storage`.
+    pub fn new(storage: &'a mut S) -> Self {
+        Self {
+            storage,
+            changes: Default::default(),
+        }
+    }
+
+    /// The backing store.
+    pub fn storage(&self) -> &S {
+        self.storage
+    }
+
+    /// The buffered writes.
+    pub const fn changes(&self) -> &StorageChanges {
+        &self.changes
+    }
+
+    /// Discard the overlay, returning the buffered writes.
+    pub fn into_changes(self) -> StorageChanges {
+        self.changes
+    }
+
+    /// Discard the buffered writes.
+    pub fn rollback(&mut self) {
+        self.changes = Default::default();
+    }
+}
+
+impl<'a, S> TransactionalStorage<'a, S>
+where
+    S: InterpreterStorage,
+{
+    /// Apply the buffered writes to the backing store.
+    ///
+    /// The writes are discarded from the overlay, even if the backing store fails
+    /// to apply some of them.
+    pub fn commit(&mut self) -> Result<(), S::DataError> {
+        let changes = core::mem::take(&mut self.changes);
+
+        for (id, contract) in changes.contracts {
+            match contract {
+                Some(contract) => {
+                    StorageMutate::<ContractsRawCode>::insert(
+                        self.storage,
+                        &id,
+                        contract.as_ref(),
+                    )?;
+                }
+                None => {
+                    StorageMutate::<ContractsRawCode>::remove(self.storage, &id)?;
+                }
+            }
+        }
+
+        for (key, balance) in changes.balances {
+            match balance {
+                Some(balance) => {
+                    StorageMutate::<ContractsAssets>::insert(
+                        self.storage,
+                        &key,
+                        &balance,
+                    )?;
+                }
+                None => {
+                    StorageMutate::<ContractsAssets>::remove(self.storage, &key)?;
+                }
+            }
+        }
+
+        for (key, value) in changes.contract_state {
+            match value {
+                Some(value) => {
+                    StorageMutate::<ContractsState>::insert(
+                        self.storage,
+                        &key,
+                        value.as_ref(),
+                    )?;
+                }
+                None => {
+                    StorageMutate::<ContractsState>::remove(self.storage, &key)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Implements the storage traits of a table with byte values, reading through the
+/// buffered writes stored in the `$field` of the [`StorageChanges`].
+macro_rules! impl_bytes_table {
+    ($table:ty, $field:ident) => {
+        impl<S> StorageInspect<$table> for TransactionalStorage<'_, S>
+        where
+            S: InterpreterStorage,
+        {
+            type Error = S::DataError;
+
+            fn get(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Cow<'_, <$table as Mappable>::OwnedValue>>, Self::Error>
+            {
+                match self.changes.$field.get(key) {
+                    Some(value) => Ok(value.as_ref().map(Cow::Borrowed)),
+                    None => StorageInspect::<$table>::get(&*self.storage, key),
+                }
+            }
+
+            fn contains_key(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<bool, Self::Error> {
+                match self.changes.$field.get(key) {
+                    Some(value) => Ok(value.is_some()),
+                    None => StorageInspect::<$table>::contains_key(&*self.storage, key),
+                }
+            }
+        }
+
+        impl<S> StorageMutate<$table> for TransactionalStorage<'_, S>
+        where
+            S: InterpreterStorage,
+        {
+            fn insert(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                value: &<$table as Mappable>::Value,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                let prev = StorageInspect::<$table>::get(self, key)?.map(Cow::into_owned);
+                self.changes.$field.insert(*key, Some(value.into()));
+                Ok(prev)
+            }
+
+            fn remove(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<<$table as Mappable>::OwnedValue>, Self::Error> {
+                let prev = StorageInspect::<$table>::get(self, key)?.map(Cow::into_owned);
+                self.changes.$field.insert(*key, None);
+                Ok(prev)
+            }
+        }
+
+        impl<S> StorageSize<$table> for TransactionalStorage<'_, S>
+        where
+            S: InterpreterStorage,
+        {
+            fn size_of_value(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<usize>, Self::Error> {
+                match self.changes.$field.get(key) {
+                    Some(value) => Ok(value.as_ref().map(|v| v.as_ref().len())),
+                    None => StorageSize::<$table>::size_of_value(&*self.storage, key),
+                }
+            }
+        }
+
+        impl<S> StorageRead<$table> for TransactionalStorage<'_, S>
+        where
+            S: InterpreterStorage,
+        {
+            fn read(
+                &self,
+                key: &<$table as Mappable>::Key,
+                buf: &mut [u8],
+            ) -> Result<Option<usize>, Self::Error> {
+                match self.changes.$field.get(key) {
+                    Some(value) => Ok(value.as_ref().map(|v| {
+                        let len = buf.len().min(v.as_ref().len());
+                        buf[..len].copy_from_slice(&v.as_ref()[..len]);
+                        len
+                    })),
+                    None => StorageRead::<$table>::read(&*self.storage, key, buf),
+                }
+            }
+
+            fn read_alloc(
+                &self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                match self.changes.$field.get(key) {
+                    Some(value) => Ok(value.as_ref().map(|v| v.as_ref().to_vec())),
+                    None => StorageRead::<$table>::read_alloc(&*self.storage, key),
+                }
+            }
+        }
+
+        impl<S> StorageWrite<$table> for TransactionalStorage<'_, S>
+        where
+            S: InterpreterStorage,
+        {
+            fn write(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<usize, Self::Error> {
+                self.changes.$field.insert(*key, Some(buf.into()));
+                Ok(buf.len())
+            }
+
+            fn replace(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+                buf: &[u8],
+            ) -> Result<(usize, Option<Vec<u8>>), Self::Error> {
+                let prev = StorageMutate::<$table>::insert(self, key, buf)?;
+                Ok((buf.len(), prev.map(Into::into)))
+            }
+
+            fn take(
+                &mut self,
+                key: &<$table as Mappable>::Key,
+            ) -> Result<Option<Vec<u8>>, Self::Error> {
+                let prev = StorageMutate::<$table>::remove(self, key)?;
+                Ok(prev.map(Into::into))
+            }
+        }
+    };
+}
+
+impl_bytes_table!(ContractsRawCode, contracts);
+impl_bytes_table!(ContractsState, contract_state);
+
+impl<S> StorageInspect<ContractsAssets> for TransactionalStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    type Error = S::DataError;
+
+    fn get(
+        &self,
+        key: &<ContractsAssets as Mappable>::Key,
+    ) -> Result<Option<Cow<'_, Word>>, Self::Error> {
+        match self.changes.balances.get(key) {
+            Some(balance) => Ok(balance.as_ref().map(Cow::Borrowed)),
+            None => StorageInspect::<ContractsAssets>::get(&*self.storage, key),
+        }
+    }
+
+    fn contains_key(
+        &self,
+        key: &<ContractsAssets as Mappable>::Key,
+    ) -> Result<bool, Self::Error> {
+        match self.changes.balances.get(key) {
+            Some(balance) => Ok(balance.is_some()),
+            None => StorageInspect::<ContractsAssets>::contains_key(&*self.storage, key),
+        }
+    }
+}
+
+impl<S> StorageMutate<ContractsAssets> for TransactionalStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    fn insert(
+        &mut self,
+        key: &<ContractsAssets as Mappable>::Key,
+        value: &Word,
+    ) -> Result<Option<Word>, Self::Error> {
+        let prev =
+            StorageInspect::<ContractsAssets>::get(self, key)?.map(Cow::into_owned);
+        self.changes.balances.insert(*key, Some(*value));
+        Ok(prev)
+    }
+
+    fn remove(
+        &mut self,
+        key: &<ContractsAssets as Mappable>::Key,
+    ) -> Result<Option<Word>, Self::Error> {
+        let prev =
+            StorageInspect::<ContractsAssets>::get(self, key)?.map(Cow::into_owned);
+        self.changes.balances.insert(*key, None);
+        Ok(prev)
+    }
+}
+
+// The blobs are only read by the interpreter, so they are read from the backing
+// store directly.
+impl<S> StorageInspect<BlobData> for TransactionalStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    type Error = S::DataError;
+
+    fn get(&self, key: &BlobId) -> Result<Option<Cow<'_, BlobBytes>>, Self::Error> {
+        StorageInspect::<BlobData>::get(&*self.storage, key)
+    }
+
+    fn contains_key(&self, key: &BlobId) -> Result<bool, Self::Error> {
+        StorageInspect::<BlobData>::contains_key(&*self.storage, key)
+    }
+}
+
+impl<S> StorageSize<BlobData> for TransactionalStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    fn size_of_value(&self, key: &BlobId) -> Result<Option<usize>, Self::Error> {
+        StorageSize::<BlobData>::size_of_value(&*self.storage, key)
+    }
+}
+
+impl<S> StorageRead<BlobData> for TransactionalStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    fn read(&self, key: &BlobId, buf: &mut [u8]) -> Result<Option<usize>, Self::Error> {
+        StorageRead::<BlobData>::read(&*self.storage, key, buf)
+    }
+
+    fn read_alloc(&self, key: &BlobId) -> Result<Option<Vec<u8>>, Self::Error> {
+        StorageRead::<BlobData>::read_alloc(&*self.storage, key)
+    }
+}
+
+impl<S> ContractsAssetsStorage for TransactionalStorage<'_, S> where S: InterpreterStorage {}
+
+impl<S> InterpreterStorage for TransactionalStorage<'_, S>
+where
+    S: InterpreterStorage,
+{
+    type DataError = S::DataError;
+
+    fn block_height(&self) -> Result<BlockHeight, Self::DataError> {
+        self.storage.block_height()
+    }
+
+    fn timestamp(&self, height: BlockHeight) -> Result<Word, Self::DataError> {
+        self.storage.timestamp(height)
+    }
+
+    fn block_hash(&self, block_height: BlockHeight) -> Result<Bytes32, Self::DataError> {
+        self.storage.block_hash(block_height)
+    }
+
+    fn coinbase(&self) -> Result<ContractId, Self::DataError> {
+        self.storage.coinbase()
+    }
+
+    fn contract_state_range(
+        &self,
+        id: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Vec<Option<Cow<ContractsStateData>>>, Self::DataError> {
+        let mut values = self.storage.contract_state_range(id, start_key, range)?;
+        for (key, value) in state_keys(start_key).zip(values.iter_mut()) {
+            if let Some(buffered) = self.changes.contract_state.get(&(id, &key).into()) {
+                *value = buffered.as_ref().map(Cow::Borrowed);
+            }
+        }
+        Ok(values)
+    }
+
+    fn contract_state_insert_range<'a, I>(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        values: I,
+    ) -> Result<usize, Self::DataError>
+    where
+        I: Iterator<Item = &'a [u8]>,
+    {
+        let mut unset_count = 0;
+        for (key, value) in state_keys(start_key).zip(values) {
+            let key: ContractsStateKey = (contract, &key).into();
+            if !StorageInspect::<ContractsState>::contains_key(self, &key)? {
+                unset_count += 1;
+            }
+            StorageWrite::<ContractsState>::write(self, &key, value)?;
+        }
+        Ok(unset_count)
+    }
+
+    fn contract_state_remove_range(
+        &mut self,
+        contract: &ContractId,
+        start_key: &Bytes32,
+        range: usize,
+    ) -> Result<Option<()>, Self::DataError> {
+        let mut all_set_key = true;
+        let mut count = 0;
+        for key in state_keys(start_key).take(range) {
+            let key: ContractsStateKey = (contract, &key).into();
+            all_set_key &= StorageInspect::<ContractsState>::contains_key(self, &key)?;
+            self.changes.contract_state.insert(key, None);
+            count += 1;
+        }
+        Ok((all_set_key && count == range).then_some(()))
+    }
+}
+
+/// The consecutive state keys starting at `start_key`, up to the maximal key.
+fn state_keys(start_key: &Bytes32) -> impl Iterator<Item = Bytes32> {
+    core::iter::successors(Some(**start_key), |n| {
+        let mut n = *n;
+        if add_one(&mut n) {
+            None
+        } else {
+            Some(n)
+        }
+    })
+    .map(Bytes32::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::MemoryStorage;
+    use alloc::vec;
+    use fuel_types::AssetId;
+
+    fn key(k: u8) -> Bytes32 {
+        let mut key = [0u8; 32];
+        key[31] = k;
+        key.into()
+    }
+
+    fn backing_storage() -> MemoryStorage {
+        let mut storage = MemoryStorage::default();
+        let contract = ContractId::default();
+        storage
+            .contract_state_insert(&contract, &key(0), &[1; 32])
+            .unwrap();
+        storage
+            .contract_state_insert(&contract, &key(2), &[2; 32])
+            .unwrap();
+        storage
+            .contract_asset_id_balance_insert(&contract, &AssetId::default(), 10)
+            .unwrap();
+        storage
+    }
+
+    #[test]
+    fn reads_through_buffered_writes() {
+        let mut backing = backing_storage();
+        let contract = ContractId::default();
+        let mut storage = TransactionalStorage::new(&mut backing);
+
+        storage
+            .contract_state_insert(&contract, &key(1), &[3; 32])
+            .unwrap();
+        storage.contract_state_remove(&contract, &key(2)).unwrap();
+        storage
+            .contract_asset_id_balance_insert(&contract, &AssetId::default(), 20)
+            .unwrap();
+
+        let range: Vec<_> = storage
+            .contract_state_range(&contract, &key(0), 3)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.map(|v| v.into_owned()))
+            .collect();
+        assert_eq!(
+            range,
+            vec![
+                Some(ContractsStateData::from(&[1; 32][..])),
+                Some(ContractsStateData::from(&[3; 32][..])),
+                None,
+            ]
+        );
+        assert_eq!(
+            storage
+                .contract_asset_id_balance(&contract, &AssetId::default())
+                .unwrap(),
+            Some(20)
+        );
+
+        // The backing store isn't modified until the commit
+        assert_eq!(
+            backing.contract_state(&contract, &key(1)).into_owned(),
+            ContractsStateData::default()
+        );
+    }
+
+    #[test]
+    fn rollback_discards_buffered_writes() {
+        let mut backing = backing_storage();
+        let before = backing.all_contract_state().count();
+        let contract = ContractId::default();
+
+        let mut storage = TransactionalStorage::new(&mut backing);
+        storage
+            .contract_state_insert_range(
+                &contract,
+                &key(3),
+                [&[4u8; 32][..], &[5u8; 32][..]].into_iter(),
+            )
+            .unwrap();
+        assert!(!storage.changes().is_empty());
+        storage.rollback();
+        assert!(storage.changes().is_empty());
+        storage.commit().unwrap();
+
+        assert_eq!(backing.all_contract_state().count(), before);
+    }
+
+    #[test]
+    fn commit_applies_buffered_writes() {
+        let mut backing = backing_storage();
+        let contract = ContractId::default();
+
+        let mut storage = TransactionalStorage::new(&mut backing);
+        assert_eq!(
+            storage
+                .contract_state_insert_range(
+                    &contract,
+                    &key(1),
+                    [&[4u8; 32][..], &[5u8; 32][..]].into_iter(),
+                )
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            storage
+                .contract_state_remove_range(&contract, &key(0), 2)
+                .unwrap(),
+            Some(())
+        );
+        storage.commit().unwrap();
+        assert!(storage.changes().is_empty());
+
+        assert_eq!(
+            backing
+                .all_contract_state()
+                .map(|(k, v)| (*k.state_key(), v.clone()))
+                .collect::<Vec<_>>(),
+            vec![(key(2), ContractsStateData::from(&[5; 32][..]))]
+        );
+    }
+}
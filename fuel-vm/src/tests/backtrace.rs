@@ -8,6 +8,7 @@ use fuel_asm::{
     op,
     RegId,
 };
+use fuel_types::canonical::Serialize;
 
 #[test]
 fn backtrace() {
@@ -91,3 +92,107 @@ fn backtrace() {
         .to();
     assert_eq!(id, &contract_call);
 }
+
+#[test]
+fn panic_in_nested_call_reports_innermost_contract_location() {
+    let mut test_context = TestBuilder::new(2322u64);
+
+    // Panics on the division by zero, at the offset `8` of its code
+    let inner = test_context
+        .setup_contract(
+            vec![
+                op::noop(),
+                op::noop(),
+                op::div(0x12, RegId::ONE, RegId::ZERO),
+                op::ret(RegId::ONE),
+            ],
+            None,
+            None,
+        )
+        .contract_id;
+
+    // Calls the inner contract with the call parameters pointed by `$r17`
+    let outer = test_context
+        .setup_contract(
+            vec![
+                op::call(0x11, RegId::ZERO, 0x11, RegId::CGAS),
+                op::ret(RegId::ONE),
+            ],
+            None,
+            None,
+        )
+        .contract_id;
+
+    let outer_call = Call::new(outer, 0, 0).to_bytes();
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, outer_call.len() as Immediate12),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = [outer_call, Call::new(inner, 0, 0).to_bytes()].concat();
+
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(outer)
+        .contract_input(inner)
+        .fee_input()
+        .contract_output(&outer)
+        .contract_output(&inner)
+        .build();
+
+    let (state, backtrace) = test_context
+        .execute_tx_with_backtrace(tx, 0)
+        .expect("Should execute tx");
+
+    let receipts = state.receipts();
+    let Receipt::Panic {
+        id, reason, pc, is, ..
+    } = &receipts[receipts.len() - 2]
+    else {
+        panic!("Expected a panic receipt, got {receipts:?}");
+    };
+    assert_eq!(*reason.reason(), PanicReason::ArithmeticError);
+    assert_eq!(id, &inner);
+    assert_eq!(pc - is, 8);
+
+    let location = backtrace
+        .expect("Expected a backtrace")
+        .panic_location()
+        .copied()
+        .expect("Expected a panic location");
+    assert_eq!(location.contract(), Some(&inner));
+    assert_eq!(location.pc(), 8);
+    assert_eq!(location.is(), *is);
+    assert_eq!(location.absolute_pc(), *pc);
+}
+
+#[test]
+fn panic_in_script_has_no_contract_location() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(
+            vec![
+                op::noop(),
+                op::div(0x10, RegId::ONE, RegId::ZERO),
+                op::ret(RegId::ONE),
+            ],
+            vec![],
+        )
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+
+    let (_, backtrace) = test_context
+        .execute_tx_with_backtrace(tx, 0)
+        .expect("Should execute tx");
+
+    let location = backtrace
+        .expect("Expected a backtrace")
+        .panic_location()
+        .copied()
+        .expect("Expected a panic location");
+    assert_eq!(location.contract(), None);
+    assert_eq!(location.pc(), 4);
+}
@@ -0,0 +1,160 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    GTFArgs,
+    RegId,
+};
+use fuel_storage::StorageMutate;
+use fuel_tx::BlobIdExt;
+use fuel_types::BlobId;
+
+use crate::{
+    interpreter::InterpreterParams,
+    prelude::*,
+    storage::BlobData,
+    tests::test_helpers::assert_panics,
+    util::test_helpers::TestBuilder,
+};
+
+const PAYLOAD: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+/// Runs `script` with the id of the blob of [`PAYLOAD`] in the script data, pointed
+/// by `0x10`. The blob is only stored if `stored` is set.
+fn run_with_blob(stored: bool, script: Vec<Instruction>) -> Vec<Receipt> {
+    let blob_id = BlobId::compute(&PAYLOAD);
+    let mut storage = MemoryStorage::default();
+    if stored {
+        StorageMutate::<BlobData>::insert(&mut storage, &blob_id, &PAYLOAD).unwrap();
+    }
+
+    let script = [
+        vec![op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData)],
+        script,
+    ]
+    .concat();
+    let mut test_context = TestBuilder::new(2322u64);
+    TestBuilder::storage(&mut test_context, storage)
+        .start_script(script, blob_id.to_vec())
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute()
+        .receipts()
+        .to_vec()
+}
+
+#[test]
+fn bsiz__returns_the_size_of_the_blob() {
+    // When
+    let receipts = run_with_blob(
+        true,
+        vec![
+            op::bsiz(0x11, 0x10),
+            op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ],
+    );
+
+    // Then
+    let Receipt::Log { ra, .. } = receipts[0] else {
+        panic!("Expected a log receipt, got {:?}", receipts[0]);
+    };
+    assert_eq!(ra, PAYLOAD.len() as Word);
+}
+
+#[test]
+fn bldd__copies_the_range_and_zero_fills_past_the_end_of_the_blob() {
+    // When
+    let receipts = run_with_blob(
+        true,
+        vec![
+            op::movi(0x11, 6),
+            op::aloc(0x11),
+            op::movi(0x12, 4),
+            op::bldd(RegId::HP, 0x10, 0x12, 0x11),
+            op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x11),
+            op::ret(RegId::ONE),
+        ],
+    );
+
+    // Then
+    assert_eq!(receipts[0].data(), Some(&[5, 6, 7, 8, 0, 0][..]));
+}
+
+#[test]
+fn bsiz__panics_when_the_blob_is_not_found() {
+    // When
+    let receipts = run_with_blob(false, vec![op::bsiz(0x11, 0x10)]);
+
+    // Then
+    assert_panics(&receipts, PanicReason::BlobNotFound);
+}
+
+#[test]
+fn bldd__panics_when_the_blob_is_not_found() {
+    // When
+    let receipts = run_with_blob(
+        false,
+        vec![
+            op::movi(0x11, 8),
+            op::aloc(0x11),
+            op::bldd(RegId::HP, 0x10, RegId::ZERO, 0x11),
+        ],
+    );
+
+    // Then
+    assert_panics(&receipts, PanicReason::BlobNotFound);
+}
+
+#[test]
+fn bldd__panics_when_the_destination_is_not_owned() {
+    // When
+    let receipts = run_with_blob(
+        true,
+        vec![
+            op::movi(0x11, 8),
+            op::bldd(RegId::ZERO, 0x10, RegId::ZERO, 0x11),
+        ],
+    );
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOwnership);
+}
+
+#[test]
+fn bldd__charges_the_size_of_the_blob_before_loading_it() {
+    // Given
+    let payload = vec![7u8; 64 * 1024];
+    let blob_id = BlobId::compute(&payload);
+    let mut storage = MemoryStorage::default();
+    StorageMutate::<BlobData>::insert(&mut storage, &blob_id, &payload).unwrap();
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::aloc(RegId::ONE),
+        op::bldd(RegId::HP, 0x10, RegId::ZERO, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let consensus_params = ConsensusParameters::standard();
+    let bldd = consensus_params.gas_costs().bldd;
+    let blob_gas = bldd.resolve_without_base(payload.len() as Word);
+    let tx = TestBuilder::new(2322u64)
+        .start_script(script, blob_id.to_vec())
+        .script_gas_limit(blob_gas)
+        .fee_input()
+        .build();
+    let mut vm =
+        Transactor::<_, _>::new(storage, InterpreterParams::new(0, &consensus_params));
+
+    // When
+    vm.transact(tx);
+
+    // Then
+    assert_panics(vm.receipts().unwrap(), PanicReason::OutOfGas);
+    let out_of_gas = vm.interpreter().out_of_gas().expect("expected out of gas");
+    assert_eq!(out_of_gas.requested, blob_gas);
+}
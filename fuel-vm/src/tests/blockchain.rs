@@ -601,7 +601,12 @@ fn ldc__load_len_of_target_contract<'a>(
             .into_checked(height, &consensus_params)
             .expect("failed to check tx");
 
-    client.deploy(tx_create_target);
+    // The target is already deployed if the client was used by a previous call
+    match client.deploy(tx_create_target) {
+        Ok(id) => assert_eq!(id, contract_id),
+        Err(InterpreterError::Panic(PanicReason::ContractIdAlreadyDeployed)) => {}
+        Err(e) => panic!("The contract should be deployed: {e:?}"),
+    }
 
     // Then deploy another contract that attempts to read the first one
     let reg_a = 0x20;
@@ -701,16 +706,15 @@ fn ldc_reason_helper(cmd: Vec<Instruction>, expected_reason: PanicReason) {
     // make gas costs free
     let gas_costs = GasCosts::default();
 
-    let consensus_params = ConsensusParameters {
-        gas_costs,
-        ..Default::default()
-    };
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_gas_costs(gas_costs);
 
     let interpreter_params = InterpreterParams::new(gas_price, &consensus_params);
 
     let mut client = MemoryClient::<NotSupportedEcal>::new(
         MemoryStorage::default(),
         interpreter_params,
+        consensus_params.clone(),
     );
 
     let gas_limit = 1_000_000;
@@ -741,7 +745,9 @@ fn ldc_reason_helper(cmd: Vec<Instruction>, expected_reason: PanicReason) {
         .into_checked(height, &consensus_params)
         .expect("failed to check tx");
 
-    client.deploy(tx_create_target);
+    client
+        .deploy(tx_create_target)
+        .expect("The contract should be deployed");
 
     let load_contract = cmd;
 
@@ -1777,6 +1783,96 @@ fn smo_instruction_works() {
     ));
 }
 
+/// Executes a script sending two messages with the data `[0xaa, 0xbb]`, then ending
+/// with `end`.
+fn transact_two_messages(end: Instruction) -> Transactor<MemoryStorage, Script> {
+    #[rustfmt::skip]
+    let script = vec![
+        op::movi(0x10, 2),                  // data buffer allocation size
+        op::aloc(0x10),                     // allocate
+        op::movi(0x10, 0xaa),               // first message byte
+        op::sb(RegId::HP, 0x10, 0),         // store above to the message buffer
+        op::movi(0x10, 0xbb),               // second message byte
+        op::sb(RegId::HP, 0x10, 1),         // store above to the message buffer
+        op::movi(0x12, 2),                  // two bytes of data
+        op::smo(RegId::ZERO, RegId::HP, 0x12, RegId::ZERO),
+        op::smo(RegId::ZERO, RegId::HP, 0x12, RegId::ZERO),
+        end,
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_checked(Default::default());
+
+    let mut transactor = Transactor::new(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &ConsensusParameters::standard()),
+    );
+    transactor.transact(tx);
+    transactor
+}
+
+#[test]
+fn outgoing_messages__returns_the_messages_of_a_successful_script() {
+    // When
+    let transactor = transact_two_messages(op::ret(RegId::ONE));
+
+    // Then
+    let state = transactor.state_transition().expect("tx was executed");
+    let messages: Vec<_> = state.outgoing_messages().collect();
+    let receipts: Vec<_> = state
+        .receipts()
+        .iter()
+        .filter(|r| matches!(r, Receipt::MessageOut { .. }))
+        .collect();
+    assert_eq!(messages.len(), 2);
+    assert_ne!(messages[0].nonce, messages[1].nonce);
+    for (message, receipt) in messages.iter().zip(receipts) {
+        assert_eq!(message.data, [0xaa, 0xbb]);
+        assert_eq!(message.digest, Output::message_digest(&[0xaa, 0xbb]));
+        assert_eq!(Some(message.message_id()), receipt.message_id());
+    }
+    assert!(transactor.interpreter().outgoing_messages().eq(messages));
+}
+
+#[test]
+fn message_out__receipt_has_the_id_of_the_sent_message() {
+    // When
+    let transactor = transact_two_messages(op::ret(RegId::ONE));
+
+    // Then
+    let tx_id = transactor
+        .interpreter()
+        .transaction()
+        .id(&ChainId::default());
+    // The script reads both the sender and the recipient at the address zero, where
+    // the transaction id is.
+    let address = Address::new(*tx_id);
+    let receipts = transactor.receipts().expect("tx was executed");
+    for (idx, receipt) in receipts.iter().take(2).enumerate() {
+        let nonce = Output::message_nonce(&tx_id, idx as Word);
+        let expected =
+            Input::compute_message_id(&address, &address, &nonce, 0, &[0xaa, 0xbb]);
+        assert_eq!(receipt.message_id(), Some(expected));
+    }
+}
+
+#[test]
+fn outgoing_messages__excludes_the_messages_of_a_reverted_script() {
+    // When
+    let transactor = transact_two_messages(op::rvrt(RegId::ONE));
+
+    // Then
+    let state = transactor.state_transition().expect("tx was executed");
+    assert!(state.should_revert());
+    assert!(state
+        .receipts()
+        .iter()
+        .any(|r| matches!(r, Receipt::MessageOut { .. })));
+    assert_eq!(state.outgoing_messages().count(), 0);
+    assert_eq!(transactor.interpreter().outgoing_messages().count(), 0);
+}
+
 #[test]
 fn timestamp_works() {
     let mut client = MemoryClient::default();
@@ -0,0 +1,218 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    TransactionBuilder,
+};
+use fuel_types::canonical::Serialize;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::InterpreterParams,
+    prelude::*,
+    script_with_data_offset,
+    util::test_helpers::TestBuilder,
+};
+
+fn transactor(storage: MemoryStorage) -> Transactor<MemoryStorage, Script> {
+    Transactor::new(
+        storage,
+        InterpreterParams::new(0, &ConsensusParameters::standard()),
+    )
+}
+
+/// Builds a script running `script` with the id of `contract_id` at the start of the
+/// script data, pointed by `0x10`, and the contract in the inputs if `declared`.
+fn script_using_contract(
+    test_context: &mut TestBuilder,
+    contract_id: ContractId,
+    script: Vec<Instruction>,
+    declared: bool,
+) -> Checked<Script> {
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        [
+            vec![op::movi(0x10, data_offset as Immediate18)],
+            script.clone()
+        ]
+        .concat(),
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let builder = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000);
+    if declared {
+        builder
+            .contract_input(contract_id)
+            .fee_input()
+            .contract_output(&contract_id);
+    } else {
+        builder.fee_input();
+    }
+    builder.build()
+}
+
+fn assert_success(receipts: Option<&[Receipt]>) {
+    assert!(matches!(
+        receipts,
+        Some([
+            ..,
+            Receipt::ScriptResult {
+                result: ScriptExecutionResult::Success,
+                ..
+            }
+        ])
+    ));
+}
+
+#[test]
+fn code_cache__serves_the_calls_after_the_first_load() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(vec![op::ret(RegId::ONE)], None, None)
+        .contract_id;
+    let tx = script_using_contract(
+        &mut test_context,
+        contract_id,
+        vec![
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        true,
+    );
+    let cache = LruContractCodeCache::default();
+    let mut vm = transactor(test_context.get_storage().clone());
+    vm.with_code_cache(Arc::new(cache.clone()));
+
+    // When
+    vm.transact(tx.clone());
+
+    // Then
+    assert_success(vm.receipts());
+    let metrics = vm.interpreter().metrics();
+    assert_eq!(metrics.contract_code_loads, 1);
+    assert_eq!(metrics.contract_code_cache_hits, 2);
+    assert_eq!(cache.len(), 1);
+
+    // When
+    vm.transact(tx);
+
+    // Then
+    assert_success(vm.receipts());
+    let metrics = vm.interpreter().metrics();
+    assert_eq!(metrics.contract_code_loads, 0);
+    assert_eq!(metrics.contract_code_cache_hits, 3);
+}
+
+#[test]
+fn code_cache__serves_ldc_ccp_and_csiz() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract_id = test_context
+        .setup_contract(vec![op::noop(), op::ret(RegId::ONE)], None, None)
+        .contract_id;
+    let tx = script_using_contract(
+        &mut test_context,
+        contract_id,
+        vec![
+            op::csiz(0x11, 0x10),
+            op::aloc(0x11),
+            op::ccp(RegId::HP, 0x10, RegId::ZERO, 0x11),
+            op::ldc(0x10, RegId::ZERO, 0x11),
+            op::ret(RegId::ONE),
+        ],
+        true,
+    );
+    let mut vm = transactor(test_context.get_storage().clone());
+    vm.with_code_cache(Arc::new(LruContractCodeCache::default()));
+    vm.transact(tx.clone());
+    assert_success(vm.receipts());
+
+    // When
+    vm.transact(tx);
+
+    // Then
+    assert_success(vm.receipts());
+    let metrics = vm.interpreter().metrics();
+    assert_eq!(metrics.contract_code_loads, 0);
+    assert_eq!(metrics.contract_code_cache_hits, 3);
+}
+
+#[test]
+fn code_cache__is_not_used_for_a_contract_rolled_back_from_the_storage() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut test_context = TestBuilder::new(2322u64);
+    let salt: Salt = rng.gen();
+    let program: Witness = op::ret(RegId::ONE).to_bytes().to_vec().into();
+    let contract = Contract::from(program.as_ref());
+    let storage_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &storage_root);
+    let create = TransactionBuilder::create(program, salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, storage_root))
+        .finalize_checked(test_context.get_block_height());
+    let tx = script_using_contract(
+        &mut test_context,
+        contract_id,
+        vec![
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        true,
+    );
+    let undeclared_tx = script_using_contract(
+        &mut test_context,
+        contract_id,
+        vec![
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        false,
+    );
+    let cache = LruContractCodeCache::default();
+    let mut vm = transactor(test_context.get_storage().clone());
+    vm.with_code_cache(Arc::new(cache.clone()));
+    vm.deploy(create).expect("failed to deploy the contract");
+    vm.transact(tx);
+    assert_success(vm.receipts());
+    assert_eq!(cache.len(), 1);
+
+    // When
+    vm.as_mut().rollback();
+    // The contract isn't declared, since the declared contracts must exist in the
+    // storage before the script runs
+    vm.allow_undeclared_contracts(true);
+    let dry_run = vm.dry_run(undeclared_tx).expect("failed to dry run");
+
+    // Then
+    assert!(matches!(
+        dry_run.receipts(),
+        [
+            Receipt::Panic {
+                reason,
+                ..
+            },
+            ..
+        ] if *reason.reason() == PanicReason::ContractNotFound
+    ));
+}
@@ -2,16 +2,22 @@
 
 use fuel_asm::{
     op,
+    GTFArgs,
+    Instruction,
     RegId,
 };
 use fuel_tx::{
+    ConsensusParameters,
     ScriptExecutionResult,
     TransactionBuilder,
 };
 
 use fuel_vm::{
     consts::*,
+    interpreter::InterpreterParams,
     prelude::*,
+    script_with_data_offset,
+    util::test_helpers::TestBuilder,
 };
 use rand::{
     rngs::StdRng,
@@ -80,6 +86,7 @@ fn code_coverage() {
             .with_profiler(output.clone())
             .build()
             .into(),
+        ConsensusParameters::standard(),
     );
 
     let receipts = client.transact(tx_script);
@@ -107,3 +114,99 @@ fn code_coverage() {
         );
     }
 }
+
+fn branching_script_run(collector: &CoverageCollector, flag: Word) {
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::lw(0x10, 0x10, 0),
+        op::jnei(0x10, RegId::ZERO, 4), // Skip the next instruction if the flag is set
+        op::noop(),
+        op::ret(RegId::ONE),
+    ];
+
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(script, flag.to_be_bytes().to_vec())
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+
+    let mut vm = Interpreter::<_, Script>::with_memory_storage();
+    vm.set_coverage_receiver(collector.clone());
+    let mut transactor = Transactor::from(vm);
+    assert_success(transactor.transact(tx).receipts());
+}
+
+fn assert_success(receipts: Option<&[Receipt]>) {
+    assert!(matches!(
+        receipts.and_then(|r| r.last()),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn coverage_receiver_records_union_of_hit_pcs() {
+    let collector = CoverageCollector::default();
+
+    // The noop is skipped when the flag is set
+    branching_script_run(&collector, 1);
+    assert_eq!(collector.hit_pcs(None), vec![0, 4, 8, 16]);
+
+    branching_script_run(&collector, 0);
+    assert_eq!(collector.hit_pcs(None), vec![0, 4, 8, 12, 16]);
+    assert_eq!(collector.hits(None, 8), 2);
+    assert_eq!(collector.hits(None, 12), 1);
+
+    assert_eq!(
+        collector.to_lcov_like_report(),
+        "SF:script\nDA:0,2\nDA:4,2\nDA:8,2\nDA:12,1\nDA:16,2\nend_of_record\n"
+    );
+}
+
+#[test]
+fn coverage_receiver_attributes_loaded_code_to_its_contract() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let loaded = vec![
+        op::addi(0x13, RegId::ZERO, 1),
+        op::noop(),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context.setup_contract(loaded, None, None).contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::movi(0x11, 3 * Instruction::SIZE as Immediate18),
+            op::ldc(0x10, RegId::ZERO, 0x11),
+            // Jump to the start of the loaded code
+            op::subi(0x12, RegId::SSP, 2 * WORD_SIZE as Immediate12),
+            op::sub(0x12, 0x12, RegId::IS),
+            op::divi(0x12, 0x12, Instruction::SIZE as Immediate12),
+            op::jmp(0x12),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let tx = test_context
+        .start_script(script, contract_id.to_vec())
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    let collector = CoverageCollector::default();
+    let mut vm = Interpreter::<_, Script>::with_storage(
+        test_context.get_storage().clone(),
+        InterpreterParams::new(0, &ConsensusParameters::standard()),
+    );
+    vm.set_coverage_receiver(collector.clone());
+    let mut transactor = Transactor::from(vm);
+    assert_success(transactor.transact(tx).receipts());
+
+    assert_eq!(collector.hit_pcs(None), vec![0, 4, 8, 12, 16, 20, 24]);
+    assert_eq!(collector.hit_pcs(Some(contract_id)), vec![0, 4, 8]);
+}
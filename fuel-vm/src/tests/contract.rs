@@ -649,3 +649,88 @@ fn internal_transfer_cant_exceed_more_than_source_contract_balance() {
         test_context.get_contract_balance(&sender_contract_id, &asset_id);
     assert_eq!(source_balance, initial_internal_balance);
 }
+
+/// Executes a script calling a contract minting 50 of the asset with `sub_id` 7,
+/// which returns if `revert` is `false` and reverts otherwise.
+fn execute_mint(revert: bool) -> (TestBuilder, ContractId, Bytes32, Vec<Receipt>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let mut sub_id = Bytes32::zeroed();
+    sub_id[Bytes32::LEN - 1] = 7;
+
+    let program = vec![
+        op::movi(0x10, Bytes32::LEN as u32),
+        op::aloc(0x10),
+        op::movi(0x10, 7),
+        op::sw(RegId::HP, 0x10, 3),
+        op::movi(0x11, 50),
+        op::mint(0x11, RegId::HP),
+        if revert {
+            op::rvrt(RegId::ONE)
+        } else {
+            op::ret(RegId::ONE)
+        },
+    ];
+    let contract_id = test_context.setup_contract(program, None, None).contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let receipts = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute()
+        .receipts()
+        .to_vec();
+
+    (test_context, contract_id, sub_id, receipts)
+}
+
+#[test]
+fn mint_emits_a_receipt_with_the_asset_sub_id() {
+    let (mut test_context, contract_id, sub_id, receipts) = execute_mint(false);
+
+    let mints: Vec<_> = receipts
+        .iter()
+        .filter(|r| matches!(r, Receipt::Mint { .. }))
+        .collect();
+    assert_eq!(mints.len(), 1);
+    assert!(matches!(
+        mints[0],
+        Receipt::Mint { sub_id: s, contract_id: c, val: 50, .. }
+            if *s == sub_id && *c == contract_id
+    ));
+
+    let asset_id = contract_id.asset_id(&sub_id);
+    assert_eq!(
+        test_context.get_contract_balance(&contract_id, &asset_id),
+        50
+    );
+}
+
+#[test]
+fn mint_receipt_is_kept_when_the_call_reverts() {
+    let (mut test_context, contract_id, sub_id, receipts) = execute_mint(true);
+
+    assert!(receipts.iter().any(|r| matches!(
+        r,
+        Receipt::Mint { sub_id: s, val: 50, .. } if *s == sub_id
+    )));
+    assert!(receipts.iter().any(|r| matches!(r, Receipt::Revert { .. })));
+
+    let asset_id = contract_id.asset_id(&sub_id);
+    assert_eq!(
+        test_context.get_contract_balance(&contract_id, &asset_id),
+        0
+    );
+}
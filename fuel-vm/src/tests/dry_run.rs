@@ -0,0 +1,147 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    ConsensusParameters,
+    Receipt,
+};
+use fuel_types::canonical::Serialize;
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::{
+        InterpreterParams,
+        NotSupportedEcal,
+    },
+    prelude::*,
+    script_with_data_offset,
+    storage::{
+        ContractsStateData,
+        ContractsStateKey,
+    },
+    util::test_helpers::TestBuilder,
+};
+
+/// Deploys a contract writing the state slot with the key `mem[0, 32]`, and builds
+/// a script calling it.
+fn state_writing_call() -> (TestBuilder, ContractId, Checked<Script>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract = vec![
+        op::movi(0x10, 42),
+        op::sww(RegId::ZERO, 0x11, 0x10),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    (test_context, contract_id, tx)
+}
+
+fn contract_state(
+    storage: &MemoryStorage,
+) -> Vec<(ContractsStateKey, ContractsStateData)> {
+    storage
+        .all_contract_state()
+        .map(|(k, v)| (*k, v.clone()))
+        .collect()
+}
+
+#[test]
+fn dry_run__produces_receipts_without_modifying_storage() {
+    // Given
+    let (test_context, contract_id, tx) = state_writing_call();
+    let storage = test_context.get_storage().clone();
+    let state_before = contract_state(&storage);
+    let consensus_params = ConsensusParameters::standard();
+    let mut client = MemoryClient::<NotSupportedEcal>::new(
+        storage,
+        InterpreterParams::new(0, &consensus_params),
+        consensus_params,
+    );
+
+    // When
+    let dry_run = client.dry_run(tx).expect("Expected the tx to be executed");
+
+    // Then
+    assert!(matches!(
+        dry_run.transition().state(),
+        ProgramState::Return(1)
+    ));
+    assert!(dry_run
+        .receipts()
+        .iter()
+        .any(|r| matches!(r, Receipt::Call { to, .. } if to == &contract_id)));
+
+    let changes: Vec<_> = dry_run.changes().contract_state().iter().collect();
+    assert_eq!(changes.len(), 1);
+    let (key, value) = changes[0];
+    assert_eq!(key.contract_id(), &contract_id);
+    let mut expected = [0u8; 32];
+    expected[..8].copy_from_slice(&42u64.to_be_bytes());
+    assert_eq!(value.as_ref().map(|v| v.as_ref()), Some(&expected[..]));
+
+    assert_eq!(contract_state(client.as_ref()), state_before);
+}
+
+#[test]
+fn dry_run__committed_writes_equal_direct_execution() {
+    // Given
+    let (mut test_context, _, tx) = state_writing_call();
+    let consensus_params = ConsensusParameters::standard();
+    let ready_tx = tx
+        .clone()
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap();
+    let mut storage = test_context.get_storage().clone();
+
+    // When
+    let mut transactional = TransactionalStorage::new(&mut storage);
+    let mut vm = Interpreter::<_, Script>::with_storage(
+        &mut transactional,
+        InterpreterParams::new(0, &consensus_params),
+    );
+    vm.transact(ready_tx)
+        .expect("Expected the tx to be executed");
+    transactional.commit().unwrap();
+
+    // Then
+    test_context
+        .execute_tx(tx)
+        .expect("Expected the tx to be executed");
+    assert!(!contract_state(&storage).is_empty());
+    assert_eq!(
+        contract_state(&storage),
+        contract_state(test_context.get_storage())
+    );
+}
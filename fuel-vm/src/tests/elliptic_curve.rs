@@ -0,0 +1,340 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use fuel_asm::{
+    op,
+    GTFArgs,
+    RegId,
+};
+use fuel_types::Bytes32;
+
+use crate::{
+    interpreter::InterpreterParams,
+    prelude::*,
+    tests::test_helpers::assert_panics,
+    util::test_helpers::TestBuilder,
+};
+
+fn field(hex: &str) -> [u8; 32] {
+    *Bytes32::from_str(hex).expect("A valid field element")
+}
+
+fn small(value: u8) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    bytes[31] = value;
+    bytes
+}
+
+/// The generator of G1.
+fn g1() -> Vec<u8> {
+    [small(1), small(2)].concat()
+}
+
+/// The negation of the generator of G1.
+fn minus_g1() -> Vec<u8> {
+    [
+        small(1),
+        field("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45"),
+    ]
+    .concat()
+}
+
+/// Twice the generator of G1.
+fn two_g1() -> Vec<u8> {
+    [
+        field("0x030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3"),
+        field("0x15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4"),
+    ]
+    .concat()
+}
+
+/// The generator of G2, the imaginary part of each coordinate first.
+fn g2() -> Vec<u8> {
+    [
+        field("0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+        field("0x1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+        field("0x090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+        field("0x12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+    ]
+    .concat()
+}
+
+/// The pairs of `e(G1, G2) * e(-G1, G2) = 1`.
+pub(super) fn valid_pairing() -> Vec<u8> {
+    [g1(), g2(), minus_g1(), g2()].concat()
+}
+
+/// Runs `script` with `data` in the script data, pointed by `0x10`.
+fn run_with_data(data: Vec<u8>, script: Vec<Instruction>) -> Vec<Receipt> {
+    let script = [
+        vec![op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData)],
+        script,
+    ]
+    .concat();
+    TestBuilder::new(2322u64)
+        .start_script(script, data)
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .execute()
+        .receipts()
+        .to_vec()
+}
+
+/// Runs `ECOP` with `operation` on `input`, and returns the logged result.
+fn ecop(operation: u16, input: Vec<u8>) -> Vec<Receipt> {
+    run_with_data(
+        input,
+        vec![
+            op::movi(0x11, 64),
+            op::aloc(0x11),
+            op::movi(0x12, operation as u32),
+            op::ecop(RegId::HP, RegId::ZERO, 0x12, 0x10),
+            op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x11),
+            op::ret(RegId::ONE),
+        ],
+    )
+}
+
+/// Runs `EPAR` on the `pairs` of `input`, and returns the logged result.
+fn epar(pairs: u32, input: Vec<u8>) -> Vec<Receipt> {
+    run_with_data(
+        input,
+        vec![
+            op::movi(0x11, pairs),
+            op::epar(0x12, RegId::ZERO, 0x11, 0x10),
+            op::log(0x12, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+            op::ret(RegId::ONE),
+        ],
+    )
+}
+
+/// Runs `script` with a memory limit of `memory_limit` bytes.
+fn run_with_memory_limit(memory_limit: u64, script: Vec<Instruction>) -> Vec<Receipt> {
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let interpreter_params = InterpreterParams {
+        memory_limit,
+        ..InterpreterParams::new(0, &ConsensusParameters::standard())
+    };
+    let mut vm = Transactor::<_, _>::new(MemoryStorage::default(), interpreter_params);
+    vm.transact(tx);
+    vm.receipts().expect("expected receipts").to_vec()
+}
+
+fn logged_word(receipts: &[Receipt]) -> Word {
+    let Receipt::Log { ra, .. } = receipts[0] else {
+        panic!("Expected a log receipt, got {:?}", receipts[0]);
+    };
+    ra
+}
+
+fn gas_used(receipts: &[Receipt]) -> Word {
+    let Some(Receipt::ScriptResult { gas_used, .. }) = receipts.last() else {
+        panic!(
+            "Expected a script result receipt, got {:?}",
+            receipts.last()
+        );
+    };
+    *gas_used
+}
+
+#[test]
+fn ecop__adds_points() {
+    // When
+    let receipts = ecop(0, [g1(), g1()].concat());
+
+    // Then
+    assert_eq!(receipts[0].data(), Some(&two_g1()[..]));
+}
+
+#[test]
+fn ecop__adds_the_point_at_infinity() {
+    // When
+    let receipts = ecop(0, [g1(), vec![0; 64]].concat());
+
+    // Then
+    assert_eq!(receipts[0].data(), Some(&g1()[..]));
+}
+
+#[test]
+fn ecop__multiplies_a_point_by_a_scalar() {
+    // When
+    let receipts = ecop(1, [g1(), small(2).to_vec()].concat());
+
+    // Then
+    assert_eq!(receipts[0].data(), Some(&two_g1()[..]));
+}
+
+#[test]
+fn ecop__multiplies_a_point_by_zero_into_the_point_at_infinity() {
+    // When
+    let receipts = ecop(1, [g1(), vec![0; 32]].concat());
+
+    // Then
+    assert_eq!(receipts[0].data(), Some(&[0; 64][..]));
+}
+
+#[test]
+fn ecop__panics_when_the_point_is_not_on_the_curve() {
+    // When
+    let receipts = ecop(0, [g1(), small(1).to_vec(), small(3).to_vec()].concat());
+
+    // Then
+    assert_panics(&receipts, PanicReason::InvalidEllipticCurvePoint);
+}
+
+#[test]
+fn ecop__panics_when_the_operation_is_unsupported() {
+    // When
+    let receipts = ecop(2, [g1(), g1()].concat());
+
+    // Then
+    assert_panics(&receipts, PanicReason::UnsupportedOperationType);
+}
+
+#[test]
+fn ecop__panics_when_the_curve_is_unsupported() {
+    // When
+    let receipts = run_with_data(
+        [g1(), g1()].concat(),
+        vec![
+            op::movi(0x11, 64),
+            op::aloc(0x11),
+            op::ecop(RegId::HP, RegId::ONE, RegId::ZERO, 0x10),
+        ],
+    );
+
+    // Then
+    assert_panics(&receipts, PanicReason::UnsupportedCurveId);
+}
+
+#[test]
+fn epar__returns_one_when_the_pairing_equation_holds() {
+    // When
+    let receipts = epar(2, valid_pairing());
+
+    // Then
+    assert_eq!(logged_word(&receipts), 1);
+}
+
+#[test]
+fn epar__returns_zero_when_the_pairing_equation_does_not_hold() {
+    // When
+    let receipts = epar(2, [g1(), g2(), g1(), g2()].concat());
+
+    // Then
+    assert_eq!(logged_word(&receipts), 0);
+}
+
+#[test]
+fn epar__returns_one_without_pairs() {
+    // When
+    let receipts = epar(0, vec![]);
+
+    // Then
+    assert_eq!(logged_word(&receipts), 1);
+}
+
+#[test]
+fn epar__panics_when_a_point_is_not_on_the_curve() {
+    // Given
+    let mut input = valid_pairing();
+    input[63] ^= 1;
+
+    // When
+    let receipts = epar(2, input);
+
+    // Then
+    assert_panics(&receipts, PanicReason::InvalidEllipticCurvePoint);
+}
+
+#[test]
+fn epar__gas_scales_with_the_number_of_pairs() {
+    // Given
+    let epar_cost = TestBuilder::new(2322u64).get_gas_costs().epar;
+
+    // When
+    let one_pair = gas_used(&epar(1, [g1(), g2()].concat()));
+    let two_pairs = gas_used(&epar(2, valid_pairing()));
+
+    // Then
+    assert_eq!(
+        two_pairs - one_pair,
+        epar_cost.resolve(2) - epar_cost.resolve(1)
+    );
+}
+
+#[test]
+fn ecop__multiplication_is_charged_per_bit_of_the_scalar() {
+    // Given
+    let ecop_cost = TestBuilder::new(2322u64).get_gas_costs().ecop;
+
+    // When
+    let addition = gas_used(&ecop(0, [g1(), g1()].concat()));
+    let multiplication = gas_used(&ecop(1, [g1(), small(2).to_vec()].concat()));
+
+    // Then
+    assert_eq!(
+        multiplication - addition,
+        ecop_cost.resolve(256) - ecop_cost.resolve(1)
+    );
+}
+
+#[test]
+fn epar__panics_on_a_reserved_register_before_checking_the_curve() {
+    // When
+    let receipts = run_with_data(
+        vec![],
+        vec![op::epar(RegId::ZERO, RegId::ONE, RegId::ZERO, 0x10)],
+    );
+
+    // Then
+    assert_panics(&receipts, PanicReason::ReservedRegisterNotWritable);
+}
+
+#[test]
+fn ecop__panics_when_the_points_are_above_the_memory_limit() {
+    // Given
+    let memory_limit = 128 * 1024;
+
+    // When
+    let receipts = run_with_memory_limit(
+        memory_limit,
+        vec![
+            op::movi(0x10, memory_limit as u32),
+            op::movi(0x11, 64),
+            op::aloc(0x11),
+            op::ecop(RegId::HP, RegId::ZERO, RegId::ZERO, 0x10),
+        ],
+    );
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
+
+#[test]
+fn epar__panics_when_the_pairs_are_above_the_memory_limit() {
+    // Given
+    let memory_limit = 128 * 1024;
+
+    // When
+    let receipts = run_with_memory_limit(
+        memory_limit,
+        vec![
+            op::movi(0x10, memory_limit as u32),
+            op::epar(0x12, RegId::ZERO, RegId::ONE, 0x10),
+        ],
+    );
+
+    // Then
+    assert_panics(&receipts, PanicReason::MemoryOverflow);
+}
@@ -1,7 +1,14 @@
-use alloc::vec;
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
 use fuel_asm::{
     op,
     GTFArgs,
+    Instruction,
+    PanicReason,
     RegId,
     Word,
 };
@@ -25,8 +32,8 @@ use itertools::Itertools;
 pub struct NoopEcal;
 
 impl ::fuel_vm::interpreter::EcalHandler for NoopEcal {
-    fn ecal<S, Tx>(
-        vm: &mut ::fuel_vm::prelude::Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        vm: &mut ::fuel_vm::prelude::Interpreter<S, Tx, Self, Observer>,
         _: RegId,
         _: RegId,
         _: RegId,
@@ -45,11 +52,12 @@ fn noop_ecal() {
     .into_iter()
     .collect();
 
+    let consensus_params = ConsensusParameters::standard();
     let mut client = MemoryClient::<NoopEcal>::new(
         fuel_vm::prelude::MemoryStorage::default(),
         Default::default(),
+        consensus_params.clone(),
     );
-    let consensus_params = ConsensusParameters::standard();
     let tx = TransactionBuilder::script(script, vec![])
         .script_gas_limit(1_000_000)
         .maturity(Default::default())
@@ -72,8 +80,8 @@ pub struct SumProdEcal;
 impl ::fuel_vm::interpreter::EcalHandler for SumProdEcal {
     /// This ecal fn computes saturating sum and product of inputs (a,b,c,d),
     /// and stores them in a and b respectively. It charges only a single gas.
-    fn ecal<S, Tx>(
-        vm: &mut ::fuel_vm::prelude::Interpreter<S, Tx, Self>,
+    fn ecal<S, Tx, Observer>(
+        vm: &mut ::fuel_vm::prelude::Interpreter<S, Tx, Self, Observer>,
         a: RegId,
         b: RegId,
         c: RegId,
@@ -124,8 +132,8 @@ fn provide_ecal_fn() {
     .into_iter()
     .collect();
 
-    let mut client = MemoryClient::from_txtor(vm.into());
     let consensus_params = ConsensusParameters::standard();
+    let mut client = MemoryClient::from_txtor(vm.into(), consensus_params.clone());
     let tx = TransactionBuilder::script(script, script_data)
         .script_gas_limit(1_000_000)
         .maturity(Default::default())
@@ -143,3 +151,79 @@ fn provide_ecal_fn() {
     assert_eq!(*ra, 2 + 3 + 4 + 5);
     assert_eq!(*rb, 2 * 3 * 4 * 5);
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SumToMemoryEcal;
+
+impl ::fuel_vm::interpreter::EcalHandler for SumToMemoryEcal {
+    /// Writes the wrapping sum of `b` and `c` to the memory at address `a`.
+    fn ecal<S, Tx, Observer>(
+        vm: &mut ::fuel_vm::prelude::Interpreter<S, Tx, Self, Observer>,
+        a: RegId,
+        b: RegId,
+        c: RegId,
+        _: RegId,
+    ) -> ::fuel_vm::error::SimpleResult<()> {
+        vm.gas_charge(vm.gas_costs().noop)?;
+
+        let addr = vm.registers()[a];
+        let sum = vm.registers()[b].wrapping_add(vm.registers()[c]);
+        vm.write_memory(addr, &sum.to_be_bytes())
+    }
+}
+
+fn run_sum_to_memory_script(script: Vec<Instruction>) -> Vec<Receipt> {
+    let vm: Interpreter<_, Script, SumToMemoryEcal> = Interpreter::with_memory_storage();
+    let consensus_params = ConsensusParameters::standard();
+    let mut client = MemoryClient::from_txtor(vm.into(), consensus_params.clone());
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .maturity(Default::default())
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate a checked tx");
+    client.transact(tx).to_vec()
+}
+
+#[test]
+fn ecal_handler__writes_to_memory_observed_by_script() {
+    // Given
+    let script = vec![
+        op::movi(0x10, 8),
+        op::aloc(0x10),
+        op::movi(0x11, 20),
+        op::movi(0x12, 22),
+        // When
+        op::ecal(RegId::HP, 0x11, 0x12, RegId::ZERO),
+        op::lw(0x13, RegId::HP, 0),
+        op::log(0x13, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let receipts = run_sum_to_memory_script(script);
+
+    // Then
+    let Receipt::Log { ra, .. } = receipts.first().unwrap() else {
+        panic!("Expected a log receipt");
+    };
+    assert_eq!(*ra, 42);
+}
+
+#[test]
+fn ecal_handler__cannot_write_to_memory_not_owned() {
+    // Given
+    let script = vec![
+        op::movi(0x11, 20),
+        op::movi(0x12, 22),
+        // When
+        op::ecal(RegId::ZERO, 0x11, 0x12, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let receipts = run_sum_to_memory_script(script);
+
+    // Then
+    let Receipt::Panic { reason, .. } = receipts.first().unwrap() else {
+        panic!("Expected a panic receipt");
+    };
+    assert_eq!(*reason.reason(), PanicReason::MemoryOwnership);
+}
@@ -6,6 +6,7 @@ use alloc::{
 
 use crate::{
     consts::*,
+    interpreter::InterpreterParams,
     prelude::*,
     script_with_data_offset,
     util::test_helpers::TestBuilder,
@@ -315,7 +316,7 @@ fn repeated_nested_calls() {
     if let Receipt::Panic { reason: pr, .. } = receipts[receipts.len() - 2] {
         assert_eq!(
             *pr.reason(),
-            PanicReason::OutOfGas,
+            PanicReason::CallDepthExceeded,
             "Panic reason differs for the expected reason"
         );
     } else {
@@ -516,3 +517,226 @@ fn logd_from_top_of_heap() {
     assert_eq!(receipts.len(), 3);
     assert!(matches!(receipts[0], Receipt::LogData { .. }));
 }
+
+/// Deploys `contract` and executes a script calling it `calls` times in a row, with
+/// the call depth limited to `max_call_depth`.
+fn execute_calls(
+    contract: Vec<Instruction>,
+    calls: usize,
+    max_call_depth: Word,
+) -> (ContractId, Vec<Receipt>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    test_context.with_contract_params(
+        ContractParameters::DEFAULT.with_max_call_depth(max_call_depth),
+    );
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+
+    let mut script = vec![op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData)];
+    script.extend(vec![op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS); calls]);
+    script.push(op::ret(RegId::ONE));
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    let result = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .execute();
+
+    (contract_id, result.receipts().to_vec())
+}
+
+fn logged_call_depths(receipts: &[Receipt]) -> Vec<Word> {
+    receipts
+        .iter()
+        .filter_map(|r| match r {
+            Receipt::Log { ra, .. } => Some(*ra),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn recursive_calls_stop_at_the_max_call_depth() {
+    const MAX_CALL_DEPTH: Word = 5;
+
+    // Logs the remaining call depth and calls itself, with `$r16` still pointing to
+    // the call parameters of the script.
+    let contract = vec![
+        op::gm_args(0x11, GMArgs::GetRemainingCallDepth),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+
+    let (contract_id, receipts) = execute_calls(contract, 1, MAX_CALL_DEPTH);
+
+    let calls = receipts
+        .iter()
+        .filter(|r| matches!(r, Receipt::Call { .. }))
+        .count();
+    assert_eq!(calls, MAX_CALL_DEPTH as usize);
+    assert_eq!(logged_call_depths(&receipts), vec![4, 3, 2, 1, 0]);
+
+    let Receipt::Panic { id, reason, .. } = &receipts[receipts.len() - 2] else {
+        panic!("Expected a panic receipt, got {receipts:?}");
+    };
+    assert_eq!(*reason.reason(), PanicReason::CallDepthExceeded);
+    assert_eq!(id, &contract_id);
+}
+
+#[test]
+fn call_depth_resets_between_top_level_calls() {
+    let contract = vec![
+        op::gm_args(0x11, GMArgs::GetRemainingCallDepth),
+        op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+
+    let (_, receipts) = execute_calls(contract, 3, 1);
+
+    assert_eq!(logged_call_depths(&receipts), vec![0, 0, 0]);
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn infinite_loop_stops_at_the_max_instructions() {
+    const MAX_INSTRUCTIONS: u64 = 1000;
+
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(vec![op::ji(0)], vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let params = InterpreterParams {
+        gas_costs: GasCosts::free(),
+        max_instructions: Some(MAX_INSTRUCTIONS),
+        ..Default::default()
+    };
+    let mut transactor = Transactor::<_, _>::new(MemoryStorage::default(), params);
+
+    transactor.transact(tx);
+
+    let receipts = transactor.receipts().expect("The transaction was executed");
+    assert!(matches!(
+        receipts.first(),
+        Some(Receipt::Panic { reason, .. })
+            if *reason.reason() == PanicReason::InstructionLimitExceeded
+    ));
+    assert_eq!(
+        transactor.interpreter().instructions_executed(),
+        MAX_INSTRUCTIONS
+    );
+}
+
+#[test]
+fn instructions_are_not_limited_by_default() {
+    let mut test_context = TestBuilder::new(2322u64);
+    let mut script = vec![op::movi(0x10, 2000)];
+    script.extend([op::subi(0x10, 0x10, 1), op::jnzb(0x10, RegId::ZERO, 0)]);
+    script.push(op::ret(RegId::ONE));
+    let tx = test_context
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let mut transactor =
+        Transactor::<_, _>::new(MemoryStorage::default(), InterpreterParams::default());
+
+    transactor.transact(tx);
+
+    assert!(matches!(
+        transactor.receipts().and_then(|r| r.last()),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        })
+    ));
+    assert_eq!(transactor.interpreter().instructions_executed(), 4002);
+}
+
+#[test]
+fn call_gas_breakdown_attributes_the_gas_to_the_innermost_call() {
+    // Given
+    let mut test_context = TestBuilder::new(2322u64);
+    let call_params_len = Call::new(ContractId::zeroed(), 0, 0).to_bytes().len();
+    // Calls the contract at the `offset` call parameters of the script data,
+    // forwarding `gas`.
+    let calling = |offset: usize, gas: u32| {
+        vec![
+            op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+            op::addi(0x10, 0x10, (offset * call_params_len) as u16),
+            op::movi(0x11, gas),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, 0x11),
+            op::ret(RegId::ONE),
+        ]
+    };
+    let c = test_context
+        .setup_contract(vec![op::ret(RegId::ONE)], None, None)
+        .contract_id;
+    let b = test_context
+        .setup_contract(calling(2, 10), None, None)
+        .contract_id;
+    let a = test_context
+        .setup_contract(calling(1, 100), None, None)
+        .contract_id;
+    let script_data: Vec<u8> = [a, b, c]
+        .into_iter()
+        .flat_map(|id| Call::new(id, 0, 0).to_bytes())
+        .collect();
+    let tx = test_context
+        .start_script(calling(0, 1000), script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(a)
+        .contract_input(b)
+        .contract_input(c)
+        .fee_input()
+        .contract_output(&a)
+        .contract_output(&b)
+        .contract_output(&c)
+        .build();
+    let params = InterpreterParams {
+        gas_costs: GasCosts::unit(),
+        ..InterpreterParams::new(0, &ConsensusParameters::standard())
+    };
+    let mut transactor =
+        Transactor::<_, _>::new(test_context.get_storage().clone(), params);
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    assert!(transactor.is_success());
+    // Every instruction costs 1, and each `CALL` also pays 40 for the new balance
+    // entry of the called contract, charged to the caller.
+    let breakdown = transactor.interpreter().call_gas_breakdown();
+    let expected = vec![CallGas {
+        contract_id: a,
+        gas_forwarded: 1000,
+        gas_consumed: 5 + 40,
+        calls: vec![CallGas {
+            contract_id: b,
+            gas_forwarded: 100,
+            gas_consumed: 5 + 40,
+            calls: vec![CallGas {
+                contract_id: c,
+                gas_forwarded: 10,
+                gas_consumed: 1,
+                calls: vec![],
+            }],
+        }],
+    }];
+    assert_eq!(breakdown, expected);
+    assert_eq!(breakdown[0].total_gas_consumed(), 91);
+    assert_eq!(transactor.script_gas_used(), Some(5 + 40 + 91));
+}
@@ -27,7 +27,9 @@ fn gas_factor_rounds_correctly() {
     let large_max_fee_limit = input;
 
     let gas_costs = GasCosts::default();
-    let fee_params = FeeParameters::default().with_gas_price_factor(factor as Word);
+    let fee_params = FeeParameters::default()
+        .with_gas_price_factor(factor as Word)
+        .expect("The gas price factor is not zero");
 
     // Random script to consume some gas
     let script = iter::repeat(op::add(0x10, 0x00, 0x01))
@@ -49,11 +51,9 @@ fn gas_factor_rounds_correctly() {
 
     let profiler = GasProfiler::default();
 
-    let consensus_params = ConsensusParameters {
-        fee_params,
-        gas_costs,
-        ..ConsensusParameters::standard()
-    };
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_fee_params(fee_params);
+    consensus_params.set_gas_costs(gas_costs);
 
     let interpreter_params = InterpreterParams::new(gas_price, &consensus_params);
     let storage = MemoryStorage::default();
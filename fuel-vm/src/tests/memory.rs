@@ -1,3 +1,4 @@
+#![allow(non_snake_case)]
 #![cfg(feature = "std")]
 
 use fuel_asm::PanicReason;
@@ -9,8 +10,10 @@ use fuel_asm::{
 };
 use fuel_tx::Receipt;
 use fuel_vm::{
+    checked_transaction::Checked,
     consts::VM_MAX_RAM,
     interpreter::InterpreterParams,
+    pool::MemoryPool,
     prelude::*,
 };
 
@@ -22,6 +25,13 @@ use super::test_helpers::{
 use fuel_tx::ConsensusParameters;
 
 fn setup(program: Vec<Instruction>) -> Transactor<MemoryStorage, Script> {
+    setup_with_memory_limit(program, VM_MAX_RAM)
+}
+
+fn setup_with_memory_limit(
+    program: Vec<Instruction>,
+    memory_limit: u64,
+) -> Transactor<MemoryStorage, Script> {
     let storage = MemoryStorage::default();
 
     let gas_price = 0;
@@ -41,7 +51,10 @@ fn setup(program: Vec<Instruction>) -> Transactor<MemoryStorage, Script> {
         .into_checked(height, &consensus_params)
         .expect("failed to check tx");
 
-    let interpreter_params = InterpreterParams::new(gas_price, &consensus_params);
+    let interpreter_params = InterpreterParams {
+        memory_limit,
+        ..InterpreterParams::new(gas_price, &consensus_params)
+    };
 
     let mut vm = Transactor::new(storage, interpreter_params);
     vm.transact(tx);
@@ -158,6 +171,117 @@ fn test_stack_and_heap_cannot_overlap(offset: u64, cause_error: bool) {
     }
 }
 
+const KIB: u64 = 1024;
+const MIB: u64 = 1024 * KIB;
+
+#[test_case(op::aloc(0x10), 64 * KIB => Some(PanicReason::MemoryOverflow); "aloc with 64 KiB")]
+#[test_case(op::aloc(0x10), 64 * MIB => None; "aloc with 64 MiB")]
+#[test_case(op::cfe(0x10), 64 * KIB => Some(PanicReason::MemoryOverflow); "cfe with 64 KiB")]
+#[test_case(op::cfe(0x10), 64 * MIB => None; "cfe with 64 MiB")]
+fn memory_limit__is_respected_by_stack_and_heap_growth(
+    grow: Instruction,
+    memory_limit: u64,
+) -> Option<PanicReason> {
+    // Given
+    let ops = vec![
+        op::movi(0x10, (128 * KIB).try_into().unwrap()),
+        grow,
+        op::ret(RegId::ONE),
+    ];
+
+    // When
+    let vm = setup_with_memory_limit(ops, memory_limit);
+
+    // Then
+    let receipts = vm.receipts().unwrap();
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    })
+}
+
+#[test]
+fn memory_limit__sets_initial_heap_pointer() {
+    // Given
+    let memory_limit = 64 * KIB;
+    let ops = vec![op::ret(RegId::HP)];
+
+    // When
+    let vm = setup_with_memory_limit(ops, memory_limit);
+
+    // Then
+    let receipts = vm.receipts().unwrap();
+    assert!(
+        matches!(receipts.first(), Some(Receipt::Return { val, .. }) if *val == memory_limit)
+    );
+}
+
+#[test]
+fn memory_limit__fails_initialization_when_transaction_does_not_fit() {
+    // Given
+    let memory_limit = 64;
+    let ops = vec![op::ret(RegId::ONE)];
+
+    // When
+    let vm = setup_with_memory_limit(ops, memory_limit);
+
+    // Then
+    let error = vm.result().expect_err("expected initialization to fail");
+    assert_eq!(error.panic_reason(), Some(PanicReason::MemoryOverflow));
+}
+
+#[test]
+fn memory_limit__above_max_ram_is_clamped() {
+    // Given
+    let ops = vec![op::ret(RegId::HP)];
+
+    // When
+    let vm = setup_with_memory_limit(ops, VM_MAX_RAM * 2);
+
+    // Then
+    let receipts = vm.receipts().unwrap();
+    assert!(
+        matches!(receipts.first(), Some(Receipt::Return { val, .. }) if *val == VM_MAX_RAM)
+    );
+}
+
+#[test]
+fn memory_limit__sizes_the_memory() {
+    // Given
+    let memory_limit = 64 * KIB;
+    let ops = vec![op::ret(RegId::ONE)];
+
+    // When
+    let vm = setup_with_memory_limit(ops, memory_limit);
+
+    // Then
+    let vm: &Interpreter<MemoryStorage, Script> = vm.as_ref();
+    assert_eq!(vm.memory().len() as u64, memory_limit);
+}
+
+#[test_case(vec![op::movi(0x10, (64 * KIB - 8) as u32), op::lw(0x11, 0x10, 0)] => None; "lw below the limit")]
+#[test_case(vec![op::movi(0x10, (64 * KIB - 4) as u32), op::lw(0x11, 0x10, 0)] => Some(PanicReason::MemoryOverflow); "lw across the limit")]
+#[test_case(vec![op::movi(0x10, (64 * KIB) as u32), op::lb(0x11, 0x10, 0)] => Some(PanicReason::MemoryOverflow); "lb at the limit")]
+#[test_case(vec![op::movi(0x10, (64 * KIB) as u32), op::movi(0x11, 8), op::meq(0x12, RegId::ZERO, 0x10, 0x11)] => Some(PanicReason::MemoryOverflow); "meq at the limit")]
+#[test_case(vec![op::movi(0x10, (64 * KIB / 4) as u32), op::jmp(0x10)] => Some(PanicReason::MemoryOverflow); "jmp at the limit")]
+fn memory_limit__is_respected_by_reads_and_jumps(
+    mut ops: Vec<Instruction>,
+) -> Option<PanicReason> {
+    // Given
+    let memory_limit = 64 * KIB;
+    ops.push(op::ret(RegId::ONE));
+
+    // When
+    let vm = setup_with_memory_limit(ops, memory_limit);
+
+    // Then
+    let receipts = vm.receipts().unwrap();
+    receipts.iter().find_map(|receipt| match receipt {
+        Receipt::Panic { reason, .. } => Some(*reason.reason()),
+        _ => None,
+    })
+}
+
 /// tests for cfe & cfs
 #[test]
 fn dynamic_call_frame_ops() {
@@ -401,3 +525,86 @@ fn test_heap_not_executable() {
         panic!("Expected panic receipt");
     }
 }
+
+/// Logs the words at `$ssp` and at the top of the heap, then writes `0xff` to both
+/// if the script data is `1`.
+fn leaking_script_tx(leak: bool) -> Checked<Script> {
+    let script = vec![
+        op::gtf_args(0x20, RegId::ZERO, GTFArgs::ScriptData),
+        op::lw(0x20, 0x20, 0),
+        op::lw(0x12, RegId::SSP, 0),
+        op::subi(0x13, RegId::HP, 8),
+        op::lw(0x13, 0x13, 0),
+        op::log(0x12, 0x13, RegId::ZERO, RegId::ZERO),
+        op::jnei(0x20, RegId::ONE, 13),
+        op::movi(0x10, 0xff),
+        op::cfei(8),
+        op::sw(RegId::SSP, 0x10, 0),
+        op::movi(0x11, 8),
+        op::aloc(0x11),
+        op::sw(RegId::HP, 0x10, 0),
+        op::ret(RegId::ONE),
+    ];
+
+    TransactionBuilder::script(
+        script.into_iter().collect(),
+        (leak as Word).to_be_bytes().to_vec(),
+    )
+    .script_gas_limit(1_000_000)
+    .add_random_fee_input()
+    .finalize()
+    .into_checked(Default::default(), &ConsensusParameters::standard())
+    .expect("failed to check tx")
+}
+
+fn logged_words(receipts: Option<&[Receipt]>) -> (Word, Word) {
+    match receipts.and_then(|r| r.first()) {
+        Some(Receipt::Log { ra, rb, .. }) => (*ra, *rb),
+        r => panic!("Expected a log receipt, got {r:?}"),
+    }
+}
+
+#[test]
+fn transact__reused_memory_reads_zeros() {
+    // Given
+    let mut vm = Transactor::<_, Script>::new(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &ConsensusParameters::standard()),
+    );
+    vm.transact(leaking_script_tx(true));
+    assert_eq!(logged_words(vm.receipts()), (0, 0));
+
+    // When
+    vm.transact(leaking_script_tx(false));
+
+    // Then
+    assert_eq!(logged_words(vm.receipts()), (0, 0));
+}
+
+#[test]
+fn memory_pool__memory_taken_again_reads_zeros() {
+    let pool = MemoryPool::new();
+    let params = InterpreterParams::new(0, &ConsensusParameters::standard());
+
+    // Given
+    let mut vm = Transactor::<_, Script>::with_memory_pool(
+        &pool,
+        MemoryStorage::default(),
+        params.clone(),
+    );
+    vm.transact(leaking_script_tx(true));
+    drop(vm);
+    assert_eq!(pool.len(), 1);
+
+    // When
+    let mut vm = Transactor::<_, Script>::with_memory_pool(
+        &pool,
+        MemoryStorage::default(),
+        params,
+    );
+    assert!(pool.is_empty());
+    vm.transact(leaking_script_tx(false));
+
+    // Then
+    assert_eq!(logged_words(vm.receipts()), (0, 0));
+}
@@ -0,0 +1,302 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::Outputs,
+    input,
+    output,
+    ConsensusParameters,
+    Contract,
+    Input,
+    Output,
+    Transaction,
+    TransactionBuilder,
+    TxPointer,
+};
+use fuel_types::{
+    canonical::Serialize,
+    BlockHeight,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+use crate::{
+    checked_transaction::CheckError,
+    interpreter::{
+        InterpreterParams,
+        NotSupportedEcal,
+    },
+    prelude::*,
+    script_with_data_offset,
+    storage::ContractsAssetsStorage,
+};
+
+const BLOCK_HEIGHT: u32 = 10;
+
+/// Builds a `Create` transaction deploying `code`, returning the id of the deployed
+/// contract along with the transaction.
+fn create(rng: &mut StdRng, code: Vec<Instruction>) -> (ContractId, Transaction) {
+    let salt: Salt = rng.gen();
+    let program: Witness = code
+        .into_iter()
+        .flat_map(Instruction::to_bytes)
+        .collect::<Vec<u8>>()
+        .into();
+    let storage_root = Contract::default_state_root();
+    let contract = Contract::from(program.as_ref());
+    let contract_id = contract.id(&salt, &contract.root(), &storage_root);
+
+    let tx = TransactionBuilder::create(program, salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, storage_root))
+        .finalize_as_transaction();
+
+    (contract_id, tx)
+}
+
+/// Builds a script calling the `contract_id`.
+fn call(rng: &mut StdRng, contract_id: ContractId) -> Transaction {
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        ConsensusParameters::standard().tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+
+    TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .add_input(Input::contract(
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, rng.gen(), rng.gen()))
+        .finalize_as_transaction()
+}
+
+#[test]
+fn execute_block__script_calls_the_contract_deployed_earlier_in_the_block() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut client = MemoryClient::default();
+    let (contract_id, create) =
+        create(rng, vec![op::log(RegId::ONE, 0, 0, 0), op::ret(RegId::ONE)]);
+    let call = call(rng, contract_id);
+
+    // When
+    let results = client.execute_block(
+        vec![create, call],
+        BlockHeight::new(BLOCK_HEIGHT),
+        0,
+        false,
+    );
+
+    // Then
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].status, TransactionExecutionStatus::Success);
+    assert_eq!(results[1].status, TransactionExecutionStatus::Success);
+    assert!(results[1].gas_used > 0);
+    assert!(results[1].receipts.iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Log { id, ra: 1, .. } if *id == contract_id
+    )));
+}
+
+#[test]
+fn execute_block__skips_the_transactions_failing_the_checks() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut client = MemoryClient::default();
+    let (contract_id, create) = create(rng, vec![op::ret(RegId::ONE)]);
+    let mut invalid = call(rng, contract_id);
+    if let Transaction::Script(script) = &mut invalid {
+        // The contract output points to an input that doesn't exist
+        script.outputs_mut()[0] = Output::contract(7, rng.gen(), rng.gen());
+    }
+    let valid = call(rng, contract_id);
+
+    // When
+    let results = client.execute_block(
+        vec![create, invalid, valid],
+        BlockHeight::new(BLOCK_HEIGHT),
+        0,
+        false,
+    );
+
+    // Then
+    assert_eq!(results[1].status, TransactionExecutionStatus::Rejected);
+    assert!(matches!(
+        results[1].error,
+        Some(InterpreterError::CheckError(_))
+    ));
+    assert!(results[1].receipts.is_empty());
+    assert_eq!(results[2].status, TransactionExecutionStatus::Success);
+}
+
+#[test]
+fn execute_block__checks_the_transactions_against_the_consensus_parameters_of_the_client()
+{
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params
+        .set_tx_params((*consensus_params.tx_params()).with_max_gas_per_tx(1_000));
+    let mut client = MemoryClient::<NotSupportedEcal>::new(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &consensus_params),
+        consensus_params,
+    );
+    let (contract_id, create) = create(rng, vec![op::ret(RegId::ONE)]);
+    let call = call(rng, contract_id);
+
+    // When
+    let results = client.execute_block(
+        vec![create, call],
+        BlockHeight::new(BLOCK_HEIGHT),
+        0,
+        false,
+    );
+
+    // Then
+    assert_eq!(results[1].status, TransactionExecutionStatus::Rejected);
+    assert!(matches!(
+        results[1].error,
+        Some(InterpreterError::CheckError(CheckError::Validity(
+            ValidityError::TransactionMaxGasExceeded
+        )))
+    ));
+}
+
+#[test]
+fn execute_block__reports_the_reverted_scripts() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut client = MemoryClient::default();
+    let (contract_id, create) = create(rng, vec![op::rvrt(RegId::ONE)]);
+    let call = call(rng, contract_id);
+
+    // When
+    let results = client.execute_block(
+        vec![create, call],
+        BlockHeight::new(BLOCK_HEIGHT),
+        0,
+        false,
+    );
+
+    // Then
+    assert_eq!(results[1].status, TransactionExecutionStatus::Revert);
+    assert!(matches!(
+        results[1].receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Revert,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn execute_block__applies_the_mint_only_if_requested() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let coinbase: ContractId = rng.gen();
+    let base_asset_id = *ConsensusParameters::standard().base_asset_id();
+    let mint = Transaction::mint(
+        TxPointer::new(BlockHeight::new(BLOCK_HEIGHT), 0),
+        input::contract::Contract {
+            contract_id: coinbase,
+            ..Default::default()
+        },
+        output::contract::Contract::default(),
+        100,
+        base_asset_id,
+        0,
+    );
+
+    for (apply_mint, status, balance) in [
+        (false, TransactionExecutionStatus::NotExecuted, None),
+        (true, TransactionExecutionStatus::Success, Some(100)),
+    ] {
+        // Given
+        let mut client = MemoryClient::default();
+
+        // When
+        let results = client.execute_block(
+            vec![mint.clone().into()],
+            BlockHeight::new(BLOCK_HEIGHT),
+            0,
+            apply_mint,
+        );
+
+        // Then
+        assert_eq!(results[0].status, status);
+        let stored = client
+            .as_ref()
+            .contract_asset_id_balance(&coinbase, &base_asset_id)
+            .unwrap();
+        assert_eq!(stored, balance);
+    }
+}
+
+#[test]
+fn deploy_contract__returns_the_id_of_the_contract_called_by_the_next_scripts() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut client = MemoryClient::default();
+    let code = [op::log(RegId::ONE, 0, 0, 0), op::ret(RegId::ONE)];
+
+    // When
+    let contract_id = client
+        .deploy_contract(code.into_iter().collect(), rng.gen(), vec![])
+        .expect("The contract should be deployed");
+
+    // Then
+    let Transaction::Script(script) = call(rng, contract_id) else {
+        unreachable!("`call` builds a script")
+    };
+    let script = script
+        .into_checked(Default::default(), &ConsensusParameters::standard())
+        .expect("The script should be valid");
+    let receipts = client.transact(script);
+    assert!(receipts.iter().any(|receipt| matches!(
+        receipt,
+        Receipt::Log { id, ra: 1, .. } if *id == contract_id
+    )));
+}
+
+#[test]
+fn deploy_contract__fails_if_the_contract_already_exists() {
+    // Given
+    let mut client = MemoryClient::default();
+    let code: Vec<u8> = [op::ret(RegId::ONE)].into_iter().collect();
+    client
+        .deploy_contract(code.clone(), Salt::zeroed(), vec![])
+        .expect("The contract should be deployed");
+
+    // When
+    let result = client.deploy_contract(code, Salt::zeroed(), vec![]);
+
+    // Then
+    assert!(matches!(
+        result,
+        Err(InterpreterError::Panic(
+            PanicReason::ContractIdAlreadyDeployed
+        ))
+    ));
+}
@@ -22,11 +22,15 @@ use fuel_tx::{
     field::{
         Inputs,
         Outputs,
+        Policies as PoliciesField,
         ReceiptsRoot,
         Script as ScriptField,
         Witnesses,
     },
-    policies::PoliciesBits,
+    policies::{
+        PoliciesBits,
+        PolicyType,
+    },
     ConsensusParameters,
     Finalizable,
     Receipt,
@@ -240,8 +244,13 @@ fn get_metadata_chain_id() {
         ..Default::default()
     };
 
-    let mut client =
-        MemoryClient::<NotSupportedEcal>::new(Default::default(), interpreter_params);
+    let consensus_params = ConsensusParameters::standard_with_id(chain_id);
+
+    let mut client = MemoryClient::<NotSupportedEcal>::new(
+        Default::default(),
+        interpreter_params,
+        consensus_params.clone(),
+    );
 
     #[rustfmt::skip]
     let get_chain_id = vec![
@@ -249,8 +258,6 @@ fn get_metadata_chain_id() {
         op::ret(0x10),
     ];
 
-    let consensus_params = ConsensusParameters::standard_with_id(chain_id);
-
     let script = TransactionBuilder::script(get_chain_id.into_iter().collect(), vec![])
         .script_gas_limit(gas_limit)
         .with_chain_id(chain_id)
@@ -278,6 +285,8 @@ fn get_transaction_fields() {
     let witness_limit = 1234;
     let max_fee_limit = 4321;
     let tip = 4321;
+    // The memory client runs with a zero gas price
+    let gas_price_floor = 0;
     let gas_limit = 10_000_000;
     let maturity = 50.into();
     let height = 122.into();
@@ -304,7 +313,7 @@ fn get_transaction_fields() {
         )
         .finalize_checked(height);
 
-    client.deploy(tx);
+    client.deploy(tx).expect("The contract should be deployed");
 
     let predicate = vec![op::ret(RegId::ONE)].into_iter().collect::<Vec<u8>>();
     let mut predicate_data = vec![0u8; 512];
@@ -517,6 +526,12 @@ fn get_transaction_fields() {
         op::eq(0x10, 0x10, 0x11),
         op::and(0x20, 0x20, 0x10),
 
+        op::movi(0x19, 0x00),
+        op::movi(0x11, gas_price_floor as Immediate18),
+        op::gtf_args(0x10, 0x19, GTFArgs::PolicyGasPriceFloor),
+        op::eq(0x10, 0x10, 0x11),
+        op::and(0x20, 0x20, 0x10),
+
         op::movi(0x19, 0x00),
         op::movi(0x11, PoliciesBits::all().bits() as Immediate18),
         op::gtf_args(0x10, 0x19, GTFArgs::PolicyTypes),
@@ -849,6 +864,7 @@ fn get_transaction_fields() {
         .script_gas_limit(gas_limit)
         .witness_limit(witness_limit)
         .max_fee_limit(max_fee_limit)
+        .min_gas_price(gas_price_floor)
         .finalize_checked_basic(height);
 
     let receipts = client.transact(tx);
@@ -858,3 +874,75 @@ fn get_transaction_fields() {
 
     assert!(success);
 }
+
+#[test]
+fn get_transaction_fields_returns_tip_and_maturity() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut client = MemoryClient::default();
+
+    let tip = 1234;
+    let maturity = 50.into();
+    let height = 122.into();
+
+    #[rustfmt::skip]
+    let script = vec![
+        op::movi(0x19, 0x00),
+        op::gtf_args(0x10, 0x19, GTFArgs::PolicyTip),
+        op::gtf_args(0x11, 0x19, GTFArgs::PolicyMaturity),
+        op::movi(0x12, 2 * bytes::WORD_SIZE as Immediate18),
+        op::aloc(0x12),
+        op::sw(RegId::HP, 0x10, 0),
+        op::sw(RegId::HP, 0x11, 1),
+        op::retd(RegId::HP, 0x12),
+    ];
+
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .tip(tip)
+        .max_fee_limit(tip)
+        .maturity(maturity)
+        .script_gas_limit(1_000_000)
+        .add_unsigned_coin_input(
+            SecretKey::random(rng),
+            rng.gen(),
+            tip,
+            AssetId::default(),
+            rng.gen(),
+        )
+        .finalize_checked_basic(height);
+
+    let receipts = client.transact(tx);
+
+    let expected: Vec<u8> = [tip, *maturity as Word]
+        .into_iter()
+        .flat_map(Word::to_be_bytes)
+        .collect();
+    assert!(matches!(
+        &receipts[0],
+        Receipt::ReturnData { data: Some(data), .. } if data == &expected
+    ));
+}
+
+#[test]
+fn get_transaction_fields_panics_on_unset_policy() {
+    let mut client = MemoryClient::default();
+
+    #[rustfmt::skip]
+    let script = vec![
+        op::movi(0x19, 0x00),
+        op::gtf_args(0x10, 0x19, GTFArgs::PolicyTip),
+        op::ret(RegId::ONE),
+    ];
+
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_checked_basic(Default::default());
+    assert_eq!(tx.transaction().policies().get(PolicyType::Tip), None);
+
+    let receipts = client.transact(tx);
+
+    assert!(matches!(
+        &receipts[0],
+        Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::PolicyIsNotSet
+    ));
+}
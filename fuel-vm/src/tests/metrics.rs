@@ -0,0 +1,144 @@
+#![allow(non_snake_case)]
+
+use alloc::vec;
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::ConsensusParameters;
+use fuel_types::canonical::Serialize;
+
+use crate::{
+    checked_transaction::Checked,
+    interpreter::{
+        ExecutionMetrics,
+        InterpreterParams,
+    },
+    prelude::*,
+    script_with_data_offset,
+    util::test_helpers::TestBuilder,
+};
+
+const SWW_PER_CALL: u64 = 3;
+/// Five instructions of four bytes.
+const CONTRACT_SIZE: u64 = 20;
+
+/// Deploys a contract executing `SWW` three times, and builds a script calling it
+/// `calls` times before returning or reverting.
+fn sww_calls(calls: usize, revert: bool) -> (MemoryStorage, Checked<Script>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract = vec![
+        op::movi(0x10, 42),
+        op::sww(RegId::ZERO, 0x11, 0x10),
+        op::sww(RegId::ZERO, 0x11, 0x10),
+        op::sww(RegId::ZERO, 0x11, 0x10),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+
+    let mut script = vec![op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS); calls];
+    script.push(if revert {
+        op::rvrt(RegId::ONE)
+    } else {
+        op::ret(RegId::ONE)
+    });
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        [
+            vec![op::movi(0x10, data_offset as Immediate18)],
+            script.clone()
+        ]
+        .concat(),
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .contract_input(contract_id)
+        .fee_input()
+        .contract_output(&contract_id)
+        .build();
+
+    (test_context.get_storage().clone(), tx)
+}
+
+fn expected_metrics(calls: u64) -> ExecutionMetrics {
+    ExecutionMetrics {
+        storage_writes: calls * SWW_PER_CALL,
+        storage_write_bytes: calls * SWW_PER_CALL * 32,
+        contract_code_loads: calls,
+        contract_code_bytes: calls * CONTRACT_SIZE,
+        ..Default::default()
+    }
+}
+
+fn transactor(storage: MemoryStorage) -> Transactor<MemoryStorage, Script> {
+    Transactor::new(
+        storage,
+        InterpreterParams::new(0, &ConsensusParameters::standard()),
+    )
+}
+
+#[test]
+fn metrics__count_storage_writes_and_code_loads() {
+    // Given
+    let (storage, tx) = sww_calls(2, false);
+    let mut vm = transactor(storage);
+
+    // When
+    vm.transact(tx);
+
+    // Then
+    assert!(matches!(
+        vm.receipts(),
+        Some([
+            ..,
+            Receipt::ScriptResult {
+                result: ScriptExecutionResult::Success,
+                ..
+            }
+        ])
+    ));
+    assert_eq!(vm.interpreter().metrics(), &expected_metrics(2));
+}
+
+#[test]
+fn metrics__are_kept_when_the_transaction_reverts() {
+    // Given
+    let (storage, tx) = sww_calls(3, true);
+    let mut vm = transactor(storage);
+
+    // When
+    vm.transact(tx);
+
+    // Then
+    assert!(matches!(
+        vm.receipts(),
+        Some([
+            ..,
+            Receipt::ScriptResult {
+                result: ScriptExecutionResult::Revert,
+                ..
+            }
+        ])
+    ));
+    assert_eq!(vm.interpreter().metrics(), &expected_metrics(3));
+}
+
+#[test]
+fn metrics__are_reset_for_each_transaction() {
+    // Given
+    let (storage, tx) = sww_calls(2, false);
+    let mut vm = transactor(storage);
+    vm.transact(tx.clone());
+
+    // When
+    vm.transact(tx);
+
+    // Then
+    assert_eq!(vm.interpreter().metrics(), &expected_metrics(2));
+}
@@ -7,11 +7,15 @@ mod test_helpers;
 
 mod alu;
 mod backtrace;
+mod blob;
 mod blockchain;
 mod cgas;
+mod code_cache;
 mod code_coverage;
 mod contract;
 mod crypto;
+mod dry_run;
+mod elliptic_curve;
 mod encoding;
 mod external;
 mod flow;
@@ -20,12 +24,19 @@ mod jump_absolute;
 mod jump_relative;
 mod log;
 mod memory;
+mod memory_client;
 mod metadata;
+mod metrics;
+mod out_of_gas;
 mod outputs;
 mod predicate;
 mod profile_gas;
 mod receipts;
 mod serde_profile;
+mod snapshot;
 mod spec;
+#[cfg(feature = "trace")]
+mod tracer;
+mod transactor;
 mod validation;
 mod wideint;
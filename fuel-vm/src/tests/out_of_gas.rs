@@ -0,0 +1,174 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::{
+    field::Inputs,
+    ConsensusParameters,
+    TransactionBuilder,
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+use crate::{
+    checked_transaction::{
+        CheckPredicateParams,
+        EstimatePredicates,
+    },
+    interpreter::InterpreterParams,
+    prelude::*,
+};
+
+/// Clears a heap buffer, so the script is charged both fixed and dependent costs.
+fn script() -> Vec<Instruction> {
+    vec![
+        op::movi(0x10, 1000),
+        op::aloc(0x10),
+        op::mcl(RegId::HP, 0x10),
+        op::ret(RegId::ONE),
+    ]
+}
+
+/// Executes the script with the `gas_limit`, returning the transactor and the gas
+/// used reported by the script result.
+fn transact(gas_limit: Word) -> (Transactor<MemoryStorage, Script>, Word) {
+    let tx = TestBuilder::new(2322u64)
+        .start_script(script(), vec![])
+        .script_gas_limit(gas_limit)
+        .fee_input()
+        .build();
+    let mut transactor = Transactor::new(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &ConsensusParameters::standard()),
+    );
+    transactor.transact(tx);
+
+    let gas_used = match transactor.receipts().expect("tx was executed").last() {
+        Some(Receipt::ScriptResult { gas_used, .. }) => *gas_used,
+        _ => panic!("expected script result"),
+    };
+    (transactor, gas_used)
+}
+
+#[test]
+fn out_of_gas__is_none_when_the_execution_has_enough_gas() {
+    // When
+    let (transactor, gas_used) = transact(1_000_000);
+
+    // Then
+    assert!(!transactor.is_reverted());
+    assert_eq!(transactor.interpreter().out_of_gas(), None);
+    assert_eq!(transactor.interpreter().total_gas_charged(), gas_used);
+}
+
+#[test]
+fn out_of_gas__reports_the_gas_of_the_failed_charge() {
+    // Given
+    let (_, required_gas) = transact(1_000_000);
+    let gas_limit = required_gas - 1;
+
+    // When
+    let (transactor, gas_used) = transact(gas_limit);
+
+    // Then
+    assert!(transactor.is_reverted());
+    assert_eq!(gas_used, gas_limit);
+    assert_eq!(transactor.interpreter().total_gas_charged(), gas_limit);
+    let out_of_gas = transactor
+        .interpreter()
+        .out_of_gas()
+        .expect("ran out of gas");
+    assert!(out_of_gas.consumed < gas_limit);
+    assert_eq!(out_of_gas.required_gas(), required_gas);
+
+    let (transactor, _) = transact(out_of_gas.required_gas());
+    assert!(!transactor.is_reverted());
+}
+
+#[test]
+fn out_of_gas__retrying_with_the_required_gas_gets_past_the_failed_charge() {
+    // Given
+    let (_, required_gas) = transact(1_000_000);
+    let mut gas_limit = 1;
+
+    // When
+    let mut retries = 0;
+    let transactor = loop {
+        let (transactor, gas_used) = transact(gas_limit);
+        let Some(out_of_gas) = transactor.interpreter().out_of_gas() else {
+            break transactor
+        };
+
+        // Then
+        assert_eq!(gas_used, gas_limit);
+        assert!(out_of_gas.required_gas() > gas_limit);
+        gas_limit = out_of_gas.required_gas();
+        retries += 1;
+        assert!(retries <= script().len());
+    };
+
+    assert!(!transactor.is_reverted());
+    assert_eq!(gas_limit, required_gas);
+}
+
+#[test]
+fn total_gas_charged__includes_the_gas_of_the_predicates() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let params = ConsensusParameters::standard();
+    let predicate: Vec<u8> = vec![op::addi(0x10, 0x10, 1), op::ret(RegId::ONE)]
+        .into_iter()
+        .collect();
+    let owner = Input::predicate_owner(&predicate);
+    let mut tx = TransactionBuilder::script(script().into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            1_000,
+            *params.base_asset_id(),
+            rng.gen(),
+            0,
+            predicate,
+            vec![],
+        ))
+        .finalize();
+    tx.estimate_predicates(&CheckPredicateParams::from(&params))
+        .expect("Should estimate the predicate");
+    let predicate_gas = tx.inputs()[0]
+        .predicate_gas_used()
+        .expect("A predicate input");
+    let tx = tx
+        .into_checked(Default::default(), &params)
+        .expect("Should check the transaction");
+    let mut transactor = Transactor::<_, Script>::new(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &params),
+    );
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    assert!(transactor.is_success());
+    let gas_used = transactor
+        .receipts()
+        .and_then(|receipts| receipts.last())
+        .and_then(Receipt::gas_used)
+        .expect("expected script result");
+    assert!(predicate_gas > 0);
+    assert_eq!(
+        transactor.interpreter().total_gas_charged(),
+        gas_used + predicate_gas
+    );
+}
@@ -5,6 +5,7 @@ use alloc::{
 };
 
 use crate::{
+    interpreter::InterpreterParams,
     prelude::{
         field::Outputs,
         *,
@@ -19,9 +20,11 @@ use fuel_asm::{
     op,
     RegId,
 };
+use fuel_crypto::SecretKey;
 use fuel_tx::{
     policies::Policies,
     ConsensusParameters,
+    UpgradePurpose,
     Witness,
 };
 use fuel_types::canonical::Serialize;
@@ -168,6 +171,7 @@ fn correct_change_is_provided_for_coin_outputs_create() {
         context.get_chain_id(),
         context.get_gas_costs().to_owned(),
         *context.get_base_asset_id(),
+        Default::default(),
     );
     let create = create
         .into_checked_basic(context.get_block_height(), &consensus_params)
@@ -179,6 +183,43 @@ fn correct_change_is_provided_for_coin_outputs_create() {
     assert_eq!(change, input_amount - spend_amount);
 }
 
+#[test]
+fn correct_change_is_provided_for_coin_outputs_upgrade() {
+    let mut rng = StdRng::seed_from_u64(2322u64);
+    let input_amount = 1000;
+    let spend_amount = 600;
+    let secret = SecretKey::random(&mut rng);
+
+    let mut context = TestBuilder::new(2322u64);
+    let mut consensus_params =
+        ConsensusParameters::standard_with_id(context.get_chain_id());
+    consensus_params.set_privileged_address(Input::owner(&secret.public_key()));
+    let base_asset_id = *consensus_params.base_asset_id();
+
+    let upgrade =
+        TransactionBuilder::upgrade(UpgradePurpose::StateTransition { root: rng.gen() })
+            .with_params(consensus_params.clone())
+            .add_unsigned_coin_input(
+                secret,
+                rng.gen(),
+                input_amount,
+                base_asset_id,
+                rng.gen(),
+            )
+            .add_output(Output::change(rng.gen(), 0, base_asset_id))
+            .add_output(Output::coin(rng.gen(), spend_amount, base_asset_id))
+            .finalize()
+            .into_checked_basic(context.get_block_height(), &consensus_params)
+            .expect("failed to generate checked tx");
+
+    let state = context
+        .upgrade(upgrade)
+        .expect("Upgrade should be executed");
+    let change = find_change(state.tx().outputs().to_vec(), base_asset_id);
+
+    assert_eq!(change, input_amount - spend_amount);
+}
+
 #[test]
 fn change_is_reduced_by_external_transfer() {
     let mut rng = StdRng::seed_from_u64(2322u64);
@@ -712,3 +753,132 @@ fn variable_output_not_increased_by_contract_transfer_out_on_revert() {
         .iter()
         .any(|r| matches!(r, Receipt::TransferOut { .. })));
 }
+
+fn transfer_out_script(
+    last_op: Instruction,
+) -> (Vec<Instruction>, Vec<u8>, Word, AssetId) {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let transfer_amount: Word = 600;
+    let asset_id: AssetId = rng.gen();
+    let owner: Address = rng.gen();
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            // load amount of coins to 0x10
+            op::movi(0x10, data_offset),
+            op::lw(0x10, 0x10, 0),
+            // load asset id to 0x11
+            op::movi(0x11, data_offset + 8),
+            // load address to 0x12
+            op::movi(0x12, data_offset + 40),
+            // transfer to the variable output at index 0
+            op::tro(0x12, RegId::ZERO, 0x10, 0x11),
+            last_op,
+        ],
+        TxParameters::DEFAULT.tx_offset()
+    );
+
+    let script_data: Vec<u8> = [
+        transfer_amount.to_be_bytes().as_ref(),
+        asset_id.as_ref(),
+        owner.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .copied()
+    .collect();
+
+    (script, script_data, transfer_amount, asset_id)
+}
+
+fn execute_transfer_out(
+    last_op: Instruction,
+) -> (Transactor<MemoryStorage, Script>, Word, AssetId, Word) {
+    let gas_price = 1_000_000;
+    let external_balance = 1_000_000;
+    let (script, script_data, transfer_amount, asset_id) = transfer_out_script(last_op);
+
+    let mut test_context = TestBuilder::new(2322u64);
+    let base_asset_id = *test_context.get_base_asset_id();
+    let tx = test_context
+        .start_script(script, script_data)
+        .gas_price(gas_price)
+        .script_gas_limit(1_000_000)
+        .max_fee_limit(external_balance)
+        .coin_input(asset_id, external_balance)
+        .coin_input(base_asset_id, external_balance)
+        .variable_output(asset_id)
+        .change_output(asset_id)
+        .change_output(base_asset_id)
+        .build();
+    let interpreter_params =
+        InterpreterParams::new(gas_price, &ConsensusParameters::standard());
+    let mut transactor =
+        Transactor::<_, _>::new(MemoryStorage::default(), interpreter_params);
+
+    transactor.transact(tx);
+
+    (transactor, external_balance, asset_id, transfer_amount)
+}
+
+#[test]
+fn balances_and_variable_outputs_sum_to_inputs() {
+    let (transactor, external_balance, asset_id, transfer_amount) =
+        execute_transfer_out(op::ret(RegId::ONE));
+    assert!(transactor.is_success());
+
+    let balances: Vec<_> = transactor.balances().expect("executed").collect();
+    let outputs = transactor.interpreter().transaction().outputs();
+
+    let asset_balance = balances
+        .iter()
+        .find_map(|(asset, amount)| (*asset == asset_id).then_some(*amount))
+        .expect("the asset has a balance");
+    assert!(matches!(
+        outputs[0],
+        Output::Variable { amount, .. } if amount == transfer_amount
+    ));
+    assert_eq!(asset_balance + transfer_amount, external_balance);
+    assert_eq!(find_change(outputs.to_vec(), asset_id), asset_balance);
+}
+
+#[test]
+fn refund_amount_matches_base_asset_change() {
+    let (transactor, ..) = execute_transfer_out(op::ret(RegId::ONE));
+
+    let base_asset_id = *transactor.interpreter().base_asset_id();
+    let base_balance = transactor
+        .balances()
+        .expect("executed")
+        .find_map(|(asset, amount)| (asset == base_asset_id).then_some(amount))
+        .expect("the base asset has a balance");
+    let gas_price = transactor.interpreter().gas_price();
+    let refund = transactor
+        .refund_amount(gas_price)
+        .expect("the refund is computable");
+    let outputs = transactor.interpreter().transaction().outputs();
+
+    assert!(transactor.unused_gas().expect("executed") > 0);
+    assert!(refund > 0);
+    assert_eq!(
+        find_change(outputs.to_vec(), base_asset_id),
+        base_balance + refund
+    );
+}
+
+#[test]
+fn balances_are_reset_to_inputs_on_revert() {
+    let (transactor, external_balance, asset_id, _) =
+        execute_transfer_out(op::rvrt(RegId::ONE));
+    assert!(transactor.is_reverted());
+
+    let asset_balance = transactor
+        .balances()
+        .expect("executed")
+        .find_map(|(asset, amount)| (asset == asset_id).then_some(amount));
+    let outputs = transactor.interpreter().transaction().outputs();
+
+    assert_eq!(asset_balance, Some(external_balance));
+    assert!(matches!(outputs[0], Output::Variable { amount: 0, .. }));
+}
@@ -1,3 +1,4 @@
+#![allow(non_snake_case)]
 #![cfg(feature = "std")]
 
 use fuel_asm::{
@@ -7,7 +8,14 @@ use fuel_asm::{
     Instruction,
     RegId,
 };
-use fuel_tx::TransactionBuilder;
+use fuel_tx::{
+    field::{
+        Inputs,
+        ReceiptsRoot,
+    },
+    TransactionBuilder,
+};
+use fuel_types::bytes::WORD_SIZE;
 use rand::{
     rngs::StdRng,
     Rng,
@@ -17,9 +25,16 @@ use tokio_rayon::AsyncRayonHandle;
 
 use crate::{
     error::PredicateVerificationFailed,
+    interpreter::{
+        ExecutableTransaction,
+        InterpreterParams,
+    },
     prelude::*,
+    storage::PredicateStorage,
 };
 
+#[cfg(feature = "rayon")]
+use crate::checked_transaction::Checks;
 use crate::checked_transaction::{
     CheckError,
     CheckPredicateParams,
@@ -195,6 +210,23 @@ async fn get_verifying_predicate() {
     }
 }
 
+#[tokio::test]
+async fn predicate__checks_a_pairing() {
+    let predicate = vec![
+        op::gtf_args(0x10, 0, GTFArgs::InputCoinPredicateData),
+        op::movi(0x11, 2),
+        op::epar(0x12, RegId::ZERO, 0x11, 0x10),
+        op::ret(0x12),
+    ];
+    let valid = super::elliptic_curve::valid_pairing();
+    let mut invalid = valid.clone();
+    invalid.truncate(192);
+    invalid.extend_from_within(..192);
+
+    assert!(execute_predicate(predicate.clone(), valid, 0).await);
+    assert!(!execute_predicate(predicate, invalid, 0).await);
+}
+
 /// Returns the amount of gas used if verification succeeds
 async fn execute_gas_metered_predicates(
     predicates: Vec<Vec<Instruction>>,
@@ -561,3 +593,511 @@ async fn gas_used_by_predicates_more_than_limit() {
         CheckError::PredicateVerificationFailed(_)
     ));
 }
+
+#[tokio::test]
+async fn estimate_predicates__infinite_loop_is_cut_off_at_max_gas_per_predicate() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    // Given
+    const MAX_GAS_PER_PREDICATE: Word = 10_000;
+    let params = CheckPredicateParams {
+        max_gas_per_predicate: MAX_GAS_PER_PREDICATE,
+        ..Default::default()
+    };
+    assert!(MAX_GAS_PER_PREDICATE < params.max_gas_per_tx);
+
+    let predicate: Vec<u8> = iter::once(op::ji(0))
+        .flat_map(|op| u32::from(op).to_be_bytes())
+        .collect();
+    let owner = Input::predicate_owner(&predicate);
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(1_000).add_unsigned_coin_input(
+        SecretKey::random(rng),
+        rng.gen(),
+        1_000,
+        params.base_asset_id,
+        rng.gen(),
+    );
+    builder.add_input(Input::coin_predicate(
+        rng.gen(),
+        owner,
+        1_000,
+        params.base_asset_id,
+        rng.gen(),
+        0,
+        predicate,
+        vec![],
+    ));
+    let transaction = builder.finalize();
+    let expected = PredicateVerificationFailed::PredicateGasExceeded {
+        index: 1,
+        limit: MAX_GAS_PER_PREDICATE,
+    };
+
+    // When
+    let parallel_result = transaction
+        .clone()
+        .estimate_predicates_async::<TokioWithRayon>(&params)
+        .await;
+    let seq_result = transaction.clone().estimate_predicates(&params);
+
+    // Then
+    assert_eq!(
+        parallel_result,
+        Err(CheckError::PredicateVerificationFailed(expected.clone()))
+    );
+    assert_eq!(
+        seq_result,
+        Err(CheckError::PredicateVerificationFailed(expected))
+    );
+}
+
+#[test]
+fn estimate_predicates__infinite_loop_is_cut_off_at_max_instructions() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+
+    // Given
+    let params = CheckPredicateParams {
+        gas_costs: GasCosts::free(),
+        max_instructions: Some(1000),
+        ..Default::default()
+    };
+
+    let predicate: Vec<u8> = iter::once(op::ji(0))
+        .flat_map(|op| u32::from(op).to_be_bytes())
+        .collect();
+    let owner = Input::predicate_owner(&predicate);
+
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.max_fee_limit(1_000).add_unsigned_coin_input(
+        SecretKey::random(rng),
+        rng.gen(),
+        1_000,
+        params.base_asset_id,
+        rng.gen(),
+    );
+    builder.add_input(Input::coin_predicate(
+        rng.gen(),
+        owner,
+        1_000,
+        params.base_asset_id,
+        rng.gen(),
+        0,
+        predicate,
+        vec![],
+    ));
+    let mut transaction = builder.finalize();
+
+    // When
+    let result = transaction.estimate_predicates(&params);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(CheckError::PredicateVerificationFailed(
+            PredicateVerificationFailed::PredicateInstructionLimitExceeded {
+                index: 1,
+                limit: 1000,
+            }
+        ))
+    );
+}
+
+/// A predicate counting down from `iterations` before returning `true`.
+fn countdown_predicate(iterations: u16) -> Vec<u8> {
+    let counter = RegId::new(0x20);
+
+    #[rustfmt::skip]
+    let predicate = vec![
+        op::movi(counter, iterations.into()),
+        // loop_start:
+        op::subi(counter, counter, 1),
+        op::jnzb(counter, RegId::ZERO, 0), // if counter != 0 then goto loop_start
+        op::ret(RegId::ONE),
+    ];
+
+    predicate.into_iter().collect()
+}
+
+#[cfg(feature = "rayon")]
+fn script_with_countdown_predicates(count: u16) -> Script {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.script_gas_limit(1_000_000);
+
+    for i in 1..=count {
+        let predicate = countdown_predicate(i * 10);
+        let owner = Input::predicate_owner(&predicate);
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            0,
+            rng.gen(),
+            rng.gen(),
+            0,
+            predicate,
+            vec![],
+        ));
+    }
+
+    builder.finalize()
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn check_predicates_parallel__matches_sequential_for_many_predicates() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let check_params = CheckPredicateParams::from(&params);
+    let transaction = script_with_countdown_predicates(64);
+
+    // When
+    let mut seq_estimated = transaction.clone();
+    seq_estimated
+        .estimate_predicates(&check_params)
+        .expect("Should estimate predicates");
+    let mut par_estimated = transaction;
+    par_estimated
+        .estimate_predicates_parallel(&check_params)
+        .expect("Should estimate predicates");
+
+    let checked = seq_estimated
+        .clone()
+        .into_checked_basic(Default::default(), &params)
+        .expect("Should successfully convert into Checked");
+    let seq_checked =
+        Interpreter::<PredicateStorage, _>::check_predicates(&checked, &check_params)
+            .expect("Predicates should be valid");
+    let par_checked = Interpreter::<PredicateStorage, _>::check_predicates_parallel(
+        &checked,
+        &check_params,
+    )
+    .expect("Predicates should be valid");
+
+    // Then
+    assert_eq!(par_estimated, seq_estimated);
+    assert_eq!(par_checked.gas_used(), seq_checked.gas_used());
+    assert!(checked
+        .check_predicates_parallel(&check_params)
+        .expect("Predicates should be valid")
+        .checks()
+        .contains(Checks::Predicates));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn check_predicates_parallel__returns_error_of_lowest_failing_input() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let check_params = CheckPredicateParams::from(&params);
+    let mut transaction = script_with_countdown_predicates(64);
+    transaction
+        .estimate_predicates(&check_params)
+        .expect("Should estimate predicates");
+    for (index, gas_used) in [(40, 0), (10, 1_000_000)] {
+        match &mut transaction.inputs_mut()[index] {
+            Input::CoinPredicate(coin) => coin.predicate_gas_used = gas_used,
+            _ => unreachable!("All inputs are coin predicates"),
+        }
+    }
+    let checked = transaction
+        .into_checked_basic(Default::default(), &params)
+        .expect("Should successfully convert into Checked");
+
+    // When
+    let seq_result =
+        Interpreter::<PredicateStorage, _>::check_predicates(&checked, &check_params)
+            .map(|checked| checked.gas_used());
+    let par_result = Interpreter::<PredicateStorage, _>::check_predicates_parallel(
+        &checked,
+        &check_params,
+    )
+    .map(|checked| checked.gas_used());
+
+    // Then
+    assert_eq!(seq_result, Err(PredicateVerificationFailed::GasMismatch));
+    assert_eq!(par_result, seq_result);
+}
+
+#[test]
+fn predicate_gas_used__is_reported_apart_from_the_script_gas() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let params = ConsensusParameters::standard();
+    let script = vec![
+        op::addi(0x20, 0x20, 1),
+        op::addi(0x20, 0x20, 1),
+        op::ret(RegId::ONE),
+    ];
+    let mut builder = TransactionBuilder::script(script.into_iter().collect(), vec![]);
+    builder.script_gas_limit(1_000_000);
+    for iterations in [10, 20] {
+        let predicate = countdown_predicate(iterations);
+        let owner = Input::predicate_owner(&predicate);
+        builder.add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            0,
+            rng.gen(),
+            rng.gen(),
+            0,
+            predicate,
+            vec![],
+        ));
+    }
+    let mut transaction = builder.finalize();
+    transaction
+        .estimate_predicates(&CheckPredicateParams::from(&params))
+        .expect("Should estimate predicates");
+    let declared_gas: Vec<_> = transaction
+        .inputs()
+        .iter()
+        .filter_map(Input::predicate_gas_used)
+        .collect();
+    let checked = transaction
+        .into_checked(Default::default(), &params)
+        .expect("Should successfully convert into Checked");
+
+    // When
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(MemoryStorage::default())
+        .with_params(InterpreterParams::new(0, &params))
+        .build();
+    let predicate_gas_used = checked.predicate_gas_used();
+    vm.transact(checked);
+
+    // Then
+    assert_eq!(declared_gas.len(), 2);
+    assert_eq!(predicate_gas_used, Some(declared_gas.iter().sum()));
+    assert_eq!(vm.predicate_gas_used(), predicate_gas_used);
+    let script_gas_used = vm.script_gas_used().expect("Expected a state transition");
+    let receipts = vm.receipts().expect("Expected receipts");
+    assert_eq!(
+        receipts.last().and_then(Receipt::gas_used),
+        Some(script_gas_used)
+    );
+    assert_eq!(
+        vm.interpreter().total_gas_charged(),
+        script_gas_used + declared_gas.iter().sum::<Word>()
+    );
+}
+
+fn assert_predicate_memory_is_predicate_context<Tx>(tx: Tx)
+where
+    Tx: ExecutableTransaction + Into<Transaction>,
+{
+    // Given
+    let params = InterpreterParams::default();
+    let chain_id = params.chain_id;
+    let mut vm = Interpreter::<_, Tx>::with_storage(PredicateStorage, params);
+    let tx_offset = vm.tx_offset();
+    let expected = tx.clone().into().predicate_context_bytes(&chain_id);
+
+    // When
+    vm.init_predicate(
+        Context::PredicateVerification {
+            program: Default::default(),
+        },
+        tx,
+        1_000,
+    )
+    .expect("Failed to initialize the predicate");
+
+    // Then
+    let (id, context) = expected.split_at(Bytes32::LEN);
+    let context_range = tx_offset - WORD_SIZE..tx_offset - WORD_SIZE + context.len();
+    assert_eq!(&vm.memory()[..Bytes32::LEN], id);
+    assert_eq!(&vm.memory()[context_range], context);
+}
+
+fn malleable_inputs_and_outputs(rng: &mut StdRng) -> (Vec<Input>, Vec<Output>) {
+    let predicate = vec![op::ret(RegId::ONE)].into_iter().collect::<Vec<u8>>();
+    let owner = Input::predicate_owner(&predicate);
+    let inputs = vec![
+        Input::coin_predicate(
+            rng.gen(),
+            owner,
+            1_000,
+            rng.gen(),
+            rng.gen(),
+            rng.gen(),
+            predicate,
+            vec![1, 2, 3],
+        ),
+        Input::contract(rng.gen(), rng.gen(), rng.gen(), rng.gen(), rng.gen()),
+    ];
+    let outputs = vec![
+        Output::contract(1, rng.gen(), rng.gen()),
+        Output::change(rng.gen(), rng.gen(), rng.gen()),
+        Output::variable(rng.gen(), rng.gen(), rng.gen()),
+    ];
+    (inputs, outputs)
+}
+
+#[test]
+fn init_predicate__memory_of_script_matches_predicate_context_bytes() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let (inputs, outputs) = malleable_inputs_and_outputs(rng);
+    let mut tx = Transaction::script(
+        1_000,
+        vec![],
+        vec![],
+        Default::default(),
+        inputs,
+        outputs,
+        vec![],
+    );
+    *tx.receipts_root_mut() = rng.gen();
+
+    assert_predicate_memory_is_predicate_context(tx);
+}
+
+#[test]
+fn init_predicate__memory_of_create_matches_predicate_context_bytes() {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let (inputs, outputs) = malleable_inputs_and_outputs(rng);
+    let tx = Transaction::create(
+        0,
+        Default::default(),
+        rng.gen(),
+        vec![],
+        inputs,
+        outputs,
+        vec![vec![0u8; 8].into()],
+    );
+
+    assert_predicate_memory_is_predicate_context(tx);
+}
+
+/// A predicate returning whether the `tx_pointer` and `predicate_gas_used` of the coin
+/// input, and the amounts of the change and variable outputs that follow it, are the
+/// given ones.
+fn predicate_reading_malleable_fields(
+    block_height: u32,
+    predicate_gas_used: Word,
+    change_amount: Word,
+    variable_amount: Word,
+) -> Vec<u8> {
+    // The serialized change output: the discriminant, `to`, `amount` and `asset_id`.
+    const CHANGE_SIZE: u16 = (WORD_SIZE + 32 + WORD_SIZE + 32) as u16;
+    const AMOUNT_OFFSET: u16 = 32 / WORD_SIZE as u16;
+    const VARIABLE_AMOUNT_OFFSET: u16 = CHANGE_SIZE / WORD_SIZE as u16 + AMOUNT_OFFSET;
+
+    vec![
+        op::movi(0x13, 1),
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::InputCoinTxPointer),
+        op::lw(0x11, 0x10, 0),
+        op::movi(0x12, block_height),
+        op::eq(0x14, 0x11, 0x12),
+        op::and(0x13, 0x13, 0x14),
+        op::gtf_args(0x11, RegId::ZERO, GTFArgs::InputCoinPredicateGasUsed),
+        op::movi(0x12, predicate_gas_used as u32),
+        op::eq(0x14, 0x11, 0x12),
+        op::and(0x13, 0x13, 0x14),
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::OutputCoinTo),
+        op::lw(0x11, 0x10, AMOUNT_OFFSET),
+        op::movi(0x12, change_amount as u32),
+        op::eq(0x14, 0x11, 0x12),
+        op::and(0x13, 0x13, 0x14),
+        op::lw(0x11, 0x10, VARIABLE_AMOUNT_OFFSET),
+        op::movi(0x12, variable_amount as u32),
+        op::eq(0x14, 0x11, 0x12),
+        op::and(0x13, 0x13, 0x14),
+        op::ret(0x13),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// Verifies the predicate of the first input of `tx`, with the gas it declares.
+fn verify_first_predicate<Tx>(tx: Tx) -> Result<ProgramState, PredicateVerificationFailed>
+where
+    Tx: ExecutableTransaction,
+{
+    let params = InterpreterParams::default();
+    let mut vm = Interpreter::<_, Tx>::with_storage(PredicateStorage, params);
+    let predicate =
+        RuntimePredicate::from_tx(&tx, vm.tx_offset(), 0).expect("A predicate input");
+    let gas = tx.inputs()[0]
+        .predicate_gas_used()
+        .expect("A predicate input");
+    vm.init_predicate(
+        Context::PredicateVerification { program: predicate },
+        tx,
+        gas,
+    )?;
+    vm.verify_predicate()
+}
+
+fn transaction_with_malleable_fields_read_by_predicate<Tx>(
+    new_tx: impl FnOnce(Vec<Input>, Vec<Output>) -> Tx,
+) -> Tx {
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let predicate = predicate_reading_malleable_fields(46, 12_345, 23, 25);
+    let owner = Input::predicate_owner(&predicate);
+    let inputs = vec![Input::coin_predicate(
+        rng.gen(),
+        owner,
+        1_000,
+        AssetId::BASE,
+        TxPointer::new(46.into(), 5),
+        12_345,
+        predicate,
+        vec![],
+    )];
+    let outputs = vec![
+        Output::change(rng.gen(), 23, AssetId::BASE),
+        Output::variable(rng.gen(), 25, rng.gen()),
+    ];
+    new_tx(inputs, outputs)
+}
+
+#[test]
+fn init_predicate__create_predicates_read_malleable_fields_as_is() {
+    // Given
+    let tx = transaction_with_malleable_fields_read_by_predicate(|inputs, outputs| {
+        Transaction::create(
+            0,
+            Default::default(),
+            Default::default(),
+            vec![],
+            inputs,
+            outputs,
+            vec![vec![0u8; 8].into()],
+        )
+    });
+
+    // When
+    let result = verify_first_predicate(tx);
+
+    // Then
+    assert_eq!(result, Ok(ProgramState::Return(1)));
+}
+
+#[test]
+fn init_predicate__script_predicates_read_malleable_fields_zeroed() {
+    // Given
+    let tx = transaction_with_malleable_fields_read_by_predicate(|inputs, outputs| {
+        Transaction::script(
+            1_000,
+            vec![],
+            vec![],
+            Default::default(),
+            inputs,
+            outputs,
+            vec![],
+        )
+    });
+
+    // When
+    let result = verify_first_predicate(tx);
+
+    // Then
+    assert_eq!(
+        result,
+        Err(PredicateVerificationFailed::Panic(
+            PanicReason::PredicateReturnedNonOne
+        ))
+    );
+}
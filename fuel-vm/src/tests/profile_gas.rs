@@ -1,11 +1,23 @@
+#![allow(non_snake_case)]
 #![cfg(feature = "std")]
 
+use crate::{
+    interpreter::InterpreterParams,
+    prelude::*,
+    storage::PredicateStorage,
+};
 use fuel_asm::{
     op,
+    Opcode,
     RegId,
 };
-use fuel_tx::TransactionBuilder;
-use fuel_vm::prelude::*;
+use fuel_tx::{
+    ConsensusParameters,
+    DependentCost,
+    GasCosts,
+    GasCostsValues,
+    TransactionBuilder,
+};
 use rand::{
     rngs::StdRng,
     Rng,
@@ -55,6 +67,7 @@ fn profile_gas() {
                 .with_profiler(output.clone())
                 .build()
                 .into(),
+            ConsensusParameters::standard(),
         );
 
         let receipts = client.transact(tx_deploy);
@@ -103,3 +116,160 @@ fn profile_gas() {
     assert!(items0[0] == items1[0] && items0[0] == items2[0]);
     assert!(items0[1] == items1[1] && items0[1] == items2[1]);
 }
+
+#[test]
+fn gas_profile__reports_gas_per_opcode() {
+    // Given
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_gas_costs(GasCosts::unit());
+    let script = vec![
+        op::movi(0x10, 3),
+        op::subi(0x10, 0x10, 1),
+        op::jnei(RegId::ZERO, 0x10, 1),
+        op::movi(0x11, 32),
+        op::aloc(0x11),
+        op::mcli(RegId::HP, 32),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000)
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate checked tx")
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap();
+    let mut vm = Interpreter::<_, Script>::with_storage(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &consensus_params),
+    );
+
+    // When
+    vm.transact(tx).expect("failed to execute the script");
+    let profile = vm.gas_profile();
+
+    // Then
+    let usage = |count, gas| GasUsage { count, gas };
+    let by_opcode = profile.by_opcode();
+    let expected = [
+        (Opcode::MOVI, usage(2, 2)),
+        (Opcode::SUBI, usage(3, 3)),
+        (Opcode::JNEI, usage(3, 3)),
+        (Opcode::ALOC, usage(1, 1)),
+        (Opcode::MCLI, usage(1, 1)),
+        (Opcode::RET, usage(1, 1)),
+    ];
+    assert_eq!(by_opcode.len(), expected.len());
+    for entry in expected {
+        assert!(
+            by_opcode.contains(&entry),
+            "missing {entry:?} in {by_opcode:?}"
+        );
+    }
+    assert!(by_opcode
+        .windows(2)
+        .all(|pair| pair[0].1.gas >= pair[1].1.gas));
+    assert_eq!(
+        profile.by_context(),
+        vec![(GasContext::Script, usage(11, 11))]
+    );
+    assert_eq!(profile.total_gas(), 1_000 - vm.remaining_gas());
+    assert!(profile.to_string().contains("script"));
+}
+
+#[test]
+fn gas_profile__reports_the_dependent_gas_of_each_execution() {
+    // Given
+    let mut consensus_params = ConsensusParameters::standard();
+    let mcp = DependentCost::LightOperation {
+        base: 2,
+        units_per_gas: 8,
+    };
+    consensus_params.set_gas_costs(GasCosts::new(GasCostsValues::unit().with_mcp(mcp)));
+    let script = vec![
+        op::movi(0x10, 512),
+        op::aloc(0x10),
+        op::addi(0x12, RegId::HP, 256),
+        op::movi(0x11, 32),
+        op::mcp(0x12, RegId::HP, 0x11),
+        op::movi(0x11, 200),
+        op::mcp(0x12, RegId::HP, 0x11),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000)
+        .add_random_fee_input()
+        .finalize()
+        .into_checked(Default::default(), &consensus_params)
+        .expect("failed to generate checked tx")
+        .into_ready(
+            0,
+            consensus_params.gas_costs(),
+            consensus_params.fee_params(),
+        )
+        .unwrap();
+    let mut vm = Interpreter::<_, Script>::with_storage(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &consensus_params),
+    );
+
+    // When
+    vm.transact(tx).expect("failed to execute the script");
+    let profile = vm.gas_profile();
+
+    // Then
+    let expected = GasUsage {
+        count: 2,
+        gas: mcp.resolve(32) + mcp.resolve(200),
+    };
+    assert!(
+        profile.by_opcode().contains(&(Opcode::MCP, expected)),
+        "missing {expected:?} for MCP in {:?}",
+        profile.by_opcode()
+    );
+    assert_eq!(profile.total_gas(), 1_000 - vm.remaining_gas());
+}
+
+#[test]
+fn gas_profile__attributes_the_gas_of_predicates_to_the_predicate() {
+    // Given
+    let rng = &mut StdRng::seed_from_u64(2322u64);
+    let mut consensus_params = ConsensusParameters::standard();
+    consensus_params.set_gas_costs(GasCosts::unit());
+    let predicate: Vec<u8> = vec![op::movi(0x10, 1), op::ret(0x10)].into_iter().collect();
+    let owner = Input::predicate_owner(&predicate);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            1_000,
+            AssetId::BASE,
+            rng.gen(),
+            1_000,
+            predicate,
+            vec![],
+        ))
+        .finalize();
+    let mut vm = Interpreter::<_, Script>::with_storage(
+        PredicateStorage,
+        InterpreterParams::new(0, &consensus_params),
+    );
+    let program =
+        RuntimePredicate::from_tx(&tx, vm.tx_offset(), 0).expect("A predicate input");
+    vm.init_predicate(Context::PredicateVerification { program }, tx, 1_000)
+        .expect("failed to initialize the predicate");
+
+    // When
+    let result = vm.verify_predicate();
+    let profile = vm.gas_profile();
+
+    // Then
+    assert!(matches!(result, Ok(ProgramState::Return(1))));
+    let usage = GasUsage { count: 2, gas: 2 };
+    assert_eq!(profile.by_context(), vec![(GasContext::Predicate, usage)]);
+    assert!(profile.to_string().contains("predicate"));
+}
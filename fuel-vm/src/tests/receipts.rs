@@ -4,13 +4,29 @@ use fuel_asm::{
     RegId,
 };
 use fuel_tx::{
+    field::ReceiptsRoot,
+    ConsensusParameters,
     Receipt,
     ScriptExecutionResult,
 };
+use fuel_types::canonical::Serialize;
 
-use alloc::vec;
+use alloc::{
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use std::sync::Mutex;
 
-use crate::interpreter::ReceiptsCtx;
+use crate::{
+    checked_transaction::Checked,
+    crypto::ephemeral_merkle_root,
+    interpreter::{
+        InterpreterParams,
+        ReceiptsCtx,
+    },
+    prelude::*,
+};
 
 use super::test_helpers::run_script;
 
@@ -68,3 +84,221 @@ fn can_return_successfully_just_below_max_receipts() {
     };
     assert_eq!(result, ScriptExecutionResult::Success);
 }
+
+/// Runs `logs` logs followed by a return, with `data_len` bytes of data logged by
+/// every log if any, under the receipts limits.
+fn run_logs_with_limits(
+    logs: u32,
+    data_len: Option<u32>,
+    max_receipts_count: u64,
+    max_receipts_size: u64,
+) -> (Vec<Receipt>, Bytes32) {
+    let log = match data_len {
+        Some(_) => op::logd(RegId::ZERO, RegId::ZERO, RegId::ZERO, 0x11),
+        None => op::log(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+    };
+    let script = vec![
+        op::movi(0x10, logs),
+        op::movi(0x11, data_len.unwrap_or_default()),
+        log,
+        op::subi(0x10, 0x10, 1),
+        op::jnzb(0x10, RegId::ZERO, 1),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TestBuilder::new(2322u64)
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+
+    let params = ConsensusParameters::standard();
+    let mut transactor = Transactor::<_, Script>::new(
+        MemoryStorage::default(),
+        InterpreterParams {
+            max_receipts_count,
+            max_receipts_size,
+            ..InterpreterParams::new(0, &params)
+        },
+    );
+    transactor.transact(tx);
+
+    let receipts_root = transactor.interpreter().compute_receipts_root();
+    let receipts = transactor.receipts().expect("expected receipts").to_vec();
+    (receipts, receipts_root)
+}
+
+fn assert_too_many_receipts(receipts: &[Receipt], receipts_root: Bytes32) {
+    let [.., Receipt::Panic { reason, .. }, Receipt::ScriptResult { result, .. }] =
+        receipts
+    else {
+        panic!("Expect panic and result receipts");
+    };
+    assert_eq!(*reason.reason(), PanicReason::TooManyReceipts);
+    assert_eq!(*result, ScriptExecutionResult::Panic);
+
+    // The root covers exactly the retained receipts
+    let leaves = receipts.iter().map(|receipt| receipt.to_bytes());
+    assert_eq!(
+        receipts_root,
+        ephemeral_merkle_root(leaves.collect::<Vec<_>>().into_iter())
+    );
+}
+
+#[test]
+fn max_receipts_count_allows_exactly_the_max() {
+    let (receipts, _) = run_logs_with_limits(9, None, 10, u64::MAX);
+
+    // Nine logs and the return make ten receipts, then the script result
+    assert_eq!(receipts.len(), 11);
+    let Receipt::ScriptResult { result, .. } = receipts[10] else {
+        panic!("Expect result receipt");
+    };
+    assert_eq!(result, ScriptExecutionResult::Success);
+}
+
+#[test]
+fn max_receipts_count_panics_beyond_the_max() {
+    let (receipts, receipts_root) = run_logs_with_limits(10, None, 10, u64::MAX);
+
+    // Ten logs, then the panic instead of the return, and the script result
+    assert_eq!(receipts.len(), 12);
+    assert!(matches!(receipts[9], Receipt::Log { .. }));
+    assert_too_many_receipts(&receipts, receipts_root);
+}
+
+#[test]
+fn max_receipts_size_limits_the_receipts_with_their_data() {
+    let (receipts, _) = run_logs_with_limits(1, Some(100), u64::MAX, u64::MAX);
+    let log_size = (receipts[0].to_bytes().len() + 100) as u64;
+    let return_size = receipts[1].to_bytes().len() as u64;
+    let max_receipts_size = 3 * log_size + return_size;
+
+    let (receipts, _) = run_logs_with_limits(3, Some(100), u64::MAX, max_receipts_size);
+    // Three logs, the return and the script result
+    assert_eq!(receipts.len(), 5);
+    assert!(matches!(
+        receipts[4],
+        Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            ..
+        }
+    ));
+
+    let (receipts, receipts_root) =
+        run_logs_with_limits(3, Some(100), u64::MAX, max_receipts_size - 1);
+    // Three logs, then the panic instead of the return, and the script result
+    assert_eq!(receipts.len(), 5);
+    assert_too_many_receipts(&receipts, receipts_root);
+}
+
+/// Collects the delivered receipts.
+#[derive(Default)]
+struct CollectingListener(Mutex<Vec<Receipt>>);
+
+impl ReceiptListener for CollectingListener {
+    fn on_receipt(&self, receipt: &Receipt) {
+        self.0.lock().unwrap().push(receipt.clone());
+    }
+}
+
+/// A script with five logs followed by a panic.
+fn logs_and_panic_script() -> Checked<Script> {
+    let mut test_context = TestBuilder::new(2322u64);
+    let mut script = vec![op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO); 5];
+    script.push(op::div(0x10, RegId::ZERO, RegId::ZERO));
+    test_context
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build()
+}
+
+/// Runs five logs followed by a panic, with the listener installed if any.
+fn run_logs_and_panic(
+    listener: Option<(Arc<CollectingListener>, bool)>,
+) -> (Vec<Receipt>, Bytes32) {
+    let tx = logs_and_panic_script();
+
+    let mut vm = Interpreter::<_, Script>::with_memory_storage();
+    if let Some((listener, discard_receipts)) = listener {
+        vm.with_receipt_listener(listener, discard_receipts);
+    }
+    let mut transactor = Transactor::from(vm);
+    transactor.transact(tx);
+
+    let receipts_root = transactor.interpreter().compute_receipts_root();
+    let receipts = transactor.receipts().expect("expected receipts").to_vec();
+    (receipts, receipts_root)
+}
+
+#[test]
+fn receipt_listener_receives_receipts_in_order() {
+    let (expected_receipts, expected_root) = run_logs_and_panic(None);
+    let listener = Arc::new(CollectingListener::default());
+
+    let (receipts, receipts_root) = run_logs_and_panic(Some((listener.clone(), false)));
+
+    let delivered = listener.0.lock().unwrap().clone();
+    // Five logs, the panic and the script result
+    assert_eq!(delivered.len(), 7);
+    assert!(matches!(delivered[5], Receipt::Panic { .. }));
+    assert_eq!(delivered, expected_receipts);
+    assert_eq!(receipts, expected_receipts);
+    assert_eq!(receipts_root, expected_root);
+}
+
+#[test]
+fn receipt_listener_keeps_receipts_root_when_receipts_are_discarded() {
+    let (expected_receipts, expected_root) = run_logs_and_panic(None);
+    let listener = Arc::new(CollectingListener::default());
+
+    let (receipts, receipts_root) = run_logs_and_panic(Some((listener.clone(), true)));
+
+    assert!(receipts.is_empty());
+    assert_eq!(*listener.0.lock().unwrap(), expected_receipts);
+    assert_eq!(receipts_root, expected_root);
+}
+
+#[test]
+fn receipt_listener_is_shared_by_the_clones_of_the_interpreter() {
+    // Given
+    let (expected_receipts, _) = run_logs_and_panic(None);
+    let listener = Arc::new(CollectingListener::default());
+    let mut vm = Interpreter::<_, Script>::with_memory_storage();
+    vm.with_receipt_listener(listener.clone(), false);
+    let mut transactor = Transactor::from(vm.clone());
+
+    // When
+    transactor.transact(logs_and_panic_script());
+
+    // Then
+    assert_eq!(*listener.0.lock().unwrap(), expected_receipts);
+}
+
+#[test]
+fn receipts_root_matches_the_root_set_on_the_executed_script() {
+    let script = vec![
+        op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::logd(RegId::ZERO, RegId::ZERO, RegId::ZERO, RegId::ONE),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TestBuilder::new(2322u64)
+        .start_script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let mut transactor = Transactor::<_, Script>::new(
+        MemoryStorage::default(),
+        InterpreterParams::new(0, &ConsensusParameters::standard()),
+    );
+
+    transactor.transact(tx);
+
+    let state = transactor.state_transition().expect("tx was executed");
+    assert_eq!(state.receipts().len(), 4);
+    assert_eq!(
+        fuel_tx::receipts_root(state.receipts()),
+        *state.tx().receipts_root()
+    );
+    assert_ne!(*state.tx().receipts_root(), Script::EMPTY_RECEIPTS_ROOT);
+}
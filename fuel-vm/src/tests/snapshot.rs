@@ -0,0 +1,122 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::field::Script as _;
+
+use crate::{
+    prelude::*,
+    state::ExecuteState,
+};
+
+/// Writes a counter into the heap and logs it four times.
+fn counter_script() -> Vec<Instruction> {
+    let mut script = vec![op::movi(0x10, 32), op::aloc(0x10), op::movi(0x11, 1)];
+    for _ in 0..4 {
+        script.extend([
+            op::sw(RegId::HP, 0x11, 0),
+            op::addi(0x11, 0x11, 1),
+            op::logd(RegId::ZERO, RegId::ZERO, RegId::HP, 0x10),
+            op::log(0x11, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        ]);
+    }
+    script.push(op::ret(RegId::ONE));
+    script
+}
+
+/// Initializes the script and executes `steps` instructions.
+fn vm_after_steps(steps: usize) -> Interpreter<MemoryStorage, Script> {
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(counter_script(), vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let ready = tx
+        .into_ready(
+            0,
+            test_context.get_gas_costs(),
+            test_context.get_fee_params(),
+        )
+        .expect("failed to make the tx ready");
+
+    let mut vm = Interpreter::<_, Script>::with_memory_storage();
+    vm.init_script(ready)
+        .expect("failed to initialize the script");
+    let script_start = (vm.tx_offset() + vm.transaction().script_offset()) as Word;
+    vm.registers_mut()[RegId::PC] = script_start;
+    vm.registers_mut()[RegId::IS] = script_start;
+    for _ in 0..steps {
+        assert_eq!(vm.single_step().unwrap(), ExecuteState::Proceed);
+    }
+    vm
+}
+
+fn run_to_completion(vm: &mut Interpreter<MemoryStorage, Script>) -> Vec<Receipt> {
+    while vm.single_step().unwrap() == ExecuteState::Proceed {}
+    vm.receipts().to_vec()
+}
+
+#[test]
+fn restore__continuation_produces_the_same_receipts() {
+    // Given
+    let mut vm = vm_after_steps(9);
+    let snapshot = vm.snapshot();
+    let receipts_at_snapshot = vm.receipts().len();
+    let first_run = run_to_completion(&mut vm);
+
+    // When
+    vm.restore(&snapshot).expect("failed to restore");
+
+    // Then
+    assert_eq!(vm.receipts().len(), receipts_at_snapshot);
+    assert_eq!(vm.registers(), snapshot.registers());
+    let second_run = run_to_completion(&mut vm);
+    assert_eq!(second_run, first_run);
+    // The eight logs, the return and the script result
+    assert_eq!(second_run.len(), 10);
+}
+
+#[test]
+fn restore__memory_written_after_the_snapshot_is_rewound() {
+    // Given
+    let mut vm = vm_after_steps(4);
+    let snapshot = vm.snapshot();
+    let heap = vm.registers()[RegId::HP] as usize;
+    let counter_at_snapshot = vm.memory()[heap..heap + 8].to_vec();
+    run_to_completion(&mut vm);
+    assert_ne!(vm.memory()[heap..heap + 8], counter_at_snapshot);
+
+    // When
+    vm.restore(&snapshot).expect("failed to restore");
+
+    // Then
+    assert_eq!(vm.memory()[heap..heap + 8], counter_at_snapshot);
+    assert!(snapshot.memory_size() < vm.memory().len());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn snapshot__serde_roundtrip_restores_the_same_state() {
+    // Given
+    let mut vm = vm_after_steps(9);
+    let snapshot = vm.snapshot();
+    let first_run = run_to_completion(&mut vm);
+
+    // When
+    let json = serde_json::to_string(&snapshot).expect("failed to serialize");
+    let decoded: VmSnapshot<Script> =
+        serde_json::from_str(&json).expect("failed to deserialize");
+
+    // Then
+    assert_eq!(decoded, snapshot);
+    vm.restore(&decoded).expect("failed to restore");
+    assert_eq!(run_to_completion(&mut vm), first_run);
+}
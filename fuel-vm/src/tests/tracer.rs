@@ -0,0 +1,107 @@
+#![allow(non_snake_case)]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    Instruction,
+    Opcode,
+    RegId,
+};
+use fuel_types::Word;
+
+use crate::{
+    consts::VM_REGISTER_COUNT,
+    prelude::*,
+    tracer::ExecutionTrace,
+};
+
+/// 16 registers initialized and mixed, with 50 instructions in total.
+fn fifty_instructions_script() -> Vec<Instruction> {
+    let regs = || (0..16u8).map(|i| 0x10 + i);
+    let next = |r: u8| 0x10 + (r - 0x10 + 1) % 16;
+
+    let mut script: Vec<_> = regs().map(|r| op::movi(r, 3 * r as u32 + 1)).collect();
+    script.extend(regs().map(|r| op::add(r, r, next(r))));
+    script.extend(regs().map(|r| op::mul(r, r, next(r))));
+    script.push(op::log(0x10, 0x11, 0x12, 0x13));
+    script.push(op::ret(RegId::ONE));
+    script
+}
+
+fn replay(trace: &ExecutionTrace) -> Vec<Word> {
+    let mut registers = trace.initial_registers.clone();
+    for step in &trace.steps {
+        for (index, value) in &step.changed_registers {
+            registers[*index as usize] = *value;
+        }
+    }
+    registers
+}
+
+#[test]
+fn trace_recorder__replaying_register_deltas_reproduces_final_registers() {
+    // Given
+    let recorder = TraceRecorder::new();
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(fifty_instructions_script(), vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let mut vm = Interpreter::<_, Script>::with_memory_storage();
+    vm.with_tracer(recorder.clone());
+    let mut transactor = Transactor::from(vm);
+
+    // When
+    transactor.transact(tx);
+
+    // Then
+    let trace = recorder.trace();
+    assert_eq!(trace.initial_registers.len(), VM_REGISTER_COUNT);
+    assert_eq!(trace.steps.len(), 50);
+    assert_eq!(trace.steps[0].opcode, Some(Opcode::MOVI));
+    assert_eq!(trace.steps[49].opcode, Some(Opcode::RET));
+    assert!(trace
+        .steps
+        .iter()
+        .all(|step| step.result == InstructionResult::Success));
+    assert!(trace
+        .steps
+        .windows(2)
+        .all(|w| w[1].pc == w[0].pc + Instruction::SIZE as Word));
+    assert!(trace
+        .steps
+        .windows(2)
+        .all(|w| w[1].gas_remaining < w[0].gas_remaining));
+
+    let registers = transactor.interpreter().registers();
+    assert_eq!(replay(&trace), registers);
+}
+
+#[test]
+fn trace_recorder__trace_roundtrips_through_serde() {
+    // Given
+    let recorder = TraceRecorder::new();
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(fifty_instructions_script(), vec![])
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let mut vm = Interpreter::<_, Script>::with_memory_storage();
+    vm.with_tracer(recorder.clone());
+    Transactor::from(vm).transact(tx);
+    let trace = recorder.trace();
+
+    // When
+    let json = serde_json::to_string(&trace).expect("The trace is serializable");
+    let decoded: ExecutionTrace =
+        serde_json::from_str(&json).expect("The trace is deserializable");
+
+    // Then
+    assert_eq!(decoded, trace);
+}
@@ -0,0 +1,419 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "std")]
+
+use alloc::{
+    vec,
+    vec::Vec,
+};
+
+use fuel_asm::{
+    op,
+    RegId,
+};
+use fuel_tx::ConsensusParameters;
+use fuel_types::canonical::Serialize;
+
+use crate::{
+    checked_transaction::{
+        CheckPredicates,
+        Checked,
+        EstimatePredicates,
+    },
+    interpreter::{
+        InterpreterParams,
+        NotSupportedEcal,
+    },
+    pool::MemoryPool,
+    prelude::*,
+    script_with_data_offset,
+    util::test_helpers::TestBuilder,
+};
+
+/// Deploys a contract incrementing a counter in its storage and logging the new
+/// value, and builds `count` scripts calling it once.
+fn counter_calls(count: usize) -> (MemoryStorage, Vec<Checked<Script>>) {
+    let (storage, _, txs) = counter_calls_declaring(count, true);
+    (storage, txs)
+}
+
+/// Same as [`counter_calls`], declaring the called contract in the inputs of the
+/// scripts only if `declare_contract` is `true`.
+fn counter_calls_declaring(
+    count: usize,
+    declare_contract: bool,
+) -> (MemoryStorage, ContractId, Vec<Checked<Script>>) {
+    let mut test_context = TestBuilder::new(2322u64);
+    let contract = vec![
+        op::movi(0x12, 32),
+        op::aloc(0x12),
+        op::srw(0x10, 0x11, RegId::HP),
+        op::addi(0x10, 0x10, 1),
+        op::sww(RegId::HP, 0x11, 0x10),
+        op::log(0x10, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let contract_id = test_context
+        .setup_contract(contract, None, None)
+        .contract_id;
+
+    let (script, _) = script_with_data_offset!(
+        data_offset,
+        vec![
+            op::movi(0x10, data_offset as Immediate18),
+            op::call(0x10, RegId::ZERO, 0x10, RegId::CGAS),
+            op::ret(RegId::ONE),
+        ],
+        test_context.get_tx_params().tx_offset()
+    );
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let txs = (0..count)
+        .map(|_| {
+            let builder = test_context
+                .start_script(script.clone(), script_data.clone())
+                .script_gas_limit(1_000_000);
+            if declare_contract {
+                builder
+                    .contract_input(contract_id)
+                    .fee_input()
+                    .contract_output(&contract_id)
+                    .build()
+            } else {
+                builder.fee_input().build()
+            }
+        })
+        .collect();
+
+    (test_context.get_storage().clone(), contract_id, txs)
+}
+
+fn logged_value(receipts: &[Receipt]) -> Word {
+    match receipts.iter().find(|r| matches!(r, Receipt::Log { .. })) {
+        Some(Receipt::Log { ra, .. }) => *ra,
+        r => panic!("Expected a log receipt, got {r:?}"),
+    }
+}
+
+#[test]
+fn transactor_builder__uses_the_given_storage_params_and_memory() {
+    // Given
+    let pool = MemoryPool::new();
+    let (storage, txs) = counter_calls(1);
+    let mut params = InterpreterParams::new(0, &ConsensusParameters::standard());
+    params.memory_limit = 1024 * 1024;
+
+    // When
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(storage)
+        .with_params(params)
+        .with_memory(pool.get())
+        .build();
+    vm.transact(txs[0].clone());
+
+    // Then
+    assert_eq!(vm.interpreter().memory_limit(), 1024 * 1024);
+    assert_eq!(logged_value(vm.receipts().expect("expected receipts")), 1);
+    drop(vm);
+    assert_eq!(pool.len(), 1);
+}
+
+#[test]
+fn transactor_builder__accepts_a_storage_without_default() {
+    // Given
+    let (mut storage, txs) = counter_calls(1);
+
+    // When
+    let mut vm = Transactor::<&mut MemoryStorage, Script>::builder()
+        .with_storage(&mut storage)
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+    vm.transact(txs[0].clone());
+
+    // Then
+    assert_eq!(logged_value(vm.receipts().expect("expected receipts")), 1);
+}
+
+#[test]
+fn transact_many__applies_the_storage_changes_in_order() {
+    // Given
+    let (storage, txs) = counter_calls(3);
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(storage)
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+
+    // When
+    let results = vm.transact_many(txs);
+
+    // Then
+    let logged: Vec<_> = results
+        .iter()
+        .map(|result| logged_value(result.as_ref().expect("expected success").receipts()))
+        .collect();
+    assert_eq!(logged, vec![1, 2, 3]);
+    assert!(vm.state_transition().is_none());
+}
+
+#[test]
+fn replace_storage__next_transaction_uses_the_new_storage() {
+    // Given
+    let (storage, txs) = counter_calls(2);
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(storage.clone())
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+    vm.transact(txs[0].clone());
+
+    // When
+    let previous = vm.replace_storage(storage);
+    vm.transact(txs[1].clone());
+
+    // Then
+    assert_eq!(logged_value(vm.receipts().expect("expected receipts")), 1);
+    vm.replace_storage(previous);
+    vm.transact(txs[1].clone());
+    assert_eq!(logged_value(vm.receipts().expect("expected receipts")), 2);
+}
+
+#[test]
+fn allow_undeclared_contracts__calls_the_contract_missing_from_the_inputs() {
+    // Given
+    let (storage, contract_id, txs) = counter_calls_declaring(1, false);
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(storage)
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+    vm.transact(txs[0].clone());
+    let receipts = vm.receipts().expect("expected receipts");
+    assert!(receipts.iter().any(|r| matches!(
+        r,
+        Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::ContractNotInInputs
+    )));
+    assert_eq!(vm.interpreter().touched_contracts().count(), 0);
+
+    // When
+    vm.allow_undeclared_contracts(true);
+    let dry_run = vm.dry_run(txs[0].clone()).expect("failed to dry run");
+
+    // Then
+    assert!(!dry_run.transition().should_revert());
+    assert_eq!(logged_value(dry_run.receipts()), 1);
+    let touched: Vec<_> = vm.interpreter().touched_contracts().copied().collect();
+    assert_eq!(touched, vec![contract_id]);
+}
+
+#[test]
+fn allow_undeclared_contracts__is_ignored_by_transact() {
+    // Given
+    let (storage, _, txs) = counter_calls_declaring(1, false);
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(storage)
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+    vm.allow_undeclared_contracts(true);
+
+    // When
+    vm.transact(txs[0].clone());
+
+    // Then
+    let receipts = vm.receipts().expect("expected receipts");
+    assert!(receipts.iter().any(|r| matches!(
+        r,
+        Receipt::Panic { reason, .. } if *reason.reason() == PanicReason::ContractNotInInputs
+    )));
+    assert_eq!(vm.interpreter().touched_contracts().count(), 0);
+}
+
+#[test]
+fn instruction_counter__counts_the_instructions_of_the_script_and_the_calls() {
+    // Given
+    let (storage, txs) = counter_calls(1);
+    let mut vm = Transactor::<_, Script, NotSupportedEcal, InstructionCounter>::builder()
+        .with_storage(storage)
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+
+    // When
+    vm.transact(txs[0].clone());
+
+    // Then
+    assert!(vm.is_success());
+    let counter = vm.observer();
+    assert_eq!(counter.script(), 3);
+    assert_eq!(counter.calls(), 7);
+    assert_eq!(counter.predicates(), 0);
+    assert_eq!(counter.panics(), 0);
+    assert_eq!(counter.total(), 10);
+}
+
+#[test]
+fn instruction_counter__counts_the_panicking_instruction() {
+    // Given
+    let (storage, _, txs) = counter_calls_declaring(1, false);
+    let mut vm = Transactor::<_, Script, NotSupportedEcal, InstructionCounter>::builder()
+        .with_storage(storage)
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+
+    // When
+    vm.transact(txs[0].clone());
+
+    // Then
+    let counter = vm.observer();
+    assert_eq!(counter.script(), 2);
+    assert_eq!(counter.calls(), 0);
+    assert_eq!(counter.panics(), 1);
+}
+
+#[test]
+fn instruction_observer__does_not_change_the_execution() {
+    // Given
+    let (storage, txs) = counter_calls(2);
+    let params = InterpreterParams::new(0, &ConsensusParameters::standard());
+    let mut unobserved = Transactor::<_, Script>::builder()
+        .with_storage(storage.clone())
+        .with_params(params.clone())
+        .build();
+    let mut observed =
+        Transactor::<_, Script, NotSupportedEcal, InstructionCounter>::builder()
+            .with_storage(storage)
+            .with_params(params)
+            .build();
+
+    for tx in txs {
+        // When
+        unobserved.transact(tx.clone());
+        observed.transact(tx);
+
+        // Then
+        assert_eq!(unobserved.receipts(), observed.receipts());
+        assert_eq!(unobserved.script_gas_used(), observed.script_gas_used());
+    }
+    assert_eq!(observed.observer().total(), 20);
+}
+
+fn failing_script(
+    script: Vec<Instruction>,
+    script_data: Vec<u8>,
+) -> Transactor<MemoryStorage, Script> {
+    let mut test_context = TestBuilder::new(2322u64);
+    let tx = test_context
+        .start_script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .fee_input()
+        .build();
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(test_context.get_storage().clone())
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+    vm.transact(tx);
+    vm
+}
+
+#[test]
+fn failure__has_the_data_logged_before_the_revert() {
+    // Given
+    let payload = b"insufficient funds".to_vec();
+    let script = vec![
+        op::gtf_args(0x10, RegId::ZERO, GTFArgs::ScriptData),
+        op::movi(0x11, payload.len() as Immediate18),
+        op::logd(RegId::ZERO, RegId::ZERO, 0x10, 0x11),
+        op::movi(0x12, 42),
+        op::rvrt(0x12),
+    ];
+
+    // When
+    let vm = failing_script(script, payload.clone());
+
+    // Then
+    let failure = vm.failure().expect("expected the script to revert");
+    assert_eq!(
+        failure,
+        ScriptFailure::Revert {
+            code: 42,
+            revert_data: Some(payload.clone()),
+        }
+    );
+    assert_eq!(
+        ScriptExecutionResult::from(&failure),
+        ScriptExecutionResult::Revert
+    );
+    assert_eq!(
+        vm.state_transition().and_then(|s| s.failure()),
+        Some(failure)
+    );
+}
+
+#[test]
+fn failure__has_no_data_when_nothing_was_logged_before_the_panic() {
+    // Given
+    let script = vec![
+        op::log(RegId::ONE, RegId::ZERO, RegId::ZERO, RegId::ZERO),
+        op::div(0x10, RegId::ONE, RegId::ZERO),
+    ];
+
+    // When
+    let vm = failing_script(script, vec![]);
+
+    // Then
+    assert_eq!(
+        vm.failure(),
+        Some(ScriptFailure::Panic {
+            reason: PanicReason::ArithmeticError,
+            revert_data: None,
+        })
+    );
+}
+
+#[test]
+fn failure__is_none_when_the_script_succeeds() {
+    // Given
+    let (storage, txs) = counter_calls(1);
+    let mut vm = Transactor::<_, Script>::builder()
+        .with_storage(storage)
+        .with_params(InterpreterParams::new(0, &ConsensusParameters::standard()))
+        .build();
+
+    // When
+    vm.transact(txs[0].clone());
+
+    // Then
+    assert_eq!(vm.failure(), None);
+}
+
+#[test]
+fn instruction_counter__counts_the_instructions_of_the_predicates() {
+    // Given
+    let params = ConsensusParameters::standard();
+    let predicate: Vec<u8> = [op::movi(0x10, 1), op::ret(0x10)].into_iter().collect();
+    let owner = Input::predicate_owner(&predicate);
+    let mut tx =
+        TransactionBuilder::script(op::ret(RegId::ONE).to_bytes().to_vec(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_input(Input::coin_predicate(
+                Default::default(),
+                owner,
+                1_000_000,
+                *params.base_asset_id(),
+                Default::default(),
+                0,
+                predicate,
+                vec![],
+            ))
+            .finalize();
+    let mut estimation = InstructionCounter::default();
+    let mut verification = InstructionCounter::default();
+
+    // When
+    tx.estimate_predicates_with_observer(&params.clone().into(), &mut estimation)
+        .expect("Predicate estimation failed");
+    tx.into_checked_basic(Default::default(), &params)
+        .expect("Failed to check the transaction")
+        .check_predicates_with_observer(&params.into(), &mut verification)
+        .expect("Predicate verification failed");
+
+    // Then
+    assert_eq!(estimation.predicates(), 2);
+    assert_eq!(verification.predicates(), 2);
+    assert_eq!(verification.script(), 0);
+    assert_eq!(verification.panics(), 0);
+}
@@ -0,0 +1,174 @@
+//! Tracing of the executed instructions, used to compare the execution against
+//! another implementation of the VM.
+
+pub use crate::observer::VmStateView;
+
+use alloc::boxed::Box;
+use core::fmt;
+use dyn_clone::DynClone;
+use fuel_asm::PanicReason;
+
+#[cfg(feature = "std")]
+use crate::consts::VM_REGISTER_COUNT;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use fuel_asm::{
+    Opcode,
+    RegId,
+};
+#[cfg(feature = "std")]
+use fuel_types::Word;
+
+/// Outcome of the execution of an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InstructionResult {
+    /// The instruction was executed.
+    Success,
+    /// The instruction panicked.
+    Panic(PanicReason),
+    /// The execution was halted by an error that isn't a panic, like a storage
+    /// error.
+    Halt,
+}
+
+/// Receives the VM state before and after the execution of every instruction.
+pub trait ExecutionTracer: DynClone {
+    /// Called before the instruction is executed.
+    fn before_instruction(&mut self, state: &VmStateView);
+
+    /// Called after the instruction is executed.
+    fn after_instruction(&mut self, state: &VmStateView, result: &InstructionResult);
+}
+
+dyn_clone::clone_trait_object!(ExecutionTracer);
+
+/// The tracer installed into the interpreter.
+#[derive(Default, Clone)]
+pub(crate) struct Tracer(Option<Box<dyn ExecutionTracer + Send + Sync>>);
+
+impl Tracer {
+    pub(crate) fn set(&mut self, tracer: Box<dyn ExecutionTracer + Send + Sync>) {
+        self.0 = Some(tracer);
+    }
+
+    pub(crate) fn get_mut(
+        &mut self,
+    ) -> Option<&mut Box<dyn ExecutionTracer + Send + Sync>> {
+        self.0.as_mut()
+    }
+}
+
+impl fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => write!(f, "Tracer(enabled)"),
+            None => write!(f, "Tracer(disabled)"),
+        }
+    }
+}
+
+/// Executed instruction recorded by [`TraceRecorder`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceStep {
+    /// The program counter of the instruction.
+    pub pc: Word,
+    /// The opcode of the instruction, or `None` if the instruction is invalid.
+    pub opcode: Option<Opcode>,
+    /// The registers changed by the instruction, with their new values.
+    pub changed_registers: Vec<(u8, Word)>,
+    /// The global gas remaining after the instruction.
+    pub gas_remaining: Word,
+    /// The outcome of the instruction.
+    pub result: InstructionResult,
+}
+
+/// Trace recorded by [`TraceRecorder`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecutionTrace {
+    /// The registers before the first recorded instruction, empty if no instruction
+    /// was recorded.
+    pub initial_registers: Vec<Word>,
+    /// The recorded instructions.
+    pub steps: Vec<TraceStep>,
+}
+
+/// Records the executed instructions. The clones share the recorded trace, so a
+/// clone can be installed into the interpreter while the original is used to read
+/// the trace.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct TraceRecorder {
+    trace: std::sync::Arc<std::sync::Mutex<ExecutionTrace>>,
+    /// Registers before the traced instruction
+    registers: [Word; VM_REGISTER_COUNT],
+}
+
+#[cfg(feature = "std")]
+impl Default for TraceRecorder {
+    fn default() -> Self {
+        Self {
+            trace: Default::default(),
+            registers: [0; VM_REGISTER_COUNT],
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TraceRecorder {
+    /// Create a new empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The recorded trace.
+    pub fn trace(&self) -> ExecutionTrace {
+        self.lock().clone()
+    }
+
+    /// Clear the recorded trace.
+    pub fn clear(&self) {
+        *self.lock() = Default::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ExecutionTrace> {
+        self.trace.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(feature = "std")]
+impl ExecutionTracer for TraceRecorder {
+    fn before_instruction(&mut self, state: &VmStateView) {
+        self.registers = *state.registers();
+
+        let mut trace = self.lock();
+        if trace.steps.is_empty() {
+            trace.initial_registers = self.registers.to_vec();
+        }
+    }
+
+    fn after_instruction(&mut self, state: &VmStateView, result: &InstructionResult) {
+        let changed_registers = self
+            .registers
+            .iter()
+            .zip(state.registers())
+            .zip(0u8..)
+            .filter(|((before, after), _)| before != after)
+            .map(|((_, after), index)| (index, *after))
+            .collect();
+        let opcode = Opcode::try_from((state.instruction() >> 24) as u8).ok();
+
+        self.lock().steps.push(TraceStep {
+            pc: self.registers[RegId::PC],
+            opcode,
+            changed_registers,
+            gas_remaining: state.registers()[RegId::GGAS],
+            result: *result,
+        });
+    }
+}
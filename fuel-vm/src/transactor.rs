@@ -13,18 +13,28 @@ use crate::{
         ExecutableTransaction,
         Interpreter,
     },
+    observer::{
+        InstructionObserver,
+        NoopObserver,
+    },
     state::{
+        DryRun,
         ProgramState,
+        ScriptFailure,
         StateTransition,
         StateTransitionRef,
     },
     storage::InterpreterStorage,
 };
 
+#[cfg(feature = "std")]
+use crate::pool::MemoryPool;
 use crate::{
     checked_transaction::Ready,
+    code_cache::ContractCodeCache,
     interpreter::{
         InterpreterParams,
+        MemoryInstance,
         NotSupportedEcal,
     },
 };
@@ -34,6 +44,16 @@ use fuel_tx::{
     Receipt,
     Script,
 };
+use fuel_types::{
+    AssetId,
+    Word,
+};
+
+use alloc::{
+    sync::Arc,
+    vec::Vec,
+};
+use core::marker::PhantomData;
 
 #[derive(Debug)]
 /// State machine to execute transactions and provide runtime entities on
@@ -43,38 +63,72 @@ use fuel_tx::{
 /// builder`.
 ///
 /// Based on <https://doc.rust-lang.org/1.5.0/style/ownership/builders.html#non-consuming-builders-preferred>
-pub struct Transactor<S, Tx, Ecal = NotSupportedEcal>
+pub struct Transactor<S, Tx, Ecal = NotSupportedEcal, Observer = NoopObserver>
 where
     S: InterpreterStorage,
 {
-    interpreter: Interpreter<S, Tx, Ecal>,
+    interpreter: Interpreter<S, Tx, Ecal, Observer>,
     program_state: Option<ProgramState>,
     error: Option<InterpreterError<S::DataError>>,
 }
 
-impl<S, Tx, Ecal> Transactor<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Transactor<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     Ecal: EcalHandler + Default,
+    Observer: Default,
 {
     /// Transactor constructor
     pub fn new(storage: S, interpreter_params: InterpreterParams) -> Self {
         Self {
-            interpreter: Interpreter::<S, Tx, Ecal>::with_storage(
+            interpreter: Interpreter::<S, Tx, Ecal, Observer>::with_storage(
+                storage,
+                interpreter_params,
+            ),
+            program_state: None,
+            error: None,
+        }
+    }
+
+    /// Builder of a transactor, configuring the storage, the parameters and the
+    /// memory of the interpreter.
+    pub fn builder() -> TransactorBuilder<S, Tx, Ecal, Observer> {
+        TransactorBuilder {
+            storage: DefaultStorage,
+            interpreter_params: InterpreterParams::default(),
+            memory: None,
+            ecal: Ecal::default(),
+            _tx: PhantomData,
+        }
+    }
+
+    /// Transactor constructor executing the transactions in a memory taken from the
+    /// `pool`. The memory is returned to the pool when the transactor is dropped.
+    #[cfg(feature = "std")]
+    pub fn with_memory_pool(
+        pool: &MemoryPool,
+        storage: S,
+        interpreter_params: InterpreterParams,
+    ) -> Self {
+        Self {
+            interpreter: Interpreter::<S, Tx, Ecal, Observer>::with_memory_and_ecal(
+                pool.get(),
                 storage,
                 interpreter_params,
+                Ecal::default(),
             ),
             program_state: None,
             error: None,
         }
     }
 }
-impl<'a, S, Tx, Ecal> Transactor<S, Tx, Ecal>
+impl<'a, S, Tx, Ecal, Observer> Transactor<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     Ecal: EcalHandler,
+    Observer: InstructionObserver,
 {
     /// State transition representation after the execution of a transaction.
     ///
@@ -144,11 +198,118 @@ where
         }
     }
 
+    /// The free balances after the execution of a transaction, see
+    /// [`Interpreter::balances`].
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return
+    /// `None`.
+    pub fn balances(&self) -> Option<impl Iterator<Item = (AssetId, Word)> + '_> {
+        self.program_state
+            .is_some()
+            .then(|| self.interpreter.balances())
+    }
+
+    /// The script gas not used by the execution of a transaction.
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return
+    /// `None`.
+    pub fn unused_gas(&self) -> Option<Word> {
+        self.program_state
+            .is_some()
+            .then(|| self.interpreter.unused_gas())
+    }
+
+    /// The gas used by the predicates of the executed transaction, see
+    /// [`Interpreter::predicate_gas_used`].
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return
+    /// `None`.
+    pub fn predicate_gas_used(&self) -> Option<Word> {
+        self.program_state
+            .is_some()
+            .then(|| self.interpreter.predicate_gas_used())
+    }
+
+    /// The gas used by the execution of a transaction, excluding its predicates, see
+    /// [`Interpreter::script_gas_used`].
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return
+    /// `None`.
+    pub fn script_gas_used(&self) -> Option<Word> {
+        self.program_state
+            .is_some()
+            .then(|| self.interpreter.script_gas_used())
+    }
+
+    /// The fee refunded after the execution of a transaction, see
+    /// [`Interpreter::refund_amount`].
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return
+    /// `None`, and also returns `None` if the computation overflows.
+    pub fn refund_amount(&self, gas_price: Word) -> Option<Word> {
+        self.program_state
+            .is_some()
+            .then(|| self.interpreter.refund_amount(gas_price))
+            .flatten()
+    }
+
     /// Gets the interpreter.
-    pub fn interpreter(&self) -> &Interpreter<S, Tx, Ecal> {
+    pub fn interpreter(&self) -> &Interpreter<S, Tx, Ecal, Observer> {
         &self.interpreter
     }
 
+    /// Returns the instruction observer of the interpreter.
+    pub fn observer(&self) -> &Observer {
+        self.interpreter.observer()
+    }
+
+    /// Returns mutable access to the instruction observer of the interpreter.
+    pub fn observer_mut(&mut self) -> &mut Observer {
+        self.interpreter.observer_mut()
+    }
+
+    /// Replaces the storage used by the next transactions, returning the previous
+    /// one.
+    pub fn replace_storage(&mut self, storage: S) -> S {
+        self.interpreter.replace_storage(storage)
+    }
+
+    /// Replaces the memory used by the next transactions, returning the previous
+    /// one.
+    pub fn replace_memory(&mut self, memory: MemoryInstance) -> MemoryInstance {
+        self.interpreter.replace_memory(memory)
+    }
+
+    /// Sets the gas price of the next transactions, returning the previous one.
+    pub(crate) fn set_gas_price(&mut self, gas_price: Word) -> Word {
+        self.interpreter.set_gas_price(gas_price)
+    }
+
+    /// Sets the gas costs charged for the instructions of the next transactions,
+    /// returning the previous ones, see [`Interpreter::set_gas_costs`].
+    ///
+    /// [`Self::transact`] makes the transaction ready with the gas costs of the
+    /// transactor, so the fee follows them too; use [`Self::transact_ready_tx`] to
+    /// keep the fee of the transaction.
+    pub fn set_gas_costs(&mut self, gas_costs: GasCosts) -> GasCosts {
+        self.interpreter.set_gas_costs(gas_costs)
+    }
+
+    /// Sets a cache of the contract codes shared by the executed transactions, see
+    /// [`Interpreter::with_code_cache`].
+    pub fn with_code_cache(&mut self, cache: Arc<dyn ContractCodeCache>) -> &mut Self {
+        self.interpreter.with_code_cache(cache);
+        self
+    }
+
+    /// Allows the next dry runs, see [`Self::dry_run`], to use the contracts not declared
+    /// in their inputs, see [`Interpreter::allow_undeclared_contracts`]. The
+    /// transactions executed by [`Self::transact`] always reject them.
+    pub fn allow_undeclared_contracts(&mut self, allow: bool) -> &mut Self {
+        self.interpreter.allow_undeclared_contracts(allow);
+        self
+    }
+
     /// Gas costs of opcodes
     pub fn gas_costs(&self) -> &GasCosts {
         self.interpreter.gas_costs()
@@ -160,7 +321,7 @@ where
     }
 }
 
-impl<S, Ecal> Transactor<S, Script, Ecal>
+impl<S, Ecal, Observer> Transactor<S, Script, Ecal, Observer>
 where
     S: InterpreterStorage,
 {
@@ -174,6 +335,15 @@ where
             .then(|| self.interpreter.receipts())
     }
 
+    /// The revert or panic that ended the script, with the data it logged right
+    /// before failing. See [`ScriptFailure`].
+    ///
+    /// Follows the same criteria as [`Self::state_transition`] to return
+    /// `None`, and is `None` as well if the script didn't fail.
+    pub fn failure(&self) -> Option<ScriptFailure> {
+        self.receipts().and_then(ScriptFailure::from_receipts)
+    }
+
     /// Generate a backtrace when at least one receipt of `ScriptResult` was
     /// found.
     pub fn backtrace(&self) -> Option<Backtrace> {
@@ -184,7 +354,7 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Transactor<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Transactor<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
 {
@@ -213,12 +383,13 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Transactor<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Transactor<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage,
     Tx: ExecutableTransaction,
     <Tx as IntoChecked>::Metadata: CheckedMetadata,
     Ecal: EcalHandler,
+    Observer: InstructionObserver,
 {
     /// Execute a transaction, and return the new state of the transactor
     pub fn transact(&mut self, tx: Checked<Tx>) -> &mut Self {
@@ -248,6 +419,55 @@ where
         }
     }
 
+    /// Execute the transactions one after another on the same interpreter, and
+    /// return their results in order. Every transaction sees the storage changes of
+    /// the previous ones.
+    ///
+    /// The results are returned instead of being kept by the transactor, so
+    /// [`Self::state_transition`] and [`Self::error`] are `None` afterwards.
+    pub fn transact_many<I>(
+        &mut self,
+        txs: I,
+    ) -> Vec<Result<StateTransition<Tx>, InterpreterError<S::DataError>>>
+    where
+        I: IntoIterator<Item = Checked<Tx>>,
+    {
+        self.program_state.take();
+        self.error.take();
+
+        txs.into_iter()
+            .map(|tx| {
+                let gas_price = self.interpreter.gas_price();
+                let gas_costs = self.interpreter.gas_costs();
+                let fee_params = self.interpreter.fee_params();
+
+                let ready_tx = tx
+                    .into_ready(gas_price, gas_costs, fee_params)
+                    .map_err(InterpreterError::CheckError)?;
+                self.interpreter.transact(ready_tx).map(Into::into)
+            })
+            .collect()
+    }
+
+    /// Execute a transaction without modifying the storage, and return the result
+    /// along with the storage writes it would have done.
+    ///
+    /// The state of the transactor is left untouched, apart from the contracts touched
+    /// by the dry run, see [`Self::allow_undeclared_contracts`].
+    pub fn dry_run(
+        &mut self,
+        tx: Checked<Tx>,
+    ) -> Result<DryRun<Tx>, InterpreterError<S::DataError>> {
+        let gas_price = self.interpreter.gas_price();
+        let gas_costs = self.interpreter.gas_costs();
+        let fee_params = self.interpreter.fee_params();
+
+        let ready_tx = tx
+            .into_ready(gas_price, gas_costs, fee_params)
+            .map_err(InterpreterError::CheckError)?;
+        self.interpreter.dry_run(ready_tx)
+    }
+
     fn handle_error(&mut self, error: InterpreterError<S::DataError>) -> &mut Self {
         self.program_state.take();
         self.error.replace(error);
@@ -255,12 +475,99 @@ where
     }
 }
 
-impl<S, Tx, Ecal> From<Interpreter<S, Tx, Ecal>> for Transactor<S, Tx, Ecal>
+/// Marks a [`TransactorBuilder`] without a storage, building the transactor with the
+/// [`Default`] storage.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultStorage;
+
+/// Builder of a [`Transactor`], see [`Transactor::builder`].
+///
+/// `Storage` is the storage set by [`Self::with_storage`], or [`DefaultStorage`].
+#[derive(Debug)]
+pub struct TransactorBuilder<
+    S,
+    Tx,
+    Ecal = NotSupportedEcal,
+    Observer = NoopObserver,
+    Storage = DefaultStorage,
+> {
+    storage: Storage,
+    interpreter_params: InterpreterParams,
+    memory: Option<MemoryInstance>,
+    ecal: Ecal,
+    _tx: PhantomData<(S, Tx, Observer)>,
+}
+
+impl<S, Tx, Ecal, Observer, Storage> TransactorBuilder<S, Tx, Ecal, Observer, Storage> {
+    /// Sets the storage, [`Default`] otherwise.
+    pub fn with_storage(self, storage: S) -> TransactorBuilder<S, Tx, Ecal, Observer, S> {
+        TransactorBuilder {
+            storage,
+            interpreter_params: self.interpreter_params,
+            memory: self.memory,
+            ecal: self.ecal,
+            _tx: PhantomData,
+        }
+    }
+
+    /// Sets the parameters of the interpreter, [`InterpreterParams::default`]
+    /// otherwise.
+    pub fn with_params(mut self, interpreter_params: InterpreterParams) -> Self {
+        self.interpreter_params = interpreter_params;
+        self
+    }
+
+    /// Sets the memory the transactions are executed in, e.g. taken from a
+    /// [`MemoryPool`]. A new memory is allocated otherwise.
+    pub fn with_memory(mut self, memory: MemoryInstance) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Sets the state of the `ECAL` handler, [`Default`] otherwise.
+    pub fn with_ecal(mut self, ecal: Ecal) -> Self {
+        self.ecal = ecal;
+        self
+    }
+}
+
+impl<S, Tx, Ecal, Observer> TransactorBuilder<S, Tx, Ecal, Observer>
+where
+    S: InterpreterStorage + Default,
+    Tx: ExecutableTransaction,
+    Observer: Default,
+{
+    /// Builds the transactor with the [`Default`] storage.
+    pub fn build(self) -> Transactor<S, Tx, Ecal, Observer> {
+        self.with_storage(S::default()).build()
+    }
+}
+
+impl<S, Tx, Ecal, Observer> TransactorBuilder<S, Tx, Ecal, Observer, S>
+where
+    S: InterpreterStorage,
+    Tx: ExecutableTransaction,
+    Observer: Default,
+{
+    /// Builds the transactor with the storage set by [`Self::with_storage`].
+    pub fn build(self) -> Transactor<S, Tx, Ecal, Observer> {
+        Interpreter::with_memory_and_ecal(
+            self.memory.unwrap_or_default(),
+            self.storage,
+            self.interpreter_params,
+            self.ecal,
+        )
+        .into()
+    }
+}
+
+impl<S, Tx, Ecal, Observer> From<Interpreter<S, Tx, Ecal, Observer>>
+    for Transactor<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
 {
-    fn from(interpreter: Interpreter<S, Tx, Ecal>) -> Self {
+    fn from(interpreter: Interpreter<S, Tx, Ecal, Observer>) -> Self {
         let program_state = None;
         let error = None;
 
@@ -272,28 +579,30 @@ where
     }
 }
 
-impl<S, Tx, Ecal> From<Transactor<S, Tx, Ecal>> for Interpreter<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> From<Transactor<S, Tx, Ecal, Observer>>
+    for Interpreter<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
 {
-    fn from(transactor: Transactor<S, Tx, Ecal>) -> Self {
+    fn from(transactor: Transactor<S, Tx, Ecal, Observer>) -> Self {
         transactor.interpreter
     }
 }
 
-impl<S, Tx, Ecal> AsRef<Interpreter<S, Tx, Ecal>> for Transactor<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> AsRef<Interpreter<S, Tx, Ecal, Observer>>
+    for Transactor<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
     Ecal: EcalHandler,
 {
-    fn as_ref(&self) -> &Interpreter<S, Tx, Ecal> {
+    fn as_ref(&self) -> &Interpreter<S, Tx, Ecal, Observer> {
         &self.interpreter
     }
 }
 
-impl<S, Tx, Ecal> AsRef<S> for Transactor<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> AsRef<S> for Transactor<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
@@ -303,7 +612,7 @@ where
     }
 }
 
-impl<S, Tx, Ecal> AsMut<S> for Transactor<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> AsMut<S> for Transactor<S, Tx, Ecal, Observer>
 where
     Tx: ExecutableTransaction,
     S: InterpreterStorage,
@@ -313,11 +622,12 @@ where
     }
 }
 
-impl<S, Tx, Ecal> Default for Transactor<S, Tx, Ecal>
+impl<S, Tx, Ecal, Observer> Default for Transactor<S, Tx, Ecal, Observer>
 where
     S: InterpreterStorage + Default,
     Tx: ExecutableTransaction,
     Ecal: EcalHandler + Default,
+    Observer: Default,
 {
     fn default() -> Self {
         Self::new(S::default(), InterpreterParams::default())
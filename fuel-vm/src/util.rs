@@ -142,6 +142,7 @@ pub mod test_helpers {
         Transaction,
         TransactionBuilder,
         TxParameters,
+        Upgrade,
         Witness,
     };
     use fuel_types::{
@@ -314,12 +315,20 @@ pub mod test_helpers {
         }
 
         pub fn with_fee_params(&mut self, fee_params: FeeParameters) -> &mut TestBuilder {
-            self.consensus_params.fee_params = fee_params;
+            self.consensus_params.set_fee_params(fee_params);
+            self
+        }
+
+        pub fn with_contract_params(
+            &mut self,
+            contract_params: ContractParameters,
+        ) -> &mut TestBuilder {
+            self.consensus_params.set_contract_params(contract_params);
             self
         }
 
         pub fn base_asset_id(&mut self, base_asset_id: AssetId) -> &mut TestBuilder {
-            self.consensus_params.base_asset_id = base_asset_id;
+            self.consensus_params.set_base_asset_id(base_asset_id);
             self
         }
 
@@ -517,6 +526,18 @@ pub mod test_helpers {
             self.execute_tx_inner(&mut transactor, checked)
         }
 
+        pub fn upgrade(
+            &mut self,
+            checked: Checked<Upgrade>,
+        ) -> anyhow::Result<StateTransition<Upgrade>> {
+            let interpreter_params =
+                InterpreterParams::new(self.gas_price, &self.consensus_params);
+            let mut transactor =
+                Transactor::<_, _>::new(self.storage.clone(), interpreter_params);
+
+            self.execute_tx_inner(&mut transactor, checked)
+        }
+
         pub fn execute_tx(
             &mut self,
             checked: Checked<Script>,